@@ -0,0 +1,432 @@
+//! Persistent cache of lint results, enabled with `--cache`.
+//!
+//! Each linted file is stored with the hash of its source text, the hash of the configuration
+//! resolved for it, and the diagnostics reported for it. A file is skipped on later runs as long as
+//! neither hash changes, nor the content of any module it depends on, nor any of the paths tried for
+//! its unresolved imports is created (only tracked when the import plugin is enabled, see
+//! [`LintRunner::module_dependencies`] and [`LintRunner::missing_module_paths`]).
+
+use std::{
+    ffi::OsStr,
+    fs,
+    hash::{BuildHasher, Hasher},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rayon::prelude::*;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{
+    DiagnosticFix, DiagnosticService, Error, LabeledSpan, OxcDiagnostic, Severity,
+};
+use oxc_linter::{ConfigStore, LintRunner};
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of the format of cache entries.
+/// Bump it when the content of entries changes, so that caches written before are not reused.
+const FORMAT_VERSION: u32 = 2;
+
+/// File name of the cache when `--cache-location` is not given, or is a directory.
+pub const DEFAULT_CACHE_FILE_NAME: &str = ".oxlintcache";
+
+pub struct LintCache {
+    location: PathBuf,
+    cwd: PathBuf,
+    /// Hash of the CLI options which affect the diagnostics of every file, e.g. `--fix`.
+    options_hash: u64,
+    files: FxHashMap<PathBuf, CacheEntry>,
+    /// Hashes of files read during this run. `None` if the file could not be read.
+    content_hashes: FxHashMap<PathBuf, Option<u64>>,
+    /// Hashes of the configuration resolved for each file to lint during this run.
+    config_hashes: FxHashMap<PathBuf, u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    version: String,
+    format_version: u32,
+    files: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    content_hash: u64,
+    config_hash: u64,
+    /// Modules the file depends on, with the hashes of their content at the time of linting.
+    dependencies: Vec<(PathBuf, u64)>,
+    /// Paths tried for the unresolved imports of the file and its dependencies, which did not exist
+    /// at the time of linting.
+    missing: Vec<PathBuf>,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDiagnostic {
+    message: String,
+    severity: CachedSeverity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    labels: Vec<CachedLabel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<CachedFix>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedLabel {
+    offset: usize,
+    length: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    primary: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedFix {
+    offset: usize,
+    length: usize,
+    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+impl LintCache {
+    /// Load the cache from `location`, or start with an empty cache if it does not exist,
+    /// can not be parsed, or was written by another version of oxlint or in another format.
+    pub fn load(cwd: &Path, location: Option<&Path>, options: &impl Serialize) -> Self {
+        let location = match location {
+            Some(location) => {
+                let location = cwd.join(location);
+                if location.is_dir() { location.join(DEFAULT_CACHE_FILE_NAME) } else { location }
+            }
+            None => cwd.join(DEFAULT_CACHE_FILE_NAME),
+        };
+
+        let files = fs::read_to_string(&location)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| {
+                cache_file.version == VERSION && cache_file.format_version == FORMAT_VERSION
+            })
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+
+        Self {
+            location,
+            cwd: cwd.to_path_buf(),
+            options_hash: hash_serialized(options),
+            files,
+            content_hashes: FxHashMap::default(),
+            config_hashes: FxHashMap::default(),
+        }
+    }
+
+    /// Split `paths` into the files which need to be linted, and the diagnostics of the files
+    /// whose cached results are still valid.
+    pub fn partition(
+        &mut self,
+        paths: Vec<Arc<OsStr>>,
+        config_store: &ConfigStore,
+    ) -> (Vec<Arc<OsStr>>, Vec<Vec<Error>>) {
+        self.hash_files(paths.iter().map(|path| PathBuf::from(path.as_ref())).collect());
+
+        // Files sharing a configuration usually resolve to the same `Arc`s, so only hash each of them once.
+        // The resolved states are kept alive in the map, so that their addresses can not be reused.
+        let mut resolved_configs = FxHashMap::default();
+        for path in &paths {
+            let path = Path::new(path);
+            let state = config_store.resolve(path);
+            let key = (
+                Arc::as_ptr(&state.rules).cast::<()>(),
+                Arc::as_ptr(&state.config).cast::<()>(),
+                Arc::as_ptr(&state.external_rules).cast::<()>(),
+            );
+            let options_hash = self.options_hash;
+            let (_, config_hash) = resolved_configs
+                .entry(key)
+                .or_insert_with(|| (state.clone(), hash_serialized(&(options_hash, &state))));
+            self.config_hashes.insert(path.to_path_buf(), *config_hash);
+        }
+
+        let candidates = paths
+            .iter()
+            .map(Path::new)
+            .filter(|path| self.is_unchanged(path))
+            .flat_map(|path| &self.files[path].dependencies)
+            .map(|(dependency, _)| dependency.clone())
+            .collect::<Vec<_>>();
+        self.hash_files(candidates);
+
+        let (cached, to_lint): (Vec<_>, Vec<_>) = paths.into_iter().partition(|path| {
+            let path = Path::new(path);
+            self.is_unchanged(path)
+                && self.files[path].dependencies.iter().all(|(dependency, hash)| {
+                    self.content_hashes.get(dependency).copied().flatten() == Some(*hash)
+                })
+                && self.files[path].missing.iter().all(|missing| !missing.exists())
+        });
+
+        let diagnostics = cached
+            .par_iter()
+            .filter_map(|path| {
+                let path = Path::new(path);
+                let entry = &self.files[path];
+                if entry.diagnostics.is_empty() {
+                    return None;
+                }
+                let source_text = fs::read_to_string(path).ok()?;
+                let diagnostics =
+                    entry.diagnostics.iter().map(CachedDiagnostic::to_diagnostic).collect();
+                Some(DiagnosticService::wrap_diagnostics(
+                    &self.cwd,
+                    path,
+                    &source_text,
+                    diagnostics,
+                ))
+            })
+            .collect();
+
+        (to_lint, diagnostics)
+    }
+
+    /// Store the `diagnostics` reported for the files in `paths`, which were just linted by `lint_runner`.
+    pub fn update(
+        &mut self,
+        paths: &[Arc<OsStr>],
+        diagnostics: &[Vec<Error>],
+        lint_runner: &LintRunner,
+    ) {
+        let paths_by_source_name = paths
+            .iter()
+            .map(|path| {
+                let path = Path::new(path);
+                (DiagnosticService::source_name(&self.cwd, path), path)
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut diagnostics_by_path = FxHashMap::<&Path, Vec<CachedDiagnostic>>::default();
+        for error in diagnostics.iter().flatten() {
+            let Some(path) = error
+                .source_code()
+                .and_then(|source| source.name())
+                .and_then(|name| paths_by_source_name.get(name))
+            else {
+                continue;
+            };
+            diagnostics_by_path.entry(path).or_default().push(CachedDiagnostic::from_error(error));
+        }
+
+        let dependencies_by_path = paths
+            .iter()
+            .map(|path| lint_runner.module_dependencies(Path::new(path)))
+            .collect::<Vec<_>>();
+        self.hash_files(dependencies_by_path.iter().flatten().cloned().collect());
+
+        for (path, dependencies) in paths.iter().zip(dependencies_by_path) {
+            let path = Path::new(path);
+            self.files.remove(path);
+
+            let Some(content_hash) = self.content_hashes.get(path).copied().flatten() else {
+                continue;
+            };
+            let Some(dependencies) = dependencies
+                .into_iter()
+                .map(|dependency| {
+                    let hash = self.content_hashes.get(&dependency).copied().flatten()?;
+                    Some((dependency, hash))
+                })
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            self.files.insert(
+                path.to_path_buf(),
+                CacheEntry {
+                    content_hash,
+                    config_hash: self.config_hashes[path],
+                    dependencies,
+                    missing: lint_runner.missing_module_paths(path),
+                    diagnostics: diagnostics_by_path.remove(path).unwrap_or_default(),
+                },
+            );
+        }
+    }
+
+    /// Write the cache to disk, dropping entries of files which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file can not be written.
+    pub fn save(mut self) -> io::Result<()> {
+        self.files.retain(|path, _| self.content_hashes.contains_key(path) || path.exists());
+
+        let cache_file = CacheFile {
+            version: VERSION.to_string(),
+            format_version: FORMAT_VERSION,
+            files: self.files,
+        };
+        let content = serde_json::to_string(&cache_file)?;
+        if let Some(parent) = self.location.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.location, content)
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Whether the content and the configuration of `path` match its cache entry.
+    /// Dependencies of the file are not checked.
+    fn is_unchanged(&self, path: &Path) -> bool {
+        self.files.get(path).is_some_and(|entry| {
+            self.content_hashes.get(path).copied().flatten() == Some(entry.content_hash)
+                && self.config_hashes.get(path) == Some(&entry.config_hash)
+        })
+    }
+
+    /// Hash the content of all `paths` which have not been read during this run yet.
+    fn hash_files(&mut self, mut paths: Vec<PathBuf>) {
+        paths.retain(|path| !self.content_hashes.contains_key(path));
+        paths.sort_unstable();
+        paths.dedup();
+        let hashes = paths
+            .into_par_iter()
+            .map(|path| {
+                let hash = fs::read(&path).ok().map(|content| FxBuildHasher.hash_one(content));
+                (path, hash)
+            })
+            .collect::<Vec<_>>();
+        self.content_hashes.extend(hashes);
+    }
+}
+
+impl CachedDiagnostic {
+    fn from_error(error: &Error) -> Self {
+        let severity = match error.severity() {
+            Some(Severity::Warning) => CachedSeverity::Warning,
+            Some(Severity::Advice) => CachedSeverity::Advice,
+            Some(Severity::Error) | None => CachedSeverity::Error,
+        };
+        let labels = error
+            .labels()
+            .into_iter()
+            .flatten()
+            .map(|label| CachedLabel {
+                offset: label.offset(),
+                length: label.len(),
+                label: label.label().map(ToString::to_string),
+                primary: label.primary(),
+            })
+            .collect();
+        let fixes = OxcDiagnostic::downcast_ref(error)
+            .and_then(|diagnostic| diagnostic.fixes.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|fix| CachedFix {
+                offset: fix.span.offset(),
+                length: fix.span.len(),
+                content: fix.content.to_string(),
+                message: fix.message.as_ref().map(ToString::to_string),
+            })
+            .collect();
+
+        Self {
+            message: error.to_string(),
+            severity,
+            code: error.code().map(|code| code.to_string()),
+            help: error.help().map(|help| help.to_string()),
+            note: error.note().map(|note| note.to_string()),
+            url: error.url().map(|url| url.to_string()),
+            labels,
+            fixes,
+        }
+    }
+
+    fn to_diagnostic(&self) -> OxcDiagnostic {
+        let severity = match self.severity {
+            CachedSeverity::Error => Severity::Error,
+            CachedSeverity::Warning => Severity::Warning,
+            CachedSeverity::Advice => Severity::Advice,
+        };
+        let mut diagnostic = OxcDiagnostic::error(self.message.clone()).with_severity(severity);
+
+        if let Some(code) = &self.code {
+            // Codes are rendered as `scope(number)`, see `OxcCode`.
+            diagnostic = match code.strip_suffix(')').and_then(|code| code.split_once('(')) {
+                Some((scope, number)) => {
+                    diagnostic.with_error_code(scope.to_string(), number.to_string())
+                }
+                None => diagnostic.with_error_code_scope(code.clone()),
+            };
+        }
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        if let Some(note) = &self.note {
+            diagnostic = diagnostic.with_note(note.clone());
+        }
+        if let Some(url) = &self.url {
+            diagnostic = diagnostic.with_url(url.clone());
+        }
+
+        diagnostic
+            .with_labels(self.labels.iter().map(|label| {
+                let span = (label.offset, label.length);
+                if label.primary {
+                    LabeledSpan::new_primary_with_span(label.label.clone(), span)
+                } else {
+                    LabeledSpan::new_with_span(label.label.clone(), span)
+                }
+            }))
+            .with_fixes(self.fixes.iter().map(|fix| DiagnosticFix {
+                span: (fix.offset, fix.length).into(),
+                content: fix.content.clone().into(),
+                message: fix.message.clone().map(Into::into),
+            }))
+    }
+}
+
+/// Hash the JSON serialization of `value`.
+///
+/// Used for the resolved configuration, which does not implement `Hash`.
+fn hash_serialized(value: &impl Serialize) -> u64 {
+    struct HashWriter(FxHasher);
+
+    impl Write for HashWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.write(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = HashWriter(FxHasher::default());
+    // Serializing the configuration can only fail for maps with non-string keys, which it does not have.
+    let _ = serde_json::to_writer(&mut writer, value);
+    writer.0.finish()
+}
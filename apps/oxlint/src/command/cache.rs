@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint changed files.
+    /// Results of unchanged files are read from the cache.
+    /// Has no effect when type-aware linting or JS plugins are enabled.
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file or directory.
+    /// Defaults to `.oxlintcache` in the current working directory.
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod cache_options {
+    use std::path::PathBuf;
+
+    use super::{super::lint::lint_command, CacheOptions};
+
+    fn get_cache_options(arg: &str) -> CacheOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().cache_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_cache_options(".");
+        assert!(!options.cache);
+        assert!(options.cache_location.is_none());
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_cache_options("--cache .");
+        assert!(options.cache);
+    }
+
+    #[test]
+    fn cache_location() {
+        let options = get_cache_options("--cache --cache-location .cache/oxlint .");
+        assert!(options.cache);
+        assert_eq!(options.cache_location, Some(PathBuf::from(".cache/oxlint")));
+    }
+}
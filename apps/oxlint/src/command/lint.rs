@@ -7,8 +7,11 @@ use crate::output_formatter::OutputFormat;
 
 use super::{
     MiscOptions, PATHS_ERROR_MESSAGE, VERSION,
+    cache::{CacheOptions, cache_options},
     ignore::{IgnoreOptions, ignore_options},
-    misc_options, validate_paths,
+    misc_options,
+    suppressions::{SuppressionsOptions, suppressions_options},
+    validate_paths,
 };
 
 #[derive(Debug, Clone, Bpaf)]
@@ -35,6 +38,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
     /// List all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -65,6 +71,9 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub inline_config_options: InlineConfigOptions,
 
+    #[bpaf(external)]
+    pub suppressions_options: SuppressionsOptions,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE))]
     pub paths: Vec<PathBuf>,
@@ -253,7 +262,7 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback_with(default_output_format), hide_usage)]
     pub format: OutputFormat,
 }
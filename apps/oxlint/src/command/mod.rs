@@ -1,13 +1,17 @@
+mod cache;
 mod ignore;
 mod lint;
+mod suppressions;
 
 use std::path::PathBuf;
 
 use bpaf::Bpaf;
 
 pub use self::{
+    cache::CacheOptions,
     ignore::IgnoreOptions,
     lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
+    suppressions::{SuppressionsMode, SuppressionsOptions},
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,50 @@
+use bpaf::Bpaf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Bpaf)]
+pub enum SuppressionsMode {
+    /// Suppress all existing errors by recording their number per file and rule in `oxlint-suppressions.json`.
+    /// Later runs only report the errors of a rule in a file if there are more of them than were suppressed.
+    #[bpaf(long("suppress-all"))]
+    SuppressAll,
+    /// Remove suppressions from `oxlint-suppressions.json` that no longer occur.
+    #[bpaf(long("prune-suppressions"))]
+    Prune,
+}
+
+/// Bulk Suppressions
+#[derive(Debug, Clone, Bpaf)]
+pub struct SuppressionsOptions {
+    #[bpaf(external, optional, hide_usage)]
+    pub suppressions_mode: Option<SuppressionsMode>,
+}
+
+#[cfg(test)]
+mod suppressions_options {
+    use super::{super::lint::lint_command, SuppressionsMode};
+
+    fn get_suppressions_mode(arg: &str) -> Option<SuppressionsMode> {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().suppressions_options.suppressions_mode
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(get_suppressions_mode("."), None);
+    }
+
+    #[test]
+    fn suppress_all() {
+        assert_eq!(get_suppressions_mode("--suppress-all ."), Some(SuppressionsMode::SuppressAll));
+    }
+
+    #[test]
+    fn prune_suppressions() {
+        assert_eq!(get_suppressions_mode("--prune-suppressions ."), Some(SuppressionsMode::Prune));
+    }
+
+    #[test]
+    fn conflicting_modes() {
+        let args = ["--suppress-all", "--prune-suppressions", "."];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+}
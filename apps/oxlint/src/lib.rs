@@ -1,6 +1,7 @@
 // Ignore dead code warnings when building `tasks/website`, which disables `napi` Cargo feature
 #![cfg_attr(not(feature = "napi"), allow(dead_code))]
 
+mod cache;
 mod command;
 mod config_loader;
 mod init;
@@ -9,6 +10,7 @@ mod lsp;
 mod mode;
 mod output_formatter;
 mod result;
+mod suppressions;
 mod walk;
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use std::{
     fmt::Debug,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
-    sync::Arc,
+    sync::{Arc, mpsc},
     time::Instant,
 };
 
@@ -14,16 +14,18 @@ use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
     AllowWarnDeny, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ExternalLinter,
-    ExternalPluginStore, InvalidFilterKind, LintFilter, LintOptions, LintRunner,
+    ExternalPluginStore, FixKind, InvalidFilterKind, LintFilter, LintOptions, LintRunner,
     LintServiceOptions, Linter,
 };
 
 #[cfg(feature = "napi")]
 use crate::js_config::JsConfigLoaderCb;
 use crate::{
+    cache::LintCache,
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, WarningOptions},
     config_loader::{CliConfigLoadError, ConfigLoadError, ConfigLoader},
-    output_formatter::{LintCommandInfo, OutputFormatter},
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    suppressions::{SUPPRESSIONS_FILE_NAME, Suppressions},
     walk::Walk,
 };
 use oxc_linter::LintIgnoreMatcher;
@@ -82,6 +84,8 @@ impl CliRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            cache_options,
+            suppressions_options,
             ..
         } = self.options;
 
@@ -402,12 +406,47 @@ impl CliRunner {
             .into_iter()
             .filter(|path| !ignore_matcher.should_ignore(Path::new(path)))
             .collect::<Vec<Arc<OsStr>>>();
+        let number_of_files = files_to_lint.len();
+
+        let suppressions_mode = suppressions_options.suppressions_mode;
+        let mut suppressions = match Suppressions::load(&self.cwd, suppressions_mode) {
+            Ok(suppressions) => suppressions,
+            Err(err) => {
+                print_and_flush_stdout(
+                    stdout,
+                    &format!("Failed to read {SUPPRESSIONS_FILE_NAME}: {err}\n"),
+                );
+                return CliRunResult::InvalidOptionConfig;
+            }
+        };
+        // Suppressions are applied to the diagnostics of all files, including the ones read from the cache.
+        let suppressed_files = suppressions.as_ref().map(|_| files_to_lint.clone());
+
+        // SARIF reports the fixes offered by rules, so they are computed even if none are applied.
+        let report_fixes = format_str == OutputFormat::Sarif && fix_options.fix_kind().is_none();
+        let fix_kind =
+            if report_fixes { FixKind::SafeFixOrSuggestion } else { fix_options.fix_kind() };
+
+        // Results of type-aware rules and JS plugins may depend on files outside of the module graph,
+        // so they can not be cached.
+        let mut lint_cache = (cache_options.cache && !type_aware && external_linter.is_none())
+            .then(|| {
+                LintCache::load(
+                    &self.cwd,
+                    cache_options.cache_location.as_deref(),
+                    &(fix_kind.bits(), report_fixes, report_unused_directives, &basic_options.tsconfig),
+                )
+            });
+        let (files_to_lint, cached_diagnostics) = match &mut lint_cache {
+            Some(lint_cache) => lint_cache.partition(files_to_lint, &config_store),
+            None => (files_to_lint, vec![]),
+        };
 
         let linter = Linter::new(LintOptions::default(), config_store, external_linter)
-            .with_fix(fix_options.fix_kind())
+            .with_fix(fix_kind)
+            .with_report_fixes(report_fixes)
             .with_report_unused_directives(report_unused_directives);
 
-        let number_of_files = files_to_lint.len();
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if path.is_file() {
@@ -457,14 +496,71 @@ impl CliRunner {
             }
         };
 
-        match lint_runner.lint_files(&files_to_lint, tx_error.clone()) {
+        // With `--cache` or bulk suppressions, diagnostics are collected to be stored in the cache
+        // and filtered by the suppressions before they are reported.
+        let (tx_lint, rx_lint) = if lint_cache.is_some() || suppressions.is_some() {
+            let (tx_lint, rx_lint) = mpsc::channel();
+            (tx_lint, Some(rx_lint))
+        } else {
+            (tx_error.clone(), None)
+        };
+
+        let lint_runner = match lint_runner.lint_files(&files_to_lint, tx_lint.clone()) {
             Ok(lint_runner) => {
-                lint_runner.report_unused_directives(report_unused_directives, &tx_error);
+                lint_runner.report_unused_directives(report_unused_directives, &tx_lint);
+                lint_runner
             }
             Err(err) => {
                 print_and_flush_stdout(stdout, &err);
                 return CliRunResult::TsGoLintError;
             }
+        };
+
+        drop(tx_lint);
+
+        if let Some(rx_lint) = rx_lint {
+            let mut diagnostics = rx_lint.into_iter().collect::<Vec<_>>();
+
+            if let Some(mut lint_cache) = lint_cache {
+                lint_cache.update(&files_to_lint, &diagnostics, &lint_runner);
+                let location = lint_cache.location().to_path_buf();
+                if let Err(err) = lint_cache.save() {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to write the cache file {}: {err}\n",
+                            location.to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                }
+            }
+            diagnostics.extend(cached_diagnostics);
+
+            if let Some(suppressions) = &mut suppressions
+                && let Some(suppressed_files) = &suppressed_files
+            {
+                diagnostics = suppressions.apply(
+                    suppressions_mode,
+                    suppressed_files,
+                    diagnostics,
+                    report_unused_directives,
+                );
+                if suppressions_mode.is_some()
+                    && let Err(err) = suppressions.save()
+                {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to write {}: {err}\n",
+                            suppressions.location().to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                }
+            }
+
+            for diagnostics in diagnostics {
+                tx_error.send(diagnostics).unwrap();
+            }
         }
 
         drop(tx_error);
@@ -633,6 +729,8 @@ fn render_config_builder_error(
 mod test {
     use std::fs;
 
+    use cow_utils::CowUtils;
+
     use crate::{DEFAULT_OXLINTRC_NAME, tester::Tester};
     use oxc_linter::rules::RULES;
 
@@ -1087,6 +1185,173 @@ mod test {
         insta::assert_snapshot!("init_config", content);
     }
 
+    #[test]
+    fn test_cache() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        let cache_path = temp_dir.path().join(crate::cache::DEFAULT_CACHE_FILE_NAME);
+        fs::write(temp_dir.path().join("debugger.js"), "debugger;\n").unwrap();
+
+        let tester = Tester::new().with_cwd(temp_dir.path().to_path_buf());
+        let args = &["--cache", "-f", "unix", "-D", "no-debugger", "debugger.js"];
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("debugger.js:1:1: `debugger` statement is not allowed"));
+        assert!(fs::exists(&cache_path).unwrap());
+
+        // Unchanged files are not linted again, their diagnostics are read from the cache.
+        let cache = fs::read_to_string(&cache_path).unwrap();
+        fs::write(&cache_path, cache.cow_replace("is not allowed", "is cached").as_ref()).unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("debugger.js:1:1: `debugger` statement is cached"));
+
+        // Caches written in another format are not reused.
+        let cache = fs::read_to_string(&cache_path).unwrap();
+        let mut cache_file = serde_json::from_str::<serde_json::Value>(&cache).unwrap();
+        cache_file["formatVersion"] = 1.into();
+        fs::write(&cache_path, cache_file.to_string()).unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("debugger.js:1:1: `debugger` statement is not allowed"));
+
+        // A different configuration invalidates the cache.
+        let output = tester.test_output_verbose(&[
+            "--cache",
+            "-f",
+            "unix",
+            "-W",
+            "no-debugger",
+            "debugger.js",
+        ]);
+        assert!(
+            output.contains("`debugger` statement is not allowed [Warning/eslint(no-debugger)]")
+        );
+
+        // So does changing the file.
+        fs::write(temp_dir.path().join("debugger.js"), "foo();\n").unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.is_empty(), "{output}");
+    }
+
+    #[test]
+    fn test_cache_with_rule_options() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        let config_path = temp_dir.path().join(".oxlintrc.json");
+        fs::write(temp_dir.path().join("test.js"), "if (a == null) {}\n").unwrap();
+        fs::write(&config_path, r#"{ "rules": { "eqeqeq": ["error", "smart"] } }"#).unwrap();
+
+        let tester = Tester::new().with_cwd(temp_dir.path().to_path_buf());
+        let args = &["--cache", "-f", "unix", "test.js"];
+        assert!(tester.test_output_verbose(args).is_empty());
+
+        // Only the options of the rule change, which invalidates the cache.
+        fs::write(&config_path, r#"{ "rules": { "eqeqeq": ["error", "always"] } }"#).unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("eslint(eqeqeq)"), "{output}");
+    }
+
+    #[test]
+    fn test_cache_location_with_import_plugin() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        fs::create_dir(temp_dir.path().join("cache")).unwrap();
+        fs::write(temp_dir.path().join("a.js"), "import { foo } from './b.js';\nfoo();\n").unwrap();
+        fs::write(temp_dir.path().join("b.js"), "export function foo() {}\n").unwrap();
+
+        let tester = Tester::new().with_cwd(temp_dir.path().to_path_buf());
+        let args = &[
+            "--cache",
+            "--cache-location",
+            "cache",
+            "-f",
+            "unix",
+            "--import-plugin",
+            "-A",
+            "all",
+            "-D",
+            "import/named",
+            "a.js",
+        ];
+        assert!(tester.test_output_verbose(args).is_empty());
+        assert!(fs::exists(temp_dir.path().join("cache/.oxlintcache")).unwrap());
+
+        // `a.js` is unchanged, but the module it imports from is not.
+        fs::write(temp_dir.path().join("b.js"), "export function bar() {}\n").unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("a.js:1:10:"), "{output}");
+        assert!(output.contains("import(named)"), "{output}");
+    }
+
+    #[test]
+    fn test_cache_with_unresolved_import() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        fs::write(temp_dir.path().join("a.js"), "import { foo } from './missing';
+foo();
+")
+            .unwrap();
+
+        let tester = Tester::new().with_cwd(temp_dir.path().to_path_buf());
+        let args = &[
+            "--cache",
+            "-f",
+            "unix",
+            "--import-plugin",
+            "-A",
+            "all",
+            "-D",
+            "import/named",
+            "a.js",
+        ];
+        assert!(tester.test_output_verbose(args).is_empty());
+
+        // `a.js` is unchanged, but the module it imports can now be resolved.
+        fs::write(temp_dir.path().join("missing.js"), "export function bar() {}\n").unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("a.js:1:10:"), "{output}");
+        assert!(output.contains("import(named)"), "{output}");
+    }
+
+    #[test]
+    fn test_bulk_suppressions() {
+        let temp_dir = tempfile::tempdir().expect("Could not create a temp dir");
+        let file_path = temp_dir.path().join("debugger.js");
+        let suppressions_path = temp_dir.path().join(crate::suppressions::SUPPRESSIONS_FILE_NAME);
+        fs::write(&file_path, "debugger;\ndebugger;\n").unwrap();
+
+        let tester = Tester::new().with_cwd(temp_dir.path().to_path_buf());
+        let args = &["-f", "unix", "-A", "all", "-D", "no-debugger", "debugger.js"];
+        let with_args = |extra: &'static str| [&[extra][..], args].concat();
+
+        assert!(tester.test_output_verbose(&with_args("--suppress-all")).is_empty());
+        assert_eq!(
+            fs::read_to_string(&suppressions_path).unwrap(),
+            "{\n  \"debugger.js\": {\n    \"no-debugger\": {\n      \"count\": 2\n    }\n  }\n}\n"
+        );
+        assert!(tester.test_output_verbose(args).is_empty());
+
+        // All errors of the rule are reported once there are more than were suppressed.
+        fs::write(&file_path, "debugger;\ndebugger;\ndebugger;\n").unwrap();
+        let output = tester.test_output_verbose(args);
+        assert!(output.contains("3 problems"), "{output}");
+
+        // Suppressions which no longer occur are reported like unused disable directives.
+        fs::write(&file_path, "debugger;\n").unwrap();
+        assert!(tester.test_output_verbose(args).is_empty());
+        let output = tester.test_output_verbose(&with_args("--report-unused-disable-directives"));
+        assert!(
+            output.contains(
+                "debugger.js:1:1: Unused suppression (only 1 of 2 suppressed errors were reported from no-debugger). [Warning]"
+            ),
+            "{output}"
+        );
+
+        let output = tester.test_output_verbose(&with_args("--prune-suppressions"));
+        assert!(output.is_empty(), "{output}");
+        assert!(fs::read_to_string(&suppressions_path).unwrap().contains("\"count\": 1"));
+        let output = tester.test_output_verbose(&with_args("--report-unused-disable-directives"));
+        assert!(output.is_empty(), "{output}");
+
+        fs::write(&file_path, "").unwrap();
+        tester.test_output_verbose(&with_args("--prune-suppressions"));
+        assert_eq!(fs::read_to_string(&suppressions_path).unwrap(), "{}\n");
+    }
+
     #[test]
     fn test_overrides() {
         // This is split into three to avoid creating a snapshot with a filename too large to be usable on Windows.
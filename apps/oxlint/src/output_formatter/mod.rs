@@ -5,6 +5,7 @@ mod github;
 mod gitlab;
 mod json;
 mod junit;
+mod sarif;
 mod stylish;
 mod unix;
 mod xml_utils;
@@ -18,6 +19,7 @@ use github::GithubOutputFormatter;
 use gitlab::GitlabOutputFormatter;
 use junit::JUnitOutputFormatter;
 use rustc_hash::FxHashSet;
+use sarif::SarifOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
@@ -38,6 +40,9 @@ pub enum OutputFormat {
     Checkstyle,
     Stylish,
     JUnit,
+    /// Static Analysis Results Interchange Format 2.1.0
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -54,6 +59,7 @@ impl FromStr for OutputFormat {
             "gitlab" => Ok(Self::Gitlab),
             "stylish" => Ok(Self::Stylish),
             "junit" => Ok(Self::JUnit),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -135,6 +141,7 @@ impl OutputFormatter {
             OutputFormat::Default => Box::new(DefaultOutputFormatter),
             OutputFormat::Stylish => Box::<StylishOutputFormatter>::default(),
             OutputFormat::JUnit => Box::<JUnitOutputFormatter>::default(),
+            OutputFormat::Sarif => Box::<SarifOutputFormatter>::default(),
         }
     }
 
@@ -165,7 +172,7 @@ mod test {
     #[test]
     fn test_output_formatter_diagnostic_formats() {
         let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix"];
+            vec!["checkstyle", "default", "github", "junit", "agent", "sarif", "stylish", "unix"];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -189,7 +196,7 @@ mod test {
     #[test]
     fn test_output_formatter_diagnostic_formats_success() {
         let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix"];
+            vec!["checkstyle", "default", "github", "junit", "agent", "sarif", "stylish", "unix"];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -216,7 +223,7 @@ mod test {
     #[test]
     fn test_output_formatter_diagnostic_formats_with_parser_error() {
         let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix"];
+            vec!["checkstyle", "default", "github", "junit", "agent", "sarif", "stylish", "unix"];
 
         // disabled for windows
         // json will output the offset which will be different for windows
@@ -241,7 +248,7 @@ mod test {
     #[test]
     fn test_output_formatter_diagnostic_formats_with_disable_directive() {
         let mut formats: Vec<&str> =
-            vec!["checkstyle", "default", "github", "junit", "agent", "stylish", "unix"];
+            vec!["checkstyle", "default", "github", "junit", "agent", "sarif", "stylish", "unix"];
 
         // disabled for windows
         // json will output the offset which will be different for windows
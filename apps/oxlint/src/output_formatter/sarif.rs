@@ -0,0 +1,437 @@
+use cow_utils::CowUtils;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use oxc_diagnostics::{
+    Error, OxcDiagnostic, Severity, SourceSpan,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::{
+    RuleCategory, plugin_display_name,
+    rules::{RULES, RuleEnum},
+};
+
+use crate::output_formatter::InternalFormatter;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Characters which are percent-encoded in artifact URIs.
+/// RFC 3986 allows only alphanumeric characters, `-`, `.`, `_`, `~` and the `/` separator in paths.
+const URI_PATH_ENCODE_SET: &AsciiSet =
+    &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~').remove(b'/');
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties {
+    category: RuleCategory,
+    fix: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifText,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifText {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<SarifText>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifText>,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifText>,
+}
+
+/// Renders reports as a SARIF 2.1.0 log with a single run.
+///
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all
+/// diagnostics have been reported before writing them to the output stream.
+#[derive(Default)]
+struct SarifReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        Some(format_sarif(&mut self.diagnostics))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+fn format_sarif(diagnostics: &mut Vec<Error>) -> String {
+    let mut rules = vec![];
+    let mut rule_indices = FxHashMap::<String, usize>::default();
+
+    let results = diagnostics
+        .drain(..)
+        .map(|error| {
+            let rule_id = error.code().map(|code| code.to_string());
+            let rule_index = rule_id.as_ref().map(|rule_id| {
+                *rule_indices.entry(rule_id.clone()).or_insert_with(|| {
+                    rules.push(sarif_rule(rule_id, &error));
+                    rules.len() - 1
+                })
+            });
+            let level = match error.severity() {
+                Some(Severity::Error) | None => "error",
+                Some(Severity::Warning) => "warning",
+                Some(Severity::Advice) => "note",
+            };
+
+            let source_code = error.source_code();
+            let artifact_location = SarifArtifactLocation {
+                uri: artifact_uri(source_code.and_then(|source| source.name()).unwrap_or_default()),
+            };
+            let source_text = source_text(&error);
+            let region = source_text.zip(error.labels().and_then(|mut labels| labels.next())).map(
+                |(source_text, label)| {
+                    let mut region = sarif_region(source_text, *label.inner());
+                    let snippet = source_text.get(label.offset()..label.offset() + label.len());
+                    region.snippet = snippet
+                        .filter(|snippet| !snippet.is_empty())
+                        .map(|snippet| SarifText { text: snippet.to_string() });
+                    region
+                },
+            );
+
+            // Fixes offered as alternatives are separate SARIF fixes, each replacing a single region.
+            let fixes = source_text
+                .zip(
+                    OxcDiagnostic::downcast_ref(&error)
+                        .and_then(|diagnostic| diagnostic.fixes.as_ref()),
+                )
+                .map(|(source_text, fixes)| {
+                    fixes
+                        .iter()
+                        .map(|fix| SarifFix {
+                            description: fix
+                                .message
+                                .as_ref()
+                                .map(|message| SarifText { text: message.to_string() }),
+                            artifact_changes: vec![SarifArtifactChange {
+                                artifact_location: artifact_location.clone(),
+                                replacements: vec![SarifReplacement {
+                                    deleted_region: sarif_region(source_text, fix.span),
+                                    inserted_content: (!fix.content.is_empty())
+                                        .then(|| SarifText { text: fix.content.to_string() }),
+                                }],
+                            }],
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level,
+                message: SarifText { text: error.to_string() },
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation { artifact_location, region },
+                }],
+                fixes,
+            }
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver { name: "oxlint", information_uri: "https://oxc.rs", rules },
+            },
+            column_kind: "utf16CodeUnits",
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("Failed to serialize")
+}
+
+/// The URI of the file named `source_name`, which is relative to the working directory.
+///
+/// Paths are `/`-separated and percent-encoded, as SARIF requires URIs. Names which are already
+/// `file://` URIs are kept as they are.
+fn artifact_uri(source_name: &str) -> String {
+    if source_name.starts_with("file://") {
+        return source_name.to_string();
+    }
+    let path = source_name.cow_replace('\\', "/");
+    utf8_percent_encode(&path, URI_PATH_ENCODE_SET).to_string()
+}
+
+/// Describe the rule which reported `error`, using the metadata of the oxlint rule with the code `rule_id`.
+/// Rules of JS plugins only have their id and the URL of the diagnostic.
+fn sarif_rule(rule_id: &str, error: &Error) -> SarifRule {
+    let Some(rule) = find_rule(rule_id) else {
+        return SarifRule {
+            id: rule_id.to_string(),
+            name: None,
+            short_description: None,
+            help_uri: error.url().map(|url| url.to_string()),
+            properties: None,
+        };
+    };
+
+    SarifRule {
+        id: rule_id.to_string(),
+        name: Some(rule.name()),
+        short_description: short_description(rule).map(|text| SarifText { text }),
+        help_uri: Some(format!(
+            "https://oxc.rs/docs/guide/usage/linter/rules/{}/{}.html",
+            rule.plugin_name(),
+            rule.name()
+        )),
+        properties: Some(SarifRuleProperties {
+            category: rule.category(),
+            fix: rule.fix().to_string(),
+        }),
+    }
+}
+
+/// Find the rule reporting diagnostics with the code `rule_id`, e.g. `eslint(no-debugger)`.
+fn find_rule(rule_id: &str) -> Option<&'static RuleEnum> {
+    let (plugin, name) = rule_id.strip_suffix(')')?.split_once('(')?;
+    RULES
+        .iter()
+        .find(|rule| rule.name() == name && plugin_display_name(rule.plugin_name()) == plugin)
+}
+
+/// The first paragraph of the "What it does" section of the rule documentation.
+#[cfg(feature = "ruledocs")]
+fn short_description(rule: &RuleEnum) -> Option<String> {
+    let what_it_does = rule.documentation()?.split("### What it does").nth(1)?;
+    let paragraph = what_it_does.trim_start().split("\n\n").next()?;
+    let paragraph = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!paragraph.is_empty()).then_some(paragraph)
+}
+
+#[cfg(not(feature = "ruledocs"))]
+fn short_description(_rule: &RuleEnum) -> Option<String> {
+    None
+}
+
+/// The whole source text of the file `error` was reported for.
+fn source_text(error: &Error) -> Option<&str> {
+    let source = error.source_code()?;
+    let contents = source.read_span(&SourceSpan::from((0, 0)), 0, usize::MAX).ok()?;
+    std::str::from_utf8(contents.data()).ok()
+}
+
+fn sarif_region(source_text: &str, span: SourceSpan) -> SarifRegion {
+    let (start_line, start_column) = position(source_text, span.offset());
+    let (end_line, end_column) = position(source_text, span.offset() + span.len());
+    SarifRegion { start_line, start_column, end_line, end_column, snippet: None }
+}
+
+/// The 1-based line and column of `offset` in `source_text`.
+///
+/// Columns are counted in UTF-16 code units, the default `columnKind` of SARIF.
+fn position(source_text: &str, offset: usize) -> (usize, usize) {
+    let before = source_text.get(..offset).unwrap_or(source_text);
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let line = before.bytes().filter(|byte| *byte == b'\n').count() + 1;
+    let column = before[line_start..].encode_utf16().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        DiagnosticFix, NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::{SarifReporter, artifact_uri, position};
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(Span::new(11, 20))
+            .with_fixes([DiagnosticFix {
+                span: Span::new(11, 20).into(),
+                content: "".into(),
+                message: Some("Remove the debugger statement".into()),
+            }])
+            .with_source_code(NamedSource::new("test.ts", "const a=1;\ndebugger;"));
+
+        let first_result = reporter.render_error(error);
+
+        // reporter keeps it in memory
+        assert!(first_result.is_none());
+
+        // reporter gives results when finishing
+        let second_result = reporter.finish(&DiagnosticResult::default());
+
+        assert!(second_result.is_some());
+        let json: serde_json::Value = serde_json::from_str(&second_result.unwrap()).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+
+        let run = &json["runs"][0];
+        let rule = &run["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["id"], "eslint(no-debugger)");
+        assert_eq!(rule["name"], "no-debugger");
+        assert_eq!(
+            rule["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rule["properties"]["category"], "correctness");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "eslint(no-debugger)");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "error message");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "test.ts");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 1);
+        assert_eq!(location["region"]["endLine"], 2);
+        assert_eq!(location["region"]["endColumn"], 10);
+        assert_eq!(location["region"]["snippet"]["text"], "debugger;");
+
+        let fix = &result["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Remove the debugger statement");
+        let change = &fix["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], "test.ts");
+        assert_eq!(change["replacements"][0]["deletedRegion"]["startLine"], 2);
+        assert!(change["replacements"][0].get("insertedContent").is_none());
+    }
+
+    #[test]
+    fn artifact_uris() {
+        assert_eq!(artifact_uri("src/test.ts"), "src/test.ts");
+        assert_eq!(artifact_uri("my dir/a file.ts"), "my%20dir/a%20file.ts");
+        assert_eq!(artifact_uri("src\\[id]\\ä.ts"), "src/%5Bid%5D/%C3%A4.ts");
+        assert_eq!(artifact_uri("file:///tmp/a%20file.ts"), "file:///tmp/a%20file.ts");
+    }
+
+    #[test]
+    fn utf16_columns() {
+        let source_text = "const s = '😀';\nfoo(s);";
+        assert_eq!(position(source_text, 0), (1, 1));
+        assert_eq!(position(source_text, source_text.find(';').unwrap()), (1, 15));
+        assert_eq!(position(source_text, source_text.find("foo").unwrap()), (2, 1));
+    }
+}
@@ -0,0 +1,227 @@
+//! Bulk suppressions, recorded in `oxlint-suppressions.json`.
+//!
+//! Instead of fixing every existing violation of a newly enabled rule, `--suppress-all` records how many
+//! errors each rule reports in each file. Later runs hide the errors of a rule in a file as long as
+//! there are no more of them than were suppressed, and report all of them otherwise.
+//! Suppressions which no longer occur are removed with `--prune-suppressions`.
+
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{DiagnosticService, Error, LabeledSpan, OxcDiagnostic, Severity};
+use oxc_linter::AllowWarnDeny;
+
+use crate::cli::SuppressionsMode;
+
+pub const SUPPRESSIONS_FILE_NAME: &str = "oxlint-suppressions.json";
+
+/// Suppressed error counts, keyed by file path relative to the working directory and rule name.
+type SuppressedFiles = BTreeMap<String, BTreeMap<String, SuppressedRule>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct SuppressedRule {
+    count: usize,
+}
+
+pub struct Suppressions {
+    location: PathBuf,
+    cwd: PathBuf,
+    files: SuppressedFiles,
+}
+
+impl Suppressions {
+    /// Load `oxlint-suppressions.json` from `cwd`.
+    ///
+    /// Returns `Ok(None)` if the file does not exist and no suppressions are going to be written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists, but can not be read or parsed.
+    pub fn load(cwd: &Path, mode: Option<SuppressionsMode>) -> io::Result<Option<Self>> {
+        let location = cwd.join(SUPPRESSIONS_FILE_NAME);
+        let files = match fs::read_to_string(&location) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && mode.is_some() => {
+                SuppressedFiles::default()
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        Ok(Some(Self { location, cwd: cwd.to_path_buf(), files }))
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Remove the suppressed errors from `diagnostics`, which were reported for the files in `paths`.
+    ///
+    /// With [`SuppressionsMode::SuppressAll`], all errors are recorded as suppressed first.
+    /// With [`SuppressionsMode::Prune`], suppressions that no longer occur are removed first.
+    /// Otherwise, suppressions that no longer occur are reported with `unused_severity`, if any.
+    pub fn apply(
+        &mut self,
+        mode: Option<SuppressionsMode>,
+        paths: &[Arc<OsStr>],
+        diagnostics: Vec<Vec<Error>>,
+        unused_severity: Option<AllowWarnDeny>,
+    ) -> Vec<Vec<Error>> {
+        let paths_by_source_name = paths
+            .iter()
+            .map(|path| {
+                let path = Path::new(path);
+                (DiagnosticService::source_name(&self.cwd, path), path)
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut counts = FxHashMap::<&str, BTreeMap<String, usize>>::default();
+        for (source_name, rule) in diagnostics.iter().flatten().filter_map(suppressible) {
+            if let Some((source_name, _)) = paths_by_source_name.get_key_value(source_name) {
+                *counts.entry(source_name.as_str()).or_default().entry(rule).or_default() += 1;
+            }
+        }
+
+        match mode {
+            Some(SuppressionsMode::SuppressAll) => {
+                for source_name in paths_by_source_name.keys() {
+                    match counts.get(source_name.as_str()) {
+                        Some(rules) => {
+                            let rules = rules
+                                .iter()
+                                .map(|(rule, count)| {
+                                    (rule.clone(), SuppressedRule { count: *count })
+                                })
+                                .collect();
+                            self.files.insert(source_name.clone(), rules);
+                        }
+                        None => {
+                            self.files.remove(source_name);
+                        }
+                    }
+                }
+            }
+            Some(SuppressionsMode::Prune) => {
+                let cwd = &self.cwd;
+                self.files.retain(|source_name, rules| {
+                    if paths_by_source_name.contains_key(source_name) {
+                        let counts = counts.get(source_name.as_str());
+                        rules.retain(|rule, suppressed| {
+                            let count = counts.and_then(|counts| counts.get(rule)).copied();
+                            suppressed.count = suppressed.count.min(count.unwrap_or_default());
+                            suppressed.count > 0
+                        });
+                        !rules.is_empty()
+                    } else {
+                        cwd.join(source_name).exists()
+                    }
+                });
+            }
+            None => {}
+        }
+
+        // Rules of a file which report more errors than were suppressed are not suppressed at all.
+        let suppressed = |source_name: &str, rule: &str| {
+            self.files.get(source_name).and_then(|rules| rules.get(rule)).is_some_and(
+                |suppressed| {
+                    let count = counts.get(source_name).and_then(|counts| counts.get(rule));
+                    count.is_some_and(|count| *count <= suppressed.count)
+                },
+            )
+        };
+        let mut diagnostics = diagnostics
+            .into_iter()
+            .map(|diagnostics| {
+                diagnostics
+                    .into_iter()
+                    .filter(|error| {
+                        !suppressible(error).is_some_and(|(source_name, rule)| {
+                            paths_by_source_name.contains_key(source_name)
+                                && suppressed(source_name, &rule)
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|diagnostics| !diagnostics.is_empty())
+            .collect::<Vec<_>>();
+
+        if let Some(severity) = unused_severity {
+            for (source_name, rules) in &self.files {
+                let Some(path) = paths_by_source_name.get(source_name) else {
+                    continue;
+                };
+                let unused = rules
+                    .iter()
+                    .filter_map(|(rule, suppressed)| {
+                        let count = counts
+                            .get(source_name.as_str())
+                            .and_then(|counts| counts.get(rule))
+                            .copied()
+                            .unwrap_or_default();
+                        (count < suppressed.count)
+                            .then(|| unused_suppression(rule, suppressed.count, count, severity))
+                    })
+                    .collect::<Vec<_>>();
+                if !unused.is_empty() {
+                    let source_text = fs::read_to_string(path).unwrap_or_default();
+                    diagnostics.push(DiagnosticService::wrap_diagnostics(
+                        &self.cwd,
+                        path,
+                        &source_text,
+                        unused,
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Write the suppressions to `oxlint-suppressions.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be written.
+    pub fn save(&self) -> io::Result<()> {
+        let mut content = serde_json::to_string_pretty(&self.files)?;
+        content.push('\n');
+        fs::write(&self.location, content)
+    }
+}
+
+/// The source name and rule name of `error`, if it is an error reported by a rule.
+///
+/// Rule names are written as in configuration files, e.g. `no-debugger` or `import/no-cycle`.
+fn suppressible(error: &Error) -> Option<(&str, String)> {
+    if !matches!(error.severity(), Some(Severity::Error) | None) {
+        return None;
+    }
+    let code = error.code()?.to_string();
+    let (plugin, rule) = code.strip_suffix(')')?.split_once('(')?;
+    let rule = if plugin == "eslint" { rule.to_string() } else { format!("{plugin}/{rule}") };
+    let source_name = error.source_code()?.name()?;
+    Some((source_name, rule))
+}
+
+fn unused_suppression(
+    rule: &str,
+    suppressed: usize,
+    count: usize,
+    severity: AllowWarnDeny,
+) -> OxcDiagnostic {
+    let severity =
+        if severity == AllowWarnDeny::Deny { Severity::Error } else { Severity::Warning };
+    OxcDiagnostic::warn(format!(
+        "Unused suppression (only {count} of {suppressed} suppressed errors were reported from {rule})."
+    ))
+    .with_help("Run oxlint with `--prune-suppressions` to remove unused suppressions.")
+    .with_label(LabeledSpan::new_with_span(None, (0, 0)))
+    .with_severity(severity)
+}
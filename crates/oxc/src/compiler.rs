@@ -1,4 +1,10 @@
-use std::{mem, ops::ControlFlow, path::Path};
+use std::{
+    fs, mem,
+    ops::ControlFlow,
+    path::{Component, Path},
+};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
@@ -9,6 +15,7 @@ use oxc_mangler::{MangleOptions, Mangler, ManglerReturn};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
@@ -58,6 +65,31 @@ pub trait CompilerInterface {
         false
     }
 
+    /// Compose the generated sourcemap with [Self::input_source_map].
+    ///
+    /// Disabled by default, because the input source text decides which file is read.
+    fn enable_input_source_map(&self) -> bool {
+        false
+    }
+
+    /// Source map of the input source text, composed with the generated sourcemap
+    /// if [Self::enable_input_source_map] is `true`.
+    ///
+    /// By default, it is read from the `//# sourceMappingURL` comment of the input,
+    /// which is either a base64 data URL or a relative path to a file in the directory
+    /// of `source_path` or below it. Absolute paths and paths containing `..` are ignored.
+    /// Return `None` to ignore the comment.
+    fn input_source_map(&self, program: &Program<'_>, source_path: &Path) -> Option<SourceMap> {
+        let url = source_mapping_url(program)?;
+        let json = if let Some(data) = url.strip_prefix("data:") {
+            let (_, data) = data.split_once(";base64,")?;
+            String::from_utf8(BASE64_STANDARD.decode(data).ok()?).ok()?
+        } else {
+            fs::read_to_string(source_path.parent()?.join(relative_path(url)?)).ok()?
+        };
+        SourceMap::from_json_string(&json).ok()
+    }
+
     fn parse_options(&self) -> ParseOptions {
         ParseOptions::default()
     }
@@ -283,14 +315,38 @@ pub trait CompilerInterface {
         let mut options = options;
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
+            if options.input_source_map.is_none() && self.enable_input_source_map() {
+                options.input_source_map = self.input_source_map(program, source_path);
+            }
         }
-        let (scoping, class_private_mappings) = mangler_return
-            .map(|m| (Some(m.scoping), Some(m.class_private_mappings)))
+        let (scoping, class_private_mappings, property_mappings) = mangler_return
+            .map(|m| (Some(m.scoping), Some(m.class_private_mappings), m.property_mappings))
             .unwrap_or_default();
         Codegen::new()
             .with_options(options)
             .with_scoping(scoping)
             .with_private_member_mappings(class_private_mappings)
+            .with_property_mappings(property_mappings)
             .build(program)
     }
 }
+
+/// The URL of the last `//# sourceMappingURL=` comment.
+fn source_mapping_url<'a>(program: &Program<'a>) -> Option<&'a str> {
+    program.comments.iter().rev().filter(|comment| comment.is_line()).find_map(|comment| {
+        let text = comment.content_span().source_text(program.source_text);
+        let url = text
+            .strip_prefix("# sourceMappingURL=")
+            .or_else(|| text.strip_prefix("@ sourceMappingURL="))?
+            .trim();
+        (!url.is_empty()).then_some(url)
+    })
+}
+
+/// `url` as a path, if it cannot point outside of the directory it is relative to.
+fn relative_path(url: &str) -> Option<&Path> {
+    let path = Path::new(url);
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        .then_some(path)
+}
@@ -164,6 +164,15 @@ pub enum Expression<'a> {
     /// See [`V8IntrinsicExpression`] for AST node details.
     V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
 
+    /// See [`DoExpression`] for AST node details.
+    DoExpression(Box<'a, DoExpression<'a>>) = 40,
+    /// See [`PipelineExpression`] for AST node details.
+    PipelineExpression(Box<'a, PipelineExpression<'a>>) = 41,
+    /// See [`ThrowExpression`] for AST node details.
+    ThrowExpression(Box<'a, ThrowExpression<'a>>) = 42,
+    /// See [`TopicReference`] for AST node details.
+    TopicReference(Box<'a, TopicReference>) = 43,
+
     // `MemberExpression` variants added here by `inherit_variants!` macro
     @inherit MemberExpression
 }
@@ -217,6 +226,10 @@ macro_rules! match_expression {
             | $ty::StaticMemberExpression(_)
             | $ty::PrivateFieldExpression(_)
             | $ty::V8IntrinsicExpression(_)
+            | $ty::DoExpression(_)
+            | $ty::PipelineExpression(_)
+            | $ty::ThrowExpression(_)
+            | $ty::TopicReference(_)
     };
 }
 pub use match_expression;
@@ -2818,3 +2831,61 @@ pub struct V8IntrinsicExpression<'a> {
     pub name: IdentifierName<'a>,
     pub arguments: Vec<'a, Argument<'a>>,
 }
+
+/// `do { ... }` in `let x = do { if (a) { 1 } else { 2 } };`
+///
+/// Represents a [do expression], which evaluates to the completion value of its block.
+/// Only parsed when `ParseOptions::allow_do_expressions` is enabled in `oxc_parser`.
+///
+/// [do expression]: https://github.com/tc39/proposal-do-expressions
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct DoExpression<'a> {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+    pub body: Box<'a, BlockStatement<'a>>,
+}
+
+/// `x |> f(%)`
+///
+/// Represents a Hack-style [pipe expression]. `%` in `body` refers to the value of `left`.
+/// Only parsed when `ParseOptions::allow_pipeline_operator` is enabled in `oxc_parser`.
+///
+/// [pipe expression]: https://github.com/tc39/proposal-pipeline-operator
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct PipelineExpression<'a> {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+    pub left: Expression<'a>,
+    pub body: Expression<'a>,
+}
+
+/// `%` in `x |> f(%)`
+///
+/// Represents the topic reference of a Hack-style pipe body, which refers to the value
+/// of the left-hand side of the innermost [`PipelineExpression`].
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct TopicReference {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+}
+
+/// `throw new Error()` in `const f = () => throw new Error();`
+///
+/// Represents a [throw expression].
+/// Only parsed when `ParseOptions::allow_throw_expressions` is enabled in `oxc_parser`.
+///
+/// [throw expression]: https://github.com/tc39/proposal-throw-expressions
+#[ast(visit)]
+#[derive(Debug)]
+#[generate_derive(CloneIn, Dummy, TakeIn, GetSpan, GetSpanMut, ContentEq, ESTree, UnstableAddress)]
+pub struct ThrowExpression<'a> {
+    pub node_id: Cell<NodeId>,
+    pub span: Span,
+    pub argument: Expression<'a>,
+}
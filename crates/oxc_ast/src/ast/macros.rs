@@ -174,6 +174,15 @@ macro_rules! inherit_variants {
                 /// Inherited from [`Expression`]
                 V8IntrinsicExpression(Box<'a, V8IntrinsicExpression<'a>>) = 39,
 
+                /// Inherited from [`Expression`]
+                DoExpression(Box<'a, DoExpression<'a>>) = 40,
+                /// Inherited from [`Expression`]
+                PipelineExpression(Box<'a, PipelineExpression<'a>>) = 41,
+                /// Inherited from [`Expression`]
+                ThrowExpression(Box<'a, ThrowExpression<'a>>) = 42,
+                /// Inherited from [`Expression`]
+                TopicReference(Box<'a, TopicReference>) = 43,
+
                 // Inherited from `MemberExpression`
                 @inherit MemberExpression
 
@@ -231,6 +240,10 @@ macro_rules! inherit_variants {
                 TSNonNullExpression,
                 TSInstantiationExpression,
                 V8IntrinsicExpression,
+                DoExpression,
+                PipelineExpression,
+                ThrowExpression,
+                TopicReference,
                 ComputedMemberExpression,
                 StaticMemberExpression,
                 PrivateFieldExpression,
@@ -284,6 +284,10 @@ impl<'a> AstKind<'a> {
             Expression::TSNonNullExpression(e) => Self::TSNonNullExpression(e),
             Expression::TSInstantiationExpression(e) => Self::TSInstantiationExpression(e),
             Expression::V8IntrinsicExpression(e) => Self::V8IntrinsicExpression(e),
+            Expression::DoExpression(e) => Self::DoExpression(e),
+            Expression::PipelineExpression(e) => Self::PipelineExpression(e),
+            Expression::ThrowExpression(e) => Self::ThrowExpression(e),
+            Expression::TopicReference(e) => Self::TopicReference(e),
         }
     }
 
@@ -595,6 +599,10 @@ impl AstKind<'_> {
             Self::TSInterfaceBody(_) => "TSInterfaceBody".into(),
             Self::TSIndexSignature(_) => "TSIndexSignature".into(),
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression".into(),
+            Self::DoExpression(_) => "DoExpression".into(),
+            Self::PipelineExpression(_) => "PipelineExpression".into(),
+            Self::ThrowExpression(_) => "ThrowExpression".into(),
+            Self::TopicReference(_) => "TopicReference".into(),
 
             Self::JSDocNullableType(_) => "JSDocNullableType".into(),
             Self::JSDocNonNullableType(_) => "JSDocNonNullableType".into(),
@@ -954,6 +954,34 @@ const _: () = {
     assert!(offset_of!(V8IntrinsicExpression, name) == 16);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 48);
 
+    // Padding: 4 bytes
+    assert!(size_of::<DoExpression>() == 24);
+    assert!(align_of::<DoExpression>() == 8);
+    assert!(offset_of!(DoExpression, span) == 0);
+    assert!(offset_of!(DoExpression, node_id) == 8);
+    assert!(offset_of!(DoExpression, body) == 16);
+
+    // Padding: 4 bytes
+    assert!(size_of::<PipelineExpression>() == 48);
+    assert!(align_of::<PipelineExpression>() == 8);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, node_id) == 8);
+    assert!(offset_of!(PipelineExpression, left) == 16);
+    assert!(offset_of!(PipelineExpression, body) == 32);
+
+    // Padding: 4 bytes
+    assert!(size_of::<TopicReference>() == 16);
+    assert!(align_of::<TopicReference>() == 8);
+    assert!(offset_of!(TopicReference, span) == 0);
+    assert!(offset_of!(TopicReference, node_id) == 8);
+
+    // Padding: 4 bytes
+    assert!(size_of::<ThrowExpression>() == 32);
+    assert!(align_of::<ThrowExpression>() == 8);
+    assert!(offset_of!(ThrowExpression, span) == 0);
+    assert!(offset_of!(ThrowExpression, node_id) == 8);
+    assert!(offset_of!(ThrowExpression, argument) == 16);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 8);
@@ -2765,6 +2793,34 @@ const _: () = if cfg!(target_family = "wasm") || align_of::<u64>() == 8 {
     assert!(offset_of!(V8IntrinsicExpression, name) == 12);
     assert!(offset_of!(V8IntrinsicExpression, arguments) == 36);
 
+    // Padding: 0 bytes
+    assert!(size_of::<DoExpression>() == 16);
+    assert!(align_of::<DoExpression>() == 4);
+    assert!(offset_of!(DoExpression, span) == 0);
+    assert!(offset_of!(DoExpression, node_id) == 8);
+    assert!(offset_of!(DoExpression, body) == 12);
+
+    // Padding: 0 bytes
+    assert!(size_of::<PipelineExpression>() == 28);
+    assert!(align_of::<PipelineExpression>() == 4);
+    assert!(offset_of!(PipelineExpression, span) == 0);
+    assert!(offset_of!(PipelineExpression, node_id) == 8);
+    assert!(offset_of!(PipelineExpression, left) == 12);
+    assert!(offset_of!(PipelineExpression, body) == 20);
+
+    // Padding: 0 bytes
+    assert!(size_of::<TopicReference>() == 12);
+    assert!(align_of::<TopicReference>() == 4);
+    assert!(offset_of!(TopicReference, span) == 0);
+    assert!(offset_of!(TopicReference, node_id) == 8);
+
+    // Padding: 0 bytes
+    assert!(size_of::<ThrowExpression>() == 20);
+    assert!(align_of::<ThrowExpression>() == 4);
+    assert!(offset_of!(ThrowExpression, span) == 0);
+    assert!(offset_of!(ThrowExpression, node_id) == 8);
+    assert!(offset_of!(ThrowExpression, argument) == 12);
+
     // Padding: 3 bytes
     assert!(size_of::<BooleanLiteral>() == 16);
     assert!(align_of::<BooleanLiteral>() == 4);
@@ -10,7 +10,7 @@ use oxc_syntax::node::NodeId;
 use crate::ast::*;
 
 /// The largest integer value that can be mapped to an `AstType`/`AstKind` enum variant.
-pub const AST_TYPE_MAX: u8 = 187;
+pub const AST_TYPE_MAX: u8 = 191;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -109,100 +109,104 @@ pub enum AstType {
     ExportAllDeclaration = 91,
     ExportSpecifier = 92,
     V8IntrinsicExpression = 93,
-    BooleanLiteral = 94,
-    NullLiteral = 95,
-    NumericLiteral = 96,
-    StringLiteral = 97,
-    BigIntLiteral = 98,
-    RegExpLiteral = 99,
-    JSXElement = 100,
-    JSXOpeningElement = 101,
-    JSXClosingElement = 102,
-    JSXFragment = 103,
-    JSXOpeningFragment = 104,
-    JSXClosingFragment = 105,
-    JSXNamespacedName = 106,
-    JSXMemberExpression = 107,
-    JSXExpressionContainer = 108,
-    JSXEmptyExpression = 109,
-    JSXAttribute = 110,
-    JSXSpreadAttribute = 111,
-    JSXIdentifier = 112,
-    JSXSpreadChild = 113,
-    JSXText = 114,
-    TSThisParameter = 115,
-    TSEnumDeclaration = 116,
-    TSEnumBody = 117,
-    TSEnumMember = 118,
-    TSTypeAnnotation = 119,
-    TSLiteralType = 120,
-    TSConditionalType = 121,
-    TSUnionType = 122,
-    TSIntersectionType = 123,
-    TSParenthesizedType = 124,
-    TSTypeOperator = 125,
-    TSArrayType = 126,
-    TSIndexedAccessType = 127,
-    TSTupleType = 128,
-    TSNamedTupleMember = 129,
-    TSOptionalType = 130,
-    TSRestType = 131,
-    TSAnyKeyword = 132,
-    TSStringKeyword = 133,
-    TSBooleanKeyword = 134,
-    TSNumberKeyword = 135,
-    TSNeverKeyword = 136,
-    TSIntrinsicKeyword = 137,
-    TSUnknownKeyword = 138,
-    TSNullKeyword = 139,
-    TSUndefinedKeyword = 140,
-    TSVoidKeyword = 141,
-    TSSymbolKeyword = 142,
-    TSThisType = 143,
-    TSObjectKeyword = 144,
-    TSBigIntKeyword = 145,
-    TSTypeReference = 146,
-    TSQualifiedName = 147,
-    TSTypeParameterInstantiation = 148,
-    TSTypeParameter = 149,
-    TSTypeParameterDeclaration = 150,
-    TSTypeAliasDeclaration = 151,
-    TSClassImplements = 152,
-    TSInterfaceDeclaration = 153,
-    TSInterfaceBody = 154,
-    TSPropertySignature = 155,
-    TSIndexSignature = 156,
-    TSCallSignatureDeclaration = 157,
-    TSMethodSignature = 158,
-    TSConstructSignatureDeclaration = 159,
-    TSIndexSignatureName = 160,
-    TSInterfaceHeritage = 161,
-    TSTypePredicate = 162,
-    TSModuleDeclaration = 163,
-    TSGlobalDeclaration = 164,
-    TSModuleBlock = 165,
-    TSTypeLiteral = 166,
-    TSInferType = 167,
-    TSTypeQuery = 168,
-    TSImportType = 169,
-    TSImportTypeQualifiedName = 170,
-    TSFunctionType = 171,
-    TSConstructorType = 172,
-    TSMappedType = 173,
-    TSTemplateLiteralType = 174,
-    TSAsExpression = 175,
-    TSSatisfiesExpression = 176,
-    TSTypeAssertion = 177,
-    TSImportEqualsDeclaration = 178,
-    TSExternalModuleReference = 179,
-    TSNonNullExpression = 180,
-    Decorator = 181,
-    TSExportAssignment = 182,
-    TSNamespaceExportDeclaration = 183,
-    TSInstantiationExpression = 184,
-    JSDocNullableType = 185,
-    JSDocNonNullableType = 186,
-    JSDocUnknownType = 187,
+    DoExpression = 94,
+    PipelineExpression = 95,
+    TopicReference = 96,
+    ThrowExpression = 97,
+    BooleanLiteral = 98,
+    NullLiteral = 99,
+    NumericLiteral = 100,
+    StringLiteral = 101,
+    BigIntLiteral = 102,
+    RegExpLiteral = 103,
+    JSXElement = 104,
+    JSXOpeningElement = 105,
+    JSXClosingElement = 106,
+    JSXFragment = 107,
+    JSXOpeningFragment = 108,
+    JSXClosingFragment = 109,
+    JSXNamespacedName = 110,
+    JSXMemberExpression = 111,
+    JSXExpressionContainer = 112,
+    JSXEmptyExpression = 113,
+    JSXAttribute = 114,
+    JSXSpreadAttribute = 115,
+    JSXIdentifier = 116,
+    JSXSpreadChild = 117,
+    JSXText = 118,
+    TSThisParameter = 119,
+    TSEnumDeclaration = 120,
+    TSEnumBody = 121,
+    TSEnumMember = 122,
+    TSTypeAnnotation = 123,
+    TSLiteralType = 124,
+    TSConditionalType = 125,
+    TSUnionType = 126,
+    TSIntersectionType = 127,
+    TSParenthesizedType = 128,
+    TSTypeOperator = 129,
+    TSArrayType = 130,
+    TSIndexedAccessType = 131,
+    TSTupleType = 132,
+    TSNamedTupleMember = 133,
+    TSOptionalType = 134,
+    TSRestType = 135,
+    TSAnyKeyword = 136,
+    TSStringKeyword = 137,
+    TSBooleanKeyword = 138,
+    TSNumberKeyword = 139,
+    TSNeverKeyword = 140,
+    TSIntrinsicKeyword = 141,
+    TSUnknownKeyword = 142,
+    TSNullKeyword = 143,
+    TSUndefinedKeyword = 144,
+    TSVoidKeyword = 145,
+    TSSymbolKeyword = 146,
+    TSThisType = 147,
+    TSObjectKeyword = 148,
+    TSBigIntKeyword = 149,
+    TSTypeReference = 150,
+    TSQualifiedName = 151,
+    TSTypeParameterInstantiation = 152,
+    TSTypeParameter = 153,
+    TSTypeParameterDeclaration = 154,
+    TSTypeAliasDeclaration = 155,
+    TSClassImplements = 156,
+    TSInterfaceDeclaration = 157,
+    TSInterfaceBody = 158,
+    TSPropertySignature = 159,
+    TSIndexSignature = 160,
+    TSCallSignatureDeclaration = 161,
+    TSMethodSignature = 162,
+    TSConstructSignatureDeclaration = 163,
+    TSIndexSignatureName = 164,
+    TSInterfaceHeritage = 165,
+    TSTypePredicate = 166,
+    TSModuleDeclaration = 167,
+    TSGlobalDeclaration = 168,
+    TSModuleBlock = 169,
+    TSTypeLiteral = 170,
+    TSInferType = 171,
+    TSTypeQuery = 172,
+    TSImportType = 173,
+    TSImportTypeQualifiedName = 174,
+    TSFunctionType = 175,
+    TSConstructorType = 176,
+    TSMappedType = 177,
+    TSTemplateLiteralType = 178,
+    TSAsExpression = 179,
+    TSSatisfiesExpression = 180,
+    TSTypeAssertion = 181,
+    TSImportEqualsDeclaration = 182,
+    TSExternalModuleReference = 183,
+    TSNonNullExpression = 184,
+    Decorator = 185,
+    TSExportAssignment = 186,
+    TSNamespaceExportDeclaration = 187,
+    TSInstantiationExpression = 188,
+    JSDocNullableType = 189,
+    JSDocNonNullableType = 190,
+    JSDocUnknownType = 191,
 }
 
 /// Untyped AST Node Kind
@@ -312,6 +316,10 @@ pub enum AstKind<'a> {
     ExportAllDeclaration(&'a ExportAllDeclaration<'a>) = AstType::ExportAllDeclaration as u8,
     ExportSpecifier(&'a ExportSpecifier<'a>) = AstType::ExportSpecifier as u8,
     V8IntrinsicExpression(&'a V8IntrinsicExpression<'a>) = AstType::V8IntrinsicExpression as u8,
+    DoExpression(&'a DoExpression<'a>) = AstType::DoExpression as u8,
+    PipelineExpression(&'a PipelineExpression<'a>) = AstType::PipelineExpression as u8,
+    TopicReference(&'a TopicReference) = AstType::TopicReference as u8,
+    ThrowExpression(&'a ThrowExpression<'a>) = AstType::ThrowExpression as u8,
     BooleanLiteral(&'a BooleanLiteral) = AstType::BooleanLiteral as u8,
     NullLiteral(&'a NullLiteral) = AstType::NullLiteral as u8,
     NumericLiteral(&'a NumericLiteral<'a>) = AstType::NumericLiteral as u8,
@@ -527,6 +535,10 @@ impl AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.node_id(),
             Self::ExportSpecifier(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
             Self::BooleanLiteral(it) => it.node_id(),
             Self::NullLiteral(it) => it.node_id(),
             Self::NumericLiteral(it) => it.node_id(),
@@ -723,6 +735,10 @@ impl AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.set_node_id(node_id),
             Self::ExportSpecifier(it) => it.set_node_id(node_id),
             Self::V8IntrinsicExpression(it) => it.set_node_id(node_id),
+            Self::DoExpression(it) => it.set_node_id(node_id),
+            Self::PipelineExpression(it) => it.set_node_id(node_id),
+            Self::TopicReference(it) => it.set_node_id(node_id),
+            Self::ThrowExpression(it) => it.set_node_id(node_id),
             Self::BooleanLiteral(it) => it.set_node_id(node_id),
             Self::NullLiteral(it) => it.set_node_id(node_id),
             Self::NumericLiteral(it) => it.set_node_id(node_id),
@@ -921,6 +937,10 @@ impl GetSpan for AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.span(),
             Self::ExportSpecifier(it) => it.span(),
             Self::V8IntrinsicExpression(it) => it.span(),
+            Self::DoExpression(it) => it.span(),
+            Self::PipelineExpression(it) => it.span(),
+            Self::TopicReference(it) => it.span(),
+            Self::ThrowExpression(it) => it.span(),
             Self::BooleanLiteral(it) => it.span(),
             Self::NullLiteral(it) => it.span(),
             Self::NumericLiteral(it) => it.span(),
@@ -1120,6 +1140,10 @@ impl GetAddress for AstKind<'_> {
             Self::ExportAllDeclaration(it) => it.unstable_address(),
             Self::ExportSpecifier(it) => it.unstable_address(),
             Self::V8IntrinsicExpression(it) => it.unstable_address(),
+            Self::DoExpression(it) => it.unstable_address(),
+            Self::PipelineExpression(it) => it.unstable_address(),
+            Self::TopicReference(it) => it.unstable_address(),
+            Self::ThrowExpression(it) => it.unstable_address(),
             Self::BooleanLiteral(it) => it.unstable_address(),
             Self::NullLiteral(it) => it.unstable_address(),
             Self::NumericLiteral(it) => it.unstable_address(),
@@ -1693,6 +1717,26 @@ impl<'a> AstKind<'a> {
         if let Self::V8IntrinsicExpression(v) = self { Some(v) } else { None }
     }
 
+    #[inline]
+    pub fn as_do_expression(self) -> Option<&'a DoExpression<'a>> {
+        if let Self::DoExpression(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_pipeline_expression(self) -> Option<&'a PipelineExpression<'a>> {
+        if let Self::PipelineExpression(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_topic_reference(self) -> Option<&'a TopicReference> {
+        if let Self::TopicReference(v) = self { Some(v) } else { None }
+    }
+
+    #[inline]
+    pub fn as_throw_expression(self) -> Option<&'a ThrowExpression<'a>> {
+        if let Self::ThrowExpression(v) = self { Some(v) } else { None }
+    }
+
     #[inline]
     pub fn as_boolean_literal(self) -> Option<&'a BooleanLiteral> {
         if let Self::BooleanLiteral(v) = self { Some(v) } else { None }
@@ -150,6 +150,16 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Expression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => Expression::DoExpression(CloneIn::clone_in(it, allocator)),
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                Expression::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 Expression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -284,6 +294,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for Expression<'_> {
             Self::V8IntrinsicExpression(it) => Expression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => {
+                Expression::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Expression::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                Expression::ThrowExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Expression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Expression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -546,6 +568,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ArrayExpressionElement::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => {
+                ArrayExpressionElement::DoExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ArrayExpressionElement::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                ArrayExpressionElement::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ArrayExpressionElement::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ArrayExpressionElement::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -688,6 +722,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ArrayExpressionElement<'_> {
             Self::V8IntrinsicExpression(it) => ArrayExpressionElement::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => ArrayExpressionElement::DoExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ArrayExpressionElement::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::ThrowExpression(it) => ArrayExpressionElement::ThrowExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ArrayExpressionElement::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => ArrayExpressionElement::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -903,6 +949,16 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => {
                 PropertyKey::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => PropertyKey::DoExpression(CloneIn::clone_in(it, allocator)),
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                PropertyKey::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 PropertyKey::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1043,6 +1099,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for PropertyKey<'_> {
             Self::V8IntrinsicExpression(it) => PropertyKey::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => {
+                PropertyKey::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                PropertyKey::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                PropertyKey::ThrowExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                PropertyKey::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => PropertyKey::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -1454,6 +1522,14 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => Argument::DoExpression(CloneIn::clone_in(it, allocator)),
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                Argument::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => Argument::TopicReference(CloneIn::clone_in(it, allocator)),
             Self::ComputedMemberExpression(it) => {
                 Argument::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -1589,6 +1665,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for Argument<'_> {
             Self::V8IntrinsicExpression(it) => {
                 Argument::V8IntrinsicExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
             }
+            Self::DoExpression(it) => {
+                Argument::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                Argument::PipelineExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                Argument::ThrowExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                Argument::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => Argument::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -2983,6 +3071,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => {
                 ForStatementInit::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => {
+                ForStatementInit::DoExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ForStatementInit::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                ForStatementInit::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ForStatementInit::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -3120,6 +3220,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ForStatementInit<'_> {
             Self::V8IntrinsicExpression(it) => ForStatementInit::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => {
+                ForStatementInit::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => ForStatementInit::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::ThrowExpression(it) => ForStatementInit::ThrowExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => {
+                ForStatementInit::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => ForStatementInit::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -4792,6 +4904,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in(it, allocator),
             ),
+            Self::DoExpression(it) => {
+                ExportDefaultDeclarationKind::DoExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::PipelineExpression(it) => {
+                ExportDefaultDeclarationKind::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                ExportDefaultDeclarationKind::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                ExportDefaultDeclarationKind::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(CloneIn::clone_in(
                     it, allocator,
@@ -4951,6 +5075,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for ExportDefaultDeclarationKind<'_> {
             Self::V8IntrinsicExpression(it) => ExportDefaultDeclarationKind::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => ExportDefaultDeclarationKind::DoExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::PipelineExpression(it) => ExportDefaultDeclarationKind::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::ThrowExpression(it) => ExportDefaultDeclarationKind::ThrowExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::TopicReference(it) => ExportDefaultDeclarationKind::TopicReference(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
             Self::ComputedMemberExpression(it) => {
                 ExportDefaultDeclarationKind::ComputedMemberExpression(
                     CloneIn::clone_in_with_semantic_ids(it, allocator),
@@ -5024,6 +5160,86 @@ impl<'new_alloc> CloneIn<'new_alloc> for V8IntrinsicExpression<'_> {
     }
 }
 
+impl<'new_alloc> CloneIn<'new_alloc> for DoExpression<'_> {
+    type Cloned = DoExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        DoExpression {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        DoExpression {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for PipelineExpression<'_> {
+    type Cloned = PipelineExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            left: CloneIn::clone_in(&self.left, allocator),
+            body: CloneIn::clone_in(&self.body, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        PipelineExpression {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            left: CloneIn::clone_in_with_semantic_ids(&self.left, allocator),
+            body: CloneIn::clone_in_with_semantic_ids(&self.body, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for TopicReference {
+    type Cloned = TopicReference;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        TopicReference {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for ThrowExpression<'_> {
+    type Cloned = ThrowExpression<'new_alloc>;
+
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ThrowExpression {
+            node_id: Default::default(),
+            span: CloneIn::clone_in(&self.span, allocator),
+            argument: CloneIn::clone_in(&self.argument, allocator),
+        }
+    }
+
+    fn clone_in_with_semantic_ids(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        ThrowExpression {
+            node_id: CloneIn::clone_in_with_semantic_ids(&self.node_id, allocator),
+            span: CloneIn::clone_in_with_semantic_ids(&self.span, allocator),
+            argument: CloneIn::clone_in_with_semantic_ids(&self.argument, allocator),
+        }
+    }
+}
+
 impl<'new_alloc> CloneIn<'new_alloc> for BooleanLiteral {
     type Cloned = BooleanLiteral;
 
@@ -5577,6 +5793,16 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => {
                 JSXExpression::V8IntrinsicExpression(CloneIn::clone_in(it, allocator))
             }
+            Self::DoExpression(it) => JSXExpression::DoExpression(CloneIn::clone_in(it, allocator)),
+            Self::PipelineExpression(it) => {
+                JSXExpression::PipelineExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::ThrowExpression(it) => {
+                JSXExpression::ThrowExpression(CloneIn::clone_in(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => {
                 JSXExpression::ComputedMemberExpression(CloneIn::clone_in(it, allocator))
             }
@@ -5714,6 +5940,18 @@ impl<'new_alloc> CloneIn<'new_alloc> for JSXExpression<'_> {
             Self::V8IntrinsicExpression(it) => JSXExpression::V8IntrinsicExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
+            Self::DoExpression(it) => {
+                JSXExpression::DoExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::PipelineExpression(it) => JSXExpression::PipelineExpression(
+                CloneIn::clone_in_with_semantic_ids(it, allocator),
+            ),
+            Self::ThrowExpression(it) => {
+                JSXExpression::ThrowExpression(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
+            Self::TopicReference(it) => {
+                JSXExpression::TopicReference(CloneIn::clone_in_with_semantic_ids(it, allocator))
+            }
             Self::ComputedMemberExpression(it) => JSXExpression::ComputedMemberExpression(
                 CloneIn::clone_in_with_semantic_ids(it, allocator),
             ),
@@ -67,6 +67,10 @@ impl ContentEq for Expression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -162,6 +166,10 @@ impl ContentEq for ArrayExpressionElement<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -254,6 +262,10 @@ impl ContentEq for PropertyKey<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -417,6 +429,10 @@ impl ContentEq for Argument<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -861,6 +877,10 @@ impl ContentEq for ForStatementInit<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1436,6 +1456,10 @@ impl ContentEq for ExportDefaultDeclarationKind<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
@@ -1464,6 +1488,31 @@ impl ContentEq for V8IntrinsicExpression<'_> {
     }
 }
 
+impl ContentEq for DoExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.body, &other.body)
+    }
+}
+
+impl ContentEq for PipelineExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.left, &other.left)
+            && ContentEq::content_eq(&self.body, &other.body)
+    }
+}
+
+impl ContentEq for TopicReference {
+    fn content_eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl ContentEq for ThrowExpression<'_> {
+    fn content_eq(&self, other: &Self) -> bool {
+        ContentEq::content_eq(&self.argument, &other.argument)
+    }
+}
+
 impl ContentEq for BooleanLiteral {
     fn content_eq(&self, other: &Self) -> bool {
         ContentEq::content_eq(&self.value, &other.value)
@@ -1648,6 +1697,10 @@ impl ContentEq for JSXExpression<'_> {
                 a.content_eq(b)
             }
             (Self::V8IntrinsicExpression(a), Self::V8IntrinsicExpression(b)) => a.content_eq(b),
+            (Self::DoExpression(a), Self::DoExpression(b)) => a.content_eq(b),
+            (Self::PipelineExpression(a), Self::PipelineExpression(b)) => a.content_eq(b),
+            (Self::ThrowExpression(a), Self::ThrowExpression(b)) => a.content_eq(b),
+            (Self::TopicReference(a), Self::TopicReference(b)) => a.content_eq(b),
             (Self::ComputedMemberExpression(a), Self::ComputedMemberExpression(b)) => {
                 a.content_eq(b)
             }
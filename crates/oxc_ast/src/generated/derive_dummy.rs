@@ -1724,6 +1724,55 @@ impl<'a> Dummy<'a> for V8IntrinsicExpression<'a> {
     }
 }
 
+impl<'a> Dummy<'a> for DoExpression<'a> {
+    /// Create a dummy [`DoExpression`].
+    ///
+    /// Has cost of making 1 allocation (40 bytes).
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self {
+            node_id: Dummy::dummy(allocator),
+            span: Dummy::dummy(allocator),
+            body: Dummy::dummy(allocator),
+        }
+    }
+}
+
+impl<'a> Dummy<'a> for PipelineExpression<'a> {
+    /// Create a dummy [`PipelineExpression`].
+    ///
+    /// Has cost of making 2 allocations (32 bytes).
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self {
+            node_id: Dummy::dummy(allocator),
+            span: Dummy::dummy(allocator),
+            left: Dummy::dummy(allocator),
+            body: Dummy::dummy(allocator),
+        }
+    }
+}
+
+impl<'a> Dummy<'a> for TopicReference {
+    /// Create a dummy [`TopicReference`].
+    ///
+    /// Does not allocate any data into arena.
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self { node_id: Dummy::dummy(allocator), span: Dummy::dummy(allocator) }
+    }
+}
+
+impl<'a> Dummy<'a> for ThrowExpression<'a> {
+    /// Create a dummy [`ThrowExpression`].
+    ///
+    /// Has cost of making 1 allocation (16 bytes).
+    fn dummy(allocator: &'a Allocator) -> Self {
+        Self {
+            node_id: Dummy::dummy(allocator),
+            span: Dummy::dummy(allocator),
+            argument: Dummy::dummy(allocator),
+        }
+    }
+}
+
 impl<'a> Dummy<'a> for BooleanLiteral {
     /// Create a dummy [`BooleanLiteral`].
     ///
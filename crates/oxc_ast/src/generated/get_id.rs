@@ -1749,6 +1749,70 @@ impl V8IntrinsicExpression<'_> {
     }
 }
 
+impl DoExpression<'_> {
+    /// Get [`NodeId`] of [`DoExpression`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`DoExpression`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl PipelineExpression<'_> {
+    /// Get [`NodeId`] of [`PipelineExpression`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`PipelineExpression`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl TopicReference {
+    /// Get [`NodeId`] of [`TopicReference`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`TopicReference`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
+impl ThrowExpression<'_> {
+    /// Get [`NodeId`] of [`ThrowExpression`].
+    ///
+    /// Only use this method on a post-semantic AST where [`NodeId`]s are always defined.
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.node_id.get()
+    }
+
+    /// Set [`NodeId`] of [`ThrowExpression`].
+    #[inline]
+    pub fn set_node_id(&self, node_id: NodeId) {
+        self.node_id.set(node_id);
+    }
+}
+
 impl BooleanLiteral {
     /// Get [`NodeId`] of [`BooleanLiteral`].
     ///
@@ -3503,6 +3567,10 @@ impl Expression<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3558,6 +3626,10 @@ impl ArrayExpressionElement<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3625,6 +3697,10 @@ impl PropertyKey<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3692,6 +3768,10 @@ impl Argument<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -3906,6 +3986,10 @@ impl ForStatementInit<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -4053,6 +4137,10 @@ impl ExportDefaultDeclarationKind<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
@@ -4148,6 +4236,10 @@ impl JSXExpression<'_> {
             Self::TSNonNullExpression(it) => it.node_id(),
             Self::TSInstantiationExpression(it) => it.node_id(),
             Self::V8IntrinsicExpression(it) => it.node_id(),
+            Self::DoExpression(it) => it.node_id(),
+            Self::PipelineExpression(it) => it.node_id(),
+            Self::ThrowExpression(it) => it.node_id(),
+            Self::TopicReference(it) => it.node_id(),
             Self::ComputedMemberExpression(it) => it.node_id(),
             Self::StaticMemberExpression(it) => it.node_id(),
             Self::PrivateFieldExpression(it) => it.node_id(),
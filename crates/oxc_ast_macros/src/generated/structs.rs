@@ -6,316 +6,321 @@ use crate::ast::StructDetails;
 /// Details of how `#[ast]` macro should modify structs.
 #[expect(clippy::unreadable_literal)]
 pub static STRUCTS: phf::Map<&'static str, StructDetails> = ::phf::Map {
-    key: 16287231350648472473,
+    key: 16263683158343804936,
     disps: &[
-        (0, 0),
-        (0, 224),
-        (0, 87),
-        (0, 1),
-        (0, 1),
-        (6, 101),
-        (2, 24),
-        (2, 21),
-        (16, 232),
+        (0, 9),
+        (0, 27),
+        (0, 125),
+        (1, 116),
+        (1, 12),
+        (1, 29),
+        (0, 59),
+        (0, 54),
+        (12, 224),
+        (0, 8),
+        (1, 230),
+        (0, 52),
         (0, 69),
-        (6, 106),
-        (0, 5),
-        (0, 1),
-        (0, 145),
-        (0, 14),
-        (0, 2),
-        (0, 40),
-        (1, 20),
-        (0, 153),
-        (0, 170),
-        (2, 8),
-        (0, 0),
-        (1, 32),
-        (1, 173),
-        (0, 10),
-        (1, 34),
-        (0, 46),
-        (0, 7),
-        (1, 122),
-        (3, 14),
-        (1, 158),
+        (4, 218),
+        (0, 204),
+        (0, 53),
+        (0, 66),
+        (1, 67),
         (0, 9),
-        (5, 42),
-        (0, 1),
-        (1, 156),
-        (8, 184),
-        (0, 0),
-        (78, 5),
-        (0, 0),
-        (2, 132),
-        (0, 18),
-        (0, 26),
-        (9, 108),
+        (8, 211),
+        (3, 4),
+        (1, 0),
+        (0, 83),
+        (1, 68),
+        (1, 163),
         (0, 75),
-        (1, 150),
-        (60, 152),
+        (1, 0),
+        (0, 8),
+        (0, 7),
+        (13, 117),
+        (0, 37),
+        (2, 13),
+        (0, 18),
+        (1, 15),
         (0, 3),
+        (1, 16),
+        (2, 221),
+        (1, 98),
+        (0, 61),
+        (0, 87),
+        (0, 23),
+        (0, 0),
+        (1, 96),
+        (11, 118),
+        (0, 0),
+        (24, 231),
+        (10, 223),
+        (1, 142),
     ],
     entries: &[
-        ("IndexedReference", StructDetails { field_order: None }),
-        ("TSCallSignatureDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("JSXExpressionContainer", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("IdentifierReference", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("SourceType", StructDetails { field_order: None }),
-        ("BindingRestElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSInterfaceBody", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ExportAllDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
-        ("SwitchStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSTypeAliasDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("TSBigIntKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("StaticBlock", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("JSDocUnknownType", StructDetails { field_order: Some(&[1, 0]) }),
+        ("NonMaxU32", StructDetails { field_order: None }),
+        ("ExportNamedDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2, 6]) }),
+        ("RawTransferMetadata2", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
+        ("ArrayPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ThrowExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("StaticExport", StructDetails { field_order: None }),
+        ("CapturingGroup", StructDetails { field_order: None }),
+        ("JSXAttribute", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("NamedReference", StructDetails { field_order: None }),
+        ("TSVoidKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("ErrorLabel", StructDetails { field_order: Some(&[1, 0]) }),
+        ("FunctionBody", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("FormalParameters", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("JSXNamespacedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSSatisfiesExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("NullLiteral", StructDetails { field_order: Some(&[1, 0]) }),
+        ("SequenceExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
         (
             "PropertyDefinition",
             StructDetails {
                 field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11, 12, 13, 14]),
             },
         ),
-        ("ExportNamedDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2, 6]) }),
-        ("VariableDeclarator", StructDetails { field_order: Some(&[1, 0, 2, 4, 5, 6, 3]) }),
-        ("TemplateElementValue", StructDetails { field_order: None }),
-        ("TSEnumDeclaration", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
-        ("ExportDefaultDeclaration", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ClassString", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("Span", StructDetails { field_order: Some(&[1, 2, 0]) }),
-        ("TSIntersectionType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("AssignmentPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("StaticExport", StructDetails { field_order: None }),
-        ("ExpressionStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("CharacterClass", StructDetails { field_order: Some(&[0, 2, 3, 4, 1]) }),
-        ("TSNamedTupleMember", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("BoundaryAssertion", StructDetails { field_order: None }),
+        ("UnicodePropertyEscape", StructDetails { field_order: Some(&[0, 3, 4, 1, 2]) }),
         ("TSNamespaceExportDeclaration", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("JSXAttribute", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("CallExpression", StructDetails { field_order: Some(&[1, 0, 4, 5, 6, 2, 3]) }),
-        ("TSTypeAnnotation", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSParenthesizedType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("CommentNewlines", StructDetails { field_order: None }),
-        ("Elision", StructDetails { field_order: Some(&[1, 0]) }),
-        ("Quantifier", StructDetails { field_order: Some(&[0, 1, 2, 4, 3]) }),
-        ("AssignmentTargetRest", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("PrivateInExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("NonMaxU32", StructDetails { field_order: None }),
-        ("ImportSpecifier", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("ArrayPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("Character", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("FormalParameter", StructDetails { field_order: Some(&[1, 0, 6, 7, 8, 9, 2, 3, 4, 5]) }),
-        ("TSIndexSignatureName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ConditionalExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("JSXOpeningFragment", StructDetails { field_order: Some(&[1, 0]) }),
-        ("FormalParameterRest", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("SequenceExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("Disjunction", StructDetails { field_order: None }),
-        ("TSUndefinedKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("JSXNamespacedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSNonNullExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ObjectAssignmentTarget", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("JSDocUnknownType", StructDetails { field_order: Some(&[1, 0]) }),
-        ("Modifiers", StructDetails { field_order: None }),
-        ("TSSymbolKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("ErrorLabel", StructDetails { field_order: Some(&[1, 0]) }),
-        ("ImportExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("CatchClause", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSImportType", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5]) }),
-        ("TSTypeOperator", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("NamedReference", StructDetails { field_order: None }),
         ("JSXElement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("JSXMemberExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("NumericLiteral", StructDetails { field_order: Some(&[1, 0, 4, 3, 2]) }),
-        ("FunctionBody", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("Program", StructDetails { field_order: Some(&[1, 0, 8, 3, 4, 5, 6, 7, 2]) }),
+        ("StaticBlock", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("SwitchCase", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("SpreadElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSClassImplements", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSTupleType", StructDetails { field_order: Some(&[1, 0, 2]) }),
         ("TSStringKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("TSInterfaceDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 2]) }),
-        ("Error", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
-        ("JSXSpreadChild", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("DynamicImport", StructDetails { field_order: None }),
+        ("PrivateIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        (
+            "TSMethodSignature",
+            StructDetails { field_order: Some(&[1, 0, 3, 8, 9, 10, 4, 5, 6, 7, 2]) },
+        ),
+        ("TSObjectKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("PrivateFieldExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("JSXExpressionContainer", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ImportExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("UnaryExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
         ("TSGlobalDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
-        ("LabelIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ClassStringDisjunction", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("BindingIdentifier", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("Super", StructDetails { field_order: Some(&[1, 0]) }),
-        ("Decorator", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ClassBody", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("AssignmentTargetWithDefault", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("FormalParameters", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("ReturnStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("BlockStatement", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("TSSatisfiesExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSEnumDeclaration", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
+        ("DoExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSTypeOperator", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSTypeParameterDeclaration", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSBooleanKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSAsExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ReferenceId", StructDetails { field_order: None }),
+        ("TSAnyKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TemplateLiteral", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSTypePredicate", StructDetails { field_order: Some(&[1, 0, 3, 2, 4]) }),
+        ("TSImportType", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5]) }),
+        ("TSInterfaceHeritage", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("NumericLiteral", StructDetails { field_order: Some(&[1, 0, 4, 3, 2]) }),
+        ("Modifiers", StructDetails { field_order: None }),
+        ("TSArrayType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSIntersectionType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ThrowStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSInterfaceBody", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Disjunction", StructDetails { field_order: None }),
+        ("TSCallSignatureDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        ("StringLiteral", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("NameSpan", StructDetails { field_order: Some(&[1, 0]) }),
+        ("IdentifierName", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Hashbang", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("I32Dummy", StructDetails { field_order: None }),
+        ("Directive", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSInstantiationExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSRestType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXFragment", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
         ("LogicalExpression", StructDetails { field_order: Some(&[1, 0, 3, 2, 4]) }),
-        ("TSTupleType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("NodeId", StructDetails { field_order: None }),
-        ("ForInStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
-        ("TSLiteralType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("PrivateFieldExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSObjectKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("RegExpFlags", StructDetails { field_order: None }),
+        ("TSTypeQuery", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSTemplateLiteralType", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSUnknownKeyword", StructDetails { field_order: Some(&[1, 0]) }),
         ("TSExternalModuleReference", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXMemberExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("Character", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("Modifier", StructDetails { field_order: None }),
+        ("NewExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
+        ("LabeledStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ExportDefaultDeclaration", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSThisParameter", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ConditionalExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("Decorator", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Pattern", StructDetails { field_order: None }),
+        ("CharacterClassEscape", StructDetails { field_order: None }),
+        ("TSPropertySignature", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
+        ("StaticImport", StructDetails { field_order: None }),
+        ("ObjectPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("LabelIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ComputedMemberExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSIndexedAccessType", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("UpdateExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("Quantifier", StructDetails { field_order: Some(&[0, 1, 2, 4, 3]) }),
+        ("JSXIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("RawTransferData", StructDetails { field_order: None }),
+        ("CatchClause", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
         ("TSMappedType", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 8, 2]) }),
+        ("TSBigIntKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSIndexSignatureName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("IfStatement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("ObjectProperty", StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 3, 4, 5]) }),
+        ("DynamicImport", StructDetails { field_order: None }),
+        ("V8IntrinsicExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ObjectAssignmentTarget", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ImportNamespaceSpecifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Super", StructDetails { field_order: Some(&[1, 0]) }),
+        ("CharacterClass", StructDetails { field_order: Some(&[0, 2, 3, 4, 1]) }),
         ("TSTypeReference", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("AssignmentExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
         ("SymbolId", StructDetails { field_order: None }),
-        ("FixedSizeAllocatorMetadata", StructDetails { field_order: None }),
-        ("RawTransferData", StructDetails { field_order: None }),
-        ("CharacterClassRange", StructDetails { field_order: None }),
-        ("Hashbang", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("CharacterClassEscape", StructDetails { field_order: None }),
-        ("BindingProperty", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
-        ("ComputedMemberExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSEnumMember", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("UnicodePropertyEscape", StructDetails { field_order: Some(&[0, 3, 4, 1, 2]) }),
-        ("TaggedTemplateExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("LookAroundAssertion", StructDetails { field_order: Some(&[0, 2, 1]) }),
-        ("TSAnyKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("TSThisParameter", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSTypeParameterDeclaration", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("CapturingGroup", StructDetails { field_order: None }),
-        ("ThisExpression", StructDetails { field_order: Some(&[1, 0]) }),
-        ("JSXClosingElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("BreakStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("IdentifierName", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TryStatement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("ParenthesizedExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("BinaryExpression", StructDetails { field_order: Some(&[1, 0, 3, 2, 4]) }),
-        ("NewExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
-        ("TSVoidKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("JSXClosingFragment", StructDetails { field_order: Some(&[1, 0]) }),
-        ("Directive", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("Program", StructDetails { field_order: Some(&[1, 0, 8, 3, 4, 5, 6, 7, 2]) }),
-        ("TSPropertySignature", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
-        ("TSConditionalType", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("TSNeverKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("SpreadElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("Alternative", StructDetails { field_order: None }),
+        ("BigIntLiteral", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("IgnoreGroup", StructDetails { field_order: None }),
+        ("Elision", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TopicReference", StructDetails { field_order: Some(&[1, 0]) }),
         ("TSThisType", StructDetails { field_order: Some(&[1, 0]) }),
-        ("WhileStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ImportAttribute", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("RegExpLiteral", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        (
-            "MethodDefinition",
-            StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 8, 3, 4, 5, 9, 10, 11]) },
-        ),
-        ("JSXEmptyExpression", StructDetails { field_order: Some(&[1, 0]) }),
-        ("TSInstantiationExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("JSDocNullableType", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("TSQualifiedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("AssignmentTargetPropertyIdentifier", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("AwaitExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ExportEntry", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
-        ("MetaProperty", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSUnionType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ArrayExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ObjectProperty", StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 3, 4, 5]) }),
-        ("ForStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("DoWhileStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSTypeQuery", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ThrowStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("PrivateIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("DebuggerStatement", StructDetails { field_order: Some(&[1, 0]) }),
+        ("JSXText", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSExportAssignment", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("YieldExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ImportDeclaration", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 6, 3]) }),
+        ("Alternative", StructDetails { field_order: None }),
+        ("ExportSpecifier", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSInferType", StructDetails { field_order: Some(&[1, 0, 2]) }),
         (
             "Function",
             StructDetails {
                 field_order: Some(&[1, 0, 9, 3, 10, 11, 12, 4, 5, 6, 7, 8, 2, 13, 14]),
             },
         ),
-        ("TSImportTypeQualifiedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSExportAssignment", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSOptionalType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSTemplateLiteralType", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("AssignmentTargetRest", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSTypeAliasDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        ("TryStatement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
         (
-            "TSMethodSignature",
-            StructDetails { field_order: Some(&[1, 0, 3, 8, 9, 10, 4, 5, 6, 7, 2]) },
+            "AccessorProperty",
+            StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11]) },
         ),
-        ("TSTypeParameter", StructDetails { field_order: Some(&[1, 0, 5, 6, 7, 2, 3, 4]) }),
+        ("ThisExpression", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSNamedTupleMember", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("ScopeId", StructDetails { field_order: None }),
+        ("VariableDeclarator", StructDetails { field_order: Some(&[1, 0, 2, 4, 5, 6, 3]) }),
+        ("ImportSpecifier", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("RawTransferMetadata", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
+        ("CallExpression", StructDetails { field_order: Some(&[1, 0, 4, 5, 6, 2, 3]) }),
+        ("BinaryExpression", StructDetails { field_order: Some(&[1, 0, 3, 2, 4]) }),
+        ("JSXEmptyExpression", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSTypeParameterInstantiation", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Span", StructDetails { field_order: Some(&[1, 2, 0]) }),
+        ("IdentifierReference", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("AssignmentPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("WhileStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("FormalParameter", StructDetails { field_order: Some(&[1, 0, 6, 7, 8, 9, 2, 3, 4, 5]) }),
+        ("BindingIdentifier", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("CatchParameter", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSSymbolKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("TSTypeLiteral", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXSpreadAttribute", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("FixedSizeAllocatorMetadata", StructDetails { field_order: None }),
+        ("BindingRestElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSUnionType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("AssignmentTargetWithDefault", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("RegExpPattern", StructDetails { field_order: None }),
+        ("TemplateElementValue", StructDetails { field_order: None }),
+        (
+            "TSConstructSignatureDeclaration",
+            StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) },
+        ),
+        ("TSNonNullExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSEnumBody", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("TSConditionalType", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        ("JSXSpreadChild", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("NodeId", StructDetails { field_order: None }),
+        ("TSIndexSignature", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
+        ("VariableDeclaration", StructDetails { field_order: Some(&[1, 0, 2, 4, 3]) }),
+        ("ParenthesizedExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("Comment", StructDetails { field_order: None }),
         (
             "ArrowFunctionExpression",
             StructDetails { field_order: Some(&[1, 0, 7, 8, 3, 4, 5, 6, 2, 9, 10]) },
         ),
-        ("WithClause", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("Class", StructDetails { field_order: Some(&[1, 0, 10, 3, 4, 5, 6, 7, 8, 9, 11, 12, 2]) }),
-        ("TSTypeAssertion", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ForInStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
+        ("FormalParameterRest", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("ForStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        ("CommentNewlines", StructDetails { field_order: None }),
         ("JSDocNonNullableType", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("RawTransferMetadata2", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
-        ("RegExp", StructDetails { field_order: None }),
-        (
-            "TSConstructSignatureDeclaration",
-            StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) },
-        ),
-        ("ArrayAssignmentTarget", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ExportSpecifier", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("RegExpPattern", StructDetails { field_order: None }),
-        ("TSRestType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSModuleDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("ChainExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("BoundaryAssertion", StructDetails { field_order: None }),
-        ("NullLiteral", StructDetails { field_order: Some(&[1, 0]) }),
-        ("SwitchCase", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("WithStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSModuleBlock", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TaggedTemplateExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("BindingProperty", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
+        ("MetaProperty", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("RegExpLiteral", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("JSXOpeningFragment", StructDetails { field_order: Some(&[1, 0]) }),
+        ("AwaitExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ForOfStatement", StructDetails { field_order: Some(&[1, 0, 6, 3, 4, 5, 2]) }),
         ("StaticMemberExpression", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSConstructorType", StructDetails { field_order: Some(&[1, 0, 6, 3, 4, 5, 2]) }),
         ("BooleanLiteral", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ContinueStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("TSInterfaceHeritage", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("BigIntLiteral", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("TSIntrinsicKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("CatchParameter", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("JSXOpeningElement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("TSInferType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("NameSpan", StructDetails { field_order: Some(&[1, 0]) }),
-        ("TSBooleanKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("DebuggerStatement", StructDetails { field_order: Some(&[1, 0]) }),
-        ("ImportDeclaration", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 6, 3]) }),
-        ("IgnoreGroup", StructDetails { field_order: None }),
-        ("TSIndexedAccessType", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        (
-            "AccessorProperty",
-            StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 8, 9, 3, 4, 5, 10, 11]) },
-        ),
-        ("TSAsExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSTypeLiteral", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("IfStatement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("TSArrayType", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("EcmaScriptModule", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
-        ("TSTypePredicate", StructDetails { field_order: Some(&[1, 0, 3, 2, 4]) }),
-        ("JSXIdentifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ExportAllDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 2]) }),
+        ("ClassString", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("BreakStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSParenthesizedType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXClosingElement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXClosingFragment", StructDetails { field_order: Some(&[1, 0]) }),
+        ("BlockStatement", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("ClassStringDisjunction", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("ExpressionStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
         ("TSNumberKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("VariableDeclaration", StructDetails { field_order: Some(&[1, 0, 2, 4, 3]) }),
-        ("TSUnknownKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("TSFunctionType", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
-        ("V8IntrinsicExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TSIndexSignature", StructDetails { field_order: Some(&[1, 0, 4, 5, 2, 3]) }),
-        ("LabeledStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("TemplateLiteral", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("YieldExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("EmptyStatement", StructDetails { field_order: Some(&[1, 0]) }),
-        ("Modifier", StructDetails { field_order: None }),
-        ("TSTypeParameterInstantiation", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("RawTransferMetadata", StructDetails { field_order: Some(&[0, 3, 4, 5, 1, 2]) }),
-        ("StringLiteral", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("UpdateExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("JSXFragment", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
-        ("ImportDefaultSpecifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("ObjectPattern", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ScopeId", StructDetails { field_order: None }),
-        ("I32Dummy", StructDetails { field_order: None }),
-        ("TSEnumBody", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
-        ("JSXSpreadAttribute", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("JSXText", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("ForOfStatement", StructDetails { field_order: Some(&[1, 0, 6, 3, 4, 5, 2]) }),
-        ("ReferenceId", StructDetails { field_order: None }),
-        ("Dot", StructDetails { field_order: None }),
+        ("TSUndefinedKeyword", StructDetails { field_order: Some(&[1, 0]) }),
         ("TemplateElement", StructDetails { field_order: Some(&[1, 0, 4, 2, 3]) }),
+        ("ClassBody", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("RegExpFlags", StructDetails { field_order: None }),
+        ("CharacterClassRange", StructDetails { field_order: None }),
         ("ObjectExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("EcmaScriptModule", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
+        ("Class", StructDetails { field_order: Some(&[1, 0, 10, 3, 4, 5, 6, 7, 8, 9, 11, 12, 2]) }),
+        ("TSModuleDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        ("TSNeverKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("RegExp", StructDetails { field_order: None }),
+        ("ReturnStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("LookAroundAssertion", StructDetails { field_order: Some(&[0, 2, 1]) }),
+        ("JSDocNullableType", StructDetails { field_order: Some(&[1, 0, 3, 2]) }),
+        ("ImportDefaultSpecifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("JSXOpeningElement", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
+        ("ExportEntry", StructDetails { field_order: Some(&[1, 0, 2, 3, 4, 5, 6]) }),
         ("TSNullKeyword", StructDetails { field_order: Some(&[1, 0]) }),
-        ("UnaryExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("AssignmentTargetPropertyProperty", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("ImportNamespaceSpecifier", StructDetails { field_order: Some(&[1, 0, 2]) }),
-        ("Comment", StructDetails { field_order: None }),
-        ("TSImportEqualsDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
-        ("AssignmentExpression", StructDetails { field_order: Some(&[1, 0, 2, 3, 4]) }),
         ("ImportEntry", StructDetails { field_order: None }),
-        ("TSClassImplements", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
-        ("StaticImport", StructDetails { field_order: None }),
-        ("Pattern", StructDetails { field_order: None }),
-        ("TSConstructorType", StructDetails { field_order: Some(&[1, 0, 6, 3, 4, 5, 2]) }),
+        ("TSEnumMember", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSInterfaceDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 7, 2]) }),
+        ("TSLiteralType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("IndexedReference", StructDetails { field_order: None }),
+        ("WithClause", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ArrayAssignmentTarget", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("Error", StructDetails { field_order: Some(&[4, 0, 1, 2, 3]) }),
+        ("SwitchStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSFunctionType", StructDetails { field_order: Some(&[1, 0, 3, 4, 5, 6, 2]) }),
+        (
+            "MethodDefinition",
+            StructDetails { field_order: Some(&[1, 0, 2, 6, 7, 8, 3, 4, 5, 9, 10, 11]) },
+        ),
+        ("TSTypeAssertion", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSOptionalType", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("TSTypeParameter", StructDetails { field_order: Some(&[1, 0, 5, 6, 7, 2, 3, 4]) }),
+        ("ContinueStatement", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("SourceType", StructDetails { field_order: None }),
+        ("AssignmentTargetPropertyIdentifier", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("ChainExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("ImportAttribute", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSImportEqualsDeclaration", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSTypeAnnotation", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("PrivateInExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSModuleBlock", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSQualifiedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("EmptyStatement", StructDetails { field_order: Some(&[1, 0]) }),
+        ("ArrayExpression", StructDetails { field_order: Some(&[1, 0, 2]) }),
+        ("AssignmentTargetPropertyProperty", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("WithStatement", StructDetails { field_order: Some(&[1, 0, 3, 4, 2]) }),
+        ("TSImportTypeQualifiedName", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("TSIntrinsicKeyword", StructDetails { field_order: Some(&[1, 0]) }),
+        ("PipelineExpression", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("DoWhileStatement", StructDetails { field_order: Some(&[1, 0, 2, 3]) }),
+        ("Dot", StructDetails { field_order: None }),
     ],
 };
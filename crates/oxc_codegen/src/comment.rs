@@ -1,14 +1,18 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
+use cow_utils::CowUtils;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use oxc_ast::{Comment, CommentKind, ast::Program};
+use oxc_ast::{Comment, CommentContent, CommentKind, ast::Program};
 use oxc_syntax::line_terminator::LineTerminatorSplitter;
 
-use crate::{Codegen, LegalComment, options::CommentOptions};
+use crate::{Codegen, LegalComment, UnicodeEscape, options::CommentOptions};
 
 pub type CommentsMap = FxHashMap</* attached_to */ u32, Vec<Comment>>;
 
+/// Trailing comments keyed by their start, in source order.
+pub type TrailingCommentsMap = BTreeMap</* start */ u32, Comment>;
+
 impl Codegen<'_> {
     pub(crate) fn build_comments(&mut self, comments: &[Comment]) {
         if self.options.comments == CommentOptions::disabled() {
@@ -19,28 +23,46 @@ impl Codegen<'_> {
             if comment.is_pure() || comment.is_no_side_effects() {
                 continue;
             }
+            // The sourcemap URL of the input does not apply to the output.
+            if self.options.source_map_path.is_some()
+                && self
+                    .source_text
+                    .is_some_and(|source_text| is_source_map_url(comment, source_text))
+            {
+                continue;
+            }
             let mut add = false;
-            if comment.is_leading() {
-                if comment.is_legal() && self.options.print_legal_comment() {
-                    add = true;
-                }
-                if comment.is_jsdoc() && self.options.print_jsdoc_comment() {
-                    add = true;
-                }
-                if comment.is_annotation() && self.options.print_annotation_comment() {
-                    add = true;
-                }
-                if comment.is_normal() && self.options.print_normal_comment() {
-                    add = true;
-                }
+            if comment.is_legal() && self.options.print_legal_comment() {
+                add = true;
             }
-            if add {
+            if comment.is_jsdoc() && self.options.print_jsdoc_comment() {
+                add = true;
+            }
+            if comment.is_annotation() && self.options.print_annotation_comment() {
+                add = true;
+            }
+            if comment.is_normal() && self.options.print_normal_comment() {
+                add = true;
+            }
+            if !add {
+                continue;
+            }
+            if comment.is_leading() {
                 if comment.is_legal()
                     && let Err(idx) = self.legal_comment_keys.binary_search(&comment.attached_to)
                 {
                     self.legal_comment_keys.insert(idx, comment.attached_to);
                 }
                 self.comments.entry(comment.attached_to).or_default().push(*comment);
+            } else if comment.content != CommentContent::PureNotApplied
+                && !self
+                    .source_text
+                    .is_some_and(|source_text| is_html_comment(comment, source_text))
+            {
+                // Trailing pure comments that were not applied are dropped like leading ones.
+                // HTML-like comments are dropped too: they are a syntax error in module code,
+                // and `<!--` comments out the rest of the line.
+                self.trailing_comments.insert(comment.span.start, *comment);
             }
         }
     }
@@ -125,13 +147,257 @@ impl Codegen<'_> {
         false
     }
 
+    /// Whether a comment spanning multiple lines is attached to `start`.
+    ///
+    /// Such a comment counts as a line break, so it must not be printed where
+    /// a line break triggers automatic semicolon insertion, e.g. `return /*\n*/ a`.
+    pub(crate) fn has_multi_line_comment(&self, start: u32) -> bool {
+        if self.comments.is_empty() {
+            return false;
+        }
+        let Some(source_text) = self.source_text else { return false };
+        // Parentheses are not nodes, so also check the tokens after them, e.g. `(/*\n*/ a)`.
+        let mut pos = start;
+        loop {
+            if self.comments.get(&pos).is_some_and(|comments| {
+                comments.iter().any(|c| !c.is_legal() && c.is_multiline_block())
+            }) {
+                return true;
+            }
+            if source_text.as_bytes().get(pos as usize) != Some(&b'(') {
+                return false;
+            }
+            let Some(next) = next_token_start(source_text, pos + 1) else { return false };
+            pos = next;
+        }
+    }
+
+    /// Print comments attached to the start of a node within a statement,
+    /// e.g. `foo(/* comment */ a)`, on the same line as the node.
+    pub(crate) fn print_inline_comments_at(&mut self, start: u32) {
+        let mut comments = self.take_trailing_comments_before(start);
+        comments.extend(self.take_comments_before_parentheses(start));
+        if let Some(at_start) = self.take_inline_comments(start) {
+            comments.extend(at_start);
+        }
+        self.print_leading_inline_comments(&comments);
+    }
+
+    /// Take trailing comments before `start` that have not been printed yet,
+    /// e.g. `a ? // comment` before the consequent, so they are printed in source order.
+    pub(crate) fn take_trailing_comments_before(&mut self, start: u32) -> Vec<Comment> {
+        let mut comments = vec![];
+        while let Some(entry) = self.trailing_comments.first_entry()
+            && *entry.key() < start
+        {
+            comments.push(entry.remove());
+        }
+        comments
+    }
+
+    /// Print trailing comments before the separator in front of the item at `start`,
+    /// e.g. `a /* comment */, b` for `a, // comment` followed by `b` on the next line.
+    pub(crate) fn print_trailing_comments_before_separator(&mut self, start: u32) {
+        for comment in self.take_trailing_comments_before(start) {
+            self.print_inline_comment(&comment);
+        }
+    }
+
+    /// Take comments attached to the parentheses around a node, e.g. `/* comment */ (a)`.
+    ///
+    /// Parentheses are not nodes, and may have been removed from the AST.
+    pub(crate) fn take_comments_before_parentheses(&mut self, start: u32) -> Vec<Comment> {
+        let mut comments = vec![];
+        if self.comments.is_empty() {
+            return comments;
+        }
+        let Some(source_text) = self.source_text else { return comments };
+        let bytes = source_text.as_bytes();
+        let mut parens = vec![];
+        let mut pos = start as usize;
+        loop {
+            while pos > 0 && bytes[pos - 1].is_ascii_whitespace() {
+                pos -= 1;
+            }
+            if pos == 0 || bytes[pos - 1] != b'(' {
+                break;
+            }
+            pos -= 1;
+            parens.push(pos);
+        }
+        for &paren in parens.iter().rev() {
+            #[expect(clippy::cast_possible_truncation)]
+            if let Some(at_paren) = self.take_inline_comments(paren as u32) {
+                comments.extend(at_paren);
+            }
+        }
+        comments
+    }
+
+    fn print_leading_inline_comments(&mut self, comments: &[Comment]) {
+        if comments.is_empty() {
+            return;
+        }
+        let n = self.code.len();
+        for comment in comments {
+            self.print_inline_comment(comment);
+        }
+        self.print_soft_space();
+        // Comments are not code: keep checks like "is this expression at the
+        // start of a statement" working after printing them.
+        let len = self.code.len();
+        if self.start_of_stmt == n {
+            self.start_of_stmt = len;
+        }
+        if self.start_of_arrow_expr == n {
+            self.start_of_arrow_expr = len;
+        }
+        if self.start_of_default_export == n {
+            self.start_of_default_export = len;
+        }
+    }
+
+    /// Print comments between the end of a node and the punctuator following
+    /// it, e.g. `[a /* comment */]` or `a /* comment */ + b`.
+    ///
+    /// These comments are attached to the punctuator, which is not a node.
+    pub(crate) fn print_comments_before_punctuator(&mut self, end: u32) {
+        if self.comments.is_empty() {
+            return;
+        }
+        let Some(source_text) = self.source_text else { return };
+        let Some(start) = next_token_start(source_text, end) else { return };
+        if is_punctuator_after_node(&source_text[start as usize..]) {
+            self.print_inline_comments_before(start);
+        }
+    }
+
+    /// Print comments attached to the token at `start` on the same line,
+    /// without a space between the last comment and the token.
+    pub(crate) fn print_inline_comments_before(&mut self, start: u32) {
+        if let Some(comments) = self.take_inline_comments(start) {
+            self.print_inline_comment_list(&comments);
+        }
+    }
+
+    /// Print comments on the same line, like [Self::print_inline_comments_before].
+    pub(crate) fn print_inline_comment_list(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            self.print_inline_comment(comment);
+        }
+    }
+
+    /// Take the comments attached to `start`, except legal comments which are
+    /// printed at statement boundaries by [Self::print_legal_orphans_before].
+    fn take_inline_comments(&mut self, start: u32) -> Option<Vec<Comment>> {
+        if self.comments.is_empty() {
+            return None;
+        }
+        let entry = self.comments.get_mut(&start)?;
+        // Pure comments that were not applied are dropped, because printing them without
+        // the parentheses in e.g. `(/* @__PURE__ */ a)()` would turn them into valid annotations.
+        let comments = entry
+            .extract_if(.., |c| !c.is_legal())
+            .filter(|c| c.content != CommentContent::PureNotApplied)
+            .collect::<Vec<_>>();
+        if entry.is_empty() {
+            self.comments.remove(&start);
+        }
+        (!comments.is_empty()).then_some(comments)
+    }
+
+    /// Print a comment in the middle of a line. Line comments are printed as
+    /// block comments so they do not swallow the code after them, e.g.
+    /// `foo(a, // comment` becomes `foo(a /* comment */, b)`.
+    ///
+    /// Breaking the line after them instead is not safe in general, because a
+    /// line break can insert a semicolon, e.g. in `return // comment` or before `=>`.
+    fn print_inline_comment(&mut self, comment: &Comment) {
+        let Some(source_text) = self.source_text else {
+            return;
+        };
+        if !self.options.minify
+            && self.last_byte().is_some_and(|b| !matches!(b, b' ' | b'\t' | b'\n' | b'(' | b'['))
+        {
+            self.print_hard_space();
+        }
+        if comment.is_line() {
+            let content = line_comment_content(comment, source_text).trim_end();
+            self.print_str("/*");
+            self.print_raw_str(&content.cow_replace("*/", "* /"), UnicodeEscape::CodePoints);
+            self.print_str(" */");
+        } else {
+            self.print_comment(comment);
+        }
+    }
+
+    /// Print trailing comments on the last line of the node ending at `end`,
+    /// e.g. `foo(); // comment`, including unprinted trailing comments inside the node.
+    ///
+    /// The caller must print a line break afterwards, unless in minify mode.
+    pub(crate) fn print_trailing_comments(&mut self, end: u32) {
+        if self.trailing_comments.is_empty() {
+            return;
+        }
+        let Some(source_text) = self.source_text else { return };
+        // Extend `end` past the comments directly following the node, skipping separators.
+        let bytes = source_text.as_bytes();
+        let mut limit = end;
+        let mut pos = end as usize;
+        loop {
+            while pos < bytes.len() && matches!(bytes[pos], b' ' | b'\t' | b',' | b';') {
+                pos += 1;
+            }
+            #[expect(clippy::cast_possible_truncation)]
+            let Some(comment) = self.trailing_comments.get(&(pos as u32)) else { break };
+            limit = comment.span.end;
+            pos = limit as usize;
+        }
+        while let Some(entry) = self.trailing_comments.first_entry()
+            && *entry.key() < limit
+        {
+            let comment = entry.remove();
+            let is_last = self.trailing_comments.first_key_value().is_none_or(|(&k, _)| k >= limit);
+            if is_last {
+                self.print_soft_space();
+                self.print_comment(&comment);
+                if comment.is_line() && self.options.minify {
+                    self.print_hard_newline();
+                }
+            } else {
+                self.print_inline_comment(&comment);
+            }
+        }
+    }
+
+    /// Print trailing comments before `start` that have not been printed with
+    /// their node, each on its own line.
+    pub(crate) fn print_trailing_comments_before(&mut self, start: u32) {
+        while let Some(entry) = self.trailing_comments.first_entry()
+            && *entry.key() < start
+        {
+            let comment = entry.remove();
+            if self.last_byte().is_some_and(|b| b != b'\n') {
+                self.print_hard_newline();
+            }
+            self.print_next_indent_as_space = false;
+            self.print_indent();
+            self.print_comment(&comment);
+            self.print_hard_newline();
+        }
+    }
+
     pub(crate) fn print_expr_comments(&mut self, start: u32) -> bool {
         if self.comments.is_empty() {
             return false;
         }
         let Some(comments) = self.comments.remove(&start) else { return false };
+        self.print_expr_comment_list(&comments)
+    }
 
-        for comment in &comments {
+    /// Print comments on their own lines, like [Self::print_expr_comments].
+    pub(crate) fn print_expr_comment_list(&mut self, comments: &[Comment]) -> bool {
+        for comment in comments {
             self.print_hard_newline();
             self.print_indent();
             self.print_comment(comment);
@@ -209,14 +475,14 @@ impl Codegen<'_> {
         let comment_source = comment.span.source_text(source_text);
         match comment.kind {
             CommentKind::Line | CommentKind::SingleLineBlock => {
-                self.print_str_escaping_script_close_tag(comment_source);
+                self.print_raw_str(comment_source, UnicodeEscape::CodePoints);
             }
             CommentKind::MultiLineBlock => {
                 for line in LineTerminatorSplitter::new(comment_source) {
                     if !line.starts_with("/*") {
                         self.print_indent();
                     }
-                    self.print_str_escaping_script_close_tag(line.trim_start());
+                    self.print_raw_str(line.trim_start(), UnicodeEscape::CodePoints);
                     if !line.ends_with("*/") {
                         self.print_hard_newline();
                     }
@@ -290,3 +556,59 @@ impl Codegen<'_> {
         }
     }
 }
+
+/// Whether a line comment is an HTML-like comment, i.e. `<!--` or `-->`.
+fn is_html_comment(comment: &Comment, source_text: &str) -> bool {
+    comment.is_line() && !comment.span.source_text(source_text).starts_with("//")
+}
+
+/// Whether a line comment is a `//# sourceMappingURL=` comment.
+fn is_source_map_url(comment: &Comment, source_text: &str) -> bool {
+    let text = comment.span.source_text(source_text);
+    comment.is_line()
+        && (text.starts_with("//# sourceMappingURL=") || text.starts_with("//@ sourceMappingURL="))
+}
+
+/// Content of a line comment, after `//`, `<!--` or `-->`.
+fn line_comment_content<'s>(comment: &Comment, source_text: &'s str) -> &'s str {
+    let text = comment.span.source_text(source_text);
+    text.strip_prefix("//")
+        .or_else(|| text.strip_prefix("<!--"))
+        .or_else(|| text.strip_prefix("-->"))
+        .unwrap_or(text)
+}
+
+/// Start of the first token at or after `pos`, skipping whitespace and comments.
+fn next_token_start(source_text: &str, pos: u32) -> Option<u32> {
+    let bytes = source_text.as_bytes();
+    let mut pos = pos as usize;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b' ' | b'\t' | b'\n' | b'\r' => pos += 1,
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                pos += bytes[pos..].iter().position(|&b| b == b'\n')?;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos += source_text[pos + 2..].find("*/")? + 4;
+            }
+            _ => return u32::try_from(pos).ok(),
+        }
+    }
+    None
+}
+
+/// Whether the token at the start of `s` is a punctuator that can only follow a node,
+/// so comments attached to it are not leading comments of another node.
+fn is_punctuator_after_node(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    match bytes.first() {
+        Some(
+            b',' | b';' | b')' | b']' | b'}' | b':' | b'?' | b'=' | b'*' | b'/' | b'%' | b'&'
+            | b'|' | b'^' | b'<' | b'>' | b'.',
+        ) => !s.starts_with("..."),
+        Some(b'+') => bytes.get(1) != Some(&b'+'),
+        Some(b'-') => bytes.get(1) != Some(&b'-'),
+        Some(b'!') => bytes.get(1) == Some(&b'='),
+        _ => false,
+    }
+}
@@ -10,7 +10,7 @@ use oxc_syntax::{
 };
 
 use crate::{
-    Codegen, Context, Operator, Quote,
+    Codegen, Context, Operator, Quote, UnicodeEscape,
     binary_expr_visitor::{BinaryExpressionVisitor, Binaryish, BinaryishOperator},
 };
 
@@ -95,7 +95,7 @@ impl Gen for Directive<'_> {
             }
         }
         quote.print(p);
-        p.print_str(directive);
+        p.print_raw_str(directive, UnicodeEscape::SurrogatePairs);
         quote.print(p);
         p.print_ascii_byte(b';');
         p.print_soft_newline();
@@ -229,6 +229,7 @@ fn print_if(if_stmt: &IfStatement<'_>, p: &mut Codegen, ctx: Context) {
     match &if_stmt.consequent {
         Statement::BlockStatement(block) => {
             p.print_soft_space();
+            p.print_inline_comments_at(block.span.start);
             p.print_block_statement(block, ctx);
             if if_stmt.alternate.is_some() {
                 p.print_soft_space();
@@ -262,6 +263,7 @@ fn print_if(if_stmt: &IfStatement<'_>, p: &mut Codegen, ctx: Context) {
         match alternate {
             Statement::BlockStatement(block) => {
                 p.print_soft_space();
+                p.print_inline_comments_at(block.span.start);
                 p.print_block_statement(block, ctx);
                 p.print_soft_newline();
             }
@@ -554,7 +556,7 @@ impl Gen for ReturnStatement<'_> {
         p.print_str("return");
         if let Some(arg) = &self.argument {
             p.print_soft_space();
-            p.print_expression(arg);
+            p.print_restricted_expression(arg);
         }
         p.print_semicolon_after_statement();
     }
@@ -631,7 +633,7 @@ impl Gen for ThrowStatement<'_> {
         p.add_source_mapping(self.span);
         p.print_str("throw");
         p.print_soft_space();
-        p.print_expression(&self.argument);
+        p.print_restricted_expression(&self.argument);
         p.print_semicolon_after_statement();
     }
 }
@@ -785,6 +787,7 @@ impl Gen for FunctionBody<'_> {
 
 impl Gen for FormalParameter<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        p.print_inline_comments_at(self.span.start);
         p.add_source_mapping(self.span);
         p.print_decorators(&self.decorators, ctx);
         if let Some(accessibility) = self.accessibility {
@@ -1169,6 +1172,7 @@ impl Gen for ExportDefaultDeclaration<'_> {
         p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str("export default ");
+        p.print_inline_comments_at(self.declaration.span().start);
         self.declaration.print(p, ctx);
     }
 }
@@ -1195,6 +1199,11 @@ impl Gen for ExportDefaultDeclarationKind<'_> {
 
 impl GenExpr for Expression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        // Object literals print their own comments, see `ObjectExpression::gen_expr`.
+        // Comments on parentheses are printed with the expression inside them.
+        if !matches!(self, Self::ObjectExpression(_) | Self::ParenthesizedExpression(_)) {
+            p.print_inline_comments_at(self.span().start);
+        }
         match self {
             // Most common expressions first (identifiers, member access, calls)
             Self::Identifier(ident) => ident.print(p, ctx),
@@ -1270,7 +1279,13 @@ impl GenExpr for Expression<'_> {
             Self::TSInstantiationExpression(e) => e.print_expr(p, precedence, ctx),
             // V8 intrinsics (rare)
             Self::V8IntrinsicExpression(e) => e.print_expr(p, precedence, ctx),
+            // Proposals (rare)
+            Self::DoExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::PipelineExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::ThrowExpression(e) => e.print_expr(p, precedence, ctx),
+            Self::TopicReference(e) => e.print(p, ctx),
         }
+        p.print_comments_before_punctuator(self.span().end);
     }
 }
 
@@ -1285,7 +1300,7 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1293,7 +1308,7 @@ impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1302,7 +1317,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1310,7 +1325,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1403,7 +1418,13 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(self.regex.pattern.text.as_str());
+        let escape = if self.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V) {
+            UnicodeEscape::CodePoints
+        } else {
+            UnicodeEscape::SurrogatePairs
+        };
+        let unicode_sets = self.regex.flags.contains(RegExpFlags::V);
+        p.print_regexp_pattern(self.regex.pattern.text.as_str(), escape, unicode_sets);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1460,7 +1481,7 @@ impl GenExpr for StaticMemberExpression<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'.');
-        self.property.print(p, ctx);
+        p.print_property_name(&self.property);
     }
 }
 
@@ -1520,7 +1541,8 @@ impl Gen for ArrayExpressionElement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::SpreadElement(elem) => elem.print(p, ctx),
-            Self::Elision(_span) => {}
+            // The span of a hole is its comma, e.g. `[/* comment */, a]`.
+            Self::Elision(elision) => p.print_inline_comments_before(elision.span.start),
             _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
         }
     }
@@ -1545,9 +1567,15 @@ impl Gen for ArrayExpression<'_> {
         for (i, item) in self.elements.iter().enumerate() {
             if i != 0 {
                 p.print_comma();
+                if is_multi_line {
+                    p.print_trailing_comments(self.elements[i - 1].span().end);
+                }
             }
             if is_multi_line {
                 p.print_soft_newline();
+                if !matches!(item, ArrayExpressionElement::Elision(_)) {
+                    p.print_comments_at(item.span().start);
+                }
                 p.print_indent();
             } else if i != 0 {
                 p.print_soft_space();
@@ -1557,6 +1585,12 @@ impl Gen for ArrayExpression<'_> {
                 p.print_comma();
             }
         }
+        if is_multi_line && let Some(last) = self.elements.last() {
+            p.print_trailing_comments(last.span().end);
+        }
+        if self.span.end > 0 {
+            p.print_inline_comments_before(self.span.end - 1);
+        }
         if is_multi_line {
             p.print_soft_newline();
             p.dedent();
@@ -1572,13 +1606,18 @@ impl GenExpr for ObjectExpression<'_> {
         let n = p.code_len();
         let len = self.properties.len();
         let is_multi_line = len > 1;
-        let has_comment = p.has_comment(self.span.start);
+        let mut comments = p.take_trailing_comments_before(self.span.start);
+        comments.extend(p.take_comments_before_parentheses(self.span.start));
+        if let Some(at_start) = p.get_comments(self.span.start) {
+            comments.extend(at_start);
+        }
+        let has_comment = !comments.is_empty();
         let wrap = has_comment || p.start_of_stmt == n || p.start_of_arrow_expr == n;
         p.wrap(wrap, |p| {
             // Print comments for lingui https://lingui.dev/ref/macro#definemessage
             // `const message = /*i18n*/ { };`
             if has_comment {
-                p.print_leading_comments(self.span.start);
+                p.print_comments(&comments);
                 p.print_indent();
             }
             p.add_source_mapping(self.span);
@@ -1589,6 +1628,9 @@ impl GenExpr for ObjectExpression<'_> {
             for (i, item) in self.properties.iter().enumerate() {
                 if i != 0 {
                     p.print_comma();
+                    if is_multi_line {
+                        p.print_trailing_comments(self.properties[i - 1].span().end);
+                    }
                 }
                 if is_multi_line {
                     p.print_soft_newline();
@@ -1606,6 +1648,12 @@ impl GenExpr for ObjectExpression<'_> {
                 }
                 item.print(p, ctx);
             }
+            if is_multi_line && let Some(last) = self.properties.last() {
+                p.print_trailing_comments(last.span().end);
+            }
+            if self.span.end > 0 {
+                p.print_inline_comments_before(self.span.end - 1);
+            }
             if is_multi_line {
                 p.print_soft_newline();
                 p.dedent();
@@ -1692,7 +1740,7 @@ impl Gen for ObjectProperty<'_> {
             if key.name == "__proto__" {
                 shorthand = self.shorthand;
             } else if let Expression::Identifier(ident) = self.value.without_parentheses()
-                && key.name == p.get_identifier_reference_name(ident)
+                && p.get_property_name(&key.name) == p.get_identifier_reference_name(ident)
             {
                 shorthand = true;
             }
@@ -1728,7 +1776,7 @@ impl Gen for ObjectProperty<'_> {
 impl Gen for PropertyKey<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::StaticIdentifier(ident) => ident.print(p, ctx),
+            Self::StaticIdentifier(ident) => p.print_property_name(ident),
             Self::PrivateIdentifier(ident) => ident.print(p, ctx),
             Self::StringLiteral(s) => p.print_string_literal(s, /* allow_backtick */ false),
             _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
@@ -1796,7 +1844,10 @@ impl GenExpr for YieldExpression<'_> {
             }
             if let Some(argument) = self.argument.as_ref() {
                 p.print_soft_space();
-                argument.print_expr(p, Precedence::Yield, Context::empty());
+                let wrap = p.has_multi_line_comment(argument.span().start);
+                p.wrap(wrap, |p| {
+                    argument.print_expr(p, Precedence::Yield, Context::empty());
+                });
             }
         });
     }
@@ -2065,11 +2116,13 @@ impl Gen for AssignmentTargetProperty<'_> {
 impl Gen for AssignmentTargetPropertyIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let ident_name = p.get_identifier_reference_name(&self.binding);
-        if ident_name == self.binding.name.as_str() {
+        let key_name = p.get_mangled_property_name(&self.binding.name);
+        let key_name = key_name.as_deref().unwrap_or(self.binding.name.as_str());
+        if ident_name == key_name {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_str(key_name);
             p.print_colon();
             p.print_soft_space();
             p.print_str(ident_name);
@@ -2087,7 +2140,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let omit_key = if p.options.minify {
             let key_name = match &self.name {
-                PropertyKey::StaticIdentifier(ident) => Some(&ident.name),
+                PropertyKey::StaticIdentifier(ident) => Some(p.get_property_name(&ident.name)),
                 _ => None,
             };
             let value_name =
@@ -2102,7 +2155,7 @@ impl Gen for AssignmentTargetPropertyProperty<'_> {
         if !omit_key {
             match &self.name {
                 PropertyKey::StaticIdentifier(ident) => {
-                    ident.print(p, ctx);
+                    p.print_property_name(ident);
                 }
                 PropertyKey::PrivateIdentifier(ident) => {
                     ident.print(p, ctx);
@@ -2152,11 +2205,15 @@ impl GenExpr for ImportExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let wrap = precedence >= Precedence::New || ctx.intersects(Context::FORBID_CALL);
 
-        let has_comment_before_right_paren = p.options.print_annotation_comment()
-            && self.span.end > 0
-            && p.has_comment(self.span.end - 1);
+        // Taken up front, so they are not printed after the last argument.
+        let comments_before_right_paren =
+            if p.options.print_annotation_comment() && self.span.end > 0 {
+                p.get_comments(self.span.end - 1)
+            } else {
+                None
+            };
         let has_comment = p.options.print_annotation_comment()
-            && (has_comment_before_right_paren
+            && (comments_before_right_paren.is_some()
                 || p.has_comment(self.source.span().start)
                 || self
                     .options
@@ -2194,7 +2251,10 @@ impl GenExpr for ImportExpression<'_> {
             }
             if has_comment {
                 // Handle `/* comment */);`
-                if !has_comment_before_right_paren || !p.print_expr_comments(self.span.end - 1) {
+                if !comments_before_right_paren
+                    .as_ref()
+                    .is_some_and(|comments| p.print_expr_comment_list(comments))
+                {
                     p.print_soft_newline();
                 }
                 p.dedent();
@@ -2206,20 +2266,32 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.add_source_mapping(self.span);
-        p.print_ascii_byte(b'`');
-        debug_assert_eq!(self.quasis.len(), self.expressions.len() + 1);
-        let (first_quasi, remaining_quasis) = self.quasis.split_first().unwrap();
-        p.print_str_escaping_script_close_tag(first_quasi.value.raw.as_str());
-        for (expr, quasi) in self.expressions.iter().zip(remaining_quasis) {
-            p.print_str("${");
-            p.print_expression(expr);
-            p.print_ascii_byte(b'}');
-            p.add_source_mapping(quasi.span);
+        print_template_literal(self, p, false);
+    }
+}
+
+fn print_template_literal(template: &TemplateLiteral<'_>, p: &mut Codegen, tagged: bool) {
+    p.add_source_mapping(template.span);
+    p.print_ascii_byte(b'`');
+    debug_assert_eq!(template.quasis.len(), template.expressions.len() + 1);
+    let (first_quasi, remaining_quasis) = template.quasis.split_first().unwrap();
+    let print_quasi = |p: &mut Codegen, quasi: &TemplateElement<'_>| {
+        // Escaping would change the raw strings passed to the tag.
+        if tagged {
             p.print_str_escaping_script_close_tag(quasi.value.raw.as_str());
+        } else {
+            p.print_raw_str(quasi.value.raw.as_str(), UnicodeEscape::CodePoints);
         }
-        p.print_ascii_byte(b'`');
+    };
+    print_quasi(p, first_quasi);
+    for (expr, quasi) in template.expressions.iter().zip(remaining_quasis) {
+        p.print_str("${");
+        p.print_expression(expr);
+        p.print_ascii_byte(b'}');
+        p.add_source_mapping(quasi.span);
+        print_quasi(p, quasi);
     }
+    p.print_ascii_byte(b'`');
 }
 
 impl Gen for TaggedTemplateExpression<'_> {
@@ -2229,7 +2301,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        print_template_literal(&self.quasi, p, true);
     }
 }
 
@@ -2409,12 +2481,25 @@ impl Gen for Class<'_> {
 
 impl Gen for ClassBody<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.print_curly_braces(self.span, self.body.is_empty(), |p| {
+        let span_end = self.span.end;
+        let comments_at_end = if span_end > 0 { p.get_comments(span_end - 1) } else { None };
+        let single_line = self.body.is_empty()
+            && comments_at_end
+                .as_ref()
+                .is_none_or(|comments| comments.iter().all(|c| !c.has_newlines_around()));
+        p.print_curly_braces(self.span, single_line, |p| {
             for item in &self.body {
+                p.print_trailing_comments_before(item.span().start);
                 p.print_semicolon_if_needed();
                 p.print_leading_comments(item.span().start);
                 p.print_indent();
-                item.print(p, ctx);
+                p.print_statement(item, ctx);
+            }
+            p.print_trailing_comments_before(self.span.end);
+            // Print comments before the closing brace.
+            if let Some(comments) = comments_at_end {
+                p.print_comments(&comments);
+                p.print_next_indent_as_space = false;
             }
         });
     }
@@ -2468,7 +2553,11 @@ impl Gen for JSXMemberExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         self.object.print(p, ctx);
         p.print_ascii_byte(b'.');
-        self.property.print(p, ctx);
+        if let Some(mangled) = p.get_mangled_property_name(&self.property.name) {
+            p.print_str(mangled.as_str());
+        } else {
+            self.property.print(p, ctx);
+        }
     }
 }
 
@@ -2514,6 +2603,7 @@ impl Gen for JSXAttribute<'_> {
 impl Gen for JSXEmptyExpression {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_comments_at(self.span.end);
+        p.print_next_indent_as_space = false;
     }
 }
 
@@ -2549,7 +2639,7 @@ impl Gen for JSXAttributeValue<'_> {
             Self::StringLiteral(lit) => {
                 let quote = if lit.value.contains('"') { b'\'' } else { b'"' };
                 p.print_ascii_byte(quote);
-                p.print_str(&lit.value);
+                p.print_jsx_str(&lit.value);
                 p.print_ascii_byte(quote);
             }
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
@@ -2571,6 +2661,7 @@ impl Gen for JSXSpreadAttribute<'_> {
 
 impl Gen for JSXAttributeItem<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        p.print_inline_comments_at(self.span().start);
         match self {
             Self::Attribute(attr) => attr.print(p, ctx),
             Self::SpreadAttribute(spread_attr) => spread_attr.print(p, ctx),
@@ -2638,7 +2729,7 @@ impl Gen for JSXClosingFragment {
 impl Gen for JSXText<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span);
-        p.print_str(self.value.as_str());
+        p.print_jsx_str(self.value.as_str());
     }
 }
 
@@ -2893,19 +2984,21 @@ impl Gen for PrivateIdentifier<'_> {
         }) {
             p.print_str(mangled.as_str());
         } else {
-            p.print_str(self.name.as_str());
+            p.print_identifier(self.name.as_str());
         }
     }
 }
 
 impl Gen for BindingPattern<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        p.print_inline_comments_at(self.span().start);
         match self {
             BindingPattern::BindingIdentifier(ident) => ident.print(p, ctx),
             BindingPattern::ObjectPattern(pattern) => pattern.print(p, ctx),
             BindingPattern::ArrayPattern(pattern) => pattern.print(p, ctx),
             BindingPattern::AssignmentPattern(pattern) => pattern.print(p, ctx),
         }
+        p.print_comments_before_punctuator(self.span().end);
     }
 }
 
@@ -2937,13 +3030,13 @@ impl Gen for BindingProperty<'_> {
         if let PropertyKey::StaticIdentifier(key) = &self.key {
             match &self.value {
                 BindingPattern::BindingIdentifier(ident)
-                    if key.name == p.get_binding_identifier_name(ident) =>
+                    if p.get_property_name(&key.name) == p.get_binding_identifier_name(ident) =>
                 {
                     shorthand = true;
                 }
                 BindingPattern::AssignmentPattern(assignment_pattern) => {
                     if let BindingPattern::BindingIdentifier(ident) = &assignment_pattern.left
-                        && key.name == p.get_binding_identifier_name(ident)
+                        && p.get_property_name(&key.name) == p.get_binding_identifier_name(ident)
                     {
                         shorthand = true;
                     }
@@ -3087,6 +3180,7 @@ impl Gen for TSTypeAnnotation<'_> {
 impl Gen for TSType<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         let ctx = ctx.with_typescript();
+        p.print_inline_comments_at(self.span().start);
         match self {
             Self::TSFunctionType(ty) => ty.print(p, ctx),
             Self::TSConstructorType(ty) => ty.print(p, ctx),
@@ -3126,6 +3220,7 @@ impl Gen for TSType<'_> {
             Self::JSDocNonNullableType(ty) => ty.print(p, ctx),
             Self::JSDocUnknownType(_ty) => p.print_str("unknown"),
         }
+        p.print_comments_before_punctuator(self.span().end);
     }
 }
 
@@ -4003,3 +4098,57 @@ impl GenExpr for V8IntrinsicExpression<'_> {
         });
     }
 }
+
+impl GenExpr for DoExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, _precedence: Precedence, ctx: Context) {
+        // `do {}` at the start of a statement would be parsed as a `do...while` statement
+        let wrap = p.start_of_stmt == p.code_len();
+        p.wrap(wrap, |p| {
+            p.print_space_before_identifier();
+            p.add_source_mapping(self.span);
+            p.print_str("do");
+            p.print_soft_space();
+            p.print_block_statement(&self.body, ctx);
+        });
+    }
+}
+
+impl GenExpr for PipelineExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        p.wrap(precedence >= Precedence::Assign, |p| {
+            p.add_source_mapping(self.span);
+            self.left.print_expr(p, Precedence::Conditional, ctx);
+            p.print_soft_space();
+            p.print_str("|>");
+            p.print_soft_space();
+            // Pipe bodies may contain further pipes, e.g. `x |> f(%) |> g(%)`
+            let body_precedence = if matches!(self.body, Expression::PipelineExpression(_)) {
+                Precedence::Yield
+            } else {
+                Precedence::Conditional
+            };
+            self.body.print_expr(p, body_precedence, ctx);
+        });
+    }
+}
+
+impl GenExpr for ThrowExpression<'_> {
+    fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
+        // `throw x` at the start of a statement would be parsed as a `throw` statement
+        let wrap = precedence >= Precedence::Prefix || p.start_of_stmt == p.code_len();
+        p.wrap(wrap, |p| {
+            p.print_space_before_identifier();
+            p.add_source_mapping(self.span);
+            p.print_str("throw");
+            p.print_soft_space();
+            self.argument.print_expr(p, Precedence::Prefix, ctx);
+        });
+    }
+}
+
+impl Gen for TopicReference {
+    fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        p.add_source_mapping(self.span);
+        p.print_ascii_byte(b'%');
+    }
+}
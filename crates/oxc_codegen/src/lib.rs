@@ -3,7 +3,7 @@
 //! Code adapted from
 //! * [esbuild](https://github.com/evanw/esbuild/blob/v0.24.0/internal/js_printer/js_printer.go)
 
-use std::{borrow::Cow, cmp, slice};
+use std::{borrow::Cow, cmp, mem, slice};
 
 use cow_utils::CowUtils;
 
@@ -32,11 +32,11 @@ mod sourcemap_builder;
 mod str;
 
 use binary_expr_visitor::BinaryExpressionVisitor;
-use comment::CommentsMap;
+use comment::{CommentsMap, TrailingCommentsMap};
 use operator::Operator;
 #[cfg(feature = "sourcemap")]
 use sourcemap_builder::SourcemapBuilder;
-use str::{Quote, cold_branch, is_script_close_tag};
+use str::{Quote, UnicodeEscape, cold_branch, is_script_close_tag};
 
 pub use context::Context;
 pub use r#gen::{Gen, GenExpr};
@@ -90,6 +90,9 @@ pub struct Codegen<'a> {
     /// Private member name mappings for mangling
     private_member_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// Property name mappings for mangling
+    property_mappings: Option<FxHashMap<String, CompactStr>>,
+
     /// Output Code
     code: CodeBuffer,
 
@@ -108,6 +111,11 @@ pub struct Codegen<'a> {
     /// For avoiding `;` if the previous statement ends with `}`.
     needs_semicolon: bool,
 
+    /// Start of the current line, for [CodegenOptions::max_line_length].
+    /// Only up to date when `line_scan_end` is the end of the code.
+    line_start: usize,
+    line_scan_end: usize,
+
     prev_op: Option<Operator>,
 
     start_of_stmt: usize,
@@ -123,6 +131,11 @@ pub struct Codegen<'a> {
     // Builders
     comments: CommentsMap,
 
+    trailing_comments: TrailingCommentsMap,
+
+    /// End of the statement being printed, for printing its trailing comments.
+    statement_end: u32,
+
     /// Sorted, deduped `attached_to` keys for pending legal comments. Lets
     /// `print_legal_orphans_before` flush via `partition_point` + `drain`.
     legal_comment_keys: Vec<u32>,
@@ -162,8 +175,11 @@ impl<'a> Codegen<'a> {
             source_text: None,
             scoping: None,
             private_member_mappings: None,
+            property_mappings: None,
             code: CodeBuffer::default(),
             needs_semicolon: false,
+            line_start: 0,
+            line_scan_end: 0,
             need_space_before_dot: 0,
             print_next_indent_as_space: false,
             binary_expr_stack: Stack::with_capacity(12),
@@ -179,6 +195,8 @@ impl<'a> Codegen<'a> {
             indent: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
+            trailing_comments: TrailingCommentsMap::default(),
+            statement_end: 0,
             legal_comment_keys: Vec::new(),
             #[cfg(feature = "sourcemap")]
             sourcemap_builder: None,
@@ -223,9 +241,22 @@ impl<'a> Codegen<'a> {
         self
     }
 
+    /// Set property name mappings for mangling.
+    ///
+    /// This allows renaming of properties like `obj.foo` -> `obj.a` and `{ foo: 1 }` -> `{ a: 1 }`.
+    #[must_use]
+    pub fn with_property_mappings(
+        mut self,
+        mappings: Option<FxHashMap<String, CompactStr>>,
+    ) -> Self {
+        self.property_mappings = mappings;
+        self
+    }
+
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
+    /// It is composed with [`CodegenOptions::input_source_map`] if provided.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
         self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
@@ -241,7 +272,13 @@ impl<'a> Codegen<'a> {
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
         #[cfg(feature = "sourcemap")]
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_map) => SourcemapBuilder::compose(&map, input_map),
+                None => map,
+            }
+        });
         CodegenReturn {
             code,
             #[cfg(feature = "sourcemap")]
@@ -435,11 +472,35 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_semicolon(&mut self) {
         self.print_ascii_byte(b';');
+        self.print_newline_past_line_limit();
     }
 
     #[inline]
     fn print_comma(&mut self) {
         self.print_ascii_byte(b',');
+        self.print_newline_past_line_limit();
+    }
+
+    /// Print a newline if the current line is longer than [CodegenOptions::max_line_length].
+    ///
+    /// Must only be called where a line break can not change the meaning of the code.
+    #[inline]
+    fn print_newline_past_line_limit(&mut self) {
+        if let Some(max_line_length) = self.options.max_line_length
+            && self.options.minify
+            && self.current_line_length() > max_line_length
+        {
+            self.print_hard_newline();
+        }
+    }
+
+    fn current_line_length(&mut self) -> usize {
+        let code = self.code.as_bytes();
+        if let Some(index) = code[self.line_scan_end..].iter().rposition(|&b| b == b'\n') {
+            self.line_start = self.line_scan_end + index + 1;
+        }
+        self.line_scan_end = code.len();
+        code.len() - self.line_start
     }
 
     #[inline]
@@ -530,8 +591,11 @@ impl<'a> Codegen<'a> {
     fn print_semicolon_after_statement(&mut self) {
         if self.options.minify {
             self.needs_semicolon = true;
+            self.print_trailing_comments(self.statement_end);
         } else {
-            self.print_str(";\n");
+            self.print_ascii_byte(b';');
+            self.print_trailing_comments(self.statement_end);
+            self.print_hard_newline();
         }
     }
 
@@ -561,6 +625,7 @@ impl<'a> Codegen<'a> {
     fn print_curly_braces<F: FnOnce(&mut Self)>(&mut self, span: Span, single_line: bool, op: F) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
+        self.print_newline_past_line_limit();
         if !single_line {
             self.print_soft_newline();
             self.indent();
@@ -576,6 +641,7 @@ impl<'a> Codegen<'a> {
     fn print_block_start(&mut self, span: Span) {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
+        self.print_newline_past_line_limit();
         self.print_soft_newline();
         self.indent();
     }
@@ -602,15 +668,33 @@ impl<'a> Codegen<'a> {
                     self.print_hard_space();
                 }
                 self.print_next_indent_as_space = true;
-                stmt.print(self, ctx);
+                self.print_statement(stmt, ctx);
             }
         }
     }
 
+    /// Print a statement, keeping track of its end for its trailing comments.
+    fn print_statement<T: Gen + GetSpan>(&mut self, stmt: &T, ctx: Context) {
+        let statement_end = mem::replace(&mut self.statement_end, stmt.span().end);
+        stmt.print(self, ctx);
+        self.statement_end = statement_end;
+    }
+
     fn print_block_statement(&mut self, stmt: &BlockStatement<'_>, ctx: Context) {
-        let single_line = stmt.body.is_empty() && !self.has_legal_orphans_before(stmt.span.end);
+        let span_end = stmt.span.end;
+        let comments_at_end = if span_end > 0 { self.get_comments(span_end - 1) } else { None };
+        let single_line = stmt.body.is_empty()
+            && !self.has_legal_orphans_before(span_end)
+            && comments_at_end
+                .as_ref()
+                .is_none_or(|comments| comments.iter().all(|c| !c.has_newlines_around()));
         self.print_curly_braces(stmt.span, single_line, |p| {
-            p.print_stmts_with_orphan_flush(&stmt.body, stmt.span.end, ctx);
+            p.print_stmts_with_orphan_flush(&stmt.body, span_end, ctx);
+            // Print comments before the closing brace.
+            if let Some(comments) = comments_at_end {
+                p.print_comments(&comments);
+                p.print_next_indent_as_space = false;
+            }
         });
         self.needs_semicolon = false;
     }
@@ -624,10 +708,12 @@ impl<'a> Codegen<'a> {
     ) {
         for stmt in stmts {
             self.print_legal_orphans_before(stmt.span().start);
+            self.print_trailing_comments_before(stmt.span().start);
             self.print_semicolon_if_needed();
-            stmt.print(self, ctx);
+            self.print_statement(stmt, ctx);
         }
         self.print_legal_orphans_before(scope_end);
+        self.print_trailing_comments_before(scope_end);
     }
 
     fn print_directives_and_statements(
@@ -642,10 +728,12 @@ impl<'a> Codegen<'a> {
         }
         let Some((first, rest)) = stmts.split_first() else {
             self.print_legal_orphans_before(scope_end);
+            self.print_trailing_comments_before(scope_end);
             return;
         };
 
         self.print_legal_orphans_before(first.span().start);
+        self.print_trailing_comments_before(first.span().start);
 
         // Ensure first string literal is not a directive.
         let mut first_needs_parens = false;
@@ -659,37 +747,52 @@ impl<'a> Codegen<'a> {
                 self.print_ascii_byte(b'(');
                 s.print_expr(self, Precedence::Lowest, ctx);
                 self.print_ascii_byte(b')');
+                let statement_end = mem::replace(&mut self.statement_end, first.span().end);
                 self.print_semicolon_after_statement();
+                self.statement_end = statement_end;
             }
         }
 
         if !first_needs_parens {
-            first.print(self, ctx);
+            self.print_statement(first, ctx);
         }
 
         self.print_stmts_with_orphan_flush(rest, scope_end, ctx);
     }
 
     #[inline]
-    fn print_list<T: Gen>(&mut self, items: &[T], ctx: Context) {
+    fn print_list<T: Gen + GetSpan>(&mut self, items: &[T], ctx: Context) {
         let Some((first, rest)) = items.split_first() else {
             return;
         };
         first.print(self, ctx);
         for item in rest {
+            self.print_trailing_comments_before_separator(item.span().start);
             self.print_comma();
             self.print_soft_space();
             item.print(self, ctx);
         }
     }
 
+    /// Print the argument of `return` or `throw`, which must start on the same line.
+    fn print_restricted_expression(&mut self, expr: &Expression<'_>) {
+        let wrap = self.has_multi_line_comment(expr.span().start);
+        self.wrap(wrap, |p| p.print_expression(expr));
+    }
+
     #[inline]
-    fn print_expressions<T: GenExpr>(&mut self, items: &[T], precedence: Precedence, ctx: Context) {
+    fn print_expressions<T: GenExpr + GetSpan>(
+        &mut self,
+        items: &[T],
+        precedence: Precedence,
+        ctx: Context,
+    ) {
         let Some((first, rest)) = items.split_first() else {
             return;
         };
         first.print_expr(self, precedence, ctx);
         for item in rest {
+            self.print_trailing_comments_before_separator(item.span().start);
             self.print_comma();
             self.print_soft_space();
             item.print_expr(self, precedence, ctx);
@@ -699,17 +802,24 @@ impl<'a> Codegen<'a> {
     fn print_arguments(&mut self, span: Span, arguments: &[Argument<'_>], ctx: Context) {
         self.print_ascii_byte(b'(');
 
-        let has_comment_before_right_paren = span.end > 0 && self.has_comment(span.end - 1);
+        // Taken up front, so they are not printed after the last argument.
+        let comments_before_right_paren =
+            if span.end > 0 { self.get_comments(span.end - 1) } else { None };
 
-        let has_comment = has_comment_before_right_paren
+        let has_comment = comments_before_right_paren.is_some()
             || arguments.iter().any(|item| self.has_comment(item.span().start));
 
-        if has_comment {
+        if arguments.is_empty() {
+            // Handle `foo(/* comment */)` on one line.
+            if let Some(comments) = comments_before_right_paren {
+                self.print_inline_comment_list(&comments);
+            }
+        } else if has_comment {
             self.indent();
             self.print_list_with_comments(arguments, ctx);
             // Handle `/* comment */);`
-            if !has_comment_before_right_paren
-                || (span.end > 0 && !self.print_expr_comments(span.end - 1))
+            if !comments_before_right_paren
+                .is_some_and(|comments| self.print_expr_comment_list(&comments))
             {
                 self.print_soft_newline();
             }
@@ -734,6 +844,7 @@ impl<'a> Codegen<'a> {
         }
         first.print(self, ctx);
         for item in rest {
+            self.print_trailing_comments_before_separator(item.span().start);
             self.print_comma();
             if self.print_expr_comments(item.span().start) {
                 self.print_indent();
@@ -767,6 +878,27 @@ impl<'a> Codegen<'a> {
         ident.name.as_str()
     }
 
+    fn get_property_name<'s>(&'s self, name: &'s str) -> &'s str {
+        self.property_mappings
+            .as_ref()
+            .and_then(|mappings| mappings.get(name))
+            .map_or(name, CompactStr::as_str)
+    }
+
+    fn get_mangled_property_name(&self, name: &str) -> Option<CompactStr> {
+        self.property_mappings.as_ref().and_then(|mappings| mappings.get(name)).cloned()
+    }
+
+    fn print_property_name(&mut self, ident: &IdentifierName<'_>) {
+        let Some(mangled) = self.get_mangled_property_name(ident.name.as_str()) else {
+            ident.print(self, Context::empty());
+            return;
+        };
+        self.print_space_before_identifier();
+        self.add_source_mapping_for_name(ident.span, &ident.name);
+        self.print_str(mangled.as_str());
+    }
+
     fn print_space_before_operator(&mut self, next: Operator) {
         if self.prev_op_end != self.code.len() {
             return;
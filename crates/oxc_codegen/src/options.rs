@@ -25,10 +25,20 @@ pub struct CodegenOptions {
     /// Enable sourcemap.
     ///
     /// The provided path sets the `source` field in the returned sourcemap.
+    /// `//# sourceMappingURL=` comments of the input are not printed.
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
 
+    /// Source map of the input source text, e.g. from a previous compilation step.
+    ///
+    /// When set, the generated sourcemap is composed with it,
+    /// so it maps the output back to the original sources of the input map.
+    ///
+    /// Default is `None`.
+    #[cfg(feature = "sourcemap")]
+    pub input_source_map: Option<oxc_sourcemap::SourceMap>,
+
     /// Indentation character.
     ///
     /// Default is [`IndentChar::Tab`].
@@ -43,6 +53,26 @@ pub struct CodegenOptions {
     ///
     /// Default is `0`.
     pub initial_indent: u32,
+
+    /// Break lines longer than this number of bytes when [`CodegenOptions::minify`] is enabled.
+    ///
+    /// Lines are only broken after `,`, `;` and `{`, so a line can still be longer
+    /// than this if it does not contain any of them, e.g. a long string.
+    ///
+    /// Default is `None` - lines are not broken.
+    pub max_line_length: Option<usize>,
+
+    /// Escape all non-ASCII characters, so the output does not depend on the charset it is served with.
+    ///
+    /// * strings, template literals and regexes: `\xE9`, `\u2028`, `\u{1F600}` or `\uD83D\uDE00`
+    /// * identifiers: `\u00E9`
+    /// * JSX text and attributes: `&#xE9;`
+    ///
+    /// Raw strings of tagged templates and JSX element names can not be escaped,
+    /// so they are printed as is.
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
 }
 
 impl Default for CodegenOptions {
@@ -52,9 +82,13 @@ impl Default for CodegenOptions {
             minify: false,
             comments: CommentOptions::default(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            max_line_length: None,
+            ascii_only: false,
         }
     }
 }
@@ -67,9 +101,13 @@ impl CodegenOptions {
             minify: true,
             comments: CommentOptions::disabled(),
             source_map_path: None,
+            #[cfg(feature = "sourcemap")]
+            input_source_map: None,
             indent_char: IndentChar::default(),
             indent_width: DEFAULT_INDENT_WIDTH,
             initial_indent: 0,
+            max_line_length: None,
+            ascii_only: false,
         }
     }
 
@@ -99,7 +137,9 @@ impl CodegenOptions {
 pub struct CommentOptions {
     /// Print normal comments that do not have special meanings.
     ///
-    /// At present only statement level comments are printed.
+    /// Comments inside expressions, object literals, JSX and type annotations are printed
+    /// on the same line as their node. Line comments in the middle of a line are printed
+    /// as block comments.
     ///
     /// Default is `true`.
     pub normal: bool,
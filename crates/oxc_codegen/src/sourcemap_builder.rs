@@ -1,8 +1,9 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use oxc_index::{IndexVec, define_nonmax_u32_index_type};
 use oxc_span::Span;
 use oxc_syntax::line_terminator::{LS, LS_LAST_2_BYTES, LS_OR_PS_FIRST_BYTE, PS, PS_LAST_2_BYTES};
+use rustc_hash::FxHashMap;
 
 /// Number of lines to check with linear search when translating byte position to line index
 const LINE_SEARCH_LINEAR_ITERATIONS: usize = 16;
@@ -84,6 +85,54 @@ impl<'a> SourcemapBuilder<'a> {
         self.sourcemap_builder.into_sourcemap()
     }
 
+    /// Compose `map` with `input_map`, the source map of the source text `map` was generated from.
+    ///
+    /// Every token of `map` is traced back through `input_map` to its original position.
+    /// Tokens which do not map to an original source are dropped.
+    #[expect(clippy::cast_possible_truncation)]
+    pub fn compose(
+        map: &oxc_sourcemap::SourceMap,
+        input_map: &oxc_sourcemap::SourceMap,
+    ) -> oxc_sourcemap::SourceMap {
+        let lookup_table = input_map.generate_lookup_table();
+        let mut names = input_map.get_names().cloned().collect::<Vec<_>>();
+        let mut name_ids = FxHashMap::default();
+        let mut tokens = vec![];
+        for token in map.get_tokens() {
+            let Some(original) =
+                input_map.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+            else {
+                continue;
+            };
+            let Some(source_id) = original.get_source_id() else { continue };
+            // Prefer the original name, which may have been renamed by the input transform.
+            let name_id = original.get_name_id().or_else(|| {
+                let name = map.get_name(token.get_name_id()?)?;
+                Some(*name_ids.entry(Arc::clone(name)).or_insert_with(|| {
+                    names.push(Arc::clone(name));
+                    names.len() as u32 - 1
+                }))
+            });
+            tokens.push(oxc_sourcemap::Token::new(
+                token.get_dst_line(),
+                token.get_dst_col(),
+                original.get_src_line(),
+                original.get_src_col(),
+                Some(source_id),
+                name_id,
+            ));
+        }
+        oxc_sourcemap::SourceMap::new(
+            map.get_file().cloned(),
+            names,
+            input_map.get_source_root().map(ToString::to_string),
+            input_map.get_sources().cloned().collect(),
+            input_map.get_source_contents().map(|content| content.map(Arc::clone)).collect(),
+            tokens.into_boxed_slice(),
+            None,
+        )
+    }
+
     pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
         debug_assert!(
             (span.end as usize) <= self.original_source.len(),
@@ -1,10 +1,10 @@
-use std::slice;
+use std::{fmt::Write, slice};
 
 use oxc_ast::ast::StringLiteral;
 use oxc_data_structures::{assert_unchecked, slice_iter::SliceIter};
 use oxc_syntax::{
     identifier::NBSP,
-    line_terminator::{LS_LAST_2_BYTES, PS_LAST_2_BYTES},
+    line_terminator::{LS, LS_LAST_2_BYTES, PS, PS_LAST_2_BYTES},
 };
 
 use crate::Codegen;
@@ -45,6 +45,9 @@ impl Codegen<'_> {
             Some(quote)
         };
 
+        // With `ascii_only` option, all non-ASCII characters need to be escaped too.
+        let escapes = if self.options.ascii_only { &ESCAPES_ASCII_ONLY.0 } else { &ESCAPES.0 };
+
         // Loop through bytes, looking for any which need to be escaped.
         // String is written to buffer in chunks.
         let bytes = s.value.as_bytes().iter();
@@ -59,7 +62,7 @@ impl Codegen<'_> {
         // Loop through bytes.
         while let Some(b) = state.peek() {
             // Look up whether byte needs escaping
-            let escape = ESCAPES.0[b as usize];
+            let escape = escapes[b as usize];
             if escape == Escape::__ {
                 // No escape required.
                 // SAFETY: We just checked there's a byte to consume.
@@ -89,6 +92,63 @@ impl Codegen<'_> {
         let quote = unsafe { state.quote.unwrap_unchecked() };
         quote.print(self);
     }
+
+    /// Print the name of an identifier.
+    ///
+    /// With `ascii_only` option, non-ASCII characters are printed as `\u` escapes.
+    pub(crate) fn print_identifier(&mut self, name: &str) {
+        if !self.options.ascii_only || name.is_ascii() {
+            self.print_str(name);
+            return;
+        }
+        let escaped = escape_non_ascii(name, UnicodeEscape::Identifier);
+        self.print_str(&escaped);
+        // `\u{10000}` followed by an identifier would be a single identifier.
+        // Force a space before next identifier, same as after a regex.
+        if escaped.ends_with('}') {
+            self.prev_reg_exp_end = self.code.len();
+        }
+    }
+
+    /// Print raw source text, e.g. of a template literal or a comment, escaping `</script`.
+    ///
+    /// With `ascii_only` option, non-ASCII characters are escaped too.
+    pub(crate) fn print_raw_str(&mut self, s: &str, escape: UnicodeEscape) {
+        if self.options.ascii_only && !s.is_ascii() {
+            self.print_str_escaping_script_close_tag(&escape_non_ascii(s, escape));
+        } else {
+            self.print_str_escaping_script_close_tag(s);
+        }
+    }
+
+    /// Print the pattern of a regular expression literal, escaping `</script`.
+    ///
+    /// With `ascii_only` option, non-ASCII characters are escaped too.
+    /// `unicode_sets` is `true` if the regex has the `v` flag.
+    pub(crate) fn print_regexp_pattern(
+        &mut self,
+        pattern: &str,
+        escape: UnicodeEscape,
+        unicode_sets: bool,
+    ) {
+        if self.options.ascii_only && !pattern.is_ascii() {
+            let escaped = escape_non_ascii_regexp(pattern, escape, unicode_sets);
+            self.print_str_escaping_script_close_tag(&escaped);
+        } else {
+            self.print_str_escaping_script_close_tag(pattern);
+        }
+    }
+
+    /// Print JSX text or a JSX attribute string.
+    ///
+    /// With `ascii_only` option, non-ASCII characters are printed as HTML character references.
+    pub(crate) fn print_jsx_str(&mut self, s: &str) {
+        if self.options.ascii_only && !s.is_ascii() {
+            self.print_str(&escape_non_ascii_jsx(s));
+        } else {
+            self.print_str(s);
+        }
+    }
 }
 
 /// String printer state.
@@ -329,6 +389,7 @@ enum Escape {
     LS = 15, // LS/PS - U+2028 LINE SEPARATOR or U+2029 PARAGRAPH SEPARATOR (first byte)
     NB = 16, // NBSP  - Non-breaking space (first byte)
     LO = 17, // �     - U+FFFD lossy replacement character (first byte)
+    NA = 18, // Any non-ASCII character (first byte), only with `ascii_only` option
 }
 
 /// Struct which ensures content is aligned on 128.
@@ -340,10 +401,25 @@ struct Aligned128<T>(T);
 /// Aligned on 128, so top half (ASCII chars) occupies a pair of L1 cache lines.
 /// Bottom half (non-ASCII chars) also occupies a pair of L1 cache lines,
 /// but will not be accessed for strings which only contain ASCII (common case).
-static ESCAPES: Aligned128<[Escape; 256]> = {
+static ESCAPES: Aligned128<[Escape; 256]> = Aligned128(ESCAPES_TABLE);
+
+/// Table mapping bytes to `Escape`s, with `ascii_only` option.
+///
+/// Same as `ESCAPES`, except the first bytes of all non-ASCII characters are `Escape::NA`.
+static ESCAPES_ASCII_ONLY: Aligned128<[Escape; 256]> = {
+    let mut table = ESCAPES_TABLE;
+    let mut b = 0xC0;
+    while b < 256 {
+        table[b] = Escape::NA;
+        b += 1;
+    }
+    Aligned128(table)
+};
+
+const ESCAPES_TABLE: [Escape; 256] = {
     #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
     use Escape::*;
-    Aligned128([
+    [
         //   1   2   3   4   5   6   7   8   9   A   B   C   D   E   F
         NU, __, __, __, __, __, __, BE, BK, __, NL, VT, FF, CR, __, __, // 0
         __, __, __, __, __, __, __, __, __, __, __, ES, __, __, __, __, // 1
@@ -361,7 +437,7 @@ static ESCAPES: Aligned128<[Escape; 256]> = {
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // D
         __, __, LS, __, __, __, __, __, __, __, __, __, __, __, __, LO, // E
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // F
-    ])
+    ]
 };
 
 type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
@@ -371,10 +447,10 @@ type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
 /// Indexed by `escape as usize - 1` (where `escape` is not `Escape::__`).
 /// Must be in same order as discriminants in `Escape`.
 ///
-/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 136 bytes in total.
+/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 144 bytes in total.
 /// Aligned on 128, so first 16 occupy a pair of L1 cache lines.
-/// The last will be in separate cache line, but it should be vanishingly rare that it's accessed.
-static BYTE_HANDLERS: Aligned128<[ByteHandler; 17]> = Aligned128([
+/// The last 2 will be in separate cache line, but it should be vanishingly rare that they're accessed.
+static BYTE_HANDLERS: Aligned128<[ByteHandler; 18]> = Aligned128([
     print_null,
     print_bell,
     print_backspace,
@@ -392,6 +468,7 @@ static BYTE_HANDLERS: Aligned128<[ByteHandler; 17]> = Aligned128([
     print_ls_or_ps,
     print_non_breaking_space,
     print_lossy_replacement,
+    print_non_ascii,
 ]);
 
 /// Call byte handler for byte which needs escaping.
@@ -699,6 +776,161 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
     unsafe { state.consume_bytes_unchecked(3) };
 }
 
+// Any non-ASCII character, with `ascii_only` option
+unsafe fn print_non_ascii(codegen: &mut Codegen, state: &mut PrintStringState) {
+    let slice = state.bytes.as_slice();
+    // SAFETY: `bytes` iterator is always positioned on a UTF-8 character boundary of a `&str`
+    let ch = unsafe { str::from_utf8_unchecked(slice) }.chars().next().unwrap();
+
+    if ch == '\u{FFFD}' && state.lone_surrogates {
+        // Lone surrogate or lossy replacement character, encoded as `\u{FFFD}XXXX`.
+        // See `print_lossy_replacement`.
+        let hex: [u8; 4] = slice[3..7].try_into().unwrap();
+        // Check all 4 hex bytes are ASCII
+        assert_eq!(u32::from_ne_bytes(hex) & 0x8080_8080, 0);
+        // SAFETY: `slice[3..7]` would have panicked if there weren't at least 7 bytes remaining.
+        // First 3 bytes are lossy replacement character, and we just checked that
+        // next 4 bytes are ASCII, so this leaves `bytes` on a UTF-8 char boundary.
+        unsafe { state.flush_and_consume_bytes(codegen, 7) };
+        codegen.print_str("\\u");
+        // SAFETY: Just checked all 4 hex bytes are ASCII
+        unsafe { codegen.code.print_bytes_unchecked(&hex) };
+        return;
+    }
+
+    // SAFETY: Consuming the whole character leaves `bytes` on a UTF-8 char boundary
+    unsafe { state.flush_and_consume_bytes(codegen, ch.len_utf8()) };
+    let mut escaped = String::new();
+    push_unicode_escape(&mut escaped, ch, UnicodeEscape::SurrogatePairs);
+    codegen.print_str(&escaped);
+}
+
+/// How to escape a non-ASCII character with [`CodegenOptions::ascii_only`] option.
+///
+/// [`CodegenOptions::ascii_only`]: crate::CodegenOptions::ascii_only
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum UnicodeEscape {
+    /// `\xE9`, `\u2028` and `\uD83D\uDE00`. For strings, and regexes without `u` or `v` flag.
+    SurrogatePairs,
+    /// `\xE9`, `\u2028` and `\u{1F600}`. For templates, and regexes with `u` or `v` flag.
+    CodePoints,
+    /// `\u00E9`, `\u2028` and `\u{1F600}`. Identifiers do not allow `\x` escapes.
+    Identifier,
+}
+
+/// Push an escape sequence for non-ASCII character `ch` to `out`.
+fn push_unicode_escape(out: &mut String, ch: char, escape: UnicodeEscape) {
+    let code_point = ch as u32;
+    if code_point <= 0xFF && escape != UnicodeEscape::Identifier {
+        write!(out, "\\x{code_point:02X}").unwrap();
+    } else if code_point <= 0xFFFF {
+        write!(out, "\\u{code_point:04X}").unwrap();
+    } else if escape == UnicodeEscape::SurrogatePairs {
+        let mut units = [0; 2];
+        for unit in ch.encode_utf16(&mut units) {
+            write!(out, "\\u{unit:04X}").unwrap();
+        }
+    } else {
+        write!(out, "\\u{{{code_point:X}}}").unwrap();
+    }
+}
+
+/// Escape all non-ASCII characters in raw source text, e.g. of a template literal or a regex.
+///
+/// A non-ASCII character after `\` is an identity escape, so the `\` is replaced along with it,
+/// except for a line continuation (`\` followed by `<LS>` or `<PS>`), which is removed.
+fn escape_non_ascii(text: &str, escape: UnicodeEscape) -> String {
+    let mut out = String::with_capacity(text.len() + 16);
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some(LS | PS) => {}
+                Some(next) if !next.is_ascii() => push_unicode_escape(&mut out, next, escape),
+                Some(next) => {
+                    out.push('\\');
+                    out.push(next);
+                }
+                None => out.push('\\'),
+            },
+            ch if ch.is_ascii() => out.push(ch),
+            ch => push_unicode_escape(&mut out, ch, escape),
+        }
+    }
+    out
+}
+
+/// Escape all non-ASCII characters in a regex pattern.
+///
+/// Same as [`escape_non_ascii`], except that group names in `(?<name>...)` and `\k<name>` only allow
+/// `\u` escapes, so they are escaped like identifiers. Character classes are skipped when looking
+/// for group names, as `(?<` is not a group inside them. With `v` flag, classes can be nested.
+fn escape_non_ascii_regexp(pattern: &str, escape: UnicodeEscape, unicode_sets: bool) -> String {
+    let mut out = String::with_capacity(pattern.len() + 16);
+    let mut chars = pattern.chars();
+    let mut class_depth = 0u32;
+    let mut in_group_name = false;
+    while let Some(ch) = chars.next() {
+        let escape = if in_group_name { UnicodeEscape::Identifier } else { escape };
+        match ch {
+            '\\' => match chars.next() {
+                Some(LS | PS) => {}
+                Some(next) if !next.is_ascii() => push_unicode_escape(&mut out, next, escape),
+                Some(next) => {
+                    out.push('\\');
+                    out.push(next);
+                    // `\k<name>`
+                    if next == 'k' && class_depth == 0 && chars.as_str().starts_with('<') {
+                        chars.next();
+                        out.push('<');
+                        in_group_name = true;
+                    }
+                }
+                None => out.push('\\'),
+            },
+            '[' if !in_group_name && (class_depth == 0 || unicode_sets) => {
+                class_depth += 1;
+                out.push(ch);
+            }
+            ']' if !in_group_name && class_depth > 0 => {
+                class_depth -= 1;
+                out.push(ch);
+            }
+            // `(?<name>`, but not lookbehind `(?<=` or `(?<!`
+            '(' if !in_group_name && class_depth == 0 => {
+                out.push(ch);
+                let rest = chars.as_str();
+                if rest.starts_with("?<") && !rest[2..].starts_with(['=', '!']) {
+                    chars.next();
+                    chars.next();
+                    out.push_str("?<");
+                    in_group_name = true;
+                }
+            }
+            '>' if in_group_name => {
+                in_group_name = false;
+                out.push(ch);
+            }
+            ch if ch.is_ascii() => out.push(ch),
+            ch => push_unicode_escape(&mut out, ch, escape),
+        }
+    }
+    out
+}
+
+/// Escape all non-ASCII characters in JSX text or a JSX attribute string with HTML character references.
+fn escape_non_ascii_jsx(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 16);
+    for ch in text.chars() {
+        if ch.is_ascii() {
+            out.push(ch);
+        } else {
+            write!(out, "&#x{:X};", ch as u32).unwrap();
+        }
+    }
+    out
+}
+
 /// Call a closure while hinting to compiler that this branch is rarely taken.
 ///
 /// "Cold trampoline function", suggested in:
@@ -40,6 +40,8 @@ fn unit() {
         "function foo() {\n\treturn (\t/*i18n*/ {});\n}\n",
     );
 
+    test_same("const el = <div /* attr */ id=\"x\">{/* child */}</div>;\nlet t = 1;\n");
+
     test_same("export { /** @deprecated */ parseAst } from \"rolldown/parseAst\";\n");
     test_same("export { /** @deprecated */ parseAst };\n");
     test_same("export { parseAst as /** @deprecated */ b } from \"rolldown/parseAst\";\n");
@@ -57,6 +59,56 @@ pub mod misc_comments {
     }
 }
 
+pub mod inline_comments {
+    use crate::{snapshot, test_idempotency};
+
+    fn cases() -> Vec<&'static str> {
+        vec![
+            "const a = /* value */ 1; // trailing a",
+            "const o = {\n  // leading prop\n  x: 1, // trailing x\n  /* before y */ y: /* in y */ 2,\n};",
+            "const arr = [/* first */ 1, 2 /* after 2 */];",
+            "const list = [\n  1, // one\n  2, // two\n  3, // three\n];",
+            "foo(/* arg */ a, b);",
+            "foo(a, // first arg\n  b);",
+            "function f(/* param */ p) {\n  return /* ret */ p + /* right */ 1;\n}",
+            "function g() {\n  return (/*\n   multi */ a);\n}",
+            "function* h() {\n  yield (/*\n   multi */ b);\n}",
+            "let t: /* type */ string = '';",
+            "if (a /* cond */) {}",
+            "x = a ? /* yes */ b : /* no */ c;",
+            "let v = a /* x */ + b;",
+            "const { p /* q */ } = o;",
+            "type T = { a: string /* s */ };",
+            "class A {\n  x = 1; // field\n  m() {} // method\n}",
+            "if (x) {} // after if\nfoo();",
+            "export default /* d */ function () {}",
+            "(() => /* c */ ({}));",
+            "(/* c */ function () {})();",
+            "x = a ? // first line\n  // second line\n  b : c;",
+            "foo(a, // first\n  // second\n  b);",
+            "if (a) /* then */ {} else /* else */ {}",
+            "x = [ /* hole */ , 1, /* second hole */ , 2];",
+            "const list = [\n  // leading one\n  1,\n  /* before two */ 2,\n  3,\n];",
+            "foo(/* no args */);",
+            "new Foo(// no args\n);",
+            // Line comments in the middle of a line are printed as block comments.
+            "foo(a // line\n, b);",
+        ]
+    }
+
+    #[test]
+    fn comment() {
+        snapshot("inline_comments", &cases());
+    }
+
+    #[test]
+    fn idempotency() {
+        for case in cases() {
+            test_idempotency(case);
+        }
+    }
+}
+
 pub mod jsdoc {
     use crate::snapshot;
 
@@ -218,7 +270,7 @@ catch (err) /* v8 ignore next */ { console.error(err); }",
 catch (err) /* c8 ignore next */ /* istanbul ignore next */ { handle(err); }",
             // Line comment between catch param and body.
             // NOTE: Line comments after `)` are classified as trailing comments by the parser,
-            // so they are not preserved. Use block comments instead.
+            // so they are printed at the start of the body instead.
             // See: https://github.com/oxc-project/oxc/pull/16167#discussion_r2567604139
             "try { something(); }
 catch (err) // v8 ignore next
@@ -655,6 +655,33 @@ fn v8_intrinsics() {
     );
 }
 
+#[test]
+fn proposals() {
+    let parse_opts = oxc_parser::ParseOptions {
+        allow_pipeline_operator: true,
+        allow_do_expressions: true,
+        allow_throw_expressions: true,
+        ..oxc_parser::ParseOptions::default()
+    };
+
+    test_with_parse_options("x |> f(%) |> g(%, %)", "x |> f(%) |> g(%, %);\n", parse_opts);
+    test_with_parse_options("(x |> f(%)) |> g(%)", "(x |> f(%)) |> g(%);\n", parse_opts);
+    test_with_parse_options("x |> (% ? a : b)", "x |> (% ? a : b);\n", parse_opts);
+    test_with_parse_options("a = x |> % + 1", "a = x |> % + 1;\n", parse_opts);
+    test_with_parse_options(
+        "let a = do { if (x) { 1 } else { 2 } }",
+        "let a = do {\n\tif (x) {\n\t\t1;\n\t} else {\n\t\t2;\n\t}\n};\n",
+        parse_opts,
+    );
+    test_with_parse_options("(do { 1 })", "(do {\n\t1;\n});\n", parse_opts);
+    test_with_parse_options(
+        "f(x ?? throw new Error())",
+        "f(x ?? throw new Error());\n",
+        parse_opts,
+    );
+    test_with_parse_options("(throw a) + 1", "(throw a) + 1;\n", parse_opts);
+}
+
 #[test]
 fn indentation() {
     // Test default - tabs with width 1
@@ -705,6 +732,74 @@ fn indentation() {
     );
 }
 
+#[test]
+fn max_line_length() {
+    let options = CodegenOptions { max_line_length: Some(10), ..CodegenOptions::minify() };
+    test_options(
+        "foo(a, b, c, d, e, f); bar(); if (x) { baz() }",
+        "foo(a,b,c,d,\ne,f);bar();\nif(x){baz()}",
+        options.clone(),
+    );
+    // Strings and `return` arguments are not broken
+    test_options(
+        "function f() { return 'a long string, which is not broken' }",
+        "function f(){\nreturn`a long string, which is not broken`}",
+        options,
+    );
+    // Only minified output is broken
+    test_options(
+        "foo(a, b, c, d, e, f);",
+        "foo(a, b, c, d, e, f);\n",
+        CodegenOptions { max_line_length: Some(10), ..CodegenOptions::default() },
+    );
+}
+
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    // Strings
+    test_options(
+        "x = 'café ☕ 😀';",
+        "x = \"caf\\xE9 \\u2615 \\uD83D\\uDE00\";\n",
+        options.clone(),
+    );
+    test_options(r"x = '\u00a0\u2028';", "x = \"\\xA0\\u2028\";\n", options.clone());
+    test_options(r"x = '\ud800';", "x = \"\\ud800\";\n", options.clone());
+    // Identifiers
+    test_options(
+        "let café = 1, 𐊧 = 2; café + 𐊧 in o;",
+        "let caf\\u00E9 = 1, \\u{102A7} = 2;\ncaf\\u00E9 + \\u{102A7} in o;\n",
+        options.clone(),
+    );
+    test_options(
+        "𐊧 in o;",
+        "\\u{102A7} in o;",
+        CodegenOptions { ascii_only: true, ..CodegenOptions::minify() },
+    );
+    test_options("class A { #é = 1 }", "class A {\n\t#\\u00E9 = 1;\n}\n", options.clone());
+    // Templates
+    test_options("x = `é${a}\\é😀`;", "x = `\\xE9${a}\\xE9\\u{1F600}`;\n", options.clone());
+    test_options("x = tag`é`;", "x = tag`é`;\n", options.clone());
+    // Regexes
+    test_options(
+        "x = /é😀/g; y = /é😀/u; z = /\\é/;",
+        "x = /\\xE9\\uD83D\\uDE00/g;\ny = /\\xE9\\u{1F600}/u;\nz = /\\xE9/;\n",
+        options.clone(),
+    );
+    // Group names only allow `\u` escapes
+    test_options(
+        "x = /(?<café>é)\\k<café>/; y = /(?<𝑥>[(?<é>])\\k<𝑥>/u; z = /[[é]](?<é>)/v; w = /(?<=é)(?<!é)/;",
+        "x = /(?<caf\\u00E9>\\xE9)\\k<caf\\u00E9>/;\ny = /(?<\\u{1D465}>[(?<\\xE9>])\\k<\\u{1D465}>/u;\nz = /[[\\xE9]](?<\\u00E9>)/v;\nw = /(?<=\\xE9)(?<!\\xE9)/;\n",
+        options.clone(),
+    );
+    // JSX
+    test_options("<a title='é'>é</a>;", "<a title=\"&#xE9;\">&#xE9;</a>;\n", options.clone());
+    // Comments
+    test_options("// é\nx;", "// \\xE9\nx;\n", options);
+    // Output is unchanged without the option
+    test("x = 'é';", "x = \"é\";\n");
+}
+
 #[test]
 fn template_literal_escape_when_building_ast() {
     use oxc_ast::ast::TemplateElementValue;
@@ -195,6 +195,90 @@ fn class_member_mappings_start_before_member_keys() {
     assert_member_start_maps_before_key(&tokens, pos(5, 2), pos(5, 9));
 }
 
+#[test]
+fn mappings_after_line_breaks() {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, "foo(a, b, c, d, e, f);\nbar();", SourceType::mjs()).parse();
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("test.js")),
+            max_line_length: Some(10),
+            ..CodegenOptions::minify()
+        })
+        .build(&ret.program);
+    assert_eq!(ret.code, "foo(a,b,c,d,\ne,f);bar();\n");
+    let tokens = ret
+        .map
+        .unwrap()
+        .get_tokens()
+        .map(|token| Mapping {
+            dst: pos(token.get_dst_line(), token.get_dst_col()),
+            src: pos(token.get_src_line(), token.get_src_col()),
+        })
+        .collect::<Vec<_>>();
+    // `e` and `bar`
+    assert!(has_mapping(&tokens, pos(0, 16), pos(1, 0)));
+    assert!(has_mapping(&tokens, pos(1, 0), pos(1, 5)));
+}
+
+#[test]
+fn drop_input_source_map_url() {
+    let source_text =
+        "log(1);\n//# sourceMappingURL=input.js.map\n//@ sourceMappingURL=input.js.map\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let code = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("input.js")),
+            ..Default::default()
+        })
+        .build(&ret.program)
+        .code;
+    assert_eq!(code, "log(1);\n");
+
+    // Kept when no sourcemap is generated.
+    let code = Codegen::new().build(&ret.program).code;
+    assert!(code.contains("//# sourceMappingURL=input.js.map"));
+}
+
+#[test]
+fn compose_with_input_source_map() {
+    // `original.js` was reformatted to `input.js`, which is minified here.
+    let original_text = "// comment\n\n  const value = 1;\n  log(value);\n";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, original_text, SourceType::mjs()).parse();
+    let input = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("original.js")),
+            ..Default::default()
+        })
+        .build(&ret.program);
+
+    let ret = Parser::new(&allocator, &input.code, SourceType::mjs()).parse();
+    let map = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some(PathBuf::from("input.js")),
+            input_source_map: input.map,
+            ..CodegenOptions::minify()
+        })
+        .build(&ret.program)
+        .map
+        .unwrap();
+
+    assert_eq!(map.get_sources().map(AsRef::as_ref).collect::<Vec<&str>>(), ["original.js"]);
+    assert_eq!(map.get_source_content(0).map(AsRef::as_ref), Some(original_text));
+    let tokens = map
+        .get_tokens()
+        .map(|token| Mapping {
+            dst: pos(token.get_dst_line(), token.get_dst_col()),
+            src: pos(token.get_src_line(), token.get_src_col()),
+        })
+        .collect::<Vec<_>>();
+    // `const value` and `log(value)` map to their original positions.
+    assert!(has_mapping(&tokens, pos(2, 2), pos(0, 0)));
+    assert!(tokens.iter().any(|token| token.src.line == 3 && token.src.col == 2));
+}
+
 #[test]
 #[cfg(all(not(target_endian = "big"), target_pointer_width = "64"))] // we run big endian tests on docker that does not have node installed; skip 32-bit as well
 fn stacktrace_is_correct() {
@@ -64,7 +64,7 @@ pub type Severity = miette::Severity;
 pub type Result<T> = std::result::Result<T, OxcDiagnostic>;
 
 use miette::{Diagnostic, SourceCode};
-pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource};
+pub use miette::{GraphicalReportHandler, GraphicalTheme, LabeledSpan, NamedSource, SourceSpan};
 
 /// Describes an error or warning that occurred.
 ///
@@ -123,6 +123,18 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    pub fixes: Option<Vec<DiagnosticFix>>,
+}
+
+/// A suggested edit of the source code which resolves a diagnostic.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    /// The portion of source code to replace.
+    pub span: SourceSpan,
+    /// The replacement text. Empty for deletions.
+    pub content: Cow<'static, str>,
+    /// A brief description of the edit.
+    pub message: Option<Cow<'static, str>>,
 }
 
 impl Display for OxcDiagnostic {
@@ -188,6 +200,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fixes: None,
             }),
         }
     }
@@ -203,6 +216,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fixes: None,
             }),
         }
     }
@@ -362,11 +376,29 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Attach the edits which resolve this diagnostic, e.g. the fixes of a lint rule.
+    pub fn with_fixes<T: IntoIterator<Item = DiagnosticFix>>(mut self, fixes: T) -> Self {
+        let fixes = fixes.into_iter().collect::<Vec<_>>();
+        self.inner.fixes = if fixes.is_empty() { None } else { Some(fixes) };
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+
+    /// Get the [`OxcDiagnostic`] an [`Error`] was created from, if any.
+    ///
+    /// Useful for reporters which need data not exposed by the [`Diagnostic`] trait, such as
+    /// [fixes](OxcDiagnosticInner::fixes).
+    pub fn downcast_ref(error: &Error) -> Option<&Self> {
+        error
+            .downcast_ref::<WithSourceCode>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
     }
 
     /// Consumes the diagnostic and returns the inner owned data.
@@ -374,3 +406,56 @@ impl OxcDiagnostic {
         *self.inner
     }
 }
+
+/// An [`OxcDiagnostic`] with its source code, created by [`OxcDiagnostic::with_source_code`].
+///
+/// Unlike [`Error::with_source_code`], the diagnostic can still be retrieved from the resulting
+/// [`Error`] with [`OxcDiagnostic::downcast_ref`].
+struct WithSourceCode {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn note<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.note()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+}
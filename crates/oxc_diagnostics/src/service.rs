@@ -122,19 +122,7 @@ impl DiagnosticService {
         source_text: &str,
         diagnostics: Vec<OxcDiagnostic>,
     ) -> Vec<Error> {
-        // TODO: This causes snapshots to fail when running tests through a JetBrains terminal.
-        let is_jetbrains =
-            std::env::var("TERMINAL_EMULATOR").is_ok_and(|x| x.eq("JetBrains-JediTerm"));
-
-        let path_ref = path.as_ref();
-        let path_display = if is_jetbrains { from_file_path(path_ref) } else { None }
-            .unwrap_or_else(|| {
-                let relative_path =
-                    path_ref.strip_prefix(cwd).unwrap_or(path_ref).to_string_lossy();
-                let normalized_path = relative_path.cow_replace('\\', "/");
-                normalized_path.to_string()
-            });
-
+        let path_display = Self::source_name(cwd, path);
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         diagnostics
             .into_iter()
@@ -142,6 +130,21 @@ impl DiagnosticService {
             .collect()
     }
 
+    /// The name given to the source code of diagnostics wrapped by
+    /// [`wrap_diagnostics`](DiagnosticService::wrap_diagnostics) for `path`.
+    pub fn source_name<C: AsRef<Path>, P: AsRef<Path>>(cwd: C, path: P) -> String {
+        // TODO: This causes snapshots to fail when running tests through a JetBrains terminal.
+        let is_jetbrains =
+            std::env::var("TERMINAL_EMULATOR").is_ok_and(|x| x.eq("JetBrains-JediTerm"));
+
+        let path_ref = path.as_ref();
+        if is_jetbrains { from_file_path(path_ref) } else { None }.unwrap_or_else(|| {
+            let relative_path = path_ref.strip_prefix(cwd).unwrap_or(path_ref).to_string_lossy();
+            let normalized_path = relative_path.cow_replace('\\', "/");
+            normalized_path.to_string()
+        })
+    }
+
     /// # Panics
     ///
     /// * When the writer fails to write
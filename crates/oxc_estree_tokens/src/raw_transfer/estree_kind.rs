@@ -1,6 +1,6 @@
 //! [`ESTreeKind`] — compact token kind for raw transfer to JS.
 //!
-//! Maps the full [`Kind`] enum (169 variants) to a small set of 12 ESTree token types.
+//! Maps the full [`Kind`] enum (170 variants) to a small set of 12 ESTree token types.
 //!
 //! ## How it works
 //!
@@ -37,7 +37,7 @@ pub enum ESTreeKind {
 const KINDS_LEN: usize = Kind::VARIANTS.len();
 
 // Verify number of `Kind` variants, so we catch if new variants are added
-const _: () = assert!(KINDS_LEN == 169);
+const _: () = assert!(KINDS_LEN == 170);
 
 // Verify that the `Kind` discriminants we rely on for `to_kind` haven't shifted.
 // If any of these assertions fail, the `to_kind` mapping needs updating.
@@ -120,6 +120,10 @@ pub enum AstNodes<'a> {
     ExportAllDeclaration(&'a AstNode<'a, ExportAllDeclaration<'a>>),
     ExportSpecifier(&'a AstNode<'a, ExportSpecifier<'a>>),
     V8IntrinsicExpression(&'a AstNode<'a, V8IntrinsicExpression<'a>>),
+    DoExpression(&'a AstNode<'a, DoExpression<'a>>),
+    PipelineExpression(&'a AstNode<'a, PipelineExpression<'a>>),
+    TopicReference(&'a AstNode<'a, TopicReference>),
+    ThrowExpression(&'a AstNode<'a, ThrowExpression<'a>>),
     BooleanLiteral(&'a AstNode<'a, BooleanLiteral>),
     NullLiteral(&'a AstNode<'a, NullLiteral>),
     NumericLiteral(&'a AstNode<'a, NumericLiteral<'a>>),
@@ -314,6 +318,10 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(n) => n.span(),
             Self::ExportSpecifier(n) => n.span(),
             Self::V8IntrinsicExpression(n) => n.span(),
+            Self::DoExpression(n) => n.span(),
+            Self::PipelineExpression(n) => n.span(),
+            Self::TopicReference(n) => n.span(),
+            Self::ThrowExpression(n) => n.span(),
             Self::BooleanLiteral(n) => n.span(),
             Self::NullLiteral(n) => n.span(),
             Self::NumericLiteral(n) => n.span(),
@@ -508,6 +516,10 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(n) => n.parent(),
             Self::ExportSpecifier(n) => n.parent(),
             Self::V8IntrinsicExpression(n) => n.parent(),
+            Self::DoExpression(n) => n.parent(),
+            Self::PipelineExpression(n) => n.parent(),
+            Self::TopicReference(n) => n.parent(),
+            Self::ThrowExpression(n) => n.parent(),
             Self::BooleanLiteral(n) => n.parent(),
             Self::NullLiteral(n) => n.parent(),
             Self::NumericLiteral(n) => n.parent(),
@@ -702,6 +714,10 @@ impl AstNodes<'_> {
             Self::ExportAllDeclaration(_) => "ExportAllDeclaration",
             Self::ExportSpecifier(_) => "ExportSpecifier",
             Self::V8IntrinsicExpression(_) => "V8IntrinsicExpression",
+            Self::DoExpression(_) => "DoExpression",
+            Self::PipelineExpression(_) => "PipelineExpression",
+            Self::TopicReference(_) => "TopicReference",
+            Self::ThrowExpression(_) => "ThrowExpression",
             Self::BooleanLiteral(_) => "BooleanLiteral",
             Self::NullLiteral(_) => "NullLiteral",
             Self::NumericLiteral(_) => "NumericLiteral",
@@ -1192,6 +1208,36 @@ impl<'a> AstNode<'a, Expression<'a>> {
                     following_span_start: self.following_span_start,
                 }))
             }
+            Expression::DoExpression(s) => AstNodes::DoExpression(self.allocator.alloc(AstNode {
+                inner: s.as_ref(),
+                parent,
+                allocator: self.allocator,
+                following_span_start: self.following_span_start,
+            })),
+            Expression::PipelineExpression(s) => {
+                AstNodes::PipelineExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
+            Expression::ThrowExpression(s) => {
+                AstNodes::ThrowExpression(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
+            Expression::TopicReference(s) => {
+                AstNodes::TopicReference(self.allocator.alloc(AstNode {
+                    inner: s.as_ref(),
+                    parent,
+                    allocator: self.allocator,
+                    following_span_start: self.following_span_start,
+                }))
+            }
             it @ match_member_expression!(Expression) => {
                 return self
                     .allocator
@@ -6282,6 +6328,114 @@ impl<'a> AstNode<'a, V8IntrinsicExpression<'a>> {
     }
 }
 
+impl<'a> AstNode<'a, DoExpression<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, BlockStatement<'a>> {
+        let following_span_start = self.following_span_start;
+        self.allocator.alloc(AstNode {
+            inner: self.inner.body.as_ref(),
+            allocator: self.allocator,
+            parent: AstNodes::DoExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, PipelineExpression<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn left(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.inner.body.span().start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.left,
+            allocator: self.allocator,
+            parent: AstNodes::PipelineExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    #[inline]
+    pub fn body(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.following_span_start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.body,
+            allocator: self.allocator,
+            parent: AstNodes::PipelineExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, TopicReference> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
+impl<'a> AstNode<'a, ThrowExpression<'a>> {
+    #[inline]
+    pub fn node_id(&self) -> NodeId {
+        self.inner.node_id()
+    }
+
+    #[inline]
+    pub fn argument(&self) -> &AstNode<'a, Expression<'a>> {
+        let following_span_start = self.following_span_start;
+        self.allocator.alloc(AstNode {
+            inner: &self.inner.argument,
+            allocator: self.allocator,
+            parent: AstNodes::ThrowExpression(transmute_self(self)),
+            following_span_start,
+        })
+    }
+
+    pub fn format_leading_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_leading_comments(self.span()).fmt(f);
+    }
+
+    pub fn format_trailing_comments(&self, f: &mut Formatter<'_, 'a>) {
+        format_trailing_comments(self.parent.span(), self.inner.span(), self.following_span_start)
+            .fmt(f);
+    }
+}
+
 impl<'a> AstNode<'a, BooleanLiteral> {
     #[inline]
     pub fn node_id(&self) -> NodeId {
@@ -55,6 +55,10 @@ impl NeedsParentheses<'_> for AstNode<'_, Expression<'_>> {
             AstNodes::TSNonNullExpression(it) => it.needs_parentheses(f),
             AstNodes::TSInstantiationExpression(it) => it.needs_parentheses(f),
             AstNodes::V8IntrinsicExpression(it) => it.needs_parentheses(f),
+            AstNodes::DoExpression(it) => it.needs_parentheses(f),
+            AstNodes::PipelineExpression(it) => it.needs_parentheses(f),
+            AstNodes::ThrowExpression(it) => it.needs_parentheses(f),
+            AstNodes::TopicReference(it) => it.needs_parentheses(f),
             AstNodes::StaticMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::ComputedMemberExpression(it) => it.needs_parentheses(f),
             AstNodes::PrivateFieldExpression(it) => it.needs_parentheses(f),
@@ -838,6 +842,55 @@ impl NeedsParentheses<'_> for AstNode<'_, V8IntrinsicExpression<'_>> {
     }
 }
 
+impl NeedsParentheses<'_> for AstNode<'_, DoExpression<'_>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        // `(do {});` -> would be a `do...while` statement without parens
+        matches!(self.parent(), AstNodes::ExpressionStatement(stmt) if !stmt.is_arrow_function_body())
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, PipelineExpression<'_>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        let parent = self.parent();
+        match parent {
+            // `(a |> f(%)) |> g(%)`
+            AstNodes::PipelineExpression(e) => e.left.span() == self.span(),
+            AstNodes::AwaitExpression(_)
+            | AstNodes::ThrowExpression(_)
+            | AstNodes::TSTypeAssertion(_) => true,
+            _ => await_or_yield_needs_parens(self.span(), parent),
+        }
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, ThrowExpression<'_>> {
+    fn needs_parentheses(&self, f: &Formatter<'_, '_>) -> bool {
+        if f.comments().is_type_cast_node(self) {
+            return false;
+        }
+
+        let parent = self.parent();
+        // `(throw a);` -> would be a `throw` statement without parens
+        matches!(parent, AstNodes::ExpressionStatement(stmt) if !stmt.is_arrow_function_body())
+            || await_or_yield_needs_parens(self.span(), parent)
+    }
+}
+
+impl NeedsParentheses<'_> for AstNode<'_, TopicReference> {
+    #[inline]
+    fn needs_parentheses(&self, _f: &Formatter<'_, '_>) -> bool {
+        false
+    }
+}
+
 impl NeedsParentheses<'_> for AstNode<'_, JSXMemberExpression<'_>> {
     #[inline]
     fn needs_parentheses(&self, _f: &Formatter<'_, '_>) -> bool {
@@ -8,6 +8,9 @@ pub fn get_parse_options() -> ParseOptions {
         // Enable all syntax features
         allow_return_outside_function: true,
         allow_v8_intrinsics: true,
+        allow_pipeline_operator: true,
+        allow_do_expressions: true,
+        allow_throw_expressions: true,
         // `oxc_formatter` expects this to be `false`, otherwise panics
         preserve_parens: false,
     }
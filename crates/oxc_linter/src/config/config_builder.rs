@@ -1,6 +1,7 @@
 use std::{
     fmt::{self, Debug, Display},
     path::{Component as PathComponent, Path, PathBuf},
+    sync::Arc,
 };
 
 use itertools::Itertools;
@@ -258,6 +259,7 @@ impl ConfigStoreBuilder {
             categories.entry(RuleCategory::Correctness).or_insert(AllowWarnDeny::Warn);
         }
 
+        let source = Arc::new(oxlintrc.clone());
         let config = LintConfig {
             plugins,
             settings: oxlintrc.settings,
@@ -265,6 +267,7 @@ impl ConfigStoreBuilder {
             globals: oxlintrc.globals,
             path: Some(oxlintrc.path),
             options: oxlintrc.options,
+            source: Some(source),
         };
 
         let mut builder = Self {
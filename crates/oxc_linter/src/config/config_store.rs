@@ -4,6 +4,7 @@ use std::{
 };
 
 use rustc_hash::FxHashMap;
+use serde::{Serialize, Serializer, ser::SerializeStruct};
 
 use crate::{
     AllowWarnDeny,
@@ -26,6 +27,39 @@ pub struct ResolvedLinterState {
     pub external_rules: Arc<[(ExternalRuleId, ExternalOptionsId, AllowWarnDeny)]>,
 }
 
+/// Serializes the enabled rules with their severities, and the configuration they were resolved from.
+///
+/// Configured rules can not be serialized, so their options are only included through the
+/// configuration they were built from, see `LintConfig::source`.
+impl Serialize for ResolvedLinterState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut rules = self
+            .rules
+            .iter()
+            .map(|(rule, severity)| (rule.plugin_name(), rule.name(), *severity))
+            .collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|&(plugin_name, rule_name, _)| (plugin_name, rule_name));
+        let mut external_rules = self
+            .external_rules
+            .iter()
+            .map(|(rule_id, options_id, severity)| (rule_id.raw(), options_id.raw(), *severity))
+            .collect::<Vec<_>>();
+        external_rules.sort_unstable_by_key(|&(rule_id, _, _)| rule_id);
+
+        let config = &self.config;
+        let mut state = serializer.serialize_struct("ResolvedLinterState", 8)?;
+        state.serialize_field("rules", &rules)?;
+        state.serialize_field("externalRules", &external_rules)?;
+        state.serialize_field("plugins", &config.plugins)?;
+        state.serialize_field("settings", &config.settings)?;
+        state.serialize_field("env", &config.env)?;
+        state.serialize_field("globals", &config.globals)?;
+        state.serialize_field("options", &config.options)?;
+        state.serialize_field("source", &config.source.as_deref())?;
+        state.end()
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ResolvedOxlintOverrides(Vec<ResolvedOxlintOverride>);
 
@@ -818,6 +852,7 @@ mod test {
             globals: OxlintGlobals::default(),
             path: None,
             options: OxlintOptions::default(),
+            source: None,
         };
 
         // Set up categories to enable restriction rules
@@ -906,6 +941,7 @@ mod test {
             globals: OxlintGlobals::default(),
             path: None,
             options: OxlintOptions::default(),
+            source: None,
         };
 
         // Set up categories
@@ -1012,6 +1048,7 @@ mod test {
             globals: OxlintGlobals::default(),
             path: None,
             options: OxlintOptions::default(),
+            source: None,
         };
 
         // Set up categories
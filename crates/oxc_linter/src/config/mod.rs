@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 mod categories;
 mod config_builder;
@@ -37,10 +37,14 @@ pub struct LintConfig {
     pub(crate) path: Option<PathBuf>,
     /// Options for the linter.
     pub(crate) options: OxlintOptions,
+    /// The configuration this config was built from, with `extends` resolved.
+    /// Keeps the options of the configured rules, which can not be read back from the rules.
+    pub(crate) source: Option<Arc<Oxlintrc>>,
 }
 
 impl From<Oxlintrc> for LintConfig {
     fn from(config: Oxlintrc) -> Self {
+        let source = Arc::new(config.clone());
         Self {
             plugins: config.plugins.unwrap_or_default(),
             settings: config.settings,
@@ -48,6 +52,7 @@ impl From<Oxlintrc> for LintConfig {
             globals: config.globals,
             path: Some(config.path),
             options: config.options,
+            source: Some(source),
         }
     }
 }
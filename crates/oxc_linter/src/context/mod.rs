@@ -596,7 +596,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_display_name("nextjs"), "next");
 /// ```
 #[inline]
-fn plugin_display_name(plugin_name: &'static str) -> &'static str {
+pub fn plugin_display_name(plugin_name: &'static str) -> &'static str {
     match plugin_name {
         "jsx_a11y" => "jsx-a11y",
         "react_perf" => "react-perf",
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, OxcDiagnostic, SourceSpan};
 use oxc_span::{GetSpan, SourceType, Span};
 
 use crate::LintContext;
@@ -298,7 +298,18 @@ impl Message {
 impl From<Message> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
-        message.error
+        let fixes = match message.fixes {
+            PossibleFixes::None => return message.error,
+            PossibleFixes::Single(fix) => vec![fix],
+            PossibleFixes::Multiple(fixes) => fixes,
+        };
+        // Empty fixes don't change the source code.
+        let fixes = fixes.into_iter().filter(|fix| !fix.span.is_empty() || !fix.content.is_empty());
+        message.error.with_fixes(fixes.map(|fix| DiagnosticFix {
+            span: SourceSpan::from(fix.span),
+            content: fix.content,
+            message: fix.message,
+        }))
     }
 }
 
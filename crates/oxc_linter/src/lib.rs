@@ -41,6 +41,7 @@ mod module_graph_visitor;
 mod module_record;
 mod options;
 mod rule;
+mod selector;
 mod service;
 mod tsgolint;
 mod utils;
@@ -71,7 +72,7 @@ pub use crate::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintIgnoreMatcher,
         LintPlugins, Oxlintrc, ResolvedLinterState,
     },
-    context::{ContextSubHost, ContextSubHostOptions, LintContext},
+    context::{ContextSubHost, ContextSubHostOptions, LintContext, plugin_display_name},
     external_linter::{
         ExternalLinter, ExternalLinterCreateWorkspaceCb, ExternalLinterDestroyWorkspaceCb,
         ExternalLinterLintFileCb, ExternalLinterLoadPluginCb, ExternalLinterSetupRuleConfigsCb,
@@ -143,6 +144,13 @@ impl Linter {
         self
     }
 
+    /// Report fixes with their diagnostics instead of applying them.
+    #[must_use]
+    pub fn with_report_fixes(mut self, report_fixes: bool) -> Self {
+        self.options.report_fixes = report_fixes;
+        self
+    }
+
     #[must_use]
     pub fn with_report_unused_directives(mut self, report_config: Option<AllowWarnDeny>) -> Self {
         self.options.report_unused_directive = report_config;
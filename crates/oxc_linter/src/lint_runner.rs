@@ -282,6 +282,21 @@ impl LintRunner {
         }
     }
 
+    /// Resolved paths of all modules `path` depends on, directly or transitively.
+    ///
+    /// Only available after `path` has been linted with the import plugin enabled.
+    pub fn module_dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        self.lint_service.module_dependencies(path)
+    }
+
+    /// Paths tried by the resolver for the imports of `path` and the modules it depends on
+    /// which could not be resolved. `path` may be linted differently once one of them exists.
+    ///
+    /// Only available after `path` has been linted with the import plugin enabled.
+    pub fn missing_module_paths(&self, path: &Path) -> Vec<PathBuf> {
+        self.lint_service.missing_module_paths(path)
+    }
+
     /// Get the directives coordinator for external use
     pub fn directives_coordinator(&self) -> &DirectivesStore {
         &self.directives_store
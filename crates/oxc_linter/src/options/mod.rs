@@ -13,4 +13,6 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Report fixes with their diagnostics instead of applying them to the source files.
+    pub report_fixes: bool,
 }
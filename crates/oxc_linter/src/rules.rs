@@ -35,6 +35,7 @@ pub(crate) mod import {
     pub mod no_self_import;
     pub mod no_unassigned_import;
     pub mod no_webpack_loader_syntax;
+    pub mod order;
     pub mod prefer_default_export;
     pub mod unambiguous;
 }
@@ -149,6 +150,7 @@ pub(crate) mod eslint {
     pub mod no_restricted_globals;
     pub mod no_restricted_imports;
     pub mod no_restricted_properties;
+    pub mod no_restricted_syntax;
     pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
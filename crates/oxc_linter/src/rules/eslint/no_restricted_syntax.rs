@@ -0,0 +1,284 @@
+use cow_utils::CowUtils;
+use schemars::JsonSchema;
+use serde::{Deserialize, de};
+use serde_json::Value;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    selector::{Selector, SelectorContext},
+};
+
+fn no_restricted_syntax_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(message.to_string()).with_label(span)
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum RestrictedSyntaxConfig {
+    /// An [esquery](https://github.com/estools/esquery) selector.
+    Selector(String),
+    Object {
+        /// An [esquery](https://github.com/estools/esquery) selector.
+        selector: String,
+        /// A custom message to report instead of the default one. `{{selector}}` is replaced
+        /// with the selector.
+        message: Option<String>,
+    },
+}
+
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+struct RestrictedSyntaxConfigList(Vec<RestrictedSyntaxConfig>);
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    message: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restrictions: Vec<RestrictedSyntax>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows syntax matching the given selectors.
+    ///
+    /// Selectors use the [esquery](https://github.com/estools/esquery) syntax supported by ESLint,
+    /// such as `FunctionExpression`, `CallExpression[callee.name="setTimeout"][arguments.length!=2]`,
+    /// `ForInStatement > :not(BlockStatement)` or `:function:has(ThisExpression)`. Selectors are
+    /// matched against the ESTree representation of the AST, so node types and attributes are the
+    /// same as in ESLint (and typescript-eslint for TypeScript files).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript has a lot of language features, and not everyone likes all of them. As a result,
+    /// some projects choose to disallow the use of certain language features altogether. For
+    /// instance, you might decide to disallow the use of `try-catch` or `class`, or you might decide
+    /// to disallow the use of the `in` operator.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// /* no-restricted-syntax: ["error", "FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"] */
+    ///
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    ///
+    /// const doSomething = function () {};
+    ///
+    /// foo in bar;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// /* no-restricted-syntax: ["error", "FunctionExpression", "WithStatement", "BinaryExpression[operator='in']"] */
+    ///
+    /// me.dontMess();
+    ///
+    /// function doSomething() {};
+    ///
+    /// foo instanceof bar;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// This rule takes a list of strings, where each string is a selector, or objects with a
+    /// `selector` and a custom `message`:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///     "error",
+    ///     {
+    ///         "selector": "FunctionExpression",
+    ///         "message": "Function expressions are not allowed."
+    ///     },
+    ///     {
+    ///         "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+    ///         "message": "setTimeout must always be invoked with two arguments."
+    ///     }
+    /// ]
+    /// ```
+    NoRestrictedSyntax,
+    eslint,
+    restriction,
+    config = RestrictedSyntaxConfigList,
+    version = "next",
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Result<Self, serde_json::error::Error> {
+        let list = match value {
+            Value::Null => RestrictedSyntaxConfigList::default(),
+            value => serde_json::from_value::<RestrictedSyntaxConfigList>(value)?,
+        };
+        let restrictions = list
+            .0
+            .into_iter()
+            .map(|config| {
+                let (selector_text, message) = match config {
+                    RestrictedSyntaxConfig::Selector(selector) => (selector, None),
+                    RestrictedSyntaxConfig::Object { selector, message } => (selector, message),
+                };
+                let selector = Selector::parse(&selector_text).map_err(|err| {
+                    de::Error::custom(format!("invalid selector `{selector_text}`: {err}"))
+                })?;
+                let message = match message {
+                    Some(message) => {
+                        message.cow_replace("{{selector}}", &selector_text).into_owned()
+                    }
+                    None => format!("Using '{selector_text}' is not allowed."),
+                };
+                Ok(RestrictedSyntax { selector, message })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+        Ok(Self(Box::new(NoRestrictedSyntaxConfig { restrictions })))
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        if self.0.restrictions.is_empty() {
+            return;
+        }
+        let selector_ctx = SelectorContext::new(ctx.nodes(), ctx.source_type().is_typescript());
+        for node in ctx.nodes() {
+            for restriction in &self.0.restrictions {
+                if restriction.selector.matches(node, &selector_ctx) {
+                    ctx.diagnostic(no_restricted_syntax_diagnostic(
+                        &restriction.message,
+                        node.kind().span(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        // string format
+        ("doSomething();", None, None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"])), None),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"])), None),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"])), None),
+        ("() => 5", Some(json!(["ArrowFunctionExpression > BlockStatement"])), None),
+        ("({ foo: 1, bar: 2 })", Some(json!(["Property > Literal.key"])), None),
+        ("A: for (;;) break;", Some(json!(["BreakStatement[label]"])), None),
+        ("function foo(bar, baz) {}", Some(json!(["FunctionDeclaration[params.length>2]"])), None),
+        // object format
+        ("var foo = 42;", Some(json!([{ "selector": "ConditionalExpression" }])), None),
+        ("({ foo: 1, bar: 2 })", Some(json!([{ "selector": "Property > Literal.key" }])), None),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+            None,
+        ),
+        // https://github.com/eslint/eslint/issues/8733
+        ("console.log(/a/);", Some(json!(["Literal[regex.flags=/./]"])), None),
+        // other selectors
+        ("var a; f(); var b;", Some(json!(["VariableDeclaration + VariableDeclaration"])), None),
+        ("f(a.b)", Some(json!(["CallExpression:has(> Identifier[name='b'])"])), None),
+        ("if (a) { b; }", Some(json!(["IfStatement > :not(BlockStatement).consequent"])), None),
+        ("let x: number;", Some(json!(["TSStringKeyword"])), None),
+    ];
+
+    let fail = vec![
+        // string format
+        ("var foo = 41;", Some(json!(["VariableDeclaration"])), None),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"])), None),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+            None,
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"])), None),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"])), None),
+        ("() => {}", Some(json!(["ArrowFunctionExpression > BlockStatement"])), None),
+        ("({ foo: 1, 'bar': 2 })", Some(json!(["Property > Literal"])), None),
+        ("A: for (;;) break A;", Some(json!(["BreakStatement[label]"])), None),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!(["FunctionDeclaration[params.length>2]"])),
+            None,
+        ),
+        // object format
+        ("var foo = 41;", Some(json!([{ "selector": "VariableDeclaration" }])), None),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+            None,
+        ),
+        // with custom message
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{
+                "selector": "FunctionDeclaration[params.length>2]",
+                "message": "custom error message."
+            }])),
+            None,
+        ),
+        (
+            "function foo(bar, baz, qux) {}",
+            Some(json!([{
+                "selector": "FunctionDeclaration[params.length>2]",
+                "message": "custom message with {{selector}}"
+            }])),
+            None,
+        ),
+        // https://github.com/eslint/eslint/issues/8733
+        ("console.log(/a/i);", Some(json!(["Literal[regex.flags=/./]"])), None),
+        // optional chaining
+        ("var foo = foo?.bar?.();", Some(json!(["ChainExpression"])), None),
+        ("var foo = foo?.bar?.();", Some(json!(["[optional=true]"])), None),
+        ("a?.b", Some(json!([":nth-child(1)"])), None),
+        // other selectors
+        ("var a; var b;", Some(json!(["VariableDeclaration + VariableDeclaration"])), None),
+        ("var a; f(); var b;", Some(json!(["VariableDeclaration ~ VariableDeclaration"])), None),
+        ("f(a.b)", Some(json!(["CallExpression:has(Identifier[name='b'])"])), None),
+        ("if (a) b;", Some(json!(["IfStatement > :not(BlockStatement).consequent"])), None),
+        (
+            "setTimeout(() => {}); setTimeout(() => {}, 1);",
+            Some(json!([{
+                "selector": "CallExpression[callee.name='setTimeout'][arguments.length!=2]",
+                "message": "setTimeout must always be invoked with two arguments."
+            }])),
+            None,
+        ),
+        (
+            "function f() { this; } () => this;",
+            Some(json!([":function:has(ThisExpression)"])),
+            None,
+        ),
+        ("let x: string;", Some(json!(["TSStringKeyword"])), None),
+        ("enum E { A }", Some(json!(["TSEnumDeclaration"])), None),
+        ("<div id='a' />", Some(json!(["JSXAttribute[name.name='id']"])), None),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, NoRestrictedSyntax::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
+
+#[test]
+fn invalid_configs_error_in_from_configuration() {
+    assert!(NoRestrictedSyntax::from_configuration(serde_json::Value::Null).is_ok());
+    assert!(NoRestrictedSyntax::from_configuration(serde_json::json!([{}])).is_err());
+    assert!(NoRestrictedSyntax::from_configuration(serde_json::json!([1])).is_err());
+    let selector_error =
+        NoRestrictedSyntax::from_configuration(serde_json::json!(["Identifier[name="]))
+            .unwrap_err();
+    assert!(selector_error.to_string().contains("invalid selector `Identifier[name=`"));
+}
@@ -122,6 +122,7 @@ impl NoUnusedExpressions {
             | Expression::BinaryExpression(_)
             | Expression::PrivateInExpression(_)
             | Expression::ThisExpression(_)
+            | Expression::TopicReference(_)
             | Expression::Identifier(_) => true,
             Expression::ChainExpression(chain_expression) => match &chain_expression.expression {
                 ChainElement::CallExpression(_) => false,
@@ -139,6 +140,9 @@ impl NoUnusedExpressions {
             | Expression::Super(_)
             | Expression::CallExpression(_)
             | Expression::V8IntrinsicExpression(_)
+            | Expression::DoExpression(_)
+            | Expression::PipelineExpression(_)
+            | Expression::ThrowExpression(_)
             | Expression::UpdateExpression(_)
             | Expression::TSSatisfiesExpression(_)
             | Expression::YieldExpression(_) => false,
@@ -0,0 +1,767 @@
+use std::cmp::Ordering;
+
+use cow_utils::CowUtils;
+use nodejs_built_in_modules::is_nodejs_builtin_module;
+use oxc_ast::ast::Statement;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    context::LintContext,
+    rule::{DefaultRuleConfig, Rule},
+};
+
+fn order_diagnostic(span: Span, module_name: &str, other: &str, before: bool) -> OxcDiagnostic {
+    let position = if before { "before" } else { "after" };
+    OxcDiagnostic::warn(format!(
+        "`{module_name}` import should occur {position} import of `{other}`"
+    ))
+    .with_help("Reorder the import statements to match the configured groups")
+    .with_label(span)
+}
+
+fn missing_newline_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be at least one empty line between import groups")
+        .with_help("Add an empty line after this import")
+        .with_label(span)
+}
+
+fn newline_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line between import groups")
+        .with_help("Remove the empty lines after this import")
+        .with_label(span)
+}
+
+fn newline_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("There should be no empty line within import group")
+        .with_help("Remove the empty lines after this import")
+        .with_label(span)
+}
+
+/// The kind of module an import refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum ImportType {
+    /// Node.js builtin modules, e.g. `fs` or `node:path`.
+    Builtin,
+    /// Packages resolved from `node_modules`, or bare specifiers that could not be resolved.
+    External,
+    /// Bare specifiers that resolve to a file outside of `node_modules`, e.g. through path aliases.
+    Internal,
+    /// Specifiers that are neither relative nor bare, e.g. `~/foo` or `#internal` when unresolved.
+    Unknown,
+    /// Imports from a parent directory, e.g. `../foo`.
+    Parent,
+    /// Imports from the same directory, e.g. `./foo`.
+    Sibling,
+    /// Imports of the index file of the current directory, e.g. `./` or `./index.js`.
+    Index,
+    /// TypeScript `import foo = Bar.baz` declarations. These do not create module requests and
+    /// are therefore never ordered.
+    Object,
+    /// Type-only imports, e.g. `import type { Foo } from 'foo'`. Only used when listed in `groups`.
+    Type,
+    /// Absolute paths, e.g. `/foo/bar`.
+    Absolute,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged)]
+enum GroupEntry {
+    Single(ImportType),
+    Multiple(Vec<ImportType>),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum PathGroupPosition {
+    Before,
+    #[default]
+    None,
+    After,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct PathGroup {
+    /// Glob pattern matched against the import specifier.
+    pattern: String,
+    /// The group the matching imports are ranked relative to.
+    group: ImportType,
+    /// Whether matching imports go before or after the rest of `group`. When omitted, they are
+    /// treated as part of `group`.
+    #[serde(default)]
+    position: PathGroupPosition,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum NewlinesBetween {
+    /// Do not check empty lines between imports.
+    #[default]
+    Ignore,
+    /// Require at least one empty line between groups, and forbid them inside a group.
+    Always,
+    /// Require at least one empty line between groups, and allow them inside a group.
+    AlwaysAndInsideGroups,
+    /// Forbid empty lines between imports.
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct Alphabetize {
+    /// Sort imports within a group by their specifier.
+    order: AlphabetizeOrder,
+    /// Ignore case when sorting.
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OrderConfig {
+    /// The order of import groups. Each entry is an import type or an array of import types that
+    /// share a rank. Types that are not listed are ranked together after all listed groups.
+    ///
+    /// Defaults to `["builtin", "external", "parent", "sibling", "index"]`.
+    groups: Vec<GroupEntry>,
+    /// Assign imports matching a glob pattern to a position relative to a group.
+    ///
+    /// ```json
+    /// { "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }] }
+    /// ```
+    path_groups: Vec<PathGroup>,
+    /// Import types that `pathGroups` are not applied to.
+    ///
+    /// Defaults to `["builtin", "external", "object"]`.
+    path_groups_excluded_import_types: Vec<ImportType>,
+    /// Enforce or forbid empty lines between import groups.
+    #[serde(rename = "newlines-between")]
+    newlines_between: NewlinesBetween,
+    /// Sort imports within each group by their specifier.
+    alphabetize: Alphabetize,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                GroupEntry::Single(ImportType::Builtin),
+                GroupEntry::Single(ImportType::External),
+                GroupEntry::Single(ImportType::Parent),
+                GroupEntry::Single(ImportType::Sibling),
+                GroupEntry::Single(ImportType::Index),
+            ],
+            path_groups: vec![],
+            path_groups_excluded_import_types: vec![
+                ImportType::Builtin,
+                ImportType::External,
+                ImportType::Object,
+            ],
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Order(Box<OrderConfig>);
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` statements, based on the kind of module
+    /// they import: Node.js builtins, external packages, internal modules, parent directories,
+    /// sibling files and the index file.
+    ///
+    /// Imports without bindings (`import './polyfill'`) are left in place, as they may have
+    /// side effects that depend on their position.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent import order makes it easy to see at a glance what a module depends on,
+    /// and avoids noisy diffs when imports are added by different people.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```js
+    /// import _ from 'lodash';
+    /// import path from 'path'; // `path` import should occur before import of `lodash`
+    ///
+    /// import foo from './foo';
+    /// import bar from '../bar'; // `../bar` import should occur before import of `./foo`
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```js
+    /// import path from 'path';
+    /// import _ from 'lodash';
+    /// import bar from '../bar';
+    /// import foo from './foo';
+    /// import main from './';
+    /// ```
+    ///
+    /// Examples of **incorrect** code for this rule with
+    /// `{ "newlines-between": "always", "alphabetize": { "order": "asc" } }`:
+    /// ```js
+    /// import path from 'path';
+    /// import fs from 'fs';
+    /// import _ from 'lodash';
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule with
+    /// `{ "newlines-between": "always", "alphabetize": { "order": "asc" } }`:
+    /// ```js
+    /// import fs from 'fs';
+    /// import path from 'path';
+    ///
+    /// import _ from 'lodash';
+    /// ```
+    Order,
+    import,
+    style,
+    conditional_fix,
+    config = OrderConfig,
+    version = "next",
+);
+
+/// An import declaration taking part in ordering.
+struct OrderedImport<'a> {
+    span: Span,
+    name: &'a str,
+    rank: (usize, i32),
+    /// Index of the statement in `Program::body`.
+    body_index: usize,
+    /// The full lines covered by the statement, including attached comments. `None` when the
+    /// statement shares a line with other code and cannot be moved safely.
+    chunk: Option<Span>,
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/v2.31.0/docs/rules/order.md>
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Result<Self, serde_json::error::Error> {
+        serde_json::from_value::<DefaultRuleConfig<Self>>(value).map(DefaultRuleConfig::into_inner)
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        // Only imports that bind something have import entries; side-effect imports are skipped.
+        let import_statements = module_record
+            .import_entries
+            .iter()
+            .map(|entry| entry.statement_span)
+            .collect::<FxHashSet<_>>();
+        if import_statements.is_empty() {
+            return;
+        }
+
+        let program = ctx.nodes().program();
+        let imports = program
+            .body
+            .iter()
+            .enumerate()
+            .filter_map(|(body_index, statement)| {
+                let Statement::ImportDeclaration(decl) = statement else {
+                    return None;
+                };
+                if !import_statements.contains(&decl.span) {
+                    return None;
+                }
+                let name = decl.source.value.as_str();
+                let import_type = if decl.import_kind.is_type() && self.has_group(ImportType::Type)
+                {
+                    ImportType::Type
+                } else {
+                    classify_import(name, ctx)
+                };
+                Some(OrderedImport {
+                    span: decl.span,
+                    name,
+                    rank: self.rank(name, import_type),
+                    body_index,
+                    chunk: statement_chunk(decl.span, ctx),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.check_order(&imports, ctx);
+        if self.newlines_between != NewlinesBetween::Ignore {
+            self.check_newlines(&imports, ctx);
+        }
+    }
+}
+
+impl Order {
+    fn has_group(&self, import_type: ImportType) -> bool {
+        self.group_index(import_type).is_some()
+    }
+
+    fn group_index(&self, import_type: ImportType) -> Option<usize> {
+        self.groups.iter().position(|group| match group {
+            GroupEntry::Single(ty) => *ty == import_type,
+            GroupEntry::Multiple(types) => types.contains(&import_type),
+        })
+    }
+
+    /// Returns the group index and the position inside of the group assigned by `pathGroups`.
+    fn rank(&self, name: &str, import_type: ImportType) -> (usize, i32) {
+        let group_rank = |ty| self.group_index(ty).unwrap_or(self.groups.len());
+
+        if !self.path_groups_excluded_import_types.contains(&import_type)
+            && let Some((index, path_group)) = self
+                .path_groups
+                .iter()
+                .enumerate()
+                .find(|(_, path_group)| fast_glob::glob_match(&path_group.pattern, name))
+        {
+            // Path groups sharing a position keep the order in which they were declared.
+            let index = i32::try_from(index).unwrap_or(i32::MAX - 1);
+            let position = match path_group.position {
+                PathGroupPosition::Before => i32::MIN + index,
+                PathGroupPosition::None => 0,
+                PathGroupPosition::After => index + 1,
+            };
+            return (group_rank(path_group.group), position);
+        }
+
+        (group_rank(import_type), 0)
+    }
+
+    fn compare(&self, a: &OrderedImport, b: &OrderedImport) -> Ordering {
+        a.rank.cmp(&b.rank).then_with(|| {
+            let ordering = if self.alphabetize.case_insensitive {
+                a.name.cow_to_lowercase().cmp(&b.name.cow_to_lowercase())
+            } else {
+                a.name.cmp(b.name)
+            };
+            match self.alphabetize.order {
+                AlphabetizeOrder::Ignore => Ordering::Equal,
+                AlphabetizeOrder::Asc => ordering,
+                AlphabetizeOrder::Desc => ordering.reverse(),
+            }
+        })
+    }
+
+    fn check_order(&self, imports: &[OrderedImport], ctx: &LintContext<'_>) {
+        // Imports that should be moved before an earlier import.
+        let forward = imports
+            .iter()
+            .enumerate()
+            .filter_map(|(i, import)| {
+                imports[..i]
+                    .iter()
+                    .position(|prev| self.compare(prev, import) == Ordering::Greater)
+                    .map(|target| (i, target))
+            })
+            .collect::<Vec<_>>();
+        // Imports that should be moved after a later import.
+        let backward = imports
+            .iter()
+            .enumerate()
+            .filter_map(|(i, import)| {
+                imports[i + 1..]
+                    .iter()
+                    .rposition(|next| self.compare(import, next) == Ordering::Greater)
+                    .map(|offset| (i, i + 1 + offset))
+            })
+            .collect::<Vec<_>>();
+
+        // Report whichever direction needs fewer imports to be moved.
+        let (out_of_order, before) =
+            if backward.len() < forward.len() { (backward, false) } else { (forward, true) };
+
+        for (index, target_index) in out_of_order {
+            let import = &imports[index];
+            let target = &imports[target_index];
+            let diagnostic = order_diagnostic(import.span, import.name, target.name, before);
+
+            let (first, last) = if before { (target, import) } else { (import, target) };
+            // Moving a statement across anything that is not an ordered import could change
+            // evaluation order, so only fix runs of consecutive ordered imports.
+            let is_contiguous = last.body_index - first.body_index == target_index.abs_diff(index);
+            match (first.chunk, last.chunk) {
+                (Some(first_chunk), Some(last_chunk)) if is_contiguous => {
+                    let source = ctx.source_text();
+                    let text = |start: u32, end: u32| &source[start as usize..end as usize];
+                    let (moved, rest) = if before {
+                        (
+                            text(last_chunk.start, last_chunk.end),
+                            text(first_chunk.start, last_chunk.start),
+                        )
+                    } else {
+                        (
+                            text(first_chunk.start, first_chunk.end),
+                            text(first_chunk.end, last_chunk.end),
+                        )
+                    };
+                    let replacement =
+                        if before { join_lines(moved, rest) } else { join_lines(rest, moved) };
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(Span::new(first_chunk.start, last_chunk.end), replacement)
+                    });
+                }
+                _ => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+
+    fn check_newlines(&self, imports: &[OrderedImport], ctx: &LintContext<'_>) {
+        let source = ctx.source_text();
+
+        for (prev, current) in imports.iter().zip(imports.iter().skip(1)) {
+            let between = &source[prev.span.end as usize..current.span.start as usize];
+            // The first and last segments are the ends of the lines holding `prev` and `current`.
+            let lines = between.split('\n').collect::<Vec<_>>();
+            let empty_lines = lines
+                .get(1..lines.len().saturating_sub(1))
+                .map_or(0, |lines| lines.iter().filter(|line| line.trim().is_empty()).count());
+            let same_group = prev.rank == current.rank;
+
+            if empty_lines == 0 {
+                if !same_group
+                    && matches!(
+                        self.newlines_between,
+                        NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    )
+                {
+                    let diagnostic = missing_newline_diagnostic(prev.span);
+                    match between.find('\n') {
+                        Some(offset) => {
+                            #[expect(clippy::cast_possible_truncation)]
+                            let position = prev.span.end + offset as u32;
+                            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                                fixer.insert_text_before_range(Span::empty(position), "\n")
+                            });
+                        }
+                        None => ctx.diagnostic(diagnostic),
+                    }
+                }
+                continue;
+            }
+
+            let diagnostic = match self.newlines_between {
+                NewlinesBetween::Never => newline_between_groups_diagnostic(prev.span),
+                NewlinesBetween::Always if same_group => newline_within_group_diagnostic(prev.span),
+                _ => continue,
+            };
+            // Only remove the empty lines when nothing else, like a comment, sits between them.
+            let first_newline = between.find('\n').unwrap_or_default();
+            let last_newline = between.rfind('\n').unwrap_or_default();
+            if between[first_newline..].trim().is_empty() {
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::new(
+                    prev.span.end + first_newline as u32,
+                    prev.span.end + last_newline as u32,
+                );
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(span));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+/// Classify an import specifier, using the module resolved by `oxc_resolver` when available.
+fn classify_import(name: &str, ctx: &LintContext<'_>) -> ImportType {
+    if name.starts_with("node:") || is_nodejs_builtin_module(name) {
+        return ImportType::Builtin;
+    }
+    if name.starts_with('/') {
+        return ImportType::Absolute;
+    }
+    if is_index(name) {
+        return ImportType::Index;
+    }
+    if name == ".." || name.starts_with("../") {
+        return ImportType::Parent;
+    }
+    if name.starts_with("./") {
+        return ImportType::Sibling;
+    }
+
+    if let Some(module) = ctx.module_record().get_loaded_module(name) {
+        let in_node_modules = module
+            .resolved_absolute_path
+            .components()
+            .any(|component| component.as_os_str() == "node_modules");
+        return if in_node_modules { ImportType::External } else { ImportType::Internal };
+    }
+
+    let looks_like_package =
+        name.starts_with('@') || name.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+    if looks_like_package { ImportType::External } else { ImportType::Unknown }
+}
+
+fn is_index(name: &str) -> bool {
+    let Some(rest) = name.strip_prefix('.') else {
+        return false;
+    };
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    rest.is_empty()
+        || rest == "index"
+        || rest.strip_prefix("index.").is_some_and(|ext| !ext.is_empty() && !ext.contains('/'))
+}
+
+/// Returns the whole lines covered by the statement at `span`, including comments on the lines
+/// directly above it and a trailing comment on its last line.
+fn statement_chunk(span: Span, ctx: &LintContext<'_>) -> Option<Span> {
+    let source = ctx.source_text();
+    let text = |start: u32, end: u32| &source[start as usize..end as usize];
+    let line_start = |pos: u32| {
+        #[expect(clippy::cast_possible_truncation)]
+        text(0, pos).rfind('\n').map_or(0, |i| i as u32 + 1)
+    };
+
+    let mut start = span.start;
+    for comment in ctx.comments_range(..span.start).rev() {
+        let between = text(comment.span.end, start);
+        if !between.trim().is_empty()
+            || between.matches('\n').count() > 1
+            || !text(line_start(comment.span.start), comment.span.start).trim().is_empty()
+        {
+            break;
+        }
+        start = comment.span.start;
+    }
+    let chunk_start = line_start(start);
+    if !text(chunk_start, start).trim().is_empty() {
+        return None;
+    }
+
+    let mut end = span.end;
+    for comment in ctx.comments_range(span.end..) {
+        let between = text(end, comment.span.start);
+        if !between.trim().is_empty() || between.contains('\n') {
+            break;
+        }
+        end = comment.span.end;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    let line_end =
+        source[end as usize..].find('\n').map_or(source.len() as u32, |i| end + i as u32 + 1);
+    if !text(end, line_end).trim().is_empty() {
+        return None;
+    }
+
+    Some(Span::new(chunk_start, line_end))
+}
+
+/// Concatenates two runs of whole lines, where only the last one may lack a trailing newline.
+fn join_lines(first: &str, second: &str) -> String {
+    if first.ends_with('\n') {
+        format!("{first}{second}")
+    } else {
+        // `first` was the last line of the file: keep the file without a trailing newline.
+        let second = second.strip_suffix('\n').unwrap_or(second);
+        format!("{first}\n{second}")
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"import fs from 'fs';
+            import _ from 'lodash';
+            import bar from '../bar';
+            import foo from './foo';
+            import main from './';",
+            None,
+        ),
+        (r"import path from 'node:path'; import async from 'async';", None),
+        // side-effect imports are not ordered
+        (r"import './foo'; import fs from 'fs';", None),
+        (r"import fs from 'fs'; import './polyfill'; import foo from './foo';", None),
+        // groups sharing a rank
+        (
+            r"import foo from './foo'; import bar from '../bar';",
+            Some(json!([{ "groups": [["parent", "sibling"]] }])),
+        ),
+        // unlisted groups go last
+        (
+            r"import foo from './foo'; import fs from 'fs';",
+            Some(json!([{ "groups": ["sibling"] }])),
+        ),
+        // type imports
+        (
+            r"import fs from 'fs'; import foo from './foo'; import type { Bar } from 'bar';",
+            Some(json!([{ "groups": ["builtin", "external", "sibling", "type"] }])),
+        ),
+        (
+            r"import type { Bar } from 'bar'; import fs from 'fs';",
+            Some(json!([{ "groups": ["type", "builtin"] }])),
+        ),
+        // pathGroups
+        (
+            r"import _ from 'lodash'; import app from '@app/core'; import foo from './foo';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+            }])),
+        ),
+        (
+            r"import react from 'react'; import fs from 'fs'; import _ from 'lodash';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "react", "group": "builtin", "position": "before" }],
+                "pathGroupsExcludedImportTypes": [],
+            }])),
+        ),
+        // alphabetize
+        (
+            r"import a from 'a'; import B from 'B'; import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"import c from 'c'; import b from 'b'; import foo from './foo';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        // newlines-between
+        (
+            "import fs from 'fs';\nimport path from 'path';\n\nimport _ from 'lodash';\n\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';\n\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';\n// comment\nimport foo from './foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r"import _ from 'lodash'; import fs from 'fs';", None),
+        (r"import foo from './foo'; import bar from '../bar';", None),
+        (r"import main from './'; import foo from './foo';", None),
+        (r"import foo from './foo'; import _ from 'lodash'; import fs from 'fs';", None),
+        (r"import foo from './foo'; foo(); import fs from 'fs';", None),
+        (
+            r"import foo from './foo'; import type { Bar } from 'bar';",
+            Some(json!([{ "groups": ["type", "sibling"] }])),
+        ),
+        (
+            r"import app from '@app/core'; import _ from 'lodash';",
+            Some(json!([{
+                "pathGroups": [{ "pattern": "@app/**", "group": "external", "position": "after" }],
+                "pathGroupsExcludedImportTypes": ["builtin"],
+            }])),
+        ),
+        (
+            r"import b from 'b'; import a from 'a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import a from 'a'; import B from 'B';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": false } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import _ from 'lodash';\nimport fs from 'fs';\n",
+            "import fs from 'fs';\nimport _ from 'lodash';\n",
+            None,
+        ),
+        // moves leading and trailing comments with the statement
+        (
+            "import _ from 'lodash'; // utils\n// file system\nimport fs from 'fs'; // builtin\nfs();\n",
+            "// file system\nimport fs from 'fs'; // builtin\nimport _ from 'lodash'; // utils\nfs();\n",
+            None,
+        ),
+        // no trailing newline at the end of the file
+        (
+            "import _ from 'lodash';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            None,
+        ),
+        // moved after when that needs fewer moves
+        (
+            "import foo from './foo';\nimport fs from 'fs';\nimport path from 'path';\nimport _ from 'lodash';\n",
+            "import fs from 'fs';\nimport path from 'path';\nimport _ from 'lodash';\nimport foo from './foo';\n",
+            None,
+        ),
+        // not fixed across other statements
+        (
+            "import _ from 'lodash';\nfoo();\nimport fs from 'fs';\n",
+            "import _ from 'lodash';\nfoo();\nimport fs from 'fs';\n",
+            None,
+        ),
+        // not fixed across side-effect imports
+        (
+            "import _ from 'lodash';\nimport './setup';\nimport fs from 'fs';\n",
+            "import _ from 'lodash';\nimport './setup';\nimport fs from 'fs';\n",
+            None,
+        ),
+        // not fixed when sharing a line
+        (
+            "import _ from 'lodash'; import fs from 'fs';\n",
+            "import _ from 'lodash'; import fs from 'fs';\n",
+            None,
+        ),
+        (
+            "import b from 'b';\nimport a from 'a';\n",
+            "import a from 'a';\nimport b from 'b';\n",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';\n",
+            "import fs from 'fs';\n\nimport _ from 'lodash';\n",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\n\nimport path from 'path';\n",
+            "import fs from 'fs';\nimport path from 'path';\n",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';\n",
+            "import fs from 'fs';\nimport _ from 'lodash';\n",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, Order::PLUGIN, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,727 @@
+use std::{
+    cell::{OnceCell, RefCell},
+    rc::Rc,
+};
+
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use oxc_ast::{
+    AstKind,
+    ast::{
+        AccessorPropertyType, ClassType, FunctionType, MethodDefinitionType, PropertyDefinitionType,
+    },
+};
+use oxc_estree::{ConfigJS, ConfigTS, ESTree, Serializer};
+use oxc_index::IndexVec;
+use oxc_semantic::{AstNode, AstNodes, NodeId};
+use oxc_span::GetSpan;
+
+use super::{
+    Attribute, AttributeOperator, AttributeValue, Combinator, NodeClass, Selector,
+    serializer::ShallowSerializer,
+};
+
+/// Calls `$m!` with the names of all [`AstKind`] variants.
+macro_rules! with_ast_kinds {
+    ($m:ident) => {
+        $m! {
+            Program, IdentifierName, IdentifierReference, BindingIdentifier, LabelIdentifier,
+            ThisExpression, ArrayExpression, Elision, ObjectExpression, ObjectProperty,
+            TemplateLiteral, TaggedTemplateExpression, TemplateElement, ComputedMemberExpression,
+            StaticMemberExpression, PrivateFieldExpression, CallExpression, NewExpression,
+            MetaProperty, SpreadElement, UpdateExpression, UnaryExpression, BinaryExpression,
+            PrivateInExpression, LogicalExpression, ConditionalExpression, AssignmentExpression,
+            ArrayAssignmentTarget, ObjectAssignmentTarget, AssignmentTargetRest,
+            AssignmentTargetWithDefault, AssignmentTargetPropertyIdentifier,
+            AssignmentTargetPropertyProperty, SequenceExpression, Super, AwaitExpression,
+            ChainExpression, ParenthesizedExpression, Directive, Hashbang, BlockStatement,
+            VariableDeclaration, VariableDeclarator, EmptyStatement, ExpressionStatement,
+            IfStatement, DoWhileStatement, WhileStatement, ForStatement, ForInStatement,
+            ForOfStatement, ContinueStatement, BreakStatement, ReturnStatement, WithStatement,
+            SwitchStatement, SwitchCase, LabeledStatement, ThrowStatement, TryStatement,
+            CatchClause, CatchParameter, DebuggerStatement, AssignmentPattern, ObjectPattern,
+            BindingProperty, ArrayPattern, BindingRestElement, Function, FormalParameters,
+            FormalParameter, FormalParameterRest, FunctionBody, ArrowFunctionExpression,
+            YieldExpression, Class, ClassBody, MethodDefinition, PropertyDefinition,
+            PrivateIdentifier, StaticBlock, AccessorProperty, ImportExpression, ImportDeclaration,
+            ImportSpecifier, ImportDefaultSpecifier, ImportNamespaceSpecifier, WithClause,
+            ImportAttribute, ExportNamedDeclaration, ExportDefaultDeclaration,
+            ExportAllDeclaration, ExportSpecifier, DoExpression, PipelineExpression,
+            TopicReference, ThrowExpression, V8IntrinsicExpression, BooleanLiteral, NullLiteral,
+            NumericLiteral,
+            StringLiteral, BigIntLiteral, RegExpLiteral, JSXElement, JSXOpeningElement,
+            JSXClosingElement, JSXFragment, JSXOpeningFragment, JSXClosingFragment,
+            JSXNamespacedName, JSXMemberExpression, JSXExpressionContainer, JSXEmptyExpression,
+            JSXAttribute, JSXSpreadAttribute, JSXIdentifier, JSXSpreadChild, JSXText,
+            TSThisParameter, TSEnumDeclaration, TSEnumBody, TSEnumMember, TSTypeAnnotation,
+            TSLiteralType, TSConditionalType, TSUnionType, TSIntersectionType,
+            TSParenthesizedType, TSTypeOperator, TSArrayType, TSIndexedAccessType, TSTupleType,
+            TSNamedTupleMember, TSOptionalType, TSRestType, TSAnyKeyword, TSStringKeyword,
+            TSBooleanKeyword, TSNumberKeyword, TSNeverKeyword, TSIntrinsicKeyword,
+            TSUnknownKeyword, TSNullKeyword, TSUndefinedKeyword, TSVoidKeyword, TSSymbolKeyword,
+            TSThisType, TSObjectKeyword, TSBigIntKeyword, TSTypeReference, TSQualifiedName,
+            TSTypeParameterInstantiation, TSTypeParameter, TSTypeParameterDeclaration,
+            TSTypeAliasDeclaration, TSClassImplements, TSInterfaceDeclaration, TSInterfaceBody,
+            TSPropertySignature, TSIndexSignature, TSCallSignatureDeclaration, TSMethodSignature,
+            TSConstructSignatureDeclaration, TSIndexSignatureName, TSInterfaceHeritage,
+            TSTypePredicate, TSModuleDeclaration, TSGlobalDeclaration, TSModuleBlock,
+            TSTypeLiteral, TSInferType, TSTypeQuery, TSImportType, TSImportTypeQualifiedName,
+            TSFunctionType, TSConstructorType, TSMappedType, TSTemplateLiteralType,
+            TSAsExpression, TSSatisfiesExpression, TSTypeAssertion, TSImportEqualsDeclaration,
+            TSExternalModuleReference, TSNonNullExpression, Decorator, TSExportAssignment,
+            TSNamespaceExportDeclaration, TSInstantiationExpression, JSDocNullableType,
+            JSDocNonNullableType, JSDocUnknownType,
+        }
+    };
+}
+
+macro_rules! kind_functions {
+    ($($kind:ident),* $(,)?) => {
+        fn kind_name(kind: &AstKind) -> &'static str {
+            match kind {
+                $(AstKind::$kind(_) => stringify!($kind),)*
+            }
+        }
+
+        fn serialize_kind<S: Serializer>(kind: &AstKind, serializer: S) {
+            match kind {
+                $(AstKind::$kind(it) => it.serialize(serializer),)*
+            }
+        }
+    };
+}
+
+with_ast_kinds!(kind_functions);
+
+/// Get the ESTree `type` of a node.
+///
+/// Returns `None` for nodes which are not present in ESTree, such as `ParenthesizedExpression`.
+/// See [`SelectorContext::node_type`] for nodes which depend on their parent.
+fn estree_type(kind: &AstKind) -> Option<&'static str> {
+    let ty = match kind {
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_)
+        | AstKind::TSThisParameter(_)
+        | AstKind::TSIndexSignatureName(_) => "Identifier",
+        AstKind::ComputedMemberExpression(_)
+        | AstKind::StaticMemberExpression(_)
+        | AstKind::PrivateFieldExpression(_) => "MemberExpression",
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::ObjectProperty(_)
+        | AstKind::BindingProperty(_)
+        | AstKind::AssignmentTargetPropertyIdentifier(_)
+        | AstKind::AssignmentTargetPropertyProperty(_) => "Property",
+        AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::ArrayAssignmentTarget(_) => "ArrayPattern",
+        AstKind::ObjectAssignmentTarget(_) => "ObjectPattern",
+        AstKind::AssignmentTargetRest(_)
+        | AstKind::BindingRestElement(_)
+        | AstKind::FormalParameterRest(_) => "RestElement",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::Function(func) => match func.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) => match class.r#type {
+            ClassType::ClassDeclaration => "ClassDeclaration",
+            ClassType::ClassExpression => "ClassExpression",
+        },
+        AstKind::MethodDefinition(method) => match method.r#type {
+            MethodDefinitionType::MethodDefinition => "MethodDefinition",
+            MethodDefinitionType::TSAbstractMethodDefinition => "TSAbstractMethodDefinition",
+        },
+        AstKind::PropertyDefinition(prop) => match prop.r#type {
+            PropertyDefinitionType::PropertyDefinition => "PropertyDefinition",
+            PropertyDefinitionType::TSAbstractPropertyDefinition => "TSAbstractPropertyDefinition",
+        },
+        AstKind::AccessorProperty(prop) => match prop.r#type {
+            AccessorPropertyType::AccessorProperty => "AccessorProperty",
+            AccessorPropertyType::TSAbstractAccessorProperty => "TSAbstractAccessorProperty",
+        },
+        AstKind::FormalParameter(param) => {
+            if param.has_modifier() {
+                "TSParameterProperty"
+            } else if param.initializer.is_some() {
+                "AssignmentPattern"
+            } else {
+                // Serialized as its binding pattern.
+                return None;
+            }
+        }
+        AstKind::TSGlobalDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSImportTypeQualifiedName(_) => "TSQualifiedName",
+        AstKind::JSDocNullableType(_) => "TSJSDocNullableType",
+        AstKind::JSDocNonNullableType(_) => "TSJSDocNonNullableType",
+        AstKind::JSDocUnknownType(_) => "TSJSDocUnknownType",
+        AstKind::Elision(_)
+        | AstKind::ParenthesizedExpression(_)
+        | AstKind::Hashbang(_)
+        | AstKind::CatchParameter(_)
+        | AstKind::FormalParameters(_)
+        | AstKind::WithClause(_)
+        | AstKind::TSParenthesizedType(_) => return None,
+        _ => kind_name(kind),
+    };
+    Some(ty)
+}
+
+/// The ESTree view of [`AstNodes`] that [`Selector`]s are matched against.
+///
+/// Parent / child relations are computed lazily and cached, so a context should be reused when
+/// matching many nodes of the same AST.
+pub struct SelectorContext<'n, 'a> {
+    nodes: &'n AstNodes<'a>,
+    /// Serialize TypeScript fields of nodes.
+    typescript: bool,
+    tree: OnceCell<Tree>,
+    locations: RefCell<FxHashMap<NodeId, Option<Rc<Location>>>>,
+}
+
+/// Type, parent and children of each node, skipping nodes without an ESTree type.
+struct Tree {
+    types: IndexVec<NodeId, Option<&'static str>>,
+    parents: IndexVec<NodeId, Option<NodeId>>,
+    children: IndexVec<NodeId, Vec<NodeId>>,
+}
+
+/// Where a node is stored in its ESTree parent, e.g. `arguments[1]` of a `CallExpression`.
+struct Location {
+    key: &'static str,
+    /// Index in the list, if the property is a list.
+    index: Option<usize>,
+    /// Length of the list, if the property is a list.
+    len: usize,
+}
+
+impl<'n, 'a> SelectorContext<'n, 'a> {
+    pub fn new(nodes: &'n AstNodes<'a>, typescript: bool) -> Self {
+        Self { nodes, typescript, tree: OnceCell::new(), locations: RefCell::default() }
+    }
+
+    fn tree(&self) -> &Tree {
+        self.tree.get_or_init(|| {
+            let mut types: IndexVec<NodeId, Option<&str>> =
+                IndexVec::with_capacity(self.nodes.len());
+            let mut parents = IndexVec::with_capacity(self.nodes.len());
+            let mut children = IndexVec::with_capacity(self.nodes.len());
+            // Node ids are assigned in pre-order, so parents are always visited before their children.
+            for (id, node) in self.nodes.iter_enumerated() {
+                let kind = node.kind();
+                let (ty, parent) = if id == NodeId::ROOT {
+                    (estree_type(&kind), None)
+                } else {
+                    let parent_id = self.nodes.parent_id(id);
+                    let parent_kind = self.nodes.kind(parent_id);
+                    let ty = match (kind, parent_kind) {
+                        // The body of `() => expr` is the expression itself.
+                        (AstKind::FunctionBody(_), AstKind::ArrowFunctionExpression(arrow))
+                            if arrow.expression =>
+                        {
+                            None
+                        }
+                        (AstKind::ExpressionStatement(_), AstKind::FunctionBody(_))
+                            if matches!(
+                                self.nodes.parent_kind(parent_id),
+                                AstKind::ArrowFunctionExpression(arrow) if arrow.expression
+                            ) =>
+                        {
+                            None
+                        }
+                        _ => estree_type(&kind),
+                    };
+                    let parent = if types[parent_id].is_some() {
+                        Some(parent_id)
+                    } else {
+                        parents[parent_id]
+                    };
+                    (ty, parent)
+                };
+                types.push(ty);
+                parents.push(parent);
+                children.push(vec![]);
+                if let Some(parent) = parent
+                    && ty.is_some()
+                {
+                    children[parent].push(id);
+                }
+            }
+            Tree { types, parents, children }
+        })
+    }
+
+    /// The ESTree `type` of a node, or `None` if it is not present in ESTree.
+    pub fn node_type(&self, id: NodeId) -> Option<&'static str> {
+        self.tree().types[id]
+    }
+
+    fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.tree().parents[id]
+    }
+
+    /// ESTree children of a node, in source order.
+    fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.tree().children[id]
+    }
+
+    fn location(&self, id: NodeId) -> Option<Rc<Location>> {
+        if let Some(location) = self.locations.borrow().get(&id) {
+            return location.clone();
+        }
+        let parent = self.parent(id)?;
+        self.locate_children(parent);
+        self.locations.borrow().get(&id).cloned().flatten()
+    }
+
+    /// Find the properties of `parent` which hold its children.
+    ///
+    /// The child nodes are collected by walking only the direct properties of the ESTree
+    /// serialization of `parent`, and matched with the [`AstNodes`] children by their span, in
+    /// order. A shorthand property `{ a }` has two children with the same span, which are the
+    /// `key` and `value` in both.
+    fn locate_children(&self, parent: NodeId) {
+        let kind = self.nodes.kind(parent);
+        let fields = if self.typescript {
+            let mut serializer = ShallowSerializer::<ConfigTS>::fields();
+            serialize_kind(&kind, &mut serializer);
+            serializer.into_fields()
+        } else {
+            let mut serializer = ShallowSerializer::<ConfigJS>::fields();
+            serialize_kind(&kind, &mut serializer);
+            serializer.into_fields()
+        };
+
+        let mut used = vec![false; fields.len()];
+        let mut locations = self.locations.borrow_mut();
+        for child in self.children(parent) {
+            let span = self.nodes.kind(*child).span();
+            let found =
+                fields.iter().enumerate().position(|(i, field)| !used[i] && field.span == span);
+            let location = found.map(|i| {
+                used[i] = true;
+                let field = &fields[i];
+                Rc::new(Location { key: field.key, index: field.index, len: field.len })
+            });
+            locations.insert(*child, location);
+        }
+    }
+
+    /// Nodes in the same list as `id`, and the index of `id` in that list.
+    ///
+    /// Holes in the list, such as elisions in array literals, are `None`.
+    fn siblings(&self, id: NodeId) -> Option<(Vec<Option<NodeId>>, usize)> {
+        let location = self.location(id)?;
+        let index = location.index?;
+        let mut siblings = vec![None; location.len];
+        for sibling in self.children(self.parent(id)?) {
+            if let Some(sibling_location) = self.location(*sibling)
+                && sibling_location.key == location.key
+                && let Some(i) = sibling_location.index
+            {
+                siblings[i] = Some(*sibling);
+            }
+        }
+        Some((siblings, index))
+    }
+
+    /// The ESTree serialization of a node, containing only the properties on `path`.
+    fn attribute_value(&self, id: NodeId, path: &[Box<str>]) -> Value {
+        let kind = self.nodes.kind(id);
+        // `selfClosing` is only known to the parent element, which serializes the opening element.
+        if matches!(kind, AstKind::JSXOpeningElement(_)) {
+            let path = std::iter::once("openingElement".into())
+                .chain(path.iter().cloned())
+                .collect::<Vec<Box<str>>>();
+            let value = self.attribute_value(self.nodes.parent_id(id), &path);
+            return value.get("openingElement").cloned().unwrap_or(Value::Null);
+        }
+        let json = if self.typescript {
+            let mut serializer = ShallowSerializer::<ConfigTS>::path(path);
+            serialize_kind(&kind, &mut serializer);
+            serializer.into_string()
+        } else {
+            let mut serializer = ShallowSerializer::<ConfigJS>::path(path);
+            serialize_kind(&kind, &mut serializer);
+            serializer.into_string()
+        };
+        serde_json::from_str(&json).unwrap_or(Value::Null)
+    }
+}
+
+impl Selector {
+    /// Check if `node` matches this selector.
+    ///
+    /// Nodes without an ESTree type never match.
+    pub fn matches(&self, node: &AstNode, ctx: &SelectorContext) -> bool {
+        ctx.node_type(node.id()).is_some() && self.matches_id(node.id(), None, ctx)
+    }
+
+    /// `scope` is the node a `:has` selector is evaluated for.
+    fn matches_id(&self, id: NodeId, scope: Option<NodeId>, ctx: &SelectorContext) -> bool {
+        match self {
+            Self::Wildcard => true,
+            Self::Type(name) => ctx.node_type(id).is_some_and(|ty| ty.eq_ignore_ascii_case(name)),
+            Self::Attribute(attribute) => {
+                attribute.matches(ctx.attribute_value(id, &attribute.path))
+            }
+            Self::Field(path) => {
+                let mut current = Some(id);
+                path.iter().rev().all(|key| {
+                    let Some(id) = current else { return false };
+                    current = ctx.parent(id);
+                    ctx.location(id).is_some_and(|location| location.key == &**key)
+                })
+            }
+            Self::NthChild { index, from_end } => ctx.location(id).is_some_and(|location| {
+                location.index.is_some_and(|position| {
+                    let position = if *from_end { location.len - position } else { position + 1 };
+                    position == *index
+                })
+            }),
+            Self::Class(class) => class.matches(id, ctx),
+            Self::Compound(selectors) => {
+                selectors.iter().all(|selector| selector.matches_id(id, scope, ctx))
+            }
+            Self::Matches(selectors) => {
+                selectors.iter().any(|selector| selector.matches_id(id, scope, ctx))
+            }
+            Self::Not(selectors) => {
+                !selectors.iter().any(|selector| selector.matches_id(id, scope, ctx))
+            }
+            Self::Has(selectors) => {
+                let mut stack = ctx.children(id).to_vec();
+                while let Some(descendant) = stack.pop() {
+                    if selectors
+                        .iter()
+                        .any(|selector| selector.matches_id(descendant, Some(id), ctx))
+                    {
+                        return true;
+                    }
+                    stack.extend_from_slice(ctx.children(descendant));
+                }
+                false
+            }
+            Self::Relation { left, combinator, right } => {
+                if !right.matches_id(id, scope, ctx) {
+                    return false;
+                }
+                match combinator {
+                    Combinator::Child => {
+                        ctx.parent(id).is_some_and(|parent| left.matches_id(parent, scope, ctx))
+                    }
+                    Combinator::Descendant => {
+                        let mut ancestor = ctx.parent(id);
+                        while let Some(id) = ancestor {
+                            if left.matches_id(id, scope, ctx) {
+                                return true;
+                            }
+                            ancestor = ctx.parent(id);
+                        }
+                        false
+                    }
+                    Combinator::Sibling => ctx.siblings(id).is_some_and(|(siblings, position)| {
+                        siblings[..position]
+                            .iter()
+                            .flatten()
+                            .any(|id| left.matches_id(*id, scope, ctx))
+                    }),
+                    Combinator::Adjacent => ctx.siblings(id).is_some_and(|(siblings, position)| {
+                        position > 0
+                            && siblings[position - 1]
+                                .is_some_and(|sibling| left.matches_id(sibling, scope, ctx))
+                    }),
+                }
+            }
+            Self::Scope => scope == Some(id),
+        }
+    }
+}
+
+impl NodeClass {
+    fn matches(self, id: NodeId, ctx: &SelectorContext) -> bool {
+        let Some(ty) = ctx.node_type(id) else { return false };
+        match self {
+            Self::Statement => ty.ends_with("Statement") || ty.ends_with("Declaration"),
+            Self::Declaration => ty.ends_with("Declaration"),
+            Self::Function => matches!(
+                ty,
+                "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+            ),
+            Self::Expression => is_expression(ty, id, ctx),
+            Self::Pattern => ty.ends_with("Pattern") || is_expression(ty, id, ctx),
+        }
+    }
+}
+
+fn is_expression(ty: &str, id: NodeId, ctx: &SelectorContext) -> bool {
+    ty.ends_with("Expression")
+        || ty.ends_with("Literal")
+        || ty == "MetaProperty"
+        || (ty == "Identifier"
+            && ctx
+                .parent(id)
+                .is_none_or(|parent| !matches!(ctx.nodes.kind(parent), AstKind::MetaProperty(_))))
+}
+
+impl Attribute {
+    fn matches(&self, node: Value) -> bool {
+        let value = self.path.iter().try_fold(node, |value, key| match value {
+            Value::Object(mut object) => object.remove(&**key),
+            Value::Array(array) if &**key == "length" => Some(Value::from(array.len())),
+            Value::Array(mut array) => {
+                let index = key.parse::<usize>().ok().filter(|index| *index < array.len())?;
+                Some(array.swap_remove(index))
+            }
+            Value::String(string) if &**key == "length" => {
+                Some(Value::from(string.encode_utf16().count()))
+            }
+            _ => None,
+        });
+
+        let Some((operator, expected)) = &self.test else {
+            return value.is_some_and(|value| !value.is_null());
+        };
+
+        match expected {
+            AttributeValue::Literal(expected) => match operator {
+                AttributeOperator::Eq => to_js_string(value.as_ref()) == **expected,
+                AttributeOperator::NotEq => to_js_string(value.as_ref()) != **expected,
+                AttributeOperator::Lt
+                | AttributeOperator::LtEq
+                | AttributeOperator::Gt
+                | AttributeOperator::GtEq => {
+                    let (Some(value), Ok(expected)) =
+                        (value.as_ref().and_then(Value::as_f64), expected.parse::<f64>())
+                    else {
+                        return false;
+                    };
+                    match operator {
+                        AttributeOperator::Lt => value < expected,
+                        AttributeOperator::LtEq => value <= expected,
+                        AttributeOperator::Gt => value > expected,
+                        _ => value >= expected,
+                    }
+                }
+            },
+            AttributeValue::Regex(regex) => {
+                let is_match = value
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .is_some_and(|value| regex.is_match(value));
+                if *operator == AttributeOperator::NotEq { !is_match } else { is_match }
+            }
+            AttributeValue::Type(expected) => {
+                let is_match = js_typeof(value.as_ref()) == &**expected;
+                if *operator == AttributeOperator::NotEq { !is_match } else { is_match }
+            }
+        }
+    }
+}
+
+/// `String(value)` in JavaScript.
+fn to_js_string(value: Option<&Value>) -> String {
+    match value {
+        None => "undefined".to_string(),
+        Some(Value::Null) => "null".to_string(),
+        Some(Value::Bool(value)) => value.to_string(),
+        Some(Value::Number(number)) => number.as_f64().map_or_else(String::new, number_to_string),
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| if value.is_null() { String::new() } else { to_js_string(Some(value)) })
+            .collect::<Vec<_>>()
+            .join(","),
+        Some(Value::Object(_)) => "[object Object]".to_string(),
+    }
+}
+
+/// `typeof value` in JavaScript.
+fn js_typeof(value: Option<&Value>) -> &'static str {
+    match value {
+        None => "undefined",
+        Some(Value::Null | Value::Array(_) | Value::Object(_)) => "object",
+        Some(Value::Bool(_)) => "boolean",
+        Some(Value::Number(_)) => "number",
+        Some(Value::String(_)) => "string",
+    }
+}
+
+/// `String(number)` in JavaScript, for the numbers which appear in selectors and ASTs.
+pub(super) fn number_to_string(number: f64) -> String {
+    #[expect(clippy::cast_possible_truncation)]
+    if number.fract() == 0.0 && number.abs() < 1e21 {
+        format!("{}", number as i64)
+    } else {
+        number.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{Selector, SelectorContext};
+
+    const SOURCE: &str = r#"
+        "use strict";
+        import foo, { bar as baz } from "foo" with { type: "json" };
+        import * as ns from "ns";
+        export { baz };
+        export default class A<T> extends B implements C {
+            #x = 1;
+            static accessor y;
+            constructor(private readonly p: number, q = 2) { super(); }
+            get z() { return this.#x in this; }
+            static { label: for (;;) { break label; } }
+        }
+        const { a = 1, b: [c, ...d], ...e } = obj, [f, , g = 2] = arr;
+        ({ a, b: c.d, ...e } = obj);
+        [a, ...b] = c;
+        function* gen(x, { y } = {}, ...rest) { yield* x; }
+        async (a) => { await a?.b?.[c](); };
+        new Foo(...args), a ? b : c, typeof a, a++, -a, a ** b, a && b, a ??= b;
+        tag`a${b}c`;
+        /re/g, 1n, null, true, 1.5, import.meta, import("x");
+        switch (a) { case 1: debugger; default: throw new Error(); }
+        try { if (a) {} else ; } catch ({ message }) {} finally {}
+        do {} while (a); while (a) continue; for (const k in o); for (const v of o);
+        with (a) {}
+        let t: (string | number)[] = [], u: A & B = x as any, v = y!, s = x satisfies T;
+        type Fn = (a: string, ...b: number[]) => void;
+        type M = { readonly [K in keyof T]?: T[K] } & { (x: number): string; new (): T; m(): void; [key: string]: any; p: 1 };
+        type C = T extends infer U ? `a${U}` : typeof import("x").y.z | [a: string, b?: number, ...c: any[]];
+        interface I extends J<K> { }
+        enum E { A = 1, B }
+        declare module "m" { export = x; }
+        declare global { }
+        namespace N.M { }
+        import eq = require("eq");
+        export as namespace UMD;
+        function assert(x: unknown): asserts x is string {}
+        function f(this: Window) {}
+        abstract class Q { @dec m() {} abstract n(): void; abstract p: string; abstract accessor q: number; }
+        const el = <div a="1" {...b}><x.y /><a:b /> text {c} {...d} <></></div>;
+        const inst = f<string>;
+    "#;
+
+    #[test]
+    fn estree_types() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::tsx();
+        let ret = Parser::new(&allocator, SOURCE, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let ctx = SelectorContext::new(semantic.nodes(), true);
+
+        for node in semantic.nodes() {
+            let Some(ty) = ctx.node_type(node.id()) else { continue };
+            let json = ctx.attribute_value(node.id(), &["type".into()]);
+            assert_eq!(
+                json.get("type").and_then(|ty| ty.as_str()),
+                Some(ty),
+                "{:?} at {:?}",
+                node.kind().debug_name(),
+                oxc_span::GetSpan::span(&node.kind()),
+            );
+        }
+    }
+
+    fn matches(source: &str, selector: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source, SourceType::tsx()).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+        let ctx = SelectorContext::new(semantic.nodes(), true);
+        let selector = Selector::parse(selector).unwrap();
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| selector.matches(node, &ctx))
+            .map(|node| oxc_span::GetSpan::span(&node.kind()).source_text(source).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn selectors() {
+        let cases: &[(&str, &str, &[&str])] = &[
+            ("a; b;", "Identifier", &["a", "b"]),
+            ("a; b;", "identifier", &["a", "b"]),
+            ("a; b;", "Identifier[name='b']", &["b"]),
+            ("a; b;", "Identifier[name!=b]", &["a"]),
+            ("foo; bar;", "Identifier[name=/^f/]", &["foo"]),
+            ("foo; Bar;", "Identifier[name=/^b/i]", &["Bar"]),
+            ("1; '1';", "Literal[value=1]", &["1", "'1'"]),
+            ("1; '1';", "Literal[value=type(number)]", &["1"]),
+            ("1; '1';", "Literal[value!=type(number)]", &["'1'"]),
+            ("1; 2; 3;", "Literal[value>1]", &["2", "3"]),
+            ("1; 2; 3;", "Literal[value<=2]", &["1", "2"]),
+            ("f(); g(1);", "CallExpression[arguments.length=0]", &["f()"]),
+            ("f(1, 2);", "CallExpression[arguments.1.value=2]", &["f(1, 2)"]),
+            ("a.b(); c();", "CallExpression[callee.object.name='a']", &["a.b()"]),
+            ("a.b(); c();", "[callee.object]", &["a.b()"]),
+            ("(function () {}); async () => {};", "[async=true]", &["async () => {}"]),
+            ("x = null;", "Literal[value=null]", &["null"]),
+            ("f(a);", "CallExpression > Identifier", &["f", "a"]),
+            ("f(a.b);", "CallExpression > Identifier", &["f"]),
+            ("f(a.b);", "CallExpression Identifier", &["f", "a", "b"]),
+            ("f(a.b);", "CallExpression MemberExpression > Identifier", &["a", "b"]),
+            (
+                "var a; var b; f(); var c;",
+                "VariableDeclaration ~ VariableDeclaration",
+                &["var b;", "var c;"],
+            ),
+            ("var a; var b; f(); var c;", "VariableDeclaration + VariableDeclaration", &["var b;"]),
+            ("a; 1;", ":matches(Identifier, Literal)", &["a", "1"]),
+            ("a; 1;", "Identifier, Literal", &["a", "1"]),
+            ("a; 1;", ":is(Literal)", &["1"]),
+            ("a; b; 1;", "Identifier:not([name=a])", &["b"]),
+            ("a; b; 1;", "ExpressionStatement > :not(Identifier, Literal[value=2])", &["1"]),
+            ("f(a); g(b.c);", "CallExpression:has(MemberExpression)", &["g(b.c)"]),
+            ("f(g(a)); h(b);", "CallExpression:has(> CallExpression)", &["f(g(a))"]),
+            ("f(g(a)); h(b);", "ExpressionStatement:has(Identifier[name=a])", &["f(g(a));"]),
+            ("f(a.b); g(a);", ":has(> MemberExpression > Identifier[name=a])", &["f(a.b)"]),
+            ("f(a, b, c);", "CallExpression > Identifier:first-child", &["a"]),
+            ("f(a, b, c);", "CallExpression > Identifier:last-child", &["c"]),
+            ("f(a, b, c);", "CallExpression > :nth-child(3)", &["c"]),
+            ("[a, , b, c];", "Identifier + Identifier", &["c"]),
+            ("[a, , b, c];", ":nth-child(3)", &["b"]),
+            ("f(a.b);", ".callee", &["f"]),
+            ("f(a.b);", "Identifier.arguments.property", &["b"]),
+            ("({ a, b: c });", "Identifier.value", &["a", "c"]),
+            ("({ a, b: c });", "Identifier.key", &["a", "b"]),
+            ("f(a, b, c);", "CallExpression > :nth-last-child(2)", &["b"]),
+            ("a; function f() {}", ":statement", &["a;", "function f() {}", "{}"]),
+            ("function f() { return a; }", ":declaration", &["function f() { return a; }"]),
+            ("function f() {} (() => {});", ":function", &["function f() {}", "() => {}"]),
+            ("new.target; a;", ":expression", &["new.target", "a"]),
+            ("(a);", "ExpressionStatement > Identifier", &["a"]),
+            ("'use strict'; a;", "ExpressionStatement[directive]", &["'use strict';"]),
+            ("function f(a = 1) {}", "AssignmentPattern > Identifier", &["a"]),
+            (
+                "class A { m() {} }",
+                "MethodDefinition[kind=method] > FunctionExpression",
+                &["() {}"],
+            ),
+            ("let x: string;", "TSStringKeyword", &["string"]),
+            ("let x = <div />;", "JSXOpeningElement[selfClosing=true]", &["<div />"]),
+            ("() => a; () => { b; };", "ArrowFunctionExpression > *", &["a", "{ b; }"]),
+        ];
+        for (source, selector, expected) in cases {
+            assert_eq!(&matches(source, selector), expected, "{selector} in {source}");
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! An [esquery](https://github.com/estools/esquery) selector engine.
+//!
+//! Selectors are written against the ESTree shape of the AST, as in ESLint: node types are ESTree
+//! types (e.g. `Identifier`, `MemberExpression` or `Literal`) and attributes are read from the
+//! ESTree serialization of a node. Matching walks the [`AstNodes`](oxc_semantic::AstNodes) of the
+//! semantic model, skipping nodes which have no ESTree counterpart, such as `ParenthesizedExpression`
+//! or `FormalParameters`.
+//!
+//! Supported syntax:
+//!
+//! * wildcard: `*`
+//! * node type: `ForStatement`
+//! * attribute existence: `[attr]`
+//! * attribute value: `[attr="foo"]`, `[attr=123]`, `[attr!=foo]`, `[attr.level2="foo"]`
+//! * attribute regex: `[attr=/foo.*/]`, `[attr!=/foo/i]`
+//! * attribute type: `[attr=type(string)]`
+//! * attribute comparison: `[attr>2]`, `[attr<=2]`, `[arguments.length=0]`
+//! * field: `.callee`, `FunctionDeclaration > Identifier.id`
+//! * compound: `Identifier[name="foo"]`
+//! * descendant: `FunctionDeclaration Identifier`
+//! * child: `BinaryExpression > Literal`
+//! * following sibling: `VariableDeclaration ~ VariableDeclaration`
+//! * adjacent sibling: `ImportDeclaration + ExpressionStatement`
+//! * alternatives: `:matches(A, B)`, `:is(A, B)`, `A, B`
+//! * negation: `:not(A, B)`
+//! * has: `:has(Identifier)`, `:has(> Identifier)`
+//! * position: `:first-child`, `:last-child`, `:nth-child(2)`, `:nth-last-child(2)`
+//! * class: `:statement`, `:expression`, `:declaration`, `:function`, `:pattern`
+//!
+//! Like in esquery, siblings are the other nodes in the same list property of the parent node (e.g.
+//! the `body` of a `BlockStatement`), and `:nth-child` counts positions in that list.
+
+mod matcher;
+mod parser;
+mod serializer;
+
+use std::fmt;
+
+use lazy_regex::Regex;
+
+pub use matcher::SelectorContext;
+
+/// A parsed esquery selector.
+#[derive(Debug, Clone)]
+pub enum Selector {
+    /// `*`
+    Wildcard,
+    /// `Identifier`, compared case-insensitively.
+    Type(Box<str>),
+    /// `[callee.name="foo"]`
+    Attribute(Attribute),
+    /// `.callee.object`, matches if the node is stored in these properties of its ancestors.
+    Field(Vec<Box<str>>),
+    /// `:nth-child(2)`, or `:nth-last-child(2)` with `from_end`.
+    NthChild { index: usize, from_end: bool },
+    /// `:statement`
+    Class(NodeClass),
+    /// `Identifier[name="foo"]`, matches if all of the selectors match.
+    Compound(Vec<Selector>),
+    /// `:matches(A, B)`, matches if any of the selectors match.
+    Matches(Vec<Selector>),
+    /// `:not(A, B)`, matches if none of the selectors match.
+    Not(Vec<Selector>),
+    /// `:has(A, B)`, matches if any descendant matches one of the selectors.
+    Has(Vec<Selector>),
+    /// `A B`, `A > B`, `A ~ B` or `A + B`.
+    Relation { left: Box<Selector>, combinator: Combinator, right: Box<Selector> },
+    /// The node `:has` is evaluated for, which is the implicit left side of its selectors.
+    Scope,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// `A B`
+    Descendant,
+    /// `A > B`
+    Child,
+    /// `A ~ B`
+    Sibling,
+    /// `A + B`
+    Adjacent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    /// Property path, e.g. `["callee", "name"]`.
+    pub path: Vec<Box<str>>,
+    /// `None` checks that the attribute exists.
+    pub test: Option<(AttributeOperator, AttributeValue)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    /// A string, number or name, compared with the string representation of the attribute.
+    Literal(Box<str>),
+    /// `/foo/i`
+    Regex(Regex),
+    /// `type(string)`
+    Type(Box<str>),
+}
+
+/// An error in the syntax of a selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    pub message: String,
+    /// Byte offset of the error in the selector.
+    pub offset: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+impl Selector {
+    /// Parse an esquery selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `selector` is not a valid selector.
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        parser::Parser::new(selector).parse()
+    }
+}
@@ -0,0 +1,534 @@
+use cow_utils::CowUtils;
+use lazy_regex::Regex;
+
+use super::{
+    Attribute, AttributeOperator, AttributeValue, Combinator, NodeClass, Selector, SelectorError,
+    matcher::number_to_string,
+};
+
+/// Recursive descent parser for esquery selectors.
+pub struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    pub fn new(source: &'s str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub fn parse(mut self) -> Result<Selector, SelectorError> {
+        self.skip_whitespace();
+        let selectors = self.parse_selectors(false)?;
+        self.skip_whitespace();
+        if let Some(c) = self.peek() {
+            return Err(self.error(format!("Unexpected `{c}`")));
+        }
+        Ok(Self::alternatives(selectors))
+    }
+
+    /// `A, B`
+    fn parse_selectors(&mut self, relative: bool) -> Result<Vec<Selector>, SelectorError> {
+        let mut selectors = vec![self.parse_selector(relative)?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(selectors);
+            }
+            self.skip_whitespace();
+            selectors.push(self.parse_selector(relative)?);
+        }
+    }
+
+    /// `A > B C`, or `> B C` relative to the [`Selector::Scope`] of `:has`.
+    fn parse_selector(&mut self, relative: bool) -> Result<Selector, SelectorError> {
+        let mut selector = if relative {
+            let combinator = self.parse_combinator().unwrap_or(Combinator::Descendant);
+            self.skip_whitespace();
+            Selector::Relation {
+                left: Box::new(Selector::Scope),
+                combinator,
+                right: Box::new(self.parse_sequence()?),
+            }
+        } else {
+            self.parse_sequence()?
+        };
+
+        loop {
+            let has_whitespace = self.skip_whitespace();
+            let combinator = match self.parse_combinator() {
+                Some(combinator) => {
+                    self.skip_whitespace();
+                    combinator
+                }
+                None if has_whitespace && self.peek().is_some_and(|c| !matches!(c, ',' | ')')) => {
+                    Combinator::Descendant
+                }
+                None => return Ok(selector),
+            };
+            let right = self.parse_sequence()?;
+            selector =
+                Selector::Relation { left: Box::new(selector), combinator, right: Box::new(right) };
+        }
+    }
+
+    fn parse_combinator(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            '>' => Combinator::Child,
+            '~' => Combinator::Sibling,
+            '+' => Combinator::Adjacent,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(combinator)
+    }
+
+    /// `Identifier[name="foo"]:not(Foo)`
+    fn parse_sequence(&mut self) -> Result<Selector, SelectorError> {
+        let mut atoms = vec![];
+        while let Some(c) = self.peek() {
+            let atom = match c {
+                '*' => {
+                    self.pos += 1;
+                    Selector::Wildcard
+                }
+                '[' => self.parse_attribute()?,
+                '.' => {
+                    self.pos += 1;
+                    Selector::Field(self.parse_path()?)
+                }
+                ':' => self.parse_pseudo_class()?,
+                c if is_identifier_start(c) => Selector::Type(self.parse_identifier()?.into()),
+                _ => break,
+            };
+            atoms.push(atom);
+        }
+        match atoms.len() {
+            0 => Err(match self.peek() {
+                Some(c) => self.error(format!("Expected a selector, but found `{c}`")),
+                None => self.error("Expected a selector"),
+            }),
+            1 => Ok(atoms.pop().unwrap()),
+            _ => Ok(Selector::Compound(atoms)),
+        }
+    }
+
+    /// `[attr]`, `[attr="foo"]`, `[attr=/foo/]`, `[attr=type(string)]`, `[attr>1]`
+    fn parse_attribute(&mut self) -> Result<Selector, SelectorError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let path = self.parse_path()?;
+        self.skip_whitespace();
+
+        let test = if self.eat(']') {
+            None
+        } else {
+            let operator = self.parse_attribute_operator()?;
+            self.skip_whitespace();
+            let value = self.parse_attribute_value(operator)?;
+            self.skip_whitespace();
+            self.expect(']')?;
+            Some((operator, value))
+        };
+
+        Ok(Selector::Attribute(Attribute { path, test }))
+    }
+
+    fn parse_attribute_operator(&mut self) -> Result<AttributeOperator, SelectorError> {
+        let operators = [
+            ("!=", AttributeOperator::NotEq),
+            ("<=", AttributeOperator::LtEq),
+            (">=", AttributeOperator::GtEq),
+            ("=", AttributeOperator::Eq),
+            ("<", AttributeOperator::Lt),
+            (">", AttributeOperator::Gt),
+        ];
+        for (token, operator) in operators {
+            if self.rest().starts_with(token) {
+                self.pos += token.len();
+                return Ok(operator);
+            }
+        }
+        Err(self.error("Expected an attribute operator or `]`"))
+    }
+
+    fn parse_attribute_value(
+        &mut self,
+        operator: AttributeOperator,
+    ) -> Result<AttributeValue, SelectorError> {
+        let is_equality = matches!(operator, AttributeOperator::Eq | AttributeOperator::NotEq);
+        match self.peek() {
+            Some('"' | '\'') => Ok(AttributeValue::Literal(self.parse_string()?.into())),
+            Some('/') if is_equality => Ok(AttributeValue::Regex(self.parse_regex()?)),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => {
+                Ok(AttributeValue::Literal(self.parse_number()?.into()))
+            }
+            Some(c) if is_identifier_start(c) => {
+                if is_equality && self.rest().starts_with("type(") {
+                    self.pos += "type(".len();
+                    self.skip_whitespace();
+                    let name = self.parse_identifier()?;
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    return Ok(AttributeValue::Type(name.into()));
+                }
+                Ok(AttributeValue::Literal(self.parse_path()?.join(".").into()))
+            }
+            _ => Err(self.error("Expected an attribute value")),
+        }
+    }
+
+    /// `"foo"` or `'foo'`, where `\` escapes the next character.
+    fn parse_string(&mut self) -> Result<String, SelectorError> {
+        let start = self.pos;
+        let Some(quote) = self.bump() else {
+            return Err(self.error("Expected a string"));
+        };
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        Err(SelectorError { message: "Unterminated string".to_string(), offset: start })
+    }
+
+    /// `/foo/i`
+    fn parse_regex(&mut self) -> Result<Regex, SelectorError> {
+        let start = self.pos;
+        self.expect('/')?;
+        let mut pattern = String::new();
+        let mut in_class = false;
+        loop {
+            match self.bump() {
+                Some('\\') => {
+                    pattern.push('\\');
+                    match self.bump() {
+                        Some(c) => pattern.push(c),
+                        None => break,
+                    }
+                }
+                Some('/') if !in_class => {
+                    let flags_start = self.pos;
+                    while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                        self.pos += 1;
+                    }
+                    let mut flags = String::new();
+                    for flag in self.source[flags_start..self.pos].chars() {
+                        match flag {
+                            'i' | 'm' | 's' => flags.push(flag),
+                            // Patterns always match unicode code points.
+                            'u' => {}
+                            _ => {
+                                return Err(SelectorError {
+                                    message: format!(
+                                        "Unsupported regular expression flag `{flag}`"
+                                    ),
+                                    offset: flags_start,
+                                });
+                            }
+                        }
+                    }
+                    let pattern =
+                        if flags.is_empty() { pattern } else { format!("(?{flags}){pattern}") };
+                    return Regex::new(&pattern).map_err(|err| SelectorError {
+                        message: format!("Invalid regular expression: {err}"),
+                        offset: start,
+                    });
+                }
+                Some(c) => {
+                    match c {
+                        '[' => in_class = true,
+                        ']' => in_class = false,
+                        _ => {}
+                    }
+                    pattern.push(c);
+                }
+                None => break,
+            }
+        }
+        Err(SelectorError { message: "Unterminated regular expression".to_string(), offset: start })
+    }
+
+    fn parse_number(&mut self) -> Result<String, SelectorError> {
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let number = self.source[start..self.pos].parse::<f64>();
+        let Ok(number) = number else {
+            return Err(SelectorError { message: "Invalid number".to_string(), offset: start });
+        };
+        Ok(number_to_string(number))
+    }
+
+    /// `:not(A)`, `:first-child`, `:statement`, ...
+    fn parse_pseudo_class(&mut self) -> Result<Selector, SelectorError> {
+        let start = self.pos;
+        self.expect(':')?;
+        let name = self.parse_identifier()?;
+        let selector = match name.cow_to_ascii_lowercase().as_ref() {
+            "not" => Selector::Not(self.parse_arguments(false)?),
+            "matches" | "is" => Self::alternatives(self.parse_arguments(false)?),
+            "has" => Selector::Has(self.parse_arguments(true)?),
+            "first-child" => Selector::NthChild { index: 1, from_end: false },
+            "last-child" => Selector::NthChild { index: 1, from_end: true },
+            "nth-child" => Selector::NthChild { index: self.parse_index()?, from_end: false },
+            "nth-last-child" => Selector::NthChild { index: self.parse_index()?, from_end: true },
+            "statement" => Selector::Class(NodeClass::Statement),
+            "expression" => Selector::Class(NodeClass::Expression),
+            "declaration" => Selector::Class(NodeClass::Declaration),
+            "function" => Selector::Class(NodeClass::Function),
+            "pattern" => Selector::Class(NodeClass::Pattern),
+            _ => {
+                return Err(SelectorError {
+                    message: format!("Unknown pseudo-class `:{name}`"),
+                    offset: start,
+                });
+            }
+        };
+        Ok(selector)
+    }
+
+    fn parse_arguments(&mut self, relative: bool) -> Result<Vec<Selector>, SelectorError> {
+        self.expect('(')?;
+        self.skip_whitespace();
+        let selectors = self.parse_selectors(relative)?;
+        self.skip_whitespace();
+        self.expect(')')?;
+        Ok(selectors)
+    }
+
+    /// `(2)` of `:nth-child(2)`
+    fn parse_index(&mut self) -> Result<usize, SelectorError> {
+        self.expect('(')?;
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let index = self.source[start..self.pos].parse::<usize>().ok().filter(|index| *index > 0);
+        let Some(index) = index else {
+            return Err(SelectorError {
+                message: "Expected a positive integer".to_string(),
+                offset: start,
+            });
+        };
+        self.skip_whitespace();
+        self.expect(')')?;
+        Ok(index)
+    }
+
+    /// `callee.object.name`
+    fn parse_path(&mut self) -> Result<Vec<Box<str>>, SelectorError> {
+        let mut path = vec![self.parse_path_segment()?.into()];
+        while self.eat('.') {
+            path.push(self.parse_path_segment()?.into());
+        }
+        Ok(path)
+    }
+
+    /// An identifier, or an array index.
+    fn parse_path_segment(&mut self) -> Result<&'s str, SelectorError> {
+        if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            return Ok(&self.source[start..self.pos]);
+        }
+        self.parse_identifier()
+    }
+
+    fn parse_identifier(&mut self) -> Result<&'s str, SelectorError> {
+        let start = self.pos;
+        if !self.peek().is_some_and(is_identifier_start) {
+            return Err(self.error("Expected an identifier"));
+        }
+        while self.peek().is_some_and(|c| is_identifier_start(c) || c.is_ascii_digit() || c == '-')
+        {
+            self.pos += 1;
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    fn alternatives(mut selectors: Vec<Selector>) -> Selector {
+        if selectors.len() == 1 { selectors.pop().unwrap() } else { Selector::Matches(selectors) }
+    }
+
+    fn rest(&self) -> &'s str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(match self.peek() {
+                Some(found) => self.error(format!("Expected `{c}`, but found `{found}`")),
+                None => self.error(format!("Expected `{c}`")),
+            })
+        }
+    }
+
+    /// Returns `true` if any whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn error<T: Into<String>>(&self, message: T) -> SelectorError {
+        SelectorError { message: message.into(), offset: self.pos }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{AttributeOperator, AttributeValue, Combinator, Selector};
+
+    fn parse(selector: &str) -> Selector {
+        Selector::parse(selector).unwrap_or_else(|err| panic!("{selector}: {err}"))
+    }
+
+    #[test]
+    fn valid() {
+        let selectors = [
+            "*",
+            "Identifier",
+            "  Identifier  ",
+            "[name]",
+            "[name='foo']",
+            "[name=\"foo\"]",
+            "[ name = foo ]",
+            "[callee.object.name=console]",
+            "[arguments.length=0]",
+            "[arguments.0.value='foo']",
+            "[value=-1.5]",
+            "[value>=2]",
+            "[name=/^foo/i]",
+            "[name!=/[/]/]",
+            "[value=type(string)]",
+            "Identifier[name=foo][parent]",
+            ".callee",
+            "Identifier.callee.object",
+            "FunctionDeclaration Identifier",
+            "BinaryExpression > Literal",
+            "BinaryExpression>Literal",
+            "VariableDeclaration ~ VariableDeclaration",
+            "ImportDeclaration + ExpressionStatement",
+            "Identifier, Literal",
+            ":matches(Identifier, Literal)",
+            ":is(Identifier)",
+            "Identifier:not([name=foo], [name=bar])",
+            "CallExpression:has(Identifier[name=foo])",
+            "CallExpression:has(> Identifier, > Literal)",
+            ":first-child",
+            ":last-child",
+            ":nth-child(2)",
+            ":nth-last-child(3)",
+            ":statement, :expression, :declaration, :function, :pattern",
+        ];
+        for selector in selectors {
+            parse(selector);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let selectors = [
+            "",
+            "Identifier >",
+            "> Identifier",
+            "Identifier,",
+            "[name",
+            "[name=]",
+            "[name='foo]",
+            "[name=/foo]",
+            "[name=/(/]",
+            "[name=/foo/g]",
+            ".",
+            "Identifier.",
+            "[name=type(]",
+            ":unknown",
+            ":not(Identifier",
+            ":not()",
+            ":nth-child(0)",
+            ":nth-child(a)",
+            "Identifier)",
+            "#foo",
+        ];
+        for selector in selectors {
+            assert!(Selector::parse(selector).is_err(), "{selector} should not parse");
+        }
+    }
+
+    #[test]
+    fn structure() {
+        let Selector::Relation { left, combinator, right } = parse("A > B C") else {
+            panic!("expected a relation");
+        };
+        assert_eq!(combinator, Combinator::Descendant);
+        assert!(matches!(*right, Selector::Type(ref name) if &**name == "C"));
+        assert!(matches!(*left, Selector::Relation { combinator: Combinator::Child, .. }));
+
+        let Selector::Compound(atoms) = parse("Identifier[name='a b']") else {
+            panic!("expected a compound selector");
+        };
+        let Selector::Attribute(attribute) = &atoms[1] else {
+            panic!("expected an attribute selector");
+        };
+        assert_eq!(&*attribute.path[0], "name");
+        assert!(matches!(
+            attribute.test,
+            Some((AttributeOperator::Eq, AttributeValue::Literal(ref value))) if &**value == "a b"
+        ));
+
+        let Selector::Has(selectors) = parse(":has(> Identifier)") else {
+            panic!("expected :has");
+        };
+        assert!(matches!(
+            selectors[0],
+            Selector::Relation { ref left, combinator: Combinator::Child, .. }
+                if matches!(**left, Selector::Scope)
+        ));
+
+        let error = Selector::parse("Identifier[name=]").unwrap_err();
+        assert_eq!(error.to_string(), "Expected an attribute value at position 16");
+    }
+}
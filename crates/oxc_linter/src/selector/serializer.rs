@@ -0,0 +1,229 @@
+use std::{marker::PhantomData, mem};
+
+use oxc_data_structures::code_buffer::CodeBuffer;
+use oxc_estree::{
+    CompactFormatter, Config, ESTree, ESTreeSpan, SequenceSerializer, Serializer, StructSerializer,
+};
+use oxc_span::Span;
+
+/// A property of a node which holds one of its ESTree children, e.g. `arguments[1]` of a
+/// `CallExpression`.
+pub struct ChildField {
+    pub key: &'static str,
+    /// Index in the list, if the property is a list.
+    pub index: Option<usize>,
+    /// Length of the list, if the property is a list.
+    pub len: usize,
+    pub span: Span,
+}
+
+/// An ESTree serializer which only serializes the parts of a node that a selector looks at, so the
+/// cost of matching a node doesn't depend on the size of its subtree.
+///
+/// * [`ShallowSerializer::path`] serializes the values along a property path, e.g. only `callee`
+///   and then `name` for `[callee.name="foo"]`. Nodes at the end of the path are serialized as
+///   empty objects, and other elements of lists on the path as `null`.
+/// * [`ShallowSerializer::fields`] outputs no JSON, but records the property of the node which
+///   holds each of its direct children, and the span of that child.
+pub struct ShallowSerializer<'p, C: Config> {
+    buffer: CodeBuffer,
+    formatter: CompactFormatter,
+    /// Property path to serialize, `None` when collecting child fields.
+    path: Option<&'p [Box<str>]>,
+    /// Number of properties of `path` which have been entered.
+    depth: usize,
+    /// What the next struct or sequence is, when collecting child fields.
+    position: Position,
+    fields: Vec<ChildField>,
+    config: PhantomData<C>,
+}
+
+#[derive(Clone, Copy)]
+enum Position {
+    /// The node itself.
+    Root,
+    /// The value of a property of the node, or an element of a list property of the node.
+    Child(&'static str, Option<usize>),
+    /// Anything nested more deeply.
+    Nested,
+}
+
+impl<'p, C: Config> ShallowSerializer<'p, C> {
+    fn new(path: Option<&'p [Box<str>]>) -> Self {
+        Self {
+            buffer: CodeBuffer::new(),
+            formatter: CompactFormatter,
+            path,
+            depth: 0,
+            position: Position::Root,
+            fields: vec![],
+            config: PhantomData,
+        }
+    }
+
+    /// Serializer for the values along `path`. Get the JSON with [`Self::into_string`].
+    pub fn path(path: &'p [Box<str>]) -> Self {
+        Self::new(Some(path))
+    }
+
+    /// Serializer collecting child fields. Get them with [`Self::into_fields`].
+    pub fn fields() -> Self {
+        Self::new(None)
+    }
+
+    pub fn into_string(self) -> String {
+        self.buffer.into_string()
+    }
+
+    pub fn into_fields(self) -> Vec<ChildField> {
+        self.fields
+    }
+
+    /// The property of `path` which is serialized next, if there is one.
+    fn next_key(&self) -> Option<&str> {
+        self.path.and_then(|path| path.get(self.depth)).map(|key| &**key)
+    }
+}
+
+impl<'s, 'p, C: Config> Serializer for &'s mut ShallowSerializer<'p, C> {
+    const INCLUDE_TS_FIELDS: bool = C::INCLUDE_TS_FIELDS;
+
+    type Formatter = CompactFormatter;
+    type StructSerializer = ShallowStructSerializer<'s, 'p, C>;
+    type SequenceSerializer = ShallowSequenceSerializer<'s, 'p, C>;
+
+    fn ranges(&self) -> bool {
+        false
+    }
+
+    fn serialize_struct(self) -> Self::StructSerializer {
+        let position = mem::replace(&mut self.position, Position::Nested);
+        self.buffer.print_ascii_byte(b'{');
+        ShallowStructSerializer { serializer: self, position, span: None, has_fields: false }
+    }
+
+    fn serialize_sequence(self) -> Self::SequenceSerializer {
+        let position = mem::replace(&mut self.position, Position::Nested);
+        let first_field = self.fields.len();
+        self.buffer.print_ascii_byte(b'[');
+        ShallowSequenceSerializer { serializer: self, position, first_field, len: 0 }
+    }
+
+    fn record_fix_path(&mut self) {}
+
+    fn buffer_mut(&mut self) -> &mut CodeBuffer {
+        &mut self.buffer
+    }
+
+    fn buffer_and_formatter_mut(&mut self) -> (&mut CodeBuffer, &mut CompactFormatter) {
+        (&mut self.buffer, &mut self.formatter)
+    }
+}
+
+pub struct ShallowStructSerializer<'s, 'p, C: Config> {
+    serializer: &'s mut ShallowSerializer<'p, C>,
+    position: Position,
+    /// Span of the struct, if it is a child of the node.
+    span: Option<Span>,
+    has_fields: bool,
+}
+
+impl<C: Config> StructSerializer for ShallowStructSerializer<'_, '_, C> {
+    type Config = C;
+    type Formatter = CompactFormatter;
+
+    fn serialize_field<T: ESTree + ?Sized>(&mut self, key: &'static str, value: &T) {
+        if self.serializer.path.is_some() {
+            if self.serializer.next_key() != Some(key) {
+                return;
+            }
+            if self.has_fields {
+                self.serializer.buffer.print_ascii_byte(b',');
+            }
+            self.has_fields = true;
+            self.serializer.buffer.print_strs_array(["\"", key, "\":"]);
+            self.serializer.depth += 1;
+            value.serialize(&mut *self.serializer);
+            self.serializer.depth -= 1;
+        } else if matches!(self.position, Position::Root) {
+            self.serializer.position = Position::Child(key, None);
+            value.serialize(&mut *self.serializer);
+            self.serializer.position = Position::Nested;
+        }
+    }
+
+    fn serialize_js_field<T: ESTree + ?Sized>(&mut self, key: &'static str, value: &T) {
+        if !C::INCLUDE_TS_FIELDS {
+            self.serialize_field(key, value);
+        }
+    }
+
+    fn serialize_ts_field<T: ESTree + ?Sized>(&mut self, key: &'static str, value: &T) {
+        if C::INCLUDE_TS_FIELDS {
+            self.serialize_field(key, value);
+        }
+    }
+
+    fn serialize_span<S: ESTreeSpan>(&mut self, span: S) {
+        let [start, end] = span.range();
+        if self.serializer.path.is_some() {
+            self.serialize_field("start", &start);
+            self.serialize_field("end", &end);
+        } else if matches!(self.position, Position::Child(..)) {
+            self.span = Some(Span::new(start, end));
+        }
+    }
+
+    fn end(self) {
+        self.serializer.buffer.print_ascii_byte(b'}');
+        if let (Position::Child(key, index), Some(span)) = (self.position, self.span) {
+            self.serializer.fields.push(ChildField { key, index, len: 0, span });
+        }
+    }
+
+    fn ranges(&self) -> bool {
+        false
+    }
+}
+
+pub struct ShallowSequenceSerializer<'s, 'p, C: Config> {
+    serializer: &'s mut ShallowSerializer<'p, C>,
+    position: Position,
+    /// Index of the first field in `serializer.fields` which may be an element of this sequence.
+    first_field: usize,
+    len: usize,
+}
+
+impl<C: Config> SequenceSerializer for ShallowSequenceSerializer<'_, '_, C> {
+    fn serialize_element<T: ESTree + ?Sized>(&mut self, value: &T) {
+        if self.len > 0 {
+            self.serializer.buffer.print_ascii_byte(b',');
+        }
+        if let Some(path) = self.serializer.path {
+            match path.get(self.serializer.depth) {
+                // The end of the path, serialize the whole list.
+                None => value.serialize(&mut *self.serializer),
+                Some(key) if key.parse::<usize>().is_ok_and(|index| index == self.len) => {
+                    self.serializer.depth += 1;
+                    value.serialize(&mut *self.serializer);
+                    self.serializer.depth -= 1;
+                }
+                Some(_) => self.serializer.buffer.print_str("null"),
+            }
+        } else if let Position::Child(key, None) = self.position {
+            self.serializer.position = Position::Child(key, Some(self.len));
+            value.serialize(&mut *self.serializer);
+            self.serializer.position = Position::Nested;
+        }
+        self.len += 1;
+    }
+
+    fn end(self) {
+        self.serializer.buffer.print_ascii_byte(b']');
+        if let Position::Child(..) = self.position {
+            for field in &mut self.serializer.fields[self.first_field..] {
+                field.len = self.len;
+            }
+        }
+    }
+}
@@ -87,6 +87,16 @@ impl LintService {
         self.runtime.set_disable_directives_map(map);
     }
 
+    /// Resolved paths of all modules `path` depends on, directly or transitively.
+    pub fn module_dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        self.runtime.module_dependencies(path)
+    }
+
+    /// Paths tried by the resolver for the imports of `path` and its dependencies which could not be resolved.
+    pub fn missing_module_paths(&self, path: &Path) -> Vec<PathBuf> {
+        self.runtime.missing_module_paths(path)
+    }
+
     pub fn run_source(
         &self,
         file_system: &(dyn RuntimeFileSystem + Sync + Send),
@@ -22,7 +22,7 @@ use smallvec::SmallVec;
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool, Box as ArenaBox};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser, Token, config::RuntimeParserConfig};
-use oxc_resolver::Resolver;
+use oxc_resolver::{ResolveContext, Resolver};
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use oxc_str::CompactStr;
@@ -63,6 +63,9 @@ pub struct Runtime {
     /// To make sure all `ModuleRecord` gets dropped after `Runtime` is dropped,
     /// `modules_by_path` must own `ModuleRecord` with `Arc`, all other references must use `Weak<ModuleRecord>`.
     modules_by_path: ModulesByPath,
+    /// Paths tried by the resolver for the import specifiers of each module which could not be resolved.
+    /// A module could be resolved if one of them is created.
+    missing_paths_by_module: Mutex<FxHashMap<Arc<OsStr>, Vec<PathBuf>>>,
     /// Collected disable directives from linted files
     disable_directives_map: Arc<Mutex<FxHashMap<PathBuf, DisableDirectives>>>,
 }
@@ -261,6 +264,7 @@ impl Runtime {
                 .hasher(BuildHasherDefault::default())
                 .resize_mode(papaya::ResizeMode::Blocking)
                 .build(),
+            missing_paths_by_module: Mutex::new(FxHashMap::default()),
             disable_directives_map: Arc::new(Mutex::new(FxHashMap::default())),
         }
     }
@@ -283,6 +287,53 @@ impl Runtime {
         self.disable_directives_map = map;
     }
 
+    /// Resolved paths of all modules `path` depends on, directly or transitively.
+    ///
+    /// The module graph is only constructed when the import plugin is enabled, so this is empty otherwise.
+    /// It must be called after `path` has been linted.
+    pub(super) fn module_dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        let modules_by_path = self.modules_by_path.pin();
+        let Some(records) = modules_by_path.get(path.as_os_str()) else {
+            return vec![];
+        };
+
+        let mut visited = FxHashSet::<PathBuf>::default();
+        let mut stack = records.iter().map(Arc::clone).collect::<Vec<_>>();
+        while let Some(record) = stack.pop() {
+            for dependency in record.loaded_modules().values() {
+                let Some(dependency) = dependency.upgrade() else { continue };
+                if dependency.resolved_absolute_path != path
+                    && visited.insert(dependency.resolved_absolute_path.clone())
+                {
+                    stack.push(dependency);
+                }
+            }
+        }
+
+        let mut dependencies = visited.into_iter().collect::<Vec<_>>();
+        dependencies.sort_unstable();
+        dependencies
+    }
+
+    /// Paths tried by the resolver for the imports of `path` and the modules it depends on
+    /// which could not be resolved.
+    ///
+    /// Like [`Self::module_dependencies`], this is empty if the import plugin is disabled.
+    pub(super) fn missing_module_paths(&self, path: &Path) -> Vec<PathBuf> {
+        let dependencies = self.module_dependencies(path);
+        let missing_paths_by_module =
+            self.missing_paths_by_module.lock().expect("missing_paths_by_module mutex poisoned");
+        let mut missing_paths = std::iter::once(path)
+            .chain(dependencies.iter().map(PathBuf::as_path))
+            .filter_map(|module| missing_paths_by_module.get(module.as_os_str()))
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        missing_paths.sort_unstable();
+        missing_paths.dedup();
+        missing_paths
+    }
+
     fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
         use oxc_resolver::{
             ResolveOptions, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
@@ -665,7 +716,7 @@ impl Runtime {
                                 .insert(path.to_path_buf(), disable_directives);
                         }
 
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.options().fix.is_some() && !me.linter.options().report_fixes {
                             let fix_result = Fixer::new(
                                 dep.source_text,
                                 messages,
@@ -1135,7 +1186,10 @@ impl Runtime {
                 .requested_modules
                 .keys()
                 .filter_map(|specifier| {
-                    let resolution = resolver.resolve_file(path, specifier).ok()?;
+                    let Ok(resolution) = resolver.resolve_file(path, specifier) else {
+                        self.record_missing_paths(resolver, path, specifier);
+                        return None;
+                    };
                     Some(ResolvedModuleRequest {
                         specifier: specifier.clone(),
                         resolved_requested_path: Arc::<OsStr>::from(resolution.path().as_os_str()),
@@ -1145,4 +1199,21 @@ impl Runtime {
         }
         Ok((ResolvedModuleRecord { module_record, resolved_module_requests }, semantic, tokens))
     }
+
+    /// Record the paths tried by the resolver for `specifier`, which could not be resolved from `path`.
+    fn record_missing_paths(&self, resolver: &Resolver, path: &Path, specifier: &str) {
+        let Some(dir) = path.parent() else { return };
+        let tsconfig = resolver.find_tsconfig(path).ok().flatten();
+        let mut context = ResolveContext::default();
+        let _ = resolver.resolve_with_context(dir, specifier, tsconfig.as_deref(), &mut context);
+        if context.missing_dependencies.is_empty() {
+            return;
+        }
+        self.missing_paths_by_module
+            .lock()
+            .expect("missing_paths_by_module mutex poisoned")
+            .entry(Arc::from(path.as_os_str()))
+            .or_default()
+            .extend(context.missing_dependencies);
+    }
 }
@@ -0,0 +1,907 @@
+/// Names of properties of JavaScript builtins and common DOM and Node.js APIs, sorted.
+///
+/// These are never mangled unless [`crate::MangleOptionsProperties::builtins`] is set,
+/// and are never used as mangled names, so that user properties do not collide with inherited ones.
+pub static BUILTIN_PROPERTIES: &[&str] = &[
+    "$1",
+    "$2",
+    "$3",
+    "$4",
+    "$5",
+    "$6",
+    "$7",
+    "$8",
+    "$9",
+    "$_",
+    "BYTES_PER_ELEMENT",
+    "Collator",
+    "DateTimeFormat",
+    "DisplayNames",
+    "E",
+    "EPSILON",
+    "LN10",
+    "LN2",
+    "LOG10E",
+    "LOG2E",
+    "ListFormat",
+    "Locale",
+    "MAX_SAFE_INTEGER",
+    "MAX_VALUE",
+    "MIN_SAFE_INTEGER",
+    "MIN_VALUE",
+    "NEGATIVE_INFINITY",
+    "NaN",
+    "NumberFormat",
+    "PI",
+    "POSITIVE_INFINITY",
+    "PluralRules",
+    "RelativeTimeFormat",
+    "SQRT1_2",
+    "SQRT2",
+    "Segmenter",
+    "UTC",
+    "__defineGetter__",
+    "__defineSetter__",
+    "__esModule",
+    "__lookupGetter__",
+    "__lookupSetter__",
+    "__proto__",
+    "abort",
+    "aborted",
+    "abs",
+    "accept",
+    "acos",
+    "acosh",
+    "activeElement",
+    "add",
+    "addEventListener",
+    "addListener",
+    "addedNodes",
+    "adoptedCallback",
+    "after",
+    "alert",
+    "all",
+    "allSettled",
+    "alt",
+    "altKey",
+    "anchor",
+    "and",
+    "animate",
+    "any",
+    "append",
+    "appendChild",
+    "apply",
+    "arc",
+    "arcTo",
+    "arguments",
+    "argv",
+    "arrayBuffer",
+    "asIntN",
+    "asUintN",
+    "asin",
+    "asinh",
+    "assign",
+    "assignedNodes",
+    "asyncDispose",
+    "asyncIterator",
+    "at",
+    "atan",
+    "atan2",
+    "atanh",
+    "atob",
+    "attachInternals",
+    "attachShadow",
+    "attributeChangedCallback",
+    "attributeName",
+    "attributes",
+    "autocomplete",
+    "autoplay",
+    "availHeight",
+    "availWidth",
+    "back",
+    "baseName",
+    "before",
+    "beginPath",
+    "big",
+    "bind",
+    "blink",
+    "blob",
+    "blur",
+    "body",
+    "bold",
+    "borderBoxSize",
+    "bottom",
+    "boundingClientRect",
+    "btoa",
+    "bubbles",
+    "buffer",
+    "button",
+    "buttons",
+    "byteLength",
+    "byteOffset",
+    "cache",
+    "caches",
+    "calendar",
+    "calendars",
+    "call",
+    "callee",
+    "caller",
+    "cancelAnimationFrame",
+    "cancelIdleCallback",
+    "cancelable",
+    "captureStackTrace",
+    "caseFirst",
+    "catch",
+    "cause",
+    "cbrt",
+    "ceil",
+    "changedTouches",
+    "charAt",
+    "charCode",
+    "charCodeAt",
+    "checkValidity",
+    "checked",
+    "childElementCount",
+    "childNodes",
+    "children",
+    "classList",
+    "className",
+    "clear",
+    "clearInterval",
+    "clearRect",
+    "clearTimeout",
+    "click",
+    "clientHeight",
+    "clientLeft",
+    "clientTop",
+    "clientWidth",
+    "clientX",
+    "clientY",
+    "clipboard",
+    "cloneNode",
+    "close",
+    "closePath",
+    "closed",
+    "closest",
+    "clz32",
+    "code",
+    "codePointAt",
+    "collation",
+    "collations",
+    "compare",
+    "compareExchange",
+    "compile",
+    "complete",
+    "concat",
+    "configurable",
+    "confirm",
+    "connectedCallback",
+    "console",
+    "construct",
+    "constructor",
+    "contains",
+    "content",
+    "contentBoxSize",
+    "contentEditable",
+    "contentRect",
+    "controls",
+    "cookie",
+    "copyWithin",
+    "cos",
+    "cosh",
+    "create",
+    "createComment",
+    "createDocumentFragment",
+    "createElement",
+    "createElementNS",
+    "createEvent",
+    "createImageData",
+    "createRange",
+    "createTextNode",
+    "credentials",
+    "crossOrigin",
+    "crypto",
+    "cssText",
+    "ctrlKey",
+    "currentScript",
+    "currentTarget",
+    "currentTime",
+    "customElements",
+    "cwd",
+    "data",
+    "dataset",
+    "day",
+    "debug",
+    "decoding",
+    "default",
+    "defaultPrevented",
+    "defaultValue",
+    "defaultView",
+    "define",
+    "defineProperties",
+    "defineProperty",
+    "delete",
+    "deleteProperty",
+    "deltaMode",
+    "deltaX",
+    "deltaY",
+    "deltaZ",
+    "deref",
+    "description",
+    "detail",
+    "devicePixelRatio",
+    "dir",
+    "disabled",
+    "disconnect",
+    "disconnectedCallback",
+    "dispatchEvent",
+    "dispose",
+    "document",
+    "documentElement",
+    "domain",
+    "done",
+    "dotAll",
+    "draggable",
+    "drawImage",
+    "duration",
+    "elements",
+    "ended",
+    "endsWith",
+    "entries",
+    "enumerable",
+    "env",
+    "error",
+    "errors",
+    "every",
+    "exchange",
+    "exec",
+    "execCommand",
+    "exit",
+    "exp",
+    "expm1",
+    "exports",
+    "fetch",
+    "files",
+    "fill",
+    "fillRect",
+    "fillStyle",
+    "fillText",
+    "filter",
+    "finally",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "firstChild",
+    "firstElementChild",
+    "fixed",
+    "flags",
+    "flat",
+    "flatMap",
+    "floor",
+    "focus",
+    "font",
+    "fontcolor",
+    "fontsize",
+    "for",
+    "forEach",
+    "form",
+    "formData",
+    "format",
+    "formatRange",
+    "formatRangeToParts",
+    "formatToParts",
+    "forms",
+    "forward",
+    "frames",
+    "freeze",
+    "from",
+    "fromCharCode",
+    "fromCodePoint",
+    "fromEntries",
+    "fround",
+    "geolocation",
+    "get",
+    "getAllResponseHeaders",
+    "getAnimations",
+    "getAttribute",
+    "getAttributeNS",
+    "getBigInt64",
+    "getBigUint64",
+    "getBoundingClientRect",
+    "getCanonicalLocales",
+    "getClientRects",
+    "getComputedStyle",
+    "getContext",
+    "getDate",
+    "getDay",
+    "getElementById",
+    "getElementsByClassName",
+    "getElementsByName",
+    "getElementsByTagName",
+    "getFloat32",
+    "getFloat64",
+    "getFullYear",
+    "getHours",
+    "getImageData",
+    "getInt16",
+    "getInt32",
+    "getInt8",
+    "getItem",
+    "getMilliseconds",
+    "getMinutes",
+    "getMonth",
+    "getOwnPropertyDescriptor",
+    "getOwnPropertyDescriptors",
+    "getOwnPropertyNames",
+    "getOwnPropertySymbols",
+    "getPropertyValue",
+    "getPrototypeOf",
+    "getRandomValues",
+    "getResponseHeader",
+    "getRootNode",
+    "getSeconds",
+    "getSelection",
+    "getTime",
+    "getTimezoneOffset",
+    "getUTCDate",
+    "getUTCDay",
+    "getUTCFullYear",
+    "getUTCHours",
+    "getUTCMilliseconds",
+    "getUTCMinutes",
+    "getUTCMonth",
+    "getUTCSeconds",
+    "getUint16",
+    "getUint32",
+    "getUint8",
+    "getYear",
+    "global",
+    "globalAlpha",
+    "go",
+    "granularity",
+    "group",
+    "groupEnd",
+    "groups",
+    "grow",
+    "growable",
+    "handleEvent",
+    "has",
+    "hasAttribute",
+    "hasFocus",
+    "hasIndices",
+    "hasInstance",
+    "hasOwn",
+    "hasOwnProperty",
+    "hash",
+    "head",
+    "headers",
+    "height",
+    "heldValue",
+    "hidden",
+    "history",
+    "host",
+    "hostname",
+    "hourCycle",
+    "hourCycles",
+    "href",
+    "hypot",
+    "id",
+    "identifier",
+    "ignoreCase",
+    "ignorePunctuation",
+    "images",
+    "implementation",
+    "imul",
+    "includes",
+    "index",
+    "indexOf",
+    "indexedDB",
+    "indices",
+    "info",
+    "innerHTML",
+    "innerHeight",
+    "innerText",
+    "innerWidth",
+    "input",
+    "insertAdjacentElement",
+    "insertAdjacentHTML",
+    "insertAdjacentText",
+    "insertBefore",
+    "intersectionRatio",
+    "intersectionRect",
+    "is",
+    "isArray",
+    "isComposing",
+    "isConcatSpreadable",
+    "isConnected",
+    "isContentEditable",
+    "isExtensible",
+    "isFinite",
+    "isFrozen",
+    "isInteger",
+    "isIntersecting",
+    "isLockFree",
+    "isNaN",
+    "isPrototypeOf",
+    "isSafeInteger",
+    "isSealed",
+    "isView",
+    "isWellFormed",
+    "italics",
+    "iterator",
+    "join",
+    "json",
+    "key",
+    "keyCode",
+    "keyFor",
+    "keys",
+    "lang",
+    "language",
+    "languages",
+    "lastChild",
+    "lastElementChild",
+    "lastIndexOf",
+    "lastMatch",
+    "lastParen",
+    "left",
+    "leftContext",
+    "length",
+    "lineCap",
+    "lineJoin",
+    "lineTo",
+    "lineWidth",
+    "link",
+    "links",
+    "load",
+    "loading",
+    "localName",
+    "localStorage",
+    "locale",
+    "localeCompare",
+    "location",
+    "log",
+    "log10",
+    "log1p",
+    "log2",
+    "loop",
+    "map",
+    "mark",
+    "match",
+    "matchAll",
+    "matchMedia",
+    "matches",
+    "max",
+    "maxByteLength",
+    "maxLength",
+    "maximize",
+    "maximumFractionDigits",
+    "measure",
+    "measureText",
+    "media",
+    "message",
+    "metaKey",
+    "method",
+    "min",
+    "minLength",
+    "minimize",
+    "minimumFractionDigits",
+    "minimumIntegerDigits",
+    "mode",
+    "module",
+    "month",
+    "moveTo",
+    "movementX",
+    "movementY",
+    "multiline",
+    "multiple",
+    "muted",
+    "name",
+    "namespaceURI",
+    "naturalHeight",
+    "naturalWidth",
+    "navigator",
+    "next",
+    "nextElementSibling",
+    "nextSibling",
+    "nextTick",
+    "nodeName",
+    "nodeType",
+    "nodeValue",
+    "normalize",
+    "notation",
+    "notify",
+    "now",
+    "numberingSystem",
+    "numberingSystems",
+    "numeric",
+    "observe",
+    "observedAttributes",
+    "of",
+    "offsetHeight",
+    "offsetLeft",
+    "offsetParent",
+    "offsetTop",
+    "offsetWidth",
+    "offsetX",
+    "offsetY",
+    "ok",
+    "oldValue",
+    "onLine",
+    "onabort",
+    "onblur",
+    "onchange",
+    "onclick",
+    "onclose",
+    "onerror",
+    "onfocus",
+    "oninput",
+    "onkeydown",
+    "onkeyup",
+    "onload",
+    "onmessage",
+    "onmousedown",
+    "onmousemove",
+    "onmouseup",
+    "onopen",
+    "onprogress",
+    "onreadystatechange",
+    "onresize",
+    "onscroll",
+    "onsubmit",
+    "open",
+    "opener",
+    "options",
+    "or",
+    "origin",
+    "outerHTML",
+    "outerHeight",
+    "outerText",
+    "outerWidth",
+    "overrideMimeType",
+    "ownKeys",
+    "ownerDocument",
+    "padEnd",
+    "padStart",
+    "pageX",
+    "pageXOffset",
+    "pageY",
+    "pageYOffset",
+    "parent",
+    "parentElement",
+    "parentNode",
+    "parse",
+    "parseFloat",
+    "parseInt",
+    "pathname",
+    "pattern",
+    "pause",
+    "paused",
+    "performance",
+    "placeholder",
+    "platform",
+    "play",
+    "playbackRate",
+    "pluralCategories",
+    "pointerId",
+    "pointerType",
+    "pop",
+    "port",
+    "ports",
+    "postMessage",
+    "poster",
+    "pow",
+    "preload",
+    "prepareStackTrace",
+    "prepend",
+    "pressure",
+    "preventDefault",
+    "preventExtensions",
+    "previousElementSibling",
+    "previousSibling",
+    "print",
+    "process",
+    "prompt",
+    "propertyIsEnumerable",
+    "protocol",
+    "prototype",
+    "push",
+    "pushState",
+    "putImageData",
+    "querySelector",
+    "querySelectorAll",
+    "queueMicrotask",
+    "race",
+    "random",
+    "randomUUID",
+    "raw",
+    "readOnly",
+    "readText",
+    "readyState",
+    "reason",
+    "rect",
+    "redirect",
+    "reduce",
+    "reduceRight",
+    "referrer",
+    "region",
+    "register",
+    "reject",
+    "rel",
+    "relatedTarget",
+    "removeAttribute",
+    "removeChild",
+    "removeEventListener",
+    "removeItem",
+    "removeListener",
+    "removeProperty",
+    "removedNodes",
+    "repeat",
+    "replace",
+    "replaceAll",
+    "replaceChild",
+    "replaceChildren",
+    "replaceState",
+    "replaceWith",
+    "reportValidity",
+    "requestAnimationFrame",
+    "requestIdleCallback",
+    "require",
+    "required",
+    "reset",
+    "resizable",
+    "resize",
+    "resolve",
+    "resolvedOptions",
+    "response",
+    "responseText",
+    "responseType",
+    "responseURL",
+    "responseXML",
+    "restore",
+    "return",
+    "reverse",
+    "revocable",
+    "right",
+    "rightContext",
+    "rootBounds",
+    "rotate",
+    "round",
+    "roundingIncrement",
+    "roundingMode",
+    "roundingPriority",
+    "save",
+    "scale",
+    "screen",
+    "screenX",
+    "screenY",
+    "script",
+    "scripts",
+    "scroll",
+    "scrollBy",
+    "scrollHeight",
+    "scrollIntoView",
+    "scrollLeft",
+    "scrollTo",
+    "scrollTop",
+    "scrollWidth",
+    "scrollX",
+    "scrollY",
+    "seal",
+    "search",
+    "segment",
+    "select",
+    "selectRange",
+    "selected",
+    "selectedIndex",
+    "selectionEnd",
+    "selectionStart",
+    "self",
+    "send",
+    "sensitivity",
+    "serviceWorker",
+    "sessionStorage",
+    "set",
+    "setAttribute",
+    "setAttributeNS",
+    "setBigInt64",
+    "setBigUint64",
+    "setCustomValidity",
+    "setDate",
+    "setFloat32",
+    "setFloat64",
+    "setFullYear",
+    "setHours",
+    "setInt16",
+    "setInt32",
+    "setInt8",
+    "setInterval",
+    "setItem",
+    "setMilliseconds",
+    "setMinutes",
+    "setMonth",
+    "setProperty",
+    "setPrototypeOf",
+    "setRequestHeader",
+    "setSeconds",
+    "setSelectionRange",
+    "setTime",
+    "setTimeout",
+    "setTransform",
+    "setUTCDate",
+    "setUTCFullYear",
+    "setUTCHours",
+    "setUTCMilliseconds",
+    "setUTCMinutes",
+    "setUTCMonth",
+    "setUTCSeconds",
+    "setUint16",
+    "setUint32",
+    "setUint8",
+    "setYear",
+    "shadowRoot",
+    "shift",
+    "shiftKey",
+    "sign",
+    "signDisplay",
+    "signal",
+    "sin",
+    "sinh",
+    "size",
+    "slice",
+    "slot",
+    "small",
+    "some",
+    "sort",
+    "source",
+    "species",
+    "splice",
+    "split",
+    "sqrt",
+    "src",
+    "srcset",
+    "stack",
+    "stackTraceLimit",
+    "startsWith",
+    "state",
+    "status",
+    "statusText",
+    "stderr",
+    "stdin",
+    "stdout",
+    "step",
+    "sticky",
+    "stopImmediatePropagation",
+    "stopPropagation",
+    "store",
+    "strike",
+    "stringify",
+    "stroke",
+    "strokeRect",
+    "strokeStyle",
+    "strokeText",
+    "structuredClone",
+    "style",
+    "styleSheets",
+    "sub",
+    "subarray",
+    "submit",
+    "substr",
+    "substring",
+    "subtle",
+    "sup",
+    "supportedLocalesOf",
+    "supportedValuesOf",
+    "tabIndex",
+    "table",
+    "tagName",
+    "takeRecords",
+    "tan",
+    "tanh",
+    "target",
+    "targetTouches",
+    "test",
+    "text",
+    "textAlign",
+    "textBaseline",
+    "textContent",
+    "textInfo",
+    "then",
+    "throw",
+    "time",
+    "timeEnd",
+    "timeStamp",
+    "timeZone",
+    "timeZones",
+    "timeout",
+    "title",
+    "toBlob",
+    "toDataURL",
+    "toDateString",
+    "toExponential",
+    "toFixed",
+    "toGMTString",
+    "toISOString",
+    "toJSON",
+    "toLocaleDateString",
+    "toLocaleLowerCase",
+    "toLocaleString",
+    "toLocaleTimeString",
+    "toLocaleUpperCase",
+    "toLowerCase",
+    "toPrecision",
+    "toPrimitive",
+    "toReversed",
+    "toSorted",
+    "toSpliced",
+    "toString",
+    "toStringTag",
+    "toTimeString",
+    "toUTCString",
+    "toUpperCase",
+    "toWellFormed",
+    "toggle",
+    "toggleAttribute",
+    "top",
+    "touches",
+    "trace",
+    "trailingZeroDisplay",
+    "transform",
+    "translate",
+    "trim",
+    "trimEnd",
+    "trimLeft",
+    "trimRight",
+    "trimStart",
+    "trunc",
+    "type",
+    "unicode",
+    "unicodeSets",
+    "unobserve",
+    "unregister",
+    "unregisterToken",
+    "unscopables",
+    "unshift",
+    "upload",
+    "url",
+    "usage",
+    "useGrouping",
+    "userAgent",
+    "validationMessage",
+    "validity",
+    "value",
+    "valueAsDate",
+    "valueAsNumber",
+    "valueOf",
+    "values",
+    "visibilityState",
+    "volume",
+    "wait",
+    "waitAsync",
+    "warn",
+    "weekInfo",
+    "whenDefined",
+    "which",
+    "width",
+    "window",
+    "with",
+    "withCredentials",
+    "writable",
+    "write",
+    "writeText",
+    "x",
+    "xor",
+    "y",
+    "year",
+];
+
+#[cfg(test)]
+mod test {
+    use super::BUILTIN_PROPERTIES;
+
+    #[test]
+    fn sorted() {
+        // Required for binary search
+        assert!(BUILTIN_PROPERTIES.is_sorted());
+    }
+}
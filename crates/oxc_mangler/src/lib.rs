@@ -15,11 +15,16 @@ use oxc_span::SourceType;
 use oxc_str::{CompactStr, Ident, Str};
 
 pub(crate) mod base54;
+mod builtin_properties;
 mod keep_names;
+mod name_cache;
+mod properties;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use name_cache::MangleNameCache;
+pub use properties::{MangleOptionsProperties, mangle_properties};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -37,10 +42,20 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names.
+    ///
+    /// Default `None`, which does not mangle any properties except private class members.
+    pub properties: Option<MangleOptionsProperties>,
+
+    /// Names given by previous builds, to keep the names of top-level symbols and properties stable.
+    ///
+    /// The extended cache is returned in [`ManglerReturn::name_cache`].
+    pub name_cache: Option<MangleNameCache>,
 }
 
 impl MangleOptions {
-    fn top_level(self, source_type: SourceType) -> bool {
+    fn top_level(&self, source_type: SourceType) -> bool {
         self.top_level.unwrap_or(source_type.is_module() || source_type.is_commonjs())
     }
 }
@@ -77,6 +92,12 @@ pub struct ManglerReturn {
     /// A vector where each element corresponds to a class in declaration order.
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: IndexVec<ClassId, FxHashMap<String, CompactStr>>,
+    /// A mapping from original property names to their mangled names.
+    /// `None` unless [`MangleOptions::properties`] is set.
+    pub property_mappings: Option<FxHashMap<String, CompactStr>>,
+    /// [`MangleOptions::name_cache`] extended with the names given in this run.
+    /// `None` unless [`MangleOptions::name_cache`] is set.
+    pub name_cache: Option<MangleNameCache>,
 }
 
 /// # Name Mangler / Symbol Minification
@@ -282,8 +303,30 @@ impl<'t> Mangler<'t> {
     #[must_use]
     pub fn build(self, program: &Program<'_>) -> ManglerReturn {
         let mut semantic = SemanticBuilder::new().build(program).semantic;
+        let property_mappings = self.build_property_mappings(&semantic);
+        let mut name_cache = self.options.name_cache.clone();
         let class_private_mappings = self.build_with_semantic(&mut semantic, program);
-        ManglerReturn { scoping: semantic.into_scoping(), class_private_mappings }
+        let scoping = semantic.into_scoping();
+        if let Some(name_cache) = &mut name_cache {
+            name_cache.extend(&scoping, property_mappings.as_ref());
+        }
+        ManglerReturn { scoping, class_private_mappings, property_mappings, name_cache }
+    }
+
+    /// Generates mangled names for properties, if [`MangleOptions::properties`] is set.
+    /// Pass the mappings to oxc_codegen to generate the mangled code.
+    pub fn build_property_mappings(
+        &self,
+        semantic: &Semantic<'_>,
+    ) -> Option<FxHashMap<String, CompactStr>> {
+        self.options.properties.as_ref().map(|options| {
+            mangle_properties(
+                options,
+                semantic.nodes(),
+                self.options.name_cache.as_ref(),
+                self.options.debug,
+            )
+        })
     }
 
     /// # Panics
@@ -326,6 +369,20 @@ impl<'t> Mangler<'t> {
             scoping,
             ast_nodes,
         );
+        // Top-level symbols which keep the names given by a previous build.
+        // Like symbols whose names are kept, they are not assigned to slots.
+        let (cached_names, cached_symbols) = match &self.options.name_cache {
+            Some(name_cache) if top_level => Mangler::collect_cached_symbols(
+                name_cache,
+                temp_allocator,
+                scoping,
+                &exported_names,
+                exported_symbols.as_ref(),
+                keep_name_symbols.as_ref(),
+                &keep_name_names,
+            ),
+            _ => (FxHashSet::default(), None),
+        };
 
         // All symbols with their assigned slots. Keyed by symbol id.
         let mut slots = Vec::from_iter_in(
@@ -369,6 +426,9 @@ impl<'t> Mangler<'t> {
                 !keep_name_symbols
                     .as_ref()
                     .is_some_and(|keep_name_symbols| keep_name_symbols.has_bit(binding.index()))
+                    && !cached_symbols
+                        .as_ref()
+                        .is_some_and(|(cached_symbols, _)| cached_symbols.has_bit(binding.index()))
             }));
             if tmp_bindings.is_empty() {
                 continue;
@@ -522,6 +582,7 @@ impl<'t> Mangler<'t> {
                     // TODO: only skip the names that are kept in the current scope
                     && !keep_name_names.contains(n)
                     && !eval_reserved_names.contains(n)
+                    && !cached_names.contains(n)
                 {
                     break name;
                 }
@@ -578,6 +639,12 @@ impl<'t> Mangler<'t> {
                 }
             }
         }
+
+        if let Some((_, cached_symbols)) = cached_symbols {
+            for (symbol_id, cached_name) in cached_symbols {
+                scoping.set_symbol_name(symbol_id, Ident::from(cached_name));
+            }
+        }
     }
 
     fn tally_slot_frequencies<'a>(
@@ -671,6 +738,64 @@ impl<'t> Mangler<'t> {
         (ids.ones().map(|id| scoping.symbol_name(SymbolId::from_usize(id))).collect(), Some(ids))
     }
 
+    /// Collects top-level symbols which can keep the names given to them by a previous build,
+    /// paired with their cached names, and all names in the cache.
+    ///
+    /// A cached name is not reused if it would collide with a name which is not mangled
+    /// in this build. Such symbols are mangled as usual.
+    #[expect(clippy::type_complexity)]
+    fn collect_cached_symbols<'c>(
+        name_cache: &'c MangleNameCache,
+        temp_allocator: &'t Allocator,
+        scoping: &Scoping,
+        exported_names: &HashSet<'_, Str<'_>>,
+        exported_symbols: Option<&BitSet<'_>>,
+        keep_name_symbols: Option<&BitSet<'_>>,
+        keep_name_names: &FxHashSet<&str>,
+    ) -> (FxHashSet<&'c str>, Option<(BitSet<'t>, Vec<'t, (SymbolId, &'c str)>)>) {
+        let cached_names = name_cache.vars.values().map(String::as_str).collect::<FxHashSet<_>>();
+        let root_scope_id = scoping.root_scope_id();
+        // Top-level symbols are not mangled at all
+        if scoping.scope_flags(root_scope_id).contains_direct_eval() {
+            return (cached_names, None);
+        }
+
+        let eval_reserved_names = scoping
+            .iter_bindings()
+            .filter(|(scope_id, _)| scoping.scope_flags(*scope_id).contains_direct_eval())
+            .flat_map(|(_, bindings)| bindings.keys().map(Ident::as_str))
+            .collect::<FxHashSet<_>>();
+        let root_unresolved_references = scoping.root_unresolved_references();
+
+        let mut symbols = BitSet::new_in(scoping.symbols_len(), temp_allocator);
+        let mut pairs = Vec::new_in(temp_allocator);
+        let mut used_names = FxHashSet::default();
+        for (name, &symbol_id) in scoping.get_bindings(root_scope_id) {
+            let Some(cached_name) = name_cache.vars.get(name.as_str()) else { continue };
+            let cached_name = cached_name.as_str();
+            let is_kept = |symbols: Option<&BitSet<'_>>| {
+                symbols.is_some_and(|symbols| symbols.has_bit(symbol_id.index()))
+            };
+            if is_kept(exported_symbols) || is_kept(keep_name_symbols) || is_special_name(name) {
+                continue;
+            }
+            if !oxc_syntax::identifier::is_identifier_name(cached_name)
+                || oxc_syntax::keyword::is_reserved_keyword(cached_name)
+                || is_special_name(cached_name)
+                || root_unresolved_references.contains_key(cached_name)
+                || exported_names.contains(cached_name)
+                || keep_name_names.contains(cached_name)
+                || eval_reserved_names.contains(cached_name)
+                || !used_names.insert(cached_name)
+            {
+                continue;
+            }
+            symbols.set_bit(symbol_id.index());
+            pairs.push((symbol_id, cached_name));
+        }
+        (cached_names, Some((symbols, pairs)))
+    }
+
     /// Collects and generates mangled names for private members using semantic information
     /// Returns a Vec where each element corresponds to a class in declaration order
     fn collect_private_members_from_semantic(
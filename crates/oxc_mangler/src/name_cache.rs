@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use oxc_semantic::Scoping;
+use oxc_str::CompactStr;
+
+/// Names given to top-level symbols and properties by previous runs of the mangler.
+///
+/// Pass the cache of a previous build in [`crate::MangleOptions::name_cache`] to keep the names stable
+/// across builds. Names of symbols and properties which are not in the cache are chosen so they do not
+/// collide with any name in the cache, and the cache is extended with them.
+///
+/// Serializes to JSON as `{ "vars": { "original": "mangled" }, "props": { "original": "mangled" } }`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize), serde(default))]
+pub struct MangleNameCache {
+    /// Mangled names of top-level symbols, keyed by their original names.
+    pub vars: BTreeMap<String, String>,
+
+    /// Mangled names of properties, keyed by their original names.
+    pub props: BTreeMap<String, String>,
+}
+
+impl MangleNameCache {
+    /// Add the names given by a run of the mangler to the cache.
+    ///
+    /// `scoping` is the scoping returned by the mangler, and `property_mappings` are the mangled property names.
+    pub fn extend(
+        &mut self,
+        scoping: &Scoping,
+        property_mappings: Option<&FxHashMap<String, CompactStr>>,
+    ) {
+        // Binding names are not changed by the mangler, only symbol names
+        for (name, &symbol_id) in scoping.get_bindings(scoping.root_scope_id()) {
+            let mangled = scoping.symbol_name(symbol_id);
+            if name.as_str() != mangled {
+                self.vars.insert(name.to_string(), mangled.to_string());
+            }
+        }
+        if let Some(property_mappings) = property_mappings {
+            self.props.extend(
+                property_mappings.iter().map(|(name, mangled)| (name.clone(), mangled.to_string())),
+            );
+        }
+    }
+}
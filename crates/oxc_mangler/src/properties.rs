@@ -0,0 +1,181 @@
+use itertools::Itertools;
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::{AstKind, ast::*};
+use oxc_semantic::AstNodes;
+use oxc_str::CompactStr;
+use oxc_syntax::operator::BinaryOperator;
+
+use crate::{MangleNameCache, base54::base54, builtin_properties::BUILTIN_PROPERTIES, debug_name};
+
+#[derive(Debug, Clone, Default)]
+pub struct MangleOptionsProperties {
+    /// Only mangle property names matching this regular expression.
+    ///
+    /// Default `None`, which mangles all property names that are not reserved.
+    pub regex: Option<Regex>,
+
+    /// Property names which are never mangled.
+    pub reserved: Vec<String>,
+
+    /// Pass `true` to also mangle names of JavaScript builtin and DOM properties,
+    /// e.g. `length` or `addEventListener`.
+    ///
+    /// Default `false`
+    pub builtins: bool,
+}
+
+/// Generate mangled names for properties.
+///
+/// Properties are renamed by name, so all properties with the same name are given the same mangled name,
+/// regardless of the object they belong to. A name which is quoted anywhere (`obj["name"]`, `{ "name": 1 }`
+/// or `"name" in obj`) is never mangled.
+///
+/// As with identifiers, the most frequently used names get the shortest mangled names.
+/// Names in `name_cache` keep their cached mangled names, and new mangled names never collide with cached ones.
+pub fn mangle_properties(
+    options: &MangleOptionsProperties,
+    nodes: &AstNodes<'_>,
+    name_cache: Option<&MangleNameCache>,
+    debug: bool,
+) -> FxHashMap<String, CompactStr> {
+    let mut collector = PropertyNameCollector::default();
+    for node in nodes {
+        collector.visit(node.kind());
+    }
+
+    let PropertyNameCollector { names, quoted, .. } = collector;
+    let reserved = options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+    let is_builtin = |name: &str| BUILTIN_PROPERTIES.binary_search(&name).is_ok();
+
+    // Names in first appearance order, with the number of times they appear
+    let (mangled, kept): (Vec<_>, Vec<_>) = names.into_iter().partition(|(name, _)| {
+        // `{ __proto__: x }` and `constructor() {}` have special semantics
+        !matches!(*name, "__proto__" | "constructor")
+            && !quoted.contains(name)
+            && !reserved.contains(name)
+            && (options.builtins || !is_builtin(name))
+            && options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    });
+    if mangled.is_empty() {
+        return FxHashMap::default();
+    }
+    let kept = kept.into_iter().map(|(name, _)| name).collect::<FxHashSet<_>>();
+    let is_available = |name: &str| {
+        !kept.contains(name)
+            && !quoted.contains(name)
+            && !reserved.contains(name)
+            && !is_builtin(name)
+    };
+
+    let mut mappings = FxHashMap::default();
+
+    // Reuse names from the cache
+    let cached_names = name_cache
+        .map(|cache| cache.props.values().map(String::as_str).collect::<FxHashSet<_>>())
+        .unwrap_or_default();
+    let mangled = if let Some(cache) = name_cache {
+        let mut used_cached_names = FxHashSet::default();
+        mangled
+            .into_iter()
+            .filter(|(name, _)| {
+                let Some(cached_name) = cache.props.get(*name) else { return true };
+                if !is_available(cached_name) || !used_cached_names.insert(cached_name) {
+                    return true;
+                }
+                mappings.insert((*name).to_string(), CompactStr::new(cached_name));
+                false
+            })
+            .collect::<Vec<_>>()
+    } else {
+        mangled
+    };
+
+    let mut count = 0;
+    let generate_name = || loop {
+        let name = if debug {
+            CompactStr::new(debug_name(count).as_str())
+        } else {
+            CompactStr::new(base54(count).as_str())
+        };
+        count += 1;
+        if is_available(&name) && !cached_names.contains(name.as_str()) {
+            break name;
+        }
+    };
+    let new_names = std::iter::repeat_with(generate_name).take(mangled.len()).collect::<Vec<_>>();
+
+    // The most frequent names get the shorter mangled names.
+    // Names which get mangled names of the same length are assigned in the order they first appear,
+    // which makes the output more compressible. See `Mangler::build_with_semantic_impl`.
+    let mut order = (0..mangled.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| std::cmp::Reverse(mangled[index].1));
+
+    let mut order = order.into_iter();
+    for (_, same_len_names) in &new_names.into_iter().chunk_by(CompactStr::len) {
+        let same_len_names = same_len_names.collect::<Vec<_>>();
+        let mut batch = order.by_ref().take(same_len_names.len()).collect::<Vec<_>>();
+        batch.sort_unstable();
+        for (index, new_name) in batch.into_iter().zip(same_len_names) {
+            mappings.insert(mangled[index].0.to_string(), new_name);
+        }
+    }
+    mappings
+}
+
+#[derive(Default)]
+struct PropertyNameCollector<'a> {
+    /// Property names in first appearance order, with the number of times they appear
+    names: Vec<(&'a str, usize)>,
+    indexes: FxHashMap<&'a str, usize>,
+    /// Property names which appear as string literals
+    quoted: FxHashSet<&'a str>,
+}
+
+impl<'a> PropertyNameCollector<'a> {
+    fn visit(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::StaticMemberExpression(expr) => self.add(expr.property.name.as_str()),
+            AstKind::JSXMemberExpression(expr) => self.add(expr.property.name.as_str()),
+            AstKind::ObjectProperty(prop) => self.add_key(&prop.key),
+            AstKind::PropertyDefinition(prop) => self.add_key(&prop.key),
+            AstKind::MethodDefinition(method) => self.add_key(&method.key),
+            AstKind::AccessorProperty(prop) => self.add_key(&prop.key),
+            AstKind::BindingProperty(prop) => self.add_key(&prop.key),
+            AstKind::AssignmentTargetPropertyProperty(prop) => self.add_key(&prop.name),
+            AstKind::AssignmentTargetPropertyIdentifier(prop) => {
+                self.add(prop.binding.name.as_str());
+            }
+            AstKind::ComputedMemberExpression(expr) => {
+                if let Expression::StringLiteral(lit) = &expr.expression {
+                    self.quoted.insert(lit.value.as_str());
+                }
+            }
+            AstKind::BinaryExpression(expr) if expr.operator == BinaryOperator::In => {
+                if let Expression::StringLiteral(lit) = &expr.left {
+                    self.quoted.insert(lit.value.as_str());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_key(&mut self, key: &PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => self.add(ident.name.as_str()),
+            PropertyKey::StringLiteral(lit) => {
+                self.quoted.insert(lit.value.as_str());
+            }
+            _ => {}
+        }
+    }
+
+    fn add(&mut self, name: &'a str) {
+        let index = *self.indexes.entry(name).or_insert_with(|| {
+            self.names.push((name, 0));
+            self.names.len() - 1
+        });
+        self.names[index].1 += 1;
+    }
+}
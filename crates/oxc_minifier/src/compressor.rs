@@ -4,7 +4,7 @@ use oxc_semantic::{Scoping, SemanticBuilder};
 
 use crate::{
     CompressOptions, ReusableTraverseCtx,
-    peephole::{Normalize, NormalizeOptions, PeepholeOptimizations},
+    peephole::{HoistLiterals, Normalize, NormalizeOptions, PeepholeOptimizations},
     state::MinifierState,
 };
 
@@ -30,6 +30,7 @@ impl<'a> Compressor<'a> {
         options: CompressOptions,
     ) -> u8 {
         let max_iterations = options.max_iterations;
+        let hoist_literals = options.hoist_literals;
         let state = MinifierState::new(program.source_type, options, /* dce */ false);
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options = NormalizeOptions {
@@ -38,7 +39,11 @@ impl<'a> Compressor<'a> {
             remove_unnecessary_use_strict: true,
         };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        Self::run_in_loop(max_iterations, program, &mut ctx)
+        let iterations = Self::run_in_loop(max_iterations, program, &mut ctx);
+        if hoist_literals {
+            HoistLiterals::new().build(program, &mut ctx);
+        }
+        iterations
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>, options: CompressOptions) -> u8 {
@@ -195,134 +195,138 @@ pub(crate) enum AncestorType {
     ExportSpecifierExported = 171,
     V8IntrinsicExpressionName = 172,
     V8IntrinsicExpressionArguments = 173,
-    JSXElementOpeningElement = 174,
-    JSXElementChildren = 175,
-    JSXElementClosingElement = 176,
-    JSXOpeningElementName = 177,
-    JSXOpeningElementTypeArguments = 178,
-    JSXOpeningElementAttributes = 179,
-    JSXClosingElementName = 180,
-    JSXFragmentOpeningFragment = 181,
-    JSXFragmentChildren = 182,
-    JSXFragmentClosingFragment = 183,
-    JSXNamespacedNameNamespace = 184,
-    JSXNamespacedNameName = 185,
-    JSXMemberExpressionObject = 186,
-    JSXMemberExpressionProperty = 187,
-    JSXExpressionContainerExpression = 188,
-    JSXAttributeName = 189,
-    JSXAttributeValue = 190,
-    JSXSpreadAttributeArgument = 191,
-    JSXSpreadChildExpression = 192,
-    TSThisParameterTypeAnnotation = 193,
-    TSEnumDeclarationId = 194,
-    TSEnumDeclarationBody = 195,
-    TSEnumBodyMembers = 196,
-    TSEnumMemberId = 197,
-    TSEnumMemberInitializer = 198,
-    TSTypeAnnotationTypeAnnotation = 199,
-    TSLiteralTypeLiteral = 200,
-    TSConditionalTypeCheckType = 201,
-    TSConditionalTypeExtendsType = 202,
-    TSConditionalTypeTrueType = 203,
-    TSConditionalTypeFalseType = 204,
-    TSUnionTypeTypes = 205,
-    TSIntersectionTypeTypes = 206,
-    TSParenthesizedTypeTypeAnnotation = 207,
-    TSTypeOperatorTypeAnnotation = 208,
-    TSArrayTypeElementType = 209,
-    TSIndexedAccessTypeObjectType = 210,
-    TSIndexedAccessTypeIndexType = 211,
-    TSTupleTypeElementTypes = 212,
-    TSNamedTupleMemberLabel = 213,
-    TSNamedTupleMemberElementType = 214,
-    TSOptionalTypeTypeAnnotation = 215,
-    TSRestTypeTypeAnnotation = 216,
-    TSTypeReferenceTypeName = 217,
-    TSTypeReferenceTypeArguments = 218,
-    TSQualifiedNameLeft = 219,
-    TSQualifiedNameRight = 220,
-    TSTypeParameterInstantiationParams = 221,
-    TSTypeParameterName = 222,
-    TSTypeParameterConstraint = 223,
-    TSTypeParameterDefault = 224,
-    TSTypeParameterDeclarationParams = 225,
-    TSTypeAliasDeclarationId = 226,
-    TSTypeAliasDeclarationTypeParameters = 227,
-    TSTypeAliasDeclarationTypeAnnotation = 228,
-    TSClassImplementsExpression = 229,
-    TSClassImplementsTypeArguments = 230,
-    TSInterfaceDeclarationId = 231,
-    TSInterfaceDeclarationTypeParameters = 232,
-    TSInterfaceDeclarationExtends = 233,
-    TSInterfaceDeclarationBody = 234,
-    TSInterfaceBodyBody = 235,
-    TSPropertySignatureKey = 236,
-    TSPropertySignatureTypeAnnotation = 237,
-    TSIndexSignatureParameters = 238,
-    TSIndexSignatureTypeAnnotation = 239,
-    TSCallSignatureDeclarationTypeParameters = 240,
-    TSCallSignatureDeclarationThisParam = 241,
-    TSCallSignatureDeclarationParams = 242,
-    TSCallSignatureDeclarationReturnType = 243,
-    TSMethodSignatureKey = 244,
-    TSMethodSignatureTypeParameters = 245,
-    TSMethodSignatureThisParam = 246,
-    TSMethodSignatureParams = 247,
-    TSMethodSignatureReturnType = 248,
-    TSConstructSignatureDeclarationTypeParameters = 249,
-    TSConstructSignatureDeclarationParams = 250,
-    TSConstructSignatureDeclarationReturnType = 251,
-    TSIndexSignatureNameTypeAnnotation = 252,
-    TSInterfaceHeritageExpression = 253,
-    TSInterfaceHeritageTypeArguments = 254,
-    TSTypePredicateParameterName = 255,
-    TSTypePredicateTypeAnnotation = 256,
-    TSModuleDeclarationId = 257,
-    TSModuleDeclarationBody = 258,
-    TSGlobalDeclarationBody = 259,
-    TSModuleBlockDirectives = 260,
-    TSModuleBlockBody = 261,
-    TSTypeLiteralMembers = 262,
-    TSInferTypeTypeParameter = 263,
-    TSTypeQueryExprName = 264,
-    TSTypeQueryTypeArguments = 265,
-    TSImportTypeSource = 266,
-    TSImportTypeOptions = 267,
-    TSImportTypeQualifier = 268,
-    TSImportTypeTypeArguments = 269,
-    TSImportTypeQualifiedNameLeft = 270,
-    TSImportTypeQualifiedNameRight = 271,
-    TSFunctionTypeTypeParameters = 272,
-    TSFunctionTypeThisParam = 273,
-    TSFunctionTypeParams = 274,
-    TSFunctionTypeReturnType = 275,
-    TSConstructorTypeTypeParameters = 276,
-    TSConstructorTypeParams = 277,
-    TSConstructorTypeReturnType = 278,
-    TSMappedTypeKey = 279,
-    TSMappedTypeConstraint = 280,
-    TSMappedTypeNameType = 281,
-    TSMappedTypeTypeAnnotation = 282,
-    TSTemplateLiteralTypeQuasis = 283,
-    TSTemplateLiteralTypeTypes = 284,
-    TSAsExpressionExpression = 285,
-    TSAsExpressionTypeAnnotation = 286,
-    TSSatisfiesExpressionExpression = 287,
-    TSSatisfiesExpressionTypeAnnotation = 288,
-    TSTypeAssertionTypeAnnotation = 289,
-    TSTypeAssertionExpression = 290,
-    TSImportEqualsDeclarationId = 291,
-    TSImportEqualsDeclarationModuleReference = 292,
-    TSExternalModuleReferenceExpression = 293,
-    TSNonNullExpressionExpression = 294,
-    DecoratorExpression = 295,
-    TSExportAssignmentExpression = 296,
-    TSNamespaceExportDeclarationId = 297,
-    TSInstantiationExpressionExpression = 298,
-    TSInstantiationExpressionTypeArguments = 299,
-    JSDocNullableTypeTypeAnnotation = 300,
-    JSDocNonNullableTypeTypeAnnotation = 301,
+    DoExpressionBody = 174,
+    PipelineExpressionLeft = 175,
+    PipelineExpressionBody = 176,
+    ThrowExpressionArgument = 177,
+    JSXElementOpeningElement = 178,
+    JSXElementChildren = 179,
+    JSXElementClosingElement = 180,
+    JSXOpeningElementName = 181,
+    JSXOpeningElementTypeArguments = 182,
+    JSXOpeningElementAttributes = 183,
+    JSXClosingElementName = 184,
+    JSXFragmentOpeningFragment = 185,
+    JSXFragmentChildren = 186,
+    JSXFragmentClosingFragment = 187,
+    JSXNamespacedNameNamespace = 188,
+    JSXNamespacedNameName = 189,
+    JSXMemberExpressionObject = 190,
+    JSXMemberExpressionProperty = 191,
+    JSXExpressionContainerExpression = 192,
+    JSXAttributeName = 193,
+    JSXAttributeValue = 194,
+    JSXSpreadAttributeArgument = 195,
+    JSXSpreadChildExpression = 196,
+    TSThisParameterTypeAnnotation = 197,
+    TSEnumDeclarationId = 198,
+    TSEnumDeclarationBody = 199,
+    TSEnumBodyMembers = 200,
+    TSEnumMemberId = 201,
+    TSEnumMemberInitializer = 202,
+    TSTypeAnnotationTypeAnnotation = 203,
+    TSLiteralTypeLiteral = 204,
+    TSConditionalTypeCheckType = 205,
+    TSConditionalTypeExtendsType = 206,
+    TSConditionalTypeTrueType = 207,
+    TSConditionalTypeFalseType = 208,
+    TSUnionTypeTypes = 209,
+    TSIntersectionTypeTypes = 210,
+    TSParenthesizedTypeTypeAnnotation = 211,
+    TSTypeOperatorTypeAnnotation = 212,
+    TSArrayTypeElementType = 213,
+    TSIndexedAccessTypeObjectType = 214,
+    TSIndexedAccessTypeIndexType = 215,
+    TSTupleTypeElementTypes = 216,
+    TSNamedTupleMemberLabel = 217,
+    TSNamedTupleMemberElementType = 218,
+    TSOptionalTypeTypeAnnotation = 219,
+    TSRestTypeTypeAnnotation = 220,
+    TSTypeReferenceTypeName = 221,
+    TSTypeReferenceTypeArguments = 222,
+    TSQualifiedNameLeft = 223,
+    TSQualifiedNameRight = 224,
+    TSTypeParameterInstantiationParams = 225,
+    TSTypeParameterName = 226,
+    TSTypeParameterConstraint = 227,
+    TSTypeParameterDefault = 228,
+    TSTypeParameterDeclarationParams = 229,
+    TSTypeAliasDeclarationId = 230,
+    TSTypeAliasDeclarationTypeParameters = 231,
+    TSTypeAliasDeclarationTypeAnnotation = 232,
+    TSClassImplementsExpression = 233,
+    TSClassImplementsTypeArguments = 234,
+    TSInterfaceDeclarationId = 235,
+    TSInterfaceDeclarationTypeParameters = 236,
+    TSInterfaceDeclarationExtends = 237,
+    TSInterfaceDeclarationBody = 238,
+    TSInterfaceBodyBody = 239,
+    TSPropertySignatureKey = 240,
+    TSPropertySignatureTypeAnnotation = 241,
+    TSIndexSignatureParameters = 242,
+    TSIndexSignatureTypeAnnotation = 243,
+    TSCallSignatureDeclarationTypeParameters = 244,
+    TSCallSignatureDeclarationThisParam = 245,
+    TSCallSignatureDeclarationParams = 246,
+    TSCallSignatureDeclarationReturnType = 247,
+    TSMethodSignatureKey = 248,
+    TSMethodSignatureTypeParameters = 249,
+    TSMethodSignatureThisParam = 250,
+    TSMethodSignatureParams = 251,
+    TSMethodSignatureReturnType = 252,
+    TSConstructSignatureDeclarationTypeParameters = 253,
+    TSConstructSignatureDeclarationParams = 254,
+    TSConstructSignatureDeclarationReturnType = 255,
+    TSIndexSignatureNameTypeAnnotation = 256,
+    TSInterfaceHeritageExpression = 257,
+    TSInterfaceHeritageTypeArguments = 258,
+    TSTypePredicateParameterName = 259,
+    TSTypePredicateTypeAnnotation = 260,
+    TSModuleDeclarationId = 261,
+    TSModuleDeclarationBody = 262,
+    TSGlobalDeclarationBody = 263,
+    TSModuleBlockDirectives = 264,
+    TSModuleBlockBody = 265,
+    TSTypeLiteralMembers = 266,
+    TSInferTypeTypeParameter = 267,
+    TSTypeQueryExprName = 268,
+    TSTypeQueryTypeArguments = 269,
+    TSImportTypeSource = 270,
+    TSImportTypeOptions = 271,
+    TSImportTypeQualifier = 272,
+    TSImportTypeTypeArguments = 273,
+    TSImportTypeQualifiedNameLeft = 274,
+    TSImportTypeQualifiedNameRight = 275,
+    TSFunctionTypeTypeParameters = 276,
+    TSFunctionTypeThisParam = 277,
+    TSFunctionTypeParams = 278,
+    TSFunctionTypeReturnType = 279,
+    TSConstructorTypeTypeParameters = 280,
+    TSConstructorTypeParams = 281,
+    TSConstructorTypeReturnType = 282,
+    TSMappedTypeKey = 283,
+    TSMappedTypeConstraint = 284,
+    TSMappedTypeNameType = 285,
+    TSMappedTypeTypeAnnotation = 286,
+    TSTemplateLiteralTypeQuasis = 287,
+    TSTemplateLiteralTypeTypes = 288,
+    TSAsExpressionExpression = 289,
+    TSAsExpressionTypeAnnotation = 290,
+    TSSatisfiesExpressionExpression = 291,
+    TSSatisfiesExpressionTypeAnnotation = 292,
+    TSTypeAssertionTypeAnnotation = 293,
+    TSTypeAssertionExpression = 294,
+    TSImportEqualsDeclarationId = 295,
+    TSImportEqualsDeclarationModuleReference = 296,
+    TSExternalModuleReferenceExpression = 297,
+    TSNonNullExpressionExpression = 298,
+    DecoratorExpression = 299,
+    TSExportAssignmentExpression = 300,
+    TSNamespaceExportDeclarationId = 301,
+    TSInstantiationExpressionExpression = 302,
+    TSInstantiationExpressionTypeArguments = 303,
+    JSDocNullableTypeTypeAnnotation = 304,
+    JSDocNonNullableTypeTypeAnnotation = 305,
 }
 
 /// Ancestor type used in AST traversal.
@@ -656,6 +660,13 @@ pub enum Ancestor<'a, 't> {
         AncestorType::V8IntrinsicExpressionName as u16,
     V8IntrinsicExpressionArguments(V8IntrinsicExpressionWithoutArguments<'a, 't>) =
         AncestorType::V8IntrinsicExpressionArguments as u16,
+    DoExpressionBody(DoExpressionWithoutBody<'a, 't>) = AncestorType::DoExpressionBody as u16,
+    PipelineExpressionLeft(PipelineExpressionWithoutLeft<'a, 't>) =
+        AncestorType::PipelineExpressionLeft as u16,
+    PipelineExpressionBody(PipelineExpressionWithoutBody<'a, 't>) =
+        AncestorType::PipelineExpressionBody as u16,
+    ThrowExpressionArgument(ThrowExpressionWithoutArgument<'a, 't>) =
+        AncestorType::ThrowExpressionArgument as u16,
     JSXElementOpeningElement(JSXElementWithoutOpeningElement<'a, 't>) =
         AncestorType::JSXElementOpeningElement as u16,
     JSXElementChildren(JSXElementWithoutChildren<'a, 't>) = AncestorType::JSXElementChildren as u16,
@@ -1458,6 +1469,21 @@ impl<'a, 't> Ancestor<'a, 't> {
         matches!(self, Self::V8IntrinsicExpressionName(_) | Self::V8IntrinsicExpressionArguments(_))
     }
 
+    #[inline]
+    pub fn is_do_expression(self) -> bool {
+        matches!(self, Self::DoExpressionBody(_))
+    }
+
+    #[inline]
+    pub fn is_pipeline_expression(self) -> bool {
+        matches!(self, Self::PipelineExpressionLeft(_) | Self::PipelineExpressionBody(_))
+    }
+
+    #[inline]
+    pub fn is_throw_expression(self) -> bool {
+        matches!(self, Self::ThrowExpressionArgument(_))
+    }
+
     #[inline]
     pub fn is_jsx_element(self) -> bool {
         matches!(
@@ -2003,6 +2029,9 @@ impl<'a, 't> Ancestor<'a, 't> {
                 | Self::AccessorPropertyValue(_)
                 | Self::ImportExpressionSource(_)
                 | Self::ImportExpressionOptions(_)
+                | Self::PipelineExpressionLeft(_)
+                | Self::PipelineExpressionBody(_)
+                | Self::ThrowExpressionArgument(_)
                 | Self::JSXSpreadAttributeArgument(_)
                 | Self::JSXSpreadChildExpression(_)
                 | Self::TSEnumMemberInitializer(_)
@@ -2431,6 +2460,10 @@ impl<'a, 't> GetAddress for Ancestor<'a, 't> {
             Self::ExportSpecifierExported(a) => a.address(),
             Self::V8IntrinsicExpressionName(a) => a.address(),
             Self::V8IntrinsicExpressionArguments(a) => a.address(),
+            Self::DoExpressionBody(a) => a.address(),
+            Self::PipelineExpressionLeft(a) => a.address(),
+            Self::PipelineExpressionBody(a) => a.address(),
+            Self::ThrowExpressionArgument(a) => a.address(),
             Self::JSXElementOpeningElement(a) => a.address(),
             Self::JSXElementChildren(a) => a.address(),
             Self::JSXElementClosingElement(a) => a.address(),
@@ -12167,6 +12200,146 @@ impl<'a, 't> GetAddress for V8IntrinsicExpressionWithoutArguments<'a, 't> {
     }
 }
 
+pub(crate) const OFFSET_DO_EXPRESSION_NODE_ID: usize = offset_of!(DoExpression, node_id);
+pub(crate) const OFFSET_DO_EXPRESSION_SPAN: usize = offset_of!(DoExpression, span);
+pub(crate) const OFFSET_DO_EXPRESSION_BODY: usize = offset_of!(DoExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct DoExpressionWithoutBody<'a, 't>(
+    pub(crate) *const DoExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> DoExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_DO_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_DO_EXPRESSION_SPAN) as *const Span) }
+    }
+}
+
+impl<'a, 't> GetAddress for DoExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_NODE_ID: usize =
+    offset_of!(PipelineExpression, node_id);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_SPAN: usize = offset_of!(PipelineExpression, span);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_LEFT: usize = offset_of!(PipelineExpression, left);
+pub(crate) const OFFSET_PIPELINE_EXPRESSION_BODY: usize = offset_of!(PipelineExpression, body);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutLeft<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutLeft<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn body(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_BODY) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutLeft<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct PipelineExpressionWithoutBody<'a, 't>(
+    pub(crate) *const PipelineExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_SPAN) as *const Span) }
+    }
+
+    #[inline]
+    pub fn left(self) -> &'t Expression<'a> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_PIPELINE_EXPRESSION_LEFT) as *const Expression<'a>)
+        }
+    }
+}
+
+impl<'a, 't> GetAddress for PipelineExpressionWithoutBody<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
+pub(crate) const OFFSET_THROW_EXPRESSION_NODE_ID: usize = offset_of!(ThrowExpression, node_id);
+pub(crate) const OFFSET_THROW_EXPRESSION_SPAN: usize = offset_of!(ThrowExpression, span);
+pub(crate) const OFFSET_THROW_EXPRESSION_ARGUMENT: usize = offset_of!(ThrowExpression, argument);
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct ThrowExpressionWithoutArgument<'a, 't>(
+    pub(crate) *const ThrowExpression<'a>,
+    pub(crate) PhantomData<&'t ()>,
+);
+
+impl<'a, 't> ThrowExpressionWithoutArgument<'a, 't> {
+    #[inline]
+    pub fn node_id(self) -> &'t Cell<NodeId> {
+        unsafe {
+            &*((self.0 as *const u8).add(OFFSET_THROW_EXPRESSION_NODE_ID) as *const Cell<NodeId>)
+        }
+    }
+
+    #[inline]
+    pub fn span(self) -> &'t Span {
+        unsafe { &*((self.0 as *const u8).add(OFFSET_THROW_EXPRESSION_SPAN) as *const Span) }
+    }
+}
+
+impl<'a, 't> GetAddress for ThrowExpressionWithoutArgument<'a, 't> {
+    #[inline]
+    fn address(&self) -> Address {
+        unsafe { Address::from_ptr(self.0) }
+    }
+}
+
 pub(crate) const OFFSET_JSX_ELEMENT_NODE_ID: usize = offset_of!(JSXElement, node_id);
 pub(crate) const OFFSET_JSX_ELEMENT_SPAN: usize = offset_of!(JSXElement, span);
 pub(crate) const OFFSET_JSX_ELEMENT_OPENING_ELEMENT: usize =
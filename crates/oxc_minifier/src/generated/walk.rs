@@ -196,6 +196,18 @@ unsafe fn walk_expression<'a, Tr: Traverse<'a>>(
         Expression::V8IntrinsicExpression(node) => {
             walk_v8_intrinsic_expression(traverser, (&mut **node) as *mut _, ctx)
         }
+        Expression::DoExpression(node) => {
+            walk_do_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::PipelineExpression(node) => {
+            walk_pipeline_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::ThrowExpression(node) => {
+            walk_throw_expression(traverser, (&mut **node) as *mut _, ctx)
+        }
+        Expression::TopicReference(node) => {
+            walk_topic_reference(traverser, (&mut **node) as *mut _, ctx)
+        }
         Expression::ComputedMemberExpression(_)
         | Expression::StaticMemberExpression(_)
         | Expression::PrivateFieldExpression(_) => {
@@ -321,6 +333,10 @@ unsafe fn walk_array_expression_element<'a, Tr: Traverse<'a>>(
         | ArrayExpressionElement::TSNonNullExpression(_)
         | ArrayExpressionElement::TSInstantiationExpression(_)
         | ArrayExpressionElement::V8IntrinsicExpression(_)
+        | ArrayExpressionElement::DoExpression(_)
+        | ArrayExpressionElement::PipelineExpression(_)
+        | ArrayExpressionElement::ThrowExpression(_)
+        | ArrayExpressionElement::TopicReference(_)
         | ArrayExpressionElement::ComputedMemberExpression(_)
         | ArrayExpressionElement::StaticMemberExpression(_)
         | ArrayExpressionElement::PrivateFieldExpression(_) => {
@@ -451,6 +467,10 @@ unsafe fn walk_property_key<'a, Tr: Traverse<'a>>(
         | PropertyKey::TSNonNullExpression(_)
         | PropertyKey::TSInstantiationExpression(_)
         | PropertyKey::V8IntrinsicExpression(_)
+        | PropertyKey::DoExpression(_)
+        | PropertyKey::PipelineExpression(_)
+        | PropertyKey::ThrowExpression(_)
+        | PropertyKey::TopicReference(_)
         | PropertyKey::ComputedMemberExpression(_)
         | PropertyKey::StaticMemberExpression(_)
         | PropertyKey::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -774,6 +794,10 @@ unsafe fn walk_argument<'a, Tr: Traverse<'a>>(
         | Argument::TSNonNullExpression(_)
         | Argument::TSInstantiationExpression(_)
         | Argument::V8IntrinsicExpression(_)
+        | Argument::DoExpression(_)
+        | Argument::PipelineExpression(_)
+        | Argument::ThrowExpression(_)
+        | Argument::TopicReference(_)
         | Argument::ComputedMemberExpression(_)
         | Argument::StaticMemberExpression(_)
         | Argument::PrivateFieldExpression(_) => walk_expression(traverser, node as *mut _, ctx),
@@ -1734,6 +1758,10 @@ unsafe fn walk_for_statement_init<'a, Tr: Traverse<'a>>(
         | ForStatementInit::TSNonNullExpression(_)
         | ForStatementInit::TSInstantiationExpression(_)
         | ForStatementInit::V8IntrinsicExpression(_)
+        | ForStatementInit::DoExpression(_)
+        | ForStatementInit::PipelineExpression(_)
+        | ForStatementInit::ThrowExpression(_)
+        | ForStatementInit::TopicReference(_)
         | ForStatementInit::ComputedMemberExpression(_)
         | ForStatementInit::StaticMemberExpression(_)
         | ForStatementInit::PrivateFieldExpression(_) => {
@@ -3158,6 +3186,10 @@ unsafe fn walk_export_default_declaration_kind<'a, Tr: Traverse<'a>>(
         | ExportDefaultDeclarationKind::TSNonNullExpression(_)
         | ExportDefaultDeclarationKind::TSInstantiationExpression(_)
         | ExportDefaultDeclarationKind::V8IntrinsicExpression(_)
+        | ExportDefaultDeclarationKind::DoExpression(_)
+        | ExportDefaultDeclarationKind::PipelineExpression(_)
+        | ExportDefaultDeclarationKind::ThrowExpression(_)
+        | ExportDefaultDeclarationKind::TopicReference(_)
         | ExportDefaultDeclarationKind::ComputedMemberExpression(_)
         | ExportDefaultDeclarationKind::StaticMemberExpression(_)
         | ExportDefaultDeclarationKind::PrivateFieldExpression(_) => {
@@ -3211,6 +3243,77 @@ unsafe fn walk_v8_intrinsic_expression<'a, Tr: Traverse<'a>>(
     traverser.exit_v8_intrinsic_expression(&mut *node, ctx);
 }
 
+unsafe fn walk_do_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut DoExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_do_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::DoExpressionBody(ancestor::DoExpressionWithoutBody(
+        node,
+        PhantomData,
+    )));
+    walk_block_statement(
+        traverser,
+        (&mut **((node as *mut u8).add(ancestor::OFFSET_DO_EXPRESSION_BODY)
+            as *mut Box<BlockStatement>)) as *mut _,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_do_expression(&mut *node, ctx);
+}
+
+unsafe fn walk_pipeline_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut PipelineExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_pipeline_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::PipelineExpressionLeft(
+        ancestor::PipelineExpressionWithoutLeft(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_LEFT) as *mut Expression,
+        ctx,
+    );
+    ctx.retag_stack(AncestorType::PipelineExpressionBody);
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_PIPELINE_EXPRESSION_BODY) as *mut Expression,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_pipeline_expression(&mut *node, ctx);
+}
+
+unsafe fn walk_topic_reference<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut TopicReference,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_topic_reference(&mut *node, ctx);
+    traverser.exit_topic_reference(&mut *node, ctx);
+}
+
+unsafe fn walk_throw_expression<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    node: *mut ThrowExpression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    traverser.enter_throw_expression(&mut *node, ctx);
+    let pop_token = ctx.push_stack(Ancestor::ThrowExpressionArgument(
+        ancestor::ThrowExpressionWithoutArgument(node, PhantomData),
+    ));
+    walk_expression(
+        traverser,
+        (node as *mut u8).add(ancestor::OFFSET_THROW_EXPRESSION_ARGUMENT) as *mut Expression,
+        ctx,
+    );
+    ctx.pop_stack(pop_token);
+    traverser.exit_throw_expression(&mut *node, ctx);
+}
+
 unsafe fn walk_boolean_literal<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
     node: *mut BooleanLiteral,
@@ -3560,6 +3663,10 @@ unsafe fn walk_jsx_expression<'a, Tr: Traverse<'a>>(
         | JSXExpression::TSNonNullExpression(_)
         | JSXExpression::TSInstantiationExpression(_)
         | JSXExpression::V8IntrinsicExpression(_)
+        | JSXExpression::DoExpression(_)
+        | JSXExpression::PipelineExpression(_)
+        | JSXExpression::ThrowExpression(_)
+        | JSXExpression::TopicReference(_)
         | JSXExpression::ComputedMemberExpression(_)
         | JSXExpression::StaticMemberExpression(_)
         | JSXExpression::PrivateFieldExpression(_) => {
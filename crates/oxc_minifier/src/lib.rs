@@ -63,7 +63,9 @@ use oxc_str::CompactStr;
 use oxc_syntax::class::ClassId;
 use rustc_hash::FxHashMap;
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{
+    MangleNameCache, MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties,
+};
 
 pub(crate) use crate::generated::traverse::Traverse;
 #[doc(hidden)]
@@ -90,6 +92,14 @@ pub struct MinifierReturn {
     /// Each element is a mapping from original private member names to their mangled names.
     pub class_private_mappings: Option<IndexVec<ClassId, FxHashMap<String, CompactStr>>>,
 
+    /// A mapping from original property names to their mangled names.
+    /// `None` unless [`MangleOptions::properties`] is set.
+    pub property_mappings: Option<FxHashMap<String, CompactStr>>,
+
+    /// [`MangleOptions::name_cache`] extended with the names given by the mangler.
+    /// `None` unless [`MangleOptions::name_cache`] is set.
+    pub name_cache: Option<MangleNameCache>,
+
     /// Total number of iterations ran. Useful for debugging performance issues.
     pub iterations: u8,
 }
@@ -138,17 +148,30 @@ impl<'a> Minifier {
                 (stats, iterations)
             })
             .unwrap_or_default();
-        let (scoping, class_private_mappings) = self
+        let (scoping, class_private_mappings, property_mappings, name_cache) = match self
             .options
             .mangle
-            .map(|options| {
+        {
+            Some(options) => {
                 let mut semantic = SemanticBuilder::new().with_stats(stats).build(program).semantic;
-                let class_private_mappings = Mangler::default()
-                    .with_options(options)
-                    .build_with_semantic(&mut semantic, program);
-                (semantic.into_scoping(), class_private_mappings)
-            })
-            .map_or((None, None), |(scoping, mappings)| (Some(scoping), Some(mappings)));
-        MinifierReturn { scoping, class_private_mappings, iterations }
+                let mut name_cache = options.name_cache.clone();
+                let mangler = Mangler::default().with_options(options);
+                let property_mappings = mangler.build_property_mappings(&semantic);
+                let class_private_mappings = mangler.build_with_semantic(&mut semantic, program);
+                let scoping = semantic.into_scoping();
+                if let Some(name_cache) = &mut name_cache {
+                    name_cache.extend(&scoping, property_mappings.as_ref());
+                }
+                (Some(scoping), Some(class_private_mappings), property_mappings, name_cache)
+            }
+            None => (None, None, None, None),
+        };
+        MinifierReturn {
+            scoping,
+            class_private_mappings,
+            property_mappings,
+            name_cache,
+            iterations,
+        }
     }
 }
@@ -35,12 +35,38 @@ pub struct CompressOptions {
     /// Default `true`
     pub sequences: bool,
 
+    /// Replace string literals and property names which are repeated many times
+    /// with variables declared in the innermost function containing all of them.
+    ///
+    /// `a.prototype.x = 1; b.prototype.y = 2; c.prototype.z = 3` -> `let p = "prototype"; a[p].x = 1; ...`
+    ///
+    /// Only done when it is estimated to reduce the size of the output after mangling.
+    /// Top level variables are not added to scripts.
+    ///
+    /// Default `false`
+    pub hoist_literals: bool,
+
+    /// Inline calls to functions whose body is a single small expression,
+    /// and to functions which are only called once.
+    ///
+    /// `function get(o) { return o.x } log(get(a))` -> `log(a.x)`
+    ///
+    /// Similar to the `inline` and `reduce_funcs` options of terser.
+    ///
+    /// Default `false`
+    pub inline_functions: bool,
+
     /// Drop unreferenced functions and variables.
     pub unused: CompressOptionsUnused,
 
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
+    /// Transforms which are not safe for all code.
+    ///
+    /// Default: all disabled
+    pub r#unsafe: CompressOptionsUnsafe,
+
     /// Treeshake Options .
     /// <https://rollupjs.org/configuration-options/#treeshake>
     pub treeshake: TreeShakeOptions,
@@ -71,8 +97,11 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            hoist_literals: false,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
         }
@@ -86,8 +115,11 @@ impl CompressOptions {
             drop_console: false,
             join_vars: true,
             sequences: true,
+            hoist_literals: false,
+            inline_functions: false,
             unused: CompressOptionsUnused::Keep,
             treeshake: TreeShakeOptions::default(),
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
         }
@@ -101,8 +133,11 @@ impl CompressOptions {
             drop_console: false,
             join_vars: false,
             sequences: false,
+            hoist_literals: false,
+            inline_functions: false,
             unused: CompressOptionsUnused::Remove,
             treeshake: TreeShakeOptions::default(),
+            r#unsafe: CompressOptionsUnsafe::all_false(),
             drop_labels: FxHashSet::default(),
             max_iterations: None,
         }
@@ -152,6 +187,88 @@ impl CompressOptionsKeepNames {
     }
 }
 
+/// Compress options which may break code relying on the exact semantics of the replaced syntax.
+///
+/// Similar to the `unsafe_*` options of terser.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressOptionsUnsafe {
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+    ///
+    /// `function (a) { return a }` -> `(a) => a`
+    ///
+    /// Arrow functions can not be called with `new` and have no `prototype`.
+    ///
+    /// Default `false`
+    pub arrows: bool,
+
+    /// Invert comparisons in negated conditions.
+    ///
+    /// `!(a < b)` -> `a >= b`
+    ///
+    /// Both are `false` if either operand is `NaN`.
+    ///
+    /// Default `false`
+    pub comps: bool,
+
+    /// Fold numeric expressions which may give slightly different floating point results.
+    ///
+    /// `a * 2 * 3` -> `a * 6`
+    ///
+    /// Default `false`
+    pub math: bool,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// `({ m: function () {} })` -> `({ m() {} })`
+    ///
+    /// Methods can not be called with `new` and have no `prototype`.
+    ///
+    /// Default `false`
+    pub methods: bool,
+
+    /// Assume that reading a property has no side effects, so unused property reads can be dropped.
+    ///
+    /// `a.b.c;` -> `a;`
+    ///
+    /// Getters may have side effects, and reading a property of `null` or `undefined` throws.
+    ///
+    /// Default `false`
+    pub pure_getters: bool,
+
+    /// Replace `void 0` with a variable named `undefined` in scope, which is mangled to a shorter name.
+    ///
+    /// `(function (undefined) { return void 0 })()` -> `(function (undefined) { return undefined })()`
+    ///
+    /// The variable is assumed to be `undefined`, and is also treated as `undefined` by other compressions.
+    ///
+    /// Default `false`
+    pub undefined: bool,
+}
+
+impl CompressOptionsUnsafe {
+    pub fn all_false() -> Self {
+        Self {
+            arrows: false,
+            comps: false,
+            math: false,
+            methods: false,
+            pure_getters: false,
+            undefined: false,
+        }
+    }
+
+    pub fn all_true() -> Self {
+        Self {
+            arrows: true,
+            comps: true,
+            math: true,
+            methods: true,
+            pure_getters: true,
+            undefined: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TreeShakeOptions {
     /// Whether to respect the pure annotations.
@@ -211,6 +328,17 @@ pub struct TreeShakeOptions {
     ///
     /// Default `false`
     pub invalid_import_side_effects: bool,
+
+    /// Names of the exports of an ES module which are used.
+    ///
+    /// When set, all other exports are removed, and their declarations are dropped if they are
+    /// not used by the module itself. `"default"` is the name of the default export.
+    ///
+    /// This is not a Rollup option. It lets a single file library shed its unused API without
+    /// a bundler.
+    ///
+    /// Default: `None` (all exports are used)
+    pub used_exports: Option<FxHashSet<String>>,
 }
 
 impl Default for TreeShakeOptions {
@@ -222,6 +350,7 @@ impl Default for TreeShakeOptions {
             property_write_side_effects: true,
             unknown_global_side_effects: true,
             invalid_import_side_effects: false,
+            used_exports: None,
         }
     }
 }
@@ -303,10 +303,12 @@ impl<'a> PeepholeOptimizations {
                             && right.abs() <= 255.0
                             && right.fract() == 0.0)
                 })
-                .and_then(|_| ctx.eval_binary(e)),
+                .and_then(|_| ctx.eval_binary(e))
+                .or_else(|| Self::try_fold_unsafe_math(e, ctx)),
             BinaryOperator::Division => Self::extract_numeric_values(e)
                 .filter(|(_, right)| *right == 0.0 || right.is_nan() || right.is_infinite())
-                .and_then(|_| ctx.eval_binary(e)),
+                .and_then(|_| ctx.eval_binary(e))
+                .or_else(|| Self::try_fold_unsafe_math(e, ctx)),
             BinaryOperator::ShiftLeft => {
                 Self::extract_numeric_values(e).and_then(|(left, right)| {
                     let result = e.evaluate_value(ctx)?.into_number()?;
@@ -478,6 +480,37 @@ impl<'a> PeepholeOptimizations {
         None
     }
 
+    /// Fold the constants of nested multiplications and divisions.
+    ///
+    /// - `a * 2 * 3` -> `a * 6`
+    /// - `a / 2 / 3` -> `a / 6`
+    ///
+    /// The result may be rounded differently, so this is only done with `unsafe.math`.
+    fn try_fold_unsafe_math(
+        e: &mut BinaryExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.options().r#unsafe.math {
+            return None;
+        }
+        let op = e.operator;
+        debug_assert!(matches!(op, BinaryOperator::Multiplication | BinaryOperator::Division));
+        let Expression::NumericLiteral(right) = &e.right else { return None };
+        let Expression::BinaryExpression(left) = &mut e.left else { return None };
+        if left.operator != op {
+            return None;
+        }
+        let Expression::NumericLiteral(left_right) = &left.right else { return None };
+        // `a / 2 / 3` is `a / (2 * 3)`
+        let value = left_right.value * right.value;
+        if !value.is_finite() || value == 0.0 {
+            return None;
+        }
+        let value =
+            ctx.value_to_expr(left_right.span.merge(right.span), ConstantValue::Number(value));
+        Some(ctx.ast.expression_binary(e.span, left.left.take_in(ctx.ast), op, value))
+    }
+
     fn evaluates_to_empty_string(e: &Expression<'a>) -> bool {
         match e {
             Expression::StringLiteral(s) => s.value.is_empty(),
@@ -0,0 +1,267 @@
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, scope::ScopeId, symbol::SymbolFlags};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    ReusableTraverseCtx, Traverse, TraverseCtx, generated::ancestor::Ancestor,
+    minifier_traverse::traverse_mut_with_ctx, traverse_context::BoundIdentifier,
+};
+
+/// Estimated length of a variable name after mangling.
+const MANGLED_NAME_LEN: usize = 2;
+
+/// Hoist Literals
+///
+/// Replace string literals and property names which are repeated many times with a variable
+/// declared in the innermost function (or the program) containing all of them.
+///
+/// `a.prototype.b = "click"; c.prototype.d = "click"` -> `let s = "click", p = "prototype"; a[p].b = s; c[p].d = s`
+///
+/// Literals are only hoisted when it is estimated to reduce the size of the mangled output.
+///
+/// This runs once after the peephole optimizations,
+/// because they would inline the variables back.
+pub struct HoistLiterals<'a> {
+    /// Whether occurrences are being counted, or replaced.
+    collecting: bool,
+
+    /// Scopes of the function bodies the current node is in, starting with the program.
+    containers: Vec<ScopeId>,
+
+    /// Nesting depth of `with` statements. Their objects may have properties with any name.
+    with_depth: usize,
+
+    literals: Vec<Literal<'a>>,
+
+    literal_indexes: FxHashMap<&'a str, usize>,
+
+    /// Variables for the hoisted literals, and the scopes they are declared in.
+    hoisted: FxHashMap<&'a str, (ScopeId, BoundIdentifier<'a>)>,
+
+    /// Declarations to insert at the start of each function body.
+    declarations: FxHashMap<ScopeId, Vec<(&'a str, BoundIdentifier<'a>)>>,
+}
+
+struct Literal<'a> {
+    value: &'a str,
+
+    /// The containers of all occurrences: the common prefix of their `containers`.
+    containers: Vec<ScopeId>,
+
+    /// Estimated number of bytes saved by replacing every occurrence with a variable.
+    saving: usize,
+}
+
+impl<'a> HoistLiterals<'a> {
+    pub fn new() -> Self {
+        Self {
+            collecting: true,
+            containers: vec![],
+            with_depth: 0,
+            literals: vec![],
+            literal_indexes: FxHashMap::default(),
+            hoisted: FxHashMap::default(),
+            declarations: FxHashMap::default(),
+        }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>, ctx: &mut ReusableTraverseCtx<'a>) {
+        traverse_mut_with_ctx(self, program, ctx);
+        if self.hoisted.is_empty() {
+            return;
+        }
+        self.collecting = false;
+        traverse_mut_with_ctx(self, program, ctx);
+    }
+
+    /// Number of bytes saved by replacing one occurrence with a variable.
+    fn occurrence_saving(value: &str, is_property: bool) -> usize {
+        if is_property {
+            // `.value` -> `[v]`
+            (value.len() + 1).saturating_sub(MANGLED_NAME_LEN + 2)
+        } else {
+            // `"value"` -> `v`
+            (value.len() + 2).saturating_sub(MANGLED_NAME_LEN)
+        }
+    }
+
+    fn record(&mut self, value: &'a str, is_property: bool) {
+        let saving = Self::occurrence_saving(value, is_property);
+        if saving == 0 {
+            return;
+        }
+        if let Some(&index) = self.literal_indexes.get(value) {
+            let literal = &mut self.literals[index];
+            let common =
+                literal.containers.iter().zip(&self.containers).take_while(|(a, b)| a == b).count();
+            literal.containers.truncate(common);
+            literal.saving += saving;
+        } else {
+            self.literal_indexes.insert(value, self.literals.len());
+            self.literals.push(Literal { value, containers: self.containers.clone(), saving });
+        }
+    }
+
+    /// Choose the literals to hoist, and create their variables.
+    fn hoist(&mut self, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for literal in self.literals.drain(..) {
+            // `v = "value",`
+            let declaration_len = MANGLED_NAME_LEN + literal.value.len() + 4;
+            if literal.saving <= declaration_len {
+                continue;
+            }
+            let Some(&scope_id) = literal.containers.last() else { continue };
+            // Top level variables of scripts are visible to other scripts.
+            if scope_id == root_scope_id && ctx.source_type().is_script() {
+                continue;
+            }
+            let name = if is_identifier_name(literal.value) { literal.value } else { "str" };
+            let binding = ctx.generate_uid(name, scope_id, SymbolFlags::BlockScopedVariable);
+            self.hoisted.insert(literal.value, (scope_id, binding.clone()));
+            self.declarations.entry(scope_id).or_default().push((literal.value, binding));
+        }
+    }
+
+    fn replace(&self, expr: &mut Expression<'a>, value: &'a str, ctx: &mut TraverseCtx<'a>) {
+        let Some((scope_id, binding)) = self.hoisted.get(value) else { return };
+        if !self.containers.contains(scope_id) {
+            return;
+        }
+        let span = expr.span();
+        let ident = binding.create_spanned_read_expression(span, ctx);
+        *expr = match expr {
+            Expression::StaticMemberExpression(e) => {
+                let object = e.object.take_in(ctx.ast);
+                Expression::from(
+                    ctx.ast.member_expression_computed(span, object, ident, e.optional),
+                )
+            }
+            _ => ident,
+        };
+    }
+
+    /// Insert the declarations of the variables hoisted to `scope_id` at the start of `stmts`.
+    fn insert_declarations(
+        &mut self,
+        scope_id: ScopeId,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let Some(declarations) = self.declarations.remove(&scope_id) else { return };
+        let declarators = declarations.into_iter().map(|(value, binding)| {
+            let init = ctx.ast.expression_string_literal(SPAN, value, None);
+            let id = binding.create_binding_pattern(ctx);
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Let,
+                id,
+                NONE,
+                Some(init),
+                false,
+            )
+        });
+        // Imports are evaluated first wherever they are.
+        let index = stmts
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(stmts.len());
+        // `let s = "value"; let a` -> `let s = "value", a`
+        if let Some(Statement::VariableDeclaration(decl)) = stmts.get_mut(index)
+            && decl.kind == VariableDeclarationKind::Let
+        {
+            let rest = decl.declarations.take_in(ctx.ast);
+            decl.declarations = ctx.ast.vec_from_iter(declarators.chain(rest));
+            return;
+        }
+        let decl = ctx.ast.alloc_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec_from_iter(declarators),
+            false,
+        );
+        stmts.insert(index, Statement::VariableDeclaration(decl));
+    }
+}
+
+impl<'a> Traverse<'a> for HoistLiterals<'a> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.containers.push(program.scope_id());
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = self.containers.pop().unwrap();
+        if self.collecting {
+            self.hoist(ctx);
+        } else {
+            self.insert_declarations(scope_id, &mut program.body, ctx);
+        }
+    }
+
+    fn enter_function_body(&mut self, _body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Statements can not be inserted into `() => expr`.
+        if !matches!(ctx.parent(), Ancestor::ArrowFunctionExpressionBody(arrow) if *arrow.expression())
+        {
+            self.containers.push(ctx.current_scope_id());
+        }
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.containers.last() != Some(&ctx.current_scope_id()) {
+            return;
+        }
+        let scope_id = self.containers.pop().unwrap();
+        if !self.collecting {
+            self.insert_declarations(scope_id, &mut body.statements, ctx);
+        }
+    }
+
+    fn enter_with_statement(&mut self, _stmt: &mut WithStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.with_depth += 1;
+    }
+
+    fn exit_with_statement(&mut self, _stmt: &mut WithStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.with_depth -= 1;
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.with_depth > 0 {
+            return;
+        }
+        let (value, is_property) = match expr {
+            Expression::StringLiteral(s)
+                if !s.lone_surrogates && !Self::should_keep_literal(ctx) =>
+            {
+                (s.value.as_str(), false)
+            }
+            Expression::StaticMemberExpression(e) => (e.property.name.as_str(), true),
+            _ => return,
+        };
+        if self.collecting {
+            self.record(value, is_property);
+        } else if Self::occurrence_saving(value, is_property) > 0 {
+            self.replace(expr, value, ctx);
+        }
+    }
+}
+
+impl HoistLiterals<'_> {
+    /// Property keys can not be replaced with variables, and `import()` sources should stay literals.
+    fn should_keep_literal(ctx: &TraverseCtx<'_>) -> bool {
+        matches!(
+            ctx.parent(),
+            Ancestor::ObjectPropertyKey(_)
+                | Ancestor::BindingPropertyKey(_)
+                | Ancestor::MethodDefinitionKey(_)
+                | Ancestor::PropertyDefinitionKey(_)
+                | Ancestor::AccessorPropertyKey(_)
+                | Ancestor::AssignmentTargetPropertyPropertyName(_)
+                | Ancestor::ImportAttributeKey(_)
+                | Ancestor::TSPropertySignatureKey(_)
+                | Ancestor::TSMethodSignatureKey(_)
+                | Ancestor::ImportExpressionSource(_)
+        )
+    }
+}
@@ -119,7 +119,7 @@ impl<'a> PeepholeOptimizations {
         ctx.init_value(symbol_id, None, is_fresh);
     }
 
-    fn is_for_statement_init(ctx: &TraverseCtx<'a>) -> bool {
+    pub fn is_for_statement_init(ctx: &TraverseCtx<'a>) -> bool {
         ctx.ancestors().nth(1).is_some_and(Ancestor::is_parent_of_for_statement_left)
     }
 
@@ -0,0 +1,509 @@
+use oxc_allocator::{CloneIn, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::side_effects::MayHaveSideEffects;
+use oxc_semantic::Scoping;
+use oxc_str::Ident;
+use oxc_syntax::{
+    reference::{ReferenceFlags, ReferenceId},
+    symbol::SymbolId,
+};
+
+use crate::{CompressOptionsUnused, TraverseCtx};
+
+use super::PeepholeOptimizations;
+
+/// Functions whose body has at most this many nodes, not counting references to parameters,
+/// are inlined at every call site.
+///
+/// A call `f(x)` is two nodes plus its arguments, so inlining these functions does not grow the code.
+const MAX_INLINE_FUNCTION_SIZE: usize = 2;
+
+/// A function whose body is a single `return` or expression statement, which can be inlined at call sites.
+///
+/// The body must not depend on the function it is in (`this`, `arguments`, `new.target`),
+/// must not write to any variable and must not contain nested functions or classes,
+/// so it can be copied to a call site as is, with parameters replaced by arguments.
+pub struct InlineFunction<'a> {
+    /// Copy of the returned expression.
+    body: Expression<'a>,
+
+    /// `false` if the body is an expression statement, so a call returns `undefined`.
+    returns_value: bool,
+
+    params: Vec<SymbolId>,
+
+    /// Number of references to each parameter in the body.
+    param_uses: Vec<u32>,
+
+    /// The parameter which is evaluated first in the body.
+    /// Its argument can be any expression, because it is still evaluated before the rest of the body.
+    leftmost_param: Option<usize>,
+
+    /// References in the body to bindings other than parameters.
+    /// They must refer to the same bindings at a call site.
+    free_references: Vec<(Ident<'a>, Option<SymbolId>)>,
+
+    /// Number of nodes in the body, not counting references to parameters.
+    size: usize,
+
+    /// Whether the body is in strict mode.
+    strict: bool,
+
+    /// Whether the declaration is removed once it is unused, so a function called once can be moved to its call site.
+    removable: bool,
+}
+
+impl<'a> PeepholeOptimizations {
+    /// Record the function declarations in `stmts` before they are visited,
+    /// so calls which come before a declaration can be inlined.
+    pub fn init_inline_functions(stmts: &[Statement<'a>], ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        for stmt in stmts {
+            if let Statement::FunctionDeclaration(f) = stmt {
+                Self::init_inline_function_declaration(f, ctx);
+            }
+        }
+    }
+
+    pub fn init_inline_function_declaration(f: &Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        let Some(symbol_id) = f.id.as_ref().and_then(|id| id.symbol_id.get()) else { return };
+        let function = f.body.as_ref().and_then(|body| {
+            // Function declarations in blocks have the Annex B semantics,
+            // and top level functions in scripts can be reassigned by other scripts.
+            if f.r#async
+                || f.generator
+                || ctx.current_scope_id() != ctx.current_hoist_scope_id()
+                || Self::keep_top_level_var_in_script_mode(ctx)
+            {
+                return None;
+            }
+            let removable = ctx.state.options.unused != CompressOptionsUnused::Keep
+                && !ctx.current_scope_flags().contains_direct_eval();
+            Self::get_inline_function(Some(symbol_id), &f.params, body, false, removable, ctx)
+        });
+        Self::set_inline_function(symbol_id, function, ctx);
+    }
+
+    pub fn init_inline_function_variable(decl: &VariableDeclarator<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        let BindingPattern::BindingIdentifier(id) = &decl.id else { return };
+        let Some(symbol_id) = id.symbol_id.get() else { return };
+        // `var` is hoisted, so it may be called before it is initialized.
+        if !matches!(decl.kind, VariableDeclarationKind::Const | VariableDeclarationKind::Let)
+            || (decl.kind == VariableDeclarationKind::Let
+                && Self::keep_top_level_var_in_script_mode(ctx))
+            || Self::is_for_statement_init(ctx)
+        {
+            return;
+        }
+        let exported =
+            ctx.state.symbol_values.get_symbol_value(symbol_id).is_some_and(|value| value.exported);
+        let removable = !exported && Self::can_remove_unused_declarators(ctx);
+        let function = match &decl.init {
+            Some(Expression::ArrowFunctionExpression(f)) if !f.r#async => {
+                Self::get_inline_function(
+                    Some(symbol_id),
+                    &f.params,
+                    &f.body,
+                    f.expression,
+                    removable,
+                    ctx,
+                )
+            }
+            Some(Expression::FunctionExpression(f)) if !f.r#async && !f.generator => {
+                f.body.as_ref().and_then(|body| {
+                    Self::get_inline_function(
+                        Some(symbol_id),
+                        &f.params,
+                        body,
+                        false,
+                        removable,
+                        ctx,
+                    )
+                })
+            }
+            _ => None,
+        };
+        Self::set_inline_function(symbol_id, function, ctx);
+    }
+
+    fn set_inline_function(
+        symbol_id: SymbolId,
+        function: Option<InlineFunction<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(function) = function {
+            ctx.state.inline_functions.insert(symbol_id, function);
+        } else {
+            ctx.state.inline_functions.remove(&symbol_id);
+        }
+    }
+
+    fn get_inline_function(
+        symbol_id: Option<SymbolId>,
+        params: &FormalParameters<'a>,
+        body: &FunctionBody<'a>,
+        is_expression_body: bool,
+        removable: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<InlineFunction<'a>> {
+        // A directive may change the strictness of the body.
+        if !body.directives.is_empty() || params.rest.is_some() {
+            return None;
+        }
+        let params = params
+            .items
+            .iter()
+            .map(|param| match &param.pattern {
+                BindingPattern::BindingIdentifier(id) if param.initializer.is_none() => {
+                    id.symbol_id.get()
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if symbol_id.is_some_and(|symbol_id| {
+            !ctx.scoping().get_resolved_references(symbol_id).all(|r| r.flags().is_read_only())
+        }) {
+            return None;
+        }
+        let (expr, returns_value) = match body.statements.as_slice() {
+            [Statement::ReturnStatement(ret)] => (ret.argument.as_ref()?, true),
+            [Statement::ExpressionStatement(stmt)] => (&stmt.expression, is_expression_body),
+            _ => return None,
+        };
+
+        let mut checker = InlineFunctionChecker {
+            scoping: ctx.scoping(),
+            function_symbol_id: symbol_id,
+            params: &params,
+            param_uses: vec![0; params.len()],
+            free_references: vec![],
+            size: 0,
+            can_inline: true,
+        };
+        checker.visit_expression(expr);
+        if !checker.can_inline {
+            return None;
+        }
+        let InlineFunctionChecker { param_uses, free_references, size, .. } = checker;
+
+        let leftmost_param = leftmost_identifier(expr).and_then(|ident| {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+            params.iter().position(|&param| param == symbol_id)
+        });
+
+        Some(InlineFunction {
+            body: expr.clone_in_with_semantic_ids(ctx.ast.allocator),
+            returns_value,
+            params,
+            param_uses,
+            leftmost_param,
+            free_references,
+            size,
+            strict: ctx.current_scope_flags().is_strict_mode(),
+            removable,
+        })
+    }
+
+    /// Inline calls to small functions, and to functions which are only called once.
+    ///
+    /// `function get(o) { return o.x } get(a)` -> `function get(o) { return o.x } a.x`
+    pub fn inline_function_call(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().inline_functions {
+            return;
+        }
+        let Expression::CallExpression(call) = expr else { return };
+        if call.optional {
+            return;
+        }
+        let iife;
+        let (function, is_single_call) = match &call.callee {
+            Expression::Identifier(callee) => {
+                let reference = ctx.scoping().get_reference(callee.reference_id());
+                let Some(symbol_id) = reference.symbol_id() else { return };
+                let Some(function) = ctx.state.inline_functions.get(&symbol_id) else { return };
+                let is_single_call = function.removable
+                    && ctx.scoping().get_resolved_references(symbol_id).nth(1).is_none();
+                (function, is_single_call)
+            }
+            // `((o) => o.x)(a)` -> `a.x`
+            // Other IIFEs are handled by `remove_unused_expression`.
+            Expression::ArrowFunctionExpression(f)
+                if !f.r#async && f.params.items.len() == call.arguments.len() =>
+            {
+                let Some(function) =
+                    Self::get_inline_function(None, &f.params, &f.body, f.expression, true, ctx)
+                else {
+                    return;
+                };
+                iife = function;
+                (&iife, true)
+            }
+            _ => return,
+        };
+        if function.size > MAX_INLINE_FUNCTION_SIZE && !is_single_call {
+            return;
+        }
+        if function.strict != ctx.current_scope_flags().is_strict_mode() {
+            return;
+        }
+        // Names in the body must refer to the same bindings at the call site.
+        let scope_id = ctx.current_scope_id();
+        if function
+            .free_references
+            .iter()
+            .any(|&(name, symbol_id)| ctx.scoping().find_binding(scope_id, name) != symbol_id)
+        {
+            return;
+        }
+        if ctx.ancestor_scopes().any(|scope_id| {
+            let flags = ctx.scoping().scope_flags(scope_id);
+            flags.is_with() || flags.contains_direct_eval()
+        }) {
+            return;
+        }
+        // `f()()` -> `o.m()` would change `this` of the call.
+        if Self::should_keep_indirect_access(&function.body, ctx) {
+            return;
+        }
+        if !Self::can_substitute_arguments(function, &call.arguments, ctx) {
+            return;
+        }
+
+        let mut body = function.body.clone_in_with_semantic_ids(ctx.ast.allocator);
+        let returns_value = function.returns_value;
+        let params = function.params.clone();
+        let remaining_uses = function.param_uses.clone();
+
+        let span = call.span;
+        let mut args = call.arguments.drain(..).map(Argument::into_expression).collect::<Vec<_>>();
+        args.truncate(params.len());
+        args.resize_with(params.len(), || ctx.ast.void_0(span));
+
+        ParameterSubstitutor { params: &params, args, remaining_uses, ctx }
+            .visit_expression(&mut body);
+
+        *expr = if returns_value {
+            body
+        } else {
+            ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([body, ctx.ast.void_0(span)]))
+        };
+        ctx.state.changed = true;
+    }
+
+    fn can_substitute_arguments(
+        function: &InlineFunction<'a>,
+        arguments: &[Argument<'a>],
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        arguments.iter().enumerate().all(|(index, argument)| {
+            let Some(arg) = argument.as_expression() else { return false };
+            let uses = function.param_uses.get(index).copied().unwrap_or(0);
+            if uses == 0 {
+                return !arg.may_have_side_effects(ctx);
+            }
+            if Self::is_constant_argument(arg, ctx) {
+                // Do not copy long strings.
+                return uses == 1
+                    || !matches!(arg, Expression::StringLiteral(s) if s.value.len() > 3);
+            }
+            // Any other argument is moved to where its parameter is evaluated,
+            // which must come before anything else in the body.
+            // Other arguments are constants, so the order they are evaluated in does not matter.
+            uses == 1
+                && function.leftmost_param == Some(index)
+                && !matches!(arg, Expression::ChainExpression(_))
+        })
+    }
+
+    /// Whether the argument evaluates to the same value wherever it is moved to, without side effects.
+    fn is_constant_argument(arg: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        match arg {
+            Expression::NumericLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::BigIntLiteral(_) => true,
+            Expression::Identifier(ident) => {
+                ctx.is_identifier_undefined(ident)
+                    || ctx
+                        .scoping()
+                        .get_reference(ident.reference_id())
+                        .symbol_id()
+                        .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id))
+            }
+            _ => ctx.is_expression_undefined(arg),
+        }
+    }
+}
+
+/// The identifier which is evaluated first in `expr`.
+fn leftmost_identifier<'b, 'a>(expr: &'b Expression<'a>) -> Option<&'b IdentifierReference<'a>> {
+    match expr {
+        Expression::Identifier(ident) => Some(ident),
+        Expression::StaticMemberExpression(e) => leftmost_identifier(&e.object),
+        Expression::ComputedMemberExpression(e) => leftmost_identifier(&e.object),
+        // `p()` with a member expression argument would be called with a different `this`.
+        Expression::CallExpression(e) if !matches!(e.callee, Expression::Identifier(_)) => {
+            leftmost_identifier(&e.callee)
+        }
+        Expression::NewExpression(e) => leftmost_identifier(&e.callee),
+        Expression::BinaryExpression(e) => leftmost_identifier(&e.left),
+        Expression::LogicalExpression(e) => leftmost_identifier(&e.left),
+        Expression::ConditionalExpression(e) => leftmost_identifier(&e.test),
+        Expression::SequenceExpression(e) => e.expressions.first().and_then(leftmost_identifier),
+        // `typeof p` does not throw for undeclared globals, and `delete p` deletes nothing.
+        Expression::UnaryExpression(e)
+            if !matches!(e.operator, UnaryOperator::Typeof | UnaryOperator::Delete) =>
+        {
+            leftmost_identifier(&e.argument)
+        }
+        Expression::ParenthesizedExpression(e) => leftmost_identifier(&e.expression),
+        _ => None,
+    }
+}
+
+/// Checks whether a function body can be copied to call sites, and collects its references.
+struct InlineFunctionChecker<'a, 's> {
+    scoping: &'s Scoping,
+    function_symbol_id: Option<SymbolId>,
+    params: &'s [SymbolId],
+    param_uses: Vec<u32>,
+    free_references: Vec<(Ident<'a>, Option<SymbolId>)>,
+    size: usize,
+    can_inline: bool,
+}
+
+impl<'a> Visit<'a> for InlineFunctionChecker<'a, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        match expr {
+            // These depend on the function they are in, or have scopes which are not copied.
+            Expression::ThisExpression(_)
+            | Expression::Super(_)
+            | Expression::MetaProperty(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_)
+            | Expression::PrivateInExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::AwaitExpression(_) => {
+                self.can_inline = false;
+                return;
+            }
+            // `delete x` is an error in strict mode.
+            Expression::UnaryExpression(e)
+                if e.operator == UnaryOperator::Delete && e.argument.is_identifier_reference() =>
+            {
+                self.can_inline = false;
+                return;
+            }
+            // Direct eval can see the parameters.
+            Expression::CallExpression(e) if e.callee.is_specific_id("eval") => {
+                self.can_inline = false;
+                return;
+            }
+            Expression::Identifier(_) => {}
+            _ => self.size += 1,
+        }
+        walk::walk_expression(self, expr);
+    }
+
+    fn visit_private_field_expression(&mut self, _it: &PrivateFieldExpression<'a>) {
+        self.can_inline = false;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        let symbol_id = reference.symbol_id();
+        if ident.name == "arguments"
+            || reference.flags().is_write()
+            || (symbol_id.is_some() && symbol_id == self.function_symbol_id)
+        {
+            self.can_inline = false;
+            return;
+        }
+        if let Some(index) =
+            symbol_id.and_then(|symbol_id| self.params.iter().position(|&p| p == symbol_id))
+        {
+            self.param_uses[index] += 1;
+        } else {
+            self.size += 1;
+            self.free_references.push((ident.name, symbol_id));
+        }
+    }
+}
+
+/// Replaces parameters in a copy of a function body with the arguments of a call,
+/// and creates new references for the other identifiers.
+///
+/// Every reference created is added to the reference counts in `symbol_values`,
+/// so a variable referenced by a copied argument is not treated as used only once.
+struct ParameterSubstitutor<'a, 'c> {
+    params: &'c [SymbolId],
+    args: Vec<Expression<'a>>,
+    /// The argument is moved to the last use of its parameter, and copied to the other uses.
+    remaining_uses: Vec<u32>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ParameterSubstitutor<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && let Some(symbol_id) =
+                self.ctx.scoping().get_reference(ident.reference_id()).symbol_id()
+            && let Some(index) = self.params.iter().position(|&p| p == symbol_id)
+        {
+            self.remaining_uses[index] -= 1;
+            *expr = if self.remaining_uses[index] == 0 {
+                self.args[index].take_in(self.ctx.ast)
+            } else {
+                self.copy_argument(index)
+            };
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        let (symbol_id, flags) = (reference.symbol_id(), reference.flags());
+        let reference_id = self.create_reference(ident.name, symbol_id, flags);
+        ident.reference_id.set(Some(reference_id));
+    }
+}
+
+impl<'a> ParameterSubstitutor<'a, '_> {
+    fn copy_argument(&mut self, index: usize) -> Expression<'a> {
+        let arg = &self.args[index];
+        if let Expression::Identifier(ident) = arg {
+            let (span, name) = (ident.span, ident.name);
+            let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            let reference_id = self.create_reference(name, symbol_id, ReferenceFlags::Read);
+            self.ctx.ast.expression_identifier_with_reference_id(span, name, reference_id)
+        } else {
+            arg.clone_in(self.ctx.ast.allocator)
+        }
+    }
+
+    fn create_reference(
+        &mut self,
+        name: Ident<'a>,
+        symbol_id: Option<SymbolId>,
+        flags: ReferenceFlags,
+    ) -> ReferenceId {
+        if let Some(symbol_id) = symbol_id {
+            self.ctx.state.symbol_values.add_reference(symbol_id, flags);
+        }
+        self.ctx.create_reference(name, symbol_id, flags)
+    }
+}
@@ -117,6 +117,22 @@ impl<'a> PeepholeOptimizations {
         ctx.state.changed = true;
     }
 
+    /// Invert a comparison which is negated.
+    ///
+    /// - `!(a < b)` => `a >= b`
+    /// - `!(a <= b)` => `a > b`
+    ///
+    /// Comparisons with `NaN` are always `false`, so this is only done with `unsafe.comps`.
+    pub fn invert_comparison(e: &mut BinaryExpression<'a>) {
+        e.operator = match e.operator {
+            BinaryOperator::LessThan => BinaryOperator::GreaterEqualThan,
+            BinaryOperator::LessEqualThan => BinaryOperator::GreaterThan,
+            BinaryOperator::GreaterThan => BinaryOperator::LessEqualThan,
+            BinaryOperator::GreaterEqualThan => BinaryOperator::LessThan,
+            _ => return,
+        };
+    }
+
     /// Compress `foo == true` into `foo == 1`.
     ///
     /// - `foo == true` => `foo == 1`
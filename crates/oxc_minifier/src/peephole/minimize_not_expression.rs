@@ -43,6 +43,14 @@ impl<'a> PeepholeOptimizations {
                 *expr = e.argument.take_in(ctx.ast);
                 ctx.state.changed = true;
             }
+            // `!(a < b)` => `a >= b`
+            Expression::BinaryExpression(binary_expr)
+                if ctx.options().r#unsafe.comps && binary_expr.operator.is_compare() =>
+            {
+                Self::invert_comparison(binary_expr);
+                *expr = e.argument.take_in(ctx.ast);
+                ctx.state.changed = true;
+            }
             // "!(a, b)" => "a, !b"
             Expression::SequenceExpression(sequence_expr) => {
                 if let Some(last_expr) = sequence_expr.expressions.last_mut() {
@@ -1,6 +1,8 @@
 mod convert_to_dotted_properties;
 mod fold_constants;
+mod hoist_literals;
 mod inline;
+mod inline_functions;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_expression_in_boolean_context;
@@ -27,7 +29,11 @@ use oxc_ast::ast::*;
 
 use crate::{ReusableTraverseCtx, Traverse, TraverseCtx, minifier_traverse::traverse_mut_with_ctx};
 
-pub use self::normalize::{Normalize, NormalizeOptions};
+pub use self::{
+    hoist_literals::HoistLiterals,
+    inline_functions::InlineFunction,
+    normalize::{Normalize, NormalizeOptions},
+};
 
 /// Stateless peephole optimizer. The `dce` flag and `changed` state are stored in `MinifierState`.
 pub struct PeepholeOptimizations;
@@ -125,6 +131,7 @@ impl<'a> PeepholeOptimizations {
 impl<'a> Traverse<'a> for PeepholeOptimizations {
     fn enter_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         ctx.state.symbol_values.clear();
+        ctx.state.inline_functions.clear();
         ctx.state.proto_write_symbols.clear();
         ctx.state.changed = false;
     }
@@ -146,6 +153,13 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         debug_assert!(ctx.state.dce || ctx.state.class_symbols_stack.is_exhausted());
     }
 
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        if ctx.state.dce {
+            return;
+        }
+        Self::init_inline_functions(stmts, ctx);
+    }
+
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         Self::minimize_statements(stmts, ctx);
     }
@@ -205,6 +219,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                 Statement::LabeledStatement(_) => Self::try_fold_labeled(stmt, ctx),
                 Statement::FunctionDeclaration(f) => {
                     Self::init_function_declaration_symbol_value(f.id.as_ref(), ctx);
+                    Self::init_inline_function_declaration(f, ctx);
                     Self::remove_unused_function_declaration(stmt, ctx);
                 }
                 Statement::ClassDeclaration(c) => {
@@ -250,6 +265,9 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
         ctx: &mut TraverseCtx<'a>,
     ) {
         Self::init_symbol_value(decl, ctx);
+        if !ctx.state.dce {
+            Self::init_inline_function_variable(decl, ctx);
+        }
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -309,6 +327,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                     Self::fold_unary_expr(expr, ctx);
                     Self::minimize_unary(expr, ctx);
                     Self::substitute_unary_plus(expr, ctx);
+                    Self::substitute_unsafe_void_0(expr, ctx);
                 }
                 Expression::StaticMemberExpression(_) => {
                     Self::fold_static_member_expr(expr, ctx);
@@ -332,6 +351,7 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                     Self::fold_call_expression(expr, ctx);
                     Self::substitute_iife_call(expr, ctx);
                     Self::remove_dead_code_call_expression(expr, ctx);
+                    Self::inline_function_call(expr, ctx);
                     Self::replace_concat_chain(expr, ctx);
                     Self::replace_known_global_methods(expr, ctx);
                     Self::substitute_simple_function_call(expr, ctx);
@@ -354,7 +374,10 @@ impl<'a> Traverse<'a> for PeepholeOptimizations {
                 }
                 Expression::SequenceExpression(_) => Self::remove_sequence_expression(expr, ctx),
                 Expression::ArrowFunctionExpression(e) => Self::substitute_arrow_expression(e, ctx),
-                Expression::FunctionExpression(e) => Self::try_remove_name_from_functions(e, ctx),
+                Expression::FunctionExpression(e) => {
+                    Self::try_remove_name_from_functions(e, ctx);
+                    Self::substitute_unsafe_arrow_function(expr, ctx);
+                }
                 Expression::ClassExpression(e) => Self::try_remove_name_from_classes(e, ctx),
                 Expression::NewExpression(e) => {
                     Self::substitute_typed_array_constructor(e, ctx);
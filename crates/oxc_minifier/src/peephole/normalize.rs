@@ -1,6 +1,7 @@
 use crate::generated::ancestor::Ancestor;
-use oxc_allocator::{TakeIn, Vec};
-use oxc_ast::ast::*;
+use oxc_allocator::{Box, TakeIn, Vec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_ecmascript::{
     constant_evaluation::{DetermineValueType, ValueType},
     side_effects::is_valid_regexp,
@@ -10,6 +11,8 @@ use oxc_syntax::scope::ScopeFlags;
 
 use crate::{ReusableTraverseCtx, Traverse, TraverseCtx, minifier_traverse::traverse_mut_with_ctx};
 
+use super::ReferencesCounter;
+
 #[derive(Default)]
 pub struct NormalizeOptions {
     pub convert_while_to_fors: bool,
@@ -35,6 +38,7 @@ pub struct NormalizeOptions {
 /// Also
 ///
 /// * remove `debugger` and `console.log` (optional)
+/// * remove exports which are not in `treeshake.used_exports` (optional)
 ///
 /// <https://github.com/google/closure-compiler/blob/v20240609/src/com/google/javascript/jscomp/Normalize.java>
 pub struct Normalize {
@@ -48,6 +52,10 @@ impl<'a> Normalize {
 }
 
 impl<'a> Traverse<'a> for Normalize {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::remove_unused_exports(program, ctx);
+    }
+
     fn exit_program(&mut self, node: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
         if self.options.remove_unnecessary_use_strict && node.source_type.is_module() {
             node.directives.drain_filter(|d| d.directive.as_str() == "use strict");
@@ -131,6 +139,178 @@ impl<'a> Normalize {
         Self { options }
     }
 
+    /// Remove the exports which are not in `treeshake.used_exports`,
+    /// so their declarations can be dropped if the module does not use them.
+    ///
+    /// * `export function f() {}` -> `function f() {}`
+    /// * `export let a = 1, b = 2` -> `export let a = 1; let b = 2`
+    /// * `export { a, b }` -> `export { a }`
+    /// * `export { c } from 'mod'` -> `import 'mod'`
+    /// * `export default foo()` -> `foo()`
+    fn remove_unused_exports(program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        let Some(used_exports) = ctx.state.options.treeshake.used_exports.take() else { return };
+        let is_used = |name: &str| used_exports.contains(name);
+
+        let mut changed = false;
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ExportNamedDeclaration(mut export) => {
+                    if let Some(Declaration::VariableDeclaration(decl)) = &mut export.declaration {
+                        if decl
+                            .declarations
+                            .iter()
+                            .all(|d| d.id.all_binding_identifiers(&mut |id| !is_used(&id.name)))
+                        {
+                            changed = true;
+                            let decl = export.declaration.take().unwrap();
+                            body.push(Statement::from(decl));
+                        } else if decl.declarations.iter().all(|d| {
+                            d.id.get_binding_identifiers().iter().any(|id| is_used(&id.name))
+                        }) {
+                            body.push(Statement::ExportNamedDeclaration(export));
+                        } else {
+                            // Keep the order of the declarators.
+                            changed = true;
+                            let (span, kind) = (decl.span, decl.kind);
+                            for declarator in decl.declarations.take_in(ctx.ast) {
+                                let used = declarator
+                                    .id
+                                    .get_binding_identifiers()
+                                    .iter()
+                                    .any(|id| is_used(&id.name));
+                                let decl = ctx.ast.alloc_variable_declaration(
+                                    span,
+                                    kind,
+                                    ctx.ast.vec1(declarator),
+                                    false,
+                                );
+                                body.push(if used {
+                                    Statement::ExportNamedDeclaration(
+                                        ctx.ast.alloc_export_named_declaration(
+                                            export.span,
+                                            Some(Declaration::VariableDeclaration(decl)),
+                                            ctx.ast.vec(),
+                                            None,
+                                            export.export_kind,
+                                            NONE,
+                                        ),
+                                    )
+                                } else {
+                                    Statement::VariableDeclaration(decl)
+                                });
+                            }
+                        }
+                    } else if let Some(
+                        Declaration::FunctionDeclaration(_) | Declaration::ClassDeclaration(_),
+                    ) = &export.declaration
+                    {
+                        let name = match &export.declaration {
+                            Some(Declaration::FunctionDeclaration(f)) => f.name(),
+                            Some(Declaration::ClassDeclaration(c)) => c.name(),
+                            _ => None,
+                        };
+                        if name.is_some_and(|name| !is_used(&name)) {
+                            changed = true;
+                            body.push(Statement::from(export.declaration.take().unwrap()));
+                        } else {
+                            body.push(Statement::ExportNamedDeclaration(export));
+                        }
+                    } else if export.declaration.is_none() && !export.specifiers.is_empty() {
+                        let len = export.specifiers.len();
+                        export.specifiers.retain(|s| is_used(&s.exported.name()));
+                        changed |= export.specifiers.len() != len;
+                        if export.specifiers.is_empty() {
+                            // Keep the side effects of the module.
+                            if let Some(source) = export.source.take() {
+                                body.push(Self::create_side_effect_import(
+                                    export.span,
+                                    source,
+                                    export.with_clause.take(),
+                                    ctx,
+                                ));
+                            }
+                        } else {
+                            body.push(Statement::ExportNamedDeclaration(export));
+                        }
+                    } else {
+                        body.push(Statement::ExportNamedDeclaration(export));
+                    }
+                }
+                Statement::ExportAllDeclaration(mut export)
+                    if export.exported.as_ref().is_some_and(|name| !is_used(&name.name())) =>
+                {
+                    changed = true;
+                    let span = export.span;
+                    let source = export.source.take_in(ctx.ast);
+                    let with_clause = export.with_clause.take();
+                    body.push(Self::create_side_effect_import(span, source, with_clause, ctx));
+                }
+                Statement::ExportDefaultDeclaration(export) if !is_used("default") => {
+                    let export = export.unbox();
+                    match export.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(f) => {
+                            changed = true;
+                            if f.id.is_some() {
+                                body.push(Statement::FunctionDeclaration(f));
+                            }
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(mut c) => {
+                            changed = true;
+                            if c.id.is_some() {
+                                body.push(Statement::ClassDeclaration(c));
+                            } else {
+                                // Static blocks and decorators may have side effects.
+                                c.r#type = ClassType::ClassExpression;
+                                let expr = Expression::ClassExpression(c);
+                                body.push(ctx.ast.statement_expression(export.span, expr));
+                            }
+                        }
+                        declaration if declaration.is_expression() => {
+                            changed = true;
+                            let expr = declaration.into_expression();
+                            body.push(ctx.ast.statement_expression(export.span, expr));
+                        }
+                        declaration => {
+                            body.push(Statement::ExportDefaultDeclaration(
+                                ctx.ast.alloc_export_default_declaration(export.span, declaration),
+                            ));
+                        }
+                    }
+                }
+                stmt => body.push(stmt),
+            }
+        }
+        program.body = body;
+        ctx.state.options.treeshake.used_exports = Some(used_exports);
+
+        if changed {
+            // The references of the removed specifiers and functions.
+            let mut counter = ReferencesCounter::default();
+            counter.visit_program(program);
+            ctx.scoping_mut().retain_resolved_references(&counter.refs);
+        }
+    }
+
+    fn create_side_effect_import(
+        span: Span,
+        source: StringLiteral<'a>,
+        with_clause: Option<Box<'a, WithClause<'a>>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        Statement::ImportDeclaration(ctx.ast.alloc_import_declaration(
+            span,
+            None,
+            source,
+            None,
+            with_clause,
+            ImportOrExportKind::Value,
+        ))
+    }
+
     fn recover_arrow_expression_after_drop_console(
         expr: &mut ArrowFunctionExpression<'a>,
         ctx: &TraverseCtx<'a>,
@@ -29,6 +29,7 @@ impl<'a> PeepholeOptimizations {
             Expression::SequenceExpression(_) => Self::remove_unused_sequence_expr(e, ctx),
             Expression::TemplateLiteral(_) => Self::remove_unused_template_literal(e, ctx),
             Expression::UnaryExpression(_) => Self::remove_unused_unary_expr(e, ctx),
+            match_member_expression!(Expression) => Self::remove_unused_member_expr(e, ctx),
             // In a derived class constructor, accessing `this` before `super()` throws
             // a `ReferenceError`, so we must keep it. In all other positions (including
             // non-derived constructors) `this` is always initialized and can be dropped.
@@ -81,6 +82,28 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// `a.b.c` -> `a`, `a[b()]` -> `a, b()`
+    ///
+    /// This is only done with `unsafe.pure_getters`, because getters may have side effects
+    /// and reading a property of `null` or `undefined` throws.
+    fn remove_unused_member_expr(e: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
+        if !ctx.options().r#unsafe.pure_getters {
+            return !e.may_have_side_effects(ctx);
+        }
+        match e.take_in(ctx.ast) {
+            Expression::StaticMemberExpression(member) => *e = member.unbox().object,
+            Expression::PrivateFieldExpression(member) => *e = member.unbox().object,
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let exprs = ctx.ast.vec_from_array([member.object, member.expression]);
+                *e = ctx.ast.expression_sequence(member.span, exprs);
+            }
+            _ => unreachable!(),
+        }
+        ctx.state.changed = true;
+        Self::remove_unused_expression(e, ctx)
+    }
+
     fn remove_unused_sequence_expr(e: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
         let Expression::SequenceExpression(sequence_expr) = e else { return false };
         let old_len = sequence_expr.expressions.len();
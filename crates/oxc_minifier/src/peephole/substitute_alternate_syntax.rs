@@ -3,6 +3,7 @@ use std::iter::repeat_with;
 use crate::generated::ancestor::Ancestor;
 use oxc_allocator::{CloneIn, TakeIn, Vec};
 use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
 use oxc_compat::ESFeature;
 use oxc_ecmascript::constant_evaluation::{ConstantEvaluation, ConstantValue, DetermineValueType};
 use oxc_ecmascript::side_effects::MayHaveSideEffectsContext;
@@ -15,7 +16,9 @@ use oxc_syntax::{
     identifier::is_identifier_name_patched,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
 };
+use rustc_hash::FxHashSet;
 
 use crate::TraverseCtx;
 
@@ -38,6 +41,7 @@ impl<'a> PeepholeOptimizations {
         }
 
         Self::try_compress_property_key(&mut prop.key, &mut prop.computed, ctx);
+        Self::try_compress_object_method(prop, ctx);
     }
 
     pub fn substitute_assignment_target_property_property(
@@ -1478,6 +1482,111 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Replace `void 0` with a variable named `undefined` in scope, which is mangled to a shorter name.
+    ///
+    /// e.g. `(function (undefined) { return void 0 })()` -> `(function (undefined) { return undefined })()`
+    ///
+    /// This compression is only done with `unsafe.undefined`,
+    /// because the variable is assumed to be `undefined` without checking.
+    pub fn substitute_unsafe_void_0(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().r#unsafe.undefined || !expr.is_void_0() {
+            return;
+        }
+        let name = ctx.ast.ident("undefined");
+        let Some(symbol_id) = ctx.scoping().find_binding(ctx.current_scope_id(), name) else {
+            return;
+        };
+        *expr = ctx.create_bound_ident_expr(expr.span(), name, symbol_id, ReferenceFlags::Read);
+        ctx.state.changed = true;
+    }
+
+    /// Convert a function expression to an arrow function if it does not depend on its own `this`.
+    ///
+    /// e.g. `function (a) { return a }` -> `(a) => { return a }`
+    ///
+    /// This compression is only done with `unsafe.arrows`,
+    /// because arrow functions can not be constructed and have no `prototype`.
+    pub fn substitute_unsafe_arrow_function(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().r#unsafe.arrows {
+            return;
+        }
+        let Expression::FunctionExpression(func) = expr else { return };
+        let Some(body) = &func.body else { return };
+        if func.id.is_some() || func.generator || func.this_param.is_some() {
+            return;
+        }
+        match ctx.parent() {
+            // `new (function () {})()`
+            Ancestor::NewExpressionCallee(_) => return,
+            // `{ m() {} }`, `{ get g() {} }` and `{ set s(v) {} }` are not data properties.
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.method() || *prop.kind() != PropertyKind::Init =>
+            {
+                return;
+            }
+            // `{ m: function () {} }` -> `{ m() {} }` is shorter.
+            Ancestor::ObjectPropertyValue(_) if ctx.options().r#unsafe.methods => return,
+            _ => {}
+        }
+        // `function (a, a) {}` is allowed in sloppy mode, but `(a, a) => {}` is not.
+        let mut names = FxHashSet::default();
+        if !func.params.items.iter().all(|param| {
+            param.pattern.get_binding_identifiers().iter().all(|id| names.insert(id.name))
+        }) {
+            return;
+        }
+        let mut checker = FunctionContextChecker::default();
+        checker.visit_formal_parameters(&func.params);
+        checker.visit_function_body(body);
+        if checker.found {
+            return;
+        }
+
+        let Expression::FunctionExpression(func) = expr.take_in(ctx.ast) else { unreachable!() };
+        let func = func.unbox();
+        let scope_id = func.scope_id();
+        *ctx.scoping_mut().scope_flags_mut(scope_id) |= ScopeFlags::Arrow;
+        *expr = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            func.span,
+            false,
+            func.r#async,
+            func.type_parameters,
+            func.params,
+            func.return_type,
+            func.body.unwrap(),
+            scope_id,
+            func.pure,
+            func.pife,
+        );
+        ctx.state.changed = true;
+    }
+
+    /// Convert a function expression in an object literal to a method.
+    ///
+    /// e.g. `{ m: function () {} }` -> `{ m() {} }`
+    ///
+    /// This compression is only done with `unsafe.methods`,
+    /// because methods can not be constructed and have no `prototype`.
+    fn try_compress_object_method(prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.options().r#unsafe.methods
+            || prop.method
+            || prop.shorthand
+            || prop.kind != PropertyKind::Init
+        {
+            return;
+        }
+        // `{ __proto__: function () {} }` sets the prototype, while `{ __proto__() {} }` does not.
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            return;
+        }
+        if let Expression::FunctionExpression(func) = &prop.value
+            && func.id.is_none()
+        {
+            prop.method = true;
+            ctx.state.changed = true;
+        }
+    }
+
     /// `new Int8Array(0)` -> `new Int8Array()` (also for other TypedArrays)
     pub fn substitute_typed_array_constructor(e: &mut NewExpression<'a>, ctx: &TraverseCtx<'a>) {
         let Expression::Identifier(ident) = &e.callee else { return };
@@ -1829,6 +1938,36 @@ impl From<&MethodDefinition<'_>> for ClassPropertyKeyParent {
     }
 }
 
+/// Finds uses of `this`, `arguments` and `new.target` which refer to the visited function.
+#[derive(Default)]
+struct FunctionContextChecker {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for FunctionContextChecker {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.found = true;
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if it.name == "arguments" {
+            self.found = true;
+        }
+    }
+
+    // Nested functions have their own `this`.
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    // Class bodies have their own `this`, but computed keys do not.
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.found = true;
+    }
+}
+
 impl<T> From<&mut T> for ClassPropertyKeyParent
 where
     ClassPropertyKeyParent: for<'a> std::convert::From<&'a T>,
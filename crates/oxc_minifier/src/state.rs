@@ -6,7 +6,7 @@ use oxc_span::SourceType;
 use oxc_str::Str;
 use oxc_syntax::symbol::SymbolId;
 
-use crate::{CompressOptions, symbol_value::SymbolValues};
+use crate::{CompressOptions, peephole::InlineFunction, symbol_value::SymbolValues};
 
 pub struct MinifierState<'a> {
     pub source_type: SourceType,
@@ -21,6 +21,9 @@ pub struct MinifierState<'a> {
 
     pub symbol_values: SymbolValues<'a>,
 
+    /// Functions which can be inlined at their call sites
+    pub inline_functions: FxHashMap<SymbolId, InlineFunction<'a>>,
+
     /// Private member usage for classes
     pub class_symbols_stack: ClassSymbolsStack<'a>,
 
@@ -40,6 +43,7 @@ impl MinifierState<'_> {
             dce,
             pure_functions: FxHashMap::default(),
             symbol_values: SymbolValues::default(),
+            inline_functions: FxHashMap::default(),
             class_symbols_stack: ClassSymbolsStack::new(),
             proto_write_symbols: FxHashSet::default(),
             changed: false,
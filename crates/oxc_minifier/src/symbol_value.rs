@@ -1,7 +1,7 @@
 use rustc_hash::FxHashMap;
 
 use oxc_ecmascript::constant_evaluation::ConstantValue;
-use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use oxc_syntax::{reference::ReferenceFlags, scope::ScopeId, symbol::SymbolId};
 
 #[derive(Debug)]
 pub struct SymbolValue<'a> {
@@ -45,4 +45,18 @@ impl<'a> SymbolValues<'a> {
     pub fn get_symbol_value(&self, symbol_id: SymbolId) -> Option<&SymbolValue<'a>> {
         self.values.get(&symbol_id)
     }
+
+    /// Count a reference created after the symbol's value was initialized.
+    pub fn add_reference(&mut self, symbol_id: SymbolId, flags: ReferenceFlags) {
+        let Some(value) = self.values.get_mut(&symbol_id) else { return };
+        if flags.is_read() {
+            value.read_references_count += 1;
+        }
+        if flags.is_write() {
+            value.write_references_count += 1;
+        }
+        if flags.is_member_write_target() {
+            value.member_write_target_read_count += 1;
+        }
+    }
 }
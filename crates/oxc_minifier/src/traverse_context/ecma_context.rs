@@ -83,6 +83,9 @@ impl<'a> MayHaveSideEffectsContext<'a> for TraverseCtx<'a, MinifierState<'a>> {
     }
 
     fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        if self.state.options.r#unsafe.pure_getters {
+            return PropertyReadSideEffects::None;
+        }
         self.state.options.treeshake.property_read_side_effects
     }
 
@@ -226,10 +229,8 @@ impl<'a> TraverseCtx<'a, MinifierState<'a>> {
 
     #[inline]
     pub fn is_identifier_undefined(&self, ident: &IdentifierReference) -> bool {
-        if ident.name == "undefined" && ident.is_global_reference(self.scoping()) {
-            return true;
-        }
-        false
+        ident.name == "undefined"
+            && (ident.is_global_reference(self.scoping()) || self.options().r#unsafe.undefined)
     }
 
     pub fn init_value(
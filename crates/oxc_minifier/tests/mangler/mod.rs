@@ -2,7 +2,9 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleNameCache, MangleOptions, MangleOptionsKeepNames, MangleOptionsProperties, Mangler,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -19,6 +21,7 @@ fn mangle_with_source_type(
     Codegen::new()
         .with_scoping(Some(mangler_return.scoping))
         .with_private_member_mappings(Some(mangler_return.class_private_mappings))
+        .with_property_mappings(mangler_return.property_mappings)
         .build(&program)
         .code
 }
@@ -52,12 +55,12 @@ fn direct_eval() {
 
     // Symbols in scopes with direct eval should NOT be mangled
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 
     // Nested direct eval: parent scope also should not mangle
     let source_text = "function foo() { let NO_MANGLE; function bar() { eval('') } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert_eq!(
         mangled,
         "function foo() {\n\tlet NO_MANGLE;\n\tfunction bar() {\n\t\teval(\"\");\n\t}\n}\n"
@@ -66,14 +69,14 @@ fn direct_eval() {
     // Sibling scope without direct eval should be mangled
     let source_text =
         "function foo() { let NO_MANGLE; eval('') } function bar() { let SHOULD_MANGLE; }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     // SHOULD_MANGLE gets mangled (to some short name), NO_MANGLE stays as is
     assert!(mangled.contains("NO_MANGLE"));
     assert!(!mangled.contains("SHOULD_MANGLE"));
 
     // Child function scope without direct eval CAN be mangled (eval in parent cannot access child function locals)
     let source_text = "function foo() { eval(''); function bar() { let CAN_MANGLE; } }";
-    let mangled = mangle(source_text, options);
+    let mangled = mangle(source_text, options.clone());
     assert!(!mangled.contains("CAN_MANGLE"));
 
     // Indirect eval should still allow mangling
@@ -219,6 +222,117 @@ fn private_member_mangling() {
     });
 }
 
+#[test]
+fn property_mangling() {
+    let cases = [
+        "let o = { foo: 1, bar: 2 }; o.foo + o.bar + o.foo",
+        "class A { foo = 1; static bar; baz() { return this.foo } get qux() {} }",
+        "let foo = 1; let o = { foo, bar() {} }; o.foo",
+        "let { foo, bar: baz = 1 } = o; ({ foo, bar } = o); ({ foo: x = 1 } = o)",
+        // Quoted names are never mangled
+        "o.foo; o['foo']; o.bar; ({ 'baz': 1 }).baz; 'qux' in o; o.qux",
+        // Builtin names are never mangled
+        "o.length; o.push(1); o.addEventListener; o.myProp",
+        "class A { constructor() { this.x1 = 1 } }; ({ __proto__: null, y1: 1 })",
+        // Mangled names must not collide with names which are kept
+        "o.e; o['t']; o.foo; o.bar",
+    ];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions {
+            properties: Some(MangleOptionsProperties::default()),
+            ..MangleOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
+    let case = "o._foo; o._bar; o.baz; o._keep";
+    let options = MangleOptions {
+        properties: Some(MangleOptionsProperties {
+            regex: Some(lazy_regex::Regex::new("^_[a-z]+$").unwrap()),
+            reserved: vec!["_keep".to_string()],
+            builtins: false,
+        }),
+        ..MangleOptions::default()
+    };
+    write!(snapshot, "{case}\n{}\n", mangle(case, options)).unwrap();
+
+    let case = "o.length; o.foo";
+    let options = MangleOptions {
+        properties: Some(MangleOptionsProperties { builtins: true, ..Default::default() }),
+        ..MangleOptions::default()
+    };
+    write!(snapshot, "{case}\n{}\n", mangle(case, options)).unwrap();
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("property_mangling", snapshot);
+    });
+}
+
+fn mangle_with_name_cache(
+    source_text: &str,
+    options: MangleOptions,
+    name_cache: MangleNameCache,
+) -> (String, MangleNameCache) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.errors.is_empty(), "Parser errors: {:?}", ret.errors);
+    let program = ret.program;
+    let options = MangleOptions { name_cache: Some(name_cache), ..options };
+    let mangler_return = Mangler::new().with_options(options).build(&program);
+    let code = Codegen::new()
+        .with_scoping(Some(mangler_return.scoping))
+        .with_property_mappings(mangler_return.property_mappings)
+        .build(&program)
+        .code;
+    (code, mangler_return.name_cache.unwrap())
+}
+
+#[test]
+fn name_cache() {
+    let options = MangleOptions {
+        properties: Some(MangleOptionsProperties::default()),
+        ..MangleOptions::default()
+    };
+
+    let (code, cache) = mangle_with_name_cache(
+        "function foo() {} function bar() {} foo(bar); o.prop1; o.prop2; o.prop2",
+        options.clone(),
+        MangleNameCache::default(),
+    );
+    assert_eq!(code, "function e() {}\nfunction t() {}\ne(t);\no.e;\no.t;\no.t;\n");
+    assert_eq!(cache.vars.get("foo").map(String::as_str), Some("e"));
+    assert_eq!(cache.vars.get("bar").map(String::as_str), Some("t"));
+    assert_eq!(cache.props.get("prop1").map(String::as_str), Some("e"));
+    assert_eq!(cache.props.get("prop2").map(String::as_str), Some("t"));
+
+    // New symbols and properties, which would take the shortest names without the cache,
+    // get names which do not collide with the cached ones.
+    let (code, extended) = mangle_with_name_cache(
+        "function baz() {} baz(); baz(); function foo() {} function bar() {} foo(bar); o.prop3; o.prop3; o.prop3; o.prop1",
+        options,
+        cache.clone(),
+    );
+    assert_eq!(
+        code,
+        "function n() {}\nn();\nn();\nfunction e() {}\nfunction t() {}\ne(t);\no.n;\no.n;\no.n;\no.e;\n"
+    );
+    assert_eq!(extended.vars.len(), 3);
+    assert_eq!(extended.props.len(), 3);
+    assert!(cache.vars.iter().all(|(k, v)| extended.vars.get(k) == Some(v)));
+    assert!(cache.props.iter().all(|(k, v)| extended.props.get(k) == Some(v)));
+
+    // Cached names which would collide with a global reference or an exported name are not reused.
+    let (code, _) = mangle_with_name_cache(
+        "export function e() {} function foo() {} function bar() {} foo(bar, t)",
+        MangleOptions::default(),
+        cache,
+    );
+    assert_eq!(code, "export function e() {}\nfunction n() {}\nfunction r() {}\nn(r, t);\n");
+}
+
 /// A named function expression whose name is shadowed by a same-named declaration in its
 /// body must receive the same mangled name as the shadowing symbol; otherwise the emitted
 /// fn-expr name collides with whichever unrelated outer-scope variable happens to own slot 0.
@@ -230,14 +344,14 @@ fn function_expression_name_shadowed() {
     test(
         "function _() { var x; var f = function foo() { var foo = x; } }",
         "function _() { var e; var t = function t() { var t = e; } }",
-        options,
+        options.clone(),
     );
 
     // Parameter shadow.
     test(
         "function _() { var x; (function foo(foo) { foo + x })() }",
         "function _() { var e; (function t(t) { t + e; })(); }",
-        options,
+        options.clone(),
     );
 
     // `var` inside an `else` block — still hoists through the block scope to the fn-expr scope.
@@ -267,8 +381,8 @@ fn shadowed_fn_expr_mangle_is_idempotent() {
     ];
 
     for case in cases {
-        let pass1 = mangle(case, options);
-        let pass2 = mangle(&pass1, options);
+        let pass1 = mangle(case, options.clone());
+        let pass2 = mangle(&pass1, options.clone());
         assert_eq!(
             pass1, pass2,
             "\nIdempotency failure for:\n{case}\nPass 1:\n{pass1}\nPass 2:\n{pass2}"
@@ -4,7 +4,9 @@ static NEG_MAX_SAFE_FLOAT: f64 = -9_007_199_254_740_991_f64;
 static MAX_SAFE_INT: i64 = 9_007_199_254_740_991_i64;
 static NEG_MAX_SAFE_INT: i64 = -9_007_199_254_740_991_i64;
 
-use crate::test;
+use crate::{
+    CompressOptions, CompressOptionsUnsafe, default_options, test, test_options, test_same_options,
+};
 
 // wrap with a function call so it doesn't get removed.
 fn fold(source_text: &str, expected: &str) {
@@ -1353,3 +1355,21 @@ mod bigint {
         fold("({ ...{ ['__proto__']: null } })", "({ ['__proto__']: null })");
     }
 }
+
+#[test]
+fn test_fold_unsafe_math() {
+    test_same("x = a * 2 * 3");
+    test_same("x = a / 2 / 4");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe { math: true, ..CompressOptionsUnsafe::all_false() },
+        ..default_options()
+    };
+    test_options("x = a * 2 * 3", "x = a * 6", &options);
+    test_options("x = a / 2 / 4", "x = a / 8", &options);
+    test_options("x = a * 2 * 3 * 4", "x = a * 24", &options);
+    test_same_options("x = a * 2 / 3", &options);
+    test_same_options("x = a / 2 * 3", &options);
+    test_same_options("x = 2 * a * 3", &options);
+    test_same_options("x = a * 1e300 * 1e300", &options);
+}
@@ -0,0 +1,106 @@
+use oxc_span::SourceType;
+
+use crate::{
+    CompressOptions, default_options, test_options, test_options_source_type, test_same,
+    test_same_options, test_same_options_source_type,
+};
+
+fn options() -> CompressOptions {
+    CompressOptions { hoist_literals: true, ..default_options() }
+}
+
+#[test]
+fn hoist_strings() {
+    let options = options();
+    test_same("x('a long string', 'a long string', 'a long string')");
+    test_options(
+        "x('a long string', 'a long string', 'a long string')",
+        "let _str = 'a long string'; x(_str, _str, _str)",
+        &options,
+    );
+    test_options(
+        "x('click'); y('click'); z('click')",
+        "let _click = 'click'; x(_click), y(_click), z(_click)",
+        &options,
+    );
+    // Not a size win
+    test_same_options("x('ab', 'ab', 'ab', 'ab')", &options);
+    test_same_options("x('abcdef', 'abcdef')", &options);
+}
+
+#[test]
+fn hoist_property_names() {
+    let options = options();
+    test_options(
+        "a.prototype.x = 1; b.prototype.y = 2; c.prototype.z = 3",
+        "let _prototype = 'prototype'; a[_prototype].x = 1, b[_prototype].y = 2, c[_prototype].z = 3",
+        &options,
+    );
+    // Property names and strings share a variable.
+    test_options(
+        "x(a.prototype, b.prototype, 'prototype')",
+        "let _prototype = 'prototype'; x(a[_prototype], b[_prototype], _prototype)",
+        &options,
+    );
+    test_options(
+        "x(a?.prototype.x, b?.prototype.y, c?.prototype.z, d.prototype)",
+        "let _prototype = 'prototype'; x(a?.[_prototype].x, b?.[_prototype].y, c?.[_prototype].z, d[_prototype])",
+        &options,
+    );
+}
+
+#[test]
+fn hoist_to_function() {
+    let options = options();
+    test_options(
+        "function f() { x('a long string', 'a long string', 'a long string') }",
+        "function f() { let _str = 'a long string'; x(_str, _str, _str) }",
+        &options,
+    );
+    test_options(
+        "function f() { let a = x(); return () => a('a long string', 'a long string', 'a long string') }",
+        "function f() { let _str = 'a long string', a = x(); return () => a(_str, _str, _str) }",
+        &options,
+    );
+    // Parameters can not see variables in the function body.
+    test_options(
+        "function f(a = 'a long string') { x('a long string', 'a long string') }",
+        "let _str = 'a long string'; function f(a = _str) { x(_str, _str) }",
+        &options,
+    );
+    test_options(
+        "function f() { x('a long string') } function g() { x('a long string', 'a long string') }",
+        "let _str = 'a long string'; function f() { x(_str) } function g() { x(_str, _str) }",
+        &options,
+    );
+    // Top level variables of scripts are global.
+    test_same_options_source_type(
+        "x('a long string', 'a long string', 'a long string');",
+        SourceType::cjs().with_script(true),
+        &options,
+    );
+    test_options_source_type(
+        "function f() { x('a long string', 'a long string', 'a long string') }",
+        "function f() { let _str = 'a long string'; x(_str, _str, _str) }",
+        SourceType::cjs().with_script(true),
+        &options,
+    );
+}
+
+#[test]
+fn keep_literals() {
+    let options = options();
+    test_same_options(
+        "x({ 'a long string': 1, 'a long string': 2, 'a long string': 3, 'a long string': 4 })",
+        &options,
+    );
+    test_same_options(
+        "with (o) x('a long string', 'a long string', 'a long string', a.prototype, b.prototype)",
+        &options,
+    );
+    test_options(
+        "import 'a long string'; x('a long string', 'a long string', 'a long string')",
+        "import 'a long string'; let _str = 'a long string'; x(_str, _str, _str)",
+        &options,
+    );
+}
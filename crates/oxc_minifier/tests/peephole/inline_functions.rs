@@ -0,0 +1,164 @@
+use crate::{CompressOptions, default_options, test_options, test_same, test_same_options};
+
+fn options() -> CompressOptions {
+    CompressOptions { inline_functions: true, ..default_options() }
+}
+
+fn smallest_options() -> CompressOptions {
+    CompressOptions { inline_functions: true, ..CompressOptions::smallest() }
+}
+
+#[test]
+fn tiny_functions() {
+    let options = options();
+    test_same("function get(o) { return o.x } log(get(a), get(b))");
+    test_options(
+        "function get(o) { return o.x } log(get(a), get(b))",
+        "function get(o) { return o.x } log(a.x, b.x)",
+        &options,
+    );
+    test_options(
+        "const not = (v) => !v; log(not(a), not(b))",
+        "const not = (v) => !v; log(!a, !b)",
+        &options,
+    );
+    test_options(
+        "function f() { return g } function h(x, y) { return [f(), f()] }",
+        "function f() { return g } function h(x, y) { return [g, g] }",
+        &options,
+    );
+
+    let options = smallest_options();
+    test_options("function get(o) { return o.x } log(get(a), get(b))", "log(a.x, b.x)", &options);
+    test_options("const get = (o) => o.x; log(get(a), get(b))", "log(a.x, b.x)", &options);
+}
+
+#[test]
+fn single_call_functions() {
+    let options = options();
+    // Immediately invoked
+    test_options("log(((o) => o.x)(a))", "log(a.x)", &options);
+    test_options("log(((o, p) => o.x + p)(a, 1))", "log(a.x + 1)", &options);
+    test_options("var f = (o) => o.x; log(f(a))", "log(a.x)", &options);
+    test_same_options("log(((o) => this.x)(a))", &options);
+    // The declaration is kept
+    test_same_options("function f(a) { return a.log(1, 2) } f(x)", &options);
+
+    let options = smallest_options();
+    test_options("function f(a) { return a.log(1, 2) } f(x)", "x.log(1, 2)", &options);
+    test_options("function f(a) { a.log(1, 2) } f(x)", "x.log(1, 2)", &options);
+    test_options("function f(a) { a.log(1, 2) } log(f(x))", "log((x.log(1, 2), void 0))", &options);
+    test_options("const f = function (a) { return a.log(1, 2) }; f(x)", "x.log(1, 2)", &options);
+    // Called more than once
+    test_same_options("function f(a) { return a.log(1, 2) } f(x), f(y)", &options);
+    test_same_options("export function f(a) { return a.log(1, 2) } f(x)", &options);
+}
+
+#[test]
+fn arguments() {
+    let options = options();
+    // Missing arguments are `undefined`
+    test_options(
+        "function f(a, b) { return [a, b] } log(f(1))",
+        "function f(a, b) { return [a, b] } log([1, void 0])",
+        &options,
+    );
+    // Unused arguments without side effects are dropped
+    test_options(
+        "function get(o) { return o.x } log(get(a, 1))",
+        "function get(o) { return o.x } log(a.x)",
+        &options,
+    );
+    test_same_options("function get(o) { return o.x } log(get(a, b()))", &options);
+    test_same_options("function get(o) { return o.x } log(get(...a))", &options);
+    // Constant arguments can be copied and reordered
+    test_options(
+        "function sq(a) { return a * a } function g(x) { return sq(x) + sq(2) }",
+        "function sq(a) { return a * a } function g(x) { return x * x + 4 }",
+        &options,
+    );
+    test_options(
+        "function sub(a, b) { return b - a } function g(x, y) { return sub(x, y) }",
+        "function sub(a, b) { return b - a } function g(x, y) { return y - x }",
+        &options,
+    );
+    // Other arguments must be evaluated first
+    test_same_options("function sub(a, b) { return b - a } log(sub(x(), y()))", &options);
+    test_same_options("function sq(a) { return a * a } log(sq(x()))", &options);
+    test_options(
+        "function sub(a, b) { return a - b } log(sub(x(), 1))",
+        "function sub(a, b) { return a - b } log(x() - 1)",
+        &options,
+    );
+    test_same_options("function get(o) { return o.x } log(get(a?.b))", &options);
+    test_same_options("function call(f) { return f() } log(call(a.b))", &options);
+    test_same_options("function type(v) { return typeof v } log(type(a))", &options);
+}
+
+#[test]
+fn copied_arguments_are_counted_as_references() {
+    let options = smallest_options();
+    // `v` is referenced twice after inlining, so its initializer must not be moved into one of them.
+    test_options(
+        "function f(a) { return [a, a] } const v = {}; console.log(f(v));",
+        "const v = {}; console.log([v, v]);",
+        &options,
+    );
+    test_options(
+        "function f(a) { return a === a } const v = []; console.log(f(v));",
+        "const v = []; console.log(v === v);",
+        &options,
+    );
+}
+
+#[test]
+fn not_inlined() {
+    let options = options();
+    test_same_options("function f() { return this } log(f())", &options);
+    test_same_options("function f() { return arguments } log(f())", &options);
+    test_same_options("function f(a) { return f(a - 1) } log(f(1))", &options);
+    test_same_options("function f(a) { return () => a } log(f(1))", &options);
+    test_same_options("function f(a) { return x = a } log(f(1))", &options);
+    test_same_options("function f(a) { return a } f = g, log(f(1))", &options);
+    test_same_options("function f(a = 1) { return a } log(f())", &options);
+    test_same_options("function f(...a) { return a } log(f())", &options);
+    test_same_options("function f({ a }) { return a } log(f(o))", &options);
+    test_same_options("function* f() { return 1 } log(f())", &options);
+    test_same_options("async function f() { return 1 } log(f())", &options);
+    // `y` refers to a different binding at the call site
+    test_same_options("function f() { return y } function g(y) { return f() }", &options);
+    // Calling a member expression changes `this`
+    test_same_options("function get(o) { return o.x } get(a)()", &options);
+    test_same_options("function get(o) { return o.x } delete get(a)", &options);
+}
+
+#[test]
+fn disabled() {
+    let options = CompressOptions::smallest();
+    test_same_options("function get(o) { return o.x } log(get(a), get(b))", &options);
+    test_same_options("const get = (o) => o.x; log(get(a), get(b))", &options);
+    test_same_options("function f(a) { return a.log(1, 2) } f(x)", &options);
+    test_same_options("log(((o) => o.x)(a))", &options);
+
+    let options = CompressOptions::safest();
+    test_same_options("function get(o) { return o.x } log(get(a), get(b))", &options);
+}
+
+#[test]
+fn interaction_with_other_passes() {
+    let options = options();
+    test_options(
+        "const a = (x) => x, b = () => a(1);",
+        "const a = (x) => x, b = () => 1;",
+        &options,
+    );
+    test_options(
+        "function Object(x){return x} Object(f)(1)",
+        "function Object(x){return x} f(1)",
+        &options,
+    );
+    let options = smallest_options();
+    test_options("function foo() { bar } foo()", "bar", &options);
+    test_options("function foo() { var t; return t = x(); } foo();", "x()", &options);
+    test_options("function foo() { let t; return t = x(); } foo();", "x()", &options);
+}
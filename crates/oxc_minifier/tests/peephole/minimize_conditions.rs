@@ -1,4 +1,7 @@
-use crate::{test, test_same, test_target, test_target_same};
+use crate::{
+    CompressOptions, CompressOptionsUnsafe, default_options, test, test_options, test_same,
+    test_target, test_target_same,
+};
 
 /** Check that removing blocks with 1 child works */
 #[test]
@@ -1191,3 +1194,20 @@ fn try_minimize_binary() {
     test("f(!a === true)", "f(!a)");
     test("f(!a === false)", "f(!!a)");
 }
+
+#[test]
+fn test_unsafe_comps() {
+    test_same("x = !(a < b)");
+    test("if (!(a <= b)) c()", "a <= b || c()");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe { comps: true, ..CompressOptionsUnsafe::all_false() },
+        ..default_options()
+    };
+    test_options("x = !(a < b)", "x = a >= b", &options);
+    test_options("x = !(a <= b)", "x = a > b", &options);
+    test_options("x = !(a > b)", "x = a <= b", &options);
+    test_options("x = !(a >= b)", "x = a < b", &options);
+    test_options("if (!(a <= b)) c()", "a > b && c()", &options);
+    test_options("x = !(a == b)", "x = a != b", &options);
+}
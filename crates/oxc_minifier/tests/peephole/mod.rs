@@ -3,7 +3,9 @@ mod convert_to_dotted_properties;
 mod dead_code_elimination;
 mod esbuild;
 mod fold_constants;
+mod hoist_literals;
 mod inline;
+mod inline_functions;
 mod inline_single_use_variable;
 mod manual_pure_functions;
 mod merge_assignments_to_declarations;
@@ -25,3 +27,4 @@ mod remove_unused_private_members;
 mod replace_known_methods;
 mod statement_fusion;
 mod substitute_alternate_syntax;
+mod tree_shake_exports;
@@ -1,8 +1,9 @@
 use oxc_ecmascript::side_effects::PropertyReadSideEffects;
 
 use crate::{
-    CompressOptions, CompressOptionsUnused, TreeShakeOptions, default_options, test, test_options,
-    test_options_source_type, test_same, test_same_options, test_same_options_source_type,
+    CompressOptions, CompressOptionsUnsafe, CompressOptionsUnused, TreeShakeOptions,
+    default_options, test, test_options, test_options_source_type, test_same, test_same_options,
+    test_same_options_source_type,
 };
 
 #[test]
@@ -825,3 +826,25 @@ fn test_update_expression_respects_property_read_side_effects() {
         &options,
     );
 }
+
+#[test]
+fn test_unsafe_pure_getters() {
+    test_same("a.b.c");
+    test_same("a().b");
+    test_same("a[b()]");
+    test_same("var a; a.b");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe {
+            pure_getters: true,
+            ..CompressOptionsUnsafe::all_false()
+        },
+        ..default_options()
+    };
+    test_options("a.b.c", "a", &options);
+    test_options("a().b", "a()", &options);
+    test_options("a[b()]", "a, b()", &options);
+    test_options("var a; a.b", "var a", &options);
+    test_options("var a; a.b[c.d]", "var a; c", &options);
+    test_same_options("x = a.b", &options);
+}
@@ -1,8 +1,9 @@
 use oxc_span::SourceType;
 
 use crate::{
-    CompressOptions, CompressOptionsKeepNames, CompressOptionsUnused, default_options, test,
-    test_options, test_same, test_same_options, test_same_options_source_type, test_target_same,
+    CompressOptions, CompressOptionsKeepNames, CompressOptionsUnsafe, CompressOptionsUnused,
+    default_options, test, test_options, test_same, test_same_options,
+    test_same_options_source_type, test_target_same,
 };
 
 #[test]
@@ -981,3 +982,88 @@ fn test_flatten_array_spread_elements() {
     test_same("var y = [0, ...[1, , , 3]]");
     test("var y = [...[1, , ,], ...[, 2], , 2];", "var y = [...[1, , , ], void 0, 2, , 2];");
 }
+
+#[test]
+fn test_unsafe_arrows() {
+    test_same("x = function (a) { return a }");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe { arrows: true, ..CompressOptionsUnsafe::all_false() },
+        ..default_options()
+    };
+    test_options("x = function (a) { return a }", "x = (a) => a", &options);
+    test_options("x = async function () { await a() }", "x = async () => { await a() }", &options);
+    test_options("x = function () { return () => a }", "x = () => () => a", &options);
+    test_options(
+        "x = function () { return function () { return this } }",
+        "x = () => function () { return this }",
+        &options,
+    );
+    test_options("x = { m: function () { a() } }", "x = { m: () => { a() } }", &options);
+    test_same_options("x = function () { return this }", &options);
+    test_same_options("x = function () { return () => this }", &options);
+    test_same_options("x = function () { return arguments }", &options);
+    test_same_options("x = function () { return new.target }", &options);
+    test_same_options("x = function () { return class { a = this } }", &options);
+    test_same_options("x = function* () {}", &options);
+    test_same_options("x = function f() { return f }", &options);
+    test_same_options("x = function (a, a) { return a }", &options);
+    test_same_options("x = new (function () { a() })()", &options);
+    test_same_options("x = { m() { return 1 } }", &options);
+    test_same_options("x = { get g() { return 2 } }", &options);
+    test_same_options("x = { set s(v) { a(v) } }", &options);
+}
+
+#[test]
+fn test_unsafe_methods() {
+    test_same("x = { m: function () { a() } }");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe { methods: true, ..CompressOptionsUnsafe::all_false() },
+        ..default_options()
+    };
+    test_options("x = { m: function () { a() } }", "x = { m() { a() } }", &options);
+    test_options("x = { [k]: function () { a() } }", "x = { [k]() { a() } }", &options);
+    test_options("x = { m: async function* () { a() } }", "x = { async *m() { a() } }", &options);
+    test_same_options("x = { m: function f() { f() } }", &options);
+    test_same_options("x = { m: () => {} }", &options);
+    test_same_options("x = { __proto__: function () {} }", &options);
+
+    let options =
+        CompressOptions { r#unsafe: CompressOptionsUnsafe::all_true(), ..default_options() };
+    test_options("x = { m: function () { a() } }", "x = { m() { a() } }", &options);
+}
+
+#[test]
+fn test_unsafe_undefined() {
+    test_same("(function (undefined) { x(void 0) })()");
+
+    let options = CompressOptions {
+        r#unsafe: CompressOptionsUnsafe { undefined: true, ..CompressOptionsUnsafe::all_false() },
+        ..default_options()
+    };
+    test_options(
+        "(function (undefined) { x(void 0) })()",
+        "(function (undefined) { x(undefined) })()",
+        &options,
+    );
+    test_options(
+        "function f(undefined) { return () => void 0 }",
+        "function f(undefined) { return () => undefined }",
+        &options,
+    );
+    // The variable is treated as `undefined`
+    test_options(
+        "function f(undefined) { return undefined }",
+        "function f(undefined) { }",
+        &options,
+    );
+    test_options(
+        "function f(undefined) { return x == undefined }",
+        "function f(undefined) { return x == null }",
+        &options,
+    );
+    // There is no variable named `undefined`
+    test_same_options("x(void 0)", &options);
+    test_same_options("function f(a) { x(void 0) }", &options);
+}
@@ -0,0 +1,74 @@
+use crate::{CompressOptions, TreeShakeOptions, test_options, test_same_options};
+
+fn options(used_exports: &[&str]) -> CompressOptions {
+    CompressOptions {
+        treeshake: TreeShakeOptions {
+            used_exports: Some(used_exports.iter().map(ToString::to_string).collect()),
+            ..TreeShakeOptions::default()
+        },
+        ..CompressOptions::smallest()
+    }
+}
+
+#[test]
+fn declarations() {
+    let options = options(&["a"]);
+    test_options(
+        "export function a() {} export function b() {}",
+        "export function a() {}",
+        &options,
+    );
+    test_options("export class a {} export class b {}", "export class a {}", &options);
+    test_options("export let a = 1, b = 2; export var c = 3", "export let a = 1", &options);
+    test_options("export let c = x(), a = 1, b = y()", "x(); export let a = 1; y()", &options);
+    test_options("export let { a, b } = x", "export let { a, b } = x", &options);
+    test_options("export let { b, c } = x", "let { b, c } = x", &options);
+    // Declarations used by the module are kept.
+    test_options(
+        "let c = {}; export function b() { return c } export function a() { return b }",
+        "let c = {}; function b() { return c } export function a() { return b }",
+        &options,
+    );
+    test_options(
+        "let c = 1; export function b() { return c } export let a = b()",
+        "function b() { return 1 } export let a = b()",
+        &options,
+    );
+    // Side effects are kept.
+    test_options("export let b = x()", "x()", &options);
+}
+
+#[test]
+fn specifiers() {
+    let options = options(&["a", "default"]);
+    test_options("let a = 1, b = 2; export { a, b }", "let a = 1; export { a }", &options);
+    test_options(
+        "let a = 1, b = 2; export { b as a, a as b }",
+        "let b = 2; export { b as a }",
+        &options,
+    );
+    test_options(
+        "function b() {} export { b as default }",
+        "function b() {} export { b as default }",
+        &options,
+    );
+    test_options("function b() {} export { b as c }", "", &options);
+    test_options("export { a, b } from 'mod'", "export { a } from 'mod'", &options);
+    test_options("export { b, c } from 'mod'", "import 'mod'", &options);
+    test_options("export * as b from 'mod'", "import 'mod'", &options);
+    test_same_options("export * as a from 'mod'", &options);
+    test_same_options("export * from 'mod'", &options);
+    test_same_options("export {}", &options);
+}
+
+#[test]
+fn default_export() {
+    let options = options(&["a"]);
+    test_options("export default function () {} export let a = 1", "export let a = 1", &options);
+    test_options("export default function f() {} g(f)", "function f() {} g(f)", &options);
+    test_options("export default class {}", "", &options);
+    test_options("export default class { static { x() } }", "(class { static { x() } })", &options);
+    test_options("export default 1 + 2", "", &options);
+    test_options("export default x()", "x()", &options);
+    test_same_options("export default x()", &self::options(&["default"]));
+}
@@ -700,6 +700,24 @@ pub fn v8_intrinsic_spread_elem(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn pipe_topic_unused(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Pipe body must contain a topic reference `%`")
+        .with_help("Use `%` to refer to the value of the left-hand side of `|>`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn pipe_topic_outside_pipe_body(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Topic reference `%` is only allowed inside a pipe body").with_label(span)
+}
+
+#[cold]
+pub fn pipe_unparenthesized_body(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{kind} must be parenthesized when used as a pipe body"))
+        .with_label(span)
+}
+
 #[cold]
 pub fn a_set_accessor_cannot_have_a_return_type_annotation(span: Span) -> OxcDiagnostic {
     ts_error("1095", "A 'set' accessor cannot have a return type annotation.").with_label(span)
@@ -220,9 +220,15 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             Kind::NoSubstitutionTemplate | Kind::TemplateHead => {
                 self.parse_template_literal_expression(false)
             }
-            Kind::Percent if self.options.allow_v8_intrinsics => {
+            Kind::Percent
+                if self.options.allow_v8_intrinsics
+                    && (!self.options.allow_pipeline_operator
+                        || self.lexer.peek_token().kind().is_identifier_name()) =>
+            {
                 self.parse_v8_intrinsic_expression()
             }
+            Kind::Percent if self.options.allow_pipeline_operator => self.parse_topic_reference(),
+            Kind::Do if self.options.allow_do_expressions => self.parse_do_expression(),
             Kind::New => self.parse_new_expression(),
             Kind::Super => self.parse_super(),
             Kind::Import => self.parse_import_meta_or_call(),
@@ -705,6 +711,68 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         self.ast.expression_v_8_intrinsic(self.end_span(span), name, arguments)
     }
 
+    /// Topic reference `%` of a Hack-style pipe body.
+    fn parse_topic_reference(&mut self) -> Expression<'a> {
+        let span = self.cur_token().span();
+        self.bump_any();
+        match &mut self.state.pipe_topic {
+            Some(used) => *used = true,
+            None => self.error(diagnostics::pipe_topic_outside_pipe_body(span)),
+        }
+        self.ast.expression_topic_reference(span)
+    }
+
+    /// `do` expression
+    /// `DoExpression` :
+    ///     do `Block`
+    fn parse_do_expression(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any(); // `do`
+        let body = self.parse_block();
+        self.ast.expression_do(self.end_span(span), body)
+    }
+
+    /// `throw` expression
+    /// `ThrowExpression` :
+    ///     throw `UnaryExpression`
+    fn parse_throw_expression(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        self.bump_any(); // `throw`
+        let argument = self.parse_simple_unary_expression(self.start_span());
+        self.ast.expression_throw(self.end_span(span), argument)
+    }
+
+    /// Hack-style pipe
+    /// `PipeExpression` :
+    ///     `ShortCircuitExpression` |> `PipeBody`
+    fn parse_pipeline_expression(&mut self, lhs_span: u32, left: Expression<'a>) -> Expression<'a> {
+        self.bump_any(); // `|>`
+        let outer_topic = self.state.pipe_topic.replace(false);
+        let body_span = self.start_span();
+        let body = self.parse_assignment_expression_or_higher();
+        let topic_used = self.state.pipe_topic == Some(true);
+        self.state.pipe_topic = outer_topic;
+
+        // Without `preserve_parens`, a parenthesized body starts after its `(`.
+        let parenthesized = body.span().start != body_span;
+        if !parenthesized {
+            let kind = match body {
+                Expression::ArrowFunctionExpression(_) => Some("Arrow function"),
+                Expression::AssignmentExpression(_) => Some("Assignment expression"),
+                Expression::ConditionalExpression(_) => Some("Conditional expression"),
+                Expression::YieldExpression(_) => Some("Yield expression"),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                self.error(diagnostics::pipe_unparenthesized_body(kind, body.span()));
+            }
+        }
+        if !topic_used {
+            self.error(diagnostics::pipe_topic_unused(body.span()));
+        }
+        self.ast.expression_pipeline(self.end_span(lhs_span), left, body)
+    }
+
     fn parse_v8_intrinsic_argument(&mut self) -> Argument<'a> {
         if self.at(Kind::Dot3) {
             self.error(diagnostics::v8_intrinsic_spread_elem(self.cur_token().span()));
@@ -1150,6 +1218,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
     pub(crate) fn parse_simple_unary_expression(&mut self, lhs_span: u32) -> Expression<'a> {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => self.parse_unary_expression(),
+            Kind::Throw if self.options.allow_throw_expressions => self.parse_throw_expression(),
             Kind::LAngle => {
                 if self.source_type.is_jsx() {
                     return self.parse_jsx_expression();
@@ -1413,8 +1482,11 @@ impl<'a, C: Config> ParserImpl<'a, C> {
             );
         }
 
-        let mut expr =
-            self.parse_conditional_expression_rest(span, lhs, allow_return_type_in_arrow_function);
+        let mut expr = if kind == Kind::PipeGreater && self.options.allow_pipeline_operator {
+            self.parse_pipeline_expression(span, lhs)
+        } else {
+            self.parse_conditional_expression_rest(span, lhs, allow_return_type_in_arrow_function)
+        };
 
         if let Some(index) = pure_comment_index
             && !Self::set_pure_on_call_or_new_expr(&mut expr)
@@ -1638,6 +1710,7 @@ impl<'a, C: Config> ParserImpl<'a, C> {
         match self.cur_kind() {
             kind if kind.is_unary_operator() => false,
             Kind::Await => false,
+            Kind::Throw => !self.options.allow_throw_expressions,
             Kind::LAngle => {
                 if !self.source_type.is_jsx() {
                     return false;
@@ -479,6 +479,10 @@ ascii_byte_handler!(PIP(lexer) {
             lexer.consume_char();
             Kind::PipeEq
         }
+        Some(b'>') => {
+            lexer.consume_char();
+            Kind::PipeGreater
+        }
         _ => Kind::Pipe
     }
 });
@@ -227,6 +227,23 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Allow the Hack-style [pipeline operator](https://github.com/tc39/proposal-pipeline-operator)
+    /// (`a |> f(%)`) and its `%` topic reference.
+    ///
+    /// Default: `false`
+    pub allow_pipeline_operator: bool,
+
+    /// Allow [`do` expressions](https://github.com/tc39/proposal-do-expressions) (`do { ... }`).
+    ///
+    /// Default: `false`
+    pub allow_do_expressions: bool,
+
+    /// Allow [`throw` expressions](https://github.com/tc39/proposal-throw-expressions)
+    /// (`x ?? throw new Error()`).
+    ///
+    /// Default: `false`
+    pub allow_throw_expressions: bool,
 }
 
 impl Default for ParseOptions {
@@ -237,6 +254,9 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            allow_pipeline_operator: false,
+            allow_do_expressions: false,
+            allow_throw_expressions: false,
         }
     }
 }
@@ -808,6 +828,54 @@ mod test {
         }
     }
 
+    #[test]
+    fn proposals() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions {
+            allow_pipeline_operator: true,
+            allow_do_expressions: true,
+            allow_throw_expressions: true,
+            ..ParseOptions::default()
+        };
+        let pass = [
+            "x |> f(%)",
+            "x |> f(%) |> g(%, %)",
+            "x |> (% ? a : b)",
+            "x |> [%].map(y => y + %)",
+            "let a = do { if (x) { 1 } else { 2 } }",
+            "f(x ?? throw new Error('required'))",
+            "const g = () => throw 0",
+        ];
+        for source in pass {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+        let fail = [
+            ("x |> f()", "Pipe body must contain a topic reference `%`"),
+            ("f(%)", "Topic reference `%` is only allowed inside a pipe body"),
+            (
+                "x |> % ? a : b",
+                "Conditional expression must be parenthesized when used as a pipe body",
+            ),
+            ("x |> y => y + %", "Arrow function must be parenthesized when used as a pipe body"),
+        ];
+        for (source, message) in fail {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), message, "{source}");
+        }
+        {
+            // V8 intrinsics still take precedence for `%Name(...)`.
+            let source = "x |> %DebugPrint(%)";
+            let opts = ParseOptions { allow_v8_intrinsics: true, ..opts };
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        }
+    }
+
     #[test]
     fn comments() {
         let allocator = Allocator::default();
@@ -31,6 +31,10 @@ pub struct ParserState<'a> {
     /// Used to determine if a statement needs to be stored for potential reparsing
     /// in unambiguous mode.
     pub encountered_await_identifier: bool,
+
+    /// Topic reference state for the innermost Hack-style pipe body being parsed.
+    /// `None` outside of a pipe body, otherwise whether `%` has been used.
+    pub pipe_topic: Option<bool>,
 }
 
 impl ParserState<'_> {
@@ -41,6 +45,7 @@ impl ParserState<'_> {
             trailing_commas: FxHashMap::default(),
             potential_await_reparse: Vec::new(),
             encountered_await_identifier: false,
+            pipe_topic: None,
         }
     }
 }
@@ -141,6 +141,7 @@ fn default_as_module_name() -> Cow<'static, str> {
 
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[expect(clippy::enum_variant_names)]
 pub enum Helper {
     /// Runtime helper `awaitAsyncGenerator`.
     AwaitAsyncGenerator,
@@ -196,10 +197,52 @@ pub enum Helper {
     DecorateParam,
     /// Runtime helper `decorateMetadata`.
     DecorateMetadata,
+    /// Runtime helper `applyDecs2305`.
+    ApplyDecs2305,
     /// Runtime helper `usingCtx`.
     UsingCtx,
     /// Runtime helper `taggedTemplateLiteral`.
     TaggedTemplateLiteral,
+    /// Runtime helper `classCallCheck`.
+    ClassCallCheck,
+    /// Runtime helper `createClass`.
+    CreateClass,
+    /// Runtime helper `inherits`.
+    Inherits,
+    /// Runtime helper `inheritsLoose`.
+    InheritsLoose,
+    /// Runtime helper `callSuper`.
+    CallSuper,
+    /// Runtime helper `possibleConstructorReturn`.
+    PossibleConstructorReturn,
+    /// Runtime helper `assertThisInitialized`.
+    AssertThisInitialized,
+    /// Runtime helper `slicedToArray`.
+    SlicedToArray,
+    /// Runtime helper `toArray`.
+    ToArray,
+    /// Runtime helper `regeneratorRuntime`.
+    RegeneratorRuntime,
+    /// Runtime helper `interopRequireDefault`.
+    InteropRequireDefault,
+    /// Runtime helper `interopRequireWildcard`.
+    InteropRequireWildcard,
+    /// Runtime helper `taggedTemplateLiteralLoose`.
+    TaggedTemplateLiteralLoose,
+    /// Runtime helper `arrayLikeToArray`.
+    ArrayLikeToArray,
+    /// Runtime helper `toConsumableArray`.
+    ToConsumableArray,
+    /// Runtime helper `maybeArrayLike`.
+    MaybeArrayLike,
+    /// Runtime helper `construct`.
+    Construct,
+    /// Runtime helper `createForOfIteratorHelper`.
+    CreateForOfIteratorHelper,
+    /// Runtime helper `createForOfIteratorHelperLoose`.
+    CreateForOfIteratorHelperLoose,
+    /// Runtime helper `wrapRegExp`.
+    WrapRegExp,
 }
 
 impl Helper {
@@ -233,8 +276,29 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2305 => "applyDecs2305",
             Self::UsingCtx => "usingCtx",
             Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::InheritsLoose => "inheritsLoose",
+            Self::CallSuper => "callSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::AssertThisInitialized => "assertThisInitialized",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::ArrayLikeToArray => "arrayLikeToArray",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Construct => "construct",
+            Self::CreateForOfIteratorHelper => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorHelperLoose => "createForOfIteratorHelperLoose",
+            Self::WrapRegExp => "wrapRegExp",
         }
     }
 
@@ -10,7 +10,6 @@ use serde::Deserialize;
 pub struct CompilerAssumptions {
     /// Assume array-like values are iterable.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub array_like_is_iterable: bool,
 
     /// Assume re-exported bindings are constant.
@@ -20,7 +19,6 @@ pub struct CompilerAssumptions {
 
     /// Assume `super` property writes do not require runtime checks.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub constant_super: bool,
 
     /// Treat `import.meta` properties as enumerable.
@@ -34,22 +32,18 @@ pub struct CompilerAssumptions {
 
     /// Ignore the preferred hint passed to `Symbol.toPrimitive`.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub ignore_to_primitive_hint: bool,
 
     /// Assume iterable operations only receive arrays.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     /// Emit mutable template objects.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub mutable_template_object: bool,
 
     /// Assume class constructors are never called without `new`.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub no_class_calls: bool,
 
     /// Assume `document.all` is not special-cased.
@@ -90,7 +84,6 @@ pub struct CompilerAssumptions {
 
     /// Assume class methods can be assigned directly.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_class_methods: bool,
 
     /// Assume computed properties can be assigned directly.
@@ -147,11 +140,9 @@ pub struct CompilerAssumptions {
 
     /// Skip `for..of` iterator closing logic.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub skip_for_of_iterator_closing: bool,
 
     /// Assume `super` can be invoked as a normal callable constructor.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub super_is_callable_constructor: bool,
 }
@@ -458,7 +458,7 @@ impl<'a> LegacyDecorator<'a> {
     ///
     /// Instance: `get <key>() { return this.#<storage_name>; }`
     /// Static:   `get <key>() { return ClassName.#<storage_name>; }`
-    fn create_accessor_method(
+    pub(super) fn create_accessor_method(
         decorators: ArenaVec<'a, Decorator<'a>>,
         key: PropertyKey<'a>,
         kind: MethodDefinitionKind,
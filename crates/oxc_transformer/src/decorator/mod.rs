@@ -1,6 +1,8 @@
 mod legacy;
 mod options;
+mod standard;
 
+use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
@@ -8,17 +10,28 @@ use crate::{context::TraverseCtx, state::TransformState};
 
 use legacy::LegacyDecorator;
 pub use options::DecoratorOptions;
+use standard::StandardDecorator;
 
 pub struct Decorator<'a> {
     options: DecoratorOptions,
 
     // Plugins
-    legacy_decorator: LegacyDecorator<'a>,
+    legacy: LegacyDecorator<'a>,
+    standard: StandardDecorator,
 }
 
 impl Decorator<'_> {
     pub fn new(options: DecoratorOptions) -> Self {
-        Self { legacy_decorator: LegacyDecorator::new(options.emit_decorator_metadata), options }
+        Self {
+            legacy: LegacyDecorator::new(options.emit_decorator_metadata),
+            standard: StandardDecorator::new(),
+            options,
+        }
+    }
+
+    /// Legacy decorators take precedence if both versions are enabled.
+    fn is_standard(&self) -> bool {
+        self.options.standard && !self.options.legacy
     }
 }
 
@@ -30,35 +43,53 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut oxc_traverse::TraverseCtx<'a, TransformState<'a>>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_program(node, ctx);
+            self.legacy.exit_program(node, ctx);
+        }
+    }
+
+    #[inline]
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.is_standard() {
+            self.standard.enter_statements(stmts, ctx);
+        }
+    }
+
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.is_standard() {
+            self.standard.enter_expression(expr, ctx);
         }
     }
 
     #[inline]
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_statement(stmt, ctx);
+            self.legacy.enter_statement(stmt, ctx);
         }
     }
 
     #[inline]
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_statement(stmt, ctx);
+            self.legacy.exit_statement(stmt, ctx);
         }
     }
 
     #[inline]
     fn enter_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_class(node, ctx);
+            self.legacy.enter_class(node, ctx);
         }
     }
 
     #[inline]
     fn exit_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class(node, ctx);
+            self.legacy.exit_class(node, ctx);
         }
     }
 
@@ -69,7 +100,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_method_definition(node, ctx);
+            self.legacy.enter_method_definition(node, ctx);
         }
     }
 
@@ -80,7 +111,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_method_definition(node, ctx);
+            self.legacy.exit_method_definition(node, ctx);
         }
     }
 
@@ -91,7 +122,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_accessor_property(node, ctx);
+            self.legacy.enter_accessor_property(node, ctx);
         }
     }
 
@@ -102,7 +133,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_accessor_property(node, ctx);
+            self.legacy.exit_accessor_property(node, ctx);
         }
     }
 
@@ -113,7 +144,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_property_definition(node, ctx);
+            self.legacy.enter_property_definition(node, ctx);
         }
     }
 
@@ -124,7 +155,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.exit_property_definition(node, ctx);
+            self.legacy.exit_property_definition(node, ctx);
         }
     }
 
@@ -135,7 +166,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_decorator(node, ctx);
+            self.legacy.enter_decorator(node, ctx);
         }
     }
 }
@@ -144,7 +175,7 @@ impl<'a> Decorator<'a> {
     #[inline]
     pub fn exit_class_at_end(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class_at_end(class, ctx);
+            self.legacy.exit_class_at_end(class, ctx);
         }
     }
 }
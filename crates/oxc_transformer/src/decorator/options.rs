@@ -13,6 +13,15 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables the TC39 Stage 3 decorators transform, using the 2023-05 version of the proposal.
+    ///
+    /// This is the decorators implementation TypeScript 5 uses when `experimentalDecorators` is not set.
+    /// It has no effect if `legacy` is true.
+    ///
+    /// <https://github.com/tc39/proposal-decorators>
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option is the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -0,0 +1,1368 @@
+//! Decorators (2023-05)
+//!
+//! This plugin transforms TC39 Stage 3 decorators, as specified by the 2023-05 version of
+//! the proposal, which is also what TypeScript 5 emits when `experimentalDecorators` is off.
+//! Decorators are applied by calling the `_applyDecs2305` helper from a static block inserted
+//! at the start of the class body.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class C {
+//!   @dec method() {}
+//!   @dec prop = 1;
+//!   @dec accessor value = 2;
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let _initProto, _init_prop, _init_value, _C, _initClass;
+//! class C {
+//!   static {
+//!     ({
+//!       e: [_init_value, _init_prop, _initProto],
+//!       c: [_C, _initClass]
+//!     } = _applyDecs2305(this, [[dec, 2, "method"], [dec, 1, "value"], [dec, 0, "prop"]], [dec]));
+//!   }
+//!   method() {}
+//!   prop = (_initProto(this), _init_prop(this, 1));
+//!   #A = _init_value(this, 2);
+//!   get value() { return this.#A; }
+//!   set value(value) { this.#A = value; }
+//!   static {
+//!     _initClass();
+//!   }
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! The transform runs when entering a class, before `class-properties` inspects the class body,
+//! so the static blocks, private fields and field initializers it creates are lowered afterwards
+//! by the ES2022 plugins when they are enabled.
+//!
+//! * Decorator expressions and computed keys are evaluated once, in source order, before the class.
+//!   They are hoisted into `let` temp vars unless they are literals or references to bindings which
+//!   are never reassigned.
+//! * Decorators of the form `@a.b` are called with `a` as `this` (the "decorators have this" flag).
+//! * `accessor` properties are lowered to a private storage field plus a getter and setter pair.
+//! * Decorated private methods, getters and setters are moved into the helper call, and replaced by
+//!   private accessors that forward to the decorated functions returned by the helper.
+//! * Instance `addInitializer` callbacks run via `_initProto(this)`, which is prepended to the first
+//!   instance field initializer, or called from the constructor if the class has no instance fields.
+//! * Static `addInitializer` callbacks run via `_initStatic(this)`, right after decorators are applied.
+//! * When the class itself is decorated, all references to the class binding are redirected to a new
+//!   `_C` binding, which holds the class returned by the class decorators. Class initializers run in a
+//!   static block appended to the end of the class body.
+//! * `Symbol.metadata` is set up by the helper. For derived classes, the super class is passed to the
+//!   helper so the metadata object inherits from the parent's.
+//!
+//! ## Missing features
+//!
+//! * Static fields of a class with class decorators are defined on the original class, rather than on
+//!   the class returned by the decorators.
+//! * Decorated private methods which reference `super` are not supported, because the method body is
+//!   moved into a function expression.
+//! * Element decorators and computed keys are evaluated before the `extends` clause of the class,
+//!   rather than after it.
+//! * Anonymous classes with class decorators don't get an inferred `name`
+//!   (e.g. `const C = @dec class {}`, or `export default @dec class {}`).
+//!
+//! ## References
+//!
+//! * Proposal: <https://github.com/tc39/proposal-decorators>
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-helper-create-class-features-plugin/src/decorators.ts>
+
+use oxc_allocator::{CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+use oxc_syntax::reference::ReferenceId;
+use oxc_traverse::{BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    Helper,
+    common::{
+        computed_key::key_needs_temp_var, duplicate::duplicate_expression,
+        helper_loader::helper_call_expr,
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_class_constructor, create_class_method},
+};
+
+use super::legacy::LegacyDecorator;
+
+/// Kind of a decorated class element, as expected by the `applyDecs2305` helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// A decorator expression, and the `this` value it should be called with.
+type DecoratorPair<'a> = (Option<Expression<'a>>, Expression<'a>);
+
+/// A decorated class element.
+struct DecoratedElement<'a> {
+    kind: ElementKind,
+    is_static: bool,
+    decorators: Vec<DecoratorPair<'a>>,
+    /// Property name passed to the helper. For private elements, the name without `#`.
+    name: Expression<'a>,
+    /// Name of the private element, if the element is private.
+    private_name: Option<Ident<'a>>,
+    /// Extra arguments for private elements: the original method, or getter and setter functions
+    /// accessing the private storage.
+    private_functions: Vec<Expression<'a>>,
+    /// Bindings which the helper's returned initializers and private accessors are assigned to.
+    bindings: Vec<BoundIdentifier<'a>>,
+}
+
+/// Result of transforming a class.
+struct TransformedClass<'a> {
+    /// Expressions to evaluate before the class.
+    hoisted: Vec<Expression<'a>>,
+    /// Binding which holds the class returned by class decorators.
+    class_binding: Option<BoundIdentifier<'a>>,
+}
+
+pub struct StandardDecorator;
+
+impl StandardDecorator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator {
+    /// Transform class declarations in `stmts`.
+    ///
+    /// Class declarations are transformed here rather than in `enter_class`, so that the hoisted
+    /// expressions can be inserted before the class as statements, and still be visited by other
+    /// transforms.
+    fn enter_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !stmts.iter().any(|stmt| Self::statement_class(stmt).is_some_and(Self::needs_transform))
+        {
+            return;
+        }
+
+        let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 2);
+        let mut renamed_references = FxHashSet::default();
+        for mut stmt in stmts.take_in(ctx.ast) {
+            let Some(class) = Self::statement_class_mut(&mut stmt) else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            if !Self::needs_transform(class) {
+                new_stmts.push(stmt);
+                continue;
+            }
+
+            // `export default @dec class {}` has no binding to redirect, so it's transformed as
+            // an expression: `export default (class { ... }, _default)`
+            if class.id.is_none() && !class.decorators.is_empty() {
+                let Statement::ExportDefaultDeclaration(export) = &mut stmt else { unreachable!() };
+                let ExportDefaultDeclarationKind::ClassDeclaration(mut class) =
+                    export.declaration.take_in(ctx.ast)
+                else {
+                    unreachable!()
+                };
+                class.r#type = ClassType::ClassExpression;
+                let mut expr = Expression::ClassExpression(class);
+                Self::transform_class_expression(&mut expr, ctx);
+                export.declaration = ExportDefaultDeclarationKind::from(expr);
+                new_stmts.push(stmt);
+                continue;
+            }
+
+            let TransformedClass { hoisted, class_binding } = Self::transform_class(class, ctx);
+            new_stmts
+                .extend(hoisted.into_iter().map(|expr| ctx.ast.statement_expression(SPAN, expr)));
+
+            let Some(class_binding) = class_binding else {
+                new_stmts.push(stmt);
+                continue;
+            };
+
+            let class_id = class.id.as_ref().unwrap();
+            let (class_symbol_id, class_name) = (class_id.symbol_id(), class_id.name);
+            renamed_references.extend(Self::redirect_references(
+                class_symbol_id,
+                &class_binding,
+                ctx,
+            ));
+
+            // `export class C {}` -> `class C {}; export { _C as C };`
+            // `export default class C {}` -> `class C {}; export { _C as default };`
+            let exported_name = match &stmt {
+                Statement::ExportNamedDeclaration(_) => Some(class_name),
+                Statement::ExportDefaultDeclaration(_) => Some(Ident::from("default")),
+                _ => None,
+            };
+            match stmt {
+                Statement::ClassDeclaration(_) => new_stmts.push(stmt),
+                Statement::ExportNamedDeclaration(mut export) => {
+                    let Some(Declaration::ClassDeclaration(class)) = export.declaration.take()
+                    else {
+                        unreachable!()
+                    };
+                    new_stmts.push(Statement::ClassDeclaration(class));
+                }
+                Statement::ExportDefaultDeclaration(export) => {
+                    let ExportDefaultDeclarationKind::ClassDeclaration(class) =
+                        export.unbox().declaration
+                    else {
+                        unreachable!()
+                    };
+                    new_stmts.push(Statement::ClassDeclaration(class));
+                }
+                _ => unreachable!(),
+            }
+            if let Some(exported_name) = exported_name {
+                new_stmts.push(Self::create_export_specifier(&class_binding, exported_name, ctx));
+            }
+        }
+
+        if !renamed_references.is_empty() {
+            ReferenceRenamer::new(&renamed_references, ctx).visit_statements(&mut new_stmts);
+        }
+
+        *stmts = new_stmts;
+    }
+
+    /// Transform class expressions.
+    ///
+    /// `(@dec class C {})` -> `(class C { ... }, _C)`
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::ClassExpression(class) = expr
+            && Self::needs_transform(class)
+        {
+            Self::transform_class_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> StandardDecorator {
+    fn statement_class<'s>(stmt: &'s Statement<'a>) -> Option<&'s Class<'a>> {
+        match stmt {
+            Statement::ClassDeclaration(class) => Some(class),
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn statement_class_mut<'s>(stmt: &'s mut Statement<'a>) -> Option<&'s mut Class<'a>> {
+        match stmt {
+            Statement::ClassDeclaration(class) => Some(class),
+            Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &mut export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Check if class has any decorators or `accessor` properties.
+    fn needs_transform(class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(accessor) => !accessor.r#type.is_abstract(),
+                _ => false,
+            })
+    }
+
+    fn transform_class_expression(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        let TransformedClass { hoisted, class_binding } = Self::transform_class(class, ctx);
+
+        if let Some(class_binding) = &class_binding
+            && let Some(id) = &class.id
+        {
+            let references =
+                Self::redirect_references(id.symbol_id(), class_binding, ctx).into_iter().collect();
+            ReferenceRenamer::new(&references, ctx).visit_class(class);
+        }
+
+        if hoisted.is_empty() && class_binding.is_none() {
+            return;
+        }
+
+        let mut exprs = ctx.ast.vec_with_capacity(hoisted.len() + 2);
+        exprs.extend(hoisted);
+        exprs.push(expr.take_in(ctx.ast));
+        if let Some(class_binding) = class_binding {
+            exprs.push(class_binding.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(SPAN, exprs);
+    }
+
+    fn transform_class(class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) -> TransformedClass<'a> {
+        let class_scope_id = class.scope_id();
+        let mut hoisted = vec![];
+
+        // Class decorators are already outside class scope
+        let class_decorators = class
+            .decorators
+            .take_in(ctx.ast)
+            .into_iter()
+            .map(|decorator| Self::memoize_decorator(decorator.expression, &mut hoisted, ctx))
+            .collect::<Vec<_>>();
+
+        let has_element_decorators = class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+            ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+            ClassElement::AccessorProperty(accessor) => !accessor.decorators.is_empty(),
+            _ => false,
+        });
+        let is_decorated = has_element_decorators || !class_decorators.is_empty();
+        let static_block_scope_id = is_decorated
+            .then(|| ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock));
+
+        let mut private_names = class
+            .body
+            .body
+            .iter()
+            .filter_map(|element| element.property_key()?.private_name())
+            .collect::<FxHashSet<_>>();
+
+        let mut elements = vec![];
+        let mut body = ctx.ast.vec_with_capacity(class.body.body.len() + 2);
+        for element in class.body.body.take_in(ctx.ast) {
+            match element {
+                ClassElement::MethodDefinition(mut method)
+                    if !method.decorators.is_empty() && method.value.body.is_some() =>
+                {
+                    Self::memoize_computed_key(&mut method.key, class_scope_id, &mut hoisted, ctx);
+                    let decorators = Self::memoize_element_decorators(
+                        &mut method.decorators,
+                        class_scope_id,
+                        &mut hoisted,
+                        ctx,
+                    );
+                    let kind = match method.kind {
+                        MethodDefinitionKind::Method => ElementKind::Method,
+                        MethodDefinitionKind::Get => ElementKind::Getter,
+                        MethodDefinitionKind::Set => ElementKind::Setter,
+                        MethodDefinitionKind::Constructor => unreachable!(),
+                    };
+                    let name = Self::create_name(&method.key, ctx);
+                    let is_static = method.r#static;
+                    let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+                        elements.push(DecoratedElement {
+                            kind,
+                            is_static,
+                            decorators,
+                            name,
+                            private_name: None,
+                            private_functions: vec![],
+                            bindings: vec![],
+                        });
+                        body.push(ClassElement::MethodDefinition(method));
+                        continue;
+                    };
+
+                    // `@dec #method() {}` -> `get #method() { return _call_method; }`
+                    // and the original method is passed to the helper.
+                    let private_name = ident.name;
+                    let binding = Self::create_temp_binding(&format!("call_{private_name}"), ctx);
+                    let mut function = method.unbox().value;
+                    let function_scope_id = function.scope_id();
+                    ctx.scoping_mut().set_scope_parent_id(function_scope_id, static_block_scope_id);
+                    *ctx.scoping_mut().scope_flags_mut(function_scope_id) -=
+                        ScopeFlags::GetAccessor | ScopeFlags::SetAccessor;
+                    function.r#type = FunctionType::FunctionExpression;
+
+                    body.push(Self::create_forwarding_accessor(
+                        kind,
+                        private_name,
+                        is_static,
+                        &binding,
+                        class_scope_id,
+                        ctx,
+                    ));
+                    elements.push(DecoratedElement {
+                        kind,
+                        is_static,
+                        decorators,
+                        name,
+                        private_name: Some(private_name),
+                        private_functions: vec![Expression::FunctionExpression(function)],
+                        bindings: vec![binding],
+                    });
+                }
+                ClassElement::PropertyDefinition(mut prop)
+                    if !prop.decorators.is_empty() && !prop.declare =>
+                {
+                    Self::memoize_computed_key(&mut prop.key, class_scope_id, &mut hoisted, ctx);
+                    let decorators = Self::memoize_element_decorators(
+                        &mut prop.decorators,
+                        class_scope_id,
+                        &mut hoisted,
+                        ctx,
+                    );
+                    let name = Self::create_name(&prop.key, ctx);
+                    let init_binding = Self::create_init_binding(&prop.key, ctx);
+                    prop.value =
+                        Some(Self::create_init_call(&init_binding, prop.value.take(), ctx));
+
+                    let private_name = prop.key.private_name();
+                    let private_functions = private_name.map_or_else(Vec::new, |name| {
+                        Self::create_private_storage_functions(name, static_block_scope_id, ctx)
+                    });
+                    elements.push(DecoratedElement {
+                        kind: ElementKind::Field,
+                        is_static: prop.r#static,
+                        decorators,
+                        name,
+                        private_name,
+                        private_functions,
+                        bindings: vec![init_binding],
+                    });
+                    body.push(ClassElement::PropertyDefinition(prop));
+                }
+                ClassElement::AccessorProperty(mut accessor) if !accessor.r#type.is_abstract() => {
+                    let is_static = accessor.r#static;
+                    let storage_name = Self::create_storage_name(&mut private_names, ctx);
+                    let decorated = !accessor.decorators.is_empty();
+
+                    // The key is needed for both the getter and setter
+                    let (getter_key, setter_key) = if is_decorated {
+                        Self::memoize_computed_key(
+                            &mut accessor.key,
+                            class_scope_id,
+                            &mut hoisted,
+                            ctx,
+                        );
+                        let key = Self::clone_key(&accessor.key, ctx);
+                        (accessor.key.take_in(ctx.ast), key)
+                    } else if accessor.computed {
+                        let key = accessor.key.take_in(ctx.ast).into_expression();
+                        let (assignment, reference) = duplicate_expression(key, true, ctx);
+                        (PropertyKey::from(assignment), PropertyKey::from(reference))
+                    } else {
+                        let key = Self::clone_key(&accessor.key, ctx);
+                        (accessor.key.take_in(ctx.ast), key)
+                    };
+
+                    let mut value = accessor.value.take();
+                    let mut decorated_element = None;
+                    if decorated {
+                        let decorators = Self::memoize_element_decorators(
+                            &mut accessor.decorators,
+                            class_scope_id,
+                            &mut hoisted,
+                            ctx,
+                        );
+                        let name = Self::create_name(&getter_key, ctx);
+                        let init_binding = Self::create_init_binding(&getter_key, ctx);
+                        value = Some(Self::create_init_call(&init_binding, value, ctx));
+                        decorated_element = Some(DecoratedElement {
+                            kind: ElementKind::Accessor,
+                            is_static,
+                            decorators,
+                            name,
+                            private_name: getter_key.private_name(),
+                            private_functions: vec![],
+                            bindings: vec![init_binding],
+                        });
+                    }
+
+                    // `#A = value;`
+                    body.push(ctx.ast.class_element_property_definition(
+                        accessor.span,
+                        PropertyDefinitionType::PropertyDefinition,
+                        ctx.ast.vec(),
+                        ctx.ast.property_key_private_identifier(SPAN, storage_name),
+                        NONE,
+                        value,
+                        false,
+                        is_static,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                        None,
+                    ));
+
+                    if let Some(element) = &mut decorated_element
+                        && let Some(private_name) = element.private_name
+                    {
+                        // `@dec accessor #x;`
+                        // -> `get #x() { return _get_x(this); } set #x(value) { _set_x(this, value); }`
+                        // and getter and setter accessing the storage are passed to the helper.
+                        element.private_functions = Self::create_private_storage_functions(
+                            storage_name.into(),
+                            static_block_scope_id,
+                            ctx,
+                        );
+                        for (prefix, kind) in
+                            [("get", ElementKind::Getter), ("set", ElementKind::Setter)]
+                        {
+                            let binding =
+                                Self::create_temp_binding(&format!("{prefix}_{private_name}"), ctx);
+                            body.push(Self::create_forwarding_accessor(
+                                kind,
+                                private_name,
+                                is_static,
+                                &binding,
+                                class_scope_id,
+                                ctx,
+                            ));
+                            element.bindings.push(binding);
+                        }
+                    } else {
+                        // `get x() { return this.#A; } set x(value) { this.#A = value; }`
+                        let computed = accessor.computed;
+                        for (key, kind) in [
+                            (getter_key, MethodDefinitionKind::Get),
+                            (setter_key, MethodDefinitionKind::Set),
+                        ] {
+                            body.push(LegacyDecorator::create_accessor_method(
+                                ctx.ast.vec(),
+                                key,
+                                kind,
+                                computed,
+                                is_static,
+                                storage_name,
+                                None,
+                                class_scope_id,
+                                ctx,
+                            ));
+                        }
+                    }
+
+                    elements.extend(decorated_element);
+                }
+                ClassElement::MethodDefinition(mut method) if is_decorated => {
+                    Self::memoize_computed_key(&mut method.key, class_scope_id, &mut hoisted, ctx);
+                    body.push(ClassElement::MethodDefinition(method));
+                }
+                ClassElement::PropertyDefinition(mut prop) if is_decorated => {
+                    Self::memoize_computed_key(&mut prop.key, class_scope_id, &mut hoisted, ctx);
+                    body.push(ClassElement::PropertyDefinition(prop));
+                }
+                element => body.push(element),
+            }
+        }
+        class.body.body = body;
+
+        let Some(static_block_scope_id) = static_block_scope_id else {
+            return TransformedClass { hoisted, class_binding: None };
+        };
+
+        // Decorators are applied in this order: static methods and accessors, instance methods
+        // and accessors, static fields, instance fields.
+        elements.sort_by_key(|element| (element.kind == ElementKind::Field, !element.is_static));
+
+        let init_proto = elements
+            .iter()
+            .any(|element| !element.is_static)
+            .then(|| Self::create_temp_binding("initProto", ctx));
+        let init_static = elements
+            .iter()
+            .any(|element| element.is_static)
+            .then(|| Self::create_temp_binding("initStatic", ctx));
+        let class_bindings = (!class_decorators.is_empty()).then(|| {
+            let name = class.id.as_ref().map_or("class", |id| id.name.as_str());
+            (Self::create_temp_binding(name, ctx), Self::create_temp_binding("initClass", ctx))
+        });
+
+        let apply_call = Self::create_apply_decs_call(
+            class,
+            &mut elements,
+            class_decorators,
+            static_block_scope_id,
+            ctx,
+        );
+
+        // `[_init_x, _initProto] = _applyDecs2305(...).e`
+        // `[_C, _initClass] = _applyDecs2305(...).c`
+        // `({ e: [_init_x, _initProto], c: [_C, _initClass] } = _applyDecs2305(...))`
+        let element_bindings = elements
+            .iter()
+            .flat_map(|element| &element.bindings)
+            .chain(&init_proto)
+            .chain(&init_static)
+            .collect::<Vec<_>>();
+        let element_target = (!element_bindings.is_empty())
+            .then(|| Self::create_array_target(element_bindings.into_iter(), ctx));
+        let class_target = class_bindings.as_ref().map(|(class_binding, init_class)| {
+            Self::create_array_target([class_binding, init_class].into_iter(), ctx)
+        });
+        let assignment = match (element_target, class_target) {
+            (Some(target), None) => ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                Self::create_static_member(apply_call, "e", ctx),
+            ),
+            (None, Some(target)) => ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                Self::create_static_member(apply_call, "c", ctx),
+            ),
+            (Some(element_target), Some(class_target)) => {
+                let properties = ctx.ast.vec_from_iter(
+                    [("e", element_target), ("c", class_target)].into_iter().map(
+                        |(name, target)| {
+                            ctx.ast.assignment_target_property_assignment_target_property_property(
+                                SPAN,
+                                ctx.ast.property_key_static_identifier(SPAN, name),
+                                AssignmentTargetMaybeDefault::from(target),
+                                false,
+                            )
+                        },
+                    ),
+                );
+                let target = AssignmentTarget::from(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, NONE),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, apply_call)
+            }
+            (None, None) => unreachable!(),
+        };
+
+        let mut stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        // `_initStatic(this);`
+        if let Some(init_static) = &init_static {
+            let call = Self::create_init_call(init_static, None, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        class.body.body.insert(
+            0,
+            ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, static_block_scope_id),
+        );
+
+        if let Some(init_proto) = &init_proto {
+            Self::insert_init_proto(class, init_proto, ctx);
+        }
+
+        // `static { _initClass(); }`
+        let class_binding = class_bindings.map(|(class_binding, init_class)| {
+            let call = ctx.ast.expression_call(
+                SPAN,
+                init_class.create_read_expression(ctx),
+                NONE,
+                ctx.ast.vec(),
+                false,
+            );
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            class.body.body.push(ctx.ast.class_element_static_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+                scope_id,
+            ));
+            class_binding
+        });
+
+        TransformedClass { hoisted, class_binding }
+    }
+
+    /// `_applyDecs2305(this, [[dec, 0, "x"], ...], [classDec], classDecsHaveThis, instanceBrand, Parent)`
+    fn create_apply_decs_call(
+        class: &mut Class<'a>,
+        elements: &mut [DecoratedElement<'a>],
+        class_decorators: Vec<DecoratorPair<'a>>,
+        static_block_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let element_decorators = ctx.ast.vec_from_iter(elements.iter_mut().map(|element| {
+            let (decorators, has_this) = Self::create_decorators_expression(
+                std::mem::take(&mut element.decorators),
+                false,
+                ctx,
+            );
+            let mut flags = element.kind as u8;
+            if element.is_static {
+                flags |= 8;
+            }
+            if has_this {
+                flags |= 16;
+            }
+            let mut items = ctx.ast.vec_with_capacity(3 + element.private_functions.len());
+            items.push(ArrayExpressionElement::from(decorators));
+            items.push(ArrayExpressionElement::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )));
+            items.push(ArrayExpressionElement::from(element.name.take_in(ctx.ast)));
+            items.extend(element.private_functions.drain(..).map(ArrayExpressionElement::from));
+            ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, items))
+        }));
+        let (class_decorators, class_decorators_have_this) =
+            Self::create_decorators_expression(class_decorators, true, ctx);
+
+        let mut arguments = ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        arguments.push(Argument::from(ctx.ast.expression_array(SPAN, element_decorators)));
+        arguments.push(Argument::from(class_decorators));
+
+        // Brand check for instance private elements: `_ => #x in _`
+        let brand_name = elements
+            .iter()
+            .find(|element| !element.is_static && element.private_name.is_some())
+            .and_then(|element| element.private_name);
+        let parent = class.super_class.as_mut().map(|super_class| {
+            if key_needs_temp_var(super_class, ctx) {
+                // `class C extends (_Parent = Parent)`
+                let binding = ctx.generate_uid_based_on_node(
+                    super_class,
+                    ctx.current_block_scope_id(),
+                    SymbolFlags::BlockScopedVariable,
+                );
+                ctx.state.var_declarations.insert_let(&binding, None, ctx.ast);
+                let value = super_class.take_in(ctx.ast);
+                *super_class = create_assignment(&binding, value, SPAN, ctx);
+                binding.create_read_expression(ctx)
+            } else {
+                Self::clone_expression(super_class, ctx)
+            }
+        });
+
+        if class_decorators_have_this || brand_name.is_some() || parent.is_some() {
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(u8::from(class_decorators_have_this)),
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        if brand_name.is_some() || parent.is_some() {
+            let brand = match brand_name {
+                Some(name) => Self::create_brand_check(name, static_block_scope_id, ctx),
+                None => ctx.ast.void_0(SPAN),
+            };
+            arguments.push(Argument::from(brand));
+        }
+        if let Some(parent) = parent {
+            arguments.push(Argument::from(parent));
+        }
+
+        helper_call_expr(Helper::ApplyDecs2305, arguments, ctx)
+    }
+
+    /// Create the decorators argument for an element or class.
+    ///
+    /// If any decorator has a `this` value, all decorators are passed as `[this, decorator]` pairs,
+    /// and `true` is returned.
+    fn create_decorators_expression(
+        decorators: Vec<DecoratorPair<'a>>,
+        always_array: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let has_this = decorators.iter().any(|(this, _)| this.is_some());
+        if has_this {
+            let items =
+                ctx.ast.vec_from_iter(decorators.into_iter().flat_map(|(this, decorator)| {
+                    [this.unwrap_or_else(|| ctx.ast.void_0(SPAN)), decorator]
+                        .map(ArrayExpressionElement::from)
+                }));
+            return (ctx.ast.expression_array(SPAN, items), true);
+        }
+        if !always_array && decorators.len() == 1 {
+            let (_, decorator) = decorators.into_iter().next().unwrap();
+            return (decorator, false);
+        }
+        let items = ctx.ast.vec_from_iter(
+            decorators.into_iter().map(|(_, decorator)| ArrayExpressionElement::from(decorator)),
+        );
+        (ctx.ast.expression_array(SPAN, items), false)
+    }
+
+    /// Run instance initializers before any instance field is initialized.
+    ///
+    /// * `x = 1;` -> `x = (_initProto(this), 1);`
+    /// * `constructor() {}` -> `constructor() { _initProto(this); }`
+    /// * `constructor() { super(); }` -> `constructor() { _initProto(super()); }`
+    fn insert_init_proto(
+        class: &mut Class<'a>,
+        init_proto: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let first_field = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::PropertyDefinition(prop)
+                if !prop.r#static && !prop.declare && !prop.r#type.is_abstract() =>
+            {
+                Some(prop)
+            }
+            _ => None,
+        });
+        if let Some(prop) = first_field {
+            let init = Self::create_init_call(init_proto, None, ctx);
+            let value = prop.value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            prop.value =
+                Some(ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_array([init, value])));
+            return;
+        }
+
+        let has_super_class = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+        match constructor {
+            Some(body) if has_super_class => {
+                SuperCallReplacer { init_proto, ctx }.visit_function_body(body);
+            }
+            Some(body) => {
+                let init = Self::create_init_call(init_proto, None, ctx);
+                body.statements.insert(0, ctx.ast.statement_expression(SPAN, init));
+            }
+            None => {
+                let scope_id = ctx.create_child_scope(
+                    class.scope_id(),
+                    ScopeFlags::Function | ScopeFlags::Constructor | ScopeFlags::StrictMode,
+                );
+                let init = Self::create_init_call(init_proto, None, ctx);
+                let constructor = create_class_constructor(
+                    [ctx.ast.statement_expression(SPAN, init)],
+                    has_super_class,
+                    scope_id,
+                    ctx,
+                );
+                // After the static block applying decorators
+                class.body.body.insert(1, constructor);
+            }
+        }
+    }
+
+    /// Hoist a decorator, and return it along with the `this` value it should be called with.
+    ///
+    /// * `@dec` -> `dec`, if `dec` is never reassigned
+    /// * `@dec()` -> `_dec = dec()`, `_dec`
+    /// * `@obj.dec` -> `_dec = obj.dec`, `[obj, _dec]`
+    fn memoize_decorator(
+        mut expression: Expression<'a>,
+        hoisted: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> DecoratorPair<'a> {
+        let object = match &mut expression {
+            Expression::StaticMemberExpression(member) => Some(&mut member.object),
+            Expression::ComputedMemberExpression(member) => Some(&mut member.object),
+            _ => None,
+        };
+        let this = object.map(|object| {
+            if key_needs_temp_var(object, ctx) {
+                let value = object.take_in(ctx.ast);
+                let binding = Self::create_temp_binding_based_on_node(&value, ctx);
+                hoisted.push(create_assignment(&binding, value, SPAN, ctx));
+                *object = binding.create_read_expression(ctx);
+                binding.create_read_expression(ctx)
+            } else {
+                Self::clone_expression(object, ctx)
+            }
+        });
+
+        if this.is_none() && !key_needs_temp_var(&expression, ctx) {
+            return (None, expression);
+        }
+        let binding = Self::create_temp_binding("dec", ctx);
+        hoisted.push(create_assignment(&binding, expression, SPAN, ctx));
+        (this, binding.create_read_expression(ctx))
+    }
+
+    /// Hoist decorators of a class element. Scopes within them are moved out of the class scope.
+    fn memoize_element_decorators(
+        decorators: &mut ArenaVec<'a, Decorator<'a>>,
+        class_scope_id: ScopeId,
+        hoisted: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<DecoratorPair<'a>> {
+        decorators
+            .take_in(ctx.ast)
+            .into_iter()
+            .map(|decorator| {
+                ChildScopeCollector::reparent(&decorator.expression, class_scope_id, ctx);
+                Self::memoize_decorator(decorator.expression, hoisted, ctx)
+            })
+            .collect()
+    }
+
+    /// Hoist computed key into a temp var if it may have side effects.
+    ///
+    /// `[foo()]() {}` -> `_computedKey = foo()`, `[_computedKey]() {}`
+    fn memoize_computed_key(
+        key: &mut PropertyKey<'a>,
+        class_scope_id: ScopeId,
+        hoisted: &mut Vec<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(expr) = key.as_expression_mut() else { return };
+        if !key_needs_temp_var(expr, ctx) {
+            return;
+        }
+        ChildScopeCollector::reparent(expr, class_scope_id, ctx);
+        let value = expr.take_in(ctx.ast);
+        let binding = Self::create_temp_binding("computedKey", ctx);
+        hoisted.push(create_assignment(&binding, value, SPAN, ctx));
+        *expr = binding.create_read_expression(ctx);
+    }
+
+    /// Create a `let` binding outside the class.
+    fn create_temp_binding(name: &str, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding =
+            ctx.generate_uid(name, ctx.current_block_scope_id(), SymbolFlags::BlockScopedVariable);
+        ctx.state.var_declarations.insert_let(&binding, None, ctx.ast);
+        binding
+    }
+
+    fn create_temp_binding_based_on_node(
+        node: &Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let binding = ctx.generate_uid_based_on_node(
+            node,
+            ctx.current_block_scope_id(),
+            SymbolFlags::BlockScopedVariable,
+        );
+        ctx.state.var_declarations.insert_let(&binding, None, ctx.ast);
+        binding
+    }
+
+    /// `_init_x`
+    fn create_init_binding(
+        key: &PropertyKey<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        Self::create_temp_binding(&format!("init_{}", get_var_name_from_node(key)), ctx)
+    }
+
+    /// `_init(this)` or `_init(this, value)`
+    fn create_init_call(
+        binding: &BoundIdentifier<'a>,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_this(SPAN)));
+        arguments.extend(value.map(Argument::from));
+        ctx.ast.expression_call(SPAN, binding.create_read_expression(ctx), NONE, arguments, false)
+    }
+
+    /// `_initProto(super())`
+    fn create_init_call_with_object(
+        binding: &BoundIdentifier<'a>,
+        object: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let arguments = ctx.ast.vec1(Argument::from(object));
+        ctx.ast.expression_call(SPAN, binding.create_read_expression(ctx), NONE, arguments, false)
+    }
+
+    /// Name of an element passed to the helper.
+    ///
+    /// * `x` / `#x` -> `"x"`
+    /// * `[_computedKey]` -> `_computedKey`
+    fn create_name(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            key => Self::clone_expression(key.to_expression(), ctx),
+        }
+    }
+
+    /// Clone a key which has no side effects.
+    fn clone_key(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> PropertyKey<'a> {
+        match key.as_expression() {
+            Some(expr) => PropertyKey::from(Self::clone_expression(expr, ctx)),
+            None => key.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// Clone an expression which has no side effects, creating a new reference for identifiers.
+    fn clone_expression(expr: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Expression::Identifier(ident) = expr {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read)
+        } else {
+            expr.clone_in(ctx.ast.allocator)
+        }
+    }
+
+    /// Create a private name for `accessor` storage which doesn't clash with other private names
+    /// in the class: `#A`, `#B`, ..., `#Z`, `#AA`, ...
+    fn create_storage_name(
+        private_names: &mut FxHashSet<Ident<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Str<'a> {
+        let mut index = 0usize;
+        loop {
+            let mut name = String::new();
+            let mut n = index;
+            loop {
+                name.insert(0, char::from(b'A' + u8::try_from(n % 26).unwrap()));
+                if n < 26 {
+                    break;
+                }
+                n = n / 26 - 1;
+            }
+            let name = ctx.ast.ident(&name);
+            if private_names.insert(name) {
+                return name.into();
+            }
+            index += 1;
+        }
+    }
+
+    /// Getter and setter for a private field, passed to the helper.
+    ///
+    /// `_ => _.#x`, `(_, value) => _.#x = value`
+    fn create_private_storage_functions(
+        name: Ident<'a>,
+        static_block_scope_id: Option<ScopeId>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Expression<'a>> {
+        let static_block_scope_id = static_block_scope_id.unwrap();
+        let flags = ScopeFlags::Function | ScopeFlags::Arrow;
+
+        let scope_id = ctx.create_child_scope(static_block_scope_id, flags);
+        let object =
+            ctx.generate_binding(Ident::from("_"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let field = Self::create_private_field(&object, name, ctx);
+        let getter = Self::create_arrow(vec![&object], Expression::from(field), scope_id, ctx);
+
+        let scope_id = ctx.create_child_scope(static_block_scope_id, flags);
+        let object =
+            ctx.generate_binding(Ident::from("_"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let value = ctx.generate_binding(
+            Ident::from("value"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let field = Self::create_private_field(&object, name, ctx);
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(SimpleAssignmentTarget::from(field)),
+            value.create_read_expression(ctx),
+        );
+        let setter = Self::create_arrow(vec![&object, &value], assignment, scope_id, ctx);
+
+        vec![getter, setter]
+    }
+
+    /// `_ => #x in _`
+    fn create_brand_check(
+        name: Ident<'a>,
+        static_block_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(static_block_scope_id, ScopeFlags::Function | ScopeFlags::Arrow);
+        let object =
+            ctx.generate_binding(Ident::from("_"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let check = ctx.ast.expression_private_in(
+            SPAN,
+            ctx.ast.private_identifier(SPAN, name),
+            object.create_read_expression(ctx),
+        );
+        Self::create_arrow(vec![&object], check, scope_id, ctx)
+    }
+
+    /// `_.#x`
+    fn create_private_field(
+        object: &BoundIdentifier<'a>,
+        name: Ident<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        ctx.ast.member_expression_private_field_expression(
+            SPAN,
+            object.create_read_expression(ctx),
+            ctx.ast.private_identifier(SPAN, name),
+            false,
+        )
+    }
+
+    /// `(a, b) => expr`
+    fn create_arrow(
+        params: Vec<&BoundIdentifier<'a>>,
+        expr: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let items = ctx.ast.vec_from_iter(params.into_iter().map(|param| {
+            ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                param.create_binding_pattern(ctx),
+                NONE,
+                NONE,
+                false,
+                None,
+                false,
+                false,
+            )
+        }));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, expr)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+        )
+    }
+
+    /// Private accessor forwarding to a function returned by the helper.
+    ///
+    /// * Method: `get #x() { return _call_x; }`
+    /// * Getter: `get #x() { return _call_x(this); }`
+    /// * Setter: `set #x(value) { _call_x(this, value); }`
+    fn create_forwarding_accessor(
+        kind: ElementKind,
+        name: Ident<'a>,
+        is_static: bool,
+        binding: &BoundIdentifier<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let is_setter = kind == ElementKind::Setter;
+        let accessor_flags =
+            if is_setter { ScopeFlags::SetAccessor } else { ScopeFlags::GetAccessor };
+        let scope_id = ctx.create_child_scope(
+            class_scope_id,
+            ScopeFlags::Function | ScopeFlags::StrictMode | accessor_flags,
+        );
+
+        let mut params = ctx.ast.vec();
+        let stmt = if is_setter {
+            let value = ctx.generate_binding(
+                Ident::from("value"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            params.push(ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                value.create_binding_pattern(ctx),
+                NONE,
+                NONE,
+                false,
+                None,
+                false,
+                false,
+            ));
+            let call =
+                Self::create_init_call(binding, Some(value.create_read_expression(ctx)), ctx);
+            ctx.ast.statement_expression(SPAN, call)
+        } else if kind == ElementKind::Method {
+            ctx.ast.statement_return(SPAN, Some(binding.create_read_expression(ctx)))
+        } else {
+            let call = Self::create_init_call(binding, None, ctx);
+            ctx.ast.statement_return(SPAN, Some(call))
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+
+        create_class_method(
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, name),
+            if is_setter { MethodDefinitionKind::Set } else { MethodDefinitionKind::Get },
+            params,
+            ctx.ast.vec1(stmt),
+            false,
+            is_static,
+            scope_id,
+            ctx,
+        )
+    }
+
+    fn create_array_target<'b>(
+        bindings: impl Iterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a>
+    where
+        'a: 'b,
+    {
+        let elements = bindings
+            .map(|binding| {
+                Some(AssignmentTargetMaybeDefault::from(binding.create_write_target(ctx)))
+            })
+            .collect::<Vec<_>>();
+        AssignmentTarget::from(ctx.ast.assignment_target_pattern_array_assignment_target(
+            SPAN,
+            ctx.ast.vec_from_iter(elements),
+            NONE,
+        ))
+    }
+
+    fn create_static_member(
+        object: Expression<'a>,
+        property: &'static str,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let property = ctx.ast.identifier_name(SPAN, Str::from(property));
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// `export { _C as C }`
+    fn create_export_specifier(
+        binding: &BoundIdentifier<'a>,
+        exported_name: Ident<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = ImportOrExportKind::Value;
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let exported = ctx.ast.module_export_name_identifier_name(SPAN, exported_name);
+        let specifiers = ctx.ast.vec1(ctx.ast.export_specifier(SPAN, local, exported, kind));
+        Statement::from(
+            ctx.ast.module_declaration_export_named_declaration(
+                SPAN, None, specifiers, None, kind, NONE,
+            ),
+        )
+    }
+
+    /// Resolve all references to `symbol_id` to `binding` instead.
+    /// Returns the IDs of the references, which still need to be renamed in AST.
+    fn redirect_references(
+        symbol_id: SymbolId,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<ReferenceId> {
+        let reference_ids = ctx.scoping().get_resolved_reference_ids(symbol_id).to_vec();
+        let scoping = ctx.scoping_mut();
+        for &reference_id in &reference_ids {
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+            scoping.get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+            scoping.add_resolved_reference(binding.symbol_id, reference_id);
+        }
+        reference_ids
+    }
+}
+
+/// Rename references which were redirected to the binding holding the decorated class.
+struct ReferenceRenamer<'a, 'r> {
+    reference_ids: &'r FxHashSet<ReferenceId>,
+    ctx: &'r TraverseCtx<'a>,
+}
+
+impl<'a, 'r> ReferenceRenamer<'a, 'r> {
+    fn new(reference_ids: &'r FxHashSet<ReferenceId>, ctx: &'r TraverseCtx<'a>) -> Self {
+        Self { reference_ids, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ReferenceRenamer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        if self.reference_ids.contains(&reference_id) {
+            let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id().unwrap();
+            ident.name = self.ctx.ast.ident(self.ctx.scoping().symbol_name(symbol_id));
+        }
+    }
+}
+
+/// Replace `super()` calls in a constructor with `_initProto(super())`.
+struct SuperCallReplacer<'a, 'b> {
+    init_proto: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for SuperCallReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if let Expression::CallExpression(call) = expr
+            && call.callee.is_super()
+        {
+            let super_call = expr.take_in(self.ctx.ast);
+            *expr = StandardDecorator::create_init_call_with_object(
+                self.init_proto,
+                super_call,
+                self.ctx,
+            );
+        }
+    }
+
+    // `super()` can't appear in nested functions or classes
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
+
+/// Moves scopes which are direct children of the class scope to the current scope,
+/// for expressions hoisted out of the class.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+    depth: u32,
+}
+
+impl ChildScopeCollector {
+    fn reparent(expr: &Expression<'_>, class_scope_id: ScopeId, ctx: &mut TraverseCtx<'_>) {
+        let mut collector = Self::default();
+        collector.visit_expression(expr);
+        let current_scope_id = ctx.current_scope_id();
+        let scoping = ctx.scoping_mut();
+        for scope_id in collector.scope_ids {
+            if scoping.scope_parent_id(scope_id) == Some(class_scope_id) {
+                scoping.set_scope_parent_id(scope_id, Some(current_scope_id));
+            }
+        }
+    }
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &std::cell::Cell<Option<ScopeId>>) {
+        if self.depth == 0
+            && let Some(scope_id) = scope_id.get()
+        {
+            self.scope_ids.push(scope_id);
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
@@ -0,0 +1,1501 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations into `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   const a = 2;
+//!   console.log(a);
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(function() { return i; });
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   console.log(_a);
+//! }
+//! var _loop = function(i) {
+//!   fns.push(function() { return i; });
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `throwIfClosureRequired`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Report an error for loops whose body would need to be moved into a closure,
+//! instead of performing the transform.
+//!
+//! ### `tdz`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Report statically detectable accesses to bindings in their temporal dead zone as errors,
+//! rather than warnings.
+//!
+//! ## Implementation
+//!
+//! When entering a block (or `for` / `switch` statement), `let` and `const` bindings declared in it
+//! are renamed if their name is also bound in an enclosing scope, or refers to a global.
+//! This allows the declarations to be converted to `var` and their bindings hoisted into the enclosing
+//! function without clashing with other bindings. References are renamed when they are visited.
+//!
+//! Declarations are converted to `var` on exit. `let x;` inside a loop becomes `var x = void 0;`,
+//! so that it is reset on each iteration.
+//!
+//! When a binding declared in a loop is referenced from a closure, each iteration needs its own copy
+//! of the binding. The loop body is moved into a function `_loop` declared before the loop, and called
+//! on each iteration. Bindings from the loop head are passed to it as arguments, and written back
+//! if the body modifies them. `break`, `continue` and `return` in the body are converted into
+//! return values of `_loop`, which are checked after calling it.
+//!
+//! References to a `let` / `const` binding which are evaluated before its declaration
+//! (excluding references inside closures, which may run later) are reported.
+//!
+//! Function declarations inside blocks are renamed like `let` bindings if they shadow an outer binding,
+//! as ES5 hoists them into the enclosing function.
+//!
+//! Assignments to a `const` binding would silently succeed once it is a `var`, so they are replaced
+//! with a call to the `readOnlyError` helper, which throws:
+//!
+//! ```js
+//! const c = 1;
+//! c = 2;
+//! c++;
+//! ```
+//! ->
+//! ```js
+//! var c = 1;
+//! 2, _readOnlyError("c");
+//! +c, _readOnlyError("c");
+//! ```
+//!
+//! Assignments to a `const` binding in a destructuring pattern or the left side of a `for-in` /
+//! `for-of` loop are reported as errors.
+//!
+//! ## Missing features
+//!
+//! * Runtime TDZ checks (Babel's `tdz` option inserts `_tdz` helper calls).
+//! * Loop bodies containing `yield`, `await`, `super` or `new.target` are not moved into a closure,
+//!   and a warning is reported.
+//! * Closures in the head of a `for` loop share a single binding between all iterations.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Let and const declarations specification: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{GetAddress, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, Scoping, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_str::Ident;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+use super::destructuring::create_variable_declaration;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 block scoping transform.
+pub struct BlockScopingOptions {
+    /// Report an error instead of moving a loop body into a closure.
+    pub throw_if_closure_required: bool,
+    /// Report temporal dead zone violations as errors instead of warnings.
+    pub tdz: bool,
+}
+
+pub struct BlockScoping<'a> {
+    options: BlockScopingOptions,
+    /// `true` if class declarations are converted to `let` declarations by the classes transform
+    classes: bool,
+    /// Bindings which have been renamed, and their new names
+    renamed: FxHashMap<SymbolId, Ident<'a>>,
+    /// Bindings converted to `var`, and the scope they were originally declared in
+    converted: FxHashMap<SymbolId, ScopeId>,
+    /// `const` bindings which have been converted to `var`
+    constants: FxHashSet<SymbolId>,
+    /// Scopes of classes. Code inside a class body does not run when the class is defined.
+    class_scopes: FxHashSet<ScopeId>,
+    /// Loops currently being visited
+    loops: Vec<LoopInfo>,
+    /// Loop which has just been exited, to be transformed in `exit_statement`
+    exited_loop: Option<LoopInfo>,
+}
+
+#[derive(Clone, Copy)]
+struct LoopInfo {
+    /// Scope which per-iteration bindings of the loop are declared in.
+    /// `None` for `while` loops whose body is not a block.
+    scope_id: Option<ScopeId>,
+    /// `var` scope containing the loop
+    hoist_scope_id: ScopeId,
+    /// `true` if a per-iteration binding is referenced from a closure
+    needs_closure: bool,
+}
+
+impl BlockScoping<'_> {
+    pub fn new(options: BlockScopingOptions, classes: bool) -> Self {
+        Self {
+            options,
+            classes,
+            renamed: FxHashMap::default(),
+            converted: FxHashMap::default(),
+            constants: FxHashSet::default(),
+            class_scopes: FxHashSet::default(),
+            loops: vec![],
+            exited_loop: None,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a> {
+    fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.class_scopes.insert(class.scope_id());
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.rename_shadowing_bindings(block.scope_id(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.rename_shadowing_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.rename_shadowing_bindings(stmt.scope_id(), ctx);
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn exit_for_statement(&mut self, _stmt: &mut ForStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.exit_loop();
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.rename_shadowing_bindings(stmt.scope_id(), ctx);
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn exit_for_in_statement(
+        &mut self,
+        _stmt: &mut ForInStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.rename_shadowing_bindings(stmt.scope_id(), ctx);
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn exit_for_of_statement(
+        &mut self,
+        _stmt: &mut ForOfStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(block_scope_id(&stmt.body), ctx);
+    }
+
+    fn exit_while_statement(&mut self, _stmt: &mut WhileStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.exit_loop();
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_loop(block_scope_id(&stmt.body), ctx);
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        _stmt: &mut DoWhileStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(symbol_id) = ident.symbol_id.get()
+            && let Some(&name) = self.renamed.get(&symbol_id)
+        {
+            ident.name = name;
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if let Some(&name) = self.renamed.get(&symbol_id) {
+            ident.name = name;
+        }
+        self.check_constant_assignment(ident, symbol_id, ctx);
+
+        let declaration_scope_id = if let Some(&scope_id) = self.converted.get(&symbol_id) {
+            scope_id
+        } else {
+            if !is_lexical_variable(ctx.scoping().symbol_flags(symbol_id)) {
+                return;
+            }
+            self.check_tdz(ident, symbol_id, ctx);
+            ctx.scoping().symbol_scope_id(symbol_id)
+        };
+
+        if !self.loops.is_empty() {
+            self.mark_captured_binding(declaration_scope_id, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.transform_constant_assignment(expr, ctx);
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            return;
+        }
+
+        // `let x;` in a loop body must be reset to `undefined` on each iteration
+        let reset_uninitialized = decl.kind == VariableDeclarationKind::Let
+            && !matches!(
+                ctx.parent(),
+                Ancestor::ForStatementInit(_)
+                    | Ancestor::ForInStatementLeft(_)
+                    | Ancestor::ForOfStatementLeft(_)
+            )
+            && self
+                .loops
+                .last()
+                .is_some_and(|info| info.hoist_scope_id == ctx.current_hoist_scope_id());
+
+        self.convert_declaration(decl, reset_uninitialized, ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(info) = self.exited_loop.take()
+            && info.needs_closure
+        {
+            self.wrap_loop_body(stmt, info.hoist_scope_id, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.convert_inserted_declarations(stmts, ctx);
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.convert_inserted_declarations(&mut program.body, ctx);
+    }
+}
+
+impl<'a> BlockScoping<'a> {
+    /// Rename `let` / `const` bindings and function declarations declared in `scope_id` which would clash with other bindings
+    /// once hoisted.
+    fn rename_shadowing_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let scoping = ctx.scoping();
+        let Some(parent_scope_id) = scoping.scope_parent_id(scope_id) else { return };
+        let clashing = scoping
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| {
+                // Class declarations become `let` declarations if classes transform is enabled
+                let flags = scoping.symbol_flags(symbol_id);
+                let is_lexical = is_lexical_variable(flags)
+                    || (self.classes && flags.contains(SymbolFlags::Class));
+                let name = scoping.symbol_ident(symbol_id);
+                if is_lexical {
+                    return scoping.find_binding(parent_scope_id, name).is_some()
+                        || scoping.root_unresolved_references().contains_key(&name);
+                }
+                // Function declarations in blocks are hoisted to the enclosing function in ES5.
+                // In sloppy mode, a block function only stays in the block scope if the name is
+                // already bound in the function scope. If that binding is a `var` or function,
+                // Annex B semantics assign the block function to it, so keep the name.
+                if !flags.contains(SymbolFlags::Function) || scoping.scope_flags(scope_id).is_var()
+                {
+                    return false;
+                }
+                let is_strict_mode = scoping.scope_flags(scope_id).is_strict_mode();
+                match scoping.find_binding(parent_scope_id, name) {
+                    Some(outer_symbol_id) => {
+                        let outer_flags = scoping.symbol_flags(outer_symbol_id);
+                        is_strict_mode
+                            || is_lexical_variable(outer_flags)
+                            || outer_flags.contains(SymbolFlags::Class)
+                            || self.converted.contains_key(&outer_symbol_id)
+                    }
+                    None => {
+                        is_strict_mode && scoping.root_unresolved_references().contains_key(&name)
+                    }
+                }
+            })
+            .map(|symbol_id| (symbol_id, scoping.symbol_name(symbol_id).to_string()))
+            .collect::<Vec<_>>();
+
+        for (symbol_id, name) in clashing {
+            let new_name = ctx.generate_uid_name(&name);
+            ctx.scoping_mut().rename_symbol(symbol_id, scope_id, new_name);
+            self.renamed.insert(symbol_id, new_name);
+        }
+    }
+
+    /// Convert `let` / `const` declaration to `var`, and hoist its bindings.
+    fn convert_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        reset_uninitialized: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            declarator.id.bound_names(&mut |ident| self.convert_binding(ident, ctx));
+            if reset_uninitialized && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    /// Move binding to the enclosing `var` scope, and make it a function-scoped variable.
+    fn convert_binding(&mut self, ident: &BindingIdentifier<'a>, ctx: &mut TraverseCtx<'a>) {
+        let symbol_id = ident.symbol_id();
+        let scoping = ctx.scoping_mut();
+        if !scoping.symbol_flags(symbol_id).contains(SymbolFlags::BlockScopedVariable) {
+            return;
+        }
+
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        let hoist_scope_id = scoping
+            .scope_ancestors(scope_id)
+            .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+            .unwrap();
+        if hoist_scope_id != scope_id {
+            scoping.move_binding(scope_id, hoist_scope_id, ident.name);
+            scoping.set_symbol_scope_id(symbol_id, hoist_scope_id);
+        }
+        let flags = scoping.symbol_flags_mut(symbol_id);
+        if flags.contains(SymbolFlags::ConstVariable) {
+            self.constants.insert(symbol_id);
+        }
+        *flags = SymbolFlags::FunctionScopedVariable;
+        self.converted.insert(symbol_id, scope_id);
+    }
+
+    /// Convert `let` / `const` declarations inserted by other transforms, which are not visited.
+    fn convert_inserted_declarations(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for stmt in stmts {
+            if let Statement::VariableDeclaration(decl) = stmt
+                && matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Let | VariableDeclarationKind::Const
+                )
+            {
+                self.convert_declaration(decl, false, ctx);
+            }
+        }
+    }
+
+    fn enter_loop(&mut self, scope_id: Option<ScopeId>, ctx: &TraverseCtx<'a>) {
+        self.loops.push(LoopInfo {
+            scope_id,
+            hoist_scope_id: ctx.current_hoist_scope_id(),
+            needs_closure: false,
+        });
+    }
+
+    fn exit_loop(&mut self) {
+        self.exited_loop = self.loops.pop();
+    }
+
+    /// Returns `true` if `symbol_id` is a `const` binding, which is or will be converted to `var`.
+    fn is_constant(&self, symbol_id: SymbolId, ctx: &TraverseCtx<'a>) -> bool {
+        self.constants.contains(&symbol_id)
+            || ctx.scoping().symbol_flags(symbol_id).contains(SymbolFlags::ConstVariable)
+    }
+
+    /// Report an assignment to a `const` binding which cannot be replaced by a `readOnlyError` call
+    /// in [`Self::transform_constant_assignment`], i.e. a destructuring or `for-in` / `for-of` target.
+    fn check_constant_assignment(
+        &self,
+        ident: &IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !ctx.scoping().get_reference(ident.reference_id()).is_write()
+            || !self.is_constant(symbol_id, ctx)
+            || matches!(
+                ctx.parent(),
+                Ancestor::AssignmentExpressionLeft(_) | Ancestor::UpdateExpressionArgument(_)
+            )
+        {
+            return;
+        }
+        ctx.state.error(
+            OxcDiagnostic::error(format!(
+                "`{}` is a constant and cannot be reassigned",
+                ident.name
+            ))
+            .with_labels([
+                ident.span.label("assigned here"),
+                ctx.scoping().symbol_span(symbol_id).label("declared here"),
+            ]),
+        );
+    }
+
+    /// Replace an assignment to a `const` binding with a call to `readOnlyError` helper,
+    /// which throws a `TypeError`.
+    ///
+    /// * `c = x` -> `x, _readOnlyError("c")`
+    /// * `c &&= x` -> `c && (x, _readOnlyError("c"))`
+    /// * `c += x` -> `c + x, _readOnlyError("c")`
+    /// * `c++` -> `+c, _readOnlyError("c")`
+    fn transform_constant_assignment(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (span, ident) = match expr {
+            Expression::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                    return;
+                };
+                (assign.span, ident)
+            }
+            Expression::UpdateExpression(update) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument
+                else {
+                    return;
+                };
+                (update.span, ident)
+            }
+            _ => return,
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if !self.is_constant(symbol_id, ctx) {
+            return;
+        }
+
+        let name = ctx.ast.expression_string_literal(SPAN, ident.name, None);
+        let error =
+            helper_call_expr(Helper::ReadOnlyError, ctx.ast.vec1(Argument::from(name)), ctx);
+        let reference_id = ident.reference_id();
+        let ident_span = ident.span;
+        let ident_name = ident.name;
+
+        // Make the reference read-only, as the binding is no longer written to
+        let create_read = |ctx: &mut TraverseCtx<'a>| {
+            *ctx.scoping_mut().get_reference_mut(reference_id).flags_mut() = ReferenceFlags::Read;
+            ctx.ast.expression_identifier_with_reference_id(ident_span, ident_name, reference_id)
+        };
+
+        *expr = match expr.take_in(ctx.ast) {
+            Expression::AssignmentExpression(assign) => {
+                let assign = assign.unbox();
+                match assign.operator {
+                    AssignmentOperator::Assign => {
+                        ctx.delete_reference(reference_id, ident_name);
+                        ctx.ast.expression_sequence(
+                            span,
+                            ctx.ast.vec_from_array([assign.right, error]),
+                        )
+                    }
+                    operator => {
+                        let left = create_read(ctx);
+                        if let Some(operator) = operator.to_logical_operator() {
+                            let right = ctx.ast.expression_sequence(
+                                SPAN,
+                                ctx.ast.vec_from_array([assign.right, error]),
+                            );
+                            ctx.ast.expression_logical(span, left, operator, right)
+                        } else {
+                            let operator = operator.to_binary_operator().unwrap();
+                            let binary =
+                                ctx.ast.expression_binary(SPAN, left, operator, assign.right);
+                            ctx.ast
+                                .expression_sequence(span, ctx.ast.vec_from_array([binary, error]))
+                        }
+                    }
+                }
+            }
+            Expression::UpdateExpression(_) => {
+                let argument = create_read(ctx);
+                let plus = ctx.ast.expression_unary(SPAN, UnaryOperator::UnaryPlus, argument);
+                ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([plus, error]))
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    /// Report a reference to a `let` / `const` binding which is evaluated before the binding
+    /// is initialized.
+    fn check_tdz(
+        &self,
+        ident: &IdentifierReference<'a>,
+        symbol_id: SymbolId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if ident.span.is_unspanned() {
+            return;
+        }
+        let scoping = ctx.scoping();
+        let declaration_scope_id = scoping.symbol_scope_id(symbol_id);
+        if self.is_in_closure(ctx.current_scope_id(), declaration_scope_id, scoping) {
+            return;
+        }
+        let declaration_span = scoping.symbol_span(symbol_id);
+        if ident.span.start >= declaration_span.start && !is_in_own_initializer(symbol_id, ctx) {
+            return;
+        }
+
+        let message = format!("`{}` is used before it is initialized", ident.name);
+        let diagnostic = if self.options.tdz {
+            OxcDiagnostic::error(message)
+        } else {
+            OxcDiagnostic::warn(message)
+        };
+        ctx.state.error(
+            diagnostic.with_labels([
+                ident.span.label("used here"),
+                declaration_span.label("declared here"),
+            ]),
+        );
+    }
+
+    /// Record that a binding declared in `declaration_scope_id` is referenced from the current scope.
+    /// If the reference is inside a closure, and the binding is declared inside a loop,
+    /// the loop body needs to be moved into a closure.
+    fn mark_captured_binding(&mut self, declaration_scope_id: ScopeId, ctx: &TraverseCtx<'a>) {
+        let scoping = ctx.scoping();
+        if !self.is_in_closure(ctx.current_scope_id(), declaration_scope_id, scoping) {
+            return;
+        }
+        for scope_id in scoping.scope_ancestors(declaration_scope_id) {
+            if let Some(info) =
+                self.loops.iter_mut().rev().find(|info| info.scope_id == Some(scope_id))
+            {
+                info.needs_closure = true;
+                return;
+            }
+            if is_closure_scope(scope_id, scoping, &self.class_scopes) {
+                return;
+            }
+        }
+    }
+
+    /// Returns `true` if there is a function or class boundary between `scope_id`
+    /// and its ancestor `target_scope_id`.
+    fn is_in_closure(
+        &self,
+        scope_id: ScopeId,
+        target_scope_id: ScopeId,
+        scoping: &Scoping,
+    ) -> bool {
+        scoping
+            .scope_ancestors(scope_id)
+            .take_while(|&scope_id| scope_id != target_scope_id)
+            .any(|scope_id| is_closure_scope(scope_id, scoping, &self.class_scopes))
+    }
+
+    /// Move loop body into a function, so that each iteration gets its own copy of the bindings
+    /// captured by closures.
+    ///
+    /// ```js
+    /// for (let i = 0; i < 3; i++) {
+    ///   if (skip(i)) continue;
+    ///   if (done(i)) break;
+    ///   fns.push(() => i);
+    /// }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function(i) {
+    ///   if (skip(i)) return;
+    ///   if (done(i)) return "break";
+    ///   fns.push(() => i);
+    /// };
+    /// for (var i = 0; i < 3; i++) {
+    ///   var _ret = _loop(i);
+    ///   if (_ret === "break") break;
+    /// }
+    /// ```
+    fn wrap_loop_body(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        hoist_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.throw_if_closure_required {
+            ctx.state.error(
+                OxcDiagnostic::error(
+                    "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+                )
+                .with_label(stmt.span()),
+            );
+            return;
+        }
+
+        // Labels of the loop, and whether it's in a statement list that `_loop` can be inserted into
+        let mut loop_labels = vec![];
+        let mut insert_address = stmt.address();
+        let mut in_statement_list = false;
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                Ancestor::LabeledStatementBody(labeled) => {
+                    loop_labels.push(labeled.label().name);
+                    insert_address = labeled.address();
+                }
+                Ancestor::ProgramBody(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_) => {
+                    in_statement_list = true;
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        let (body, head, loop_scope_id) = match stmt {
+            Statement::ForStatement(for_stmt) => {
+                let scope_id = for_stmt.scope_id();
+                let ForStatement { init, body, .. } = &mut **for_stmt;
+                let head = match init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(&**decl),
+                    _ => None,
+                };
+                (body, head, Some(scope_id))
+            }
+            Statement::ForInStatement(for_in_stmt) => {
+                let scope_id = for_in_stmt.scope_id();
+                let ForInStatement { left, body, .. } = &mut **for_in_stmt;
+                let head = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (body, head, Some(scope_id))
+            }
+            Statement::ForOfStatement(for_of_stmt) => {
+                let scope_id = for_of_stmt.scope_id();
+                let ForOfStatement { left, body, .. } = &mut **for_of_stmt;
+                let head = match left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(&**decl),
+                    _ => None,
+                };
+                (body, head, Some(scope_id))
+            }
+            Statement::WhileStatement(while_stmt) => (&mut while_stmt.body, None, None),
+            Statement::DoWhileStatement(do_while_stmt) => (&mut do_while_stmt.body, None, None),
+            _ => return,
+        };
+
+        // Bindings declared in loop head
+        let mut head_bindings = vec![];
+        if let Some(decl) = head
+            && let Some(loop_scope_id) = loop_scope_id
+        {
+            decl.bound_names(&mut |ident| {
+                if self.converted.get(&ident.symbol_id()) == Some(&loop_scope_id) {
+                    head_bindings.push(BoundIdentifier::from_binding_ident(ident));
+                }
+            });
+        }
+
+        let mut scanner = LoopBodyScanner::new(&head_bindings, &self.converted, ctx.scoping());
+        scanner.visit_statement(body);
+        if let Some((span, construct)) = scanner.unsupported {
+            ctx.state.error(
+                OxcDiagnostic::warn(format!(
+                    "Cannot move loop body containing {construct} into a closure. \
+                     Closures in the loop share its bindings between iterations."
+                ))
+                .with_label(span),
+            );
+            return;
+        }
+        let LoopBodyScanner { used, written, uses_this, uses_arguments, .. } = scanner;
+
+        let span = body.span();
+        let (mut stmts, body_scope_id) = match body {
+            Statement::BlockStatement(block) => {
+                (block.body.take_in(ctx.ast), Some(block.scope_id()))
+            }
+            _ => (ctx.ast.vec1(body.take_in(ctx.ast)), None),
+        };
+        let closure_scope_id = ctx.insert_scope_below_statements(&stmts, ScopeFlags::Function);
+
+        // Params for loop head bindings used in the body, which shadow the loop head bindings.
+        // Bindings modified in the body get a new name, so they can be written back.
+        let mut params = vec![];
+        let mut arguments = ctx.ast.vec();
+        let mut param_items = ctx.ast.vec();
+        for binding in head_bindings.into_iter().filter(|binding| used.contains(&binding.symbol_id))
+        {
+            let is_written = written.contains(&binding.symbol_id);
+            let param = if is_written {
+                ctx.generate_uid(
+                    binding.name.as_str(),
+                    closure_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            } else {
+                ctx.generate_binding(
+                    binding.name,
+                    closure_scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            };
+            arguments.push(Argument::from(binding.create_read_expression(ctx)));
+            param_items
+                .push(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx)));
+            params.push(LoopParam { outer: binding, param, is_written });
+        }
+        // `arguments` -> `_arguments`
+        let arguments_param = uses_arguments.then(|| {
+            let param = ctx.generate_uid(
+                "arguments",
+                closure_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let name = ctx.ast.ident("arguments");
+            arguments.push(Argument::from(ctx.create_unbound_ident_expr(
+                SPAN,
+                name,
+                ReferenceFlags::Read,
+            )));
+            param_items
+                .push(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx)));
+            param
+        });
+
+        let mut replacer = LoopBodyReplacer {
+            ctx: &mut *ctx,
+            params: &params,
+            arguments_param: arguments_param.as_ref(),
+            converted: &self.converted,
+            body_scope_id,
+            hoist_scope_id,
+            closure_scope_id,
+            loop_labels: &loop_labels,
+            hoisted_vars: FxHashSet::default(),
+            labels: vec![],
+            function_depth: 0,
+            arrow_depth: 0,
+            loop_depth: 0,
+            switch_depth: 0,
+            has_break: false,
+            has_return: false,
+            jumps: vec![],
+        };
+        replacer.visit_statements(&mut stmts);
+        if let Some(writeback) = replacer.create_writeback() {
+            stmts.push(replacer.ctx.ast.statement_expression(SPAN, writeback));
+        }
+        let LoopBodyReplacer { has_break, has_return, jumps, .. } = replacer;
+
+        // `var _loop = function(i) { ... };`
+        let loop_binding =
+            ctx.generate_uid("loop", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        self.converted.insert(loop_binding.symbol_id, ctx.current_scope_id());
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            param_items,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            closure_scope_id,
+            false,
+            false,
+        );
+        let loop_declaration = create_variable_declaration(
+            VariableDeclarationKind::Var,
+            loop_binding.create_binding_pattern(ctx),
+            function,
+            ctx,
+        );
+
+        let mut new_body = ctx.ast.vec();
+        if in_statement_list {
+            ctx.state.statement_injector.insert_before(&insert_address, loop_declaration);
+        } else {
+            new_body.push(loop_declaration);
+        }
+
+        // `_loop(i)` or `_loop.call(this, i)`
+        let callee = loop_binding.create_read_expression(ctx);
+        let call = if uses_this {
+            let callee = create_member_callee(callee, "call", SPAN, ctx);
+            arguments.insert(0, Argument::from(ctx.ast.expression_this(SPAN)));
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        } else {
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+
+        if !has_break && !has_return && jumps.is_empty() {
+            new_body.push(ctx.ast.statement_expression(SPAN, call));
+        } else {
+            // `var _ret = _loop(i);`
+            let ret_binding =
+                ctx.generate_uid("ret", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+            self.converted.insert(ret_binding.symbol_id, ctx.current_scope_id());
+            new_body.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                ret_binding.create_binding_pattern(ctx),
+                call,
+                ctx,
+            ));
+            // `if (_ret === "break") break;`
+            if has_break {
+                let jump = ctx.ast.statement_break(SPAN, None);
+                new_body.push(create_return_value_check(&ret_binding, "break", jump, ctx));
+            }
+            // `if (_ret === "continue|outer") continue outer;`
+            for (is_break, label) in jumps {
+                let (kind, jump) = if is_break {
+                    (
+                        "break",
+                        ctx.ast.statement_break(SPAN, Some(ctx.ast.label_identifier(SPAN, label))),
+                    )
+                } else {
+                    (
+                        "continue",
+                        ctx.ast
+                            .statement_continue(SPAN, Some(ctx.ast.label_identifier(SPAN, label))),
+                    )
+                };
+                let value = ctx.ast.str(&format!("{kind}|{label}"));
+                new_body.push(create_return_value_check(&ret_binding, value.as_str(), jump, ctx));
+            }
+            // `if (typeof _ret === "object") return _ret.v;`
+            if has_return {
+                let ret = ret_binding.create_read_expression(ctx);
+                let type_of = ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, ret);
+                let object = ctx.ast.expression_string_literal(SPAN, "object", None);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    type_of,
+                    BinaryOperator::StrictEquality,
+                    object,
+                );
+                let ret = ret_binding.create_read_expression(ctx);
+                let value = Expression::from(ctx.ast.member_expression_static(
+                    SPAN,
+                    ret,
+                    ctx.ast.identifier_name(SPAN, "v"),
+                    false,
+                ));
+                let return_stmt = ctx.ast.statement_return(SPAN, Some(value));
+                new_body.push(ctx.ast.statement_if(SPAN, test, return_stmt, None));
+            }
+        }
+
+        if let Statement::BlockStatement(block) = body {
+            block.body = new_body;
+        } else {
+            let parent_scope_id = loop_scope_id.unwrap_or_else(|| ctx.current_scope_id());
+            let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            *body = ctx.ast.statement_block_with_scope_id(span, new_body, scope_id);
+        }
+    }
+}
+
+/// `if (_ret === "break") break;`
+fn create_return_value_check<'a>(
+    ret_binding: &BoundIdentifier<'a>,
+    value: &str,
+    jump: Statement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let ret = ret_binding.create_read_expression(ctx);
+    let value = ctx.ast.expression_string_literal(SPAN, ctx.ast.str(value), None);
+    let test = ctx.ast.expression_binary(SPAN, ret, BinaryOperator::StrictEquality, value);
+    ctx.ast.statement_if(SPAN, test, jump, None)
+}
+
+/// `let` / `const` binding, excluding catch clause params.
+fn is_lexical_variable(flags: SymbolFlags) -> bool {
+    flags.contains(SymbolFlags::BlockScopedVariable) && !flags.contains(SymbolFlags::CatchVariable)
+}
+
+/// Function, arrow function, static block or class scope.
+fn is_closure_scope(
+    scope_id: ScopeId,
+    scoping: &Scoping,
+    class_scopes: &FxHashSet<ScopeId>,
+) -> bool {
+    scoping.scope_flags(scope_id).intersects(ScopeFlags::Function | ScopeFlags::ClassStaticBlock)
+        || class_scopes.contains(&scope_id)
+}
+
+/// Scope of a loop body which is a block, which its per-iteration bindings are declared in.
+fn block_scope_id(body: &Statement) -> Option<ScopeId> {
+    match body {
+        Statement::BlockStatement(block) => Some(block.scope_id()),
+        _ => None,
+    }
+}
+
+/// Returns `true` if currently inside the initializer of the declaration of `symbol_id`.
+/// e.g. `let x = x + 1;`
+fn is_in_own_initializer(symbol_id: SymbolId, ctx: &TraverseCtx) -> bool {
+    let Some(declarator) = ctx.ancestors().find_map(|ancestor| match ancestor {
+        Ancestor::VariableDeclaratorInit(declarator) => Some(declarator),
+        _ => None,
+    }) else {
+        return false;
+    };
+    let mut found = false;
+    declarator.id().bound_names(&mut |ident| found |= ident.symbol_id() == symbol_id);
+    found
+}
+
+struct LoopParam<'a> {
+    /// Binding declared in loop head
+    outer: BoundIdentifier<'a>,
+    /// Param of `_loop` function
+    param: BoundIdentifier<'a>,
+    /// `true` if the binding is modified in loop body, and must be written back
+    is_written: bool,
+}
+
+/// Visitor which collects info about a loop body before it is moved into a closure.
+struct LoopBodyScanner<'a, 's> {
+    head_bindings: &'s [BoundIdentifier<'a>],
+    converted: &'s FxHashMap<SymbolId, ScopeId>,
+    scoping: &'s Scoping,
+    /// Loop head bindings referenced in the body
+    used: FxHashSet<SymbolId>,
+    /// Loop head bindings modified in the body
+    written: FxHashSet<SymbolId>,
+    uses_this: bool,
+    uses_arguments: bool,
+    /// First construct found which cannot be moved into a closure
+    unsupported: Option<(Span, &'static str)>,
+    /// Depth of functions and classes, which have their own `this`
+    function_depth: u32,
+    arrow_depth: u32,
+}
+
+impl<'a, 's> LoopBodyScanner<'a, 's> {
+    fn new(
+        head_bindings: &'s [BoundIdentifier<'a>],
+        converted: &'s FxHashMap<SymbolId, ScopeId>,
+        scoping: &'s Scoping,
+    ) -> Self {
+        Self {
+            head_bindings,
+            converted,
+            scoping,
+            used: FxHashSet::default(),
+            written: FxHashSet::default(),
+            uses_this: false,
+            uses_arguments: false,
+            unsupported: None,
+            function_depth: 0,
+            arrow_depth: 0,
+        }
+    }
+
+    fn report_unsupported(&mut self, span: Span, construct: &'static str) {
+        self.unsupported.get_or_insert((span, construct));
+    }
+}
+
+impl<'a> Visit<'a> for LoopBodyScanner<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference = self.scoping.get_reference(ident.reference_id());
+        match reference.symbol_id() {
+            Some(symbol_id) => {
+                if self.head_bindings.iter().any(|binding| binding.symbol_id == symbol_id) {
+                    self.used.insert(symbol_id);
+                    if reference.is_write() {
+                        self.written.insert(symbol_id);
+                    }
+                }
+            }
+            None => {
+                if self.function_depth == 0 && ident.name == "arguments" {
+                    self.uses_arguments = true;
+                }
+            }
+        }
+    }
+
+    fn visit_this_expression(&mut self, _this: &ThisExpression) {
+        if self.function_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, sup: &Super) {
+        if self.function_depth == 0 {
+            self.report_unsupported(sup.span, "`super`");
+        }
+    }
+
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if self.function_depth == 0 && meta.meta.name == "new" {
+            self.report_unsupported(meta.span, "`new.target`");
+        }
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 {
+            self.report_unsupported(expr.span, "`yield`");
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        if self.function_depth == 0 && self.arrow_depth == 0 {
+            self.report_unsupported(expr.span, "`await`");
+        }
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await && self.function_depth == 0 && self.arrow_depth == 0 {
+            self.report_unsupported(stmt.span, "`for await`");
+        }
+        walk::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        // `var` declarations are hoisted out of the closure, which is only supported for identifiers
+        if self.function_depth == 0
+            && self.arrow_depth == 0
+            && decl.kind.is_var()
+            && decl.declarations.iter().any(|declarator| !declarator.id.is_binding_identifier())
+        {
+            let mut is_converted = true;
+            decl.bound_names(&mut |ident| {
+                is_converted &= self.converted.contains_key(&ident.symbol_id());
+            });
+            if !is_converted {
+                self.report_unsupported(
+                    decl.span,
+                    "a `var` declaration with a destructuring pattern",
+                );
+            }
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        self.function_depth += 1;
+        walk::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+}
+
+/// Visitor which transforms a loop body into the body of the `_loop` function.
+struct LoopBodyReplacer<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    params: &'v [LoopParam<'a>],
+    /// `_arguments` param, if body references `arguments`
+    arguments_param: Option<&'v BoundIdentifier<'a>>,
+    converted: &'v FxHashMap<SymbolId, ScopeId>,
+    /// Scope of loop body, if it's a block
+    body_scope_id: Option<ScopeId>,
+    hoist_scope_id: ScopeId,
+    closure_scope_id: ScopeId,
+    /// Labels of the loop
+    loop_labels: &'v [Ident<'a>],
+    /// `var` bindings which have been hoisted out of the closure
+    hoisted_vars: FxHashSet<SymbolId>,
+    /// Labels inside the loop body which are currently in scope
+    labels: Vec<Ident<'a>>,
+    function_depth: u32,
+    arrow_depth: u32,
+    loop_depth: u32,
+    switch_depth: u32,
+    /// `true` if loop body contains `break` targeting the loop
+    has_break: bool,
+    /// `true` if loop body contains `return`
+    has_return: bool,
+    /// `break` / `continue` statements targeting a label outside the loop
+    jumps: Vec<(bool, Ident<'a>)>,
+}
+
+impl<'a> LoopBodyReplacer<'a, '_> {
+    fn is_top_level(&self) -> bool {
+        self.function_depth == 0 && self.arrow_depth == 0
+    }
+
+    /// Move binding declared in loop body into the closure's scope.
+    fn move_binding_to_closure(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        let scoping = self.ctx.scoping_mut();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        if Some(scope_id) == self.body_scope_id
+            || (scope_id == self.hoist_scope_id && self.converted.contains_key(&symbol_id))
+        {
+            scoping.set_symbol_scope_id(symbol_id, self.closure_scope_id);
+            scoping.move_binding(scope_id, self.closure_scope_id, ident.name);
+        }
+    }
+
+    /// `var` declaration which was not converted from `let` / `const`,
+    /// so it belongs to the enclosing function.
+    fn is_hoisted_var(&self, decl: &VariableDeclaration<'a>) -> bool {
+        if !decl.kind.is_var() {
+            return false;
+        }
+        let mut is_converted = false;
+        decl.bound_names(&mut |ident| {
+            is_converted |= self.converted.contains_key(&ident.symbol_id());
+        });
+        !is_converted
+    }
+
+    /// Add `var x;` to enclosing scope.
+    fn hoist_var(&mut self, ident: &BindingIdentifier<'a>) -> BoundIdentifier<'a> {
+        let binding = BoundIdentifier::from_binding_ident(ident);
+        if self.hoisted_vars.insert(binding.symbol_id) {
+            self.ctx.state.var_declarations.insert_var(&binding, self.ctx.ast);
+        }
+        binding
+    }
+
+    /// `var a = 1, b;` -> `a = 1`, with `var a, b;` hoisted.
+    fn hoist_var_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut assignments = self.ctx.ast.vec();
+        for declarator in &mut decl.declarations {
+            let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                unreachable!("Patterns are rejected by `LoopBodyScanner`");
+            };
+            let binding = self.hoist_var(ident);
+            if let Some(init) = declarator.init.take() {
+                assignments.push(create_assignment(&binding, init, declarator.span, self.ctx));
+            }
+        }
+        self.join_expressions(assignments)
+    }
+
+    fn join_expressions(
+        &self,
+        mut expressions: ArenaVec<'a, Expression<'a>>,
+    ) -> Option<Expression<'a>> {
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(SPAN, expressions)),
+        }
+    }
+
+    /// `i = _i` for each loop head binding modified in the body.
+    fn create_writeback(&mut self) -> Option<Expression<'a>> {
+        let mut assignments = self.ctx.ast.vec();
+        for param in self.params.iter().filter(|param| param.is_written) {
+            let value = param.param.create_read_expression(self.ctx);
+            assignments.push(create_assignment(&param.outer, value, SPAN, self.ctx));
+        }
+        self.join_expressions(assignments)
+    }
+
+    /// `return value;`, preceded by writeback of modified loop head bindings.
+    fn create_return(&mut self, span: Span, value: Option<Expression<'a>>) -> Statement<'a> {
+        let argument = match (self.create_writeback(), value) {
+            (None, value) => value,
+            // `return void (i = _i);`
+            (Some(writeback), None) => {
+                Some(self.ctx.ast.expression_unary(SPAN, UnaryOperator::Void, writeback))
+            }
+            // `return (i = _i, value);`
+            (Some(writeback), Some(value)) => Some(
+                self.ctx
+                    .ast
+                    .expression_sequence(SPAN, self.ctx.ast.vec_from_array([writeback, value])),
+            ),
+        };
+        self.ctx.ast.statement_return(span, argument)
+    }
+
+    /// `"break|label"` / `"continue|label"`
+    fn create_jump_value(&mut self, is_break: bool, label: Ident<'a>) -> Expression<'a> {
+        if !self.jumps.contains(&(is_break, label)) {
+            self.jumps.push((is_break, label));
+        }
+        let kind = if is_break { "break" } else { "continue" };
+        let value = self.ctx.ast.str(&format!("{kind}|{label}"));
+        self.ctx.ast.expression_string_literal(SPAN, value, None)
+    }
+
+    /// `for (var x of y)` -> `for (x of y)`, with `var x;` hoisted.
+    fn hoist_loop_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        if !self.is_top_level() {
+            return;
+        }
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        if !self.is_hoisted_var(decl) {
+            return;
+        }
+        let BindingPattern::BindingIdentifier(ident) = &decl.declarations[0].id else {
+            unreachable!("Patterns are rejected by `LoopBodyScanner`");
+        };
+        let binding = self.hoist_var(ident);
+        *left = ForStatementLeft::from(binding.create_write_target(self.ctx));
+    }
+}
+
+impl<'a> VisitMut<'a> for LoopBodyReplacer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id();
+        let params = self.params;
+        let target = match symbol_id {
+            Some(symbol_id) => params
+                .iter()
+                .find(|param| param.outer.symbol_id == symbol_id)
+                .map(|param| &param.param),
+            None if self.function_depth == 0 && ident.name == "arguments" => self.arguments_param,
+            None => None,
+        };
+        let Some(target) = target else { return };
+
+        let scoping = self.ctx.scoping_mut();
+        if let Some(symbol_id) = symbol_id {
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+        } else {
+            scoping.delete_root_unresolved_reference(ident.name, reference_id);
+        }
+        scoping.get_reference_mut(reference_id).set_symbol_id(target.symbol_id);
+        scoping.add_resolved_reference(target.symbol_id, reference_id);
+        ident.name = target.name;
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if self.is_top_level() {
+            self.move_binding_to_closure(ident);
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        // Remove `var` declarations without initializers, which have been hoisted
+        stmts.retain_mut(|stmt| {
+            self.visit_statement(stmt);
+            !matches!(stmt, Statement::EmptyStatement(empty) if empty.span.is_unspanned())
+        });
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !self.is_top_level() {
+            walk_mut::walk_statement(self, stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::BreakStatement(break_stmt) => {
+                let span = break_stmt.span;
+                let value = match break_stmt.label.as_ref().map(|label| label.name) {
+                    None if self.loop_depth > 0 || self.switch_depth > 0 => return,
+                    Some(label) if self.labels.contains(&label) => return,
+                    Some(label) if !self.loop_labels.contains(&label) => {
+                        self.create_jump_value(true, label)
+                    }
+                    _ => {
+                        self.has_break = true;
+                        self.ctx.ast.expression_string_literal(SPAN, "break", None)
+                    }
+                };
+                *stmt = self.create_return(span, Some(value));
+            }
+            Statement::ContinueStatement(continue_stmt) => {
+                let span = continue_stmt.span;
+                let value = match continue_stmt.label.as_ref().map(|label| label.name) {
+                    None if self.loop_depth > 0 => return,
+                    Some(label) if self.labels.contains(&label) => return,
+                    Some(label) if !self.loop_labels.contains(&label) => {
+                        Some(self.create_jump_value(false, label))
+                    }
+                    _ => None,
+                };
+                *stmt = self.create_return(span, value);
+            }
+            // `return x;` -> `return { v: x };`
+            Statement::ReturnStatement(return_stmt) => {
+                walk_mut::walk_return_statement(self, return_stmt);
+                self.has_return = true;
+                let span = return_stmt.span;
+                let value =
+                    return_stmt.argument.take().unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                let property = self.ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    self.ctx.ast.property_key_static_identifier(SPAN, "v"),
+                    value,
+                    false,
+                    false,
+                    false,
+                );
+                let object = self.ctx.ast.expression_object(SPAN, self.ctx.ast.vec1(property));
+                *stmt = self.create_return(span, Some(object));
+            }
+            Statement::VariableDeclaration(decl) if self.is_hoisted_var(decl) => {
+                walk_mut::walk_variable_declaration(self, decl);
+                let span = decl.span;
+                *stmt = match self.hoist_var_declaration(decl) {
+                    Some(expr) => self.ctx.ast.statement_expression(span, expr),
+                    None => self.ctx.ast.statement_empty(SPAN),
+                };
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.labels.push(labeled.label.name);
+                walk_mut::walk_labeled_statement(self, labeled);
+                self.labels.pop();
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_) => {
+                self.loop_depth += 1;
+                walk_mut::walk_statement(self, stmt);
+                self.loop_depth -= 1;
+            }
+            Statement::SwitchStatement(_) => {
+                self.switch_depth += 1;
+                walk_mut::walk_statement(self, stmt);
+                self.switch_depth -= 1;
+            }
+            _ => walk_mut::walk_statement(self, stmt),
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        walk_mut::walk_for_statement(self, stmt);
+        if self.is_top_level()
+            && let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+            && self.is_hoisted_var(decl)
+        {
+            let init = self.hoist_var_declaration(decl);
+            stmt.init = init.map(ForStatementInit::from);
+        }
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        walk_mut::walk_for_in_statement(self, stmt);
+        self.hoist_loop_left(&mut stmt.left);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        walk_mut::walk_for_of_statement(self, stmt);
+        self.hoist_loop_left(&mut stmt.left);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        if self.is_top_level()
+            && func.is_declaration()
+            && let Some(id) = &func.id
+        {
+            self.move_binding_to_closure(id);
+        }
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if self.is_top_level()
+            && class.is_declaration()
+            && let Some(id) = &class.id
+        {
+            self.move_binding_to_closure(id);
+        }
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+}
@@ -0,0 +1,1607 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms classes into constructor functions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class A extends B {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   get x() {
+//!     return super.x;
+//!   }
+//!   static create() {
+//!     return new this(1);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let A = /*#__PURE__*/ function(_B) {
+//!   function A(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, A);
+//!     _this = babelHelpers.callSuper(this, A, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(A, _B);
+//!   return babelHelpers.createClass(A, [{
+//!     key: "x",
+//!     get: function() {
+//!       return babelHelpers.superPropGet(A, "x", this, 1);
+//!     }
+//!   }], [{
+//!     key: "create",
+//!     value: function create() {
+//!       return new this(1);
+//!     }
+//!   }]);
+//! }(B);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `noClassCalls`, `setClassMethods`, `constantSuper` and `superIsCallableConstructor`
+//! assumptions. Each of them can also be enabled on its own via compiler assumptions.
+//!
+//! ## Implementation
+//!
+//! Class properties, private methods and static blocks are expected to have been removed from the class
+//! by the class properties plugin, which runs before this one. Classes still containing such elements
+//! are left as is, and an error is reported.
+//!
+//! The transform is performed when exiting the class declaration statement or class expression,
+//! so that transforms of nested code are already complete. `super` / `this` / `new.target` inside
+//! methods are then converted in a single visitation of each method.
+//!
+//! The class scope is reused as the scope of the IIFE wrapping the constructor function.
+//!
+//! `super()` calls are replaced after the spread transform has run, so spread arguments in them
+//! are lowered here when the spread transform is enabled.
+//!
+//! ## Missing features
+//!
+//! * `this` and `arguments` in computed keys refer to the IIFE rather than the enclosing function.
+//! * `super()` in constructor params.
+//! * `super` property access inside an async method or generator which has been transformed
+//!   into a nested function.
+//! * Destructuring assignment to `super` properties (`[super.x] = arr`).
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::{Ident, Str};
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::get_var_name_from_node};
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    es2015::{
+        SpreadOptions,
+        spread::{transform_arguments, transform_array_elements},
+    },
+    state::TransformState,
+    utils::ast_builder::{
+        create_assignment, create_member_callee, create_property_access, create_prototype_member,
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 classes transform.
+pub struct ClassesOptions {
+    /// Enables `noClassCalls`, `setClassMethods`, `constantSuper` and
+    /// `superIsCallableConstructor` assumptions.
+    pub loose: bool,
+}
+
+pub struct Classes {
+    options: ClassesOptions,
+    /// Options of the spread transform, if it's enabled.
+    spread: Option<SpreadOptions>,
+}
+
+impl Classes {
+    pub fn new(options: ClassesOptions, spread: Option<SpreadOptions>) -> Self {
+        Self { options, spread }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Name anonymous class from the variable it is assigned to: `let A = class {}`
+        let name_hint = match (&*expr, ctx.parent()) {
+            (Expression::ClassExpression(class), Ancestor::VariableDeclaratorInit(declarator))
+                if class.id.is_none() =>
+            {
+                declarator.id().get_binding_identifier().map(|ident| ident.name)
+            }
+            _ => None,
+        };
+
+        if let Some(class_expr) = find_class_expression(expr) {
+            self.transform_class_expression(class_expr, name_hint, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(_) => self.transform_class_declaration(stmt, ctx),
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration
+                    && let Some(declaration) = self.transform_class_to_declaration(class, ctx)
+                {
+                    export.declaration = Some(declaration);
+                }
+            }
+            Statement::ExportDefaultDeclaration(_) => {
+                self.transform_export_default_class(stmt, ctx);
+            }
+            // Class expressions created by other plugins after the expression was exited,
+            // e.g. legacy decorators: `@dec class C {}` -> `let C = class C {}`
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if let Some(init) = &mut declarator.init
+                        && let Some(class_expr) = find_class_expression(init)
+                    {
+                        self.transform_class_expression(class_expr, None, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Classes {
+    /// `class A {}` -> `let A = function () { ... }();`
+    fn transform_class_declaration(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ClassDeclaration(class) = stmt else { unreachable!() };
+        let Some(declaration) = self.transform_class_to_declaration(class, ctx) else { return };
+        let new_stmt = Statement::from(declaration);
+        ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// * `export default class A {}` -> `let A = function () { ... }(); export { A as default };`
+    /// * `export default class {}` -> `export default function () { ... }();`
+    fn transform_export_default_class(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExportDefaultDeclaration(export) = stmt else { unreachable!() };
+        let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration else {
+            return;
+        };
+
+        if class.id.is_none() {
+            let name_hint = ctx.ast.ident("default");
+            if let Some(expr) = self.transform_class(class, Some(name_hint), ctx) {
+                export.declaration = ExportDefaultDeclarationKind::from(expr);
+            }
+            return;
+        }
+
+        let Some(declaration) = self.transform_class_to_declaration(class, ctx) else { return };
+        let Declaration::VariableDeclaration(var_decl) = &declaration else { unreachable!() };
+        let BindingPattern::BindingIdentifier(ident) = &var_decl.declarations[0].id else {
+            unreachable!()
+        };
+        let binding = BoundIdentifier::from_binding_ident(ident);
+
+        // `export { A as default }`
+        let kind = ImportOrExportKind::Value;
+        let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+        let exported = ctx.ast.module_export_name_identifier_name(SPAN, "default");
+        let specifiers = ctx.ast.vec1(ctx.ast.export_specifier(SPAN, local, exported, kind));
+        let export_stmt =
+            Statement::from(ctx.ast.module_declaration_export_named_declaration(
+                SPAN, None, specifiers, None, kind, NONE,
+            ));
+
+        let new_stmt = Statement::from(declaration);
+        ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+        ctx.state.statement_injector.insert_after(&new_stmt, export_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// `class A {}` -> `let A = function () { ... }()`
+    fn transform_class_to_declaration(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Declaration<'a>> {
+        let id = class.id.clone_in_with_semantic_ids(ctx.ast.allocator)?;
+        let init = self.transform_class(class, None, ctx)?;
+
+        // Class name binding is now a `let` binding
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = SymbolFlags::BlockScopedVariable;
+
+        let kind = VariableDeclarationKind::Let;
+        let pattern = BindingPattern::BindingIdentifier(ctx.ast.alloc(id));
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false);
+        Some(ctx.ast.declaration_variable(class.span, kind, ctx.ast.vec1(declarator), false))
+    }
+
+    /// `class A {}` -> `function () { ... }()`
+    fn transform_class_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        name_hint: Option<Ident<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        if let Some(new_expr) = self.transform_class(class, name_hint, ctx) {
+            *expr = new_expr;
+        }
+    }
+
+    /// Transform class to an IIFE returning the constructor function.
+    ///
+    /// Returns `None` if the class cannot be transformed.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        name_hint: Option<Ident<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if class.declare || !Self::check_class_elements(class, ctx) {
+            return None;
+        }
+
+        let class_scope_id = class.scope_id();
+        let parent_scope_id = ctx.scoping().scope_parent_id(class_scope_id).unwrap();
+        let assumptions = ClassAssumptions::new(self.options, self.spread, ctx);
+
+        // Class scope becomes the scope of the IIFE.
+        // Class bodies are always strict mode, so add `"use strict"` to the IIFE if the class
+        // is in sloppy mode code.
+        let needs_use_strict = !ctx.state.source_type.is_module()
+            && !ctx.scoping().scope_flags(parent_scope_id).is_strict_mode();
+        *ctx.scoping_mut().scope_flags_mut(class_scope_id) =
+            ScopeFlags::Function | ScopeFlags::StrictMode;
+
+        let class_binding = Self::create_class_binding(class, name_hint, class_scope_id, ctx);
+
+        // `extends` expression is moved out of the class, to be the IIFE's argument
+        let super_class = class.super_class.take();
+        let super_binding = super_class.as_ref().map(|super_class| {
+            ctx.remove_scope_for_expression(class_scope_id, super_class);
+            ctx.generate_uid_based_on_node(
+                super_class,
+                class_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            )
+        });
+
+        let bindings = ClassBindings { class: class_binding, super_class: super_binding };
+
+        // Transform methods
+        let mut constructor = None;
+        let mut prototype_methods = Methods::default();
+        let mut static_methods = Methods::default();
+        for element in class.body.body.take_in(ctx.ast) {
+            // Other elements are TypeScript-only, or rejected in `check_class_elements`
+            let ClassElement::MethodDefinition(method) = element else { continue };
+            let method = method.unbox();
+            if method.kind == MethodDefinitionKind::Constructor {
+                constructor = Some(method.value);
+                continue;
+            }
+
+            let methods =
+                if method.r#static { &mut static_methods } else { &mut prototype_methods };
+            Self::transform_method(method, methods, &bindings, assumptions, ctx);
+        }
+
+        // Create IIFE body
+        let mut stmts = ctx.ast.vec();
+        stmts.push(Self::create_constructor(
+            constructor,
+            class_scope_id,
+            &bindings,
+            assumptions,
+            ctx,
+        ));
+
+        if let Some(super_binding) = &bindings.super_class {
+            // `_inherits(A, _B)`
+            let helper = if assumptions.super_is_callable_constructor {
+                Helper::InheritsLoose
+            } else {
+                Helper::Inherits
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(bindings.class.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = helper_call_expr(helper, arguments, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        if assumptions.set_class_methods {
+            // `A.prototype.foo = function foo() {}`
+            Self::assign_methods(&mut prototype_methods, true, &bindings.class, &mut stmts, ctx);
+            Self::assign_methods(&mut static_methods, false, &bindings.class, &mut stmts, ctx);
+        }
+
+        // `return _createClass(A, [...], [...])`
+        let return_value = if assumptions.set_class_methods
+            && prototype_methods.is_empty()
+            && static_methods.is_empty()
+        {
+            bindings.class.create_read_expression(ctx)
+        } else {
+            let mut arguments = ctx.ast.vec_with_capacity(3);
+            arguments.push(Argument::from(bindings.class.create_read_expression(ctx)));
+            if !static_methods.is_empty() {
+                arguments.push(if prototype_methods.is_empty() {
+                    Argument::from(ctx.ast.expression_null_literal(SPAN))
+                } else {
+                    Argument::from(prototype_methods.into_array(ctx))
+                });
+                arguments.push(Argument::from(static_methods.into_array(ctx)));
+            } else if !prototype_methods.is_empty() {
+                arguments.push(Argument::from(prototype_methods.into_array(ctx)));
+            }
+            helper_call_expr(Helper::CreateClass, arguments, ctx)
+        };
+        stmts.push(ctx.ast.statement_return(SPAN, Some(return_value)));
+
+        let directives = if needs_use_strict {
+            let use_strict = Str::from("use strict");
+            ctx.ast.vec1(ctx.ast.directive(
+                SPAN,
+                ctx.ast.string_literal(SPAN, use_strict, None),
+                use_strict,
+            ))
+        } else {
+            ctx.ast.vec()
+        };
+        let body = ctx.ast.alloc_function_body(SPAN, directives, stmts);
+
+        let (params, arguments) = match (super_class, &bindings.super_class) {
+            (Some(super_class), Some(super_binding)) => (
+                ctx.ast.vec1(
+                    ctx.ast.plain_formal_parameter(SPAN, super_binding.create_binding_pattern(ctx)),
+                ),
+                ctx.ast.vec1(Argument::from(super_class)),
+            ),
+            _ => (ctx.ast.vec(), ctx.ast.vec()),
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+
+        // `function (_B) { ... }`
+        let function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            class_scope_id,
+            false,
+            false,
+        );
+
+        // `/*#__PURE__*/ function (_B) { ... }(B)`
+        Some(ctx.ast.expression_call_with_pure(class.span, function, NONE, arguments, false, true))
+    }
+
+    /// Check class only contains elements this plugin can transform.
+    ///
+    /// Class fields, private methods and static blocks must be transformed by class properties plugin,
+    /// and decorators by decorators plugin, before this plugin runs.
+    fn check_class_elements(class: &Class<'a>, ctx: &mut TraverseCtx<'a>) -> bool {
+        if let Some(decorator) = class.decorators.first() {
+            ctx.state.error(
+                OxcDiagnostic::error("Missing decorators transform.").with_label(decorator.span),
+            );
+            return false;
+        }
+
+        for element in &class.body.body {
+            let span = match element {
+                ClassElement::MethodDefinition(method) => {
+                    if let Some(decorator) = method.decorators.first() {
+                        ctx.state.error(
+                            OxcDiagnostic::error("Missing decorators transform.")
+                                .with_label(decorator.span),
+                        );
+                        return false;
+                    }
+                    if !method.key.is_private_identifier() {
+                        continue;
+                    }
+                    method.span
+                }
+                ClassElement::PropertyDefinition(prop) => prop.span,
+                ClassElement::AccessorProperty(prop) => prop.span,
+                ClassElement::StaticBlock(block) => block.span,
+                ClassElement::TSIndexSignature(_) => continue,
+            };
+            ctx.state.error(
+                OxcDiagnostic::error("Missing class properties transform.").with_label(span),
+            );
+            return false;
+        }
+
+        true
+    }
+
+    /// Create binding for the constructor function.
+    ///
+    /// * Class expression `class A {}`: Binding is already in class scope. Reuse it.
+    /// * Class declaration `class A {}`: Binding is in outer scope. Create a new binding in class scope,
+    ///   and point references to the class name within the class at it.
+    /// * Anonymous class: Use name of the variable class is assigned to, if it's not referenced within
+    ///   the class. Otherwise create a UID.
+    fn create_class_binding(
+        class: &Class<'a>,
+        name_hint: Option<Ident<'a>>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(id) = &class.id {
+            let symbol_id = id.symbol_id();
+            if ctx.scoping().symbol_scope_id(symbol_id) == class_scope_id {
+                *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::Function;
+                return BoundIdentifier::new(id.name, symbol_id);
+            }
+
+            let binding = ctx.generate_binding(id.name, class_scope_id, SymbolFlags::Function);
+            ClassNameReferenceRebinder::new(symbol_id, binding.symbol_id, ctx)
+                .visit_class_body(&class.body);
+            return binding;
+        }
+
+        if let Some(name) = name_hint
+            && is_identifier_name(&name)
+            && !is_reserved_keyword(&name)
+            && !IdentifierNameFinder::contains(name, |finder| finder.visit_class_body(&class.body))
+        {
+            return ctx.generate_binding(name, class_scope_id, SymbolFlags::Function);
+        }
+
+        let name = name_hint.map_or("Class", |name| name.as_str());
+        ctx.generate_uid(name, class_scope_id, SymbolFlags::Function)
+    }
+
+    /// Transform a method to a function expression, and add it to `methods`.
+    fn transform_method(
+        method: MethodDefinition<'a>,
+        methods: &mut Methods<'a>,
+        bindings: &ClassBindings<'a>,
+        assumptions: ClassAssumptions,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let MethodDefinition { key, value: mut func, kind, computed, r#static, .. } = method;
+
+        let mode = if r#static { MethodMode::Static } else { MethodMode::Prototype };
+        ClassMethodReplacer::transform_function(&mut func, mode, None, bindings, assumptions, ctx);
+        func.r#type = FunctionType::FunctionExpression;
+
+        // Name method function `foo() {}` -> `function foo() {}`,
+        // unless that would shadow a reference within the function
+        if kind == MethodDefinitionKind::Method
+            && !computed
+            && let PropertyKey::StaticIdentifier(ident) = &key
+            && !is_reserved_keyword(&ident.name)
+            && !ctx.scoping().scope_has_binding(func.scope_id(), ident.name)
+            && !IdentifierNameFinder::contains(ident.name, |finder| {
+                finder.visit_function(&func, ScopeFlags::Function);
+            })
+        {
+            let binding = ctx.generate_binding(ident.name, func.scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+        }
+
+        let key_name =
+            if computed { None } else { key.static_name().map(std::borrow::Cow::into_owned) };
+        let key = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            key => key.into_expression(),
+        };
+        methods.add(key_name, key, kind, Expression::FunctionExpression(func));
+    }
+
+    /// Create constructor function.
+    ///
+    /// ```js
+    /// function A(x) {
+    ///   babelHelpers.classCallCheck(this, A);
+    ///   this.x = x;
+    /// }
+    /// ```
+    fn create_constructor(
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        class_scope_id: ScopeId,
+        bindings: &ClassBindings<'a>,
+        assumptions: ClassAssumptions,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let is_derived = bindings.super_class.is_some();
+
+        let mut func = if let Some(mut func) = constructor {
+            if is_derived && Self::is_simple_derived_constructor(&func) {
+                // `constructor(x) { super(x); }` -> `return _callSuper(this, A, [x]);`
+                let body = func.body.as_mut().unwrap();
+                let Some(Statement::ExpressionStatement(stmt)) = body.statements.pop() else {
+                    unreachable!()
+                };
+                let Expression::CallExpression(call) = stmt.unbox().expression else {
+                    unreachable!()
+                };
+                let call = call.unbox();
+                let value = Self::create_super_constructor_call(
+                    call.arguments,
+                    call.span,
+                    None,
+                    bindings,
+                    assumptions,
+                    ctx,
+                );
+                body.statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+                ctx.scoping_mut().scope_flags_mut(func.scope_id()).remove(ScopeFlags::Constructor);
+            } else {
+                let mode = MethodMode::Constructor;
+                ClassMethodReplacer::transform_function(
+                    &mut func,
+                    mode,
+                    Some(is_derived),
+                    bindings,
+                    assumptions,
+                    ctx,
+                );
+            }
+            func
+        } else {
+            let scope_id = ctx
+                .create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode);
+            let mut stmts = ctx.ast.vec();
+            if is_derived {
+                // `return _callSuper(this, A, arguments);`
+                let arguments = ctx.create_unbound_ident_expr(
+                    SPAN,
+                    ctx.ast.ident("arguments"),
+                    ReferenceFlags::Read,
+                );
+                let arguments = ctx.ast.vec1(ctx.ast.argument_spread_element(SPAN, arguments));
+                let value = Self::create_super_constructor_call(
+                    arguments,
+                    SPAN,
+                    None,
+                    bindings,
+                    assumptions,
+                    ctx,
+                );
+                stmts.push(ctx.ast.statement_return(SPAN, Some(value)));
+            }
+            let params = ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            );
+            let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+            ctx.ast.alloc_function_with_scope_id(
+                SPAN,
+                FunctionType::FunctionDeclaration,
+                None,
+                false,
+                false,
+                false,
+                NONE,
+                NONE,
+                params,
+                NONE,
+                Some(body),
+                scope_id,
+            )
+        };
+
+        if !assumptions.no_class_calls {
+            // `_classCallCheck(this, A);`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(bindings.class.create_read_expression(ctx)),
+            ]);
+            let call = helper_call_expr(Helper::ClassCallCheck, arguments, ctx);
+            let stmts = &mut func.body.as_mut().unwrap().statements;
+            // Insert after `var _this;`
+            let index =
+                usize::from(matches!(stmts.first(), Some(Statement::VariableDeclaration(_))));
+            stmts.insert(index, ctx.ast.statement_expression(SPAN, call));
+        }
+
+        func.r#type = FunctionType::FunctionDeclaration;
+        // Keep span of class name, so it matches span of the symbol
+        let class_binding = &bindings.class;
+        let span = ctx.scoping().symbol_span(class_binding.symbol_id);
+        func.id = Some(ctx.ast.binding_identifier_with_symbol_id(
+            span,
+            class_binding.name,
+            class_binding.symbol_id,
+        ));
+        Statement::FunctionDeclaration(func)
+    }
+
+    /// Check if derived class constructor's body is only a `super()` call,
+    /// whose arguments cannot contain `this`. e.g. `constructor(x, ...y) { super(x, ...y); }`.
+    fn is_simple_derived_constructor(func: &Function<'a>) -> bool {
+        let Some(body) = &func.body else { return false };
+        if !body.directives.is_empty() || body.statements.len() != 1 {
+            return false;
+        }
+        let Statement::ExpressionStatement(stmt) = &body.statements[0] else { return false };
+        let Expression::CallExpression(call) = &stmt.expression else { return false };
+        if !call.callee.is_super() {
+            return false;
+        }
+
+        let is_simple_expr =
+            |expr: &Expression<'a>| matches!(expr, Expression::Identifier(_)) || expr.is_literal();
+        func.params.items.iter().all(|param| {
+            param.initializer.is_none()
+                && matches!(param.pattern, BindingPattern::BindingIdentifier(_))
+        }) && call.arguments.iter().all(|arg| match arg {
+            Argument::SpreadElement(spread) => is_simple_expr(&spread.argument),
+            arg => arg.as_expression().is_some_and(is_simple_expr),
+        })
+    }
+
+    /// * `_callSuper(this, A, [args])`
+    /// * With `superIsCallableConstructor` assumption: `_B.call(this, args) || this`
+    ///
+    /// `this_binding` holds `this` of the constructor, if the call is in a nested function.
+    fn create_super_constructor_call(
+        arguments: ArenaVec<'a, Argument<'a>>,
+        span: Span,
+        this_binding: Option<&BoundIdentifier<'a>>,
+        bindings: &ClassBindings<'a>,
+        assumptions: ClassAssumptions,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let create_this = |ctx: &mut TraverseCtx<'a>| match this_binding {
+            Some(this_binding) => this_binding.create_read_expression(ctx),
+            None => ctx.ast.expression_this(SPAN),
+        };
+
+        if assumptions.super_is_callable_constructor
+            && let Some(super_binding) = &bindings.super_class
+        {
+            let super_class = super_binding.create_read_expression(ctx);
+            let this = Argument::from(create_this(ctx));
+            let call = if let Some(spread) = assumptions.spread
+                && arguments.iter().any(Argument::is_spread)
+            {
+                // `_B.apply(this, [a].concat(_toConsumableArray(b))) || this`
+                let callee = create_member_callee(super_class, "apply", SPAN, ctx);
+                let arguments = transform_arguments(arguments, spread, ctx);
+                let call_arguments = ctx.ast.vec_from_array([this, Argument::from(arguments)]);
+                ctx.ast.expression_call(span, callee, NONE, call_arguments, false)
+            } else {
+                // `_B.call(this, ...args) || this`
+                let callee = create_member_callee(super_class, "call", SPAN, ctx);
+                let mut call_arguments = ctx.ast.vec_with_capacity(arguments.len() + 1);
+                call_arguments.push(this);
+                call_arguments.extend(arguments);
+                ctx.ast.expression_call(span, callee, NONE, call_arguments, false)
+            };
+            return ctx.ast.expression_logical(SPAN, call, LogicalOperator::Or, create_this(ctx));
+        }
+
+        let mut call_arguments = ctx.ast.vec_with_capacity(3);
+        call_arguments.push(Argument::from(create_this(ctx)));
+        call_arguments.push(Argument::from(bindings.class.create_read_expression(ctx)));
+        match arguments.as_slice() {
+            [] => {}
+            // `super(...arguments)` -> `_callSuper(this, A, arguments)`
+            [Argument::SpreadElement(spread)] if matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments") =>
+            {
+                let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+                    unreachable!()
+                };
+                call_arguments.push(Argument::from(spread.unbox().argument));
+            }
+            _ => {
+                let has_spread = arguments.iter().any(Argument::is_spread);
+                let elements =
+                    ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+                let array = match assumptions.spread {
+                    // `[a, ...b]` -> `[a].concat(_toConsumableArray(b))`
+                    Some(spread) if has_spread => {
+                        transform_array_elements(elements, SPAN, spread, ctx)
+                    }
+                    _ => ctx.ast.expression_array(SPAN, elements),
+                };
+                call_arguments.push(Argument::from(array));
+            }
+        }
+        helper_call_expr(Helper::CallSuper, call_arguments, ctx)
+    }
+
+    /// Assign methods to prototype or class, for `setClassMethods` assumption.
+    ///
+    /// `A.prototype.foo = function foo() {}`
+    fn assign_methods(
+        methods: &mut Methods<'a>,
+        is_prototype: bool,
+        class_binding: &BoundIdentifier<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Accessors still need to be defined with `_createClass`
+        let (assignable, accessors) =
+            methods.list.drain(..).partition::<Vec<_>, _>(|method| method.value.is_some());
+        methods.list = accessors;
+        methods.accessors.clear();
+
+        for method in assignable {
+            let mut object = class_binding.create_read_expression(ctx);
+            if is_prototype {
+                object = create_prototype_member(object, SPAN, ctx);
+            }
+            let target = match method.key {
+                Expression::StringLiteral(lit) if is_identifier_name(&lit.value) => {
+                    let property = ctx.ast.identifier_name(lit.span, lit.value);
+                    ctx.ast.member_expression_static(SPAN, object, property, false)
+                }
+                key => ctx.ast.member_expression_computed(SPAN, object, key, false),
+            };
+            let assignment = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                method.value.unwrap(),
+            );
+            stmts.push(ctx.ast.statement_expression(SPAN, assignment));
+        }
+    }
+}
+
+/// Find class expression in `expr`, including when it was wrapped by class properties plugin.
+///
+/// * `class {}`
+/// * `_Class = class {}`
+/// * `(_Class = class {}, _Class.x = 1, _Class)`
+/// * `(_x = x(), class {})`
+fn find_class_expression<'a, 'e>(expr: &'e mut Expression<'a>) -> Option<&'e mut Expression<'a>> {
+    match expr {
+        Expression::ClassExpression(_) => Some(expr),
+        Expression::AssignmentExpression(assign)
+            if matches!(assign.right, Expression::ClassExpression(_)) =>
+        {
+            Some(&mut assign.right)
+        }
+        Expression::SequenceExpression(sequence) => {
+            sequence.expressions.iter_mut().find_map(|expr| match expr {
+                Expression::ClassExpression(_) => Some(expr),
+                Expression::AssignmentExpression(assign)
+                    if matches!(assign.right, Expression::ClassExpression(_)) =>
+                {
+                    Some(&mut assign.right)
+                }
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Bindings for class and super class.
+struct ClassBindings<'a> {
+    /// Binding for constructor function
+    class: BoundIdentifier<'a>,
+    /// Binding for super class (IIFE param)
+    super_class: Option<BoundIdentifier<'a>>,
+}
+
+/// Assumptions which alter the output of the transform.
+#[derive(Clone, Copy)]
+#[expect(clippy::struct_excessive_bools)]
+struct ClassAssumptions {
+    no_class_calls: bool,
+    set_class_methods: bool,
+    constant_super: bool,
+    super_is_callable_constructor: bool,
+    /// Lower spread arguments of `super()` calls with these spread transform options.
+    spread: Option<SpreadOptions>,
+}
+
+impl ClassAssumptions {
+    fn new(options: ClassesOptions, spread: Option<SpreadOptions>, ctx: &TraverseCtx<'_>) -> Self {
+        let assumptions = &ctx.state.assumptions;
+        Self {
+            no_class_calls: options.loose || assumptions.no_class_calls,
+            set_class_methods: options.loose || assumptions.set_class_methods,
+            constant_super: options.loose || assumptions.constant_super,
+            super_is_callable_constructor: options.loose
+                || assumptions.super_is_callable_constructor,
+            spread,
+        }
+    }
+}
+
+/// Property descriptor passed to `_createClass`.
+///
+/// `{ key: "foo", value: function foo() {} }` or `{ key: "foo", get: function () {}, set: function (v) {} }`
+struct MethodDescriptor<'a> {
+    key: Expression<'a>,
+    value: Option<Expression<'a>>,
+    get: Option<Expression<'a>>,
+    set: Option<Expression<'a>>,
+}
+
+/// Prototype or static methods of a class.
+#[derive(Default)]
+struct Methods<'a> {
+    list: Vec<MethodDescriptor<'a>>,
+    /// Indexes in `list` of accessors with static keys, so getter and setter can share a descriptor
+    accessors: FxHashMap<String, usize>,
+}
+
+impl<'a> Methods<'a> {
+    fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    fn add(
+        &mut self,
+        key_name: Option<String>,
+        key: Expression<'a>,
+        kind: MethodDefinitionKind,
+        func: Expression<'a>,
+    ) {
+        if kind == MethodDefinitionKind::Method {
+            // A later accessor with same key must not be merged with an earlier one
+            if let Some(key_name) = &key_name {
+                self.accessors.remove(key_name);
+            }
+            self.list.push(MethodDescriptor { key, value: Some(func), get: None, set: None });
+            return;
+        }
+
+        let index = if let Some(key_name) = key_name {
+            *self.accessors.entry(key_name).or_insert_with(|| {
+                self.list.push(MethodDescriptor { key, value: None, get: None, set: None });
+                self.list.len() - 1
+            })
+        } else {
+            self.list.push(MethodDescriptor { key, value: None, get: None, set: None });
+            self.list.len() - 1
+        };
+        let descriptor = &mut self.list[index];
+        if kind == MethodDefinitionKind::Get {
+            descriptor.get = Some(func);
+        } else {
+            descriptor.set = Some(func);
+        }
+    }
+
+    /// `[{ key: "foo", value: function foo() {} }, ...]`
+    fn into_array(self, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let elements = ctx.ast.vec_from_iter(self.list.into_iter().map(|descriptor| {
+            let mut properties = ctx.ast.vec_with_capacity(3);
+            let mut push_property = |name: &'static str, value: Expression<'a>| {
+                properties.push(ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_static_identifier(SPAN, name),
+                    value,
+                    false,
+                    false,
+                    false,
+                ));
+            };
+            push_property("key", descriptor.key);
+            if let Some(value) = descriptor.value {
+                push_property("value", value);
+            }
+            if let Some(get) = descriptor.get {
+                push_property("get", get);
+            }
+            if let Some(set) = descriptor.set {
+                push_property("set", set);
+            }
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        }));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MethodMode {
+    Constructor,
+    Prototype,
+    Static,
+}
+
+/// Visitor to convert `super`, `this` and `new.target` in a class method or constructor.
+///
+/// Nested functions have their own `this` / `new.target`, so only `super` is converted in them.
+/// `super` can only appear in nested functions which were arrow functions, before the arrow
+/// functions transform converted them. Their `this` has been replaced with a variable holding `this`
+/// of the method, so `super` in them is converted using such a variable too.
+/// Nested methods and accessors, which have their own `super`, are not entered.
+struct ClassMethodReplacer<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    bindings: &'v ClassBindings<'a>,
+    mode: MethodMode,
+    constant_super: bool,
+    spread: Option<SpreadOptions>,
+    /// Scope of method function
+    scope_id: ScopeId,
+    /// `_this` binding in derived class constructor
+    this_binding: Option<BoundIdentifier<'a>>,
+    /// `_this` binding holding `this` of the method, for `super` in nested functions
+    outer_this_binding: Option<BoundIdentifier<'a>>,
+    /// Temp vars to be declared at top of method
+    temp_vars: Vec<BoundIdentifier<'a>>,
+    /// Depth of arrow functions
+    arrow_depth: u32,
+    /// Depth of nested functions
+    function_depth: u32,
+}
+
+impl<'a, 'v> ClassMethodReplacer<'a, 'v> {
+    /// Transform method function.
+    ///
+    /// `is_derived` is `Some` for constructors.
+    fn transform_function(
+        func: &mut Function<'a>,
+        mode: MethodMode,
+        is_derived: Option<bool>,
+        bindings: &'v ClassBindings<'a>,
+        assumptions: ClassAssumptions,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) {
+        let scope_id = func.scope_id();
+        ctx.scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::Constructor | ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+
+        let is_derived = is_derived == Some(true);
+        let this_binding = is_derived
+            .then(|| ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable));
+
+        let body = func.body.as_mut().unwrap();
+        // `super()` called at top level of constructor, so `this` is always initialized at end
+        let super_is_called = body.statements.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::ExpressionStatement(stmt)
+                    if matches!(&stmt.expression, Expression::CallExpression(call) if call.callee.is_super())
+            )
+        });
+
+        let mut replacer = Self {
+            ctx,
+            bindings,
+            mode,
+            constant_super: assumptions.constant_super,
+            spread: assumptions.spread,
+            scope_id,
+            this_binding,
+            outer_this_binding: None,
+            temp_vars: vec![],
+            arrow_depth: 0,
+            function_depth: 0,
+        };
+        replacer.visit_formal_parameters(&mut func.params);
+        replacer.visit_function_body(body);
+
+        let Self { ctx, this_binding, outer_this_binding, temp_vars, .. } = replacer;
+
+        // Add `return _this;` to end of derived class constructor
+        if let Some(this_binding) = &this_binding
+            && !matches!(body.statements.last(), Some(Statement::ReturnStatement(_)))
+        {
+            let this = this_binding.create_read_expression(ctx);
+            let value = if super_is_called {
+                this
+            } else {
+                helper_call_expr(
+                    Helper::AssertThisInitialized,
+                    ctx.ast.vec1(Argument::from(this)),
+                    ctx,
+                )
+            };
+            body.statements.push(ctx.ast.statement_return(SPAN, Some(value)));
+        }
+
+        // `var _this, _this2 = this, _super$foo;`
+        let declarators = ctx.ast.vec_from_iter(
+            this_binding
+                .iter()
+                .map(|binding| (binding, false))
+                .chain(outer_this_binding.iter().map(|binding| (binding, true)))
+                .chain(temp_vars.iter().map(|binding| (binding, false)))
+                .map(|(binding, init_this)| {
+                    ctx.ast.variable_declarator(
+                        SPAN,
+                        VariableDeclarationKind::Var,
+                        binding.create_binding_pattern(ctx),
+                        NONE,
+                        init_this.then(|| ctx.ast.expression_this(SPAN)),
+                        false,
+                    )
+                }),
+        );
+        if !declarators.is_empty() {
+            let declaration = ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarators,
+                false,
+            );
+            body.statements.insert(0, Statement::from(declaration));
+        }
+    }
+
+    /// Receiver for super property access: `this`, or `_this` in derived class constructor.
+    fn create_receiver(&mut self) -> Expression<'a> {
+        match &self.this_binding {
+            Some(this_binding) => this_binding.create_read_expression(self.ctx),
+            None if self.function_depth > 0 => {
+                self.get_outer_this_binding().create_read_expression(self.ctx)
+            }
+            None => self.ctx.ast.expression_this(SPAN),
+        }
+    }
+
+    /// Binding holding `this` of the method, to use in nested functions.
+    /// Declared as `var _this = this` at top of method.
+    fn get_outer_this_binding(&mut self) -> BoundIdentifier<'a> {
+        if let Some(binding) = &self.outer_this_binding {
+            return binding.clone();
+        }
+        let binding =
+            self.ctx.generate_uid("this", self.scope_id, SymbolFlags::FunctionScopedVariable);
+        self.outer_this_binding = Some(binding.clone());
+        binding
+    }
+
+    /// For `constantSuper` assumption: `_B.prototype`, or `_B` in static methods.
+    fn create_constant_super_object(&mut self) -> Option<Expression<'a>> {
+        if !self.constant_super {
+            return None;
+        }
+        let super_binding = self.bindings.super_class.as_ref()?;
+        let object = super_binding.create_read_expression(self.ctx);
+        Some(if self.mode == MethodMode::Static {
+            object
+        } else {
+            create_prototype_member(object, SPAN, self.ctx)
+        })
+    }
+
+    /// Create a temp var, which will be declared at top of method.
+    fn create_temp_var(&mut self, name: &str) -> BoundIdentifier<'a> {
+        let binding =
+            self.ctx.generate_uid(name, self.scope_id, SymbolFlags::FunctionScopedVariable);
+        self.temp_vars.push(binding.clone());
+        binding
+    }
+
+    /// Duplicate super property key. The first returned `Expression` must be evaluated first.
+    ///
+    /// * Literal `"foo"` -> `"foo"`, `"foo"`
+    /// * Anything else `foo()` -> `_foo = foo()`, `_foo`
+    fn duplicate_key(&mut self, key: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if key.is_literal() {
+            let key2 = key.clone_in(self.ctx.ast.allocator);
+            return (key, key2);
+        }
+        let name = get_var_name_from_node(&key);
+        let binding = self.create_temp_var(&name);
+        let assignment = create_assignment(&binding, key, SPAN, self.ctx);
+        (assignment, binding.create_read_expression(self.ctx))
+    }
+
+    /// `super()` -> `_this = _callSuper(this, A, [args])`
+    fn transform_super_call(&mut self, expr: &mut Expression<'a>) {
+        let Expression::CallExpression(call) = expr.take_in(self.ctx.ast) else { unreachable!() };
+        let call = call.unbox();
+        let assumptions = ClassAssumptions {
+            no_class_calls: false,
+            set_class_methods: false,
+            constant_super: self.constant_super,
+            super_is_callable_constructor: self.constant_super,
+            spread: self.spread,
+        };
+        let outer_this_binding = (self.function_depth > 0).then(|| self.get_outer_this_binding());
+        let value = Classes::create_super_constructor_call(
+            call.arguments,
+            call.span,
+            outer_this_binding.as_ref(),
+            self.bindings,
+            assumptions,
+            self.ctx,
+        );
+        *expr = match &self.this_binding {
+            Some(this_binding) => create_assignment(this_binding, value, call.span, self.ctx),
+            None => value,
+        };
+    }
+
+    /// * `super.prop` -> `_superPropGet(A, "prop", this, 1)`
+    /// * `super.method` as callee -> `_superPropGet(A, "method", this, 3)`
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        is_callee: bool,
+    ) -> Expression<'a> {
+        let flags = u8::from(self.mode != MethodMode::Static) | (u8::from(is_callee) << 1);
+        let mut arguments = self.ctx.ast.vec_with_capacity(4);
+        arguments.push(Argument::from(self.bindings.class.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(self.create_receiver()));
+        if flags != 0 {
+            arguments.push(Argument::from(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(flags),
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        let mut call = helper_call_expr(Helper::SuperPropGet, arguments, self.ctx);
+        if let Expression::CallExpression(call) = &mut call {
+            call.span = span;
+        }
+        call
+    }
+
+    /// `_superPropSet(A, "prop", value, this, 1, 1)`
+    fn create_super_prop_set(
+        &mut self,
+        span: Span,
+        property: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = self.ctx.ast.vec_with_capacity(6);
+        arguments.push(Argument::from(self.bindings.class.create_read_expression(self.ctx)));
+        arguments.push(Argument::from(property));
+        arguments.push(Argument::from(value));
+        arguments.push(Argument::from(self.create_receiver()));
+        arguments.push(Argument::from(self.ctx.ast.expression_numeric_literal(
+            SPAN,
+            1.0,
+            None,
+            NumberBase::Decimal,
+        )));
+        if self.mode != MethodMode::Static {
+            arguments.push(Argument::from(self.ctx.ast.expression_numeric_literal(
+                SPAN,
+                1.0,
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        let mut call = helper_call_expr(Helper::SuperPropSet, arguments, self.ctx);
+        if let Expression::CallExpression(call) = &mut call {
+            call.span = span;
+        }
+        call
+    }
+
+    /// Take property of `super.prop` / `super[prop]` as an expression.
+    fn take_super_property(&self, member: &mut MemberExpression<'a>) -> Expression<'a> {
+        match member {
+            MemberExpression::StaticMemberExpression(member) => self
+                .ctx
+                .ast
+                .expression_string_literal(member.property.span, member.property.name, None),
+            MemberExpression::ComputedMemberExpression(member) => {
+                member.expression.take_in(self.ctx.ast)
+            }
+            MemberExpression::PrivateFieldExpression(_) => unreachable!(),
+        }
+    }
+
+    /// * `super.prop = value` -> `_superPropSet(A, "prop", value, this, 1, 1)`
+    /// * `super.prop += value`
+    ///   -> `_superPropSet(A, "prop", _superPropGet(A, "prop", this, 1) + value, this, 1, 1)`
+    /// * `super.prop &&= value`
+    ///   -> `_superPropGet(A, "prop", this, 1) && _superPropSet(A, "prop", value, this, 1, 1)`
+    fn transform_super_assignment(&mut self, expr: &mut Expression<'a>) {
+        let Expression::AssignmentExpression(assign) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, operator, left, right, .. } = assign.unbox();
+        let mut member = left.into_member_expression();
+        let property = self.take_super_property(&mut member);
+
+        *expr = if operator == AssignmentOperator::Assign {
+            self.create_super_prop_set(span, property, right)
+        } else {
+            let (property1, property2) = self.duplicate_key(property);
+            if let Some(operator) = operator.to_binary_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property2, false);
+                let value = self.ctx.ast.expression_binary(SPAN, get_call, operator, right);
+                self.create_super_prop_set(span, property1, value)
+            } else if let Some(operator) = operator.to_logical_operator() {
+                let get_call = self.create_super_prop_get(SPAN, property1, false);
+                let set_call = self.create_super_prop_set(SPAN, property2, right);
+                self.ctx.ast.expression_logical(span, get_call, operator, set_call)
+            } else {
+                unreachable!()
+            }
+        };
+    }
+
+    /// * `++super.prop`
+    ///   -> `_superPropSet(A, "prop", (_super$prop = _superPropGet(A, "prop", this, 1), ++_super$prop), this, 1, 1)`
+    /// * `super.prop++`
+    ///   -> `(_superPropSet(A, "prop", (_super$prop = _superPropGet(A, "prop", this, 1), _super$prop2 = _super$prop++, _super$prop), this, 1, 1), _super$prop2)`
+    fn transform_super_update(&mut self, expr: &mut Expression<'a>) {
+        let Expression::UpdateExpression(mut update) = expr.take_in(self.ctx.ast) else {
+            unreachable!()
+        };
+        let mut member = update.argument.take_in(self.ctx.ast).into_member_expression();
+        let temp_var_name = get_var_name_from_node(&member);
+        let property = self.take_super_property(&mut member);
+        let (property1, property2) = self.duplicate_key(property);
+
+        // `_super$prop = _superPropGet(A, "prop", this, 1)`
+        let get_call = self.create_super_prop_get(SPAN, property2, false);
+        let temp_binding = self.create_temp_var(&temp_var_name);
+        let assignment = create_assignment(&temp_binding, get_call, SPAN, self.ctx);
+
+        // `++_super$prop` / `_super$prop++`
+        let span = update.span;
+        update.span = SPAN;
+        update.argument = temp_binding.create_read_write_simple_target(self.ctx);
+        let prefix = update.prefix;
+        let update_expr = Expression::UpdateExpression(update);
+
+        *expr = if prefix {
+            let value = self
+                .ctx
+                .ast
+                .expression_sequence(SPAN, self.ctx.ast.vec_from_array([assignment, update_expr]));
+            self.create_super_prop_set(span, property1, value)
+        } else {
+            let temp_binding2 = self.create_temp_var(&temp_var_name);
+            let assignment2 = create_assignment(&temp_binding2, update_expr, SPAN, self.ctx);
+            let value = self.ctx.ast.expression_sequence(
+                SPAN,
+                self.ctx.ast.vec_from_array([
+                    assignment,
+                    assignment2,
+                    temp_binding.create_read_expression(self.ctx),
+                ]),
+            );
+            let set_call = self.create_super_prop_set(SPAN, property1, value);
+            self.ctx.ast.expression_sequence(
+                span,
+                self.ctx
+                    .ast
+                    .vec_from_array([set_call, temp_binding2.create_read_expression(self.ctx)]),
+            )
+        };
+    }
+
+    /// * Constructor: `new.target` -> `this.constructor`
+    /// * Method: `new.target` -> `void 0`
+    fn create_new_target(&self, span: Span) -> Expression<'a> {
+        if self.mode == MethodMode::Constructor {
+            let this = self.ctx.ast.expression_this(span);
+            create_property_access(span, this, "constructor", self.ctx)
+        } else {
+            self.ctx.ast.void_0(span)
+        }
+    }
+}
+
+fn is_super_member_target(target: &AssignmentTarget<'_>) -> bool {
+    match target {
+        AssignmentTarget::StaticMemberExpression(member) => member.object.is_super(),
+        AssignmentTarget::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
+
+fn is_super_member_simple_target(target: &SimpleAssignmentTarget<'_>) -> bool {
+    match target {
+        SimpleAssignmentTarget::StaticMemberExpression(member) => member.object.is_super(),
+        SimpleAssignmentTarget::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassMethodReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            // `this` -> `_this`
+            Expression::ThisExpression(this) => {
+                if self.function_depth == 0
+                    && let Some(this_binding) = &self.this_binding
+                {
+                    *expr = this_binding.create_spanned_read_expression(this.span, self.ctx);
+                }
+                return;
+            }
+            Expression::MetaProperty(meta)
+                if self.function_depth == 0
+                    && meta.meta.name == "new"
+                    && meta.property.name == "target" =>
+            {
+                *expr = self.create_new_target(meta.span);
+                return;
+            }
+            // `super()`
+            Expression::CallExpression(call) if call.callee.is_super() => {
+                for argument in &mut call.arguments {
+                    self.visit_argument(argument);
+                }
+                self.transform_super_call(expr);
+                return;
+            }
+            // `super.prop`
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                if let Some(object) = self.create_constant_super_object() {
+                    member.object = object;
+                } else {
+                    let span = member.span;
+                    let property = self.ctx.ast.expression_string_literal(
+                        member.property.span,
+                        member.property.name,
+                        None,
+                    );
+                    *expr = self.create_super_prop_get(span, property, false);
+                }
+                return;
+            }
+            // `super[prop]`
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.visit_expression(&mut member.expression);
+                if let Some(object) = self.create_constant_super_object() {
+                    member.object = object;
+                } else {
+                    let span = member.span;
+                    let property = member.expression.take_in(self.ctx.ast);
+                    *expr = self.create_super_prop_get(span, property, false);
+                }
+                return;
+            }
+            // `super.prop = value`, `super[prop] += value`
+            Expression::AssignmentExpression(assign) if is_super_member_target(&assign.left) => {
+                if let AssignmentTarget::ComputedMemberExpression(member) = &mut assign.left {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.visit_expression(&mut assign.right);
+                self.transform_super_assignment(expr);
+                return;
+            }
+            // `super.prop++`, `--super[prop]`
+            Expression::UpdateExpression(update)
+                if is_super_member_simple_target(&update.argument) =>
+            {
+                if let SimpleAssignmentTarget::ComputedMemberExpression(member) =
+                    &mut update.argument
+                {
+                    self.visit_expression(&mut member.expression);
+                }
+                self.transform_super_update(expr);
+                return;
+            }
+            _ => {}
+        }
+
+        walk_mut::walk_expression(self, expr);
+    }
+
+    /// * `super.method(args)` -> `_superPropGet(A, "method", this, 3)([args])`
+    /// * With `constantSuper` assumption: `_B.prototype.method.call(this, args)`
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        let Some(member) = call.callee.as_member_expression_mut() else {
+            walk_mut::walk_call_expression(self, call);
+            return;
+        };
+        if !member.object().is_super()
+            || matches!(member, MemberExpression::PrivateFieldExpression(_))
+        {
+            walk_mut::walk_call_expression(self, call);
+            return;
+        }
+
+        if let MemberExpression::ComputedMemberExpression(member) = member {
+            self.visit_expression(&mut member.expression);
+        }
+        for argument in &mut call.arguments {
+            self.visit_argument(argument);
+        }
+
+        if let Some(object) = self.create_constant_super_object() {
+            // `_B.prototype.method.call(this, args)`
+            let Some(member) = call.callee.as_member_expression_mut() else { unreachable!() };
+            *member.object_mut() = object;
+            let callee = call.callee.take_in(self.ctx.ast);
+            call.callee = create_member_callee(callee, "call", SPAN, self.ctx);
+            let receiver = self.create_receiver();
+            call.arguments.insert(0, Argument::from(receiver));
+            return;
+        }
+
+        // `_superPropGet(A, "method", this, 3)`
+        let span = call.callee.span();
+        let mut member = call.callee.take_in(self.ctx.ast).into_member_expression();
+        let property = self.take_super_property(&mut member);
+        call.callee = self.create_super_prop_get(span, property, true);
+
+        // `([args])`
+        let elements = self.ctx.ast.vec_from_iter(
+            call.arguments.take_in(self.ctx.ast).into_iter().map(|arg| match arg {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                arg => ArrayExpressionElement::from(arg.into_expression()),
+            }),
+        );
+        call.arguments =
+            self.ctx.ast.vec1(Argument::from(self.ctx.ast.expression_array(SPAN, elements)));
+    }
+
+    /// * `return;` -> `return _assertThisInitialized(_this);`
+    /// * `return x;` -> `return _possibleConstructorReturn(_this, x);`
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+
+        if self.arrow_depth > 0 || self.function_depth > 0 {
+            return;
+        }
+        let Some(this_binding) = &self.this_binding else { return };
+        let this = Argument::from(this_binding.create_read_expression(self.ctx));
+        stmt.argument = Some(match stmt.argument.take() {
+            Some(argument) => helper_call_expr(
+                Helper::PossibleConstructorReturn,
+                self.ctx.ast.vec_from_array([this, Argument::from(argument)]),
+                self.ctx,
+            ),
+            None => {
+                helper_call_expr(Helper::AssertThisInitialized, self.ctx.ast.vec1(this), self.ctx)
+            }
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        // Only `super` is converted in nested functions
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // Methods and accessors in object literals have their own `super`. Only visit computed keys.
+        if prop.method || prop.kind != PropertyKind::Init {
+            if prop.computed {
+                self.visit_property_key(&mut prop.key);
+            }
+            return;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_method_definition(&mut self, method: &mut MethodDefinition<'a>) {
+        // Methods of nested classes have their own `super`. Only visit computed keys.
+        if method.computed {
+            self.visit_property_key(&mut method.key);
+        }
+    }
+
+    #[inline]
+    fn visit_static_block(&mut self, _block: &mut StaticBlock<'a>) {
+        // Skip into static blocks
+    }
+
+    #[inline]
+    fn visit_ts_module_block(&mut self, _block: &mut TSModuleBlock<'a>) {
+        // Skip into TS namespaces
+    }
+
+    fn visit_property_definition(&mut self, prop: &mut PropertyDefinition<'a>) {
+        // Property values are in a different `this` context. Only visit computed keys.
+        if prop.computed {
+            self.visit_property_key(&mut prop.key);
+        }
+    }
+
+    fn visit_accessor_property(&mut self, prop: &mut AccessorProperty<'a>) {
+        // Property values are in a different `this` context. Only visit computed keys.
+        if prop.computed {
+            self.visit_property_key(&mut prop.key);
+        }
+    }
+}
+
+/// Visitor to re-point references to class name within a class declaration from the outer binding
+/// to the binding of the constructor function.
+struct ClassNameReferenceRebinder<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    outer_symbol_id: SymbolId,
+    inner_symbol_id: SymbolId,
+}
+
+impl<'a, 'v> ClassNameReferenceRebinder<'a, 'v> {
+    fn new(
+        outer_symbol_id: SymbolId,
+        inner_symbol_id: SymbolId,
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { ctx, outer_symbol_id, inner_symbol_id }
+    }
+}
+
+impl<'a> Visit<'a> for ClassNameReferenceRebinder<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        let reference = scoping.get_reference_mut(reference_id);
+        if reference.symbol_id() == Some(self.outer_symbol_id) {
+            reference.set_symbol_id(self.inner_symbol_id);
+            scoping.delete_resolved_reference(self.outer_symbol_id, reference_id);
+            scoping.add_resolved_reference(self.inner_symbol_id, reference_id);
+        }
+    }
+}
+
+/// Visitor to find if an identifier with a specific name is referenced.
+struct IdentifierNameFinder<'a> {
+    name: Ident<'a>,
+    found: bool,
+}
+
+impl<'a> IdentifierNameFinder<'a> {
+    fn contains(name: Ident<'a>, visit: impl FnOnce(&mut Self)) -> bool {
+        let mut finder = Self { name, found: false };
+        visit(&mut finder);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for IdentifierNameFinder<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.found = true;
+        }
+    }
+}
@@ -0,0 +1,1012 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns into plain property and element accesses.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: [c, d = 1], ...rest } = obj;
+//! [x, y] = [y, x];
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _ref;
+//! var a = obj.a,
+//!   _obj$b = babelHelpers.slicedToArray(obj.b, 2),
+//!   c = _obj$b[0],
+//!   _obj$b$ = _obj$b[1],
+//!   d = _obj$b$ === void 0 ? 1 : _obj$b$,
+//!   rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+//! _ref = babelHelpers.slicedToArray([y, x], 2), x = _ref[0], y = _ref[1];
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `iterableIsArray` assumption, so array patterns read elements by index
+//! instead of going through the iterator protocol.
+//!
+//! ## Implementation
+//!
+//! Every pattern is first normalized into a [`Pattern`] tree, regardless of whether it comes from
+//! a binding pattern or an assignment target. The tree is then flattened into a list of
+//! `target = value` pairs, creating temporary variables for values which are read more than once.
+//!
+//! * Variable declarations are lowered when exiting them, by replacing each destructuring declarator
+//!   with a list of declarators. Temporary variables are declared in the same declaration.
+//!   `export` declarations are converted to a plain declaration followed by `export { ... }`,
+//!   so that the temporary variables are not exported.
+//! * Assignment expressions are lowered into a sequence expression when exiting them.
+//!   Temporary variables are declared with `var` via [`VarDeclarationsStore`].
+//! * Parameters, catch clause params and `for-in` / `for-of` heads are replaced with a temporary
+//!   variable on entering them, and a declaration or assignment of the original pattern is inserted
+//!   at the start of the body. That declaration or assignment is then lowered when it is visited.
+//!
+//! [`VarDeclarationsStore`]: crate::common::var_declarations::VarDeclarationsStore
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment specification: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::{BoundNames, ToJsString, WithoutGlobalReferenceInformation};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::Ident;
+use oxc_traverse::{Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse};
+
+use crate::{
+    common::{
+        helper_loader::{Helper, helper_call_expr, helper_load},
+        var_declarations::VarDeclarationsStore,
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 destructuring transform.
+pub struct DestructuringOptions {
+    /// Enables `iterableIsArray` assumption.
+    pub loose: bool,
+}
+
+pub struct Destructuring {
+    options: DestructuringOptions,
+}
+
+impl Destructuring {
+    pub fn new(options: DestructuringOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring {
+    // `function f({ a }) {}` -> `function f(_ref) { var { a } = _ref; }`
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = &mut func.body else { return };
+        Self::transform_params(&mut func.params, &mut body.statements, scope_id, ctx);
+    }
+
+    // `({ a }) => a` -> `(_ref) => { var { a } = _ref; return a; }`
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !has_pattern_params(&arrow.params) {
+            return;
+        }
+        convert_arrow_expression_body_to_block(arrow, ctx);
+        let scope_id = arrow.scope_id();
+        Self::transform_params(&mut arrow.params, &mut arrow.body.statements, scope_id, ctx);
+    }
+
+    // `try {} catch ({ a }) {}` -> `try {} catch (_ref) { let { a } = _ref; }`
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_catch_clause(clause, ctx);
+    }
+
+    // `for (const [k, v] in obj);` -> `for (const _ref in obj) { const [k, v] = _ref; }`
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (const [k, v] of map);` -> `for (const _ref of map) { const [k, v] = _ref; }`
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `var { a, b } = obj` -> `var a = obj.a, b = obj.b`
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `export var { a } = obj` is handled in `exit_statement`
+        if matches!(ctx.parent(), Ancestor::ExportNamedDeclarationDeclaration(_)) {
+            return;
+        }
+        self.transform_variable_declaration(decl, ctx);
+    }
+
+    // `[a, b] = arr` -> `_arr = babelHelpers.slicedToArray(arr, 2), a = _arr[0], b = _arr[1]`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign_expr) = expr
+            && assign_expr.operator == AssignmentOperator::Assign
+            && assign_expr.left.is_assignment_target_pattern()
+        {
+            self.transform_assignment_expression(expr, ctx);
+        }
+    }
+
+    // `export var { a } = obj` -> `var a = obj.a; export { a };`
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Statement::ExportNamedDeclaration(export) = stmt
+            && let Some(Declaration::VariableDeclaration(decl)) = &export.declaration
+            && has_pattern_declarators(decl)
+        {
+            self.transform_export_variable_declaration(stmt, ctx);
+        }
+    }
+}
+
+impl<'a> Destructuring {
+    /// Lower all destructuring declarators of a variable declaration.
+    fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !has_pattern_declarators(decl) {
+            return;
+        }
+
+        let mut declarations = ctx.ast.vec_with_capacity(decl.declarations.len());
+        for mut declarator in decl.declarations.take_in(ctx.ast) {
+            // Declarators without an initializer are either plain bindings, or in a `for-in` / `for-of` head
+            // (which should already have been replaced).
+            let Some(init) = declarator.init.take() else {
+                declarations.push(declarator);
+                continue;
+            };
+            if declarator.id.is_binding_identifier() {
+                declarator.init = Some(init);
+                declarations.push(declarator);
+                continue;
+            }
+            self.transform_variable_declarator(declarator, init, &mut declarations, ctx);
+        }
+        decl.declarations = declarations;
+    }
+
+    /// Flatten `kind pattern = init` into `declarations`.
+    fn transform_variable_declarator(
+        &self,
+        declarator: VariableDeclarator<'a>,
+        init: Expression<'a>,
+        declarations: &mut ArenaVec<'a, VariableDeclarator<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = declarator.kind;
+
+        // Use the scope of the bound names for temporary variables, which is different for
+        // `for (var [x] = [];;);` and `for (let [x] = [];;);`.
+        let mut scope_id = ctx.current_scope_id();
+        let mut symbol_flags = kind_to_symbol_flags(kind);
+        let scoping = ctx.scoping();
+        declarator.id.bound_names(&mut |ident| {
+            let symbol_id = ident.symbol_id();
+            scope_id = scoping.symbol_scope_id(symbol_id);
+            symbol_flags.insert(scoping.symbol_flags(symbol_id));
+        });
+
+        let pattern = Pattern::from_binding_pattern(declarator.id);
+        let mut flattener =
+            Flattener::new(TempMode::Declaration { scope_id, symbol_flags }, &pattern, self, ctx);
+        flattener.flatten(pattern, init, ctx);
+
+        for (target, value) in flattener.assignments {
+            let id = match target {
+                Target::Binding(pattern) => pattern,
+                Target::Temp(binding) => binding.create_binding_pattern(ctx),
+                Target::None => {
+                    ctx.generate_uid("ref", scope_id, symbol_flags).create_binding_pattern(ctx)
+                }
+                Target::Assignment(_) => unreachable!(),
+            };
+            declarations.push(ctx.ast.variable_declarator(
+                id.span(),
+                kind,
+                id,
+                NONE,
+                Some(value),
+                false,
+            ));
+        }
+    }
+
+    /// `export var { a, b: [c] } = obj;` -> `var a = obj.a, ...; export { a, c };`
+    fn transform_export_variable_declaration(
+        &self,
+        stmt: &mut Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { unreachable!() };
+        let Some(Declaration::VariableDeclaration(mut decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+
+        let kind = ImportOrExportKind::Value;
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            let local = ModuleExportName::IdentifierReference(binding.create_read_reference(ctx));
+            let exported = ctx.ast.module_export_name_identifier_name(SPAN, binding.name);
+            specifiers.push(ctx.ast.export_specifier(SPAN, local, exported, kind));
+        });
+
+        self.transform_variable_declaration(&mut decl, ctx);
+
+        let export_stmt =
+            Statement::from(ctx.ast.module_declaration_export_named_declaration(
+                SPAN, None, specifiers, None, kind, NONE,
+            ));
+        let new_stmt = Statement::VariableDeclaration(decl);
+        ctx.state.statement_injector.move_insertions(stmt, &new_stmt);
+        ctx.state.statement_injector.insert_after(&new_stmt, export_stmt);
+        *stmt = new_stmt;
+    }
+
+    /// Lower `pattern = value` into a sequence expression.
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let value_is_used = !is_value_unused(ctx);
+
+        let Expression::AssignmentExpression(assign_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let assign_expr = assign_expr.unbox();
+
+        let pattern = Pattern::from_assignment_target(assign_expr.left, ctx);
+        let mut flattener = Flattener::new(TempMode::Assignment, &pattern, self, ctx);
+
+        // Keep a reference to the value if the result of the assignment is used.
+        // `x = [a, b] = arr` -> `x = (a = arr[0], b = arr[1], arr)`
+        let mut result = None;
+        let mut value = assign_expr.right;
+        if value_is_used {
+            let mut reference = flattener.reference(value, ctx);
+            value = reference.read(ctx);
+            result = Some(reference);
+        }
+        flattener.flatten(pattern, value, ctx);
+
+        let mut expressions = ctx.ast.vec_with_capacity(flattener.assignments.len() + 1);
+        for (target, value) in flattener.assignments {
+            let target = match target {
+                Target::Assignment(target) => AssignmentTarget::from(target),
+                Target::Temp(binding) => binding.create_write_target(ctx),
+                Target::None => {
+                    expressions.push(value);
+                    continue;
+                }
+                Target::Binding(_) => unreachable!(),
+            };
+            expressions.push(ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                target,
+                value,
+            ));
+        }
+        if let Some(mut reference) = result {
+            expressions.push(reference.read(ctx));
+        }
+
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(assign_expr.span, expressions)
+        };
+    }
+}
+
+impl<'a> Destructuring {
+    /// Replace destructured params with temporary variables, and declare the patterns in the body.
+    ///
+    /// `function f(a, { b }, ...[c]) {}` -> `function f(a, _ref, ..._ref2) { var { b } = _ref; var [c] = _ref2; }`
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let patterns = params
+            .items
+            .iter_mut()
+            .map(|param| &mut param.pattern)
+            .chain(params.rest.as_mut().map(|rest| &mut rest.rest.argument))
+            .filter(|pattern| !pattern.is_binding_identifier());
+
+        let mut declarations = vec![];
+        for pattern in patterns {
+            let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+            let pattern = mem::replace(pattern, binding.create_binding_pattern(ctx));
+            let init = binding.create_read_expression(ctx);
+            declarations.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                pattern,
+                init,
+                ctx,
+            ));
+        }
+        body.splice(0..0, declarations);
+    }
+
+    /// `try {} catch ({ a }) {}` -> `try {} catch (_ref) { let { a } = _ref; }`
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Bindings of the catch parameter live in the scope of the catch body.
+        let scope_id = clause.body.scope_id();
+        let Some(param) = &mut clause.param else { return };
+        if param.pattern.is_binding_identifier() {
+            return;
+        }
+
+        let flags = SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable;
+        let binding = ctx.generate_uid("ref", scope_id, flags);
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+
+        // The bound names are now declared by a `let` declaration in the catch body.
+        pattern.bound_names(&mut |ident| {
+            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                SymbolFlags::BlockScopedVariable;
+        });
+
+        let init = binding.create_read_expression(ctx);
+        let decl = create_variable_declaration(VariableDeclarationKind::Let, pattern, init, ctx);
+        clause.body.body.insert(0, decl);
+    }
+
+    /// Replace a destructuring `for-in` / `for-of` head with a temporary variable,
+    /// and destructure the temporary variable at the start of the loop body.
+    ///
+    /// * `for (const [a] of arr);` -> `for (const _ref of arr) { const [a] = _ref; }`
+    /// * `for ([a] of arr);` -> `for (var _ref of arr) { [a] = _ref; }`
+    fn transform_for_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let stmt = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let declarator = &mut decl.declarations[0];
+                if declarator.id.is_binding_identifier() {
+                    return;
+                }
+
+                let binding_scope_id =
+                    if kind.is_var() { ctx.current_hoist_scope_id() } else { scope_id };
+                let binding = ctx.generate_uid("ref", binding_scope_id, kind_to_symbol_flags(kind));
+                let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+
+                let body_scope_id = wrap_statement_in_block(body, scope_id, ctx);
+                // Move the lexical bindings from the loop head to the loop body.
+                if !kind.is_var() {
+                    pattern.bound_names(&mut |ident| {
+                        let scoping = ctx.scoping_mut();
+                        scoping.set_symbol_scope_id(ident.symbol_id(), body_scope_id);
+                        scoping.move_binding(scope_id, body_scope_id, ident.name);
+                    });
+                }
+
+                let init = binding.create_read_expression(ctx);
+                create_variable_declaration(kind, pattern, init, ctx)
+            }
+            left => {
+                if !left.to_assignment_target().is_assignment_target_pattern() {
+                    return;
+                }
+
+                let flags = SymbolFlags::FunctionScopedVariable;
+                let binding = ctx.generate_uid("ref", ctx.current_hoist_scope_id(), flags);
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    binding.create_binding_pattern(ctx),
+                    NONE,
+                    None,
+                    false,
+                );
+                *left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ));
+
+                wrap_statement_in_block(body, scope_id, ctx);
+                let value = binding.create_read_expression(ctx);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+        };
+
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, stmt);
+    }
+}
+
+/// How temporary variables are declared.
+#[derive(Debug, Clone, Copy)]
+enum TempMode {
+    /// In the variable declaration being lowered.
+    Declaration { scope_id: ScopeId, symbol_flags: SymbolFlags },
+    /// As a `var` in the enclosing function.
+    Assignment,
+}
+
+/// Destructuring pattern, normalized from either a [`BindingPattern`] or an [`AssignmentTarget`].
+enum Pattern<'a> {
+    /// `a` in `var a`, or `a` / `a.b` in `[a, a.b] = arr`.
+    Target(Target<'a>),
+    /// `{ a, b: c, ...rest }`
+    Object(Vec<(PropertyKey<'a>, Pattern<'a>)>, Option<Box<Pattern<'a>>>),
+    /// `[a, , b, ...rest]`
+    Array(Vec<Option<Pattern<'a>>>, Option<Box<Pattern<'a>>>),
+    /// `a = 1`
+    Default(Box<Pattern<'a>>, Expression<'a>),
+}
+
+/// Target of a flattened assignment.
+enum Target<'a> {
+    /// Binding identifier of a variable declaration.
+    Binding(BindingPattern<'a>),
+    /// Simple target of an assignment expression.
+    Assignment(SimpleAssignmentTarget<'a>),
+    /// Temporary variable.
+    Temp(BoundIdentifier<'a>),
+    /// Value is only evaluated.
+    None,
+}
+
+impl<'a> Pattern<'a> {
+    fn from_binding_pattern(pattern: BindingPattern<'a>) -> Self {
+        match pattern {
+            BindingPattern::BindingIdentifier(_) => Self::Target(Target::Binding(pattern)),
+            BindingPattern::ObjectPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let properties = pattern
+                    .properties
+                    .into_iter()
+                    .map(|property| (property.key, Self::from_binding_pattern(property.value)))
+                    .collect();
+                let rest = pattern
+                    .rest
+                    .map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Object(properties, rest)
+            }
+            BindingPattern::ArrayPattern(pattern) => {
+                let pattern = pattern.unbox();
+                let elements = pattern
+                    .elements
+                    .into_iter()
+                    .map(|element| element.map(Self::from_binding_pattern))
+                    .collect();
+                let rest = pattern
+                    .rest
+                    .map(|rest| Box::new(Self::from_binding_pattern(rest.unbox().argument)));
+                Self::Array(elements, rest)
+            }
+            BindingPattern::AssignmentPattern(pattern) => {
+                let pattern = pattern.unbox();
+                Self::Default(Box::new(Self::from_binding_pattern(pattern.left)), pattern.right)
+            }
+        }
+    }
+
+    fn from_assignment_target(target: AssignmentTarget<'a>, ctx: &TraverseCtx<'a>) -> Self {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(target) => {
+                let target = target.unbox();
+                let properties = target
+                    .properties
+                    .into_iter()
+                    .map(|property| match property {
+                        // `{ a = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                            let property = property.unbox();
+                            let key = ctx.ast.property_key_static_identifier(
+                                property.binding.span,
+                                property.binding.name,
+                            );
+                            let mut pattern = Self::Target(Target::Assignment(
+                                SimpleAssignmentTarget::AssignmentTargetIdentifier(
+                                    ctx.ast.alloc(property.binding),
+                                ),
+                            ));
+                            if let Some(init) = property.init {
+                                pattern = Self::Default(Box::new(pattern), init);
+                            }
+                            (key, pattern)
+                        }
+                        // `{ a: b = 1 }`
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let property = property.unbox();
+                            (
+                                property.name,
+                                Self::from_assignment_target_maybe_default(property.binding, ctx),
+                            )
+                        }
+                    })
+                    .collect();
+                let rest = target
+                    .rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.unbox().target, ctx)));
+                Self::Object(properties, rest)
+            }
+            AssignmentTarget::ArrayAssignmentTarget(target) => {
+                let target = target.unbox();
+                let elements = target
+                    .elements
+                    .into_iter()
+                    .map(|element| {
+                        element
+                            .map(|element| Self::from_assignment_target_maybe_default(element, ctx))
+                    })
+                    .collect();
+                let rest = target
+                    .rest
+                    .map(|rest| Box::new(Self::from_assignment_target(rest.unbox().target, ctx)));
+                Self::Array(elements, rest)
+            }
+            target => Self::Target(Target::Assignment(target.into_simple_assignment_target())),
+        }
+    }
+
+    fn from_assignment_target_maybe_default(
+        target: AssignmentTargetMaybeDefault<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                let target = target.unbox();
+                let pattern = Self::from_assignment_target(target.binding, ctx);
+                Self::Default(Box::new(pattern), target.init)
+            }
+            target => Self::from_assignment_target(target.into_assignment_target(), ctx),
+        }
+    }
+
+    /// Collect names of identifiers which are bound or assigned by this pattern.
+    fn collect_names(&self, names: &mut Vec<Ident<'a>>) {
+        match self {
+            Self::Target(Target::Binding(pattern)) => {
+                if let Some(ident) = pattern.get_binding_identifier() {
+                    names.push(ident.name);
+                }
+            }
+            Self::Target(Target::Assignment(
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+            )) => names.push(ident.name),
+            Self::Target(_) => {}
+            Self::Object(properties, rest) => {
+                for (_, pattern) in properties {
+                    pattern.collect_names(names);
+                }
+                if let Some(rest) = rest {
+                    rest.collect_names(names);
+                }
+            }
+            Self::Array(elements, rest) => {
+                for pattern in elements.iter().flatten() {
+                    pattern.collect_names(names);
+                }
+                if let Some(rest) = rest {
+                    rest.collect_names(names);
+                }
+            }
+            Self::Default(pattern, _) => pattern.collect_names(names),
+        }
+    }
+}
+
+/// Flattens a [`Pattern`] and a value into a list of `target = value` assignments.
+struct Flattener<'a> {
+    mode: TempMode,
+    /// Names assigned by the pattern. Identifier values with these names need to be copied
+    /// into a temporary variable before they can be read more than once.
+    names: Vec<Ident<'a>>,
+    iterable_is_array: bool,
+    assignments: Vec<(Target<'a>, Expression<'a>)>,
+}
+
+impl<'a> Flattener<'a> {
+    fn new(
+        mode: TempMode,
+        pattern: &Pattern<'a>,
+        destructuring: &Destructuring,
+        ctx: &TraverseCtx<'a>,
+    ) -> Self {
+        let mut names = vec![];
+        pattern.collect_names(&mut names);
+        let iterable_is_array =
+            destructuring.options.loose || ctx.state.assumptions.iterable_is_array;
+        Self { mode, names, iterable_is_array, assignments: vec![] }
+    }
+
+    fn flatten(&mut self, pattern: Pattern<'a>, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match pattern {
+            Pattern::Target(target) => self.assignments.push((target, value)),
+            Pattern::Default(pattern, default) => {
+                // `value === void 0 ? default : value`
+                let mut reference = self.reference(value, ctx);
+                let test = ctx.ast.expression_binary(
+                    SPAN,
+                    reference.read(ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let value =
+                    ctx.ast.expression_conditional(SPAN, test, default, reference.read(ctx));
+                self.flatten(*pattern, value, ctx);
+            }
+            Pattern::Object(properties, rest) => self.flatten_object(properties, rest, value, ctx),
+            Pattern::Array(elements, rest) => self.flatten_array(elements, rest, value, ctx),
+        }
+    }
+
+    fn flatten_object(
+        &mut self,
+        mut properties: Vec<(PropertyKey<'a>, Pattern<'a>)>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `{} = value` -> `babelHelpers.objectDestructuringEmpty(value)`
+        if properties.is_empty() && rest.is_none() {
+            let arguments = ctx.ast.vec1(Argument::from(value));
+            let check = helper_call_expr(Helper::ObjectDestructuringEmpty, arguments, ctx);
+            self.assignments.push((Target::None, check));
+            return;
+        }
+
+        // A single property reads the value only once: `{ a: [b] } = obj` -> `obj.a`
+        if properties.len() == 1 && rest.is_none() {
+            let (key, pattern) = properties.pop().unwrap();
+            let value = create_member_expression(value, key, ctx);
+            self.flatten(pattern, value, ctx);
+            return;
+        }
+
+        let mut reference = self.reference(value, ctx);
+
+        let Some(rest) = rest else {
+            for (key, pattern) in properties {
+                let value = create_member_expression(reference.read(ctx), key, ctx);
+                self.flatten(pattern, value, ctx);
+            }
+            return;
+        };
+
+        // Collect the keys to exclude from the rest object.
+        let mut keys = ctx.ast.vec_with_capacity(properties.len());
+        let mut all_primitives = true;
+        let has_no_properties = properties.is_empty();
+        for (mut key, pattern) in properties {
+            keys.push(self.transform_rest_excluded_key(&mut key, &mut all_primitives, ctx));
+            let value = create_member_expression(reference.read(ctx), key, ctx);
+            self.flatten(pattern, value, ctx);
+        }
+
+        let value = if has_no_properties {
+            // `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(ref), ref))`
+            let check = helper_call_expr(
+                Helper::ObjectDestructuringEmpty,
+                ctx.ast.vec1(Argument::from(reference.read(ctx))),
+                ctx,
+            );
+            let sequence = ctx
+                .ast
+                .expression_sequence(SPAN, ctx.ast.vec_from_array([check, reference.read(ctx)]));
+            let object = ctx.ast.expression_object(SPAN, ctx.ast.vec());
+            let arguments =
+                ctx.ast.vec_from_array([Argument::from(object), Argument::from(sequence)]);
+            helper_call_expr(Helper::Extends, arguments, ctx)
+        } else {
+            // `babelHelpers.objectWithoutProperties(ref, ["a", "b"])`
+            let mut keys = ctx.ast.expression_array(SPAN, keys);
+            if !all_primitives {
+                // `[a, b].map(babelHelpers.toPropertyKey)`
+                let callee = create_member_callee(keys, "map", SPAN, ctx);
+                let arguments =
+                    ctx.ast.vec1(Argument::from(helper_load(Helper::ToPropertyKey, ctx)));
+                keys = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            }
+            let arguments =
+                ctx.ast.vec_from_array([Argument::from(reference.read(ctx)), Argument::from(keys)]);
+            helper_call_expr(Helper::ObjectWithoutProperties, arguments, ctx)
+        };
+        self.flatten(*rest, value, ctx);
+    }
+
+    /// Get the element of the excluded keys array for the given property key of an object pattern with rest.
+    ///
+    /// Keys which are not literals are moved into a temporary variable, since they are used twice.
+    fn transform_rest_excluded_key(
+        &mut self,
+        key: &mut PropertyKey<'a>,
+        all_primitives: &mut bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArrayExpressionElement<'a> {
+        let key_expr = match key {
+            // `{ a, ...rest }`
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            PropertyKey::PrivateIdentifier(_) => {
+                unreachable!("Private names are not allowed in patterns")
+            }
+            // `{ "a": a, 1: b, ...rest }`
+            key if key.as_expression().is_some_and(Expression::is_literal) => {
+                let expr = key.to_expression();
+                let s = expr.to_js_string(&WithoutGlobalReferenceInformation {}).unwrap();
+                ctx.ast.expression_string_literal(expr.span(), ctx.ast.str_from_cow(&s), None)
+            }
+            // `{ [a]: b, ...rest }`
+            key => {
+                *all_primitives = false;
+                let expr = key.to_expression_mut();
+                let mut reference = self.reference(expr.take_in(ctx.ast), ctx);
+                *expr = reference.read(ctx);
+                reference.read(ctx)
+            }
+        };
+        ArrayExpressionElement::from(key_expr)
+    }
+
+    fn flatten_array(
+        &mut self,
+        elements: Vec<Option<Pattern<'a>>>,
+        rest: Option<Box<Pattern<'a>>>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut reference = if self.iterable_is_array {
+            if elements.is_empty() && rest.is_none() {
+                self.assignments.push((Target::None, value));
+                return;
+            }
+            self.reference(value, ctx)
+        } else {
+            // * `[a, b] = value` -> `babelHelpers.slicedToArray(value, 2)`
+            // * `[a, ...b] = value` -> `babelHelpers.toArray(value)`
+            let binding = self.create_temp(&value, ctx);
+            let value = if rest.is_some() {
+                helper_call_expr(Helper::ToArray, ctx.ast.vec1(Argument::from(value)), ctx)
+            } else {
+                let len = create_number(elements.len(), ctx);
+                let arguments =
+                    ctx.ast.vec_from_array([Argument::from(value), Argument::from(len)]);
+                helper_call_expr(Helper::SlicedToArray, arguments, ctx)
+            };
+            self.assignments.push((Target::Temp(binding.clone()), value));
+            Reference::from_binding(&binding)
+        };
+
+        let len = elements.len();
+        for (index, element) in elements.into_iter().enumerate() {
+            let Some(pattern) = element else { continue };
+            // `ref[index]`
+            let index = create_number(index, ctx);
+            let value = Expression::from(ctx.ast.member_expression_computed(
+                SPAN,
+                reference.read(ctx),
+                index,
+                false,
+            ));
+            self.flatten(pattern, value, ctx);
+        }
+
+        if let Some(rest) = rest {
+            // `ref.slice(len)`
+            let callee = create_member_callee(reference.read(ctx), "slice", SPAN, ctx);
+            let start = create_number(len, ctx);
+            let value = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(start)),
+                false,
+            );
+            self.flatten(*rest, value, ctx);
+        }
+    }
+
+    /// Get a [`Reference`] to `value` which can be read multiple times.
+    ///
+    /// Identifiers which are not assigned by the pattern are read directly,
+    /// anything else is stored in a temporary variable first.
+    fn reference(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Reference<'a> {
+        if let Expression::Identifier(ident) = &value
+            && !self.names.contains(&ident.name)
+        {
+            let identifier = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+            return Reference { expr: Some(value), identifier };
+        }
+        let binding = self.create_temp(&value, ctx);
+        self.assignments.push((Target::Temp(binding.clone()), value));
+        Reference::from_binding(&binding)
+    }
+
+    fn create_temp(&self, node: &Expression<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        match self.mode {
+            TempMode::Declaration { scope_id, symbol_flags } => {
+                ctx.generate_uid_based_on_node(node, scope_id, symbol_flags)
+            }
+            TempMode::Assignment => VarDeclarationsStore::create_uid_var_based_on_node(node, ctx),
+        }
+    }
+}
+
+/// A value which can be read multiple times.
+///
+/// The first read reuses the original expression.
+struct Reference<'a> {
+    expr: Option<Expression<'a>>,
+    identifier: MaybeBoundIdentifier<'a>,
+}
+
+impl<'a> Reference<'a> {
+    fn from_binding(binding: &BoundIdentifier<'a>) -> Self {
+        Self { expr: None, identifier: binding.to_maybe_bound_identifier() }
+    }
+
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.expr.take().unwrap_or_else(|| self.identifier.create_read_expression(ctx))
+    }
+}
+
+/// `object.key` or `object[key]`.
+fn create_member_expression<'a>(
+    object: Expression<'a>,
+    key: PropertyKey<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let member = match key {
+        PropertyKey::StaticIdentifier(ident) => {
+            let property = ctx.ast.identifier_name(ident.span, ident.name);
+            ctx.ast.member_expression_static(SPAN, object, property, false)
+        }
+        key => ctx.ast.member_expression_computed(SPAN, object, key.into_expression(), false),
+    };
+    Expression::from(member)
+}
+
+/// `kind pattern = init;`
+pub(super) fn create_variable_declaration<'a>(
+    kind: VariableDeclarationKind,
+    pattern: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Wrap `stmt` in a block statement if it is not one already, and return the block's scope.
+pub(super) fn wrap_statement_in_block<'a>(
+    stmt: &mut Statement<'a>,
+    parent_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> ScopeId {
+    if let Statement::BlockStatement(block) = stmt {
+        return block.scope_id();
+    }
+    let scope_id =
+        ctx.insert_scope_below_statement_from_scope_id(stmt, parent_scope_id, ScopeFlags::empty());
+    let span = stmt.span();
+    let body = if matches!(stmt, Statement::EmptyStatement(_)) {
+        ctx.ast.vec()
+    } else {
+        ctx.ast.vec1(stmt.take_in(ctx.ast))
+    };
+    *stmt = ctx.ast.statement_block_with_scope_id(span, body, scope_id);
+    scope_id
+}
+
+/// Convert `() => x` to `() => { return x; }`, so that statements can be inserted into the body.
+pub(super) fn convert_arrow_expression_body_to_block<'a>(
+    arrow: &mut ArrowFunctionExpression<'a>,
+    ctx: &TraverseCtx<'a>,
+) {
+    if !arrow.expression {
+        return;
+    }
+    arrow.expression = false;
+    let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() else {
+        unreachable!(
+            "`arrow.expression` is true, which means it has only one ExpressionStatement."
+        );
+    };
+    let stmt = stmt.unbox();
+    arrow.body.statements.push(ctx.ast.statement_return(stmt.span, Some(stmt.expression)));
+}
+
+fn has_pattern_params(params: &FormalParameters) -> bool {
+    params.items.iter().any(|param| !param.pattern.is_binding_identifier())
+        || params.rest.as_ref().is_some_and(|rest| !rest.rest.argument.is_binding_identifier())
+}
+
+fn has_pattern_declarators(decl: &VariableDeclaration) -> bool {
+    decl.declarations
+        .iter()
+        .any(|declarator| declarator.init.is_some() && !declarator.id.is_binding_identifier())
+}
+
+/// Whether the value of the expression being exited is discarded.
+fn is_value_unused(ctx: &TraverseCtx) -> bool {
+    let mut ancestors = ctx
+        .ancestors()
+        .skip_while(|ancestor| matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)));
+    match ancestors.next() {
+        // `() => [a] = b` returns the value
+        Some(Ancestor::ExpressionStatementExpression(_)) => !matches!(
+            (ancestors.next(), ancestors.next()),
+            (
+                Some(Ancestor::FunctionBodyStatements(_)),
+                Some(Ancestor::ArrowFunctionExpressionBody(arrow)),
+            ) if *arrow.expression()
+        ),
+        Some(Ancestor::ForStatementUpdate(_)) => true,
+        _ => false,
+    }
+}
+
+fn kind_to_symbol_flags(kind: VariableDeclarationKind) -> SymbolFlags {
+    match kind {
+        VariableDeclarationKind::Var => SymbolFlags::FunctionScopedVariable,
+        VariableDeclarationKind::Let
+        | VariableDeclarationKind::Using
+        | VariableDeclarationKind::AwaitUsing => SymbolFlags::BlockScopedVariable,
+        VariableDeclarationKind::Const => {
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+        }
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+pub(super) fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
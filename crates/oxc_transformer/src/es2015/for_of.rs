@@ -0,0 +1,594 @@
+//! ES2015: For Of
+//!
+//! This plugin transforms `for...of` loops.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (const x of xs) {
+//!   f(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     const x = _step.value;
+//!     f(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! Loops over an array literal are always transformed to an indexed loop:
+//!
+//! ```js
+//! for (var _i = 0, _arr = [1, 2]; _i < _arr.length; _i++) {
+//!   const x = _arr[_i];
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `skipForOfIteratorClosing` assumption. The iterator is then not closed
+//! when the loop is exited early with `break`, `return` or `throw`:
+//!
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) {
+//!   const x = _step.value;
+//!   f(x);
+//! }
+//! ```
+//!
+//! ### `assumeArray`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `iterableIsArray` assumption. All loops are then transformed to indexed loops:
+//!
+//! ```js
+//! for (let _i = 0; _i < xs.length; _i++) {
+//!   const x = xs[_i];
+//!   f(x);
+//! }
+//! ```
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `arrayLikeIsIterable` assumption, allowing loops over array-like objects
+//! (with a `length` property, but not iterable).
+//!
+//! ## Implementation
+//!
+//! Lexical bindings of the loop head are moved into the loop body. If the body block declares
+//! a binding of the same name, it is nested in a new block instead.
+//!
+//! `for await` loops are transformed by the async generator functions transform.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * For-of specification: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_str::Ident;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+use super::destructuring::{create_number, wrap_statement_in_block};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 for-of transform.
+pub struct ForOfOptions {
+    /// Enables `skipForOfIteratorClosing` assumption.
+    pub loose: bool,
+    /// Enables `iterableIsArray` assumption.
+    pub assume_array: bool,
+    /// Enables `arrayLikeIsIterable` assumption.
+    pub allow_array_like: bool,
+}
+
+pub struct ForOf {
+    options: ForOfOptions,
+}
+
+impl ForOf {
+    pub fn new(options: ForOfOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ForOf {
+    // `for (const x of xs) {}` -> `for (var _iterator = ..., _step; ...) { const x = _step.value; }`
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_for_of = |stmt: &Statement<'a>| matches!(stmt, Statement::ForOfStatement(for_of) if !for_of.r#await);
+        // Labeled loops are transformed together with their label, so the label stays on the loop.
+        let is_transformable = match stmt {
+            Statement::LabeledStatement(labeled) => is_for_of(&labeled.body),
+            _ => is_for_of(stmt) && !matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)),
+        };
+        if is_transformable {
+            self.transform_statement(stmt, ctx);
+        }
+    }
+}
+
+impl<'a> ForOf {
+    fn transform_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let (for_of, label) = match stmt.take_in(ctx.ast) {
+            Statement::ForOfStatement(for_of) => (for_of.unbox(), None),
+            Statement::LabeledStatement(labeled) => {
+                let LabeledStatement { span, label, body, .. } = labeled.unbox();
+                let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+                (for_of.unbox(), Some((span, label)))
+            }
+            _ => unreachable!(),
+        };
+        let wrap_in_label = |body: Statement<'a>, ctx: &TraverseCtx<'a>| match label {
+            Some((span, label)) => ctx.ast.statement_labeled(span, label, body),
+            None => body,
+        };
+
+        if self.options.assume_array || ctx.state.assumptions.iterable_is_array {
+            *stmt = wrap_in_label(Self::build_assume_array_loop(for_of, ctx), ctx);
+        } else if matches!(for_of.right, Expression::ArrayExpression(_)) {
+            *stmt = wrap_in_label(Self::build_array_loop(for_of, ctx), ctx);
+        } else if self.options.loose || ctx.state.assumptions.skip_for_of_iterator_closing {
+            let for_stmt = self.build_iterator_loop_without_closing(for_of, ctx);
+            *stmt = wrap_in_label(for_stmt, ctx);
+        } else {
+            let allow_multiple_statements = is_multiple_statements_allowed(ctx);
+            let parent_scope_id = if allow_multiple_statements {
+                ctx.current_scope_id()
+            } else {
+                ctx.create_child_scope_of_current(ScopeFlags::empty())
+            };
+            let (declaration, try_stmt) =
+                self.build_iterator_loop(for_of, parent_scope_id, wrap_in_label, ctx);
+            if allow_multiple_statements {
+                ctx.state.statement_injector.insert_before(&try_stmt, declaration);
+                *stmt = try_stmt;
+            } else {
+                // `if (x) for (const a of b);` -> `if (x) { var _iterator = ...; try { ... } }`
+                let body = ctx.ast.vec_from_array([declaration, try_stmt]);
+                *stmt = ctx.ast.statement_block_with_scope_id(SPAN, body, parent_scope_id);
+            }
+        }
+    }
+
+    /// `for (let _i = 0; _i < xs.length; _i++) { const x = xs[_i]; }`
+    ///
+    /// `xs` is stored in a temp var `_xs`, unless it's `this` or a constant binding:
+    /// `for (let _i = 0, _xs = xs(); _i < _xs.length; _i++) {}`
+    fn build_assume_array_loop(
+        for_of: ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let ForOfStatement { span, left, right, body, scope_id, .. } = for_of;
+        let scope_id = scope_id.get().unwrap();
+        let flags = SymbolFlags::BlockScopedVariable;
+        let index = ctx.generate_uid("i", scope_id, flags);
+
+        let mut declarators = ctx.ast.vec_with_capacity(2);
+        declarators.push(create_declarator(
+            VariableDeclarationKind::Let,
+            &index,
+            Some(create_number(0, ctx)),
+            ctx,
+        ));
+        let (array, array2) = if let Some(array2) = duplicate_static_array(&right, &body, ctx) {
+            (right, array2)
+        } else {
+            let array = ctx.generate_uid_based_on_node(&right, scope_id, flags);
+            declarators.push(create_declarator(
+                VariableDeclarationKind::Let,
+                &array,
+                Some(right),
+                ctx,
+            ));
+            (array.create_read_expression(ctx), array.create_read_expression(ctx))
+        };
+
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Let,
+            declarators,
+            false,
+        );
+        let length = create_member_callee(array, "length", SPAN, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array2,
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        let body = build_loop_body(left, value, body, scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            scope_id,
+        )
+    }
+
+    /// `for (var _i = 0, _arr = [a, b]; _i < _arr.length; _i++) { const x = _arr[_i]; }`
+    fn build_array_loop(for_of: ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let ForOfStatement { span, left, right, body, scope_id, .. } = for_of;
+        let scope_id = scope_id.get().unwrap();
+        let array = ctx.generate_uid_in_current_hoist_scope("arr");
+        let index = ctx.generate_uid_in_current_hoist_scope("i");
+
+        let kind = VariableDeclarationKind::Var;
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec_from_array([
+                create_declarator(kind, &index, Some(create_number(0, ctx)), ctx),
+                create_declarator(kind, &array, Some(right), ctx),
+            ]),
+            false,
+        );
+        let length = create_member_callee(array.create_read_expression(ctx), "length", SPAN, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        let body = build_loop_body(left, value, body, scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(
+            span,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            scope_id,
+        )
+    }
+
+    /// `for (var _iterator = _createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) {}`
+    fn build_iterator_loop_without_closing(
+        &self,
+        for_of: ForOfStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let ForOfStatement { span, left, right, body, scope_id, .. } = for_of;
+        let scope_id = scope_id.get().unwrap();
+        let iterator = ctx.generate_uid_in_current_hoist_scope("iterator");
+        let step = ctx.generate_uid_in_current_hoist_scope("step");
+
+        let kind = VariableDeclarationKind::Var;
+        let helper =
+            self.create_iterator_helper_call(Helper::CreateForOfIteratorHelperLoose, right, ctx);
+        let init = ctx.ast.for_statement_init_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec_from_array([
+                create_declarator(kind, &iterator, Some(helper), ctx),
+                create_declarator(kind, &step, None, ctx),
+            ]),
+            false,
+        );
+        // `_iterator()`
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = create_step_test(&step, next, ctx);
+        let value = create_member_callee(step.create_read_expression(ctx), "value", SPAN, ctx);
+        let body = build_loop_body(left, value, body, scope_id, ctx);
+        ctx.ast.statement_for_with_scope_id(span, Some(init), Some(test), None, body, scope_id)
+    }
+
+    /// Build the loop and the declaration of its iterator.
+    ///
+    /// ```js
+    /// var _iterator = _createForOfIteratorHelper(xs), _step;
+    /// try {
+    ///   for (_iterator.s(); !(_step = _iterator.n()).done;) {}
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn build_iterator_loop(
+        &self,
+        for_of: ForOfStatement<'a>,
+        parent_scope_id: ScopeId,
+        wrap_in_label: impl FnOnce(Statement<'a>, &TraverseCtx<'a>) -> Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, Statement<'a>) {
+        let ForOfStatement { span, left, right, body, scope_id, .. } = for_of;
+        let scope_id = scope_id.get().unwrap();
+        let iterator = ctx.generate_uid_in_current_hoist_scope("iterator");
+        let step = ctx.generate_uid_in_current_hoist_scope("step");
+
+        // `xs` is evaluated before the `try` statement, outside of the loop scope
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(parent_scope_id));
+        ctx.remove_scope_for_expression(scope_id, &right);
+
+        let kind = VariableDeclarationKind::Var;
+        let helper =
+            self.create_iterator_helper_call(Helper::CreateForOfIteratorHelper, right, ctx);
+        let declaration = Statement::from(ctx.ast.declaration_variable(
+            SPAN,
+            kind,
+            ctx.ast.vec_from_array([
+                create_declarator(kind, &iterator, Some(helper), ctx),
+                create_declarator(kind, &step, None, ctx),
+            ]),
+            false,
+        ));
+
+        let try_block_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        ctx.scoping_mut().change_scope_parent_id(scope_id, Some(try_block_scope_id));
+        // `_iterator.s()`
+        let init = create_iterator_method_call(&iterator, "s", None, ctx);
+        // `!(_step = _iterator.n()).done`
+        let next = create_iterator_method_call(&iterator, "n", None, ctx);
+        let test = create_step_test(&step, next, ctx);
+        let value = create_member_callee(step.create_read_expression(ctx), "value", SPAN, ctx);
+        let body = build_loop_body(left, value, body, scope_id, ctx);
+        let for_stmt = ctx.ast.statement_for_with_scope_id(
+            span,
+            Some(ForStatementInit::from(init)),
+            Some(test),
+            None,
+            body,
+            scope_id,
+        );
+        let block = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(wrap_in_label(for_stmt, ctx)),
+            try_block_scope_id,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        let handler = {
+            let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+            let block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+            let err = ctx.generate_binding(
+                ctx.ast.ident("err"),
+                block_scope_id,
+                SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+            );
+            let param = ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx), NONE);
+            let call = create_iterator_method_call(
+                &iterator,
+                "e",
+                Some(err.create_read_expression(ctx)),
+                ctx,
+            );
+            let body = ctx.ast.block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+                block_scope_id,
+            );
+            ctx.ast.catch_clause_with_scope_id(SPAN, Some(param), body, catch_scope_id)
+        };
+
+        // `finally { _iterator.f(); }`
+        let finalizer = {
+            let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+            let call = create_iterator_method_call(&iterator, "f", None, ctx);
+            ctx.ast.block_statement_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+                scope_id,
+            )
+        };
+
+        let try_stmt = ctx.ast.statement_try(span, block, Some(handler), Some(finalizer));
+        (declaration, try_stmt)
+    }
+
+    /// `_createForOfIteratorHelper(xs)`, or `_createForOfIteratorHelper(xs, true)`
+    /// with `arrayLikeIsIterable` assumption.
+    fn create_iterator_helper_call(
+        &self,
+        helper: Helper,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(right));
+        if self.options.allow_array_like || ctx.state.assumptions.array_like_is_iterable {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        helper_call_expr(helper, arguments, ctx)
+    }
+}
+
+/// Check the parent node to see if multiple statements are allowed.
+fn is_multiple_statements_allowed(ctx: &TraverseCtx<'_>) -> bool {
+    matches!(
+        ctx.parent(),
+        Ancestor::ProgramBody(_)
+            | Ancestor::FunctionBodyStatements(_)
+            | Ancestor::BlockStatementBody(_)
+            | Ancestor::SwitchCaseConsequent(_)
+            | Ancestor::StaticBlockBody(_)
+            | Ancestor::TSModuleBlockBody(_)
+    )
+}
+
+/// Create a second reference to the iterated array, if it's `this` or a constant binding
+/// which is not shadowed in the loop body.
+fn duplicate_static_array<'a>(
+    right: &Expression<'a>,
+    body: &Statement<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<Expression<'a>> {
+    match right {
+        Expression::ThisExpression(this) => Some(ctx.ast.expression_this(this.span)),
+        Expression::Identifier(ident) => {
+            if let Statement::BlockStatement(block) = body
+                && ctx.scoping().scope_has_binding(block.scope_id(), ident.name)
+            {
+                return None;
+            }
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+            if ctx.scoping().symbol_is_mutated(symbol_id) {
+                return None;
+            }
+            let binding = BoundIdentifier::new(ident.name, symbol_id);
+            Some(binding.create_spanned_read_expression(ident.span, ctx))
+        }
+        _ => None,
+    }
+}
+
+/// Create the loop body, with `left = value` as its first statement.
+///
+/// Lexical bindings of `left` are moved from the loop scope to the body's scope.
+fn build_loop_body<'a>(
+    left: ForStatementLeft<'a>,
+    value: Expression<'a>,
+    mut body: Statement<'a>,
+    loop_scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let mut bindings: Vec<Ident<'a>> = vec![];
+    if let ForStatementLeft::VariableDeclaration(decl) = &left
+        && !decl.kind.is_var()
+    {
+        decl.bound_names(&mut |ident| bindings.push(ident.name));
+    }
+
+    let assignment = match left {
+        ForStatementLeft::VariableDeclaration(mut decl) => {
+            decl.declarations[0].init = Some(value);
+            Statement::VariableDeclaration(decl)
+        }
+        left => {
+            let target = left.into_assignment_target();
+            let expr =
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+            ctx.ast.statement_expression(SPAN, expr)
+        }
+    };
+
+    // `for (const x of xs) { const x = 1; }` -> `{ const x = _step.value; { const x = 1; } }`
+    let has_conflict = matches!(&body, Statement::BlockStatement(block)
+        if bindings.iter().any(|&name| ctx.scoping().scope_has_binding(block.scope_id(), name)));
+    let body_scope_id = if has_conflict {
+        let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+            &body,
+            loop_scope_id,
+            ScopeFlags::empty(),
+        );
+        let span = body.span();
+        let stmts = ctx.ast.vec_from_array([assignment, body]);
+        body = ctx.ast.statement_block_with_scope_id(span, stmts, scope_id);
+        scope_id
+    } else {
+        let scope_id = wrap_statement_in_block(&mut body, loop_scope_id, ctx);
+        let Statement::BlockStatement(block) = &mut body else { unreachable!() };
+        block.body.insert(0, assignment);
+        scope_id
+    };
+
+    for name in bindings {
+        let scoping = ctx.scoping_mut();
+        if let Some(symbol_id) = scoping.get_binding(loop_scope_id, name) {
+            scoping.set_symbol_scope_id(symbol_id, body_scope_id);
+            scoping.move_binding(loop_scope_id, body_scope_id, name);
+        }
+    }
+    body
+}
+
+/// `var binding = init`
+fn create_declarator<'a>(
+    kind: VariableDeclarationKind,
+    binding: &BoundIdentifier<'a>,
+    init: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> VariableDeclarator<'a> {
+    ctx.ast.variable_declarator(SPAN, kind, binding.create_binding_pattern(ctx), NONE, init, false)
+}
+
+/// `!(_step = next).done`
+fn create_step_test<'a>(
+    step: &BoundIdentifier<'a>,
+    next: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        step.create_write_target(ctx),
+        next,
+    );
+    let done =
+        create_member_callee(ctx.ast.expression_parenthesized(SPAN, assignment), "done", SPAN, ctx);
+    ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+}
+
+/// `_iterator.method(argument)`
+fn create_iterator_method_call<'a>(
+    iterator: &BoundIdentifier<'a>,
+    method: &'static str,
+    argument: Option<Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = create_member_callee(iterator.create_read_expression(ctx), method, SPAN, ctx);
+    let arguments = ctx.ast.vec_from_iter(argument.map(Argument::from));
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
@@ -1,12 +1,30 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::state::TransformState;
+use crate::{context::TraverseCtx, state::TransformState};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod destructuring;
+mod for_of;
 mod options;
+mod parameters;
+mod regenerator;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::{Classes, ClassesOptions};
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use for_of::{ForOf, ForOfOptions};
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub use regenerator::Regenerator;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
 pub struct ES2015<'a> {
     #[expect(unused)]
@@ -15,18 +33,255 @@ pub struct ES2015<'a> {
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions,
-
-    _marker: std::marker::PhantomData<&'a ()>,
+    classes: Option<Classes>,
+    destructuring: Option<Destructuring>,
+    parameters: Option<Parameters>,
+    block_scoping: Option<BlockScoping<'a>>,
+    regenerator: Option<Regenerator>,
+    template_literals: Option<TemplateLiterals>,
+    spread: Option<Spread>,
+    for_of: Option<ForOf>,
 }
 
 impl ES2015<'_> {
     pub fn new(options: ES2015Options) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default()),
+            classes: options.classes.map(|classes| Classes::new(classes, options.spread)),
+            destructuring: options.destructuring.map(Destructuring::new),
+            parameters: options.parameters.map(Parameters::new),
+            block_scoping: options
+                .block_scoping
+                .map(|block_scoping| BlockScoping::new(block_scoping, options.classes.is_some())),
+            regenerator: options.regenerator.then(Regenerator::new),
+            template_literals: options.template_literals.map(TemplateLiterals::new),
+            spread: options.spread.map(Spread::new),
+            for_of: options.for_of.map(ForOf::new),
             options,
-            _marker: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a> {
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Parameters first, so that destructuring only sees the params remaining in the param list.
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_function(func, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_function(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(parameters) = &mut self.parameters {
+            parameters.enter_arrow_function_expression(arrow, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_arrow_function_expression(arrow, ctx);
+        }
+    }
+
+    fn enter_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_class(class, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_in_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.enter_for_of_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.exit_variable_declaration(decl, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_expression(expr, ctx);
+        }
+        if let Some(template_literals) = &mut self.template_literals {
+            template_literals.exit_expression(expr, ctx);
+        }
+        if let Some(spread) = &mut self.spread {
+            spread.exit_expression(expr, ctx);
+        }
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.exit_expression(expr, ctx);
+        }
+        if let Some(parameters) = &mut self.parameters {
+            parameters.exit_expression(expr, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.exit_expression(expr, ctx);
+        }
+        if let Some(regenerator) = &mut self.regenerator {
+            regenerator.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(for_of) = &mut self.for_of {
+            for_of.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(destructuring) = &mut self.destructuring {
+            destructuring.exit_statement(stmt, ctx);
+        }
+        if let Some(classes) = &mut self.classes {
+            classes.exit_statement(stmt, ctx);
+        }
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_statements(stmts, ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(block_scoping) = &mut self.block_scoping {
+            block_scoping.exit_program(program, ctx);
+        }
+    }
+}
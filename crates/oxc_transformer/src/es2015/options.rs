@@ -1,6 +1,9 @@
 use serde::Deserialize;
 
-use super::ArrowFunctionsOptions;
+use super::{
+    ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions, ForOfOptions,
+    ParametersOptions, SpreadOptions, TemplateLiteralsOptions,
+};
 
 #[derive(Debug, Default, Clone, Copy, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
@@ -9,4 +12,36 @@ pub struct ES2015Options {
     /// Arrow-function transform options.
     #[serde(skip)]
     pub arrow_function: Option<ArrowFunctionsOptions>,
+
+    /// Classes transform options.
+    #[serde(skip)]
+    pub classes: Option<ClassesOptions>,
+
+    /// Destructuring transform options.
+    #[serde(skip)]
+    pub destructuring: Option<DestructuringOptions>,
+
+    /// Parameters transform options.
+    #[serde(skip)]
+    pub parameters: Option<ParametersOptions>,
+
+    /// Block scoping transform options.
+    #[serde(skip)]
+    pub block_scoping: Option<BlockScopingOptions>,
+
+    /// Generator functions transform.
+    #[serde(skip)]
+    pub regenerator: bool,
+
+    /// Template literals transform options.
+    #[serde(skip)]
+    pub template_literals: Option<TemplateLiteralsOptions>,
+
+    /// Spread transform options.
+    #[serde(skip)]
+    pub spread: Option<SpreadOptions>,
+
+    /// For-of transform options.
+    #[serde(skip)]
+    pub for_of: Option<ForOfOptions>,
 }
@@ -0,0 +1,690 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters and rest parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function f(a, b = 1, ...rest) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function f(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+//!   for (var _len = arguments.length, rest = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++)
+//!     rest[_key - 2] = arguments[_key];
+//! }
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `ignoreFunctionLength` assumption. Default parameters are then kept in the parameter
+//! list and assigned in the body, which changes `Function#length`:
+//!
+//! ```js
+//! function f(a, b) {
+//!   if (b === void 0) b = 1;
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Parameters starting from the first one with a default value are moved into `var` declarations
+//! at the start of the function body, reading from `arguments`. Destructuring patterns in those
+//! declarations are lowered by the destructuring transform afterwards.
+//!
+//! Arrow functions have no `arguments` of their own, so their default parameters always use the
+//! `ignoreFunctionLength` form. Rest parameters of arrow functions are transformed after the arrow
+//! functions transform has converted the arrow into a function expression.
+//!
+//! Default values are evaluated in a scope of their own, which can't see bindings declared in the
+//! function body. Once moved into the body, they would, so body bindings which clash with names
+//! referenced in the parameters are renamed. A body `var` redeclaring a parameter starts out with
+//! the parameter's value:
+//!
+//! ```js
+//! function f(a, b = () => a) { var a = 1; }
+//! // ->
+//! function f(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : () => a;
+//!   var _a = a;
+//!   var _a = 1;
+//! }
+//! ```
+//!
+//! ## Missing features
+//!
+//! * Rest parameters of arrow functions are not transformed if the arrow functions transform is disabled,
+//!   and an error is reported instead.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions specification: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use std::mem;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::Box as ArenaBox;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{
+    Reference, ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags, SymbolId,
+};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+use super::destructuring::{
+    convert_arrow_expression_body_to_block, create_number, create_variable_declaration,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 parameters transform.
+pub struct ParametersOptions {
+    /// Enables `ignoreFunctionLength` assumption.
+    pub loose: bool,
+}
+
+pub struct Parameters {
+    options: ParametersOptions,
+}
+
+impl Parameters {
+    pub fn new(options: ParametersOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Parameters {
+    // `function f(a = 1, ...b) {}` -> `function f() { var a = ...; for (...) b[_key] = arguments[_key]; }`
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = &mut func.body else { return };
+        // Default parameters may be moved into the body, so count them before transforming.
+        let param_count = func.params.items.len();
+        let copies = Self::rename_clashing_body_bindings(&func.params, body, scope_id, ctx);
+
+        let mut statements = if self.options.loose || ctx.state.assumptions.ignore_function_length {
+            Self::transform_default_params_loose(&mut func.params, scope_id, ctx)
+        } else {
+            Self::transform_default_params(&mut func.params, scope_id, ctx)
+        };
+        if let Some(rest) = func.params.rest.take() {
+            statements.extend(Self::transform_rest_param(rest, param_count, scope_id, ctx));
+        }
+        statements.extend(copies);
+        body.statements.splice(0..0, statements);
+    }
+
+    // `(a = 1) => a` -> `(a) => { if (a === void 0) a = 1; return a; }`
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let has_default = arrow.params.items.iter().any(|param| param.initializer.is_some());
+        let has_rest_pattern = arrow
+            .params
+            .rest
+            .as_ref()
+            .is_some_and(|rest| !rest.rest.argument.is_binding_identifier());
+        if !has_default && !has_rest_pattern {
+            return;
+        }
+
+        convert_arrow_expression_body_to_block(arrow, ctx);
+        let scope_id = arrow.scope_id();
+        let copies =
+            Self::rename_clashing_body_bindings(&arrow.params, &mut arrow.body, scope_id, ctx);
+        let mut statements = Self::transform_default_params_loose(&mut arrow.params, scope_id, ctx);
+
+        // Rest parameter itself can only be transformed once the arrow has been converted to a function.
+        // `(...[a]) => {}` -> `(..._ref) => { var [a] = _ref; }`
+        if let Some(rest) = &mut arrow.params.rest
+            && !rest.rest.argument.is_binding_identifier()
+        {
+            let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+            let pattern =
+                mem::replace(&mut rest.rest.argument, binding.create_binding_pattern(ctx));
+            let init = binding.create_read_expression(ctx);
+            statements.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                pattern,
+                init,
+                ctx,
+            ));
+        }
+        statements.extend(copies);
+        arrow.body.statements.splice(0..0, statements);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            // Rest parameters of function expressions are removed on entering the function,
+            // so this is an arrow function which has been converted by the arrow functions transform.
+            Expression::FunctionExpression(func) if func.params.rest.is_some() => {
+                let scope_id = func.scope_id();
+                let rest = func.params.rest.take().unwrap();
+                let start = func.params.items.len();
+                let statements = Self::transform_rest_param(rest, start, scope_id, ctx);
+                let body = func.body.as_mut().unwrap();
+                body.statements.splice(0..0, statements);
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                if let Some(rest) = &arrow.params.rest {
+                    ctx.state.error(
+                        OxcDiagnostic::error(
+                            "Rest parameters of arrow functions can only be transformed together with the arrow functions transform.",
+                        )
+                        .with_label(rest.span),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Parameters {
+    /// Rename bindings declared in the function body which clash with names referenced in the
+    /// parameters, as default values must not see them once they are moved into the body.
+    ///
+    /// * `function f(a = () => x) { var x; }` -> `function f(a = () => x) { var _x; }`
+    /// * `function f(a, b = () => a) { var a; }` -> `function f(a, b = () => a) { var _a; }`,
+    ///   returning `var _a = a;` to be inserted after the parameters have been initialized.
+    fn rename_clashing_body_bindings(
+        params: &FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        if params.items.iter().all(|param| param.initializer.is_none()) {
+            return vec![];
+        }
+
+        let mut param_collector = ParamsCollector::new(scope_id, ctx);
+        param_collector.visit_formal_parameters(params);
+        let ParamsCollector { references, symbols: param_symbols, .. } = param_collector;
+        if references.is_empty() {
+            return vec![];
+        }
+
+        let names = references.iter().map(|&(_, name)| name).collect::<FxHashSet<_>>();
+        let mut body_collector = BodyBindingsCollector::new(scope_id, &names, ctx);
+        body_collector.visit_function_body(body);
+        let clashing = body_collector.symbols;
+        if clashing.is_empty() {
+            return vec![];
+        }
+
+        let mut renamed = FxHashMap::default();
+        let mut copies = vec![];
+        for (symbol_id, name, has_function_declaration) in clashing {
+            if param_symbols.contains(&symbol_id) {
+                // The parameter keeps its symbol, the body gets a new one.
+                let flags = ctx.scoping().symbol_flags(symbol_id);
+                let binding = ctx.generate_uid(&name, scope_id, flags);
+                // `var _a = a;`, unless a function declaration initializes it instead.
+                if !has_function_declaration {
+                    let param = BoundIdentifier::new(name, symbol_id);
+                    copies.push(create_variable_declaration(
+                        VariableDeclarationKind::Var,
+                        binding.create_binding_pattern(ctx),
+                        param.create_read_expression(ctx),
+                        ctx,
+                    ));
+                }
+                renamed.insert(symbol_id, binding);
+            } else {
+                // The body keeps the symbol, and references in the parameters resolve outwards.
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.scoping_mut().rename_symbol(symbol_id, scope_id, new_name);
+                for &(reference_id, reference_name) in &references {
+                    if reference_name == name
+                        && ctx.scoping().get_reference(reference_id).symbol_id() == Some(symbol_id)
+                    {
+                        resolve_reference_outwards(symbol_id, reference_id, name, scope_id, ctx);
+                    }
+                }
+                renamed.insert(symbol_id, BoundIdentifier::new(new_name, symbol_id));
+            }
+        }
+
+        BodyBindingsRenamer { renamed, ctx }.visit_function_body(body);
+        copies
+    }
+
+    /// Move parameters starting from the first one with a default value into the body.
+    ///
+    /// * `function f(a = 1) {}` -> `function f() { var a = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : 1; }`
+    /// * `function f(a = 1, b) {}` -> `function f() { ...; var b = arguments.length > 1 ? arguments[1] : void 0; }`
+    fn transform_default_params(
+        params: &mut FormalParameters<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let Some(start) = params.items.iter().position(|param| param.initializer.is_some()) else {
+            return vec![];
+        };
+
+        let mut statements = vec![];
+        for (index, param) in params.items.drain(start..).enumerate() {
+            let index = start + index;
+            // `arguments.length > index`
+            let length = ctx.ast.member_expression_static(
+                SPAN,
+                create_arguments(scope_id, ctx),
+                ctx.ast.identifier_name(SPAN, "length"),
+                false,
+            );
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                Expression::from(length),
+                BinaryOperator::GreaterThan,
+                create_number(index, ctx),
+            );
+            let value = if let Some(init) = param.initializer {
+                // `arguments.length > index && arguments[index] !== void 0 ? arguments[index] : init`
+                let is_defined = ctx.ast.expression_binary(
+                    SPAN,
+                    create_arguments_element(scope_id, index, ctx),
+                    BinaryOperator::StrictInequality,
+                    ctx.ast.void_0(SPAN),
+                );
+                let test = ctx.ast.expression_logical(SPAN, test, LogicalOperator::And, is_defined);
+                let consequent = create_arguments_element(scope_id, index, ctx);
+                ctx.ast.expression_conditional(SPAN, test, consequent, init.unbox())
+            } else {
+                // `arguments.length > index ? arguments[index] : void 0`
+                let consequent = create_arguments_element(scope_id, index, ctx);
+                ctx.ast.expression_conditional(SPAN, test, consequent, ctx.ast.void_0(SPAN))
+            };
+            statements.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                param.pattern,
+                value,
+                ctx,
+            ));
+        }
+        statements
+    }
+
+    /// Assign default values in the body, keeping the parameters in place.
+    ///
+    /// * `(a = 1) => {}` -> `(a) => { if (a === void 0) a = 1; }`
+    /// * `({ a } = {}) => {}` -> `(_ref) => { var { a } = _ref === void 0 ? {} : _ref; }`
+    ///
+    /// Destructured parameters after the first default value are moved into the body too,
+    /// to keep the evaluation order.
+    fn transform_default_params_loose(
+        params: &mut FormalParameters<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let Some(start) = params.items.iter().position(|param| param.initializer.is_some()) else {
+            return vec![];
+        };
+
+        let mut statements = vec![];
+        for param in params.items.iter_mut().skip(start) {
+            let init = param.initializer.take().map(ArenaBox::unbox);
+
+            if let Some(ident) = param.pattern.get_binding_identifier() {
+                let Some(init) = init else { continue };
+                // `if (a === void 0) a = init;`
+                let binding = BoundIdentifier::from_binding_ident(ident);
+                let test = create_is_undefined(&binding, ctx);
+                let assignment = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    binding.create_write_target(ctx),
+                    init,
+                );
+                let consequent = ctx.ast.statement_expression(SPAN, assignment);
+                statements.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+                continue;
+            }
+
+            // `var pattern = _ref === void 0 ? init : _ref;`
+            let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+            let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+            let mut value = binding.create_read_expression(ctx);
+            if let Some(init) = init {
+                let test = create_is_undefined(&binding, ctx);
+                value = ctx.ast.expression_conditional(SPAN, test, init, value);
+            }
+            statements.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                pattern,
+                value,
+                ctx,
+            ));
+        }
+        statements
+    }
+
+    /// Collect the rest parameter from `arguments`.
+    ///
+    /// `function f(a, ...b) {}` ->
+    /// ```js
+    /// function f(a) {
+    ///   for (var _len = arguments.length, b = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++)
+    ///     b[_key - 1] = arguments[_key];
+    /// }
+    /// ```
+    ///
+    /// A destructured rest parameter is collected into a temporary variable first:
+    /// `function f(...[a]) {}` -> `function f() { for (...) _ref[_key] = arguments[_key]; var [a] = _ref; }`
+    fn transform_rest_param(
+        rest: ArenaBox<'a, FormalParameterRest<'a>>,
+        start: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let mut pattern = rest.unbox().rest.argument;
+        let mut statements = vec![];
+
+        let binding = if let Some(ident) = pattern.get_binding_identifier() {
+            BoundIdentifier::from_binding_ident(ident)
+        } else {
+            let binding = ctx.generate_uid("ref", scope_id, flags);
+            let temp_pattern = binding.create_binding_pattern(ctx);
+            let destructured = mem::replace(&mut pattern, temp_pattern);
+            let init = binding.create_read_expression(ctx);
+            statements.push(create_variable_declaration(
+                VariableDeclarationKind::Var,
+                destructured,
+                init,
+                ctx,
+            ));
+            binding
+        };
+
+        let len_binding = ctx.generate_uid("len", scope_id, flags);
+        let key_binding = ctx.generate_uid("key", scope_id, flags);
+
+        // `_len - start`, or `_len` if `start` is 0
+        let create_len_offset = |ctx: &mut TraverseCtx<'a>| {
+            let len = len_binding.create_read_expression(ctx);
+            if start == 0 {
+                len
+            } else {
+                let start = create_number(start, ctx);
+                ctx.ast.expression_binary(SPAN, len, BinaryOperator::Subtraction, start)
+            }
+        };
+
+        // `new Array(_len > start ? _len - start : 0)`
+        let array_len = if start == 0 {
+            create_len_offset(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len_binding.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                create_number(start, ctx),
+            );
+            let consequent = create_len_offset(ctx);
+            ctx.ast.expression_conditional(SPAN, test, consequent, create_number(0, ctx))
+        };
+        let array =
+            ctx.create_unbound_ident_expr(SPAN, ctx.ast.ident("Array"), ReferenceFlags::Read);
+        let new_array =
+            ctx.ast.expression_new(SPAN, array, NONE, ctx.ast.vec1(Argument::from(array_len)));
+
+        // `var _len = arguments.length, rest = new Array(...), _key = start`
+        let kind = VariableDeclarationKind::Var;
+        let arguments_length = ctx.ast.member_expression_static(
+            SPAN,
+            create_arguments(scope_id, ctx),
+            ctx.ast.identifier_name(SPAN, "length"),
+            false,
+        );
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len_binding.create_binding_pattern(ctx),
+                NONE,
+                Some(Expression::from(arguments_length)),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, pattern, NONE, Some(new_array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key_binding.create_binding_pattern(ctx),
+                NONE,
+                Some(create_number(start, ctx)),
+                false,
+            ),
+        ]);
+        let init = ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false);
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key_binding.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len_binding.create_read_expression(ctx),
+        );
+
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key_binding.create_read_write_simple_target(ctx),
+        );
+
+        // `rest[_key - start] = arguments[_key]`
+        let index = key_binding.create_read_expression(ctx);
+        let index = if start == 0 {
+            index
+        } else {
+            let start = create_number(start, ctx);
+            ctx.ast.expression_binary(SPAN, index, BinaryOperator::Subtraction, start)
+        };
+        let target = ctx.ast.member_expression_computed(
+            SPAN,
+            binding.create_read_expression(ctx),
+            index,
+            false,
+        );
+        let value = ctx.ast.member_expression_computed(
+            SPAN,
+            create_arguments(scope_id, ctx),
+            key_binding.create_read_expression(ctx),
+            false,
+        );
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+            Expression::from(value),
+        );
+        let body = ctx.ast.statement_expression(SPAN, assignment);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let for_stmt = ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        );
+        statements.insert(0, for_stmt);
+        statements
+    }
+}
+
+/// `arguments` of the function with the given scope.
+fn create_arguments<'a>(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let name = ctx.ast.ident("arguments");
+    let symbol_id = ctx.scoping().get_binding(scope_id, name);
+    ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read)
+}
+
+/// `arguments[index]`
+fn create_arguments_element<'a>(
+    scope_id: ScopeId,
+    index: usize,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let arguments = create_arguments(scope_id, ctx);
+    let index = create_number(index, ctx);
+    Expression::from(ctx.ast.member_expression_computed(SPAN, arguments, index, false))
+}
+
+/// `binding === void 0`
+fn create_is_undefined<'a>(
+    binding: &BoundIdentifier<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let value = binding.create_read_expression(ctx);
+    ctx.ast.expression_binary(SPAN, value, BinaryOperator::StrictEquality, ctx.ast.void_0(SPAN))
+}
+
+/// Re-resolve a reference to `symbol_id` in the scopes enclosing `scope_id`.
+fn resolve_reference_outwards<'a>(
+    symbol_id: SymbolId,
+    reference_id: ReferenceId,
+    name: Ident<'a>,
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    let scoping = ctx.scoping_mut();
+    let outer_symbol_id =
+        scoping.scope_parent_id(scope_id).and_then(|parent| scoping.find_binding(parent, name));
+    scoping.delete_resolved_reference(symbol_id, reference_id);
+    if let Some(outer_symbol_id) = outer_symbol_id {
+        scoping.get_reference_mut(reference_id).set_symbol_id(outer_symbol_id);
+        scoping.add_resolved_reference(outer_symbol_id, reference_id);
+    } else {
+        let reference = scoping.get_reference(reference_id);
+        let unresolved =
+            Reference::new(reference.node_id(), reference.scope_id(), reference.flags());
+        *scoping.get_reference_mut(reference_id) = unresolved;
+        scoping.add_root_unresolved_reference(name, reference_id);
+    }
+}
+
+/// Collects references in parameters, and the parameter bindings of the function.
+struct ParamsCollector<'a, 'c> {
+    scope_id: ScopeId,
+    references: Vec<(ReferenceId, Ident<'a>)>,
+    symbols: FxHashSet<SymbolId>,
+    ctx: &'c TraverseCtx<'a>,
+}
+
+impl<'a, 'c> ParamsCollector<'a, 'c> {
+    fn new(scope_id: ScopeId, ctx: &'c TraverseCtx<'a>) -> Self {
+        Self { scope_id, references: vec![], symbols: FxHashSet::default(), ctx }
+    }
+}
+
+impl<'a> Visit<'a> for ParamsCollector<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push((ident.reference_id(), ident.name));
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        if self.ctx.scoping().symbol_scope_id(symbol_id) == self.scope_id {
+            self.symbols.insert(symbol_id);
+        }
+    }
+}
+
+/// Collects bindings of the function scope declared in its body whose names are in `names`,
+/// with their name and whether any of their declarations is a function declaration.
+struct BodyBindingsCollector<'a, 'c> {
+    scope_id: ScopeId,
+    names: &'c FxHashSet<Ident<'a>>,
+    symbols: Vec<(SymbolId, Ident<'a>, bool)>,
+    ctx: &'c TraverseCtx<'a>,
+}
+
+impl<'a, 'c> BodyBindingsCollector<'a, 'c> {
+    fn new(scope_id: ScopeId, names: &'c FxHashSet<Ident<'a>>, ctx: &'c TraverseCtx<'a>) -> Self {
+        Self { scope_id, names, symbols: vec![], ctx }
+    }
+
+    fn add(&mut self, ident: &BindingIdentifier<'a>, is_function_declaration: bool) {
+        let symbol_id = ident.symbol_id();
+        if !self.names.contains(&ident.name)
+            || self.ctx.scoping().symbol_scope_id(symbol_id) != self.scope_id
+        {
+            return;
+        }
+        match self.symbols.iter_mut().find(|(id, _, _)| *id == symbol_id) {
+            Some((_, _, has_function_declaration)) => {
+                *has_function_declaration |= is_function_declaration;
+            }
+            None => self.symbols.push((symbol_id, ident.name, is_function_declaration)),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for BodyBindingsCollector<'a, '_> {
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        if func.is_declaration()
+            && let Some(id) = &func.id
+        {
+            self.add(id, true);
+        }
+        walk::walk_function(self, func, flags);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.add(ident, false);
+    }
+}
+
+/// Renames bindings in a function body, and references to them, to the bindings in `renamed`.
+struct BodyBindingsRenamer<'a, 'c> {
+    renamed: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for BodyBindingsRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(binding) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = binding.name;
+            ident.set_symbol_id(binding.symbol_id);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let Some(symbol_id) = self.ctx.scoping().get_reference(reference_id).symbol_id() else {
+            return;
+        };
+        let Some(binding) = self.renamed.get(&symbol_id) else { return };
+        ident.name = binding.name;
+        if binding.symbol_id != symbol_id {
+            let scoping = self.ctx.scoping_mut();
+            scoping.delete_resolved_reference(symbol_id, reference_id);
+            scoping.get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+            scoping.add_resolved_reference(binding.symbol_id, reference_id);
+        }
+        walk_mut::walk_identifier_reference(self, ident);
+    }
+}
@@ -0,0 +1,1462 @@
+//! Emitter which "explodes" the statements of a generator's body into a flat listing of statements,
+//! which becomes the cases of the `switch` statement in the inner function.
+//!
+//! Statements and expressions which do not contain a leap (`yield`, `break`, `continue`, `return` or
+//! `throw`) are emitted as they are. Others are broken down, with control flow expressed as jumps
+//! between locations in the listing (`_context.next = 5; break;`). Intermediate results of expressions
+//! which must survive a `yield` are stored in temporary properties of the context (`_context.t0`).
+//!
+//! The value of a location is the index in the listing it points to. Locations are often used before
+//! their value is known (e.g. a jump to the end of a loop), so the listing holds [`Op`]s, which are
+//! converted to statements once all locations are marked.
+
+use std::mem;
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_str::Ident;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, es2015::destructuring::create_number};
+
+use super::runtime_property;
+
+/// A location in the listing.
+#[derive(Clone, Copy)]
+struct Loc(usize);
+
+/// An entry in the listing. Each is converted to a single statement.
+enum Op<'a> {
+    Statement(Statement<'a>),
+    /// `_context.next = target;`
+    SetNext(JumpTarget<'a>),
+    /// `if (test) { _context.next = loc; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `return _context.abrupt("break", loc);` or `return _context.abrupt("continue", loc);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.delegateYield(argument, "t0", loc);`
+    DelegateYield(Expression<'a>, u32, Loc),
+}
+
+impl Op<'_> {
+    /// `true` if code following this op in the same case is unreachable.
+    fn is_completion(&self) -> bool {
+        match self {
+            Self::Statement(stmt) => matches!(
+                stmt,
+                Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+                    | Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+            ),
+            Self::SetNext(_) | Self::JumpIf(..) => false,
+            Self::Abrupt(..) | Self::DelegateYield(..) => true,
+        }
+    }
+}
+
+enum JumpTarget<'a> {
+    Loc(Loc),
+    /// `_context.t0 === a ? loc1 : _context.t0 === b ? loc2 : default_loc`, for `switch` statements
+    Switch {
+        discriminant: u32,
+        cases: Vec<(Expression<'a>, Loc)>,
+        default: Loc,
+    },
+}
+
+/// Statements which `break` or `continue` can target.
+enum LeapEntry<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Ident<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Ident<'a> },
+}
+
+/// Locations of a `try` statement, passed to the runtime so it can dispatch exceptions.
+struct TryEntry {
+    /// Start of the `try` block
+    first: usize,
+    catch: Option<Loc>,
+    finally: Option<Loc>,
+    after: Loc,
+}
+
+pub(super) struct Emitter<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// `_context` param of the inner function
+    context: BoundIdentifier<'a>,
+    listing: Vec<Op<'a>>,
+    /// Indexes in the listing which are targets of a jump, and so start a new case
+    marked: FxHashSet<usize>,
+    /// Values of locations. `None` until the location is marked.
+    locs: Vec<Option<usize>>,
+    next_temp_id: u32,
+    leap_entries: Vec<LeapEntry<'a>>,
+    try_entries: Vec<TryEntry>,
+}
+
+impl<'a, 'c> Emitter<'a, 'c> {
+    pub fn new(context: BoundIdentifier<'a>, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        let mut marked = FxHashSet::default();
+        marked.insert(0);
+        Self {
+            ctx,
+            context,
+            listing: vec![],
+            marked,
+            locs: vec![],
+            next_temp_id: 0,
+            leap_entries: vec![],
+            try_entries: vec![],
+        }
+    }
+
+    /// Convert the listing into the cases of the `switch` statement, and create the list of
+    /// try locations to pass to `regeneratorRuntime().wrap`.
+    ///
+    /// Scopes created for the cases' statements are children of `switch_scope_id`.
+    pub fn finish(
+        mut self,
+        switch_scope_id: ScopeId,
+    ) -> (ArenaVec<'a, SwitchCase<'a>>, Option<Expression<'a>>) {
+        let final_loc = self.listing.len();
+        let listing = mem::take(&mut self.listing);
+        let mut cases = self.ctx.ast.vec();
+        let mut current = None;
+        let mut already_ended = false;
+        for (index, op) in listing.into_iter().enumerate() {
+            if self.marked.contains(&index) {
+                if let Some((loc, consequent)) = current.take() {
+                    cases.push(self.create_case(loc, consequent));
+                }
+                current = Some((index, self.ctx.ast.vec()));
+                already_ended = false;
+            }
+            let is_completion = op.is_completion();
+            let stmt = self.op_to_statement(op, switch_scope_id);
+            // Drop statements which can't be reached, until the next case
+            if already_ended {
+                ReferenceRemover { ctx: self.ctx }.visit_statement(&stmt);
+                continue;
+            }
+            already_ended = is_completion;
+            current.as_mut().unwrap().1.push(stmt);
+        }
+        if let Some((loc, consequent)) = current.take() {
+            cases.push(self.create_case(loc, consequent));
+        }
+
+        // The runtime jumps to `"end"` when the generator completes
+        cases.push(self.create_case(final_loc, self.ctx.ast.vec()));
+        let stop = self.create_context_call("stop", self.ctx.ast.vec());
+        let end = self.ctx.ast.expression_string_literal(SPAN, "end", None);
+        cases.push(self.ctx.ast.switch_case(
+            SPAN,
+            Some(end),
+            self.ctx.ast.vec1(self.ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        let try_locs = self.create_try_locs_list();
+        (cases, try_locs)
+    }
+
+    /// Explode a statement of the generator's body.
+    ///
+    /// `label` is the label of the statement, if it's the body of a labeled statement.
+    pub fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Ident<'a>>) {
+        // Blocks are always exploded, because the braces are not needed
+        if let Statement::BlockStatement(block) = stmt {
+            for stmt in block.unbox().body {
+                self.explode_statement(stmt, None);
+            }
+            return;
+        }
+
+        if !LeapFinder::statement(&stmt) {
+            self.emit(Op::Statement(stmt));
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression(stmt.unbox().expression, true);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                let label = stmt.label.name;
+                self.leap_entries.push(LeapEntry::Labeled { break_loc: after, label });
+                self.explode_statement(stmt.body, Some(label));
+                self.leap_entries.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_value(stmt.test);
+                self.jump_if_not(test, after);
+                self.explode_loop_body(stmt.body, after, before, label);
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.explode_loop_body(stmt.body, after, test, label);
+                self.mark(test);
+                let test = self.explode_value(stmt.test);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let head = self.loc();
+                let update = self.loc();
+                let after = self.loc();
+                match stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => {
+                        self.emit(Op::Statement(Statement::VariableDeclaration(decl)));
+                    }
+                    Some(init) => {
+                        self.explode_expression(init.into_expression(), true);
+                    }
+                    None => {}
+                }
+                self.mark(head);
+                if let Some(test) = stmt.test {
+                    let test = self.explode_value(test);
+                    self.jump_if_not(test, after);
+                }
+                self.explode_loop_body(stmt.body, after, update, label);
+                self.mark(update);
+                if let Some(update) = stmt.update {
+                    self.explode_expression(update, true);
+                }
+                self.jump(head);
+                self.mark(after);
+            }
+            Statement::ForInStatement(stmt) => {
+                let stmt = stmt.unbox();
+                // `_context.t0 = regeneratorRuntime().keys(right);`
+                let keys = self.make_temp();
+                let right = self.explode_value(stmt.right);
+                let callee = runtime_property("keys", self.ctx);
+                let call = self.ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    self.ctx.ast.vec1(right.into()),
+                    false,
+                );
+                self.emit_assign(keys, call);
+                // `if ((_context.t1 = _context.t0()).done) { ... }`
+                let head = self.loc();
+                let after = self.loc();
+                self.mark(head);
+                let key = self.make_temp();
+                let next = self.temp(keys);
+                let next =
+                    self.ctx.ast.expression_call(SPAN, next, NONE, self.ctx.ast.vec(), false);
+                self.explode_iteration(stmt.left, key, next, stmt.body, head, after, label);
+            }
+            Statement::ForOfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                if stmt.r#await {
+                    self.report_unsupported(stmt.span, "`for await` loop");
+                    return;
+                }
+                // `_context.t0 = right[Symbol.iterator]();`
+                let iterator = self.make_temp();
+                let right = self.explode_value(stmt.right);
+                let symbol = self.ctx.create_unbound_ident_expr(
+                    SPAN,
+                    Ident::from("Symbol"),
+                    ReferenceFlags::Read,
+                );
+                let property = self.ctx.ast.identifier_name(SPAN, "iterator");
+                let key = Expression::from(
+                    self.ctx.ast.member_expression_static(SPAN, symbol, property, false),
+                );
+                let method = Expression::from(
+                    self.ctx.ast.member_expression_computed(SPAN, right, key, false),
+                );
+                let call =
+                    self.ctx.ast.expression_call(SPAN, method, NONE, self.ctx.ast.vec(), false);
+                self.emit_assign(iterator, call);
+                // `if ((_context.t1 = _context.t0.next()).done) { ... }`
+                let head = self.loc();
+                let after = self.loc();
+                self.mark(head);
+                let step = self.make_temp();
+                let next = self.temp_property(iterator, "next");
+                let next =
+                    self.ctx.ast.expression_call(SPAN, next, NONE, self.ctx.ast.vec(), false);
+                self.explode_iteration(stmt.left, step, next, stmt.body, head, after, label);
+            }
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                match self.find_break_loc(label) {
+                    Some(loc) => self.emit(Op::Abrupt("break", loc)),
+                    None => self.report_unsupported(stmt.span, "`break` statement"),
+                }
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| label.name);
+                match self.find_continue_loc(label) {
+                    Some(loc) => self.emit(Op::Abrupt("continue", loc)),
+                    None => self.report_unsupported(stmt.span, "`continue` statement"),
+                }
+            }
+            Statement::SwitchStatement(stmt) => self.explode_switch_statement(stmt.unbox()),
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.is_some().then(|| self.loc());
+                let after = self.loc();
+                let test = self.explode_value(stmt.test);
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(stmt.consequent, None);
+                if let Some(alternate) = stmt.alternate {
+                    self.jump(after);
+                    self.mark(else_loc.unwrap());
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                // `return _context.abrupt("return", argument);`
+                let stmt = stmt.unbox();
+                let mut arguments = self.ctx.ast.vec1(Argument::from(
+                    self.ctx.ast.expression_string_literal(SPAN, "return", None),
+                ));
+                if let Some(argument) = stmt.argument {
+                    arguments.push(Argument::from(self.explode_value(argument)));
+                }
+                let call = self.create_context_call("abrupt", arguments);
+                self.emit(Op::Statement(self.ctx.ast.statement_return(stmt.span, Some(call))));
+            }
+            Statement::ThrowStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let argument = self.explode_value(stmt.argument);
+                self.emit(Op::Statement(self.ctx.ast.statement_throw(stmt.span, argument)));
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox()),
+            stmt => {
+                let construct = match stmt {
+                    Statement::WithStatement(_) => "`with` statement",
+                    _ => "statement",
+                };
+                self.report_unsupported(stmt.span(), construct);
+                self.emit(Op::Statement(stmt));
+            }
+        }
+    }
+
+    fn explode_loop_body(
+        &mut self,
+        body: Statement<'a>,
+        break_loc: Loc,
+        continue_loc: Loc,
+        label: Option<Ident<'a>>,
+    ) {
+        self.leap_entries.push(LeapEntry::Loop { break_loc, continue_loc, label });
+        self.explode_statement(body, None);
+        self.leap_entries.pop();
+    }
+
+    /// Shared by `for in` and `for of` loops, after the iterator has been created.
+    ///
+    /// ```js
+    /// if ((_context.t1 = next).done) { _context.next = after; break; }
+    /// left = _context.t1.value;
+    /// body;
+    /// _context.next = head;
+    /// break;
+    /// ```
+    #[expect(clippy::too_many_arguments)]
+    fn explode_iteration(
+        &mut self,
+        left: ForStatementLeft<'a>,
+        step: u32,
+        next: Expression<'a>,
+        body: Statement<'a>,
+        head: Loc,
+        after: Loc,
+        label: Option<Ident<'a>>,
+    ) {
+        let step_result = self.ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            self.temp_target(step),
+            next,
+        );
+        let property = self.ctx.ast.identifier_name(SPAN, "done");
+        let done = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            step_result,
+            property,
+            false,
+        ));
+        self.jump_if(done, after);
+
+        let value = self.temp_property(step, "value");
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                self.report_unsupported(decl.span, "loop variable declaration");
+            }
+            left => {
+                let target = left.into_assignment_target();
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                );
+                self.emit_expression(assignment);
+            }
+        }
+
+        self.explode_loop_body(body, after, head, label);
+        self.jump(head);
+        self.mark(after);
+    }
+
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>) {
+        // Always save the discriminant into a temporary, in case the case tests overwrite `_context.sent`
+        let discriminant = self.make_temp();
+        let value = self.explode_value(stmt.discriminant);
+        self.emit_assign(discriminant, value);
+
+        let after = self.loc();
+        let default_loc = self.loc();
+        let mut case_locs = Vec::with_capacity(stmt.cases.len());
+        let mut tests = vec![];
+        let mut consequents = Vec::with_capacity(stmt.cases.len());
+        for case in stmt.cases {
+            if let Some(test) = case.test {
+                if LeapFinder::expression(&test) {
+                    self.report_unsupported(test.span(), "`case` test");
+                }
+                let loc = self.loc();
+                tests.push((test, loc));
+                case_locs.push(loc);
+            } else {
+                case_locs.push(default_loc);
+            }
+            consequents.push(case.consequent);
+        }
+        self.emit(Op::SetNext(JumpTarget::Switch {
+            discriminant,
+            cases: tests,
+            default: default_loc,
+        }));
+        self.emit(Op::Statement(self.ctx.ast.statement_break(SPAN, None)));
+
+        self.leap_entries.push(LeapEntry::Switch { break_loc: after });
+        for (loc, consequent) in case_locs.into_iter().zip(consequents) {
+            self.mark(loc);
+            for stmt in consequent {
+                self.explode_statement(stmt, None);
+            }
+        }
+        self.leap_entries.pop();
+
+        if self.locs[default_loc.0].is_none() {
+            self.mark(default_loc);
+        }
+        self.mark(after);
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>) {
+        let after = self.loc();
+        let catch_loc = stmt.handler.is_some().then(|| self.loc());
+        let finally_loc = stmt.finalizer.is_some().then(|| self.loc());
+        let first_loc = self.listing.len();
+        self.try_entries.push(TryEntry {
+            first: first_loc,
+            catch: catch_loc,
+            finally: finally_loc,
+            after,
+        });
+        self.update_context_prev_loc(first_loc);
+
+        self.explode_statement(Statement::BlockStatement(stmt.block), None);
+
+        if let Some(handler) = stmt.handler {
+            // Jump over the catch block
+            self.jump(finally_loc.unwrap_or(after));
+            let index = self.mark(catch_loc.unwrap());
+            self.update_context_prev_loc(index);
+
+            let handler = handler.unbox();
+            let mut body = handler.body;
+            // `_context.t0 = _context["catch"](first_loc);`
+            let arguments = self.ctx.ast.vec1(Argument::from(create_number(first_loc, self.ctx)));
+            let call = self.create_context_call("catch", arguments);
+            match handler.param.map(|param| param.pattern) {
+                Some(BindingPattern::BindingIdentifier(ident)) => {
+                    let temp = self.make_temp();
+                    self.emit_assign(temp, call);
+                    let symbol_id = ident.symbol_id();
+                    let scope_id = self.ctx.scoping().symbol_scope_id(symbol_id);
+                    self.ctx.scoping_mut().remove_binding(scope_id, ident.name);
+                    let mut replacer = CatchParamReplacer { emitter: self, symbol_id, temp };
+                    replacer.visit_block_statement(&mut body);
+                }
+                Some(pattern) => {
+                    self.report_unsupported(pattern.span(), "`catch` parameter pattern");
+                    self.emit_expression(call);
+                }
+                None => self.emit_expression(call),
+            }
+            self.explode_statement(Statement::BlockStatement(body), None);
+        }
+
+        if let Some(finalizer) = stmt.finalizer {
+            let index = self.mark(finally_loc.unwrap());
+            self.update_context_prev_loc(index);
+            self.explode_statement(Statement::BlockStatement(finalizer), None);
+            // `return _context.finish(finally_loc);`
+            let arguments = self.ctx.ast.vec1(Argument::from(create_number(index, self.ctx)));
+            let call = self.create_context_call("finish", arguments);
+            self.emit(Op::Statement(self.ctx.ast.statement_return(SPAN, Some(call))));
+        }
+
+        self.mark(after);
+    }
+
+    fn explode_value(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_expression(expr, false).unwrap()
+    }
+
+    /// Explode an expression.
+    ///
+    /// If `ignore_result` is `true`, the expression is emitted as a statement, and `None` returned.
+    /// Otherwise, returns an expression which evaluates to the result, and must be used
+    /// before the next leap.
+    fn explode_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if !LeapFinder::expression(&expr) {
+            return self.finish_expression(expr, ignore_result);
+        }
+
+        let expr = match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                return self.explode_expression(paren.unbox().expression, ignore_result);
+            }
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_value(member.object);
+                Expression::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    member.optional,
+                ))
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = if LeapFinder::expression(&member.expression) {
+                    self.explode_via_temp(None, member.object)
+                } else {
+                    self.explode_value(member.object)
+                };
+                let property = self.explode_via_temp(None, member.expression);
+                Expression::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    member.optional,
+                ))
+            }
+            Expression::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_value(member.object);
+                Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                    member.span,
+                    object,
+                    member.field,
+                    member.optional,
+                ))
+            }
+            Expression::CallExpression(call) => self.explode_call_expression(call.unbox()),
+            Expression::NewExpression(new_expr) => {
+                let new_expr = new_expr.unbox();
+                let callee = self.explode_via_temp(None, new_expr.callee);
+                let arguments = self.explode_arguments(new_expr.arguments);
+                self.ctx.ast.expression_new(new_expr.span, callee, NONE, arguments)
+            }
+            Expression::ObjectExpression(object) => {
+                let mut object = object.unbox();
+                for property in &mut object.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property)
+                            if property.kind == PropertyKind::Init && !property.method =>
+                        {
+                            if property.computed && LeapFinder::property_key(&property.key) {
+                                let key = property.key.take_in(self.ctx.ast).into_expression();
+                                property.key = PropertyKey::from(self.explode_via_temp(None, key));
+                            }
+                            let value = property.value.take_in(self.ctx.ast);
+                            property.value = self.explode_via_temp(None, value);
+                            property.shorthand = false;
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                        }
+                        ObjectPropertyKind::ObjectProperty(_) => {}
+                    }
+                }
+                Expression::ObjectExpression(self.ctx.ast.alloc(object))
+            }
+            Expression::ArrayExpression(array) => {
+                let mut array = array.unbox();
+                for element in &mut array.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let argument = spread.argument.take_in(self.ctx.ast);
+                            spread.argument = self.explode_via_temp(None, argument);
+                        }
+                        ArrayExpressionElement::Elision(_) => {}
+                        element => {
+                            let expr = element.take_in(self.ctx.ast).into_expression();
+                            *element =
+                                ArrayExpressionElement::from(self.explode_via_temp(None, expr));
+                        }
+                    }
+                }
+                Expression::ArrayExpression(self.ctx.ast.alloc(array))
+            }
+            Expression::SequenceExpression(sequence) => {
+                let mut expressions = sequence.unbox().expressions;
+                let last = expressions.pop().unwrap();
+                for expr in expressions {
+                    self.explode_expression(expr, true);
+                }
+                return self.explode_expression(last, ignore_result);
+            }
+            Expression::LogicalExpression(logical) => {
+                let logical = logical.unbox();
+                let after = self.loc();
+                let result = (!ignore_result).then(|| self.make_temp());
+                let left = self.explode_via_temp(result, logical.left);
+                match logical.operator {
+                    LogicalOperator::And => self.jump_if_not(left, after),
+                    LogicalOperator::Or => self.jump_if(left, after),
+                    LogicalOperator::Coalesce => {
+                        let null = self.ctx.ast.expression_null_literal(SPAN);
+                        let test = self.ctx.ast.expression_binary(
+                            SPAN,
+                            left,
+                            BinaryOperator::Inequality,
+                            null,
+                        );
+                        self.jump_if(test, after);
+                    }
+                }
+                self.explode_result_via_temp(result, logical.right);
+                self.mark(after);
+                return result.map(|result| self.temp(result));
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let conditional = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_value(conditional.test);
+                self.jump_if_not(test, else_loc);
+                let result = (!ignore_result).then(|| self.make_temp());
+                self.explode_result_via_temp(result, conditional.consequent);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_result_via_temp(result, conditional.alternate);
+                self.mark(after);
+                return result.map(|result| self.temp(result));
+            }
+            Expression::UnaryExpression(unary) => {
+                let unary = unary.unbox();
+                let argument = self.explode_value(unary.argument);
+                self.ctx.ast.expression_unary(unary.span, unary.operator, argument)
+            }
+            Expression::BinaryExpression(binary) => {
+                let binary = binary.unbox();
+                let left = self.explode_via_temp(None, binary.left);
+                let right = self.explode_via_temp(None, binary.right);
+                self.ctx.ast.expression_binary(binary.span, left, binary.operator, right)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                self.explode_assignment_expression(assignment.unbox())
+            }
+            Expression::UpdateExpression(update) => {
+                let update = update.unbox();
+                let argument = self.explode_simple_target(update.argument);
+                self.ctx.ast.expression_update(
+                    update.span,
+                    update.operator,
+                    update.prefix,
+                    argument,
+                )
+            }
+            Expression::YieldExpression(yield_expr) => {
+                let yield_expr = yield_expr.unbox();
+                let after = self.loc();
+                let argument = yield_expr.argument.map(|argument| self.explode_value(argument));
+                if yield_expr.delegate {
+                    // `return _context.delegateYield(argument, "t0", after);`
+                    let result = self.make_temp();
+                    let argument = argument.unwrap();
+                    self.emit(Op::DelegateYield(argument, result, after));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp(result));
+                }
+                // `_context.next = after; return argument;`
+                self.emit(Op::SetNext(JumpTarget::Loc(after)));
+                self.emit(Op::Statement(self.ctx.ast.statement_return(yield_expr.span, argument)));
+                self.mark(after);
+                return (!ignore_result).then(|| self.context_property("sent"));
+            }
+            Expression::TemplateLiteral(template) => {
+                let mut template = template.unbox();
+                for expr in &mut template.expressions {
+                    let value = expr.take_in(self.ctx.ast);
+                    *expr = self.explode_via_temp(None, value);
+                }
+                Expression::TemplateLiteral(self.ctx.ast.alloc(template))
+            }
+            expr => {
+                let construct = match &expr {
+                    Expression::TaggedTemplateExpression(_) => "tagged template",
+                    Expression::ChainExpression(_) => "optional chain",
+                    Expression::ClassExpression(_) => "class",
+                    Expression::ImportExpression(_) => "`import()`",
+                    _ => "expression",
+                };
+                self.report_unsupported(expr.span(), construct);
+                expr
+            }
+        };
+        self.finish_expression(expr, ignore_result)
+    }
+
+    fn explode_call_expression(&mut self, call: CallExpression<'a>) -> Expression<'a> {
+        let has_leaping_arguments = call.arguments.iter().any(LeapFinder::argument);
+        let mut this_argument = None;
+        let callee = match call.callee {
+            // If arguments contain a leap, the callee must be evaluated before them,
+            // and its object still passed as `this`: `_context.t0 = obj; _context.t0.method.call(_context.t0, ...)`
+            callee if has_leaping_arguments && callee.is_member_expression() => {
+                let object_temp = self.make_temp();
+                let callee = match callee.into_member_expression() {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object = self.explode_via_temp(Some(object_temp), member.object);
+                        Expression::from(self.ctx.ast.member_expression_static(
+                            member.span,
+                            object,
+                            member.property,
+                            false,
+                        ))
+                    }
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object = self.explode_via_temp(Some(object_temp), member.object);
+                        let property = self.explode_via_temp(None, member.expression);
+                        Expression::from(self.ctx.ast.member_expression_computed(
+                            member.span,
+                            object,
+                            property,
+                            false,
+                        ))
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        let member = member.unbox();
+                        let object = self.explode_via_temp(Some(object_temp), member.object);
+                        Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                            member.span,
+                            object,
+                            member.field,
+                            false,
+                        ))
+                    }
+                };
+                this_argument = Some(self.temp(object_temp));
+                let property = self.ctx.ast.identifier_name(SPAN, "call");
+                Expression::from(
+                    self.ctx.ast.member_expression_static(SPAN, callee, property, false),
+                )
+            }
+            callee if callee.is_member_expression() => self.explode_value(callee),
+            callee => {
+                let callee = self.explode_via_temp(None, callee);
+                if callee.is_member_expression() {
+                    // Call without `this`: `(0, _context.t0)()`
+                    let zero = create_number(0, self.ctx);
+                    self.ctx
+                        .ast
+                        .expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, callee]))
+                } else {
+                    callee
+                }
+            }
+        };
+        let arguments = if has_leaping_arguments {
+            let mut arguments = self.explode_arguments(call.arguments);
+            if let Some(this_argument) = this_argument {
+                arguments.insert(0, Argument::from(this_argument));
+            }
+            arguments
+        } else {
+            call.arguments
+        };
+        self.ctx.ast.expression_call(call.span, callee, NONE, arguments, false)
+    }
+
+    fn explode_arguments(
+        &mut self,
+        mut arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        for argument in &mut arguments {
+            if let Argument::SpreadElement(spread) = argument {
+                let value = spread.argument.take_in(self.ctx.ast);
+                spread.argument = self.explode_via_temp(None, value);
+            } else {
+                let value = argument.take_in(self.ctx.ast).into_expression();
+                *argument = Argument::from(self.explode_via_temp(None, value));
+            }
+        }
+        arguments
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, right, .. } = assignment;
+        if operator == AssignmentOperator::Assign {
+            let left = if LeapFinder::assignment_target(&left) {
+                match left {
+                    left @ match_simple_assignment_target!(AssignmentTarget) => {
+                        AssignmentTarget::from(
+                            self.explode_simple_target(left.into_simple_assignment_target()),
+                        )
+                    }
+                    left => {
+                        self.report_unsupported(left.span(), "assignment pattern");
+                        left
+                    }
+                }
+            } else {
+                left
+            };
+            let right = self.explode_value(right);
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        }
+
+        let Some(binary_operator) = operator.to_binary_operator() else {
+            self.report_unsupported(span, "logical assignment");
+            return self.ctx.ast.expression_assignment(span, operator, left, right);
+        };
+
+        // `x += yield y` -> `_context.t0 = x; x = _context.t0 + _context.sent`
+        let (read, write) = self.split_simple_target(left.into_simple_assignment_target());
+        let temp = self.make_temp();
+        self.emit_assign(temp, read);
+        let right = self.explode_value(right);
+        let value = self.ctx.ast.expression_binary(span, self.temp(temp), binary_operator, right);
+        self.ctx.ast.expression_assignment(span, AssignmentOperator::Assign, write, value)
+    }
+
+    /// Explode the object and property of a member expression assignment target.
+    fn explode_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        if !LeapFinder::simple_assignment_target(&target) {
+            return target;
+        }
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_value(member.object);
+                SimpleAssignmentTarget::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = if LeapFinder::expression(&member.expression) {
+                    self.explode_via_temp(None, member.object)
+                } else {
+                    self.explode_value(member.object)
+                };
+                let property = self.explode_via_temp(None, member.expression);
+                SimpleAssignmentTarget::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_value(member.object);
+                SimpleAssignmentTarget::from(
+                    self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object,
+                        member.field,
+                        false,
+                    ),
+                )
+            }
+            target => {
+                self.report_unsupported(target.span(), "assignment target");
+                target
+            }
+        }
+    }
+
+    /// Split an assignment target into an expression reading its value, and a target to write it.
+    /// Objects and computed properties of member expressions are stored in temporaries,
+    /// so they are only evaluated once.
+    fn split_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> (Expression<'a>, AssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let read =
+                    self.ctx.create_ident_expr(SPAN, ident.name, symbol_id, ReferenceFlags::Read);
+                *self.ctx.scoping_mut().get_reference_mut(ident.reference_id()).flags_mut() =
+                    ReferenceFlags::Write;
+                (read, AssignmentTarget::AssignmentTargetIdentifier(ident))
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object_temp = self.make_temp();
+                let object = self.explode_via_temp(Some(object_temp), member.object);
+                let read = Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    object,
+                    member.property.clone(),
+                    false,
+                ));
+                let object = self.temp(object_temp);
+                let write = AssignmentTarget::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ));
+                (read, write)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object_temp = self.make_temp();
+                let object = self.explode_via_temp(Some(object_temp), member.object);
+                let property_temp = self.make_temp();
+                let property = self.explode_via_temp(Some(property_temp), member.expression);
+                let read = Expression::from(
+                    self.ctx.ast.member_expression_computed(SPAN, object, property, false),
+                );
+                let object = self.temp(object_temp);
+                let property = self.temp(property_temp);
+                let write = AssignmentTarget::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ));
+                (read, write)
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object_temp = self.make_temp();
+                let object = self.explode_via_temp(Some(object_temp), member.object);
+                let read =
+                    Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                        SPAN,
+                        object,
+                        member.field.clone(),
+                        false,
+                    ));
+                let object = self.temp(object_temp);
+                let write = AssignmentTarget::from(
+                    self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object,
+                        member.field,
+                        false,
+                    ),
+                );
+                (read, write)
+            }
+            target => {
+                self.report_unsupported(target.span(), "assignment target");
+                let void_0 = self.ctx.ast.void_0(SPAN);
+                (void_0, AssignmentTarget::from(target))
+            }
+        }
+    }
+
+    /// Explode `expr`, storing its result in a temporary, unless it is a literal.
+    /// If `temp` is provided, the result is always stored in it.
+    ///
+    /// Any sibling of an expression containing a leap needs this, so that the order of their
+    /// side effects is preserved.
+    fn explode_via_temp(&mut self, temp: Option<u32>, expr: Expression<'a>) -> Expression<'a> {
+        let result = self.explode_value(expr);
+        if temp.is_none() && is_literal(&result) {
+            return result;
+        }
+        let temp = temp.unwrap_or_else(|| self.make_temp());
+        self.emit_assign(temp, result);
+        self.temp(temp)
+    }
+
+    /// Explode a branch of a logical or conditional expression, storing its result in `result`.
+    /// If `result` is `None`, the result is not needed.
+    fn explode_result_via_temp(&mut self, result: Option<u32>, expr: Expression<'a>) {
+        match result {
+            Some(result) => {
+                let value = self.explode_value(expr);
+                self.emit_assign(result, value);
+            }
+            None => {
+                self.explode_expression(expr, true);
+            }
+        }
+    }
+
+    fn finish_expression(
+        &mut self,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Create a new location, with its value not yet known.
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Set location to the current position in the listing. Returns the location's value.
+    fn mark(&mut self, loc: Loc) -> usize {
+        let index = self.listing.len();
+        debug_assert!(self.locs[loc.0].is_none_or(|value| value == index));
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+        index
+    }
+
+    fn emit(&mut self, op: Op<'a>) {
+        self.listing.push(op);
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        let span = expr.span();
+        self.emit(Op::Statement(self.ctx.ast.statement_expression(span, expr)));
+    }
+
+    /// Emit `_context.t0 = value;`.
+    fn emit_assign(&mut self, temp: u32, value: Expression<'a>) {
+        let target = self.temp_target(temp);
+        let assignment =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment);
+    }
+
+    /// `_context.next = loc; break;`
+    fn jump(&mut self, loc: Loc) {
+        self.emit(Op::SetNext(JumpTarget::Loc(loc)));
+        self.emit(Op::Statement(self.ctx.ast.statement_break(SPAN, None)));
+    }
+
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => self.ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.emit(Op::JumpIf(test, loc));
+    }
+
+    /// `_context.prev = loc;`, so the runtime knows which `try` statement is active,
+    /// even if control flow reached this point without a jump.
+    fn update_context_prev_loc(&mut self, loc: usize) {
+        let target = AssignmentTarget::from(self.context_member("prev"));
+        let value = create_number(loc, self.ctx);
+        let assignment =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment);
+    }
+
+    fn find_break_loc(&self, label: Option<Ident<'a>>) -> Option<Loc> {
+        self.leap_entries.iter().rev().find_map(|entry| match (entry, label) {
+            (LeapEntry::Loop { break_loc, .. } | LeapEntry::Switch { break_loc }, None) => {
+                Some(*break_loc)
+            }
+            (
+                LeapEntry::Loop { break_loc, label: Some(entry_label), .. }
+                | LeapEntry::Labeled { break_loc, label: entry_label },
+                Some(label),
+            ) if *entry_label == label => Some(*break_loc),
+            _ => None,
+        })
+    }
+
+    fn find_continue_loc(&self, label: Option<Ident<'a>>) -> Option<Loc> {
+        self.leap_entries.iter().rev().find_map(|entry| match (entry, label) {
+            (LeapEntry::Loop { continue_loc, .. }, None) => Some(*continue_loc),
+            (LeapEntry::Loop { continue_loc, label: Some(entry_label), .. }, Some(label))
+                if *entry_label == label =>
+            {
+                Some(*continue_loc)
+            }
+            _ => None,
+        })
+    }
+
+    fn make_temp(&mut self) -> u32 {
+        let id = self.next_temp_id;
+        self.next_temp_id += 1;
+        id
+    }
+
+    /// `_context.t0`
+    fn temp(&mut self, temp: u32) -> Expression<'a> {
+        Expression::from(self.temp_member(temp))
+    }
+
+    fn temp_target(&mut self, temp: u32) -> AssignmentTarget<'a> {
+        AssignmentTarget::from(self.temp_member(temp))
+    }
+
+    fn temp_member(&mut self, temp: u32) -> MemberExpression<'a> {
+        let name = self.ctx.ast.str(&format!("t{temp}"));
+        self.context_member(&name)
+    }
+
+    /// `_context.t0.property`
+    fn temp_property(&mut self, temp: u32, property: &'static str) -> Expression<'a> {
+        let object = self.temp(temp);
+        let property = self.ctx.ast.identifier_name(SPAN, property);
+        Expression::from(self.ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    fn context_property(&mut self, name: &str) -> Expression<'a> {
+        Expression::from(self.context_member(name))
+    }
+
+    /// `_context.name`, or `_context["catch"]`, as `catch` is a reserved word in ES3.
+    fn context_member(&mut self, name: &str) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(self.ctx);
+        if name == "catch" {
+            let property = self.ctx.ast.expression_string_literal(SPAN, "catch", None);
+            self.ctx.ast.member_expression_computed(SPAN, object, property, false)
+        } else {
+            let property = self.ctx.ast.identifier_name(SPAN, self.ctx.ast.str(name));
+            self.ctx.ast.member_expression_static(SPAN, object, property, false)
+        }
+    }
+
+    /// `_context.method(...arguments)`
+    fn create_context_call(
+        &mut self,
+        method: &str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = self.context_property(method);
+        self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn loc_value(&self, loc: Loc) -> usize {
+        self.locs[loc.0].expect("Location should be marked before the listing is finished")
+    }
+
+    fn loc_expr(&self, loc: Loc) -> Expression<'a> {
+        create_number(self.loc_value(loc), self.ctx)
+    }
+
+    fn create_case(&self, loc: usize, consequent: ArenaVec<'a, Statement<'a>>) -> SwitchCase<'a> {
+        self.ctx.ast.switch_case(SPAN, Some(create_number(loc, self.ctx)), consequent)
+    }
+
+    fn op_to_statement(&mut self, op: Op<'a>, switch_scope_id: ScopeId) -> Statement<'a> {
+        match op {
+            Op::Statement(stmt) => stmt,
+            Op::SetNext(target) => {
+                let value = match target {
+                    JumpTarget::Loc(loc) => self.loc_expr(loc),
+                    JumpTarget::Switch { discriminant, cases, default } => {
+                        let mut value = self.loc_expr(default);
+                        for (test, loc) in cases.into_iter().rev() {
+                            let discriminant = self.temp(discriminant);
+                            let test = self.ctx.ast.expression_binary(
+                                SPAN,
+                                discriminant,
+                                BinaryOperator::StrictEquality,
+                                test,
+                            );
+                            value = self.ctx.ast.expression_conditional(
+                                SPAN,
+                                test,
+                                self.loc_expr(loc),
+                                value,
+                            );
+                        }
+                        value
+                    }
+                };
+                let target = AssignmentTarget::from(self.context_member("next"));
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                );
+                self.ctx.ast.statement_expression(SPAN, assignment)
+            }
+            Op::JumpIf(test, loc) => {
+                let target = AssignmentTarget::from(self.context_member("next"));
+                let value = self.loc_expr(loc);
+                let assignment = self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                );
+                let body = self.ctx.ast.vec_from_array([
+                    self.ctx.ast.statement_expression(SPAN, assignment),
+                    self.ctx.ast.statement_break(SPAN, None),
+                ]);
+                let scope_id = self.ctx.create_child_scope(switch_scope_id, ScopeFlags::empty());
+                let consequent = self.ctx.ast.statement_block_with_scope_id(SPAN, body, scope_id);
+                self.ctx.ast.statement_if(SPAN, test, consequent, None)
+            }
+            Op::Abrupt(kind, loc) => {
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(self.ctx.ast.expression_string_literal(SPAN, kind, None)),
+                    Argument::from(self.loc_expr(loc)),
+                ]);
+                let call = self.create_context_call("abrupt", arguments);
+                self.ctx.ast.statement_return(SPAN, Some(call))
+            }
+            Op::DelegateYield(argument, result, loc) => {
+                let result = self.ctx.ast.str(&format!("t{result}"));
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(argument),
+                    Argument::from(self.ctx.ast.expression_string_literal(SPAN, result, None)),
+                    Argument::from(self.loc_expr(loc)),
+                ]);
+                let call = self.create_context_call("delegateYield", arguments);
+                self.ctx.ast.statement_return(SPAN, Some(call))
+            }
+        }
+    }
+
+    /// `[[try_loc, catch_loc, finally_loc, after_loc], ...]`
+    fn create_try_locs_list(&self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let entries = self.ctx.ast.vec_from_iter(self.try_entries.iter().map(|entry| {
+            let mut locs = self.ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(create_number(entry.first, self.ctx)));
+            locs.push(match entry.catch {
+                Some(loc) => ArrayExpressionElement::from(self.loc_expr(loc)),
+                None => self.ctx.ast.array_expression_element_elision(SPAN),
+            });
+            if let Some(finally_loc) = entry.finally {
+                locs.push(ArrayExpressionElement::from(self.loc_expr(finally_loc)));
+                locs.push(ArrayExpressionElement::from(self.loc_expr(entry.after)));
+            }
+            ArrayExpressionElement::from(self.ctx.ast.expression_array(SPAN, locs))
+        }));
+        Some(self.ctx.ast.expression_array(SPAN, entries))
+    }
+
+    fn report_unsupported(&mut self, span: Span, construct: &str) {
+        self.ctx.state.error(
+            OxcDiagnostic::error(format!(
+                "Compiling {construct} containing `yield` to a state machine is not supported."
+            ))
+            .with_label(span),
+        );
+    }
+}
+
+/// `true` for literals, which evaluate to the same value before and after a `yield`.
+fn is_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        _ => false,
+    }
+}
+
+/// Finds leaps (`yield`, `break`, `continue`, `return` and `throw`), outside of nested functions.
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+impl LeapFinder {
+    fn statement(stmt: &Statement) -> bool {
+        let mut finder = Self::default();
+        finder.visit_statement(stmt);
+        finder.found
+    }
+
+    fn expression(expr: &Expression) -> bool {
+        let mut finder = Self::default();
+        finder.visit_expression(expr);
+        finder.found
+    }
+
+    fn argument(argument: &Argument) -> bool {
+        let mut finder = Self::default();
+        finder.visit_argument(argument);
+        finder.found
+    }
+
+    fn property_key(key: &PropertyKey) -> bool {
+        let mut finder = Self::default();
+        finder.visit_property_key(key);
+        finder.found
+    }
+
+    fn assignment_target(target: &AssignmentTarget) -> bool {
+        let mut finder = Self::default();
+        finder.visit_assignment_target(target);
+        finder.found
+    }
+
+    fn simple_assignment_target(target: &SimpleAssignmentTarget) -> bool {
+        let mut finder = Self::default();
+        finder.visit_simple_assignment_target(target);
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _stmt: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+}
+
+/// Deletes references in a statement which is dropped from the listing.
+struct ReferenceRemover<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ReferenceRemover<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.ctx.delete_reference_for_identifier(ident);
+    }
+}
+
+/// Replaces references to a `catch` clause's parameter with the temporary holding the error.
+struct CatchParamReplacer<'a, 'c, 'e> {
+    emitter: &'e mut Emitter<'a, 'c>,
+    symbol_id: SymbolId,
+    temp: u32,
+}
+
+impl<'a> CatchParamReplacer<'a, '_, '_> {
+    fn is_param(&mut self, ident: &IdentifierReference<'a>) -> bool {
+        let reference_id = ident.reference_id();
+        let scoping = self.emitter.ctx.scoping_mut();
+        if scoping.get_reference(reference_id).symbol_id() != Some(self.symbol_id) {
+            return false;
+        }
+        scoping.delete_resolved_reference(self.symbol_id, reference_id);
+        true
+    }
+}
+
+impl<'a> VisitMut<'a> for CatchParamReplacer<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_param(ident)
+        {
+            *expr = self.emitter.temp(self.temp);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && self.is_param(ident)
+        {
+            *target = SimpleAssignmentTarget::from(self.emitter.temp_member(self.temp));
+            return;
+        }
+        walk_mut::walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_object_property(&mut self, property: &mut ObjectProperty<'a>) {
+        if property.shorthand {
+            property.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, property);
+    }
+}
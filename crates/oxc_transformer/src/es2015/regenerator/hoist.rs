@@ -0,0 +1,363 @@
+//! Hoisting of declarations out of a generator function's body.
+//!
+//! The body of a generator is split into the cases of a `switch` statement in the inner function,
+//! so bindings must outlive a single invocation of it. All declarations in the body are converted to
+//! assignments, and their bindings hoisted into a `var` declaration in the outer function.
+//!
+//! ```js
+//! function* foo() {
+//!   var a = 1;
+//!   { let b = a; }
+//!   function c() {}
+//! }
+//! ```
+//! ->
+//! ```js
+//! function foo() {
+//!   var a, b, c;
+//!   // ... inner function containing:
+//!   c = function _c() {};
+//!   a = 1;
+//!   b = a;
+//! }
+//! ```
+//!
+//! References to `arguments` are replaced with `_args`, which is initialized in the outer function.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_str::Ident;
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_assignment};
+
+/// Bindings and function-level state collected by [`hoist`].
+pub(super) struct Hoisted<'a> {
+    /// Bindings to declare with `var` in the outer function
+    pub vars: Vec<BoundIdentifier<'a>>,
+    /// `_args` binding, if the body references `arguments`
+    pub arguments: Option<BoundIdentifier<'a>>,
+    /// `true` if the body references `this`
+    pub uses_this: bool,
+}
+
+/// Convert all declarations in `body` to assignments, and move their bindings into the function's scope.
+pub(super) fn hoist<'a>(
+    params: &FormalParameters<'a>,
+    body: &mut FunctionBody<'a>,
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Hoisted<'a> {
+    let mut param_symbols = FxHashSet::default();
+    params.bound_names(&mut |ident| {
+        param_symbols.insert(ident.symbol_id());
+    });
+
+    let mut collector = DeclarationCollector::default();
+    collector.visit_function_body(body);
+
+    let mut vars = Vec::with_capacity(collector.bindings.len());
+    let mut renamed = FxHashMap::default();
+    let mut seen = FxHashSet::default();
+    for (symbol_id, name) in collector.bindings {
+        if !seen.insert(symbol_id) {
+            continue;
+        }
+        let scoping = ctx.scoping();
+        let declared_scope_id = scoping.symbol_scope_id(symbol_id);
+        let name = if declared_scope_id == scope_id {
+            name
+        } else {
+            // Block-scoped binding. Rename it if its name is already visible at function level.
+            let new_name = if scoping.find_binding(scope_id, name).is_some()
+                || scoping.root_unresolved_references().contains_key(&name)
+            {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.scoping_mut().rename_symbol(symbol_id, declared_scope_id, new_name);
+                renamed.insert(symbol_id, new_name);
+                new_name
+            } else {
+                name
+            };
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(declared_scope_id, scope_id, new_name);
+            scoping.set_symbol_scope_id(symbol_id, scope_id);
+            new_name
+        };
+        if param_symbols.contains(&symbol_id) {
+            continue;
+        }
+        *ctx.scoping_mut().symbol_flags_mut(symbol_id) = SymbolFlags::FunctionScopedVariable;
+        vars.push(BoundIdentifier::new(name, symbol_id));
+    }
+
+    let mut hoister = Hoister {
+        ctx,
+        scope_id,
+        renamed,
+        arguments: None,
+        uses_this: false,
+        function_depth: 0,
+        arrow_depth: 0,
+    };
+    hoister.visit_function_body(body);
+    Hoisted { vars, arguments: hoister.arguments, uses_this: hoister.uses_this }
+}
+
+/// Collects bindings declared in a generator's body, excluding those in nested functions.
+#[derive(Default)]
+struct DeclarationCollector<'a> {
+    bindings: Vec<(SymbolId, Ident<'a>)>,
+}
+
+impl<'a> DeclarationCollector<'a> {
+    fn add(&mut self, ident: &BindingIdentifier<'a>) {
+        self.bindings.push((ident.symbol_id(), ident.name));
+    }
+}
+
+impl<'a> Visit<'a> for DeclarationCollector<'a> {
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        decl.bound_names(&mut |ident| self.add(ident));
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: ScopeFlags) {
+        if func.is_declaration()
+            && let Some(id) = &func.id
+        {
+            self.add(id);
+        }
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if class.is_declaration()
+            && let Some(id) = &class.id
+        {
+            self.add(id);
+        }
+    }
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
+
+/// Converts declarations into assignments, and replaces references to renamed bindings and `arguments`.
+struct Hoister<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Scope of the generator function
+    scope_id: ScopeId,
+    renamed: FxHashMap<SymbolId, Ident<'a>>,
+    arguments: Option<BoundIdentifier<'a>>,
+    uses_this: bool,
+    function_depth: u32,
+    arrow_depth: u32,
+}
+
+impl<'a> Hoister<'a, '_> {
+    fn is_top_level(&self) -> bool {
+        self.function_depth == 0 && self.arrow_depth == 0
+    }
+
+    /// `var a = 1, b, c = 2` -> `a = 1, c = 2`
+    fn declaration_to_expression(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        if decl.kind.is_using() {
+            self.ctx.state.error(
+                OxcDiagnostic::error(
+                    "`using` declarations in generator functions are not supported.",
+                )
+                .with_label(decl.span),
+            );
+            return None;
+        }
+        let mut assignments = self.ctx.ast.vec();
+        for declarator in &mut decl.declarations {
+            let BindingPattern::BindingIdentifier(ident) = &declarator.id else {
+                self.ctx.state.error(
+                    OxcDiagnostic::error(
+                        "Destructuring declarations in generator functions are not supported without the destructuring transform.",
+                    )
+                    .with_label(declarator.span),
+                );
+                continue;
+            };
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            if let Some(init) = declarator.init.take() {
+                assignments.push(create_assignment(&binding, init, declarator.span, self.ctx));
+            }
+        }
+        match assignments.len() {
+            0 => None,
+            1 => assignments.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(SPAN, assignments)),
+        }
+    }
+
+    /// `function foo() {}` -> `foo = function _foo() {}`
+    fn function_declaration_to_assignment(&mut self, func: &mut Function<'a>) -> Statement<'a> {
+        let id = func.id.take().unwrap();
+        let binding = BoundIdentifier::from_binding_ident(&id);
+        let expr_id = self.ctx.generate_uid(&id.name, func.scope_id(), SymbolFlags::Function);
+        func.r#type = FunctionType::FunctionExpression;
+        func.id = Some(expr_id.create_binding_identifier(self.ctx));
+        let value = Expression::FunctionExpression(self.ctx.ast.alloc(func.take_in(self.ctx.ast)));
+        let assignment = create_assignment(&binding, value, SPAN, self.ctx);
+        self.ctx.ast.statement_expression(SPAN, assignment)
+    }
+
+    /// `class Foo {}` -> `Foo = class {}`
+    fn class_declaration_to_assignment(&mut self, class: &mut Class<'a>) -> Statement<'a> {
+        let id = class.id.take().unwrap();
+        let binding = BoundIdentifier::from_binding_ident(&id);
+        let span = class.span;
+        class.r#type = ClassType::ClassExpression;
+        let value = Expression::ClassExpression(self.ctx.ast.alloc(class.take_in(self.ctx.ast)));
+        let assignment = create_assignment(&binding, value, span, self.ctx);
+        self.ctx.ast.statement_expression(SPAN, assignment)
+    }
+
+    /// `for (var x in y)` -> `for (x in y)`
+    fn convert_loop_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        let ForStatementLeft::VariableDeclaration(decl) = left else { return };
+        let BindingPattern::BindingIdentifier(ident) = &decl.declarations[0].id else {
+            return;
+        };
+        let binding = BoundIdentifier::from_binding_ident(ident);
+        *left = ForStatementLeft::from(binding.create_write_target(self.ctx));
+    }
+}
+
+impl<'a> VisitMut<'a> for Hoister<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id();
+        match symbol_id {
+            Some(symbol_id) => {
+                if let Some(&name) = self.renamed.get(&symbol_id) {
+                    ident.name = name;
+                }
+            }
+            None if self.function_depth == 0 && ident.name == "arguments" => {
+                let binding = self.arguments.get_or_insert_with(|| {
+                    self.ctx.generate_uid(
+                        "args",
+                        self.scope_id,
+                        SymbolFlags::FunctionScopedVariable,
+                    )
+                });
+                let scoping = self.ctx.scoping_mut();
+                scoping.delete_root_unresolved_reference(ident.name, reference_id);
+                scoping.get_reference_mut(reference_id).set_symbol_id(binding.symbol_id);
+                scoping.add_resolved_reference(binding.symbol_id, reference_id);
+                ident.name = binding.name;
+            }
+            None => {}
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_this_expression(&mut self, _this: &mut ThisExpression) {
+        if self.function_depth == 0 {
+            self.uses_this = true;
+        }
+    }
+
+    fn visit_super(&mut self, sup: &mut Super) {
+        if self.function_depth == 0 {
+            self.ctx.state.error(
+                OxcDiagnostic::error("`super` in generator methods is not supported.")
+                    .with_label(sup.span),
+            );
+        }
+    }
+
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        if !self.is_top_level() {
+            walk_mut::walk_statements(self, stmts);
+            return;
+        }
+
+        // Function declarations are hoisted to the top of the block
+        let mut functions = self.ctx.ast.vec();
+        stmts.retain_mut(|stmt| {
+            self.visit_statement(stmt);
+            match stmt {
+                Statement::VariableDeclaration(decl) => {
+                    let span = decl.span;
+                    match self.declaration_to_expression(decl) {
+                        Some(expr) => *stmt = self.ctx.ast.statement_expression(span, expr),
+                        None => return false,
+                    }
+                }
+                Statement::FunctionDeclaration(func) if func.id.is_some() => {
+                    functions.push(self.function_declaration_to_assignment(func));
+                    return false;
+                }
+                Statement::ClassDeclaration(class) if class.id.is_some() => {
+                    *stmt = self.class_declaration_to_assignment(class);
+                }
+                _ => {}
+            }
+            true
+        });
+        if !functions.is_empty() {
+            stmts.splice(0..0, functions);
+        }
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        walk_mut::walk_for_statement(self, stmt);
+        if self.is_top_level()
+            && let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init
+        {
+            let init = self.declaration_to_expression(decl);
+            stmt.init = init.map(ForStatementInit::from);
+        }
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        walk_mut::walk_for_in_statement(self, stmt);
+        if self.is_top_level() {
+            self.convert_loop_left(&mut stmt.left);
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        walk_mut::walk_for_of_statement(self, stmt);
+        if self.is_top_level() {
+            self.convert_loop_left(&mut stmt.left);
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+}
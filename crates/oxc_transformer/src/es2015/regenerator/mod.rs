@@ -0,0 +1,399 @@
+//! ES2015: Regenerator
+//!
+//! This plugin transforms generator functions into state machines, driven by the
+//! `regeneratorRuntime` helper.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   try {
+//!     yield x;
+//!   } finally {
+//!     cleanup();
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.prev = 0;
+//!         _context.next = 3;
+//!         return x;
+//!       case 3:
+//!         _context.prev = 3;
+//!         cleanup();
+//!         return _context.finish(3);
+//!       case 6:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked, null, [[0,, 3, 6]]);
+//! }
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator),
+//! which is a wrapper around [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform).
+//!
+//! The transform happens in two steps:
+//!
+//! 1. All declarations in the body are hoisted to the outer function (see [`hoist`]).
+//! 2. The body is "exploded" into a flat listing of statements, split into the cases of a `switch`
+//!    statement in an inner function (see [`emit`]). The runtime calls the inner function each time
+//!    the generator is resumed, with `_context.next` pointing to the case to continue from.
+//!
+//! Async functions are not handled by this plugin itself. When it is enabled alongside
+//! async-to-generator, the generator functions created by that plugin are transformed here.
+//!
+//! ## Missing features
+//!
+//! * `super` in generator methods.
+//! * Iterators of `for...of` loops are not closed when the loop is exited early.
+//! * Destructuring declarations and `catch` parameters require the destructuring transform.
+//! * Logical assignments, tagged templates and optional chains containing `yield`.
+//! * Async generator functions.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * regenerator-transform: <https://github.com/facebook/regenerator/blob/main/packages/transform/src/emit.js>
+
+use std::cell::Cell;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::{
+        helper_loader::{Helper, helper_call_expr},
+        var_declarations::VarDeclarationsStore,
+    },
+    context::TraverseCtx,
+    es2017::AsyncGeneratorExecutor,
+    state::TransformState,
+    utils::ast_builder::create_member_callee,
+};
+
+use super::destructuring::create_number;
+
+mod emit;
+mod hoist;
+
+use emit::Emitter;
+
+pub struct Regenerator;
+
+impl Regenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Regenerator {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // Methods are transformed in `exit_function`, as they must remain methods
+        if let Expression::FunctionExpression(func) = expr
+            && Self::is_generator(func)
+            && !AsyncGeneratorExecutor::is_class_method_like_ancestor(ctx.parent())
+        {
+            let Expression::FunctionExpression(func) = expr.take_in(ctx.ast) else {
+                unreachable!()
+            };
+            *expr = Self::transform_function_expression(func, ctx);
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_generator(func) {
+            return;
+        }
+        if func.is_declaration() {
+            Self::transform_function_declaration(func, ctx);
+        } else if AsyncGeneratorExecutor::is_class_method_like_ancestor(ctx.parent()) {
+            Self::transform_method(func, ctx);
+        }
+    }
+}
+
+impl<'a> Regenerator {
+    fn is_generator(func: &Function<'a>) -> bool {
+        func.generator && !func.r#async && func.body.is_some()
+    }
+
+    /// Transform a generator function expression.
+    ///
+    /// `function* () { BODY }`
+    /// -> `regeneratorRuntime().mark(function _callee() { return regeneratorRuntime().wrap(...); })`
+    ///
+    /// Also used by async-to-generator, to transform the generator functions it creates.
+    pub(crate) fn transform_function_expression(
+        mut func: ArenaBox<'a, Function<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+            binding
+        };
+        let outer_fn = binding.create_read_expression(ctx);
+        Self::transform_body(&mut func, binding.name.as_str(), Some(outer_fn), ctx);
+
+        let arguments = ctx.ast.vec1(Argument::FunctionExpression(func));
+        let callee = runtime_property("mark", ctx);
+        ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true)
+    }
+
+    /// Transform a generator function declaration.
+    ///
+    /// `function* foo() { BODY }`
+    /// -> `var _marked = regeneratorRuntime().mark(foo); function foo() { return regeneratorRuntime().wrap(...); }`
+    fn transform_function_declaration(func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `export default function* () {}`
+        let binding = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid_in_current_scope("callee", SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+            binding
+        };
+
+        let arguments = ctx.ast.vec1(Argument::from(binding.create_read_expression(ctx)));
+        let callee = runtime_property("mark", ctx);
+        let mark = ctx.ast.expression_call_with_pure(SPAN, callee, NONE, arguments, false, true);
+        let marked = VarDeclarationsStore::create_uid_var_with_init("marked", mark, ctx);
+
+        let outer_fn = marked.create_read_expression(ctx);
+        Self::transform_body(func, binding.name.as_str(), Some(outer_fn), ctx);
+    }
+
+    /// Transform a generator method. It remains a method, and is not marked.
+    ///
+    /// `*foo() { BODY }` -> `foo() { return regeneratorRuntime().wrap(function foo$(_context) { ... }, null, this); }`
+    fn transform_method(func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let key = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => method.key(),
+            Ancestor::ObjectPropertyValue(property) => property.key(),
+            _ => unreachable!(),
+        };
+        let name = match key.static_name().filter(|name| is_identifier_name(name)) {
+            Some(name) => ctx.ast.ident(&name),
+            None => ctx.generate_uid_name("callee"),
+        };
+        Self::transform_body(func, name.as_str(), None, ctx);
+    }
+
+    /// Replace the function's body with a call to `regeneratorRuntime().wrap`, passing an inner function
+    /// containing the state machine.
+    ///
+    /// ```js
+    /// var <hoisted vars>, _args = arguments;
+    /// return regeneratorRuntime().wrap(function name$(_context) {
+    ///   while (1) switch (_context.prev = _context.next) {
+    ///     case 0: ...
+    ///     case "end": return _context.stop();
+    ///   }
+    /// }, outer_fn, this, try_locs);
+    /// ```
+    fn transform_body(
+        func: &mut Function<'a>,
+        name: &str,
+        outer_fn: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        func.generator = false;
+        let scope_id = func.scope_id();
+        let body = func.body.as_mut().unwrap();
+        let hoisted = hoist::hoist(&func.params, body, scope_id, ctx);
+
+        // Scopes of the inner function and the `switch` statement in it
+        let inner_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let statements = body.statements.take_in(ctx.ast);
+        ChildScopeCollector::reparent(&statements, scope_id, switch_scope_id, ctx);
+
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let mut emitter = Emitter::new(context.clone(), ctx);
+        for stmt in statements {
+            emitter.explode_statement(stmt, None);
+        }
+        let (cases, try_locs) = emitter.finish(switch_scope_id);
+
+        // `while (1) switch (_context.prev = _context.next) { ... }`
+        let discriminant = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(Self::context_member(&context, "prev", ctx)),
+            Expression::from(Self::context_member(&context, "next", ctx)),
+        );
+        let switch =
+            ctx.ast.statement_switch_with_scope_id(SPAN, discriminant, cases, switch_scope_id);
+        let while_stmt = ctx.ast.statement_while(SPAN, create_number(1, ctx), switch);
+
+        // `function name$(_context) { ... }`
+        let inner_name = ctx.ast.ident(&format!("{name}$"));
+        let inner_id = ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            context.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let inner_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(while_stmt));
+        let inner = ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_id.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+        );
+
+        // `regeneratorRuntime().wrap(inner, outer_fn, this, try_locs)`, omitting trailing nulls
+        let this = hoisted.uses_this.then(|| ctx.ast.expression_this(SPAN));
+        let mut optional_arguments = [outer_fn, this, try_locs];
+        let len = optional_arguments.iter().rposition(Option::is_some).map_or(0, |index| index + 1);
+        let mut arguments = ctx.ast.vec_with_capacity(len + 1);
+        arguments.push(Argument::FunctionExpression(inner));
+        for argument in optional_arguments.iter_mut().take(len) {
+            let argument = argument.take().unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN));
+            arguments.push(Argument::from(argument));
+        }
+        let callee = runtime_property("wrap", ctx);
+        let wrap = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+
+        // `var a, b, _args = arguments;`
+        let mut declarations = ctx.ast.vec_from_iter(hoisted.vars.iter().map(|binding| {
+            let span = ctx.scoping().symbol_span(binding.symbol_id);
+            let ident =
+                ctx.ast.binding_identifier_with_symbol_id(span, binding.name, binding.symbol_id);
+            ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                BindingPattern::BindingIdentifier(ctx.alloc(ident)),
+                NONE,
+                None,
+                false,
+            )
+        }));
+        if let Some(args) = &hoisted.arguments {
+            let arguments = ctx.create_unbound_ident_expr(
+                SPAN,
+                ctx.ast.ident("arguments"),
+                ReferenceFlags::Read,
+            );
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                args.create_binding_pattern(ctx),
+                NONE,
+                Some(arguments),
+                false,
+            ));
+        }
+
+        let body = func.body.as_mut().unwrap();
+        if !declarations.is_empty() {
+            body.statements.push(Statement::from(ctx.ast.declaration_variable(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+            )));
+        }
+        body.statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+    }
+
+    fn context_member(
+        context: &BoundIdentifier<'a>,
+        name: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let object = context.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, name);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+}
+
+/// `babelHelpers.regeneratorRuntime().name`
+fn runtime_property<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let runtime = helper_call_expr(Helper::RegeneratorRuntime, ctx.ast.vec(), ctx);
+    create_member_callee(runtime, name, SPAN, ctx)
+}
+
+/// Moves scopes which are direct children of the generator function's scope into the `switch` statement
+/// in the inner function, where their statements end up.
+#[derive(Default)]
+struct ChildScopeCollector {
+    scope_ids: Vec<ScopeId>,
+    depth: u32,
+}
+
+impl ChildScopeCollector {
+    fn reparent(
+        statements: &[Statement<'_>],
+        old_parent_id: ScopeId,
+        new_parent_id: ScopeId,
+        ctx: &mut TraverseCtx<'_>,
+    ) {
+        let mut collector = Self::default();
+        for stmt in statements {
+            collector.visit_statement(stmt);
+        }
+        let scoping = ctx.scoping_mut();
+        for scope_id in collector.scope_ids {
+            if scoping.scope_parent_id(scope_id) == Some(old_parent_id) {
+                scoping.set_scope_parent_id(scope_id, Some(new_parent_id));
+            }
+        }
+    }
+}
+
+impl Visit<'_> for ChildScopeCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0
+            && let Some(scope_id) = scope_id.get()
+        {
+            self.scope_ids.push(scope_id);
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
@@ -0,0 +1,295 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var a = [b, ...c, d];
+//! f(...a);
+//! obj.method(x, ...a);
+//! new Foo(...a);
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = [b].concat(babelHelpers.toConsumableArray(c), [d]);
+//! f.apply(void 0, babelHelpers.toConsumableArray(a));
+//! obj.method.apply(obj, [x].concat(babelHelpers.toConsumableArray(a)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(a));
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `iterableIsArray` assumption. Spread arguments are then used as they are,
+//! instead of being converted to arrays with `toConsumableArray`:
+//!
+//! ```js
+//! var a = [b].concat(c, [d]);
+//! ```
+//!
+//! ### `allowArrayLike`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `arrayLikeIsIterable` assumption, allowing array-like objects
+//! (with a `length` property, but not iterable) to be spread.
+//!
+//! ## Implementation
+//!
+//! `super(...args)` calls are not transformed here. The classes transform lowers spread elements
+//! in them when this transform is enabled, as it replaces the whole `super()` call.
+//!
+//! ## Missing features
+//!
+//! * Spread arguments in optional calls (`a?.(...b)`) are only transformed
+//!   if the optional chaining transform is enabled too.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax specification: <https://tc39.es/ecma262/#sec-array-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::{
+        duplicate::duplicate_expression,
+        helper_loader::{Helper, helper_call_expr, helper_load},
+    },
+    context::TraverseCtx,
+    state::TransformState,
+    utils::ast_builder::{create_member_callee, create_prototype_member},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 spread transform.
+pub struct SpreadOptions {
+    /// Enables `iterableIsArray` assumption.
+    pub loose: bool,
+    /// Enables `arrayLikeIsIterable` assumption.
+    pub allow_array_like: bool,
+}
+
+pub struct Spread {
+    options: SpreadOptions,
+}
+
+impl Spread {
+    pub fn new(options: SpreadOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Spread {
+    // `[a, ...b]` -> `[a].concat(_toConsumableArray(b))`
+    // `f(...a)` -> `f.apply(void 0, _toConsumableArray(a))`
+    // `new F(...a)` -> `_construct(F, _toConsumableArray(a))`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) if has_spread_element(&array.elements) => {
+                let span = array.span;
+                let elements = array.elements.take_in(ctx.ast);
+                *expr = transform_array_elements(elements, span, self.options, ctx);
+            }
+            Expression::CallExpression(call)
+                if !call.callee.is_super() && has_spread_argument(&call.arguments) =>
+            {
+                self.transform_call_expression(call, ctx);
+            }
+            Expression::NewExpression(new_expr) if has_spread_argument(&new_expr.arguments) => {
+                let span = new_expr.span;
+                let callee = new_expr.callee.take_in(ctx.ast);
+                let arguments = new_expr.arguments.take_in(ctx.ast);
+                let arguments = transform_arguments(arguments, self.options, ctx);
+                let arguments = ctx.ast.vec_from_array([callee.into(), arguments.into()]);
+                *expr = helper_call_expr(Helper::Construct, arguments, ctx);
+                if let Expression::CallExpression(call) = expr {
+                    call.span = span;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Spread {
+    /// `obj.f(...a)` -> `obj.f.apply(obj, _toConsumableArray(a))`
+    fn transform_call_expression(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let arguments = call.arguments.take_in(ctx.ast);
+        let arguments = transform_arguments(arguments, self.options, ctx);
+
+        let this = if let Some(member) = call.callee.as_member_expression_mut() {
+            let object = member.object_mut();
+            let (first, second) = duplicate_expression(object.take_in(ctx.ast), true, ctx);
+            *object = first;
+            if second.is_super() { ctx.ast.expression_this(SPAN) } else { second }
+        } else {
+            ctx.ast.void_0(SPAN)
+        };
+
+        let callee = call.callee.take_in(ctx.ast);
+        call.callee = create_member_callee(callee, "apply", SPAN, ctx);
+        call.arguments = ctx.ast.vec_from_array([this.into(), arguments.into()]);
+    }
+}
+
+/// Lower an array literal containing spread elements.
+///
+/// * `[...a]` -> `_toConsumableArray(a)`
+/// * `[a, ...b, c]` -> `[a].concat(_toConsumableArray(b), [c])`
+pub(super) fn transform_array_elements<'a>(
+    elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    span: Span,
+    options: SpreadOptions,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let (nodes, is_copied) = build(elements, options, ctx);
+    let mut nodes = nodes.into_iter();
+    let first = nodes.next().unwrap();
+    // `toConsumableArray` and `Array.prototype.slice.call` already create a new array
+    if is_copied && nodes.len() == 0 {
+        return first;
+    }
+    let (first, rest) = if matches!(first, Expression::ArrayExpression(_)) {
+        (first, ctx.ast.vec_from_iter(nodes.map(Argument::from)))
+    } else {
+        let rest = ctx.ast.vec_from_iter(std::iter::once(first).chain(nodes).map(Argument::from));
+        (ctx.ast.expression_array(SPAN, ctx.ast.vec()), rest)
+    };
+    let callee = create_member_callee(first, "concat", SPAN, ctx);
+    ctx.ast.expression_call(span, callee, NONE, rest, false)
+}
+
+/// Lower call arguments containing spread elements to a single array-like expression.
+///
+/// * `(...arguments)` -> `arguments`
+/// * `(a, ...b)` -> `[a].concat(_toConsumableArray(b))`
+pub(super) fn transform_arguments<'a>(
+    arguments: ArenaVec<'a, Argument<'a>>,
+    options: SpreadOptions,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    if let [Argument::SpreadElement(spread)] = arguments.as_slice()
+        && is_arguments(&spread.argument)
+    {
+        let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+            unreachable!()
+        };
+        return spread.unbox().argument;
+    }
+
+    let elements = ctx.ast.vec_from_iter(arguments.into_iter().map(ArrayExpressionElement::from));
+    let (nodes, _) = build(elements, options, ctx);
+    let mut nodes = nodes.into_iter();
+    let first = nodes.next().unwrap();
+    if nodes.len() == 0 {
+        return first;
+    }
+    let callee = create_member_callee(first, "concat", SPAN, ctx);
+    let rest = ctx.ast.vec_from_iter(nodes.map(Argument::from));
+    ctx.ast.expression_call(SPAN, callee, NONE, rest, false)
+}
+
+/// Split elements into arrays, with one array for each spread element, and one for each run of
+/// other elements in between.
+///
+/// Also returns whether the elements consist of a single spread element, which has been copied
+/// into a new array.
+fn build<'a>(
+    elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    options: SpreadOptions,
+    ctx: &mut TraverseCtx<'a>,
+) -> (Vec<Expression<'a>>, bool) {
+    let mut nodes = vec![];
+    let mut is_copied = false;
+    let mut props = ctx.ast.vec();
+    for element in elements {
+        if let ArrayExpressionElement::SpreadElement(spread) = element {
+            if !props.is_empty() {
+                nodes.push(ctx.ast.expression_array(SPAN, props));
+                props = ctx.ast.vec();
+            }
+            let (mut literal, copied) = get_spread_literal(spread.unbox().argument, options, ctx);
+            is_copied = copied;
+            // `[...[a, , b]]` -> `_arrayLikeToArray([a, , b])`, to fill the holes with `undefined`
+            if let Expression::ArrayExpression(array) = &literal
+                && array.elements.iter().any(ArrayExpressionElement::is_elision)
+            {
+                let arguments = ctx.ast.vec1(Argument::from(literal));
+                literal = helper_call_expr(Helper::ArrayLikeToArray, arguments, ctx);
+                is_copied = true;
+            }
+            nodes.push(literal);
+        } else {
+            props.push(element);
+        }
+    }
+    if !props.is_empty() {
+        nodes.push(ctx.ast.expression_array(SPAN, props));
+    }
+    is_copied &= nodes.len() == 1;
+    (nodes, is_copied)
+}
+
+/// Convert the argument of a spread element to an array.
+///
+/// Returns `true` as second value if the argument is copied into a new array.
+fn get_spread_literal<'a>(
+    argument: Expression<'a>,
+    options: SpreadOptions,
+    ctx: &mut TraverseCtx<'a>,
+) -> (Expression<'a>, bool) {
+    let iterable_is_array = options.loose || ctx.state.assumptions.iterable_is_array;
+    if is_arguments(&argument) {
+        // `Array.prototype.slice.call(arguments)`
+        let array =
+            ctx.create_unbound_ident_expr(SPAN, ctx.ast.ident("Array"), ReferenceFlags::Read);
+        let slice =
+            create_member_callee(create_prototype_member(array, SPAN, ctx), "slice", SPAN, ctx);
+        let callee = create_member_callee(slice, "call", SPAN, ctx);
+        let arguments = ctx.ast.vec1(Argument::from(argument));
+        return (ctx.ast.expression_call(SPAN, callee, NONE, arguments, false), true);
+    }
+    if iterable_is_array || matches!(argument, Expression::ArrayExpression(_)) {
+        return (argument, false);
+    }
+
+    let array_like_is_iterable =
+        options.allow_array_like || ctx.state.assumptions.array_like_is_iterable;
+    let literal = if array_like_is_iterable {
+        // `_maybeArrayLike(_toConsumableArray, a)`
+        let to_consumable_array = helper_load(Helper::ToConsumableArray, ctx);
+        let arguments = ctx.ast.vec_from_array([to_consumable_array.into(), argument.into()]);
+        helper_call_expr(Helper::MaybeArrayLike, arguments, ctx)
+    } else {
+        helper_call_expr(Helper::ToConsumableArray, ctx.ast.vec1(Argument::from(argument)), ctx)
+    };
+    (literal, true)
+}
+
+fn is_arguments(expr: &Expression) -> bool {
+    matches!(expr, Expression::Identifier(ident) if ident.name == "arguments")
+}
+
+fn has_spread_element(elements: &[ArrayExpressionElement]) -> bool {
+    elements.iter().any(ArrayExpressionElement::is_spread)
+}
+
+fn has_spread_argument(arguments: &[Argument]) -> bool {
+    arguments.iter().any(Argument::is_spread)
+}
@@ -0,0 +1,195 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `foo${bar}baz${qux}`;
+//! tag`a${b}c`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "foo".concat(bar, "baz").concat(qux);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "c"])), b);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+//!
+//! With `ignoreToPrimitiveHint`, template literals are joined with the `+` operator instead of
+//! `String.prototype.concat`. This calls `valueOf` rather than `toString` on objects:
+//!
+//! ```js
+//! "foo" + bar + "baz" + qux;
+//! ```
+//!
+//! With `mutableTemplateObject`, the template object of a tagged template is not frozen,
+//! and the `taggedTemplateLiteralLoose` helper is used instead.
+//!
+//! ## Implementation
+//!
+//! `.concat` calls are built the same way as Babel's: every literal is appended to the arguments
+//! of the previous `.concat` call, while only the first non-literal expression can be. Each
+//! following expression starts a new `.concat` call, so that expressions are still converted to
+//! strings in source order, after the preceding ones have been evaluated.
+//!
+//! Tagged templates cache their template object in a top-level `var`, shared with the
+//! `taggedTemplateTransform` plugin.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals specification: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{
+    common::helper_loader::Helper, context::TraverseCtx,
+    plugins::tagged_template_transform::TaggedTemplateTransform, state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES2015 template literals transform.
+pub struct TemplateLiteralsOptions {
+    /// Enables `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals {
+    options: TemplateLiteralsOptions,
+}
+
+impl TemplateLiterals {
+    pub fn new(options: TemplateLiteralsOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TemplateLiterals {
+    // `` `a${b}` `` -> `"a".concat(b)`
+    // `` tag`a${b}` `` -> `tag(_templateObject || (_templateObject = ...), b)`
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(_) => self.transform_template_literal(expr, ctx),
+            Expression::TaggedTemplateExpression(_) => self.transform_tagged_template(expr, ctx),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> TemplateLiterals {
+    fn transform_template_literal(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::TemplateLiteral(template) = expr.take_in(ctx.ast) else { unreachable!() };
+        let TemplateLiteral { span, quasis, expressions, .. } = template.unbox();
+
+        let mut nodes = Vec::with_capacity(quasis.len() + expressions.len());
+        let mut expressions = expressions.into_iter();
+        for quasi in quasis {
+            if let Some(cooked) = quasi.value.cooked
+                && !cooked.is_empty()
+            {
+                nodes.push(ctx.ast.expression_string_literal_with_lone_surrogates(
+                    quasi.span,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                ));
+            }
+            if let Some(expression) = expressions.next()
+                && !matches!(&expression, Expression::StringLiteral(lit) if lit.value.is_empty())
+            {
+                nodes.push(expression);
+            }
+        }
+
+        let ignore_to_primitive_hint =
+            self.options.loose || ctx.state.assumptions.ignore_to_primitive_hint;
+
+        // `+` and `.concat` are left-associative, so make sure the result is a string
+        // by starting with a string, unless the second operand of `+` is one.
+        let is_string =
+            |node: Option<&Expression>| matches!(node, Some(Expression::StringLiteral(_)));
+        if !(is_string(nodes.first()) || ignore_to_primitive_hint && is_string(nodes.get(1))) {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let first = nodes.next().unwrap();
+        *expr = if ignore_to_primitive_hint {
+            nodes.fold(first, |left, right| {
+                ctx.ast.expression_binary(SPAN, left, BinaryOperator::Addition, right)
+            })
+        } else {
+            Self::build_concat_call_expressions(first, nodes, ctx)
+        };
+        match expr {
+            Expression::CallExpression(call) => call.span = span,
+            Expression::BinaryExpression(binary) => binary.span = span,
+            _ => {}
+        }
+    }
+
+    /// `"a".concat(b, "c").concat(d)`
+    ///
+    /// Literals can always be added to the previous `.concat` call, but only the first
+    /// non-literal can, as converting it to a string must not happen after the next one is evaluated.
+    fn build_concat_call_expressions(
+        first: Expression<'a>,
+        rest: impl Iterator<Item = Expression<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut avail = true;
+        rest.fold(first, |mut left, right| {
+            let mut can_be_inserted = right.is_literal();
+            if !can_be_inserted && avail {
+                can_be_inserted = true;
+                avail = false;
+            }
+            if can_be_inserted && let Expression::CallExpression(call) = &mut left {
+                call.arguments.push(Argument::from(right));
+                return left;
+            }
+            let callee = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                left,
+                ctx.ast.identifier_name(SPAN, "concat"),
+                false,
+            ));
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(right)), false)
+        })
+    }
+
+    fn transform_tagged_template(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::TaggedTemplateExpression(tagged) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let TaggedTemplateExpression { span, tag, quasi, type_arguments, .. } = tagged.unbox();
+
+        let helper = if self.options.loose || ctx.state.assumptions.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let binding = TaggedTemplateTransform::create_top_level_binding(ctx);
+        let arguments =
+            TaggedTemplateTransform::transform_template_literal(&binding, quasi, helper, ctx);
+        *expr = ctx.ast.expression_call(span, tag, type_arguments, arguments, false);
+    }
+}
@@ -68,6 +68,7 @@ use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 use crate::{
     common::helper_loader::{Helper, helper_call_expr},
     context::TraverseCtx,
+    es2015::Regenerator,
     state::TransformState,
 };
 
@@ -728,8 +729,12 @@ impl<'a> AsyncGeneratorExecutor<'a> {
             ctx,
         );
         function.generator = true;
-        let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
-        helper_call_expr(self.helper, arguments, ctx)
+        let argument = if ctx.state.is_regenerator_plugin_enabled {
+            Argument::from(Regenerator::transform_function_expression(function, ctx))
+        } else {
+            Argument::FunctionExpression(function)
+        };
+        helper_call_expr(self.helper, ctx.ast.vec1(argument), ctx)
     }
 
     /// Creates a helper declaration statement for async-to-generator transformation.
@@ -33,6 +33,7 @@ mod es2021;
 mod es2022;
 mod es2026;
 mod jsx;
+mod modules;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -53,6 +54,8 @@ use es2021::ES2021;
 use es2022::ES2022;
 use es2026::ES2026;
 use jsx::Jsx;
+use modules::Modules;
+use proposals::Proposals;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
 use state::TransformState;
@@ -64,7 +67,10 @@ pub use crate::{
     compiler_assumptions::CompilerAssumptions,
     context::TransformCtx,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions,
+        ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -74,6 +80,7 @@ pub use crate::{
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    modules::{CommonJsOptions, ImportInterop},
     options::{
         ESFeature, ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
@@ -107,7 +114,6 @@ pub struct Transformer<'a> {
     plugins: PluginsOptions,
     jsx: JsxOptions,
     env: EnvOptions,
-    #[expect(dead_code)]
     proposals: ProposalOptions,
 }
 
@@ -158,6 +164,7 @@ impl<'a> Transformer<'a> {
             common: Common::new(&self.env),
             decorator: Decorator::new(self.decorator),
             plugins: Plugins::new(self.plugins),
+            proposals: Proposals::new(self.proposals),
             x0_typescript: program
                 .source_type
                 .is_typescript()
@@ -182,6 +189,7 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017),
             x3_es2015: ES2015::new(self.env.es2015),
             x4_regexp: RegExp::new(self.env.regexp),
+            modules: Modules::new(&self.env),
         };
 
         let mut reusable_ctx = ReusableTraverseCtx::new(self.state, scoping, allocator);
@@ -198,6 +206,7 @@ struct TransformerImpl<'a> {
     x0_typescript: Option<TypeScript<'a>>,
     decorator: Decorator<'a>,
     plugins: Plugins<'a>,
+    proposals: Proposals<'a>,
     x1_jsx: Jsx<'a>,
     x2_es2026: ES2026<'a>,
     x2_es2022: ES2022<'a>,
@@ -207,10 +216,10 @@ struct TransformerImpl<'a> {
     x2_es2018: ES2018<'a>,
     x2_es2017: ES2017<'a>,
     x2_es2016: ES2016<'a>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a>,
     x4_regexp: RegExp,
     common: Common<'a>,
+    modules: Modules,
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
@@ -233,6 +242,10 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2020.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        // Convert `let` declarations inserted by `common`
+        self.x3_es2015.exit_program(program, ctx);
+        // Convert `import`s inserted by `common`, and all other module syntax
+        self.modules.exit_program(program, ctx);
     }
 
     // ALPHASORT
@@ -246,6 +259,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declaration(
@@ -256,6 +270,14 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
     }
 
+    fn exit_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_variable_declaration(decl, ctx);
+    }
+
     fn enter_variable_declarator(
         &mut self,
         decl: &mut VariableDeclarator<'a>,
@@ -301,6 +323,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_binding_identifier(node, ctx);
+        self.x3_es2015.enter_binding_identifier(node, ctx);
     }
 
     fn enter_identifier_reference(
@@ -309,6 +332,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -336,6 +360,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_class(class, ctx);
         }
+        self.x3_es2015.enter_class(class, ctx);
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -369,7 +394,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
+        self.decorator.enter_expression(expr, ctx);
         self.plugins.enter_expression(expr, ctx);
+        self.proposals.enter_expression(expr, ctx);
         self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
@@ -384,6 +411,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -396,6 +424,16 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
     }
 
+    fn enter_assignment_target_property(
+        &mut self,
+        node: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(typescript) = self.x0_typescript.as_mut() {
+            typescript.enter_assignment_target_property(node, ctx);
+        }
+    }
+
     fn enter_assignment_target(
         &mut self,
         node: &mut AssignmentTarget<'a>,
@@ -407,6 +445,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x2_es2022.enter_assignment_target(node, ctx);
     }
 
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(typescript) = self.x0_typescript.as_mut() {
+            typescript.enter_object_property(prop, ctx);
+        }
+    }
+
     fn enter_formal_parameters(
         &mut self,
         node: &mut FormalParameters<'a>,
@@ -436,6 +480,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -445,6 +490,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -566,6 +612,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statements(stmts, ctx);
         }
+        self.decorator.enter_statements(stmts, ctx);
     }
 
     fn exit_arrow_function_expression(
@@ -615,6 +662,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.exit_statements(stmts, ctx);
         }
         self.common.exit_statements(stmts, ctx);
+        // Convert `let` declarations inserted by `common`
+        self.x3_es2015.exit_statements(stmts, ctx);
     }
 
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -624,6 +673,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -643,6 +693,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
         self.x2_es2018.enter_statement(stmt, ctx);
         self.x2_es2026.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
     }
 
     fn enter_declaration(&mut self, decl: &mut Declaration<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -657,10 +708,27 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
     }
 
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
+    }
+
     fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_while_statement(stmt, ctx);
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_while_statement(stmt, ctx);
     }
 
     fn enter_do_while_statement(
@@ -671,12 +739,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_do_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_do_while_statement(stmt, ctx);
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_do_while_statement(stmt, ctx);
     }
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -685,6 +767,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
         }
         self.x2_es2026.enter_for_of_statement(stmt, ctx);
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -692,6 +779,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -701,6 +793,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a> {
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
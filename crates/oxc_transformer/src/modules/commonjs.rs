@@ -0,0 +1,1122 @@
+//! ES Modules to CommonJS
+//!
+//! This plugin transforms ES module syntax (`import` / `export`) to CommonJS (`require` / `exports`).
+//!
+//! > This plugin is included in `preset-env` when `modules` is `"commonjs"`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export const baz = foo(bar);
+//! export default baz;
+//! ```
+//!
+//! Output:
+//! ```js
+//! "use strict";
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! Object.defineProperty(exports, "baz", {
+//!   enumerable: true,
+//!   get: function () { return baz; }
+//! });
+//! Object.defineProperty(exports, "default", {
+//!   enumerable: true,
+//!   get: function () { return _default; }
+//! });
+//! var _foo = _interopRequireWildcard(require("foo"));
+//! const baz = (0, _foo.default)(_foo.bar);
+//! var _default = baz;
+//! ```
+//!
+//! ## Implementation
+//!
+//! The transform runs on exit of the program, after all other transforms, so `import`s inserted by
+//! other transforms (runtime helpers, JSX automatic runtime) are converted too.
+//!
+//! * `import` and `export` declarations are removed. Each source gets a single `require` call,
+//!   hoisted to the top of the module in the order sources first appear.
+//! * Every export is defined on `exports` with a getter, so exports are live bindings, as in ESM.
+//!   Unlike Babel, which emits `exports.x = x` after every assignment to an exported binding,
+//!   getters do not require tracking assignments.
+//! * References to imported bindings are replaced with member expressions on the required module.
+//!   Calls become `(0, _foo.bar)()`, so the module object is not passed as `this`.
+//! * Top-level `this` is replaced with `void 0`.
+//! * `import(x)` is replaced with `Promise.resolve().then(() => require(x))`.
+//!
+//! ## Options
+//!
+//! ### `importInterop`
+//!
+//! `"babel"` | `"node"` | `"none"`, defaults to `"babel"`.
+//!
+//! * `"babel"`: `require` calls are wrapped in `_interopRequireDefault` / `_interopRequireWildcard`,
+//!   so a CommonJS module without an `__esModule` marker is treated as the default export.
+//! * `"node"`: Follow Node.js semantics. The default import is `module.exports` itself.
+//! * `"none"`: No interop. The default import is `module.exports.default`.
+//!
+//! ## Missing features
+//!
+//! * Assignments to imported bindings are left as is, rather than rewritten to throw.
+//! * Imported bindings used as JSX element names are not rewritten.
+//! * `lazy`, `strict`, `strictMode` and `allowTopLevelThis` options.
+//!
+//! ## References
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-modules-commonjs>
+//! * Babel module transforms helper: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-helper-module-transforms>
+//! * `importInterop` option: <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs#importinterop>
+
+use std::cell::Cell;
+
+use indexmap::IndexMap;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ReferenceId, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{SPAN, Span};
+use oxc_str::Str;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    operator::{BinaryOperator, LogicalOperator},
+};
+use oxc_traverse::{BoundIdentifier, ast_operations::to_identifier};
+
+use crate::{
+    common::{
+        helper_loader::{Helper, helper_call_expr},
+        module_imports::{Import, ModuleImportsStore},
+    },
+    context::TraverseCtx,
+    utils::ast_builder::{create_member_callee, create_prototype_member},
+};
+
+/// How `require`d modules are exposed to default and namespace imports.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportInterop {
+    /// Interop with CommonJS modules via `_interopRequireDefault` / `_interopRequireWildcard`.
+    #[default]
+    Babel,
+    /// Node.js semantics: the default import is `module.exports`.
+    Node,
+    /// No interop: the default import is `module.exports.default`.
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+/// Options for the ES modules to CommonJS transform.
+pub struct CommonJsOptions {
+    /// How imports of CommonJS modules are resolved.
+    pub import_interop: ImportInterop,
+}
+
+pub struct CommonJs {
+    options: CommonJsOptions,
+    /// `true` if arrow functions are transformed, so callbacks must be emitted as function expressions
+    arrow_functions: bool,
+}
+
+impl CommonJs {
+    pub fn new(options: CommonJsOptions, arrow_functions: bool) -> Self {
+        Self { options, arrow_functions }
+    }
+}
+
+/// What a `require` call is wrapped in.
+#[derive(Clone, Copy)]
+enum Interop {
+    /// `require("foo")`
+    None,
+    /// `_interopRequireDefault(require("foo"))`
+    Default,
+    /// `_interopRequireWildcard(require("foo"))`
+    Wildcard,
+    /// `_interopRequireWildcard(require("foo"), true)`
+    NodeWildcard,
+}
+
+/// Everything imported from or re-exported from one source.
+#[derive(Default)]
+struct SourceInfo<'a> {
+    /// Binding of the first `import * as ns` of this source, which is reused as the module variable
+    namespace: Option<(BoundIdentifier<'a>, Span)>,
+    /// Source is imported or re-exported as a namespace (`import * as ns`, `export * as ns`)
+    needs_namespace: bool,
+    /// `default` is imported or re-exported
+    needs_default: bool,
+    /// Names other than `default` are imported or re-exported
+    needs_named: bool,
+    /// `export * from "foo"`
+    export_all: bool,
+    /// Local bindings of import specifiers, and the name they import. `None` for namespace imports.
+    imports: Vec<(SymbolId, Option<Str<'a>>)>,
+}
+
+impl SourceInfo<'_> {
+    fn needs_module_binding(&self) -> bool {
+        !self.imports.is_empty() || self.needs_namespace || self.needs_default || self.needs_named
+    }
+}
+
+/// Value of an export.
+enum ExportValue<'a> {
+    /// A local binding or other expression
+    Local(Expression<'a>),
+    /// `export { imported as exported } from "source"`, or `export * as exported from "source"`
+    /// when `imported` is `None`
+    Reexport { source: usize, imported: Option<Str<'a>> },
+}
+
+/// Replacement for references to an imported binding.
+struct ImportBinding<'a> {
+    /// Module variable which the import resolves to
+    module: BoundIdentifier<'a>,
+    /// Property of the module. `None` if the binding is the module itself.
+    property: Option<Str<'a>>,
+}
+
+impl<'a> CommonJs {
+    pub fn exit_program(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !program.source_type.is_module() {
+            // Scripts have no `import` / `export` declarations, but may contain `import()`
+            let mut rewriter = ReferenceRewriter::new(FxHashMap::default(), false, self, ctx);
+            rewriter.visit_program(program);
+            Self::insert_helper_requires(program, ctx);
+            return;
+        }
+
+        if !program.directives.iter().any(Directive::is_use_strict) {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+
+        let mut sources = IndexMap::<Str<'a>, SourceInfo<'a>>::default();
+        let mut exports = vec![];
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(decl) => {
+                    Self::collect_import(decl.unbox(), &mut sources);
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    Self::collect_export_named(
+                        decl.unbox(),
+                        &mut sources,
+                        &mut exports,
+                        &mut body,
+                        ctx,
+                    );
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    Self::collect_export_default(decl.unbox(), &mut exports, &mut body, ctx);
+                }
+                Statement::ExportAllDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    let (index, info) = Self::source_entry(&mut sources, decl.source.value);
+                    if let Some(exported) = &decl.exported {
+                        info.needs_namespace = true;
+                        exports.push((
+                            exported.name(),
+                            ExportValue::Reexport { source: index, imported: None },
+                        ));
+                    } else {
+                        info.export_all = true;
+                        info.needs_named = true;
+                    }
+                }
+                stmt => body.push(stmt),
+            }
+        }
+
+        // Create module variables
+        let mut import_bindings = FxHashMap::default();
+        let mut modules = Vec::with_capacity(sources.len());
+        for (source, info) in &sources {
+            let module = if let Some((namespace, _)) = &info.namespace {
+                *ctx.scoping_mut().symbol_flags_mut(namespace.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                Some(namespace.clone())
+            } else if info.needs_module_binding() {
+                let name = module_variable_name(source);
+                Some(ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable))
+            } else {
+                None
+            };
+            if let Some(module) = &module {
+                for &(symbol_id, imported) in &info.imports {
+                    let property = imported.and_then(|name| self.module_property(name, info));
+                    import_bindings
+                        .insert(symbol_id, ImportBinding { module: module.clone(), property });
+                }
+            }
+            modules.push(module);
+        }
+
+        let mut header = ctx.ast.vec();
+        let has_exports = !exports.is_empty() || sources.values().any(|info| info.export_all);
+        if has_exports {
+            header.push(Self::create_es_module_marker(ctx));
+        }
+
+        // `var _exportNames = { foo: true };`
+        let export_names = (!exports.is_empty() && sources.values().any(|info| info.export_all))
+            .then(|| {
+                let binding = ctx
+                    .generate_uid_in_root_scope("exportNames", SymbolFlags::FunctionScopedVariable);
+                let properties = ctx.ast.vec_from_iter(
+                    exports.iter().filter(|(name, _)| name.as_str() != "default").map(
+                        |(name, _)| {
+                            let value = ctx.ast.expression_boolean_literal(SPAN, true);
+                            create_property(*name, value, ctx)
+                        },
+                    ),
+                );
+                let init = ctx.ast.expression_object(SPAN, properties);
+                header.push(create_var_declaration(binding.create_binding_pattern(ctx), init, ctx));
+                binding
+            });
+
+        // Getters for exports
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for (name, value) in exports {
+            let value = match value {
+                ExportValue::Local(expr) => expr,
+                ExportValue::Reexport { source, imported } => {
+                    let info = &sources[source];
+                    let module = modules[source].as_ref().unwrap();
+                    let property = imported.and_then(|name| self.module_property(name, info));
+                    create_module_access(module, property, SPAN, None, ctx)
+                }
+            };
+            let key = ctx.ast.expression_string_literal(SPAN, name, None);
+            header.push(Self::create_export_getter(key, value, root_scope_id, ctx));
+        }
+
+        // Requires
+        for ((source, info), module) in sources.iter().zip(&modules) {
+            let source_literal = ctx.ast.expression_string_literal(SPAN, *source, None);
+            let require = Self::create_require_call(source_literal, ctx);
+            let Some(module) = module else {
+                header.push(ctx.ast.statement_expression(SPAN, require));
+                continue;
+            };
+            let init = Self::create_interop(self.interop(info), require, ctx);
+            let span = info.namespace.as_ref().map_or(SPAN, |(_, span)| *span);
+            let id = ctx.ast.binding_pattern_binding_identifier_with_symbol_id(
+                span,
+                module.name,
+                module.symbol_id,
+            );
+            header.push(create_var_declaration(id, init, ctx));
+            if info.export_all {
+                header.push(Self::create_export_all(module, export_names.as_ref(), ctx));
+            }
+        }
+
+        header.extend(body);
+        program.body = header;
+
+        // Rewrite references to imports, top-level `this`, and `import()`
+        let symbol_ids = import_bindings.keys().copied().collect::<Vec<_>>();
+        let mut rewriter = ReferenceRewriter::new(import_bindings, true, self, ctx);
+        rewriter.visit_program(program);
+        for symbol_id in symbol_ids {
+            let name = ctx.ast.ident(ctx.scoping().symbol_name(symbol_id));
+            ctx.scoping_mut().remove_binding(root_scope_id, name);
+        }
+
+        Self::insert_helper_requires(program, ctx);
+    }
+
+    /// Record `import` declaration.
+    fn collect_import(
+        decl: ImportDeclaration<'a>,
+        sources: &mut IndexMap<Str<'a>, SourceInfo<'a>>,
+    ) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let (_, info) = Self::source_entry(sources, decl.source.value);
+        let Some(specifiers) = decl.specifiers else { return };
+        for specifier in specifiers {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    let imported = specifier.imported.name();
+                    if imported == "default" {
+                        info.needs_default = true;
+                    } else {
+                        info.needs_named = true;
+                    }
+                    info.imports.push((specifier.local.symbol_id(), Some(imported)));
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    info.needs_default = true;
+                    info.imports.push((specifier.local.symbol_id(), Some(Str::from("default"))));
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    info.needs_namespace = true;
+                    if info.namespace.is_none() {
+                        let binding = BoundIdentifier::from_binding_ident(&specifier.local);
+                        info.namespace = Some((binding, specifier.local.span));
+                    } else {
+                        info.imports.push((specifier.local.symbol_id(), None));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record `export` declaration with named exports, and move any declaration into `body`.
+    fn collect_export_named(
+        decl: ExportNamedDeclaration<'a>,
+        sources: &mut IndexMap<Str<'a>, SourceInfo<'a>>,
+        exports: &mut Vec<(Str<'a>, ExportValue<'a>)>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.export_kind.is_type() {
+            return;
+        }
+
+        if let Some(declaration) = decl.declaration {
+            if declaration.is_typescript_syntax() {
+                return;
+            }
+            declaration.bound_names(&mut |ident| {
+                let value = ctx.create_bound_ident_expr(
+                    SPAN,
+                    ident.name,
+                    ident.symbol_id(),
+                    ReferenceFlags::Read,
+                );
+                exports.push((ident.name.into(), ExportValue::Local(value)));
+            });
+            body.push(Statement::from(declaration));
+            return;
+        }
+
+        if let Some(source) = &decl.source {
+            let (index, info) = Self::source_entry(sources, source.value);
+            for specifier in &decl.specifiers {
+                if specifier.export_kind.is_type() {
+                    continue;
+                }
+                let imported = specifier.local.name();
+                if imported == "default" {
+                    info.needs_default = true;
+                } else {
+                    info.needs_named = true;
+                }
+                exports.push((
+                    specifier.exported.name(),
+                    ExportValue::Reexport { source: index, imported: Some(imported) },
+                ));
+            }
+            return;
+        }
+
+        for specifier in decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+                continue;
+            };
+            let symbol_id = ctx.scoping().get_reference(local.reference_id()).symbol_id();
+            ctx.delete_reference_for_identifier(local);
+            let value = ctx.create_ident_expr(SPAN, local.name, symbol_id, ReferenceFlags::Read);
+            exports.push((specifier.exported.name(), ExportValue::Local(value)));
+        }
+    }
+
+    /// Record `export default` declaration, and move the declaration into `body`.
+    ///
+    /// * `export default function foo() {}` -> `function foo() {}`
+    /// * `export default class {}` -> `class _default {}`
+    /// * `export default expr` -> `var _default = expr;`
+    fn collect_export_default(
+        decl: ExportDefaultDeclaration<'a>,
+        exports: &mut Vec<(Str<'a>, ExportValue<'a>)>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (binding, stmt) = match decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                if func.is_typescript_syntax() {
+                    return;
+                }
+                let binding =
+                    Self::default_declaration_binding(&mut func.id, SymbolFlags::Function, ctx);
+                (binding, Statement::FunctionDeclaration(func))
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                if class.is_typescript_syntax() {
+                    return;
+                }
+                let binding =
+                    Self::default_declaration_binding(&mut class.id, SymbolFlags::Class, ctx);
+                (binding, Statement::ClassDeclaration(class))
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => return,
+            kind => {
+                let binding =
+                    ctx.generate_uid_in_root_scope("default", SymbolFlags::FunctionScopedVariable);
+                let init = kind.into_expression();
+                let stmt = create_var_declaration(binding.create_binding_pattern(ctx), init, ctx);
+                (binding, stmt)
+            }
+        };
+        body.push(stmt);
+        let value = binding.create_read_expression(ctx);
+        exports.push((Str::from("default"), ExportValue::Local(value)));
+    }
+
+    /// Get binding of a default exported function or class, naming it `_default` if it is anonymous.
+    fn default_declaration_binding(
+        id: &mut Option<BindingIdentifier<'a>>,
+        flags: SymbolFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if let Some(id) = id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid_in_root_scope("default", flags);
+            *id = Some(binding.create_binding_identifier(ctx));
+            binding
+        }
+    }
+
+    fn source_entry<'s>(
+        sources: &'s mut IndexMap<Str<'a>, SourceInfo<'a>>,
+        source: Str<'a>,
+    ) -> (usize, &'s mut SourceInfo<'a>) {
+        let entry = sources.entry(source);
+        let index = entry.index();
+        (index, entry.or_default())
+    }
+
+    /// Get interop applied to `require` call for a source.
+    fn interop(&self, info: &SourceInfo<'a>) -> Interop {
+        match self.options.import_interop {
+            ImportInterop::Babel => {
+                if info.needs_namespace || (info.needs_default && info.needs_named) {
+                    Interop::Wildcard
+                } else if info.needs_default {
+                    Interop::Default
+                } else {
+                    Interop::None
+                }
+            }
+            ImportInterop::Node if info.needs_namespace => Interop::NodeWildcard,
+            ImportInterop::Node | ImportInterop::None => Interop::None,
+        }
+    }
+
+    /// Get property of module variable which an imported name resolves to.
+    /// `None` if it resolves to the module variable itself.
+    fn module_property(&self, imported: Str<'a>, info: &SourceInfo<'a>) -> Option<Str<'a>> {
+        if imported == "default"
+            && self.options.import_interop == ImportInterop::Node
+            && !info.needs_namespace
+        {
+            None
+        } else {
+            Some(imported)
+        }
+    }
+
+    /// Wrap `require` call in interop helper.
+    fn create_interop(
+        interop: Interop,
+        require: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let helper = match interop {
+            Interop::None => return require,
+            Interop::Default => Helper::InteropRequireDefault,
+            Interop::Wildcard | Interop::NodeWildcard => Helper::InteropRequireWildcard,
+        };
+        let mut arguments = ctx.ast.vec1(Argument::from(require));
+        if matches!(interop, Interop::NodeWildcard) {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        helper_call_expr(helper, arguments, ctx)
+    }
+
+    /// `require(source)`
+    fn create_require_call(source: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let name = ctx.ast.ident("require");
+        let symbol_id = ctx.scoping().get_root_binding(name);
+        let callee = ctx.create_ident_expr(SPAN, name, symbol_id, ReferenceFlags::Read);
+        ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(source)), false)
+    }
+
+    /// `exports`
+    fn create_exports(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, ctx.ast.ident("exports"), ReferenceFlags::Read)
+    }
+
+    /// `Object`
+    fn create_object(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        ctx.create_unbound_ident_expr(SPAN, ctx.ast.ident("Object"), ReferenceFlags::Read)
+    }
+
+    /// `Object.defineProperty(exports, key, descriptor);`
+    fn create_define_property(
+        key: Expression<'a>,
+        descriptor: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let callee = create_member_callee(Self::create_object(ctx), "defineProperty", SPAN, ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(Self::create_exports(ctx)),
+            Argument::from(key),
+            Argument::from(descriptor),
+        ]);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    fn create_es_module_marker(ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+        let key = ctx.ast.expression_string_literal(SPAN, "__esModule", None);
+        let value = ctx.ast.expression_boolean_literal(SPAN, true);
+        let descriptor =
+            ctx.ast.expression_object(SPAN, ctx.ast.vec1(create_property("value", value, ctx)));
+        Self::create_define_property(key, descriptor, ctx)
+    }
+
+    /// `Object.defineProperty(exports, key, { enumerable: true, get: function () { return value; } });`
+    fn create_export_getter(
+        key: Expression<'a>,
+        value: Expression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::Function);
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let statements = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+        let getter = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        );
+        let enumerable = ctx.ast.expression_boolean_literal(SPAN, true);
+        let properties = ctx.ast.vec_from_array([
+            create_property("enumerable", enumerable, ctx),
+            create_property("get", Expression::FunctionExpression(getter), ctx),
+        ]);
+        let descriptor = ctx.ast.expression_object(SPAN, properties);
+        Self::create_define_property(key, descriptor, ctx)
+    }
+
+    /// Re-export all names of a module, except `default` and names which are exported explicitly.
+    ///
+    /// ```js
+    /// Object.keys(_foo).forEach(function (key) {
+    ///   if (key === "default" || key === "__esModule") return;
+    ///   if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    ///   if (key in exports && exports[key] === _foo[key]) return;
+    ///   Object.defineProperty(exports, key, {
+    ///     enumerable: true,
+    ///     get: function () { return _foo[key]; }
+    ///   });
+    /// });
+    /// ```
+    fn create_export_all(
+        module: &BoundIdentifier<'a>,
+        export_names: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+        let key = ctx.generate_binding(
+            ctx.ast.ident("key"),
+            scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        let mut statements = ctx.ast.vec_with_capacity(4);
+
+        // `if (key === "default" || key === "__esModule") return;`
+        let is_key = |name: &'static str, ctx: &mut TraverseCtx<'a>| {
+            let key = key.create_read_expression(ctx);
+            let name = ctx.ast.expression_string_literal(SPAN, name, None);
+            ctx.ast.expression_binary(SPAN, key, BinaryOperator::StrictEquality, name)
+        };
+        let is_default = is_key("default", ctx);
+        let is_es_module = is_key("__esModule", ctx);
+        let test = ctx.ast.expression_logical(SPAN, is_default, LogicalOperator::Or, is_es_module);
+        statements.push(create_return_if(test, ctx));
+
+        // `if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;`
+        if let Some(export_names) = export_names {
+            let prototype = create_prototype_member(Self::create_object(ctx), SPAN, ctx);
+            let has_own_property = create_member_callee(prototype, "hasOwnProperty", SPAN, ctx);
+            let callee = create_member_callee(has_own_property, "call", SPAN, ctx);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(export_names.create_read_expression(ctx)),
+                Argument::from(key.create_read_expression(ctx)),
+            ]);
+            let test = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+            statements.push(create_return_if(test, ctx));
+        }
+
+        // `if (key in exports && exports[key] === _foo[key]) return;`
+        let key_in_exports = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::In,
+            Self::create_exports(ctx),
+        );
+        let exports_key = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            Self::create_exports(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let module_key = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            module.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let is_same = ctx.ast.expression_binary(
+            SPAN,
+            exports_key,
+            BinaryOperator::StrictEquality,
+            module_key,
+        );
+        let test = ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same);
+        statements.push(create_return_if(test, ctx));
+
+        // `Object.defineProperty(exports, key, { enumerable: true, get: function () { return _foo[key]; } });`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            module.create_read_expression(ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let key_expr = key.create_read_expression(ctx);
+        statements.push(Self::create_export_getter(key_expr, value, scope_id, ctx));
+
+        // `function (key) { ... }`
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            key.create_binding_pattern(ctx),
+            NONE,
+            NONE,
+            false,
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+        let callback = ctx.ast.alloc_plain_function_with_scope_id(
+            FunctionType::FunctionExpression,
+            SPAN,
+            None,
+            params,
+            body,
+            scope_id,
+        );
+
+        // `Object.keys(_foo).forEach(callback);`
+        let keys_callee = create_member_callee(Self::create_object(ctx), "keys", SPAN, ctx);
+        let keys_arguments = ctx.ast.vec1(Argument::from(module.create_read_expression(ctx)));
+        let keys = ctx.ast.expression_call(SPAN, keys_callee, NONE, keys_arguments, false);
+        let callee = create_member_callee(keys, "forEach", SPAN, ctx);
+        let arguments = ctx.ast.vec1(Argument::FunctionExpression(callback));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        ctx.ast.statement_expression(SPAN, call)
+    }
+
+    /// Transform `import(source)`.
+    ///
+    /// * `import("foo")` -> `Promise.resolve().then(() => _interopRequireWildcard(require("foo")))`
+    /// * `import(foo)` -> ``Promise.resolve(`${foo}`).then((s) => _interopRequireWildcard(require(s)))``
+    fn transform_import_expression(
+        &self,
+        import: &mut ImportExpression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let flags = if self.arrow_functions {
+            ScopeFlags::Function
+        } else {
+            ScopeFlags::Function | ScopeFlags::Arrow
+        };
+        let scope_id = ctx.create_child_scope(parent_scope_id, flags);
+
+        let source = import.source.take_in(ctx.ast);
+        let is_static = match &source {
+            Expression::StringLiteral(_) => true,
+            Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+            _ => false,
+        };
+        let (resolve_arguments, source, params) = if is_static {
+            (ctx.ast.vec(), source, ctx.ast.vec())
+        } else {
+            // `${source}`
+            let quasi = |tail| {
+                let value =
+                    TemplateElementValue { raw: Str::from(""), cooked: Some(Str::from("")) };
+                ctx.ast.template_element(SPAN, value, tail, false)
+            };
+            let quasis = ctx.ast.vec_from_array([quasi(false), quasi(true)]);
+            let template = ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(source));
+
+            let binding = ctx.generate_uid("s", scope_id, SymbolFlags::FunctionScopedVariable);
+            let param = ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                binding.create_binding_pattern(ctx),
+                NONE,
+                NONE,
+                false,
+                None,
+                false,
+                false,
+            );
+            (
+                ctx.ast.vec1(Argument::from(template)),
+                binding.create_read_expression(ctx),
+                ctx.ast.vec1(param),
+            )
+        };
+
+        let require = Self::create_require_call(source, ctx);
+        let interop = match self.options.import_interop {
+            ImportInterop::Babel => Interop::Wildcard,
+            ImportInterop::Node => Interop::NodeWildcard,
+            ImportInterop::None => Interop::None,
+        };
+        let module = Self::create_interop(interop, require, ctx);
+
+        let kind = if self.arrow_functions {
+            FormalParameterKind::FormalParameter
+        } else {
+            FormalParameterKind::ArrowFormalParameters
+        };
+        let params = ctx.ast.alloc_formal_parameters(SPAN, kind, params, NONE);
+        let callback = if self.arrow_functions {
+            let statements = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(module)));
+            let body = ctx.ast.function_body(SPAN, ctx.ast.vec(), statements);
+            Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                params.unbox(),
+                body,
+                scope_id,
+            ))
+        } else {
+            let statements = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, module));
+            let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+            ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+                SPAN, true, false, NONE, params, NONE, body, scope_id, false, false,
+            )
+        };
+
+        // `Promise.resolve(...).then(callback)`
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, ctx.ast.ident("Promise"), ReferenceFlags::Read);
+        let resolve_callee = create_member_callee(promise, "resolve", SPAN, ctx);
+        let resolve = ctx.ast.expression_call(SPAN, resolve_callee, NONE, resolve_arguments, false);
+        let callee = create_member_callee(resolve, "then", SPAN, ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        ctx.ast.expression_call(import.span, callee, NONE, arguments, false)
+    }
+
+    /// Insert `require` calls for helpers loaded by this transform.
+    ///
+    /// `Common` has already converted the helper imports of other transforms to `import` declarations
+    /// by the time this transform runs, so imports added since then must be inserted here.
+    fn insert_helper_requires(program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let imports = ctx.state.module_imports.imports.drain(..).collect::<Vec<_>>();
+        if imports.is_empty() {
+            return;
+        }
+        let require_symbol_id = ctx.scoping().get_root_binding(ctx.ast.ident("require"));
+        let stmts = imports.into_iter().map(|(source, names)| {
+            for import in &names {
+                if let Import::Default(local) = import {
+                    *ctx.scoping_mut().symbol_flags_mut(local.symbol_id) =
+                        SymbolFlags::FunctionScopedVariable;
+                }
+            }
+            ModuleImportsStore::get_require(source, names, require_symbol_id, ctx)
+        });
+        let stmts = stmts.collect::<Vec<_>>();
+        program.body.splice(0..0, stmts);
+    }
+}
+
+/// Rewrite references to imported bindings, top-level `this`, and `import()`.
+struct ReferenceRewriter<'a, 'ctx> {
+    imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+    /// `true` if top-level `this` should be replaced with `void 0`
+    is_module: bool,
+    /// Number of functions / class members which bind `this` that are currently being visited
+    this_depth: u32,
+    scope_stack: Vec<ScopeId>,
+    transform: &'ctx CommonJs,
+    ctx: &'ctx mut TraverseCtx<'a>,
+}
+
+impl<'a, 'ctx> ReferenceRewriter<'a, 'ctx> {
+    fn new(
+        imports: FxHashMap<SymbolId, ImportBinding<'a>>,
+        is_module: bool,
+        transform: &'ctx CommonJs,
+        ctx: &'ctx mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { imports, is_module, this_depth: 0, scope_stack: vec![], transform, ctx }
+    }
+
+    /// Get replacement for a reference to an imported binding, or `None` if it is not an import.
+    ///
+    /// The existing `ReferenceId` is reused, pointing at the module variable.
+    fn rewrite_reference(&mut self, ident: &IdentifierReference<'a>) -> Option<Expression<'a>> {
+        let reference_id = ident.reference_id();
+        let symbol_id = self.ctx.scoping().get_reference(reference_id).symbol_id()?;
+        let binding = self.imports.get(&symbol_id)?;
+
+        let scoping = self.ctx.scoping_mut();
+        scoping.delete_resolved_reference(symbol_id, reference_id);
+        scoping.get_reference_mut(reference_id).set_symbol_id(binding.module.symbol_id);
+        scoping.add_resolved_reference(binding.module.symbol_id, reference_id);
+
+        Some(create_module_access(
+            &binding.module,
+            binding.property,
+            ident.span,
+            Some(reference_id),
+            self.ctx,
+        ))
+    }
+
+    /// If `expr` is a reference to an import which resolves to a property of a module,
+    /// replace it with `(0, _foo.bar)`, so the module is not the `this` of a call.
+    fn rewrite_callee(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && let Some(new_expr) = self.rewrite_reference(ident)
+        {
+            *expr = if matches!(new_expr, Expression::Identifier(_)) {
+                new_expr
+            } else {
+                let zero =
+                    self.ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+                self.ctx
+                    .ast
+                    .expression_sequence(SPAN, self.ctx.ast.vec_from_array([zero, new_expr]))
+            };
+        }
+    }
+
+    fn is_import_reference(&self, ident: &IdentifierReference<'a>) -> bool {
+        self.ctx
+            .scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| self.imports.contains_key(&symbol_id))
+    }
+
+    fn visit_with_this_bound<F: FnOnce(&mut Self)>(&mut self, f: F) {
+        self.this_depth += 1;
+        f(self);
+        self.this_depth -= 1;
+    }
+}
+
+impl<'a> VisitMut<'a> for ReferenceRewriter<'a, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scope_stack.push(scope_id.get().unwrap());
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                if let Some(new_expr) = self.rewrite_reference(ident) {
+                    *expr = new_expr;
+                }
+                return;
+            }
+            Expression::ThisExpression(this) if self.is_module && self.this_depth == 0 => {
+                *expr = self.ctx.ast.void_0(this.span);
+                return;
+            }
+            Expression::ImportExpression(import) => {
+                walk_mut::walk_import_expression(self, import);
+                let scope_id = *self.scope_stack.last().unwrap();
+                *expr = self.transform.transform_import_expression(import, scope_id, self.ctx);
+                return;
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, call: &mut CallExpression<'a>) {
+        self.rewrite_callee(&mut call.callee);
+        walk_mut::walk_call_expression(self, call);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        self.rewrite_callee(&mut expr.tag);
+        walk_mut::walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        // `{ foo }` -> `{ foo: _foo.foo }`
+        if prop.shorthand
+            && let Expression::Identifier(ident) = &prop.value
+            && self.is_import_reference(ident)
+        {
+            prop.shorthand = false;
+        }
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        self.visit_with_this_bound(|this| walk_mut::walk_function(this, func, flags));
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.visit_with_this_bound(|this| walk_mut::walk_static_block(this, block));
+    }
+
+    fn visit_property_definition(&mut self, prop: &mut PropertyDefinition<'a>) {
+        // Computed key is evaluated outside the class, but initializer is evaluated with class `this`
+        for decorator in &mut prop.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.visit_with_this_bound(|this| this.visit_expression(value));
+        }
+    }
+
+    fn visit_accessor_property(&mut self, prop: &mut AccessorProperty<'a>) {
+        for decorator in &mut prop.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut prop.key);
+        if let Some(value) = &mut prop.value {
+            self.visit_with_this_bound(|this| this.visit_expression(value));
+        }
+    }
+}
+
+/// Create `_foo`, `_foo.bar` or `_foo["bar-baz"]`.
+///
+/// `reference_id` is used for the reference to the module variable if provided.
+fn create_module_access<'a>(
+    module: &BoundIdentifier<'a>,
+    property: Option<Str<'a>>,
+    span: Span,
+    reference_id: Option<ReferenceId>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object = match reference_id {
+        Some(reference_id) => {
+            ctx.ast.expression_identifier_with_reference_id(span, module.name, reference_id)
+        }
+        None => module.create_spanned_read_expression(span, ctx),
+    };
+    let Some(property) = property else { return object };
+    if is_identifier_name(&property) {
+        let property = ctx.ast.identifier_name(SPAN, property);
+        Expression::from(ctx.ast.member_expression_static(span, object, property, false))
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, property, None);
+        Expression::from(ctx.ast.member_expression_computed(span, object, property, false))
+    }
+}
+
+/// `key: value`
+fn create_property<'a>(
+    key: impl Into<Str<'a>>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key: Str<'a> = key.into();
+    let key = if is_identifier_name(&key) {
+        ctx.ast.property_key_static_identifier(SPAN, key)
+    } else {
+        PropertyKey::from(ctx.ast.expression_string_literal(SPAN, key, None))
+    };
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `if (test) return;`
+fn create_return_if<'a>(test: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+    ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None)
+}
+
+/// `var id = init;`
+fn create_var_declaration<'a>(
+    id: BindingPattern<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, id, NONE, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// Get name for the variable holding a required module, from the basename of the source.
+///
+/// `"./foo/bar-baz.js"` -> `barBaz`
+fn module_variable_name(source: &str) -> String {
+    let basename = source.trim_end_matches('/').rsplit('/').next().unwrap_or(source);
+    let stem = match basename.rfind('.') {
+        Some(index) if index > 0 => &basename[..index],
+        _ => basename,
+    };
+    to_identifier(stem.to_string())
+}
@@ -0,0 +1,38 @@
+//! Module format transforms
+//!
+//! Converts ES module syntax to the module format selected by [`Module`].
+//!
+//! ### CommonJS
+//! - @babel/plugin-transform-modules-commonjs: <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs>
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{EnvOptions, context::TraverseCtx, state::TransformState};
+
+mod commonjs;
+
+pub use commonjs::{CommonJs, CommonJsOptions, ImportInterop};
+
+pub struct Modules {
+    commonjs: Option<CommonJs>,
+}
+
+impl Modules {
+    pub fn new(options: &EnvOptions) -> Self {
+        Self {
+            commonjs: options
+                .module
+                .is_commonjs()
+                .then(|| CommonJs::new(options.commonjs, options.es2015.arrow_function.is_some())),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Modules {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(commonjs) = &self.commonjs {
+            commonjs.exit_program(program, ctx);
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions,
+        ForOfOptions, ParametersOptions, SpreadOptions, TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    modules::CommonJsOptions,
     plugins::StyledComponentsOptions,
 };
 
@@ -22,6 +29,15 @@ pub struct SyntaxDecoratorOptions {
     pub version: String,
 }
 
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineOperatorOptions {
+    #[serde(default)]
+    pub proposal: String,
+    #[serde(default)]
+    pub topic_token: String,
+}
+
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(try_from = "PluginPresetEntries")]
 pub struct BabelPlugins {
@@ -42,7 +58,7 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<CommonJsOptions>,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -55,6 +71,14 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub classes: Option<ClassesOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub parameters: Option<ParametersOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub regenerator: bool,
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    pub spread: Option<SpreadOptions>,
+    pub for_of: Option<ForOfOptions>,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -77,6 +101,10 @@ pub struct BabelPlugins {
     pub explicit_resource_management: bool,
     // Decorator
     pub legacy_decorator: Option<DecoratorOptions>,
+    // Proposals
+    pub pipeline_operator: bool,
+    pub do_expressions: bool,
+    pub throw_expressions: bool,
     // Built-in plugins
     pub styled_components: Option<StyledComponentsOptions>,
     pub tagged_template_escape: bool,
@@ -127,7 +155,10 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs =
+                        entry.value::<CommonJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -142,6 +173,38 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-classes" => {
+                    p.classes =
+                        entry.value::<ClassesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-parameters" => {
+                    p.parameters =
+                        entry.value::<ParametersOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-regenerator" => p.regenerator = true,
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -169,6 +232,21 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         entry.value::<DecoratorOptions>().map_err(|err| p.errors.push(err)).ok();
                 }
                 "transform-explicit-resource-management" => p.explicit_resource_management = true,
+                "proposal-pipeline-operator" => {
+                    let options = entry
+                        .value::<PipelineOperatorOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .unwrap_or_default();
+                    if options.proposal == "hack" && options.topic_token == "%" {
+                        p.pipeline_operator = true;
+                    } else {
+                        p.errors.push(
+                            "proposal-pipeline-operator: only `{ \"proposal\": \"hack\", \"topicToken\": \"%\" }` is supported".to_string(),
+                        );
+                    }
+                }
+                "proposal-do-expressions" => p.do_expressions = true,
+                "proposal-throw-expressions" => p.throw_expressions = true,
                 "styled-components" => {
                     p.styled_components = entry
                         .value::<StyledComponentsOptions>()
@@ -1,7 +1,10 @@
 use serde::Deserialize;
 
 use crate::{
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, DestructuringOptions,
+        ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions, TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::{ES2018Options, ObjectRestSpreadOptions},
@@ -10,6 +13,7 @@ use crate::{
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
     es2026::ES2026Options,
+    modules::CommonJsOptions,
     regexp::RegExpOptions,
 };
 
@@ -23,6 +27,9 @@ pub struct EnvOptions {
     /// Specify what module code is generated.
     pub module: Module,
 
+    /// CommonJS transform options, used when `module` is [`Module::CommonJS`].
+    pub commonjs: CommonJsOptions,
+
     /// RegExp transform options.
     pub regexp: RegExpOptions,
 
@@ -62,6 +69,7 @@ impl EnvOptions {
     pub fn enable_all(include_unfinished_plugins: bool) -> Self {
         Self {
             module: Module::default(),
+            commonjs: CommonJsOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: true,
                 unicode_flag: true,
@@ -79,6 +87,42 @@ impl EnvOptions {
                 } else {
                     None
                 },
+                classes: if include_unfinished_plugins {
+                    Some(ClassesOptions::default())
+                } else {
+                    None
+                },
+                destructuring: if include_unfinished_plugins {
+                    Some(DestructuringOptions::default())
+                } else {
+                    None
+                },
+                parameters: if include_unfinished_plugins {
+                    Some(ParametersOptions::default())
+                } else {
+                    None
+                },
+                block_scoping: if include_unfinished_plugins {
+                    Some(BlockScopingOptions::default())
+                } else {
+                    None
+                },
+                regenerator: include_unfinished_plugins,
+                template_literals: if include_unfinished_plugins {
+                    Some(TemplateLiteralsOptions::default())
+                } else {
+                    None
+                },
+                spread: if include_unfinished_plugins {
+                    Some(SpreadOptions::default())
+                } else {
+                    None
+                },
+                for_of: if include_unfinished_plugins {
+                    Some(ForOfOptions::default())
+                } else {
+                    None
+                },
             },
             es2016: ES2016Options { exponentiation_operator: true },
             es2017: ES2017Options { async_to_generator: true },
@@ -144,6 +188,7 @@ impl From<EngineTargets> for EnvOptions {
         use ESFeature::*;
         Self {
             module: Module::default(),
+            commonjs: CommonJsOptions::default(),
             regexp: RegExpOptions {
                 sticky_flag: o.has_feature(ES2015StickyRegex),
                 unicode_flag: o.has_feature(ES2015UnicodeRegex),
@@ -156,6 +201,14 @@ impl From<EngineTargets> for EnvOptions {
             },
             es2015: ES2015Options {
                 arrow_function: o.has_feature(ES2015ArrowFunctions).then(Default::default),
+                classes: o.has_feature(ES2015Classes).then(Default::default),
+                destructuring: o.has_feature(ES2015Destructuring).then(Default::default),
+                parameters: o.has_feature(ES2015Parameters).then(Default::default),
+                block_scoping: o.has_feature(ES2015BlockScoping).then(Default::default),
+                regenerator: o.has_feature(ES2015Regenerator),
+                template_literals: o.has_feature(ES2015TemplateLiterals).then(Default::default),
+                spread: o.has_feature(ES2015Spread).then(Default::default),
+                for_of: o.has_feature(ES2015ForOf).then(Default::default),
             },
             es2016: ES2016Options {
                 exponentiation_operator: o.has_feature(ES2016ExponentiationOperator),
@@ -80,7 +80,11 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -159,6 +163,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-05"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -205,6 +214,14 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            classes: options.plugins.classes.or(env.es2015.classes),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            regenerator: options.plugins.regenerator || env.es2015.regenerator,
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
+            spread: options.plugins.spread.or(env.es2015.spread),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
         };
 
         let es2016 = ES2016Options {
@@ -278,6 +295,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx,
             env: EnvOptions {
                 module,
+                commonjs: options.plugins.modules_commonjs.unwrap_or_default(),
                 regexp,
                 es2015,
                 es2016,
@@ -291,7 +309,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
                     explicit_resource_management: options.plugins.explicit_resource_management,
                 },
             },
-            proposals: ProposalOptions::default(),
+            proposals: ProposalOptions {
+                pipeline_operator: options.plugins.pipeline_operator,
+                do_expressions: options.plugins.do_expressions,
+                throw_expressions: options.plugins.throw_expressions,
+            },
             helper_loader,
             plugins,
         })
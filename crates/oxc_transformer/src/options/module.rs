@@ -51,7 +51,7 @@ impl TryFrom<&BabelPlugins> for Module {
     type Error = Error;
 
     fn try_from(value: &BabelPlugins) -> Result<Self, Self::Error> {
-        if value.modules_commonjs {
+        if value.modules_commonjs.is_some() {
             Ok(Self::CommonJS)
         } else {
             Err(Error::msg("Doesn't find any transform-modules-* plugin."))
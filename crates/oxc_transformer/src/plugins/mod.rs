@@ -1,6 +1,6 @@
 mod options;
 mod styled_components;
-mod tagged_template_transform;
+pub mod tagged_template_transform;
 
 pub use options::PluginsOptions;
 use oxc_ast::ast::*;
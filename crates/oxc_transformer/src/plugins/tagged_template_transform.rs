@@ -75,6 +75,7 @@ impl<'a> TaggedTemplateTransform {
 
     /// Transform a tagged template expression to use the [`Helper::TaggedTemplateLiteral`] helper function.
     #[cold] // Tagged template expressions are rare
+    #[expect(clippy::unused_self)]
     fn transform_tagged_template(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         debug_assert!(matches!(expr, Expression::TaggedTemplateExpression(_)));
 
@@ -90,8 +91,13 @@ impl<'a> TaggedTemplateTransform {
         let TaggedTemplateExpression { span, tag, quasi: template_lit, type_arguments, .. } =
             tagged.unbox();
 
-        let binding = self.create_top_level_binding(ctx);
-        let arguments = self.transform_template_literal(&binding, template_lit, ctx);
+        let binding = Self::create_top_level_binding(ctx);
+        let arguments = Self::transform_template_literal(
+            &binding,
+            template_lit,
+            Helper::TaggedTemplateLiteral,
+            ctx,
+        );
         *expr = ctx.ast.expression_call(span, tag, type_arguments, arguments, false);
     }
 
@@ -141,11 +147,12 @@ impl<'a> TaggedTemplateTransform {
     /// Final arguments:
     /// - `(binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>])), <...expressions>)` when cooked == raw
     /// - `(binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>])), <...expressions>)` when cooked != raw
-    #[expect(clippy::unused_self)]
-    fn transform_template_literal(
-        &self,
+    ///
+    /// `helper` is either [`Helper::TaggedTemplateLiteral`] or [`Helper::TaggedTemplateLiteralLoose`].
+    pub fn transform_template_literal(
         binding: &BoundIdentifier<'a>,
         template_lit: TemplateLiteral<'a>,
+        helper: Helper,
         ctx: &mut TraverseCtx<'a>,
     ) -> ArenaVec<'a, Argument<'a>> {
         // Create cooked array: `[cooked0, cooked1, ...]`.
@@ -180,8 +187,7 @@ impl<'a> TaggedTemplateTransform {
         };
 
         // `babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>]?)`
-        let template_call =
-            helper_call_expr(Helper::TaggedTemplateLiteral, template_arguments, ctx);
+        let template_call = helper_call_expr(helper, template_arguments, ctx);
         // `binding || (binding = babelHelpers.taggedTemplateLiteral([<...cooked>], [<...raw>]?))`
         let template_call =
             Argument::from(Self::create_logical_or_expression(binding, template_call, ctx));
@@ -211,8 +217,7 @@ impl<'a> TaggedTemplateTransform {
     }
 
     /// Creates a `var binding;` variable declaration at the top level and returns the binding
-    #[expect(clippy::unused_self)]
-    fn create_top_level_binding(&self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+    pub fn create_top_level_binding(ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
         let binding = ctx.generate_uid(
             "templateObject",
             ctx.scoping().root_scope_id(),
@@ -0,0 +1,460 @@
+//! Proposal: Do Expressions
+//!
+//! This plugin transforms `do` expressions (`do { ... }`) into plain expressions.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = do {
+//!   if (x > 10) {
+//!     "big";
+//!   } else {
+//!     "small";
+//!   }
+//! };
+//! let b = do { foo(); bar() };
+//! ```
+//!
+//! Output:
+//! ```js
+//! let a = (() => {
+//!   if (x > 10) {
+//!     return "big";
+//!   } else {
+//!     return "small";
+//!   }
+//! })();
+//! let b = (foo(), bar());
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-do-expressions](https://babel.dev/docs/babel-plugin-proposal-do-expressions).
+//!
+//! A block containing only expression statements becomes a sequence expression.
+//! Otherwise the block becomes the body of an arrow function IIFE, and statements which produce
+//! the completion value of the block are converted to `return` statements.
+//!
+//! Completion values produced inside loops, and in `switch` cases which fall through, are assigned
+//! to a temporary variable which is returned at the end of the IIFE:
+//!
+//! ```js
+//! let a = do { for (const x of y) { x } };
+//! // ->
+//! let a = (() => { var _ret; for (const x of y) { _ret = x; } return _ret; })();
+//! ```
+//!
+//! If the block contains `await`, the IIFE is an async arrow function, and its result is awaited.
+//!
+//! Moving the block into a function would change the meaning of `return`, `yield`, `var`
+//! declarations, and `break` / `continue` which jump out of the block, so an error is reported
+//! for those instead.
+//!
+//! ## Missing features
+//!
+//! * Babel converts `if` statements to conditional expressions where possible. We always use an IIFE.
+//! * Completion values before a labeled `break` are only returned if the labeled statement produces
+//!   the completion value of the `do` block, and the `break` is not inside a loop or `switch`
+//!   which doesn't.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-proposal-do-expressions>
+//! * Do expressions TC39 proposal: <https://github.com/tc39/proposal-do-expressions>
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{SPAN, Span};
+use oxc_str::Ident;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::TraverseCtx, state::TransformState,
+    utils::ast_builder::wrap_statements_in_arrow_function_iife,
+};
+
+pub struct DoExpressions<'a> {
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl DoExpressions<'_> {
+    pub fn new() -> Self {
+        Self { _marker: std::marker::PhantomData }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for DoExpressions<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::DoExpression(_)) {
+            Self::transform_do_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> DoExpressions<'a> {
+    /// `do { foo; bar }` -> `(foo, bar)`
+    /// `do { if (x) { foo } }` -> `(() => { if (x) { return foo } })()`
+    fn transform_do_expression(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::DoExpression(do_expr) = expr else { unreachable!() };
+
+        // `do {}` -> `void 0`
+        // `do { foo; bar }` -> `(foo, bar)`
+        if do_expr.body.body.iter().all(|stmt| matches!(stmt, Statement::ExpressionStatement(_))) {
+            let Expression::DoExpression(do_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+            let DoExpression { span, body, .. } = do_expr.unbox();
+            let scope_id = body.scope_id();
+            let mut expressions =
+                ctx.ast.vec_from_iter(body.unbox().body.into_iter().map(|stmt| {
+                    let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+                    stmt.unbox().expression
+                }));
+            let new_expr = match expressions.len() {
+                0 => ctx.ast.void_0(span),
+                1 => expressions.pop().unwrap(),
+                _ => ctx.ast.expression_sequence(span, expressions),
+            };
+            // Remove the scope for the block from the scope chain
+            ctx.remove_scope_for_expression(scope_id, &new_expr);
+            *expr = new_expr;
+            return;
+        }
+
+        let mut checker = DoBlockChecker::default();
+        checker.visit_block_statement(&do_expr.body);
+        if !checker.errors.is_empty() {
+            for error in checker.errors {
+                ctx.state.error(error);
+            }
+            return;
+        }
+
+        let Expression::DoExpression(do_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let DoExpression { span, body, .. } = do_expr.unbox();
+        let scope_id = body.scope_id();
+        let mut stmts = body.unbox().body;
+
+        let mut converter = CompletionConverter { scope_id, ret: None, labels: vec![], ctx };
+        converter.convert_statements(&mut stmts, Completion::Return, false, true);
+        // `var _ret; ...; return _ret;`
+        if let Some(ret) = converter.ret {
+            let kind = VariableDeclarationKind::Var;
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                ret.create_binding_pattern(ctx),
+                NONE,
+                None,
+                false,
+            );
+            let declaration =
+                ctx.ast.declaration_variable(SPAN, kind, ctx.ast.vec1(declarator), false);
+            stmts.insert(0, Statement::from(declaration));
+            stmts.push(ctx.ast.statement_return(SPAN, Some(ret.create_read_expression(ctx))));
+        }
+
+        // Re-use the block's scope for the arrow function
+        let strict_mode = ctx.current_scope_flags() & ScopeFlags::StrictMode;
+        *ctx.scoping_mut().scope_flags_mut(scope_id) =
+            ScopeFlags::Function | ScopeFlags::Arrow | strict_mode;
+        let mut iife = wrap_statements_in_arrow_function_iife(stmts, scope_id, span, ctx);
+
+        // `await (async () => { ... })()`
+        if checker.has_await {
+            if let Expression::CallExpression(call) = &mut iife
+                && let Expression::ArrowFunctionExpression(arrow) = &mut call.callee
+            {
+                arrow.r#async = true;
+            }
+            iife = ctx.ast.expression_await(span, iife);
+        }
+        *expr = iife;
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Completion {
+    /// `foo;` -> `return foo;`
+    Return,
+    /// `foo;` -> `_ret = foo;`
+    Assign,
+}
+
+/// Converts statements which produce the completion value of a `do` block.
+struct CompletionConverter<'a, 'c> {
+    scope_id: ScopeId,
+    /// Temporary variable holding completion values produced inside loops and `switch` cases
+    /// which fall through.
+    ret: Option<BoundIdentifier<'a>>,
+    /// Labels of enclosing labeled statements which produce the completion value of the `do` block.
+    /// A statement followed by a `break` to one of them produces the completion value.
+    labels: Vec<Ident<'a>>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a> CompletionConverter<'a, '_> {
+    /// Convert the statements of `stmts` which produce its completion value.
+    ///
+    /// `in_breakable` is `true` inside a loop or `switch` within the `do` block, where a statement
+    /// followed by an unlabeled `break` also produces the completion value.
+    /// `is_tail` is `true` if the completion value of `stmts` is the completion value of the
+    /// enclosing loop, `switch` case or `do` block.
+    fn convert_statements(
+        &mut self,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        completion: Completion,
+        in_breakable: bool,
+        is_tail: bool,
+    ) {
+        for index in 0..stmts.len() {
+            let is_before_break = stmts.get(index + 1).is_some_and(|next| {
+                let Statement::BreakStatement(stmt) = next else { return false };
+                match &stmt.label {
+                    Some(label) => self.labels.contains(&label.name),
+                    None => in_breakable,
+                }
+            });
+            // Statements with an empty completion don't change the completion value, e.g. `foo; {}`
+            let is_tail =
+                is_before_break || (is_tail && stmts[index + 1..].iter().all(has_empty_completion));
+            self.convert_statement(&mut stmts[index], completion, in_breakable, is_tail);
+        }
+    }
+
+    fn convert_statement(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        completion: Completion,
+        in_breakable: bool,
+        is_tail: bool,
+    ) {
+        if !is_tail && !in_breakable && self.labels.is_empty() {
+            return;
+        }
+        match stmt {
+            Statement::ExpressionStatement(expr_stmt) if is_tail => {
+                let span = expr_stmt.span;
+                let expression = expr_stmt.expression.take_in(self.ctx.ast);
+                *stmt = match completion {
+                    // `foo;` -> `return foo;`
+                    Completion::Return => self.ctx.ast.statement_return(span, Some(expression)),
+                    // `foo;` -> `_ret = foo;`
+                    Completion::Assign => {
+                        let ret = self.ret();
+                        let target = ret.create_write_target(self.ctx);
+                        let assignment = self.ctx.ast.expression_assignment(
+                            span,
+                            AssignmentOperator::Assign,
+                            target,
+                            expression,
+                        );
+                        self.ctx.ast.statement_expression(span, assignment)
+                    }
+                };
+            }
+            Statement::BlockStatement(block) => {
+                self.convert_statements(&mut block.body, completion, in_breakable, is_tail);
+            }
+            Statement::IfStatement(if_stmt) => {
+                self.convert_statement(&mut if_stmt.consequent, completion, in_breakable, is_tail);
+                if let Some(alternate) = &mut if_stmt.alternate {
+                    self.convert_statement(alternate, completion, in_breakable, is_tail);
+                }
+            }
+            Statement::LabeledStatement(labeled) => {
+                // `break label` exits the labeled statement with the completion value before it
+                if is_tail {
+                    self.labels.push(labeled.label.name);
+                }
+                self.convert_statement(&mut labeled.body, completion, in_breakable, is_tail);
+                if is_tail {
+                    self.labels.pop();
+                }
+            }
+            // Completion value of `finally` block is ignored
+            Statement::TryStatement(try_stmt) => {
+                let block = &mut try_stmt.block.body;
+                self.convert_statements(block, completion, in_breakable, is_tail);
+                if let Some(handler) = &mut try_stmt.handler {
+                    let body = &mut handler.body.body;
+                    self.convert_statements(body, completion, in_breakable, is_tail);
+                }
+            }
+            // `break` inside a `switch` exits the `switch`, not an enclosing loop
+            Statement::SwitchStatement(switch) if is_tail => {
+                let len = switch.cases.len();
+                for (index, case) in switch.cases.iter_mut().enumerate() {
+                    let ends_with_break = matches!(
+                        case.consequent.last(),
+                        Some(Statement::BreakStatement(stmt)) if stmt.label.is_none()
+                    );
+                    // A case which falls through into the next one doesn't end the `switch`
+                    let completion = if ends_with_break || index + 1 == len {
+                        completion
+                    } else {
+                        Completion::Assign
+                    };
+                    self.convert_statements(&mut case.consequent, completion, true, true);
+                }
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_)
+                if is_tail =>
+            {
+                let body = match stmt {
+                    Statement::ForStatement(stmt) => &mut stmt.body,
+                    Statement::ForInStatement(stmt) => &mut stmt.body,
+                    Statement::ForOfStatement(stmt) => &mut stmt.body,
+                    Statement::WhileStatement(stmt) => &mut stmt.body,
+                    Statement::DoWhileStatement(stmt) => &mut stmt.body,
+                    _ => unreachable!(),
+                };
+                self.convert_statement(body, Completion::Assign, true, true);
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the temporary variable holding the completion value, creating it if needed.
+    fn ret(&mut self) -> BoundIdentifier<'a> {
+        if self.ret.is_none() {
+            let flags = SymbolFlags::FunctionScopedVariable;
+            self.ret = Some(self.ctx.generate_uid("ret", self.scope_id, flags));
+        }
+        self.ret.clone().unwrap()
+    }
+}
+
+/// Returns `true` if `stmt` always has an empty completion, so doesn't change the completion value
+/// of the statements before it.
+fn has_empty_completion(stmt: &Statement<'_>) -> bool {
+    match stmt {
+        Statement::EmptyStatement(_) | Statement::DebuggerStatement(_) => true,
+        Statement::BlockStatement(block) => block.body.iter().all(has_empty_completion),
+        _ => stmt.is_declaration(),
+    }
+}
+
+/// Finds statements and expressions in a `do` block which can't be moved into a function.
+#[derive(Default)]
+struct DoBlockChecker<'a> {
+    /// Labels declared inside the block.
+    labels: Vec<&'a str>,
+    /// Number of loops inside the block enclosing the current node.
+    loop_depth: u32,
+    /// Number of `switch` statements inside the block enclosing the current node.
+    switch_depth: u32,
+    has_await: bool,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl DoBlockChecker<'_> {
+    fn unsupported(&mut self, message: &'static str, span: Span) {
+        self.errors.push(OxcDiagnostic::error(message).with_label(span));
+    }
+}
+
+impl<'a> Visit<'a> for DoBlockChecker<'a> {
+    // Functions and class static blocks have their own `return`, `yield`, `var`s and labels.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        self.unsupported("`return` is not supported in a `do` expression", stmt.span);
+        walk::walk_return_statement(self, stmt);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        self.unsupported("`yield` is not supported in a `do` expression", expr.span);
+        walk::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.has_await = true;
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        match decl.kind {
+            VariableDeclarationKind::Var => self.unsupported(
+                "`var` declarations are not supported in a `do` expression, use `let` or `const` instead",
+                decl.span,
+            ),
+            VariableDeclarationKind::AwaitUsing => self.has_await = true,
+            _ => {}
+        }
+        walk::walk_variable_declaration(self, decl);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &LabeledStatement<'a>) {
+        self.labels.push(stmt.label.name.as_str());
+        walk::walk_labeled_statement(self, stmt);
+        self.labels.pop();
+    }
+
+    fn visit_break_statement(&mut self, stmt: &BreakStatement<'a>) {
+        let crosses_block = match &stmt.label {
+            Some(label) => !self.labels.contains(&label.name.as_str()),
+            None => self.loop_depth == 0 && self.switch_depth == 0,
+        };
+        if crosses_block {
+            self.unsupported("`break` out of a `do` expression is not supported", stmt.span);
+        }
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &ContinueStatement<'a>) {
+        let crosses_block = match &stmt.label {
+            Some(label) => !self.labels.contains(&label.name.as_str()),
+            None => self.loop_depth == 0,
+        };
+        if crosses_block {
+            self.unsupported("`continue` out of a `do` expression is not supported", stmt.span);
+        }
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        self.switch_depth += 1;
+        walk::walk_switch_statement(self, stmt);
+        self.switch_depth -= 1;
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.has_await |= stmt.r#await;
+        self.loop_depth += 1;
+        walk::walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk::walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+}
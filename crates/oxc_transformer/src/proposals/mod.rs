@@ -1,3 +1,48 @@
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+mod do_expressions;
 mod options;
+mod pipeline_operator;
+mod throw_expressions;
 
+use do_expressions::DoExpressions;
 pub use options::ProposalOptions;
+use pipeline_operator::PipelineOperator;
+use throw_expressions::ThrowExpressions;
+
+pub struct Proposals<'a> {
+    options: ProposalOptions,
+
+    // Plugins
+    pipeline_operator: PipelineOperator<'a>,
+    do_expressions: DoExpressions<'a>,
+    throw_expressions: ThrowExpressions<'a>,
+}
+
+impl Proposals<'_> {
+    pub fn new(options: ProposalOptions) -> Self {
+        Self {
+            options,
+            pipeline_operator: PipelineOperator::new(),
+            do_expressions: DoExpressions::new(),
+            throw_expressions: ThrowExpressions::new(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Proposals<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.pipeline_operator {
+            self.pipeline_operator.enter_expression(expr, ctx);
+        }
+        if self.options.do_expressions {
+            self.do_expressions.enter_expression(expr, ctx);
+        }
+        if self.options.throw_expressions {
+            self.throw_expressions.enter_expression(expr, ctx);
+        }
+    }
+}
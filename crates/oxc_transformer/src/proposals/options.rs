@@ -1,6 +1,10 @@
-#[expect(clippy::empty_structs_with_brackets)]
 #[derive(Debug, Clone, Copy, Default)]
 /// Options for proposal transforms.
 pub struct ProposalOptions {
-    // Currently no proposals are configured here.
+    /// Enable Hack-style pipeline operator transform (`value |> f(%)`).
+    pub pipeline_operator: bool,
+    /// Enable `do` expressions transform (`do { ... }`).
+    pub do_expressions: bool,
+    /// Enable `throw` expressions transform (`x ?? throw new Error()`).
+    pub throw_expressions: bool,
 }
@@ -0,0 +1,212 @@
+//! Proposal: Pipeline Operator (Hack-style)
+//!
+//! This plugin transforms the Hack-style pipeline operator (`|>`) and its topic reference (`%`).
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! const result = value |> double(%) |> % + 1;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _ref, _ref2;
+//! const result = (_ref = value, _ref2 = double(_ref), _ref2 + 1);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-pipeline-operator](https://babel.dev/docs/babel-plugin-proposal-pipeline-operator)
+//! with `{ "proposal": "hack", "topicToken": "%" }`.
+//!
+//! Each pipe stores its left-hand side in a temp var, and topic references in the pipe body
+//! are replaced by reads of that var.
+//!
+//! A function in the pipe body may be called after the pipe is evaluated again, e.g. in a loop,
+//! so a topic reference inside a function can't read a shared var. Such a pipe body is wrapped in
+//! an arrow function IIFE instead, which receives the topic as a parameter:
+//!
+//! ```js
+//! for (var i = 0; i < 2; i++) fns.push(i |> (() => %));
+//! // ->
+//! for (var i = 0; i < 2; i++) fns.push(((_ref) => () => _ref)(i));
+//! ```
+//!
+//! If that pipe body contains `await`, the IIFE is an async arrow function, and its result is awaited.
+//! `yield` can't be moved into a function, so an error is reported for it instead.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-proposal-pipeline-operator>
+//! * Pipeline operator TC39 proposal: <https://github.com/tc39/proposal-pipeline-operator>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::{SPAN, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::var_declarations::VarDeclarationsStore, context::TraverseCtx, state::TransformState,
+};
+
+pub struct PipelineOperator<'a> {
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl PipelineOperator<'_> {
+    pub fn new() -> Self {
+        Self { _marker: std::marker::PhantomData }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for PipelineOperator<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::PipelineExpression(_)) {
+            Self::transform_pipeline_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> PipelineOperator<'a> {
+    /// `left |> body(%)` -> `(_ref = left, body(_ref))`
+    fn transform_pipeline_expression(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::PipelineExpression(pipe) = expr.take_in(ctx.ast) else { unreachable!() };
+        let PipelineExpression { span, left, mut body, .. } = pipe.unbox();
+
+        let mut finder = TopicFinder::default();
+        finder.visit_expression(&body);
+        if finder.in_function {
+            if let Some(yield_span) = finder.yield_span {
+                ctx.state.error(
+                    OxcDiagnostic::error(
+                        "Cannot move a pipe body containing `yield` into a function",
+                    )
+                    .with_label(yield_span),
+                );
+            } else {
+                *expr = Self::create_topic_iife(span, left, body, finder.has_await, ctx);
+                return;
+            }
+        }
+
+        // var _ref;
+        let binding = VarDeclarationsStore::create_uid_var("ref", ctx);
+
+        TopicReplacer { binding: &binding, ctx }.visit_expression(&mut body);
+
+        let assignment = ctx.ast.expression_assignment(
+            SPAN,
+            AssignmentOperator::Assign,
+            binding.create_write_target(ctx),
+            left,
+        );
+        *expr = ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([assignment, body]));
+    }
+}
+
+impl<'a> PipelineOperator<'a> {
+    /// `left |> body(%)` -> `((_ref) => body(_ref))(left)`
+    fn create_topic_iife(
+        span: Span,
+        left: Expression<'a>,
+        mut body: Expression<'a>,
+        has_await: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.insert_scope_below_expression(&body, ScopeFlags::Arrow | ScopeFlags::Function);
+        let binding = ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+
+        TopicReplacer { binding: &binding, ctx }.visit_expression(&mut body);
+
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body));
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+        let arrow = ctx.ast.expression_arrow_function_with_scope_id_and_pure_and_pife(
+            SPAN, true, has_await, NONE, params, NONE, body, scope_id, false, false,
+        );
+        let call = ctx.ast.expression_call(span, arrow, NONE, ctx.ast.vec1(left.into()), false);
+        if has_await { ctx.ast.expression_await(span, call) } else { call }
+    }
+}
+
+/// Finds topic references of a pipe body inside functions, and `await` / `yield` outside them.
+#[derive(Default)]
+struct TopicFinder {
+    /// Number of functions and classes in the pipe body enclosing the current node.
+    function_depth: u32,
+    /// `true` if a topic reference is inside a function.
+    in_function: bool,
+    has_await: bool,
+    yield_span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for TopicFinder {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        match expr {
+            Expression::TopicReference(_) => self.in_function |= self.function_depth > 0,
+            // Topic references in the body of a nested pipe refer to that pipe
+            Expression::PipelineExpression(pipe) => self.visit_expression(&pipe.left),
+            _ => walk::walk_expression(self, expr),
+        }
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: ScopeFlags) {
+        self.function_depth += 1;
+        walk::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, arrow);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &ClassBody<'a>) {
+        self.function_depth += 1;
+        walk::walk_class_body(self, body);
+        self.function_depth -= 1;
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.has_await |= self.function_depth == 0;
+        walk::walk_await_expression(self, expr);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        if self.function_depth == 0 && self.yield_span.is_none() {
+            self.yield_span = Some(expr.span);
+        }
+        walk::walk_yield_expression(self, expr);
+    }
+}
+
+/// Replace topic references `%` of a pipe body with reads of the pipe's temp var.
+struct TopicReplacer<'a, 'b> {
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'b mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for TopicReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::TopicReference(topic) => {
+                *expr = self.binding.create_spanned_read_expression(topic.span, self.ctx);
+            }
+            // Topic references in the body of a nested pipe refer to that pipe
+            Expression::PipelineExpression(pipe) => self.visit_expression(&mut pipe.left),
+            _ => walk_mut::walk_expression(self, expr),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Proposal: Throw Expressions
+//!
+//! This plugin transforms `throw` expressions into a call to a function which throws.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function save(filename = throw new TypeError("Argument required")) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function save(filename = (function (e) {
+//!   throw e;
+//! })(new TypeError("Argument required"))) {}
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-throw-expressions](https://babel.dev/docs/babel-plugin-proposal-throw-expressions).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-proposal-throw-expressions>
+//! * Throw expressions TC39 proposal: <https://github.com/tc39/proposal-throw-expressions>
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_traverse::Traverse;
+
+use crate::{context::TraverseCtx, state::TransformState};
+
+pub struct ThrowExpressions<'a> {
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl ThrowExpressions<'_> {
+    pub fn new() -> Self {
+        Self { _marker: std::marker::PhantomData }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ThrowExpressions<'a> {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ThrowExpression(_)) {
+            Self::transform_throw_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> ThrowExpressions<'a> {
+    /// `throw argument` -> `(function (e) { throw e; })(argument)`
+    fn transform_throw_expression(expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ThrowExpression(throw_expr) = expr.take_in(ctx.ast) else { unreachable!() };
+        let ThrowExpression { span, argument, .. } = throw_expr.unbox();
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Function);
+        let param =
+            ctx.generate_binding(ctx.ast.ident("e"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let throw_stmt = ctx.ast.statement_throw(SPAN, param.create_read_expression(ctx));
+        let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(throw_stmt));
+        let function = ctx.ast.expression_function_with_scope_id_and_pure_and_pife(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            scope_id,
+            false,
+            true,
+        );
+        *expr = ctx.ast.expression_call(span, function, NONE, ctx.ast.vec1(argument.into()), false);
+    }
+}
@@ -0,0 +1,221 @@
+//! Sets of code points, used to expand character classes and property escapes.
+
+use oxc_regular_expression::ast::CharacterClassEscapeKind;
+use regex_syntax::hir::{Class, HirKind};
+
+const MAX_CODE_POINT: u32 = 0x10_FFFF;
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/// A set of code points, stored as sorted, non-overlapping and non-adjacent inclusive ranges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CodePointSet {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CodePointSet {
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u32, u32)>) -> Self {
+        let mut set = Self { ranges: ranges.into_iter().collect() };
+        set.normalize();
+        set
+    }
+
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn insert(&mut self, code_point: u32) {
+        self.insert_range(code_point, code_point);
+    }
+
+    pub fn insert_range(&mut self, min: u32, max: u32) {
+        self.ranges.push((min, max));
+        self.normalize();
+    }
+
+    pub fn union(&mut self, other: &Self) {
+        self.ranges.extend_from_slice(&other.ranges);
+        self.normalize();
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_min, a_max)), Some(&(b_min, b_max))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let min = a_min.max(b_min);
+            let max = a_max.min(b_max);
+            if min <= max {
+                ranges.push((min, max));
+            }
+            if a_max < b_max {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(min, max) in &self.ranges {
+            if min > next {
+                ranges.push((next, min - 1));
+            }
+            next = max + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { ranges }
+    }
+
+    fn normalize(&mut self) {
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+        for &(min, max) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => last.1 = last.1.max(max),
+                _ => merged.push((min, max)),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+/// Code points matched by `\d`, `\s`, `\w` and their negations, in unicode mode.
+///
+/// With the `i` flag, `\w` also includes `ſ` and `K` (Kelvin sign), which case fold to word characters.
+pub fn character_class_escape(kind: CharacterClassEscapeKind, ignore_case: bool) -> CodePointSet {
+    let set = match kind {
+        CharacterClassEscapeKind::D | CharacterClassEscapeKind::NegativeD => {
+            CodePointSet::from_ranges([(0x30, 0x39)])
+        }
+        CharacterClassEscapeKind::S | CharacterClassEscapeKind::NegativeS => {
+            // WhiteSpace and LineTerminator
+            CodePointSet::from_ranges([
+                (0x09, 0x0D),
+                (0x20, 0x20),
+                (0xA0, 0xA0),
+                (0x1680, 0x1680),
+                (0x2000, 0x200A),
+                (0x2028, 0x2029),
+                (0x202F, 0x202F),
+                (0x205F, 0x205F),
+                (0x3000, 0x3000),
+                (0xFEFF, 0xFEFF),
+            ])
+        }
+        CharacterClassEscapeKind::W | CharacterClassEscapeKind::NegativeW => {
+            let mut set =
+                CodePointSet::from_ranges([(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)]);
+            if ignore_case {
+                set.insert(0x017F);
+                set.insert(0x212A);
+            }
+            set
+        }
+    };
+    match kind {
+        CharacterClassEscapeKind::NegativeD
+        | CharacterClassEscapeKind::NegativeS
+        | CharacterClassEscapeKind::NegativeW => set.complement(),
+        _ => set,
+    }
+}
+
+/// Code points matched by `\p{name=value}` or `\p{name}`.
+///
+/// Returns `None` if the property is not known to the Unicode tables.
+pub fn unicode_property(name: &str, value: Option<&str>) -> Option<CodePointSet> {
+    match (name, value) {
+        ("Any", None) => return Some(CodePointSet::from_ranges([(0, MAX_CODE_POINT)])),
+        ("General_Category" | "gc", Some("Cs" | "Surrogate")) => {
+            return Some(CodePointSet::from_ranges([SURROGATES]));
+        }
+        _ => {}
+    }
+
+    let property = match value {
+        Some(value) => format!(r"\p{{{name}={value}}}"),
+        None => format!(r"\p{{{name}}}"),
+    };
+    let hir = regex_syntax::parse(&property).ok()?;
+    let mut set = match hir.kind() {
+        HirKind::Class(Class::Unicode(class)) => CodePointSet::from_ranges(
+            class.ranges().iter().map(|range| (u32::from(range.start()), u32::from(range.end()))),
+        ),
+        HirKind::Class(Class::Bytes(class)) if class.ranges().is_empty() => CodePointSet::default(),
+        // Single code point
+        HirKind::Literal(literal) => {
+            let s = std::str::from_utf8(&literal.0).ok()?;
+            CodePointSet::from_ranges(s.chars().map(|c| (u32::from(c), u32::from(c))))
+        }
+        _ => return None,
+    };
+
+    // Rust `char`s exclude surrogates, so the tables never contain them
+    if includes_surrogates(name, value) {
+        set.insert_range(SURROGATES.0, SURROGATES.1);
+    }
+
+    Some(set)
+}
+
+fn includes_surrogates(name: &str, value: Option<&str>) -> bool {
+    match (name, value) {
+        ("Assigned", None) => true,
+        ("General_Category" | "gc", Some(value)) => {
+            matches!(value, "C" | "Other")
+        }
+        ("Script" | "sc" | "Script_Extensions" | "scx", Some(value)) => {
+            matches!(value, "Zzzz" | "Unknown")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let a = CodePointSet::from_ranges([(10, 20), (21, 30), (40, 50)]);
+        assert_eq!(a.ranges(), &[(10, 30), (40, 50)]);
+
+        let b = CodePointSet::from_ranges([(25, 45)]);
+        assert_eq!(a.intersection(&b).ranges(), &[(25, 30), (40, 45)]);
+        assert_eq!(a.difference(&b).ranges(), &[(10, 24), (46, 50)]);
+        assert_eq!(a.complement().ranges(), &[(0, 9), (31, 39), (51, MAX_CODE_POINT)]);
+
+        let mut c = a;
+        c.union(&b);
+        assert_eq!(c.ranges(), &[(10, 50)]);
+    }
+
+    #[test]
+    fn properties() {
+        let ascii = unicode_property("ASCII", None).unwrap();
+        assert_eq!(ascii.ranges(), &[(0, 0x7F)]);
+
+        let lu = unicode_property("General_Category", Some("Lu")).unwrap();
+        assert!(lu.ranges().contains(&(0x41, 0x5A)));
+
+        let cs = unicode_property("General_Category", Some("Cs")).unwrap();
+        assert_eq!(cs.ranges(), &[SURROGATES]);
+
+        let any = unicode_property("Any", None).unwrap();
+        assert!(any.complement().is_empty());
+    }
+}
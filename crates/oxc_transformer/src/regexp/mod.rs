@@ -1,10 +1,18 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! Where possible, the pattern is rewritten to an equivalent one which the target supports:
+//!
+//! * `s` flag: `/a.b/s` -> `/a[\s\S]b/`
+//! * Unicode property escapes: `/\p{ASCII}/u` -> `/[\u0000-\u007F]/u`
+//! * Named capture groups: `/(?<a>b)/` -> `babelHelpers.wrapRegExp(/(b)/, { a: 1 })`
+//! * Set notation: `/[\p{ASCII}&&\w]/v` -> `/[0-9A-Z_a-z]/u`
+//!
+//! Features which can't be rewritten (`y`, `u` and `d` flags, lookbehind assertions,
+//! and properties of strings) make the RegExp literal be converted into a `new RegExp()`
+//! constructor call to avoid syntax errors. Only in that case, you will need to include a polyfill
+//! for the `RegExp` constructor in your code to have the correct runtime behavior.
 //!
 //! ### ES2015
 //!
@@ -44,21 +52,31 @@
 //! TODO(improve-on-babel): When flags is empty, we could output `RegExp("(?<=x)")` instead of `RegExp("(?<=x)", "")`.
 //! (actually these would be improvements on ESBuild, not Babel)
 
+use oxc_allocator::Box as ArenaBox;
 use oxc_ast::{NONE, ast::*};
 use oxc_regular_expression::{
     RegexUnsupportedPatterns, has_unsupported_regular_expression_pattern,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::SPAN;
+use oxc_str::format_str;
 use oxc_traverse::Traverse;
 
-use crate::{context::TraverseCtx, state::TransformState};
+use crate::{
+    common::helper_loader::{Helper, helper_call_expr},
+    context::TraverseCtx,
+    state::TransformState,
+};
 
+mod code_point_set;
 mod options;
+mod rewrite;
 
 pub use options::RegExpOptions;
+use rewrite::{GroupNames, PatternRewriter};
 
 pub struct RegExp {
+    options: RegExpOptions,
     unsupported_flags: RegExpFlags,
     some_unsupported_patterns: bool,
     unsupported_patterns: RegexUnsupportedPatterns,
@@ -96,6 +114,7 @@ impl RegExp {
             look_behind_assertions || named_capture_groups || unicode_property_escapes;
 
         Self {
+            options,
             unsupported_flags,
             some_unsupported_patterns,
             unsupported_patterns: RegexUnsupportedPatterns {
@@ -119,44 +138,99 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp {
 }
 
 impl<'a> RegExp {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, rewrite the pattern,
+    /// or transform to `new RegExp(...)` if it cannot be rewritten.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
         };
         let regexp = regexp.as_mut();
 
-        let pattern_text = regexp.regex.pattern.text;
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
-            } else {
-                match regexp.parse_pattern(ctx.ast.allocator) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
-                    Err(error) => {
-                        ctx.state.error(error);
-                        return;
-                    }
+        let owned_pattern;
+        let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
+            pattern
+        } else {
+            match regexp.parse_pattern(ctx.ast.allocator) {
+                Ok(pattern) => {
+                    owned_pattern = Some(pattern);
+                    owned_pattern.as_ref().unwrap()
                 }
-            };
+                Err(error) => {
+                    ctx.state.error(error);
+                    return;
+                }
+            }
+        };
+
+        if !has_unsupported_flags
+            && !has_unsupported_regular_expression_pattern(pattern, &self.unsupported_patterns)
+        {
+            return;
+        }
 
-            if !has_unsupported_regular_expression_pattern(pattern, &self.unsupported_patterns) {
-                return;
+        *expr = match self.rewrite_regexp(regexp, ctx) {
+            Some(rewritten) => rewritten,
+            None => {
+                Self::create_regexp_constructor(regexp.span, regexp.regex.pattern.text, flags, ctx)
             }
+        };
+    }
+
+    /// Rewrite the pattern of `RegExpLiteral` to remove unsupported syntax.
+    ///
+    /// Returns `None` if the pattern cannot be rewritten.
+    fn rewrite_regexp(
+        &self,
+        regexp: &RegExpLiteral<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut pattern = regexp.parse_pattern(ctx.ast.allocator).ok()?;
+        let rewriter = PatternRewriter::new(regexp.regex.flags, self.options, ctx.ast.allocator);
+        let rewritten = rewriter.rewrite(&mut pattern)?;
+        if !rewritten.changed {
+            return None;
         }
 
+        let span = regexp.span;
+        let text = format_str!(ctx.ast.allocator, "{pattern}");
+        let flags = rewritten.flags;
+        let regexp_expr = if flags.intersects(self.unsupported_flags)
+            || has_unsupported_regular_expression_pattern(&pattern, &self.unsupported_patterns)
+        {
+            Self::create_regexp_constructor(span, text, flags, ctx)
+        } else {
+            let regex = oxc_ast::ast::RegExp {
+                pattern: RegExpPattern {
+                    text,
+                    pattern: Some(ArenaBox::new_in(pattern, ctx.ast.allocator)),
+                },
+                flags,
+            };
+            ctx.ast.expression_reg_exp_literal(span, regex, None)
+        };
+
+        if rewritten.group_names.is_empty() {
+            Some(regexp_expr)
+        } else {
+            Some(Self::wrap_regexp(regexp_expr, &rewritten.group_names, ctx))
+        }
+    }
+
+    /// `new RegExp(pattern, flags)`
+    fn create_regexp_constructor(
+        span: Span,
+        pattern_text: Str<'a>,
+        flags: RegExpFlags,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
         let callee = {
             let regexp = ctx.ast.ident("RegExp");
             let symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), regexp);
@@ -172,6 +246,45 @@ impl<'a> RegExp {
             )),
         ]);
 
-        *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
+        ctx.ast.expression_new(span, callee, NONE, arguments)
+    }
+
+    /// `babelHelpers.wrapRegExp(regexp, { a: 1, b: [2, 3] })`
+    fn wrap_regexp(
+        regexp: Expression<'a>,
+        group_names: &GroupNames<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let index_expr = |index: u32, ctx: &TraverseCtx<'a>| {
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(index), None, NumberBase::Decimal)
+        };
+        let properties = ctx.ast.vec_from_iter(group_names.iter().map(|(name, indexes)| {
+            let value = if let [index] = indexes.as_slice() {
+                index_expr(*index, ctx)
+            } else {
+                ctx.ast.expression_array(
+                    SPAN,
+                    ctx.ast.vec_from_iter(
+                        indexes
+                            .iter()
+                            .map(|&index| ArrayExpressionElement::from(index_expr(index, ctx))),
+                    ),
+                )
+            };
+            ctx.ast.object_property_kind_object_property(
+                SPAN,
+                PropertyKind::Init,
+                ctx.ast.property_key_static_identifier(SPAN, *name),
+                value,
+                false,
+                false,
+                false,
+            )
+        }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(regexp),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        helper_call_expr(Helper::WrapRegExp, arguments, ctx)
     }
 }
@@ -0,0 +1,473 @@
+//! Rewrite a RegExp pattern into an equivalent one which doesn't use unsupported syntax.
+//!
+//! * DotAll flag: `/./s` -> `/[\s\S]/`
+//! * Unicode property escapes: `/\p{ASCII}/u` -> `/[\u0000-\u007F]/u`
+//! * Named capture groups: `/(?<a>x)\k<a>/` -> `/(x)\1/`, with group names returned to the caller.
+//! * Set notation: `/[\p{ASCII}--[a-z]]/v` -> `/[\u0000-\u0060\u007B-\u007F]/u`
+//!
+//! Rewriting is abandoned (the rewriter returns `None`) for cases which can't be expressed
+//! without the unsupported syntax, e.g. properties of strings, or set notation with the `i` flag.
+
+use std::collections::BTreeSet;
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Vec as ArenaVec};
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::{
+    ast::{
+        Alternative, CapturingGroup, Character, CharacterClass, CharacterClassContents,
+        CharacterClassContentsKind, CharacterClassEscape, CharacterClassEscapeKind,
+        CharacterClassRange, CharacterKind, Disjunction, IgnoreGroup, IndexedReference, Modifier,
+        Pattern, Term, UnicodePropertyEscape,
+    },
+    visit::{Visit, walk::walk_capturing_group},
+};
+use oxc_span::SPAN;
+use oxc_str::Str;
+
+use super::{
+    RegExpOptions,
+    code_point_set::{CodePointSet, character_class_escape, unicode_property},
+};
+
+/// Capture group names, and the indexes of the groups with each name.
+pub type GroupNames<'a> = Vec<(Str<'a>, Vec<u32>)>;
+
+pub struct RewrittenPattern<'a> {
+    /// Flags for the rewritten pattern
+    pub flags: RegExpFlags,
+    /// Names of capture groups which were removed from the pattern
+    pub group_names: GroupNames<'a>,
+    /// `true` if the pattern or flags were altered
+    pub changed: bool,
+}
+
+pub struct PatternRewriter<'a> {
+    allocator: &'a Allocator,
+    flags: RegExpFlags,
+    dot_all: bool,
+    property_escapes: bool,
+    named_groups: bool,
+    set_notation: bool,
+    group_names: GroupNames<'a>,
+    changed: bool,
+}
+
+/// Code points and strings matched by a character class.
+#[derive(Default)]
+struct ClassSet {
+    code_points: CodePointSet,
+    /// Strings which are not a single code point
+    strings: BTreeSet<Vec<u32>>,
+}
+
+impl<'a> PatternRewriter<'a> {
+    pub fn new(flags: RegExpFlags, options: RegExpOptions, allocator: &'a Allocator) -> Self {
+        Self {
+            allocator,
+            flags,
+            dot_all: options.dot_all_flag,
+            property_escapes: options.unicode_property_escapes,
+            named_groups: options.named_capture_groups,
+            set_notation: options.set_notation && flags.contains(RegExpFlags::V),
+            group_names: vec![],
+            changed: false,
+        }
+    }
+
+    /// Returns `None` if the pattern cannot be rewritten.
+    pub fn rewrite(mut self, pattern: &mut Pattern<'a>) -> Option<RewrittenPattern<'a>> {
+        if self.named_groups {
+            let mut collector = GroupNameCollector::default();
+            collector.visit_pattern(pattern);
+            self.group_names = collector.names;
+        }
+
+        self.rewrite_disjunction(&mut pattern.body, self.flags.contains(RegExpFlags::S))?;
+
+        let mut flags = self.flags;
+        if self.dot_all {
+            flags.remove(RegExpFlags::S);
+        }
+        if self.set_notation {
+            flags.remove(RegExpFlags::V);
+            flags.insert(RegExpFlags::U);
+        }
+
+        Some(RewrittenPattern {
+            flags,
+            group_names: self.group_names,
+            changed: self.changed || flags != self.flags,
+        })
+    }
+
+    fn ignore_case(&self) -> bool {
+        self.flags.contains(RegExpFlags::I)
+    }
+
+    fn rewrite_disjunction(
+        &mut self,
+        disjunction: &mut Disjunction<'a>,
+        dot_all: bool,
+    ) -> Option<()> {
+        for alternative in &mut disjunction.body {
+            self.rewrite_alternative(alternative, dot_all)?;
+        }
+        Some(())
+    }
+
+    fn rewrite_alternative(
+        &mut self,
+        alternative: &mut Alternative<'a>,
+        dot_all: bool,
+    ) -> Option<()> {
+        for term in &mut alternative.body {
+            self.rewrite_term(term, dot_all)?;
+        }
+
+        if self.named_groups {
+            // `\k<a>1` -> `(?:\1)1`, not `\11`
+            for i in 1..alternative.body.len() {
+                if matches!(alternative.body[i - 1], Term::IndexedReference(_))
+                    && starts_with_digit(&alternative.body[i])
+                {
+                    let reference = std::mem::replace(&mut alternative.body[i - 1], dummy_term());
+                    alternative.body[i - 1] = self.group_term(ArenaVec::from_array_in(
+                        [Self::alternative(ArenaVec::from_array_in([reference], self.allocator))],
+                        self.allocator,
+                    ));
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    fn rewrite_term(&mut self, term: &mut Term<'a>, dot_all: bool) -> Option<()> {
+        match term {
+            // `.` -> `[\s\S]`
+            Term::Dot(_) if self.dot_all && dot_all => {
+                let body = ArenaVec::from_array_in(
+                    [
+                        self.class_escape(CharacterClassEscapeKind::S),
+                        self.class_escape(CharacterClassEscapeKind::NegativeS),
+                    ],
+                    self.allocator,
+                );
+                *term = self.class_term(false, body);
+                self.changed = true;
+            }
+            Term::LookAroundAssertion(assertion) => {
+                self.rewrite_disjunction(&mut assertion.body, dot_all)?;
+            }
+            Term::Quantifier(quantifier) => self.rewrite_term(&mut quantifier.body, dot_all)?,
+            Term::CapturingGroup(group) => {
+                if self.named_groups && group.name.is_some() {
+                    group.name = None;
+                    self.changed = true;
+                }
+                self.rewrite_disjunction(&mut group.body, dot_all)?;
+            }
+            Term::IgnoreGroup(group) => {
+                let dot_all = match &group.modifiers {
+                    Some(modifiers) if modifiers.enabling.contains(Modifier::S) => true,
+                    Some(modifiers) if modifiers.disabling.contains(Modifier::S) => false,
+                    _ => dot_all,
+                };
+                self.rewrite_disjunction(&mut group.body, dot_all)?;
+            }
+            // `\k<a>` -> `\1`
+            Term::NamedReference(reference) if self.named_groups => {
+                let (_, indexes) =
+                    self.group_names.iter().find(|(name, _)| *name == reference.name)?;
+                // A reference to duplicate named groups can't refer to a single group
+                let [index] = indexes.as_slice() else { return None };
+                *term = Term::IndexedReference(ArenaBox::new_in(
+                    IndexedReference { span: reference.span, index: *index },
+                    self.allocator,
+                ));
+                self.changed = true;
+            }
+            // `\p{ASCII}` -> `[\u0000-\u007F]`
+            Term::UnicodePropertyEscape(property)
+                if self.property_escapes || (self.set_notation && property.strings) =>
+            {
+                if self.flags.contains(RegExpFlags::V) && self.ignore_case() {
+                    return None;
+                }
+                let set = Self::property_set(property)?;
+                *term = self.class_term(false, self.class_contents(&set));
+                self.changed = true;
+            }
+            Term::CharacterClass(class) => {
+                if self.set_notation {
+                    // Case folding of sets differs between `u` and `v` modes
+                    if self.ignore_case() {
+                        return None;
+                    }
+                    let set = self.evaluate_class(class)?;
+                    *term = self.class_set_term(set, class.negative)?;
+                    self.changed = true;
+                } else if self.property_escapes
+                    && class.body.iter().any(|content| {
+                        matches!(content, CharacterClassContents::UnicodePropertyEscape(_))
+                    })
+                {
+                    self.rewrite_class_properties(class)?;
+                    self.changed = true;
+                }
+            }
+            _ => {}
+        }
+        Some(())
+    }
+
+    /// Expand unicode property escapes in a class, when set notation is not being transformed.
+    fn rewrite_class_properties(&self, class: &mut CharacterClass<'a>) -> Option<()> {
+        if self.flags.contains(RegExpFlags::V) {
+            if self.ignore_case() {
+                return None;
+            }
+            // `[\p{ASCII}&&\w]` -> `[[\u0000-\u007F]&&\w]`
+            for content in &mut class.body {
+                if let CharacterClassContents::UnicodePropertyEscape(property) = content {
+                    let set = Self::property_set(property)?;
+                    *content = CharacterClassContents::NestedCharacterClass(ArenaBox::new_in(
+                        Self::class(false, self.class_contents(&set)),
+                        self.allocator,
+                    ));
+                }
+            }
+        } else {
+            // `[a\p{ASCII}]` -> `[\u0000-\u007F]`
+            let set = self.evaluate_class(class)?;
+            class.body = self.class_contents(&set.code_points);
+        }
+        Some(())
+    }
+
+    /// Evaluate the code points and strings matched by a class, ignoring whether it is negated.
+    fn evaluate_class(&self, class: &CharacterClass<'a>) -> Option<ClassSet> {
+        let mut operands = class.body.iter().map(|content| self.evaluate_class_contents(content));
+        let mut result = operands.next().unwrap_or_else(|| Some(ClassSet::default()))?;
+        for operand in operands {
+            let operand = operand?;
+            match class.kind {
+                CharacterClassContentsKind::Union => {
+                    result.code_points.union(&operand.code_points);
+                    result.strings.extend(operand.strings);
+                }
+                CharacterClassContentsKind::Intersection => {
+                    result.code_points = result.code_points.intersection(&operand.code_points);
+                    result.strings.retain(|string| operand.strings.contains(string));
+                }
+                CharacterClassContentsKind::Subtraction => {
+                    result.code_points = result.code_points.difference(&operand.code_points);
+                    result.strings.retain(|string| !operand.strings.contains(string));
+                }
+            }
+        }
+        Some(result)
+    }
+
+    fn evaluate_class_contents(&self, content: &CharacterClassContents<'a>) -> Option<ClassSet> {
+        let code_points = match content {
+            CharacterClassContents::Character(character) => {
+                CodePointSet::from_ranges([(character.value, character.value)])
+            }
+            CharacterClassContents::CharacterClassRange(range) => {
+                CodePointSet::from_ranges([(range.min.value, range.max.value)])
+            }
+            CharacterClassContents::CharacterClassEscape(escape) => {
+                character_class_escape(escape.kind, self.ignore_case())
+            }
+            CharacterClassContents::UnicodePropertyEscape(property) => {
+                Self::property_set(property)?
+            }
+            CharacterClassContents::NestedCharacterClass(class) => {
+                let mut set = self.evaluate_class(class)?;
+                if class.negative {
+                    set.code_points = set.code_points.complement();
+                }
+                return Some(set);
+            }
+            CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                let mut set = ClassSet::default();
+                for string in &disjunction.body {
+                    if let [character] = string.body.as_slice() {
+                        set.code_points.insert(character.value);
+                    } else {
+                        set.strings.insert(string.body.iter().map(|c| c.value).collect());
+                    }
+                }
+                return Some(set);
+            }
+        };
+        Some(ClassSet { code_points, strings: BTreeSet::new() })
+    }
+
+    fn property_set(property: &UnicodePropertyEscape<'a>) -> Option<CodePointSet> {
+        // Properties of strings are not supported
+        if property.strings {
+            return None;
+        }
+        let set = unicode_property(&property.name, property.value.as_deref())?;
+        Some(if property.negative { set.complement() } else { set })
+    }
+
+    /// `[\q{abc}a-z]` -> `(?:abc|[a-z])`
+    fn class_set_term(&self, set: ClassSet, negative: bool) -> Option<Term<'a>> {
+        if set.strings.is_empty() {
+            return Some(self.class_term(negative, self.class_contents(&set.code_points)));
+        }
+
+        // Longest strings are matched first, and the empty string last
+        let mut strings = set.strings.into_iter().collect::<Vec<_>>();
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let has_empty_string = strings.last().is_some_and(Vec::is_empty);
+
+        let mut alternatives = ArenaVec::new_in(self.allocator);
+        for string in strings.iter().filter(|string| !string.is_empty()) {
+            // A lone lead surrogate followed by a trail surrogate would be read as a surrogate pair
+            if string
+                .windows(2)
+                .any(|pair| is_lead_surrogate(pair[0]) && is_trail_surrogate(pair[1]))
+            {
+                return None;
+            }
+            let terms = ArenaVec::from_iter_in(
+                string.iter().map(|&value| {
+                    Term::Character(ArenaBox::new_in(Self::character(value), self.allocator))
+                }),
+                self.allocator,
+            );
+            alternatives.push(Self::alternative(terms));
+        }
+        if !set.code_points.is_empty() {
+            let class = self.class_term(false, self.class_contents(&set.code_points));
+            alternatives.push(Self::alternative(ArenaVec::from_array_in([class], self.allocator)));
+        }
+        if has_empty_string {
+            alternatives.push(Self::alternative(ArenaVec::new_in(self.allocator)));
+        }
+        Some(self.group_term(alternatives))
+    }
+
+    fn class_contents(&self, set: &CodePointSet) -> ArenaVec<'a, CharacterClassContents<'a>> {
+        // `[\uD800-\uDBFF\uDC05]` would be read as `\uD800-` followed by a surrogate pair,
+        // so put ranges starting with a trail surrogate first
+        let (trail_first, rest): (Vec<_>, Vec<_>) =
+            set.ranges().iter().partition(|(min, _)| is_trail_surrogate(*min));
+        ArenaVec::from_iter_in(
+            trail_first.into_iter().chain(rest).map(|&(min, max)| {
+                if min == max {
+                    CharacterClassContents::Character(ArenaBox::new_in(
+                        Self::character(min),
+                        self.allocator,
+                    ))
+                } else {
+                    CharacterClassContents::CharacterClassRange(ArenaBox::new_in(
+                        CharacterClassRange {
+                            span: SPAN,
+                            min: Self::character(min),
+                            max: Self::character(max),
+                        },
+                        self.allocator,
+                    ))
+                }
+            }),
+            self.allocator,
+        )
+    }
+
+    fn character(value: u32) -> Character {
+        let is_alphanumeric = char::from_u32(value).is_some_and(|c| c.is_ascii_alphanumeric());
+        let kind =
+            if is_alphanumeric { CharacterKind::Symbol } else { CharacterKind::UnicodeEscape };
+        Character { span: SPAN, kind, value }
+    }
+
+    fn class_escape(&self, kind: CharacterClassEscapeKind) -> CharacterClassContents<'a> {
+        CharacterClassContents::CharacterClassEscape(ArenaBox::new_in(
+            CharacterClassEscape { span: SPAN, kind },
+            self.allocator,
+        ))
+    }
+
+    fn class(negative: bool, body: ArenaVec<'a, CharacterClassContents<'a>>) -> CharacterClass<'a> {
+        CharacterClass {
+            span: SPAN,
+            negative,
+            strings: false,
+            kind: CharacterClassContentsKind::Union,
+            body,
+        }
+    }
+
+    fn class_term(
+        &self,
+        negative: bool,
+        body: ArenaVec<'a, CharacterClassContents<'a>>,
+    ) -> Term<'a> {
+        Term::CharacterClass(ArenaBox::new_in(Self::class(negative, body), self.allocator))
+    }
+
+    fn alternative(body: ArenaVec<'a, Term<'a>>) -> Alternative<'a> {
+        Alternative { span: SPAN, body }
+    }
+
+    /// `(?:...)`
+    fn group_term(&self, alternatives: ArenaVec<'a, Alternative<'a>>) -> Term<'a> {
+        Term::IgnoreGroup(ArenaBox::new_in(
+            IgnoreGroup {
+                span: SPAN,
+                modifiers: None,
+                body: Disjunction { span: SPAN, body: alternatives },
+            },
+            self.allocator,
+        ))
+    }
+}
+
+/// Collect names of capture groups, in the order the groups are numbered.
+#[derive(Default)]
+struct GroupNameCollector<'a> {
+    index: u32,
+    names: GroupNames<'a>,
+}
+
+impl<'a> Visit<'a> for GroupNameCollector<'a> {
+    fn visit_capturing_group(&mut self, group: &CapturingGroup<'a>) {
+        self.index += 1;
+        if let Some(name) = group.name {
+            if let Some((_, indexes)) = self.names.iter_mut().find(|(n, _)| *n == name) {
+                indexes.push(self.index);
+            } else {
+                self.names.push((name, vec![self.index]));
+            }
+        }
+        walk_capturing_group(self, group);
+    }
+}
+
+fn starts_with_digit(term: &Term) -> bool {
+    match term {
+        Term::Character(character) => {
+            character.kind == CharacterKind::Symbol
+                && char::from_u32(character.value).is_some_and(|c| c.is_ascii_digit())
+        }
+        Term::Quantifier(quantifier) => starts_with_digit(&quantifier.body),
+        _ => false,
+    }
+}
+
+fn dummy_term<'a>() -> Term<'a> {
+    Term::Dot(oxc_regular_expression::ast::Dot { span: SPAN })
+}
+
+fn is_lead_surrogate(value: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&value)
+}
+
+fn is_trail_surrogate(value: u32) -> bool {
+    (0xDC00..=0xDFFF).contains(&value)
+}
@@ -47,6 +47,8 @@ pub struct TransformState<'a> {
     // State for multiple plugins interacting
     /// `true` if class properties plugin is enabled
     pub is_class_properties_plugin_enabled: bool,
+    /// `true` if regenerator plugin is enabled
+    pub is_regenerator_plugin_enabled: bool,
 }
 
 impl Default for TransformState<'_> {
@@ -65,6 +67,7 @@ impl Default for TransformState<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: false,
+            is_regenerator_plugin_enabled: false,
         }
     }
 }
@@ -89,6 +92,7 @@ impl TransformState<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: options.env.es2022.class_properties.is_some(),
+            is_regenerator_plugin_enabled: options.env.es2015.regenerator,
         }
     }
 
@@ -26,12 +26,6 @@ pub fn ambient_module_nested(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
-#[cold]
-pub fn namespace_exporting_non_const(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Namespaces exporting non-const are not supported by Oxc. Change to const or see: https://oxc.rs/docs/guide/usage/transformer/typescript.html#partial-namespace-support")
-        .with_label(span)
-}
-
 #[cold]
 pub fn namespace_not_supported(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Namespace not marked type-only declare are disabled. To enable and review caveats see: https://oxc.rs/docs/guide/usage/transformer/typescript.html#partial-namespace-support")
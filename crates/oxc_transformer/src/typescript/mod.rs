@@ -44,7 +44,7 @@ use rewrite_extensions::TypeScriptRewriteExtensions;
 pub struct TypeScript<'a> {
     annotations: TypeScriptAnnotations<'a>,
     r#enum: TypeScriptEnum,
-    namespace: TypeScriptNamespace,
+    namespace: TypeScriptNamespace<'a>,
     module: TypeScriptModule,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
     // Options
@@ -87,7 +87,6 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a> {
 
     fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         self.annotations.exit_program(program, ctx);
-        self.module.exit_program(program, ctx);
         ctx.scoping.delete_typescript_bindings();
     }
 
@@ -145,6 +144,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a> {
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.r#enum.enter_expression(expr, ctx);
         self.annotations.enter_expression(expr, ctx);
+        self.namespace.enter_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -153,6 +153,19 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.annotations.enter_simple_assignment_target(target, ctx);
+        self.namespace.enter_simple_assignment_target(target, ctx);
+    }
+
+    fn enter_assignment_target_property(
+        &mut self,
+        property: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.namespace.enter_assignment_target_property(property, ctx);
+    }
+
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.namespace.enter_object_property(prop, ctx);
     }
 
     fn enter_assignment_target(
@@ -1,11 +1,14 @@
+use rustc_hash::FxHashMap;
+
 use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
 use oxc_ecmascript::BoundNames;
 use oxc_span::{SPAN, Span};
+use oxc_str::Ident;
 use oxc_syntax::{
     operator::{AssignmentOperator, LogicalOperator},
     scope::{ScopeFlags, ScopeId},
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 use oxc_traverse::{BoundIdentifier, Traverse};
 
@@ -13,21 +16,77 @@ use crate::{context::TraverseCtx, state::TransformState};
 
 use super::{
     TypeScriptOptions,
-    diagnostics::{ambient_module_nested, namespace_exporting_non_const, namespace_not_supported},
+    diagnostics::{ambient_module_nested, namespace_not_supported},
 };
 
-pub struct TypeScriptNamespace {
+/// Transform TypeScript namespaces.
+///
+/// Exported `let` and `var` bindings become properties of the namespace object, and all references
+/// to them are rewritten to property accesses, the same as `tsc` does:
+///
+/// ```ts
+/// namespace N {
+///   export let x = 1;
+///   x++;
+/// }
+/// ```
+///
+/// ```js
+/// let N;
+/// (function(_N) {
+///   _N.x = 1;
+///   _N.x++;
+/// })(N || (N = {}));
+/// ```
+///
+/// When a namespace is declared in multiple blocks, references in one block to names exported from
+/// another block are rewritten to property accesses too. Merging of namespaces declared in different
+/// files is not supported, as each file is transformed in isolation.
+pub struct TypeScriptNamespace<'a> {
     // Options
     allow_namespaces: bool,
+
+    /// Exported `let` and `var` bindings, mapped to the parameter of the namespace function
+    /// they are properties of
+    mutable_exports: FxHashMap<SymbolId, BoundIdentifier<'a>>,
+    /// Namespaces, indexed by `NamespaceKey`
+    namespaces: Vec<Namespace<'a>>,
+    namespace_indexes: FxHashMap<NamespaceKey<'a>, usize>,
+    /// Scopes of blocks of namespaces which are declared in more than one block,
+    /// mapped to index of the namespace and parameter of the namespace function
+    merged_blocks: FxHashMap<ScopeId, (usize, BoundIdentifier<'a>)>,
+}
+
+/// Identity of a namespace, which is shared by all the blocks the namespace is declared in.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum NamespaceKey<'a> {
+    /// Namespace which is not exported from a parent namespace
+    Symbol(SymbolId),
+    /// Namespace exported from the parent namespace with index `usize`
+    Member(usize, Ident<'a>),
+}
+
+#[derive(Default)]
+struct Namespace<'a> {
+    /// Scope and parameter binding of each block the namespace is declared in
+    blocks: Vec<(ScopeId, BoundIdentifier<'a>)>,
+    /// Names of exported bindings, and scope of the block which exports them
+    exports: Vec<(Ident<'a>, ScopeId)>,
 }
 
-impl TypeScriptNamespace {
+impl TypeScriptNamespace<'_> {
     pub fn new(options: &TypeScriptOptions) -> Self {
-        Self { allow_namespaces: options.allow_namespaces }
+        Self {
+            allow_namespaces: options.allow_namespaces,
+            mutable_exports: FxHashMap::default(),
+            namespaces: vec![],
+            namespace_indexes: FxHashMap::default(),
+            merged_blocks: FxHashMap::default(),
+        }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptNamespace {
+impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptNamespace<'a> {
     // `namespace Foo { }` -> `let Foo; (function (_Foo) { })(Foo || (Foo = {}));`
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         // namespace declaration is only allowed at the top level
@@ -83,17 +142,93 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptNamespace {
         }
 
         program.body = new_stmts;
+
+        // Only namespaces declared in more than one block can reference exports of another block
+        for (index, namespace) in self.namespaces.iter_mut().enumerate() {
+            if namespace.blocks.len() > 1 {
+                for (scope_id, binding) in namespace.blocks.drain(..) {
+                    self.merged_blocks.insert(scope_id, (index, binding));
+                }
+            }
+        }
+    }
+
+    // `x` -> `_N.x`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && let Some(binding) = self.get_namespace_binding(ident, ctx)
+        {
+            let member_expr = Self::create_member_expression(binding, ident.name, ctx);
+            ctx.delete_reference_for_identifier(ident);
+            *expr = Expression::from(member_expr);
+        }
+    }
+
+    // `x = 1` -> `_N.x = 1`
+    fn enter_simple_assignment_target(
+        &mut self,
+        target: &mut SimpleAssignmentTarget<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target
+            && let Some(binding) = self.get_namespace_binding(ident, ctx)
+        {
+            let member_expr = Self::create_member_expression(binding, ident.name, ctx);
+            ctx.delete_reference_for_identifier(ident);
+            *target = SimpleAssignmentTarget::from(member_expr);
+        }
+    }
+
+    // `({ x } = obj)` -> `({ x: x } = obj)`, then `x` is rewritten to `_N.x`
+    fn enter_assignment_target_property(
+        &mut self,
+        property: &mut AssignmentTargetProperty<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = property else {
+            return;
+        };
+        if self.get_namespace_binding(&prop.binding, ctx).is_none() {
+            return;
+        }
+
+        let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) =
+            property.take_in(ctx.ast)
+        else {
+            unreachable!()
+        };
+        let AssignmentTargetPropertyIdentifier { span, binding, init, .. } = prop.unbox();
+        let name = ctx.ast.property_key_static_identifier(binding.span, binding.name);
+        let target = AssignmentTarget::AssignmentTargetIdentifier(ctx.ast.alloc(binding));
+        let target = match init {
+            Some(init) => ctx
+                .ast
+                .assignment_target_maybe_default_assignment_target_with_default(SPAN, target, init),
+            None => AssignmentTargetMaybeDefault::from(target),
+        };
+        *property = ctx.ast.assignment_target_property_assignment_target_property_property(
+            span, name, target, false,
+        );
+    }
+
+    // `({ x })` -> `({ x: x })`, then `x` is rewritten to `_N.x`
+    fn enter_object_property(&mut self, prop: &mut ObjectProperty<'a>, ctx: &mut TraverseCtx<'a>) {
+        if prop.shorthand
+            && let Expression::Identifier(ident) = &prop.value
+            && self.get_namespace_binding(ident, ctx).is_some()
+        {
+            prop.shorthand = false;
+        }
     }
 }
 
-impl<'a> TypeScriptNamespace {
-    #[expect(clippy::self_only_used_in_recursion)]
+impl<'a> TypeScriptNamespace<'a> {
     fn handle_nested(
-        &self,
+        &mut self,
         decl: ArenaBox<'a, TSModuleDeclaration<'a>>,
         is_export: bool,
         parent_stmts: &mut ArenaVec<'a, Statement<'a>>,
-        parent_binding: Option<&BoundIdentifier<'a>>,
+        parent: Option<(&BoundIdentifier<'a>, usize)>,
         ctx: &mut TraverseCtx<'a>,
     ) {
         if decl.declare {
@@ -171,6 +306,17 @@ impl<'a> TypeScriptNamespace {
         let uid_binding =
             ctx.generate_uid(&binding.name, scope_id, SymbolFlags::FunctionScopedVariable);
 
+        let key = match parent {
+            Some((_, parent_index)) => NamespaceKey::Member(parent_index, binding.name),
+            None => NamespaceKey::Symbol(symbol_id),
+        };
+        let next_index = self.namespaces.len();
+        let index = *self.namespace_indexes.entry(key).or_insert(next_index);
+        if index == next_index {
+            self.namespaces.push(Namespace::default());
+        }
+        self.namespaces[index].blocks.push((scope_id, uid_binding.clone()));
+
         let directives;
         let namespace_top_level;
 
@@ -219,11 +365,13 @@ impl<'a> TypeScriptNamespace {
                                 let binding =
                                     BoundIdentifier::from_binding_ident(&import_equals.id);
                                 new_stmts.push(Statement::from(decl));
+                                self.namespaces[index].exports.push((binding.name, scope_id));
                                 Self::add_declaration(&uid_binding, &binding, &mut new_stmts, ctx);
                             }
                             Declaration::TSEnumDeclaration(ref enum_decl) => {
                                 let binding = BoundIdentifier::from_binding_ident(&enum_decl.id);
                                 new_stmts.push(Statement::from(decl));
+                                self.namespaces[index].exports.push((binding.name, scope_id));
                                 Self::add_declaration(&uid_binding, &binding, &mut new_stmts, ctx);
                             }
                             Declaration::ClassDeclaration(ref class_decl) => {
@@ -232,6 +380,7 @@ impl<'a> TypeScriptNamespace {
                                     class_decl.id.as_ref().unwrap(),
                                 );
                                 new_stmts.push(Statement::from(decl));
+                                self.namespaces[index].exports.push((binding.name, scope_id));
                                 Self::add_declaration(&uid_binding, &binding, &mut new_stmts, ctx);
                             }
                             Declaration::FunctionDeclaration(ref func_decl) => {
@@ -241,6 +390,7 @@ impl<'a> TypeScriptNamespace {
                                         func_decl.id.as_ref().unwrap(),
                                     );
                                     new_stmts.push(Statement::from(decl));
+                                    self.namespaces[index].exports.push((binding.name, scope_id));
                                     Self::add_declaration(
                                         &uid_binding,
                                         &binding,
@@ -250,22 +400,36 @@ impl<'a> TypeScriptNamespace {
                                 }
                             }
                             Declaration::VariableDeclaration(var_decl) => {
-                                var_decl.declarations.iter().for_each(|decl| {
-                                    if !decl.kind.is_const() {
-                                        ctx.state.error(namespace_exporting_non_const(decl.span));
-                                    }
+                                var_decl.bound_names(&mut |id| {
+                                    self.namespaces[index].exports.push((id.name, scope_id));
                                 });
-                                let stmts =
-                                    Self::handle_variable_declaration(var_decl, &uid_binding, ctx);
-                                new_stmts.extend(stmts);
+                                if var_decl.kind.is_const() {
+                                    let stmts = Self::handle_variable_declaration(
+                                        var_decl,
+                                        &uid_binding,
+                                        ctx,
+                                    );
+                                    new_stmts.extend(stmts);
+                                } else {
+                                    new_stmts.extend(self.handle_mutable_variable_declaration(
+                                        var_decl,
+                                        &uid_binding,
+                                        ctx,
+                                    ));
+                                }
                             }
                             Declaration::TSModuleDeclaration(module_decl) => {
+                                if let TSModuleDeclarationName::Identifier(id) = &module_decl.id
+                                    && ctx.scoping().symbol_flags(id.symbol_id()).is_value_module()
+                                {
+                                    self.namespaces[index].exports.push((id.name, scope_id));
+                                }
                                 self.handle_nested(
                                     module_decl,
                                     /* is_export */
                                     false,
                                     &mut new_stmts,
-                                    Some(&uid_binding),
+                                    Some((&uid_binding, index)),
                                     ctx,
                                 );
                             }
@@ -296,7 +460,7 @@ impl<'a> TypeScriptNamespace {
             span,
             &uid_binding,
             &binding,
-            parent_binding,
+            parent.map(|(parent_binding, _)| parent_binding),
             func_body,
             scope_id,
             ctx,
@@ -490,6 +654,173 @@ impl<'a> TypeScriptNamespace {
         ])
     }
 
+    /// Convert `export let a = 1, [b] = c, d;` to `_N.a = 1, [_N.b] = c;`,
+    /// and record `a`, `b` and `d` as exports, so references to them are rewritten to `_N.a` etc.
+    fn handle_mutable_variable_declaration(
+        &mut self,
+        var_decl: ArenaBox<'a, VariableDeclaration<'a>>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let VariableDeclaration { span, declarations, .. } = var_decl.unbox();
+
+        let mut assignments = ctx.ast.vec();
+        for declarator in declarations {
+            declarator.id.bound_names(&mut |id| {
+                let symbol_id = id.symbol_id();
+                let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+                ctx.scoping_mut().remove_binding(scope_id, id.name);
+                self.mutable_exports.insert(symbol_id, binding.clone());
+            });
+
+            // `export let a;` has nothing to assign
+            let Some(init) = declarator.init else { continue };
+            let target = Self::binding_pattern_to_assignment_target(declarator.id, binding, ctx);
+            assignments.push(ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+
+        let expr = match assignments.len() {
+            0 => return None,
+            1 => assignments.pop().unwrap(),
+            _ => ctx.ast.expression_sequence(SPAN, assignments),
+        };
+        Some(ctx.ast.statement_expression(span, expr))
+    }
+
+    /// `[a, { b = 1 }]` -> `[_N.a, { b: _N.b = 1 }]`
+    fn binding_pattern_to_assignment_target(
+        pattern: BindingPattern<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        match pattern {
+            BindingPattern::BindingIdentifier(id) => {
+                AssignmentTarget::from(Self::create_member_expression(binding, id.name, ctx))
+            }
+            BindingPattern::ArrayPattern(array) => {
+                let ArrayPattern { span, elements, rest, .. } = array.unbox();
+                let elements = ctx.ast.vec_from_iter(elements.into_iter().map(|element| {
+                    element.map(|element| {
+                        Self::binding_pattern_to_assignment_target_maybe_default(
+                            element, binding, ctx,
+                        )
+                    })
+                }));
+                let rest = rest
+                    .map(|rest| Self::binding_rest_to_assignment_target_rest(rest, binding, ctx));
+                AssignmentTarget::ArrayAssignmentTarget(
+                    ctx.ast.alloc_array_assignment_target(span, elements, rest),
+                )
+            }
+            BindingPattern::ObjectPattern(object) => {
+                let ObjectPattern { span, properties, rest, .. } = object.unbox();
+                let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|property| {
+                    let BindingProperty { span, key, value, computed, .. } = property;
+                    let target = Self::binding_pattern_to_assignment_target_maybe_default(
+                        value, binding, ctx,
+                    );
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        span, key, target, computed,
+                    )
+                }));
+                let rest = rest
+                    .map(|rest| Self::binding_rest_to_assignment_target_rest(rest, binding, ctx));
+                AssignmentTarget::ObjectAssignmentTarget(
+                    ctx.ast.alloc_object_assignment_target(span, properties, rest),
+                )
+            }
+            BindingPattern::AssignmentPattern(_) => {
+                unreachable!("`AssignmentPattern` can only be nested in another pattern")
+            }
+        }
+    }
+
+    fn binding_pattern_to_assignment_target_maybe_default(
+        pattern: BindingPattern<'a>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTargetMaybeDefault<'a> {
+        if let BindingPattern::AssignmentPattern(assignment) = pattern {
+            let AssignmentPattern { span, left, right, .. } = assignment.unbox();
+            let target = Self::binding_pattern_to_assignment_target(left, binding, ctx);
+            ctx.ast
+                .assignment_target_maybe_default_assignment_target_with_default(span, target, right)
+        } else {
+            AssignmentTargetMaybeDefault::from(Self::binding_pattern_to_assignment_target(
+                pattern, binding, ctx,
+            ))
+        }
+    }
+
+    fn binding_rest_to_assignment_target_rest(
+        rest: ArenaBox<'a, BindingRestElement<'a>>,
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, AssignmentTargetRest<'a>> {
+        let BindingRestElement { span, argument, .. } = rest.unbox();
+        let target = Self::binding_pattern_to_assignment_target(argument, binding, ctx);
+        ctx.ast.alloc_assignment_target_rest(span, target)
+    }
+
+    /// Get binding of the namespace function parameter, if `ident` refers to an export
+    /// which is a property of the namespace object.
+    ///
+    /// That is the case if `ident` refers to an exported `let` or `var` binding, or if it refers to
+    /// a binding exported from another block of the same namespace.
+    fn get_namespace_binding(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&BoundIdentifier<'a>> {
+        if self.mutable_exports.is_empty() && self.merged_blocks.is_empty() {
+            return None;
+        }
+
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        if let Some(symbol_id) = symbol_id
+            && let Some(binding) = self.mutable_exports.get(&symbol_id)
+        {
+            return Some(binding);
+        }
+
+        if self.merged_blocks.is_empty() {
+            return None;
+        }
+
+        // Names in a namespace block resolve to local bindings first, then to exports of the namespace
+        // (from any block), and then to bindings outside the namespace
+        let symbol_scope_id = symbol_id.map(|symbol_id| ctx.scoping().symbol_scope_id(symbol_id));
+        for scope_id in ctx.scoping().scope_ancestors(ctx.current_scope_id()) {
+            if symbol_scope_id == Some(scope_id) {
+                return None;
+            }
+            if let Some((index, binding)) = self.merged_blocks.get(&scope_id)
+                && self.namespaces[*index].exports.iter().any(|(name, block_scope_id)| {
+                    *name == ident.name && *block_scope_id != scope_id
+                })
+            {
+                return Some(binding);
+            }
+        }
+        None
+    }
+
+    /// `_N.x`
+    fn create_member_expression(
+        binding: &BoundIdentifier<'a>,
+        name: Ident<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> MemberExpression<'a> {
+        let object = binding.create_read_expression(ctx);
+        let property = ctx.ast.identifier_name(SPAN, name);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+
     /// Check the namespace binding identifier if it is a redeclaration
     fn is_redeclaration_namespace(id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
         let symbol_id = id.symbol_id();
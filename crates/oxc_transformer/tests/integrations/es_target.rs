@@ -10,6 +10,9 @@ fn es_target() {
     use std::fmt::Write;
 
     let cases = [
+        ("es5", "`a${b}c`"),
+        ("es5", "f(...a)"),
+        ("es5", "for (const x of xs) {}"),
         ("es6", "a ** b"),
         ("es2015", "a ** b"),
         ("es2016", "async function foo() {}"),
@@ -74,6 +74,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Expression::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Expression::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Expression::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            Expression::DoExpression(it) => self.visit_do_expression(it),
+            Expression::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            Expression::ThrowExpression(it) => self.visit_throw_expression(it),
             Expression::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Expression::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Expression::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -89,6 +92,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -177,6 +181,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ArrayExpressionElement::V8IntrinsicExpression(it) => {
                 self.visit_v8_intrinsic_expression(it)
             }
+            ArrayExpressionElement::DoExpression(it) => self.visit_do_expression(it),
+            ArrayExpressionElement::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            ArrayExpressionElement::ThrowExpression(it) => self.visit_throw_expression(it),
             ArrayExpressionElement::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -199,6 +206,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -256,6 +264,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             PropertyKey::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            PropertyKey::DoExpression(it) => self.visit_do_expression(it),
+            PropertyKey::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            PropertyKey::ThrowExpression(it) => self.visit_throw_expression(it),
             PropertyKey::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             PropertyKey::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             PropertyKey::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -273,6 +284,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -376,6 +388,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             Argument::TSNonNullExpression(it) => self.visit_ts_non_null_expression(it),
             Argument::TSInstantiationExpression(it) => self.visit_ts_instantiation_expression(it),
             Argument::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            Argument::DoExpression(it) => self.visit_do_expression(it),
+            Argument::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            Argument::ThrowExpression(it) => self.visit_throw_expression(it),
             Argument::ComputedMemberExpression(it) => self.visit_computed_member_expression(it),
             Argument::StaticMemberExpression(it) => self.visit_static_member_expression(it),
             Argument::PrivateFieldExpression(it) => self.visit_private_field_expression(it),
@@ -391,6 +406,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -764,6 +780,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             ForStatementInit::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            ForStatementInit::DoExpression(it) => self.visit_do_expression(it),
+            ForStatementInit::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            ForStatementInit::ThrowExpression(it) => self.visit_throw_expression(it),
             ForStatementInit::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -781,6 +800,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1207,6 +1227,11 @@ impl<'a> Visit<'a> for ChildScopeCollector {
             ExportDefaultDeclarationKind::V8IntrinsicExpression(it) => {
                 self.visit_v8_intrinsic_expression(it)
             }
+            ExportDefaultDeclarationKind::DoExpression(it) => self.visit_do_expression(it),
+            ExportDefaultDeclarationKind::PipelineExpression(it) => {
+                self.visit_pipeline_expression(it)
+            }
+            ExportDefaultDeclarationKind::ThrowExpression(it) => self.visit_throw_expression(it),
             ExportDefaultDeclarationKind::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1228,6 +1253,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1242,6 +1268,27 @@ impl<'a> Visit<'a> for ChildScopeCollector {
         self.visit_arguments(&it.arguments);
     }
 
+    #[inline]
+    fn visit_do_expression(&mut self, it: &DoExpression<'a>) {
+        self.visit_block_statement(&it.body);
+    }
+
+    #[inline]
+    fn visit_pipeline_expression(&mut self, it: &PipelineExpression<'a>) {
+        self.visit_expression(&it.left);
+        self.visit_expression(&it.body);
+    }
+
+    #[inline(always)]
+    fn visit_topic_reference(&mut self, it: &TopicReference) {
+        // Struct does not contain a scope. Halt traversal.
+    }
+
+    #[inline]
+    fn visit_throw_expression(&mut self, it: &ThrowExpression<'a>) {
+        self.visit_expression(&it.argument);
+    }
+
     #[inline(always)]
     fn visit_boolean_literal(&mut self, it: &BooleanLiteral) {
         // Struct does not contain a scope. Halt traversal.
@@ -1370,6 +1417,9 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 self.visit_ts_instantiation_expression(it)
             }
             JSXExpression::V8IntrinsicExpression(it) => self.visit_v8_intrinsic_expression(it),
+            JSXExpression::DoExpression(it) => self.visit_do_expression(it),
+            JSXExpression::PipelineExpression(it) => self.visit_pipeline_expression(it),
+            JSXExpression::ThrowExpression(it) => self.visit_throw_expression(it),
             JSXExpression::ComputedMemberExpression(it) => {
                 self.visit_computed_member_expression(it)
             }
@@ -1388,6 +1438,7 @@ impl<'a> Visit<'a> for ChildScopeCollector {
                 // `MetaProperty`
                 // `Super`
                 // `ThisExpression`
+                // `TopicReference`
             }
         }
     }
@@ -1587,6 +1587,66 @@ pub trait Traverse<'a, State> {
     ) {
     }
 
+    #[inline]
+    fn enter_do_expression(
+        &mut self,
+        node: &mut DoExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_do_expression(
+        &mut self,
+        node: &mut DoExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_pipeline_expression(
+        &mut self,
+        node: &mut PipelineExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_topic_reference(
+        &mut self,
+        node: &mut TopicReference,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
+    #[inline]
+    fn enter_throw_expression(
+        &mut self,
+        node: &mut ThrowExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+    #[inline]
+    fn exit_throw_expression(
+        &mut self,
+        node: &mut ThrowExpression<'a>,
+        ctx: &mut TraverseCtx<'a, State>,
+    ) {
+    }
+
     #[inline]
     fn enter_boolean_literal(
         &mut self,
@@ -7,6 +7,20 @@ export interface CodegenOptions {
    * @default true
    */
   removeWhitespace?: boolean
+  /**
+   * Break lines longer than this number of bytes, when whitespace is removed.
+   *
+   * Lines are only broken after `,`, `;` and `{`.
+   *
+   * @default undefined (lines are not broken)
+   */
+  maxLineLength?: number
+  /**
+   * Escape all non-ASCII characters, like esbuild's `charset: 'ascii'`.
+   *
+   * @default false
+   */
+  asciiOnly?: boolean
 }
 
 export interface CompressOptions {
@@ -46,6 +60,8 @@ export interface CompressOptions {
   unused?: boolean | 'keep_assign'
   /** Keep function / class names. */
   keepNames?: CompressOptionsKeepNames
+  /** Transforms which are not safe for all code. */
+  unsafe?: CompressOptionsUnsafe
   /**
    * Join consecutive var, let and const statements.
    *
@@ -60,6 +76,24 @@ export interface CompressOptions {
    * @default true
    */
   sequences?: boolean
+  /**
+   * Replace string literals and property names which are repeated many times
+   * with variables declared in the innermost function containing all of them.
+   *
+   * Only done when it is estimated to reduce the size of the output after mangling.
+   *
+   * @default false
+   */
+  hoistLiterals?: boolean
+  /**
+   * Inline calls to functions whose body is a single small expression,
+   * and to functions which are only called once.
+   *
+   * `function get(o) { return o.x } log(get(a))` -> `log(a.x)`
+   *
+   * @default false
+   */
+  inlineFunctions?: boolean
   /**
    * Set of label names to drop from the code.
    *
@@ -93,6 +127,63 @@ export interface CompressOptionsKeepNames {
   class: boolean
 }
 
+export interface CompressOptionsUnsafe {
+  /**
+   * Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+   *
+   * Arrow functions can not be called with `new` and have no `prototype`.
+   *
+   * @default false
+   */
+  arrows?: boolean
+  /**
+   * Invert comparisons in negated conditions, e.g. `!(a < b)` -> `a >= b`.
+   *
+   * Both are `false` if either operand is `NaN`.
+   *
+   * @default false
+   */
+  comps?: boolean
+  /**
+   * Fold numeric expressions which may give slightly different floating point results,
+   * e.g. `a * 2 * 3` -> `a * 6`.
+   *
+   * @default false
+   */
+  math?: boolean
+  /**
+   * Convert function expressions in object literals to methods.
+   *
+   * Methods can not be called with `new` and have no `prototype`.
+   *
+   * @default false
+   */
+  methods?: boolean
+  /**
+   * Assume that reading a property has no side effects, e.g. `a.b.c;` -> `a;`.
+   *
+   * Getters may have side effects, and reading a property of `null` or `undefined` throws.
+   *
+   * @default false
+   */
+  pureGetters?: boolean
+  /**
+   * Replace `void 0` with a variable named `undefined` in scope, which is mangled to a shorter name.
+   *
+   * The variable is assumed to be `undefined`.
+   *
+   * @default false
+   */
+  undefined?: boolean
+}
+
+export interface MangleNameCache {
+  /** Mangled names of top-level symbols, keyed by their original names. */
+  vars?: Record<string, string>
+  /** Mangled names of properties, keyed by their original names. */
+  props?: Record<string, string>
+}
+
 export interface MangleOptions {
   /**
    * Pass `true` to mangle names declared in the top level scope.
@@ -108,6 +199,13 @@ export interface MangleOptions {
   keepNames?: boolean | MangleOptionsKeepNames
   /** Debug mangled names. */
   debug?: boolean
+  /**
+   * Names given by previous builds, to keep the mangled names of top-level symbols stable.
+   *
+   * Pass the `nameCache` of the previous result. The extended cache is returned in
+   * `MinifyResult.nameCache`.
+   */
+  nameCache?: MangleNameCache
 }
 
 export interface MangleOptionsKeepNames {
@@ -145,6 +243,11 @@ export interface MinifyResult {
   code: string
   map?: SourceMap
   errors: Array<OxcError>
+  /**
+   * The mangler name cache extended with the names given in this build.
+   * Only set when `mangle.nameCache` is passed.
+   */
+  nameCache?: MangleNameCache
 }
 
 /** Minify synchronously. */
@@ -200,6 +303,15 @@ export interface TreeShakeOptions {
    * @default true
    */
   invalidImportSideEffects?: boolean
+  /**
+   * Names of the exports which are used by other modules.
+   *
+   * When set, all other exports are removed, and their declarations are dropped if they are
+   * not used by the module itself. `"default"` is the name of the default export.
+   *
+   * @default undefined (all exports are used)
+   */
+  usedExports?: Array<string>
 }
 export interface Comment {
   type: 'Line' | 'Block'
@@ -37,6 +37,9 @@ pub struct MinifyResult {
     pub code: String,
     pub map: Option<SourceMap>,
     pub errors: Vec<OxcError>,
+    /// The mangler name cache extended with the names given in this build.
+    /// Only set when `mangle.nameCache` is passed.
+    pub name_cache: Option<MangleNameCache>,
 }
 
 fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>) -> MinifyResult {
@@ -68,7 +71,8 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
     let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut program = parser_ret.program;
 
-    let scoping = Minifier::new(minifier_options).minify(&allocator, &mut program).scoping;
+    let ret = Minifier::new(minifier_options).minify(&allocator, &mut program);
+    let (scoping, name_cache) = (ret.scoping, ret.name_cache);
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -87,6 +91,7 @@ fn minify_impl(filename: &str, source_text: &str, options: Option<MinifyOptions>
         code: ret.code,
         map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
         errors: OxcError::from_diagnostics(filename, source_text, parser_ret.errors),
+        name_cache: name_cache.map(MangleNameCache::from),
     }
 }
 
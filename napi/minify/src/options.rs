@@ -1,5 +1,6 @@
 use napi::Either;
 use napi_derive::napi;
+use rustc_hash::FxHashMap;
 
 use oxc_compat::EngineTargets;
 
@@ -48,6 +49,14 @@ pub struct TreeShakeOptions {
     ///
     /// @default true
     pub invalid_import_side_effects: Option<bool>,
+
+    /// Names of the exports which are used by other modules.
+    ///
+    /// When set, all other exports are removed, and their declarations are dropped if they are
+    /// not used by the module itself. `"default"` is the name of the default export.
+    ///
+    /// @default undefined (all exports are used)
+    pub used_exports: Option<Vec<String>>,
 }
 
 impl TryFrom<&TreeShakeOptions> for oxc_minifier::TreeShakeOptions {
@@ -81,6 +90,11 @@ impl TryFrom<&TreeShakeOptions> for oxc_minifier::TreeShakeOptions {
             invalid_import_side_effects: o
                 .invalid_import_side_effects
                 .unwrap_or(default.invalid_import_side_effects),
+            used_exports: o
+                .used_exports
+                .as_ref()
+                .map(|names| names.iter().cloned().collect())
+                .or(default.used_exports),
         })
     }
 }
@@ -121,6 +135,10 @@ pub struct CompressOptions {
     /// Keep function / class names.
     pub keep_names: Option<CompressOptionsKeepNames>,
 
+    /// Transforms which are not safe for all code.
+    #[napi(js_name = "unsafe")]
+    pub r#unsafe: Option<CompressOptionsUnsafe>,
+
     /// Join consecutive var, let and const statements.
     ///
     /// @default true
@@ -133,6 +151,22 @@ pub struct CompressOptions {
     /// @default true
     pub sequences: Option<bool>,
 
+    /// Replace string literals and property names which are repeated many times
+    /// with variables declared in the innermost function containing all of them.
+    ///
+    /// Only done when it is estimated to reduce the size of the output after mangling.
+    ///
+    /// @default false
+    pub hoist_literals: Option<bool>,
+
+    /// Inline calls to functions whose body is a single small expression,
+    /// and to functions which are only called once.
+    ///
+    /// `function get(o) { return o.x } log(get(a))` -> `log(a.x)`
+    ///
+    /// @default false
+    pub inline_functions: Option<bool>,
+
     /// Set of label names to drop from the code.
     ///
     /// Labeled statements matching these names will be removed during minification.
@@ -161,6 +195,8 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
             join_vars: o.join_vars.unwrap_or(true),
             sequences: o.sequences.unwrap_or(true),
+            hoist_literals: o.hoist_literals.unwrap_or(default.hoist_literals),
+            inline_functions: o.inline_functions.unwrap_or(default.inline_functions),
             unused: match &o.unused {
                 Some(Either::A(true)) => oxc_minifier::CompressOptionsUnused::Remove,
                 Some(Either::A(false)) => oxc_minifier::CompressOptionsUnused::Keep,
@@ -171,6 +207,7 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 None => default.unused,
             },
             keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
+            r#unsafe: o.r#unsafe.as_ref().map(Into::into).unwrap_or_default(),
             treeshake: match &o.treeshake {
                 Some(ts) => oxc_minifier::TreeShakeOptions::try_from(ts)?,
                 None => oxc_minifier::TreeShakeOptions::default(),
@@ -208,6 +245,63 @@ impl From<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames
     }
 }
 
+#[napi(object)]
+pub struct CompressOptionsUnsafe {
+    /// Convert function expressions which do not use `this`, `arguments` or `new.target` to arrow functions.
+    ///
+    /// Arrow functions can not be called with `new` and have no `prototype`.
+    ///
+    /// @default false
+    pub arrows: Option<bool>,
+
+    /// Invert comparisons in negated conditions, e.g. `!(a < b)` -> `a >= b`.
+    ///
+    /// Both are `false` if either operand is `NaN`.
+    ///
+    /// @default false
+    pub comps: Option<bool>,
+
+    /// Fold numeric expressions which may give slightly different floating point results,
+    /// e.g. `a * 2 * 3` -> `a * 6`.
+    ///
+    /// @default false
+    pub math: Option<bool>,
+
+    /// Convert function expressions in object literals to methods.
+    ///
+    /// Methods can not be called with `new` and have no `prototype`.
+    ///
+    /// @default false
+    pub methods: Option<bool>,
+
+    /// Assume that reading a property has no side effects, e.g. `a.b.c;` -> `a;`.
+    ///
+    /// Getters may have side effects, and reading a property of `null` or `undefined` throws.
+    ///
+    /// @default false
+    pub pure_getters: Option<bool>,
+
+    /// Replace `void 0` with a variable named `undefined` in scope, which is mangled to a shorter name.
+    ///
+    /// The variable is assumed to be `undefined`.
+    ///
+    /// @default false
+    pub undefined: Option<bool>,
+}
+
+impl From<&CompressOptionsUnsafe> for oxc_minifier::CompressOptionsUnsafe {
+    fn from(o: &CompressOptionsUnsafe) -> Self {
+        oxc_minifier::CompressOptionsUnsafe {
+            arrows: o.arrows.unwrap_or(false),
+            comps: o.comps.unwrap_or(false),
+            math: o.math.unwrap_or(false),
+            methods: o.methods.unwrap_or(false),
+            pure_getters: o.pure_getters.unwrap_or(false),
+            undefined: o.undefined.unwrap_or(false),
+        }
+    }
+}
+
 #[napi(object)]
 #[derive(Default)]
 pub struct MangleOptions {
@@ -223,6 +317,12 @@ pub struct MangleOptions {
 
     /// Debug mangled names.
     pub debug: Option<bool>,
+
+    /// Names given by previous builds, to keep the mangled names of top-level symbols stable.
+    ///
+    /// Pass the `nameCache` of the previous result. The extended cache is returned in
+    /// `MinifyResult.nameCache`.
+    pub name_cache: Option<MangleNameCache>,
 }
 
 impl From<&MangleOptions> for oxc_minifier::MangleOptions {
@@ -237,6 +337,36 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
+            properties: None,
+            name_cache: o.name_cache.as_ref().map(oxc_minifier::MangleNameCache::from),
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MangleNameCache {
+    /// Mangled names of top-level symbols, keyed by their original names.
+    pub vars: Option<FxHashMap<String, String>>,
+
+    /// Mangled names of properties, keyed by their original names.
+    pub props: Option<FxHashMap<String, String>>,
+}
+
+impl From<&MangleNameCache> for oxc_minifier::MangleNameCache {
+    fn from(o: &MangleNameCache) -> Self {
+        let into_map = |map: &Option<FxHashMap<String, String>>| {
+            map.iter().flatten().map(|(k, v)| (k.clone(), v.clone())).collect()
+        };
+        Self { vars: into_map(&o.vars), props: into_map(&o.props) }
+    }
+}
+
+impl From<oxc_minifier::MangleNameCache> for MangleNameCache {
+    fn from(o: oxc_minifier::MangleNameCache) -> Self {
+        Self {
+            vars: Some(o.vars.into_iter().collect()),
+            props: Some(o.props.into_iter().collect()),
         }
     }
 }
@@ -266,21 +396,38 @@ pub struct CodegenOptions {
     ///
     /// @default true
     pub remove_whitespace: Option<bool>,
+
+    /// Break lines longer than this number of bytes, when whitespace is removed.
+    ///
+    /// Lines are only broken after `,`, `;` and `{`.
+    ///
+    /// @default undefined (lines are not broken)
+    pub max_line_length: Option<u32>,
+
+    /// Escape all non-ASCII characters, like esbuild's `charset: 'ascii'`.
+    ///
+    /// @default false
+    pub ascii_only: Option<bool>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
-        Self { remove_whitespace: Some(true) }
+        Self { remove_whitespace: Some(true), max_line_length: None, ascii_only: None }
     }
 }
 
 impl From<&CodegenOptions> for oxc_codegen::CodegenOptions {
     fn from(o: &CodegenOptions) -> Self {
-        if o.remove_whitespace.is_some_and(|b| b) {
+        let options = if o.remove_whitespace.is_some_and(|b| b) {
             oxc_codegen::CodegenOptions::minify()
         } else {
             // Need to remove all comments.
             oxc_codegen::CodegenOptions { minify: false, ..oxc_codegen::CodegenOptions::minify() }
+        };
+        oxc_codegen::CodegenOptions {
+            max_line_length: o.max_line_length.map(|n| n as usize),
+            ascii_only: o.ascii_only.unwrap_or_default(),
+            ..options
         }
     }
 }
@@ -48,6 +48,26 @@ describe("simple", () => {
     expect(ret.code).toBe("OTHER:baz();");
     expect(ret.errors.length).toBe(0);
   });
+
+  it("supports maxLineLength option", () => {
+    const code = "foo(a, b, c, d, e, f); bar();";
+    const ret = minifySync("test.js", code, {
+      compress: false,
+      mangle: false,
+      codegen: { maxLineLength: 10 },
+    });
+    expect(ret.code).toBe("foo(a,b,c,d,\ne,f);bar();\n");
+  });
+
+  it("supports asciiOnly option", () => {
+    const code = "console.log('café');";
+    const ret = minifySync("test.js", code, {
+      compress: false,
+      mangle: false,
+      codegen: { asciiOnly: true },
+    });
+    expect(ret.code).toBe("console.log(`caf\\xE9`);");
+  });
 });
 
 describe("treeshake options", () => {
@@ -125,6 +145,60 @@ describe("treeshake options", () => {
     expect(ret.errors.length).toBe(1);
     expect(ret.errors[0].message).toContain("Invalid propertyReadSideEffects value");
   });
+
+  it("removes exports which are not in usedExports", () => {
+    const code = "export function used() { return 1 } export function unused() { return 2 }";
+    const ret = minifySync("test.js", code, {
+      compress: {
+        treeshake: {
+          usedExports: ["used"],
+        },
+      },
+      mangle: false,
+    });
+    expect(ret.code).toContain("used");
+    expect(ret.code).not.toContain("unused");
+    expect(ret.errors.length).toBe(0);
+  });
+});
+
+describe("unsafe options", () => {
+  const code = "x = { m: function () { return !(a < b) } };";
+
+  it("is disabled by default", () => {
+    const ret = minifySync("test.js", code, { mangle: false });
+    expect(ret.code).toBe("x={m:function(){return!(a<b)}};");
+  });
+
+  it("enables unsafe transforms", () => {
+    const ret = minifySync("test.js", code, {
+      compress: { unsafe: { comps: true, methods: true } },
+      mangle: false,
+    });
+    expect(ret.code).toBe("x={m(){return a>=b}};");
+  });
+});
+
+describe("mangle name cache", () => {
+  it("keeps top-level names stable across builds", () => {
+    const first = minifySync("test.js", "function foo() {} function bar() {} foo(bar);", {
+      compress: false,
+      mangle: { toplevel: true, nameCache: {} },
+    });
+    expect(first.nameCache).toEqual({ vars: { foo: "e", bar: "t" }, props: {} });
+
+    const code = "function baz() {} baz(); baz(); function foo() {} function bar() {} foo(bar);";
+    const second = minifySync("test.js", code, {
+      compress: false,
+      mangle: { toplevel: true, nameCache: first.nameCache },
+    });
+    expect(second.nameCache?.vars).toEqual({ foo: "e", bar: "t", baz: "n" });
+  });
+
+  it("does not return a cache by default", () => {
+    const ret = minifySync("test.js", "function foo() {} foo();");
+    expect(ret.nameCache).toBeUndefined();
+  });
 });
 
 describe("async minify", () => {
@@ -151,6 +151,14 @@ function constructExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -527,6 +535,14 @@ function constructArrayExpressionElement(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -799,6 +815,14 @@ function constructPropertyKey(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -1528,6 +1552,14 @@ function constructArgument(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -3371,6 +3403,14 @@ function constructForStatementInit(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6205,6 +6245,14 @@ function constructExportDefaultDeclarationKind(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -6291,6 +6339,190 @@ export class V8IntrinsicExpression {
 
 const DebugV8IntrinsicExpression = class V8IntrinsicExpression {};
 
+export class DoExpression {
+  type = "DoExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructBoxBlockStatement(internal.pos + 16, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "DoExpression",
+      start: this.start,
+      end: this.end,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugDoExpression.prototype);
+  }
+}
+
+const DebugDoExpression = class DoExpression {};
+
+export class PipelineExpression {
+  type = "PipelineExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get left() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 16, internal.ast);
+  }
+
+  get body() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 32, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "PipelineExpression",
+      start: this.start,
+      end: this.end,
+      left: this.left,
+      body: this.body,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugPipelineExpression.prototype);
+  }
+}
+
+const DebugPipelineExpression = class PipelineExpression {};
+
+export class TopicReference {
+  type = "TopicReference";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "TopicReference",
+      start: this.start,
+      end: this.end,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugTopicReference.prototype);
+  }
+}
+
+const DebugTopicReference = class TopicReference {};
+
+export class ThrowExpression {
+  type = "ThrowExpression";
+  #internal;
+
+  constructor(pos, ast) {
+    if (ast?.token !== TOKEN) constructorError();
+
+    const { nodes } = ast;
+    const cached = nodes.get(pos);
+    if (cached !== void 0) return cached;
+
+    this.#internal = { pos, ast };
+    nodes.set(pos, this);
+  }
+
+  get start() {
+    const internal = this.#internal;
+    return constructI32(internal.pos, internal.ast);
+  }
+
+  get end() {
+    const internal = this.#internal;
+    return constructI32(internal.pos + 4, internal.ast);
+  }
+
+  get argument() {
+    const internal = this.#internal;
+    return constructExpression(internal.pos + 16, internal.ast);
+  }
+
+  toJSON() {
+    return {
+      type: "ThrowExpression",
+      start: this.start,
+      end: this.end,
+      argument: this.argument,
+    };
+  }
+
+  [inspectSymbol]() {
+    return Object.setPrototypeOf(this.toJSON(), DebugThrowExpression.prototype);
+  }
+}
+
+const DebugThrowExpression = class ThrowExpression {};
+
 export class BooleanLiteral {
   type = "BooleanLiteral";
   #internal;
@@ -7263,6 +7495,14 @@ function constructJSXExpression(pos, ast) {
       return constructBoxTSInstantiationExpression(pos + 8, ast);
     case 39:
       return constructBoxV8IntrinsicExpression(pos + 8, ast);
+    case 40:
+      return constructBoxDoExpression(pos + 8, ast);
+    case 41:
+      return constructBoxPipelineExpression(pos + 8, ast);
+    case 42:
+      return constructBoxThrowExpression(pos + 8, ast);
+    case 43:
+      return constructBoxTopicReference(pos + 8, ast);
     case 48:
       return constructBoxComputedMemberExpression(pos + 8, ast);
     case 49:
@@ -12852,6 +13092,22 @@ function constructBoxV8IntrinsicExpression(pos, ast) {
   return new V8IntrinsicExpression(ast.buffer.int32[pos >> 2], ast);
 }
 
+function constructBoxDoExpression(pos, ast) {
+  return new DoExpression(ast.buffer.int32[pos >> 2], ast);
+}
+
+function constructBoxPipelineExpression(pos, ast) {
+  return new PipelineExpression(ast.buffer.int32[pos >> 2], ast);
+}
+
+function constructBoxThrowExpression(pos, ast) {
+  return new ThrowExpression(ast.buffer.int32[pos >> 2], ast);
+}
+
+function constructBoxTopicReference(pos, ast) {
+  return new TopicReference(ast.buffer.int32[pos >> 2], ast);
+}
+
 function constructVecArrayExpressionElement(pos, ast) {
   const { int32 } = ast.buffer,
     pos32 = pos >> 2;
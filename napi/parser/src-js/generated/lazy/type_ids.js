@@ -16,176 +16,180 @@ export const NODE_TYPE_IDS_MAP = new Map([
   ["EmptyStatement", 9],
   ["DebuggerStatement", 10],
   ["PrivateIdentifier", 11],
-  ["BooleanLiteral", 12],
-  ["NullLiteral", 13],
-  ["NumericLiteral", 14],
-  ["StringLiteral", 15],
-  ["BigIntLiteral", 16],
-  ["RegExpLiteral", 17],
-  ["JSXOpeningFragment", 18],
-  ["JSXClosingFragment", 19],
-  ["JSXEmptyExpression", 20],
-  ["JSXIdentifier", 21],
-  ["JSXText", 22],
-  ["TSAnyKeyword", 23],
-  ["TSStringKeyword", 24],
-  ["TSBooleanKeyword", 25],
-  ["TSNumberKeyword", 26],
-  ["TSNeverKeyword", 27],
-  ["TSIntrinsicKeyword", 28],
-  ["TSUnknownKeyword", 29],
-  ["TSNullKeyword", 30],
-  ["TSUndefinedKeyword", 31],
-  ["TSVoidKeyword", 32],
-  ["TSSymbolKeyword", 33],
-  ["TSThisType", 34],
-  ["TSObjectKeyword", 35],
-  ["TSBigIntKeyword", 36],
-  ["JSDocUnknownType", 37],
+  ["TopicReference", 12],
+  ["BooleanLiteral", 13],
+  ["NullLiteral", 14],
+  ["NumericLiteral", 15],
+  ["StringLiteral", 16],
+  ["BigIntLiteral", 17],
+  ["RegExpLiteral", 18],
+  ["JSXOpeningFragment", 19],
+  ["JSXClosingFragment", 20],
+  ["JSXEmptyExpression", 21],
+  ["JSXIdentifier", 22],
+  ["JSXText", 23],
+  ["TSAnyKeyword", 24],
+  ["TSStringKeyword", 25],
+  ["TSBooleanKeyword", 26],
+  ["TSNumberKeyword", 27],
+  ["TSNeverKeyword", 28],
+  ["TSIntrinsicKeyword", 29],
+  ["TSUnknownKeyword", 30],
+  ["TSNullKeyword", 31],
+  ["TSUndefinedKeyword", 32],
+  ["TSVoidKeyword", 33],
+  ["TSSymbolKeyword", 34],
+  ["TSThisType", 35],
+  ["TSObjectKeyword", 36],
+  ["TSBigIntKeyword", 37],
+  ["JSDocUnknownType", 38],
   // Non-leaf nodes
-  ["Program", 38],
-  ["ArrayExpression", 39],
-  ["ObjectExpression", 40],
-  ["ObjectProperty", 41],
-  ["TemplateLiteral", 42],
-  ["TaggedTemplateExpression", 43],
-  ["ComputedMemberExpression", 44],
-  ["StaticMemberExpression", 45],
-  ["PrivateFieldExpression", 46],
-  ["CallExpression", 47],
-  ["NewExpression", 48],
-  ["MetaProperty", 49],
-  ["SpreadElement", 50],
-  ["UpdateExpression", 51],
-  ["UnaryExpression", 52],
-  ["BinaryExpression", 53],
-  ["PrivateInExpression", 54],
-  ["LogicalExpression", 55],
-  ["ConditionalExpression", 56],
-  ["AssignmentExpression", 57],
-  ["ArrayAssignmentTarget", 58],
-  ["ObjectAssignmentTarget", 59],
-  ["AssignmentTargetWithDefault", 60],
-  ["AssignmentTargetPropertyIdentifier", 61],
-  ["AssignmentTargetPropertyProperty", 62],
-  ["SequenceExpression", 63],
-  ["AwaitExpression", 64],
-  ["ChainExpression", 65],
-  ["ParenthesizedExpression", 66],
-  ["BlockStatement", 67],
-  ["VariableDeclaration", 68],
-  ["VariableDeclarator", 69],
-  ["ExpressionStatement", 70],
-  ["IfStatement", 71],
-  ["DoWhileStatement", 72],
-  ["WhileStatement", 73],
-  ["ForStatement", 74],
-  ["ForInStatement", 75],
-  ["ForOfStatement", 76],
-  ["ContinueStatement", 77],
-  ["BreakStatement", 78],
-  ["ReturnStatement", 79],
-  ["WithStatement", 80],
-  ["SwitchStatement", 81],
-  ["SwitchCase", 82],
-  ["LabeledStatement", 83],
-  ["ThrowStatement", 84],
-  ["TryStatement", 85],
-  ["CatchClause", 86],
-  ["AssignmentPattern", 87],
-  ["ObjectPattern", 88],
-  ["BindingProperty", 89],
-  ["ArrayPattern", 90],
-  ["Function", 91],
-  ["FormalParameters", 92],
-  ["FunctionBody", 93],
-  ["ArrowFunctionExpression", 94],
-  ["YieldExpression", 95],
-  ["Class", 96],
-  ["ClassBody", 97],
-  ["MethodDefinition", 98],
-  ["PropertyDefinition", 99],
-  ["StaticBlock", 100],
-  ["AccessorProperty", 101],
-  ["ImportExpression", 102],
-  ["ImportDeclaration", 103],
-  ["ImportSpecifier", 104],
-  ["ImportDefaultSpecifier", 105],
-  ["ImportNamespaceSpecifier", 106],
-  ["ImportAttribute", 107],
-  ["ExportNamedDeclaration", 108],
-  ["ExportDefaultDeclaration", 109],
-  ["ExportAllDeclaration", 110],
-  ["ExportSpecifier", 111],
-  ["V8IntrinsicExpression", 112],
-  ["JSXElement", 113],
-  ["JSXOpeningElement", 114],
-  ["JSXClosingElement", 115],
-  ["JSXFragment", 116],
-  ["JSXNamespacedName", 117],
-  ["JSXMemberExpression", 118],
-  ["JSXExpressionContainer", 119],
-  ["JSXAttribute", 120],
-  ["JSXSpreadAttribute", 121],
-  ["JSXSpreadChild", 122],
-  ["TSEnumDeclaration", 123],
-  ["TSEnumBody", 124],
-  ["TSEnumMember", 125],
-  ["TSTypeAnnotation", 126],
-  ["TSLiteralType", 127],
-  ["TSConditionalType", 128],
-  ["TSUnionType", 129],
-  ["TSIntersectionType", 130],
-  ["TSParenthesizedType", 131],
-  ["TSTypeOperator", 132],
-  ["TSArrayType", 133],
-  ["TSIndexedAccessType", 134],
-  ["TSTupleType", 135],
-  ["TSNamedTupleMember", 136],
-  ["TSOptionalType", 137],
-  ["TSRestType", 138],
-  ["TSTypeReference", 139],
-  ["TSQualifiedName", 140],
-  ["TSTypeParameterInstantiation", 141],
-  ["TSTypeParameter", 142],
-  ["TSTypeParameterDeclaration", 143],
-  ["TSTypeAliasDeclaration", 144],
-  ["TSClassImplements", 145],
-  ["TSInterfaceDeclaration", 146],
-  ["TSInterfaceBody", 147],
-  ["TSPropertySignature", 148],
-  ["TSIndexSignature", 149],
-  ["TSCallSignatureDeclaration", 150],
-  ["TSMethodSignature", 151],
-  ["TSConstructSignatureDeclaration", 152],
-  ["TSIndexSignatureName", 153],
-  ["TSInterfaceHeritage", 154],
-  ["TSTypePredicate", 155],
-  ["TSModuleDeclaration", 156],
-  ["TSGlobalDeclaration", 157],
-  ["TSModuleBlock", 158],
-  ["TSTypeLiteral", 159],
-  ["TSInferType", 160],
-  ["TSTypeQuery", 161],
-  ["TSImportType", 162],
-  ["TSImportTypeQualifiedName", 163],
-  ["TSFunctionType", 164],
-  ["TSConstructorType", 165],
-  ["TSMappedType", 166],
-  ["TSTemplateLiteralType", 167],
-  ["TSAsExpression", 168],
-  ["TSSatisfiesExpression", 169],
-  ["TSTypeAssertion", 170],
-  ["TSImportEqualsDeclaration", 171],
-  ["TSExternalModuleReference", 172],
-  ["TSNonNullExpression", 173],
-  ["Decorator", 174],
-  ["TSExportAssignment", 175],
-  ["TSNamespaceExportDeclaration", 176],
-  ["TSInstantiationExpression", 177],
-  ["JSDocNullableType", 178],
-  ["JSDocNonNullableType", 179],
+  ["Program", 39],
+  ["ArrayExpression", 40],
+  ["ObjectExpression", 41],
+  ["ObjectProperty", 42],
+  ["TemplateLiteral", 43],
+  ["TaggedTemplateExpression", 44],
+  ["ComputedMemberExpression", 45],
+  ["StaticMemberExpression", 46],
+  ["PrivateFieldExpression", 47],
+  ["CallExpression", 48],
+  ["NewExpression", 49],
+  ["MetaProperty", 50],
+  ["SpreadElement", 51],
+  ["UpdateExpression", 52],
+  ["UnaryExpression", 53],
+  ["BinaryExpression", 54],
+  ["PrivateInExpression", 55],
+  ["LogicalExpression", 56],
+  ["ConditionalExpression", 57],
+  ["AssignmentExpression", 58],
+  ["ArrayAssignmentTarget", 59],
+  ["ObjectAssignmentTarget", 60],
+  ["AssignmentTargetWithDefault", 61],
+  ["AssignmentTargetPropertyIdentifier", 62],
+  ["AssignmentTargetPropertyProperty", 63],
+  ["SequenceExpression", 64],
+  ["AwaitExpression", 65],
+  ["ChainExpression", 66],
+  ["ParenthesizedExpression", 67],
+  ["BlockStatement", 68],
+  ["VariableDeclaration", 69],
+  ["VariableDeclarator", 70],
+  ["ExpressionStatement", 71],
+  ["IfStatement", 72],
+  ["DoWhileStatement", 73],
+  ["WhileStatement", 74],
+  ["ForStatement", 75],
+  ["ForInStatement", 76],
+  ["ForOfStatement", 77],
+  ["ContinueStatement", 78],
+  ["BreakStatement", 79],
+  ["ReturnStatement", 80],
+  ["WithStatement", 81],
+  ["SwitchStatement", 82],
+  ["SwitchCase", 83],
+  ["LabeledStatement", 84],
+  ["ThrowStatement", 85],
+  ["TryStatement", 86],
+  ["CatchClause", 87],
+  ["AssignmentPattern", 88],
+  ["ObjectPattern", 89],
+  ["BindingProperty", 90],
+  ["ArrayPattern", 91],
+  ["Function", 92],
+  ["FormalParameters", 93],
+  ["FunctionBody", 94],
+  ["ArrowFunctionExpression", 95],
+  ["YieldExpression", 96],
+  ["Class", 97],
+  ["ClassBody", 98],
+  ["MethodDefinition", 99],
+  ["PropertyDefinition", 100],
+  ["StaticBlock", 101],
+  ["AccessorProperty", 102],
+  ["ImportExpression", 103],
+  ["ImportDeclaration", 104],
+  ["ImportSpecifier", 105],
+  ["ImportDefaultSpecifier", 106],
+  ["ImportNamespaceSpecifier", 107],
+  ["ImportAttribute", 108],
+  ["ExportNamedDeclaration", 109],
+  ["ExportDefaultDeclaration", 110],
+  ["ExportAllDeclaration", 111],
+  ["ExportSpecifier", 112],
+  ["V8IntrinsicExpression", 113],
+  ["DoExpression", 114],
+  ["PipelineExpression", 115],
+  ["ThrowExpression", 116],
+  ["JSXElement", 117],
+  ["JSXOpeningElement", 118],
+  ["JSXClosingElement", 119],
+  ["JSXFragment", 120],
+  ["JSXNamespacedName", 121],
+  ["JSXMemberExpression", 122],
+  ["JSXExpressionContainer", 123],
+  ["JSXAttribute", 124],
+  ["JSXSpreadAttribute", 125],
+  ["JSXSpreadChild", 126],
+  ["TSEnumDeclaration", 127],
+  ["TSEnumBody", 128],
+  ["TSEnumMember", 129],
+  ["TSTypeAnnotation", 130],
+  ["TSLiteralType", 131],
+  ["TSConditionalType", 132],
+  ["TSUnionType", 133],
+  ["TSIntersectionType", 134],
+  ["TSParenthesizedType", 135],
+  ["TSTypeOperator", 136],
+  ["TSArrayType", 137],
+  ["TSIndexedAccessType", 138],
+  ["TSTupleType", 139],
+  ["TSNamedTupleMember", 140],
+  ["TSOptionalType", 141],
+  ["TSRestType", 142],
+  ["TSTypeReference", 143],
+  ["TSQualifiedName", 144],
+  ["TSTypeParameterInstantiation", 145],
+  ["TSTypeParameter", 146],
+  ["TSTypeParameterDeclaration", 147],
+  ["TSTypeAliasDeclaration", 148],
+  ["TSClassImplements", 149],
+  ["TSInterfaceDeclaration", 150],
+  ["TSInterfaceBody", 151],
+  ["TSPropertySignature", 152],
+  ["TSIndexSignature", 153],
+  ["TSCallSignatureDeclaration", 154],
+  ["TSMethodSignature", 155],
+  ["TSConstructSignatureDeclaration", 156],
+  ["TSIndexSignatureName", 157],
+  ["TSInterfaceHeritage", 158],
+  ["TSTypePredicate", 159],
+  ["TSModuleDeclaration", 160],
+  ["TSGlobalDeclaration", 161],
+  ["TSModuleBlock", 162],
+  ["TSTypeLiteral", 163],
+  ["TSInferType", 164],
+  ["TSTypeQuery", 165],
+  ["TSImportType", 166],
+  ["TSImportTypeQualifiedName", 167],
+  ["TSFunctionType", 168],
+  ["TSConstructorType", 169],
+  ["TSMappedType", 170],
+  ["TSTemplateLiteralType", 171],
+  ["TSAsExpression", 172],
+  ["TSSatisfiesExpression", 173],
+  ["TSTypeAssertion", 174],
+  ["TSImportEqualsDeclaration", 175],
+  ["TSExternalModuleReference", 176],
+  ["TSNonNullExpression", 177],
+  ["Decorator", 178],
+  ["TSExportAssignment", 179],
+  ["TSNamespaceExportDeclaration", 180],
+  ["TSInstantiationExpression", 181],
+  ["JSDocNullableType", 182],
+  ["JSDocNonNullableType", 183],
 ]);
 
-export const NODE_TYPES_COUNT = 180;
-export const LEAF_NODE_TYPES_COUNT = 38;
+export const NODE_TYPES_COUNT = 184;
+export const LEAF_NODE_TYPES_COUNT = 39;
@@ -154,6 +154,9 @@ export interface OxcParserOptions {
   allowReturnOutsideFunction: boolean
   preserveParens: boolean
   allowV8Intrinsics: boolean
+  allowPipelineOperator: boolean
+  allowDoExpressions: boolean
+  allowThrowExpressions: boolean
   semanticErrors: boolean
 }
 
@@ -222,6 +222,9 @@ impl Oxc {
             allow_return_outside_function: parser_options.allow_return_outside_function,
             preserve_parens: parser_options.preserve_parens,
             allow_v8_intrinsics: parser_options.allow_v8_intrinsics,
+            allow_pipeline_operator: parser_options.allow_pipeline_operator,
+            allow_do_expressions: parser_options.allow_do_expressions,
+            allow_throw_expressions: parser_options.allow_throw_expressions,
         };
         let ParserReturn { program, errors, module_record, .. } =
             Parser::new(allocator, source_text, source_type).with_options(parser_options).parse();
@@ -343,6 +346,8 @@ impl Oxc {
                 top_level: Some(o.top_level),
                 keep_names: MangleOptionsKeepNames { function: o.keep_names, class: o.keep_names },
                 debug: false,
+                properties: None,
+                name_cache: None,
             })
         } else {
             None
@@ -656,11 +661,13 @@ impl Oxc {
             source_map_path: Some(path.to_path_buf()),
             ..CodegenOptions::default()
         };
-        let (scoping, class_private_mappings) =
-            minifier_return.map(|m| (m.scoping, m.class_private_mappings)).unwrap_or_default();
+        let (scoping, class_private_mappings, property_mappings) = minifier_return
+            .map(|m| (m.scoping, m.class_private_mappings, m.property_mappings))
+            .unwrap_or_default();
         let codegen_result = Codegen::new()
             .with_scoping(scoping)
             .with_private_member_mappings(class_private_mappings)
+            .with_property_mappings(property_mappings)
             .with_options(options)
             .build(program);
         self.codegen_text = codegen_result.code;
@@ -45,6 +45,9 @@ pub struct OxcParserOptions {
     pub allow_return_outside_function: bool,
     pub preserve_parens: bool,
     pub allow_v8_intrinsics: bool,
+    pub allow_pipeline_operator: bool,
+    pub allow_do_expressions: bool,
+    pub allow_throw_expressions: bool,
     pub semantic_errors: bool,
 }
 
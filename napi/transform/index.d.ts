@@ -48,6 +48,31 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Report an error instead of moving a loop body into a closure.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+  /**
+   * Report temporal dead zone violations as errors instead of warnings.
+   *
+   * @default false
+   */
+  tdz?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Enables the `noClassCalls`, `setClassMethods`, `constantSuper` and
+   * `superIsCallableConstructor` assumptions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
@@ -108,6 +133,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the TC39 Stage 3 decorators transform (2023-05 version of the proposal).
+   *
+   * This is the decorators implementation TypeScript 5 uses when `experimentalDecorators` is not set.
+   * It has no effect when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -120,9 +155,55 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Enables the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform classes into constructor functions. */
+  classes?: ClassesOptions
+  /** Transform destructuring patterns into property and element accesses. */
+  destructuring?: DestructuringOptions
+  /** Transform default and rest parameters. */
+  parameters?: ParametersOptions
+  /** Transform `let` and `const` declarations into `var` declarations. */
+  blockScoping?: BlockScopingOptions
+  /** Transform generator functions into state machines. */
+  regenerator?: boolean
+  /** Transform template literals into string concatenations. */
+  templateLiterals?: TemplateLiteralsOptions
+  /** Transform spread elements in arrays, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform `for...of` loops into indexed or iterator loops. */
+  forOf?: ForOfOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Enables the `skipForOfIteratorClosing` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Enables the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Enables the `arrayLikeIsIterable` assumption.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -312,6 +393,15 @@ export interface ModuleRunnerTransformResult {
 /** @deprecated Only works for Vite. */
 export declare function moduleRunnerTransformSync(filename: string, sourceText: string, options?: ModuleRunnerTransformOptions | undefined | null): ModuleRunnerTransformResult
 
+export interface ParametersOptions {
+  /**
+   * Enables the `ignoreFunctionLength` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface PluginsOptions {
   styledComponents?: StyledComponentsOptions
   taggedTemplateEscape?: boolean
@@ -333,6 +423,21 @@ export interface ReactRefreshOptions {
   emitFullSignatures?: boolean
 }
 
+export interface SpreadOptions {
+  /**
+   * Enables the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Enables the `arrayLikeIsIterable` assumption.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
 /**
  * Configure how styled-components are transformed.
  *
@@ -412,6 +517,15 @@ export interface StyledComponentsOptions {
   topLevelImportPaths?: Array<string>
 }
 
+export interface TemplateLiteralsOptions {
+  /**
+   * Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 /**
  * Transpile a JavaScript or TypeScript into a target ECMAScript version, asynchronously.
  *
@@ -396,6 +396,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the TC39 Stage 3 decorators transform (2023-05 version of the proposal).
+    ///
+    /// This is the decorators implementation TypeScript 5 uses when `experimentalDecorators` is not set.
+    /// It has no effect when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -410,6 +419,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -656,15 +666,166 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Report an error instead of moving a loop body into a closure.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+    /// Report temporal dead zone violations as errors instead of warnings.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+            tdz: options.tdz.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Enables the `noClassCalls`, `setClassMethods`, `constantSuper` and
+    /// `superIsCallableConstructor` assumptions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc::transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc::transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Enables the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Enables the `ignoreFunctionLength` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Enables the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Enables the `arrayLikeIsIterable` assumption.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Enables the `skipForOfIteratorClosing` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Enables the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+    /// Enables the `arrayLikeIsIterable` assumption.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform classes into constructor functions.
+    pub classes: Option<ClassesOptions>,
+    /// Transform destructuring patterns into property and element accesses.
+    pub destructuring: Option<DestructuringOptions>,
+    /// Transform default and rest parameters.
+    pub parameters: Option<ParametersOptions>,
+    /// Transform `let` and `const` declarations into `var` declarations.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform generator functions into state machines.
+    pub regenerator: Option<bool>,
+    /// Transform template literals into string concatenations.
+    pub template_literals: Option<TemplateLiteralsOptions>,
+    /// Transform spread elements in arrays, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+    /// Transform `for...of` loops into indexed or iterator loops.
+    pub for_of: Option<ForOfOptions>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            classes: options.classes.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            parameters: options.parameters.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            regenerator: options.regenerator.unwrap_or_default(),
+            template_literals: options.template_literals.map(Into::into),
+            spread: options.spread.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+        }
     }
 }
 
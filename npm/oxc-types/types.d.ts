@@ -50,6 +50,10 @@ export type Expression =
   | TSNonNullExpression
   | TSInstantiationExpression
   | V8IntrinsicExpression
+  | DoExpression
+  | PipelineExpression
+  | ThrowExpression
+  | TopicReference
   | MemberExpression;
 
 export interface IdentifierName extends Span {
@@ -918,6 +922,30 @@ export interface V8IntrinsicExpression extends Span {
   parent?: Node;
 }
 
+export interface DoExpression extends Span {
+  type: "DoExpression";
+  body: BlockStatement;
+  parent?: Node;
+}
+
+export interface PipelineExpression extends Span {
+  type: "PipelineExpression";
+  left: Expression;
+  body: Expression;
+  parent?: Node;
+}
+
+export interface TopicReference extends Span {
+  type: "TopicReference";
+  parent?: Node;
+}
+
+export interface ThrowExpression extends Span {
+  type: "ThrowExpression";
+  argument: Expression;
+  parent?: Node;
+}
+
 export interface BooleanLiteral extends Span {
   type: "Literal";
   value: boolean;
@@ -1815,6 +1843,10 @@ export type Node =
   | ExportAllDeclaration
   | ExportSpecifier
   | V8IntrinsicExpression
+  | DoExpression
+  | PipelineExpression
+  | TopicReference
+  | ThrowExpression
   | BooleanLiteral
   | NullLiteral
   | NumericLiteral
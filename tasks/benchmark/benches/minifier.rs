@@ -90,6 +90,8 @@ fn bench_mangler(criterion: &mut Criterion) {
                             top_level: None,
                             keep_names: MangleOptionsKeepNames::all_true(),
                             debug: false,
+                            properties: None,
+                            name_cache: None,
                         })
                         .build_with_semantic(&mut semantic, &program);
                 });
@@ -36,16 +36,25 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-regenerator",
+    "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-spread",
+    "babel-plugin-transform-for-of",
     // "babel-plugin-transform-function-name",
     // "babel-plugin-transform-shorthand-properties",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
     // // ES3
     // "babel-plugin-transform-property-literals",
+    // Modules
+    "babel-plugin-transform-modules-commonjs",
     // TypeScript
     "babel-preset-typescript",
     "babel-plugin-transform-typescript",
@@ -56,8 +65,11 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
+    // Proposal
+    "babel-plugin-proposal-decorators",
+    "babel-plugin-proposal-do-expressions",
+    "babel-plugin-proposal-pipeline-operator",
+    "babel-plugin-proposal-throw-expressions",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
     // Legacy decorators, tests almost ported from TypeScript
@@ -67,15 +79,8 @@ pub const PLUGINS: &[&str] = &[
     "plugin-tagged-template-transform",
 ];
 
-pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
-    "transform-modules-commonjs",
-    "transform-parameters",
-    "transform-property-literals",
-    "transform-react-constant-elements",
-];
+pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] =
+    &["transform-property-literals", "transform-react-constant-elements"];
 
 pub const SKIP_TESTS: &[&str] = &[
     // Shouldn't report in transformer
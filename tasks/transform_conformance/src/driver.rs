@@ -24,6 +24,9 @@ impl CompilerInterface for Driver {
     fn parse_options(&self) -> ParseOptions {
         ParseOptions {
             allow_return_outside_function: self.allow_return_outside_function,
+            allow_pipeline_operator: self.options.proposals.pipeline_operator,
+            allow_do_expressions: self.options.proposals.do_expressions,
+            allow_throw_expressions: self.options.proposals.throw_expressions,
             ..Default::default()
         }
     }
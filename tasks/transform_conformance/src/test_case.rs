@@ -177,6 +177,11 @@ impl TestCase {
             return true;
         }
 
+        // Only the 2023-05 version of decorators is supported
+        if options.plugins.proposal_decorators.as_ref().is_some_and(|o| o.version != "2023-05") {
+            return true;
+        }
+
         // Skip some Babel tests.
         if let Ok(path) = self.path.strip_prefix(packages_root()) {
             // babel skip test cases that in a directory starting with a dot
@@ -209,7 +214,18 @@ impl TestCase {
             && let Ok(source) = fs::read_to_string(&self.path)
         {
             let allocator = Allocator::default();
-            let ret = Parser::new(&allocator, &source, self.source_type).parse();
+            // Enable the same proposal syntax as `Driver`, so a test that expects a transform error
+            // is not mistaken for a parser error test.
+            let proposals = self.transform_options.as_ref().ok().map(|o| &o.proposals);
+            let parse_options = ParseOptions {
+                allow_pipeline_operator: proposals.is_some_and(|p| p.pipeline_operator),
+                allow_do_expressions: proposals.is_some_and(|p| p.do_expressions),
+                allow_throw_expressions: proposals.is_some_and(|p| p.throw_expressions),
+                ..ParseOptions::default()
+            };
+            let ret = Parser::new(&allocator, &source, self.source_type)
+                .with_options(parse_options)
+                .parse();
             if !ret.errors.is_empty() {
                 return true;
             }
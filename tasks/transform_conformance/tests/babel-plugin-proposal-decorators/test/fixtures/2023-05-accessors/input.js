@@ -0,0 +1,7 @@
+class Foo {
+  accessor a = 1;
+  @dec accessor b;
+  @dec static accessor c = 2;
+  @dec accessor #d = 3;
+  static accessor [key()] = 4;
+}
@@ -0,0 +1,64 @@
+let _dec, _init_b, _dec2, _init_c, _dec3, _init_d, _get_d, _set_d, _computedKey, _initProto, _initStatic;
+_dec = dec;
+_dec2 = dec;
+_dec3 = dec;
+_computedKey = key();
+class Foo {
+  static {
+    [_init_c, _init_b, _init_d, _get_d, _set_d, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        _dec2,
+        9,
+        "c"
+      ],
+      [
+        _dec,
+        1,
+        "b"
+      ],
+      [
+        _dec3,
+        1,
+        "d",
+        (_) => _.#D,
+        (_, value) => _.#D = value
+      ]
+    ], [], 0, (_) => #d in _).e;
+    _initStatic(this);
+  }
+  #A = (_initProto(this), 1);
+  get a() {
+    return this.#A;
+  }
+  set a(value) {
+    this.#A = value;
+  }
+  #B = _init_b(this);
+  get b() {
+    return this.#B;
+  }
+  set b(value) {
+    this.#B = value;
+  }
+  static #C = _init_c(this, 2);
+  static get c() {
+    return this.#C;
+  }
+  static set c(value) {
+    this.#C = value;
+  }
+  #D = _init_d(this, 3);
+  get #d() {
+    return _get_d(this);
+  }
+  set #d(value) {
+    _set_d(this, value);
+  }
+  static #E = 4;
+  static get [_computedKey]() {
+    return this.#E;
+  }
+  static set [_computedKey](value) {
+    this.#E = value;
+  }
+}
@@ -0,0 +1,5 @@
+const Foo = @dec class Bar {
+  @dec method() {
+    return Bar;
+  }
+};
@@ -0,0 +1,19 @@
+let _dec, _dec2, _initProto, _Bar, _initClass;
+const Foo = (_dec = dec, _dec2 = dec, class Bar {
+  static {
+    ({e: [_initProto], c: [_Bar, _initClass]} = babelHelpers.applyDecs2305(this, [[
+      _dec2,
+      2,
+      "method"
+    ]], [_dec]));
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {
+    return _Bar;
+  }
+  static {
+    _initClass();
+  }
+}, _Bar);
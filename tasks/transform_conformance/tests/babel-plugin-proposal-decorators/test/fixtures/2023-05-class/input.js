@@ -0,0 +1,9 @@
+@dec
+@obj.dec
+class Foo {
+  static create() {
+    return new Foo();
+  }
+}
+
+new Foo();
@@ -0,0 +1,21 @@
+let _dec, _obj, _dec2, _Foo, _initClass;
+_dec = dec;
+_obj = obj;
+_dec2 = _obj.dec;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2305(this, [], [
+      void 0,
+      _dec,
+      _obj,
+      _dec2
+    ], 1).c;
+  }
+  static create() {
+    return new _Foo();
+  }
+  static {
+    _initClass();
+  }
+}
+new _Foo();
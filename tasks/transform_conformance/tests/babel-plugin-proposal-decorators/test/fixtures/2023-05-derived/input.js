@@ -0,0 +1,15 @@
+class Foo extends Bar {
+  @dec method() {}
+
+  constructor() {
+    if (cond) {
+      super(1);
+    } else {
+      super(2);
+    }
+  }
+}
+
+class Baz extends getBase() {
+  @dec x = 1;
+}
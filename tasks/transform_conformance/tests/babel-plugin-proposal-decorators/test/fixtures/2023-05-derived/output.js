@@ -0,0 +1,30 @@
+let _dec, _initProto, _Bar, _dec2, _init_x, _initProto2, _getBase;
+_dec = dec;
+class Foo extends (_Bar = Bar) {
+  static {
+    [_initProto] = babelHelpers.applyDecs2305(this, [[
+      _dec,
+      2,
+      "method"
+    ]], [], 0, void 0, _Bar).e;
+  }
+  method() {}
+  constructor() {
+    if (cond) {
+      _initProto(super(1));
+    } else {
+      _initProto(super(2));
+    }
+  }
+}
+_dec2 = dec;
+class Baz extends (_getBase = getBase()) {
+  static {
+    [_init_x, _initProto2] = babelHelpers.applyDecs2305(this, [[
+      _dec2,
+      0,
+      "x"
+    ]], [], 0, void 0, _getBase).e;
+  }
+  x = (_initProto2(this), _init_x(this, 1));
+}
@@ -0,0 +1,30 @@
+let _dec, _Foo, _initClass, _dec2, _dec3, _initProto, _Bar, _initClass2;
+_dec = dec;
+class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2305(this, [], [_dec]).c;
+  }
+  static {
+    _initClass();
+  }
+}
+export { _Foo as Foo };
+_dec2 = dec;
+_dec3 = dec;
+class Bar {
+  static {
+    ({e: [_initProto], c: [_Bar, _initClass2]} = babelHelpers.applyDecs2305(this, [[
+      _dec3,
+      2,
+      "method"
+    ]], [_dec2]));
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {}
+  static {
+    _initClass2();
+  }
+}
+export { _Bar as default };
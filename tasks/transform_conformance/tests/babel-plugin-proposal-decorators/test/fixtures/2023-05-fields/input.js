@@ -0,0 +1,7 @@
+class Foo {
+  @dec a;
+  @dec b = 1;
+  @dec static c = 2;
+  @dec() d = this.b;
+  e = 3;
+}
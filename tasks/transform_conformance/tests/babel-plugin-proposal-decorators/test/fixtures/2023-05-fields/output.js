@@ -0,0 +1,37 @@
+let _dec, _init_a, _dec2, _init_b, _dec3, _init_c, _dec4, _init_d, _initProto, _initStatic;
+_dec = dec;
+_dec2 = dec;
+_dec3 = dec;
+_dec4 = dec();
+class Foo {
+  static {
+    [_init_c, _init_a, _init_b, _init_d, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        _dec3,
+        8,
+        "c"
+      ],
+      [
+        _dec,
+        0,
+        "a"
+      ],
+      [
+        _dec2,
+        0,
+        "b"
+      ],
+      [
+        _dec4,
+        0,
+        "d"
+      ]
+    ], []).e;
+    _initStatic(this);
+  }
+  a = (_initProto(this), _init_a(this));
+  b = _init_b(this, 1);
+  static c = _init_c(this, 2);
+  d = _init_d(this, this.b);
+  e = 3;
+}
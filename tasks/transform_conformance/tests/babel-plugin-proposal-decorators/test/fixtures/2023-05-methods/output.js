@@ -0,0 +1,57 @@
+let _dec, _dec2, _dec3, _dec4, _computedKey, _dec5, _obj, _dec6, _initProto, _initStatic;
+_dec = dec;
+_dec2 = dec;
+_dec3 = dec;
+_dec4 = dec;
+_computedKey = computed();
+_dec5 = dec;
+_obj = obj;
+_dec6 = _obj.dec;
+class Foo {
+  static {
+    [_initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        _dec4,
+        10,
+        "staticMethod"
+      ],
+      [
+        _dec,
+        2,
+        "method"
+      ],
+      [
+        _dec2,
+        3,
+        "getter"
+      ],
+      [
+        _dec3,
+        4,
+        "setter"
+      ],
+      [
+        _dec5,
+        2,
+        _computedKey
+      ],
+      [
+        [_obj, _dec6],
+        18,
+        "withThis"
+      ]
+    ], []).e;
+    _initStatic(this);
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {}
+  get getter() {
+    return 1;
+  }
+  set setter(v) {}
+  static staticMethod() {}
+  [_computedKey]() {}
+  withThis() {}
+}
@@ -0,0 +1,8 @@
+class Foo {
+  @dec #a = 1;
+  @dec #method() {}
+  @dec get #getter() {
+    return this.#a;
+  }
+  @dec static set #setter(v) {}
+}
@@ -0,0 +1,49 @@
+let _dec, _init_a, _dec2, _call_method, _dec3, _call_getter, _dec4, _call_setter, _initProto, _initStatic;
+_dec = dec;
+_dec2 = dec;
+_dec3 = dec;
+_dec4 = dec;
+class Foo {
+  static {
+    [_call_setter, _call_method, _call_getter, _init_a, _initProto, _initStatic] = babelHelpers.applyDecs2305(this, [
+      [
+        _dec4,
+        12,
+        "setter",
+        function(v) {}
+      ],
+      [
+        _dec2,
+        2,
+        "method",
+        function() {}
+      ],
+      [
+        _dec3,
+        3,
+        "getter",
+        function() {
+          return this.#a;
+        }
+      ],
+      [
+        _dec,
+        0,
+        "a",
+        (_) => _.#a,
+        (_, value) => _.#a = value
+      ]
+    ], [], 0, (_) => #method in _).e;
+    _initStatic(this);
+  }
+  #a = (_initProto(this), _init_a(this, 1));
+  get #method() {
+    return _call_method;
+  }
+  get #getter() {
+    return _call_getter(this);
+  }
+  static set #setter(value) {
+    _call_setter(this, value);
+  }
+}
@@ -0,0 +1,8 @@
+async function f() {
+  let a = do {
+    if (x) {
+      await x;
+    }
+  };
+  let b = do { await y };
+}
@@ -0,0 +1,8 @@
+async function f() {
+  let a = await (async () => {
+    if (x) {
+      return await x;
+    }
+  })();
+  let b = await y;
+}
@@ -0,0 +1,4 @@
+let area = do {
+  let tmp = getSize();
+  tmp.width * tmp.height;
+};
@@ -0,0 +1,4 @@
+let area = (() => {
+  let tmp = getSize();
+  return tmp.width * tmp.height;
+})();
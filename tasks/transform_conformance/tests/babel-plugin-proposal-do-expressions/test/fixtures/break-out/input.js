@@ -0,0 +1,8 @@
+for (;;) {
+  x += do {
+    if (c) {
+      break;
+    }
+    1;
+  };
+}
@@ -0,0 +1,3 @@
+let a = do { 1; {} };
+let b = do { 1; ; let x = 2; function f() {} { {} } };
+let c = do { 1; if (x) {} };
@@ -0,0 +1,17 @@
+let a = (() => {
+  return 1;
+  {}
+})();
+let b = (() => {
+  return 1;
+  ;
+  let x = 2;
+  function f() {}
+  {
+    {}
+  }
+})();
+let c = (() => {
+  1;
+  if (x) {}
+})();
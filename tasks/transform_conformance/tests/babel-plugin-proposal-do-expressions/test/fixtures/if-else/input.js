@@ -0,0 +1,9 @@
+let size = do {
+  if (x > 10) {
+    "big";
+  } else if (x > 5) {
+    "medium";
+  } else {
+    "small";
+  }
+};
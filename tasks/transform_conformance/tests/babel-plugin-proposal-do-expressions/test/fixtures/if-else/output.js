@@ -0,0 +1,9 @@
+let size = (() => {
+  if (x > 10) {
+    return "big";
+  } else if (x > 5) {
+    return "medium";
+  } else {
+    return "small";
+  }
+})();
@@ -0,0 +1,8 @@
+let a = (() => {
+  outer: for (const x of y) {
+    for (const z of x) {
+      if (z) continue outer;
+      if (!z) break outer;
+    }
+  }
+})();
@@ -0,0 +1,4 @@
+let a = do { l: { 1; break l; } };
+let b = do { l: { if (x) { 1; break l; } 2; } };
+let c = do { outer: for (const y of z) { 1; break outer; } };
+let d = do { l: { 1; break l; } 2; };
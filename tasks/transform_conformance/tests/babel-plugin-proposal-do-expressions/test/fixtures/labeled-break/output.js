@@ -0,0 +1,30 @@
+let a = (() => {
+  l: {
+    return 1;
+    break l;
+  }
+})();
+let b = (() => {
+  l: {
+    if (x) {
+      return 1;
+      break l;
+    }
+    return 2;
+  }
+})();
+let c = (() => {
+  var _ret;
+  outer: for (const y of z) {
+    _ret = 1;
+    break outer;
+  }
+  return _ret;
+})();
+let d = (() => {
+  l: {
+    1;
+    break l;
+  }
+  return 2;
+})();
@@ -0,0 +1,18 @@
+let a = do {
+  for (const x of y) {
+    if (x) {
+      x;
+      break;
+    }
+    x + 1;
+  }
+};
+let b = do {
+  if (c) {
+    "c";
+  } else {
+    while (d()) {
+      "d";
+    }
+  }
+};
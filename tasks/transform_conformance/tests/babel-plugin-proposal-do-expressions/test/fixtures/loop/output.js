@@ -0,0 +1,22 @@
+let a = (() => {
+  var _ret;
+  for (const x of y) {
+    if (x) {
+      _ret = x;
+      break;
+    }
+    _ret = x + 1;
+  }
+  return _ret;
+})();
+let b = (() => {
+  var _ret2;
+  if (c) {
+    return "c";
+  } else {
+    while (d()) {
+      _ret2 = "d";
+    }
+  }
+  return _ret2;
+})();
@@ -0,0 +1,9 @@
+function g() {
+  var x = do {
+    if (c) {
+      return "early";
+    }
+    1;
+  };
+  return "late";
+}
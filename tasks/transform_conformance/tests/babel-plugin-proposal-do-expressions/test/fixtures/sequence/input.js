@@ -0,0 +1,3 @@
+let a = do { foo(); bar() };
+let b = do { baz };
+let c = do {};
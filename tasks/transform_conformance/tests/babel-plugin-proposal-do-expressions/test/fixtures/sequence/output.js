@@ -0,0 +1,3 @@
+let a = (foo(), bar());
+let b = baz;
+let c = void 0;
@@ -0,0 +1,19 @@
+let a = do {
+  switch (x) {
+    case 1:
+      if (y) {
+        "one-y";
+        break;
+      }
+      "one";
+      break;
+    case 2:
+      "two";
+    case 3:
+      "three";
+      break;
+    case 4:
+      "four";
+    default:
+  }
+};
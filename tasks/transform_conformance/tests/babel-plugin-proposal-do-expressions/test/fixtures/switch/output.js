@@ -0,0 +1,19 @@
+let a = (() => {
+  var _ret;
+  switch (x) {
+    case 1:
+      if (y) {
+        return "one-y";
+        break;
+      }
+      return "one";
+      break;
+    case 2: _ret = "two";
+    case 3:
+      return "three";
+      break;
+    case 4: _ret = "four";
+    default:
+  }
+  return _ret;
+})();
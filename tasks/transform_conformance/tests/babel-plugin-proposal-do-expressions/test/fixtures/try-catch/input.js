@@ -0,0 +1,7 @@
+let data = do {
+  try {
+    JSON.parse(text);
+  } catch {
+    null;
+  }
+};
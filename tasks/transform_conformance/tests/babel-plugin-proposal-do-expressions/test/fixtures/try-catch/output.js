@@ -0,0 +1,7 @@
+let data = (() => {
+  try {
+    return JSON.parse(text);
+  } catch {
+    return null;
+  }
+})();
@@ -0,0 +1,4 @@
+let x = do {
+  var y = 1;
+  y;
+};
@@ -0,0 +1,6 @@
+function* g() {
+  let x = do {
+    let y = yield;
+    y;
+  };
+}
@@ -0,0 +1 @@
+const fns = list |> %.map((item) => item + %.length);
@@ -0,0 +1 @@
+const fns = ((_ref) => _ref.map((item) => item + _ref.length))(list);
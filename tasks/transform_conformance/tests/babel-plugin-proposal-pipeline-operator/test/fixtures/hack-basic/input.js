@@ -0,0 +1,5 @@
+const result = value |> double(%) |> % + 1;
+
+function run(x) {
+  return x |> f(%, %);
+}
@@ -0,0 +1,6 @@
+var _ref, _ref2;
+const result = (_ref = value, _ref2 = double(_ref), _ref2 + 1);
+function run(x) {
+  var _ref3;
+  return _ref3 = x, f(_ref3, _ref3);
+}
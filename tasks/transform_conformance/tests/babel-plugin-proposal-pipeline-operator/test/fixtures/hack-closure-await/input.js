@@ -0,0 +1,3 @@
+async function f(x) {
+  return x |> (await %) + (() => %);
+}
@@ -0,0 +1,3 @@
+async function f(x) {
+  return await (async (_ref) => await _ref + (() => _ref))(x);
+}
@@ -0,0 +1,3 @@
+const fns = [];
+for (var i = 0; i < 2; i++) fns.push(i |> (() => %));
+const values = [1, 2] |> %.map(function () { return %.length; }) |> % + 1;
@@ -0,0 +1,8 @@
+const fns = [];
+for (var i = 0; i < 2; i++) fns.push(((_ref) => (() => _ref))(i));
+const values = ((_ref2) => {
+  var _ref3;
+  return _ref3 = _ref2.map(function() {
+    return _ref2.length;
+  }), _ref3 + 1;
+})([1, 2]);
@@ -0,0 +1,2 @@
+const result = value |> (% |> [%, %]);
+const member = obj |> %.method(%.prop);
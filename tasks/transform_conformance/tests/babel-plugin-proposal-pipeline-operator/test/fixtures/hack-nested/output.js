@@ -0,0 +1,3 @@
+var _ref, _ref2, _ref3;
+const result = (_ref = value, _ref2 = _ref, [_ref2, _ref2]);
+const member = (_ref3 = obj, _ref3.method(_ref3.prop));
@@ -0,0 +1,5 @@
+function save(filename = throw new TypeError("Argument required")) {}
+
+const value = input ?? throw new Error("missing input");
+
+const fail = () => throw "failed";
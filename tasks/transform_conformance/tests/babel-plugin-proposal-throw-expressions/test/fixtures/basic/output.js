@@ -0,0 +1,9 @@
+function save(filename = (function(e) {
+  throw e;
+})(new TypeError("Argument required"))) {}
+const value = input ?? (function(e) {
+  throw e;
+})(new Error("missing input"));
+const fail = () => (function(e) {
+  throw e;
+})("failed");
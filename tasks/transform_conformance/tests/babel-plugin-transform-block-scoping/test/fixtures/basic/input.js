@@ -0,0 +1,27 @@
+let a = 1;
+const b = 2;
+{
+  let a = 3;
+  const c = a + b;
+  console.log(a, c);
+}
+function f(x) {
+  {
+    let x = 1;
+    let y = 2;
+    console.log(x, y);
+  }
+  {
+    let y = 3;
+    console.log(y);
+  }
+  return x;
+}
+switch (a) {
+  case 1:
+    let d = 1;
+    console.log(d);
+    break;
+  default:
+    const e = d;
+}
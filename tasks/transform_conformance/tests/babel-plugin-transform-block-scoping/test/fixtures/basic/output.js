@@ -0,0 +1,26 @@
+var a = 1;
+var b = 2;
+{
+  var _a = 3;
+  var c = _a + b;
+  console.log(_a, c);
+}
+function f(x) {
+  {
+    var _x = 1;
+    var y = 2;
+    console.log(_x, y);
+  }
+  {
+    var _y = 3;
+    console.log(_y);
+  }
+  return x;
+}
+switch (a) {
+  case 1:
+    var d = 1;
+    console.log(d);
+    break;
+  default: var e = d;
+}
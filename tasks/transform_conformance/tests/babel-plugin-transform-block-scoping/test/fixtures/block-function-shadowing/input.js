@@ -0,0 +1,20 @@
+let f = 1;
+{
+  function f() {}
+  f();
+}
+console.log(typeof f);
+
+var g = 1;
+{
+  function g() {}
+}
+console.log(typeof g);
+
+function outer() {
+  const h = 1;
+  {
+    function h() {}
+  }
+  return typeof h;
+}
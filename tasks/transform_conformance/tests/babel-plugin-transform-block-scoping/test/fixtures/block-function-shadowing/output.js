@@ -0,0 +1,18 @@
+var f = 1;
+{
+  function _f() {}
+  _f();
+}
+console.log(typeof f);
+var g = 1;
+{
+  function g() {}
+}
+console.log(typeof g);
+function outer() {
+  var h = 1;
+  {
+    function _h() {}
+  }
+  return typeof h;
+}
@@ -0,0 +1,16 @@
+const a = 1;
+a = 2;
+const b = 1;
+b += 2;
+const c = 1;
+c ||= 2;
+const d = 1;
+d++;
+{
+  const a = 3;
+  a = 4;
+}
+function f() {
+  e = 5;
+}
+const e = 6;
@@ -0,0 +1,16 @@
+var a = 1;
+2, babelHelpers.readOnlyError("a");
+var b = 1;
+b + 2, babelHelpers.readOnlyError("b");
+var c = 1;
+c || (2, babelHelpers.readOnlyError("c"));
+var d = 1;
++d, babelHelpers.readOnlyError("d");
+{
+  var _a = 3;
+  4, babelHelpers.readOnlyError("_a");
+}
+function f() {
+  5, babelHelpers.readOnlyError("e");
+}
+var e = 6;
@@ -0,0 +1,16 @@
+function f() {
+  outer: for (let i = 0; i < 10; i++) {
+    for (let j = 0; j < 10; j++) {
+      fns.push(() => i + j);
+      if (j === 1) continue;
+      if (j === 2) break;
+      if (j === 3) continue outer;
+      if (j === 4) break outer;
+      if (j === 5) return j;
+    }
+    switch (i) {
+      case 0:
+        break;
+    }
+  }
+}
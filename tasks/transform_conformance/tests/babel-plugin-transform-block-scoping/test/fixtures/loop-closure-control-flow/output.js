@@ -0,0 +1,27 @@
+function f() {
+  var _loop2 = function(i) {
+    var _loop = function(j) {
+      fns.push(() => i + j);
+      if (j === 1) return;
+      if (j === 2) return "break";
+      if (j === 3) return "continue|outer";
+      if (j === 4) return "break|outer";
+      if (j === 5) return { v: j };
+    };
+    for (var j = 0; j < 10; j++) {
+      var _ret = _loop(j);
+      if (_ret === "break") break;
+      if (_ret === "continue|outer") return;
+      if (_ret === "break|outer") return "break";
+      if (typeof _ret === "object") return { v: _ret.v };
+    }
+    switch (i) {
+      case 0: break;
+    }
+  };
+  outer: for (var i = 0; i < 10; i++) {
+    var _ret2 = _loop2(i);
+    if (_ret2 === "break") break;
+    if (typeof _ret2 === "object") return _ret2.v;
+  }
+}
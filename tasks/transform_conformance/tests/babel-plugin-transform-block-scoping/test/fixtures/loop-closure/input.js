@@ -0,0 +1,19 @@
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (const key in obj) fns.push(() => key);
+for (const [x, y] of pairs) {
+  fns.push(() => x + y);
+}
+while (cond()) {
+  const value = next();
+  fns.push(function () {
+    return value;
+  });
+}
+function f() {
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => this[i] + arguments[i]);
+    i++;
+  }
+}
@@ -0,0 +1,37 @@
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(key) {
+  fns.push(() => key);
+};
+for (var key in obj) {
+  _loop2(key);
+}
+var _loop3 = function(x, y) {
+  fns.push(() => x + y);
+};
+for (var [x, y] of pairs) {
+  _loop3(x, y);
+}
+var _loop4 = function() {
+  var value = next();
+  fns.push(function() {
+    return value;
+  });
+};
+while (cond()) {
+  _loop4();
+}
+function f() {
+  var _loop5 = function(_i2, _arguments) {
+    fns.push(() => this[_i2] + _arguments[_i2]);
+    _i2++;
+    _i = _i2;
+  };
+  for (var _i = 0; _i < 3; _i++) {
+    _loop5.call(this, _i, arguments);
+  }
+}
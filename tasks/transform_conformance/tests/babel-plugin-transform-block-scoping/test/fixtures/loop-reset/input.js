@@ -0,0 +1,8 @@
+for (let i = 0; i < 3; i++) {
+  let x;
+  const y = i;
+  if (!x) x = y;
+}
+for (let key in obj) {
+  let value;
+}
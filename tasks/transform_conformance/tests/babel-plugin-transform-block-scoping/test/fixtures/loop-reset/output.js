@@ -0,0 +1,8 @@
+for (var i = 0; i < 3; i++) {
+  var x = void 0;
+  var y = i;
+  if (!x) x = y;
+}
+for (var key in obj) {
+  var value = void 0;
+}
@@ -0,0 +1,2 @@
+a;
+let a = 1;
@@ -0,0 +1,13 @@
+class A {}
+{
+  class A {}
+  new A();
+}
+for (const x of xs) {
+  class B {
+    method() {
+      return x;
+    }
+  }
+  fns.push(B);
+}
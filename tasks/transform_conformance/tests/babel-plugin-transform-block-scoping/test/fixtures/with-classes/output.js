@@ -0,0 +1,35 @@
+var A = /* @__PURE__ */ function() {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A);
+}();
+{
+  var _A = /* @__PURE__ */ function() {
+    "use strict";
+    function _A() {
+      babelHelpers.classCallCheck(this, _A);
+    }
+    return babelHelpers.createClass(_A);
+  }();
+  new _A();
+}
+var _loop = function(x) {
+  var B = /* @__PURE__ */ function() {
+    "use strict";
+    function B() {
+      babelHelpers.classCallCheck(this, B);
+    }
+    return babelHelpers.createClass(B, [{
+      key: "method",
+      value: function method() {
+        return x;
+      }
+    }]);
+  }();
+  fns.push(B);
+};
+for (var x of xs) {
+  _loop(x);
+}
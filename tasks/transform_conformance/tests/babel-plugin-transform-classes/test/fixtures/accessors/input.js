@@ -0,0 +1,11 @@
+class A {
+  get x() {
+    return this._x;
+  }
+  set x(value) {
+    this._x = value;
+  }
+  static get y() {
+    return 1;
+  }
+}
@@ -0,0 +1,20 @@
+let A = /* @__PURE__ */ function() {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+  }
+  return babelHelpers.createClass(A, [{
+    key: "x",
+    get: function() {
+      return this._x;
+    },
+    set: function(value) {
+      this._x = value;
+    }
+  }], [{
+    key: "y",
+    get: function() {
+      return 1;
+    }
+  }]);
+}();
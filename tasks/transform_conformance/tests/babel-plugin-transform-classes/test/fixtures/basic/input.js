@@ -0,0 +1,22 @@
+class A {
+  constructor(x) {
+    this.x = x;
+  }
+  method() {
+    return A;
+  }
+  static create() {
+    return new this(1);
+  }
+  ["computed"]() {}
+}
+
+const B = class {
+  foo() {}
+};
+
+const C = class Named {
+  bar() {
+    return Named;
+  }
+};
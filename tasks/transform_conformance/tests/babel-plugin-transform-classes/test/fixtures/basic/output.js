@@ -0,0 +1,43 @@
+let A = /* @__PURE__ */ function() {
+  "use strict";
+  function A(x) {
+    babelHelpers.classCallCheck(this, A);
+    this.x = x;
+  }
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      return A;
+    }
+  }, {
+    key: "computed",
+    value: function() {}
+  }], [{
+    key: "create",
+    value: function create() {
+      return new this(1);
+    }
+  }]);
+}();
+const B = /* @__PURE__ */ function() {
+  "use strict";
+  function B() {
+    babelHelpers.classCallCheck(this, B);
+  }
+  return babelHelpers.createClass(B, [{
+    key: "foo",
+    value: function foo() {}
+  }]);
+}();
+const C = /* @__PURE__ */ function() {
+  "use strict";
+  function Named() {
+    babelHelpers.classCallCheck(this, Named);
+  }
+  return babelHelpers.createClass(Named, [{
+    key: "bar",
+    value: function bar() {
+      return Named;
+    }
+  }]);
+}();
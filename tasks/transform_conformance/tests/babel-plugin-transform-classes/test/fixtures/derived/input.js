@@ -0,0 +1,25 @@
+class A extends B {}
+
+class C extends D {
+  constructor(x) {
+    super(x);
+  }
+}
+
+class E extends F {
+  constructor(x, y) {
+    const self = () => this;
+    super(x, ...y);
+    this.self = self;
+  }
+}
+
+class G extends H {
+  constructor() {
+    if (cond) {
+      return {};
+    }
+    super();
+    new.target;
+  }
+}
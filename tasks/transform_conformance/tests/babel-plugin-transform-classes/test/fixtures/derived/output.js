@@ -0,0 +1,46 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+let C = /* @__PURE__ */ function(_D) {
+  "use strict";
+  function C(x) {
+    babelHelpers.classCallCheck(this, C);
+    return babelHelpers.callSuper(this, C, [x]);
+  }
+  babelHelpers.inherits(C, _D);
+  return babelHelpers.createClass(C);
+}(D);
+let E = /* @__PURE__ */ function(_F) {
+  "use strict";
+  function E(x, y) {
+    var _this;
+    babelHelpers.classCallCheck(this, E);
+    const self = () => _this;
+    _this = babelHelpers.callSuper(this, E, [x, ...y]);
+    _this.self = self;
+    return _this;
+  }
+  babelHelpers.inherits(E, _F);
+  return babelHelpers.createClass(E);
+}(F);
+let G = /* @__PURE__ */ function(_H) {
+  "use strict";
+  function G() {
+    var _this2;
+    babelHelpers.classCallCheck(this, G);
+    if (cond) {
+      return babelHelpers.possibleConstructorReturn(_this2, {});
+    }
+    _this2 = babelHelpers.callSuper(this, G);
+    this.constructor;
+    return _this2;
+  }
+  babelHelpers.inherits(G, _H);
+  return babelHelpers.createClass(G);
+}(H);
@@ -0,0 +1,12 @@
+class A extends B {
+  constructor() {
+    super();
+    this.x = 1;
+  }
+  foo() {
+    return super.foo();
+  }
+  get bar() {
+    return 1;
+  }
+}
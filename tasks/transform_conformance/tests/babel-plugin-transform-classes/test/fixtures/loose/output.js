@@ -0,0 +1,19 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    var _this;
+    _this = _B.call(this) || this;
+    _this.x = 1;
+    return _this;
+  }
+  babelHelpers.inheritsLoose(A, _B);
+  A.prototype.foo = function foo() {
+    return _B.prototype.foo.call(this);
+  };
+  return babelHelpers.createClass(A, [{
+    key: "bar",
+    get: function() {
+      return 1;
+    }
+  }]);
+}(B);
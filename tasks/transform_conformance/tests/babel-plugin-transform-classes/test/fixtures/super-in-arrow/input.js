@@ -0,0 +1,17 @@
+class A extends B {
+  constructor() {
+    const init = () => super();
+    init();
+  }
+  method() {
+    const f = () => super.method();
+    return f();
+  }
+  loop() {
+    const fs = [];
+    for (let i = 0; i < 2; i++) {
+      fs.push(() => super.loop(i));
+    }
+    return fs;
+  }
+}
@@ -0,0 +1,38 @@
+var A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    var _this3, _this4 = this;
+    babelHelpers.classCallCheck(this, A);
+    var init = function() {
+      return _this3 = babelHelpers.callSuper(_this4, A);
+    };
+    init();
+    return babelHelpers.assertThisInitialized(_this3);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "method",
+    value: function method() {
+      var _this = this;
+      var f = function() {
+        return babelHelpers.superPropGet(A, "method", _this, 3)([]);
+      };
+      return f();
+    }
+  }, {
+    key: "loop",
+    value: function loop() {
+      var _this2 = this;
+      var fs = [];
+      var _loop = function(i) {
+        fs.push(function() {
+          return babelHelpers.superPropGet(A, "loop", _this2, 3)([i]);
+        });
+      };
+      for (var i = 0; i < 2; i++) {
+        _loop(i);
+      }
+      return fs;
+    }
+  }]);
+}(B);
@@ -0,0 +1,13 @@
+class A extends B {
+  foo() {
+    super.foo();
+    super[key](1, 2);
+    super.bar = 1;
+    super.baz += 2;
+    super.qux++;
+    return super.x;
+  }
+  static bar() {
+    return super.bar;
+  }
+}
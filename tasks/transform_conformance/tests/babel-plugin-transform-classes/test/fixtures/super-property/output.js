@@ -0,0 +1,25 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    babelHelpers.classCallCheck(this, A);
+    return babelHelpers.callSuper(this, A, arguments);
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A, [{
+    key: "foo",
+    value: function foo() {
+      var _super$qux, _super$qux2;
+      babelHelpers.superPropGet(A, "foo", this, 3)([]);
+      babelHelpers.superPropGet(A, key, this, 3)([1, 2]);
+      babelHelpers.superPropSet(A, "bar", 1, this, 1, 1);
+      babelHelpers.superPropSet(A, "baz", babelHelpers.superPropGet(A, "baz", this, 1) + 2, this, 1, 1);
+      babelHelpers.superPropSet(A, "qux", (_super$qux = babelHelpers.superPropGet(A, "qux", this, 1), _super$qux2 = _super$qux++, _super$qux), this, 1, 1), _super$qux2;
+      return babelHelpers.superPropGet(A, "x", this, 1);
+    }
+  }], [{
+    key: "bar",
+    value: function bar() {
+      return babelHelpers.superPropGet(A, "bar", this);
+    }
+  }]);
+}(B);
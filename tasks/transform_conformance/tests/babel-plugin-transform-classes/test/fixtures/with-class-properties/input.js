@@ -0,0 +1,7 @@
+class A extends B {
+  x = 1;
+  static y = 2;
+  constructor() {
+    super();
+  }
+}
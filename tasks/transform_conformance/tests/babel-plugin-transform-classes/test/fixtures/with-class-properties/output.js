@@ -0,0 +1,13 @@
+let A = /* @__PURE__ */ function(_B) {
+  "use strict";
+  function A() {
+    var _this;
+    babelHelpers.classCallCheck(this, A);
+    _this = babelHelpers.callSuper(this, A);
+    babelHelpers.defineProperty(_this, "x", 1);
+    return _this;
+  }
+  babelHelpers.inherits(A, _B);
+  return babelHelpers.createClass(A);
+}(B);
+babelHelpers.defineProperty(A, "y", 2);
@@ -0,0 +1,5 @@
+[a, b] = [b, a];
+({ a: this.x, b = 1 } = obj);
+({ [key()]: k } = obj);
+var result = ({ length } = str);
+for (let i = 0, [x, y] = point; i < 1; i++) {}
@@ -0,0 +1,6 @@
+var _ref, _obj$b;
+_ref = babelHelpers.slicedToArray([b, a], 2), a = _ref[0], b = _ref[1];
+this.x = obj.a, _obj$b = obj.b, b = _obj$b === void 0 ? 1 : _obj$b;
+k = obj[key()];
+var result = (length = str.length, str);
+for (let i = 0, _point = babelHelpers.slicedToArray(point, 2), x = _point[0], y = _point[1]; i < 1; i++) {}
@@ -0,0 +1,9 @@
+function f({ a, b: [c] }, [d = 1]) {}
+try {} catch ({ message }) {
+  console.log(message);
+}
+for (const [key, value] of map) {
+  console.log(key, value);
+}
+for ({ a } of list) {}
+for (var [k] in obj) {}
@@ -0,0 +1,18 @@
+function f(_ref, _ref2) {
+  var a = _ref.a, _ref$b = babelHelpers.slicedToArray(_ref.b, 1), c = _ref$b[0];
+  var _ref3 = babelHelpers.slicedToArray(_ref2, 1), _ref3$ = _ref3[0], d = _ref3$ === void 0 ? 1 : _ref3$;
+}
+try {} catch (_ref4) {
+  let message = _ref4.message;
+  console.log(message);
+}
+for (const _ref5 of map) {
+  const _ref6 = babelHelpers.slicedToArray(_ref5, 2), key = _ref6[0], value = _ref6[1];
+  console.log(key, value);
+}
+for (var _ref7 of list) {
+  a = _ref7.a;
+}
+for (var _ref8 in obj) {
+  var _ref9 = babelHelpers.slicedToArray(_ref8, 1), k = _ref9[0];
+}
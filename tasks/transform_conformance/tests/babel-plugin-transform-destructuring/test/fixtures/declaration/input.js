@@ -0,0 +1,4 @@
+var { a, b: { c = 1 } } = obj;
+let [d, , e = 2, ...f] = arr;
+const { g: [h, i] } = obj;
+var {} = empty;
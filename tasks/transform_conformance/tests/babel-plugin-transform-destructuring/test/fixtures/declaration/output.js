@@ -0,0 +1,4 @@
+var a = obj.a, _obj$b$c = obj.b.c, c = _obj$b$c === void 0 ? 1 : _obj$b$c;
+let _arr = babelHelpers.toArray(arr), d = _arr[0], _arr$ = _arr[2], e = _arr$ === void 0 ? 2 : _arr$, f = _arr.slice(3);
+const _obj$g = babelHelpers.slicedToArray(obj.g, 2), h = _obj$g[0], i = _obj$g[1];
+var _ref = babelHelpers.objectDestructuringEmpty(empty);
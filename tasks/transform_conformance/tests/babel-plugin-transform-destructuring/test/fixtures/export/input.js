@@ -0,0 +1,2 @@
+export const { a, b: [c] } = obj;
+export let [d] = arr;
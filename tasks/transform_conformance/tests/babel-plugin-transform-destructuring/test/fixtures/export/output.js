@@ -0,0 +1,4 @@
+const a = obj.a, _obj$b = babelHelpers.slicedToArray(obj.b, 1), c = _obj$b[0];
+export { a, c };
+let _arr = babelHelpers.slicedToArray(arr, 1), d = _arr[0];
+export { d };
@@ -0,0 +1,2 @@
+var a = arr[0], b = arr[1], c = arr.slice(2);
+d = arr[0], e = arr[1];
@@ -0,0 +1,4 @@
+var { a, ...rest } = obj;
+var { [key]: b, ...rest2 } = obj;
+var { ...rest3 } = obj;
+({ c, ...rest4 } = obj);
@@ -0,0 +1,4 @@
+var a = obj.a, rest = babelHelpers.objectWithoutProperties(obj, ["a"]);
+var b = obj[key], rest2 = babelHelpers.objectWithoutProperties(obj, [key].map(babelHelpers.toPropertyKey));
+var rest3 = babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(obj), obj));
+c = obj.c, rest4 = babelHelpers.objectWithoutProperties(obj, ["c"]);
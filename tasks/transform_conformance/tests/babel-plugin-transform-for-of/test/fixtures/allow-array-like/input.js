@@ -0,0 +1,4 @@
+for (const x of xs) {
+  console.log(x);
+}
+for (const y of ys) console.log(y);
@@ -0,0 +1,4 @@
+for (const x of [1, 2, 3]) {
+  console.log(x);
+}
+label: for (var y of [a, b]) break label;
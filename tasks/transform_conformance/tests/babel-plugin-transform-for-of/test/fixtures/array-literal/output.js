@@ -0,0 +1,12 @@
+for (var _i = 0, _arr = [
+  1,
+  2,
+  3
+]; _i < _arr.length; _i++) {
+  const x = _arr[_i];
+  console.log(x);
+}
+label: for (var _i2 = 0, _arr2 = [a, b]; _i2 < _arr2.length; _i2++) {
+  var y = _arr2[_i2];
+  break label;
+}
@@ -0,0 +1,7 @@
+for (const x of xs) {
+  console.log(x);
+}
+for (const y of getItems()) {
+  console.log(y);
+}
+for (const z of this) {}
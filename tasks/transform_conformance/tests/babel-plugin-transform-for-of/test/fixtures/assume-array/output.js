@@ -0,0 +1,11 @@
+for (let _i = 0, _xs = xs; _i < _xs.length; _i++) {
+  const x = _xs[_i];
+  console.log(x);
+}
+for (let _i2 = 0, _getItems = getItems(); _i2 < _getItems.length; _i2++) {
+  const y = _getItems[_i2];
+  console.log(y);
+}
+for (let _i3 = 0; _i3 < this.length; _i3++) {
+  const z = this[_i3];
+}
@@ -0,0 +1,9 @@
+for (const x of xs) {
+  console.log(x);
+}
+for (var y of ys) console.log(y);
+for (z of zs);
+for (obj.prop of getItems()) {}
+for (const [a, b] of pairs) {
+  console.log(a, b);
+}
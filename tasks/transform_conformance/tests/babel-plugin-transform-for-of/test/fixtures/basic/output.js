@@ -0,0 +1,53 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    console.log(x);
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(ys), _step2;
+try {
+  for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    var y = _step2.value;
+    console.log(y);
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+var _iterator3 = babelHelpers.createForOfIteratorHelper(zs), _step3;
+try {
+  for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+    z = _step3.value;
+  }
+} catch (err) {
+  _iterator3.e(err);
+} finally {
+  _iterator3.f();
+}
+var _iterator4 = babelHelpers.createForOfIteratorHelper(getItems()), _step4;
+try {
+  for (_iterator4.s(); !(_step4 = _iterator4.n()).done;) {
+    obj.prop = _step4.value;
+  }
+} catch (err) {
+  _iterator4.e(err);
+} finally {
+  _iterator4.f();
+}
+var _iterator5 = babelHelpers.createForOfIteratorHelper(pairs), _step5;
+try {
+  for (_iterator5.s(); !(_step5 = _iterator5.n()).done;) {
+    const [a, b] = _step5.value;
+    console.log(a, b);
+  }
+} catch (err) {
+  _iterator5.e(err);
+} finally {
+  _iterator5.f();
+}
@@ -0,0 +1,4 @@
+for (const x of xs) {
+  console.log(x);
+}
+label: for (const y of ys) continue label;
@@ -0,0 +1,8 @@
+for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) {
+  const x = _step.value;
+  console.log(x);
+}
+label: for (var _iterator2 = babelHelpers.createForOfIteratorHelperLoose(ys), _step2; !(_step2 = _iterator2()).done;) {
+  const y = _step2.value;
+  continue label;
+}
@@ -0,0 +1,14 @@
+if (cond) for (const x of xs) use(x);
+outer: for (const x of xs) {
+  for (const y of x) {
+    if (y) continue outer;
+  }
+}
+function f() {
+  for (const x of xs) {
+    const y = x;
+    {
+      let x = y;
+    }
+  }
+}
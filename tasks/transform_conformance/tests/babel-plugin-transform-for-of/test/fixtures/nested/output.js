@@ -0,0 +1,50 @@
+if (cond) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const x = _step.value;
+      use(x);
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+}
+var _iterator2 = babelHelpers.createForOfIteratorHelper(xs), _step2;
+try {
+  outer: for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    const x = _step2.value;
+    var _iterator3 = babelHelpers.createForOfIteratorHelper(x), _step3;
+    try {
+      for (_iterator3.s(); !(_step3 = _iterator3.n()).done;) {
+        const y = _step3.value;
+        if (y) continue outer;
+      }
+    } catch (err) {
+      _iterator3.e(err);
+    } finally {
+      _iterator3.f();
+    }
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
+function f() {
+  var _iterator4 = babelHelpers.createForOfIteratorHelper(xs), _step4;
+  try {
+    for (_iterator4.s(); !(_step4 = _iterator4.n()).done;) {
+      const x = _step4.value;
+      const y = x;
+      {
+        let x = y;
+      }
+    }
+  } catch (err) {
+    _iterator4.e(err);
+  } finally {
+    _iterator4.f();
+  }
+}
@@ -0,0 +1,6 @@
+import("static");
+import(`template`);
+async function load(name) {
+  return import(name);
+}
+import(`./locale/${language}.js`).then(() => {});
@@ -0,0 +1,6 @@
+Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("static")));
+Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require(`template`)));
+async function load(name) {
+  return Promise.resolve(`${name}`).then((_s) => babelHelpers.interopRequireWildcard(require(_s)));
+}
+Promise.resolve(`${`./locale/${language}.js`}`).then((_s2) => babelHelpers.interopRequireWildcard(require(_s2))).then(() => {});
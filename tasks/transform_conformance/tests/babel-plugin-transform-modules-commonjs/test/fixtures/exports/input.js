@@ -0,0 +1,9 @@
+export var a = 1, { b, c: [d] } = {};
+export let e = 2;
+export function f() {}
+export class G {}
+const h = 3, i = 4;
+export { h, i as j, h as "string name" };
+export default function () {
+  e++;
+}
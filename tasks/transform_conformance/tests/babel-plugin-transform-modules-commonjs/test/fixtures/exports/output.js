@@ -0,0 +1,70 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "a", {
+  enumerable: true,
+  get: function() {
+    return a;
+  }
+});
+Object.defineProperty(exports, "b", {
+  enumerable: true,
+  get: function() {
+    return b;
+  }
+});
+Object.defineProperty(exports, "d", {
+  enumerable: true,
+  get: function() {
+    return d;
+  }
+});
+Object.defineProperty(exports, "e", {
+  enumerable: true,
+  get: function() {
+    return e;
+  }
+});
+Object.defineProperty(exports, "f", {
+  enumerable: true,
+  get: function() {
+    return f;
+  }
+});
+Object.defineProperty(exports, "G", {
+  enumerable: true,
+  get: function() {
+    return G;
+  }
+});
+Object.defineProperty(exports, "h", {
+  enumerable: true,
+  get: function() {
+    return h;
+  }
+});
+Object.defineProperty(exports, "j", {
+  enumerable: true,
+  get: function() {
+    return i;
+  }
+});
+Object.defineProperty(exports, "string name", {
+  enumerable: true,
+  get: function() {
+    return h;
+  }
+});
+Object.defineProperty(exports, "default", {
+  enumerable: true,
+  get: function() {
+    return _default;
+  }
+});
+var a = 1, { b, c: [d] } = {};
+let e = 2;
+function f() {}
+class G {}
+const h = 3, i = 4;
+function _default() {
+  e++;
+}
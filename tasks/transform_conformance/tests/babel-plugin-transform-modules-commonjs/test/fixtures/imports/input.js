@@ -0,0 +1,11 @@
+import "side-effect";
+import foo from "foo";
+import { bar, baz as qux, "kebab-case" as kebab } from "bar";
+import * as ns from "./namespace.js";
+import def, { named } from "mixed";
+
+foo();
+bar(qux, kebab);
+kebab`tagged`;
+ns.method();
+console.log({ bar, def }, named.prop, typeof foo);
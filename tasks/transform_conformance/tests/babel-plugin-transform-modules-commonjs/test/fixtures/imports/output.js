@@ -0,0 +1,14 @@
+"use strict";
+require("side-effect");
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+var _bar = require("bar");
+var ns = babelHelpers.interopRequireWildcard(require("./namespace.js"));
+var _mixed = babelHelpers.interopRequireWildcard(require("mixed"));
+(0, _foo.default)();
+(0, _bar.bar)(_bar.baz, _bar["kebab-case"]);
+(0, _bar["kebab-case"])`tagged`;
+ns.method();
+console.log({
+  bar: _bar.bar,
+  def: _mixed.default
+}, _mixed.named.prop, typeof _foo.default);
@@ -0,0 +1,7 @@
+import def from "default-only";
+import other, { named } from "mixed";
+import * as ns from "namespace";
+export { default as reexported } from "reexport";
+
+def(other, named, ns.default);
+import("dynamic");
@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "reexported", {
+  enumerable: true,
+  get: function() {
+    return _reexport;
+  }
+});
+var _defaultOnly = require("default-only");
+var _mixed = require("mixed");
+var ns = babelHelpers.interopRequireWildcard(require("namespace"), true);
+var _reexport = require("reexport");
+_defaultOnly(_mixed, _mixed.named, ns.default);
+Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("dynamic"), true));
@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "reexported", {
+  enumerable: true,
+  get: function() {
+    return _reexport.default;
+  }
+});
+var _defaultOnly = require("default-only");
+var _mixed = require("mixed");
+var ns = require("namespace");
+var _reexport = require("reexport");
+(0, _defaultOnly.default)(_mixed.default, _mixed.named, ns.default);
+Promise.resolve().then(() => require("dynamic"));
@@ -0,0 +1,6 @@
+import { used } from "used";
+export { used };
+export { a, b as c, default as d } from "named";
+export * from "all";
+export * as ns from "namespace";
+export const local = 1;
@@ -0,0 +1,62 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+var _exportNames = {
+  used: true,
+  a: true,
+  c: true,
+  d: true,
+  ns: true,
+  local: true
+};
+Object.defineProperty(exports, "used", {
+  enumerable: true,
+  get: function() {
+    return _used.used;
+  }
+});
+Object.defineProperty(exports, "a", {
+  enumerable: true,
+  get: function() {
+    return _named.a;
+  }
+});
+Object.defineProperty(exports, "c", {
+  enumerable: true,
+  get: function() {
+    return _named.b;
+  }
+});
+Object.defineProperty(exports, "d", {
+  enumerable: true,
+  get: function() {
+    return _named.default;
+  }
+});
+Object.defineProperty(exports, "ns", {
+  enumerable: true,
+  get: function() {
+    return _namespace;
+  }
+});
+Object.defineProperty(exports, "local", {
+  enumerable: true,
+  get: function() {
+    return local;
+  }
+});
+var _used = require("used");
+var _named = babelHelpers.interopRequireWildcard(require("named"));
+var _all = require("all");
+Object.keys(_all).forEach(function(key) {
+  if (key === "default" || key === "__esModule") return;
+  if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+  if (key in exports && exports[key] === _all[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _all[key];
+    }
+  });
+});
+var _namespace = babelHelpers.interopRequireWildcard(require("namespace"));
+const local = 1;
@@ -0,0 +1,14 @@
+export const self = this;
+const arrow = () => this;
+function fn() {
+  return this;
+}
+class C {
+  [this.key] = this;
+  static {
+    this.x = 1;
+  }
+  method() {
+    return () => this;
+  }
+}
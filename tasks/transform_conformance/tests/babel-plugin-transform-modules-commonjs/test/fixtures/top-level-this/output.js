@@ -0,0 +1,22 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+Object.defineProperty(exports, "self", {
+  enumerable: true,
+  get: function() {
+    return self;
+  }
+});
+const self = void 0;
+const arrow = () => void 0;
+function fn() {
+  return this;
+}
+class C {
+  [(void 0).key] = this;
+  static {
+    this.x = 1;
+  }
+  method() {
+    return () => this;
+  }
+}
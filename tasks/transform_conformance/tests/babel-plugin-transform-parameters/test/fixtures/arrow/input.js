@@ -0,0 +1,3 @@
+const f = (a = 1) => a;
+const g = (a, ...rest) => rest;
+const h = ({ x } = {}, ...[y]) => x + y;
@@ -0,0 +1,14 @@
+const f = function(a) {
+  if (a === void 0) a = 1;
+  return a;
+};
+const g = function(a) {
+  for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) rest[_key - 1] = arguments[_key];
+  return rest;
+};
+const h = function(_ref) {
+  for (var _len2 = arguments.length, _ref2 = new Array(_len2 > 1 ? _len2 - 1 : 0), _key2 = 1; _key2 < _len2; _key2++) _ref2[_key2 - 1] = arguments[_key2];
+  var { x } = _ref === void 0 ? {} : _ref;
+  var [y] = _ref2;
+  return x + y;
+};
@@ -0,0 +1,13 @@
+var x = "outer";
+function f(a = () => x) {
+  var x = "inner";
+  return a();
+}
+function g(a, b = () => a) {
+  var a = "body";
+  return [a, b()];
+}
+function h(a, b = () => a) {
+  function a() {}
+  return [a, b()];
+}
@@ -0,0 +1,17 @@
+var x = "outer";
+function f(a) {
+  if (a === void 0) a = () => x;
+  var _x = "inner";
+  return a();
+}
+function g(a, b) {
+  if (b === void 0) b = () => a;
+  var _a = a;
+  var _a = "body";
+  return [_a, b()];
+}
+function h(a, b) {
+  if (b === void 0) b = () => a;
+  function _a2() {}
+  return [_a2, b()];
+}
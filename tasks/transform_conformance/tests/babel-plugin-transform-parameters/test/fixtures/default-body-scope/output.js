@@ -0,0 +1,17 @@
+var x = "outer";
+function f() {
+  var a = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : () => x;
+  var _x = "inner";
+  return a();
+}
+function g(a) {
+  var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : () => a;
+  var _a = a;
+  var _a = "body";
+  return [_a, b()];
+}
+function h(a) {
+  var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : () => a;
+  function _a2() {}
+  return [_a2, b()];
+}
@@ -0,0 +1,6 @@
+function f(a, b = 1, c) {}
+function g({ x } = {}, [y] = []) {}
+var h = function (a = this.x) {};
+class A {
+  method(a, b = a) {}
+}
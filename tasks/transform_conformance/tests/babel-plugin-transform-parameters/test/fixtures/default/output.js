@@ -0,0 +1,16 @@
+function f(a) {
+  var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+  var c = arguments.length > 2 ? arguments[2] : void 0;
+}
+function g() {
+  var { x } = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : {};
+  var [y] = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : [];
+}
+var h = function() {
+  var a = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : this.x;
+};
+class A {
+  method(a) {
+    var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : a;
+  }
+}
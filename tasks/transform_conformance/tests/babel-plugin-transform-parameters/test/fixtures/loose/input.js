@@ -0,0 +1,2 @@
+function f(a, b = 1, { c } = {}) {}
+function g(a = 1, ...rest) {}
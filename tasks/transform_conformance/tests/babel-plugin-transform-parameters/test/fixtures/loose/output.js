@@ -0,0 +1,8 @@
+function f(a, b, _ref) {
+  if (b === void 0) b = 1;
+  var { c } = _ref === void 0 ? {} : _ref;
+}
+function g(a) {
+  if (a === void 0) a = 1;
+  for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) rest[_key - 1] = arguments[_key];
+}
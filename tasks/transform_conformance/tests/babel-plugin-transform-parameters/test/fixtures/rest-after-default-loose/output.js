@@ -0,0 +1,6 @@
+function f(m, _ref, _ref2) {
+  var { n } = _ref === void 0 ? {} : _ref;
+  var [k] = _ref2 === void 0 ? [3] : _ref2;
+  for (var _len = arguments.length, more = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) more[_key - 3] = arguments[_key];
+  return more;
+}
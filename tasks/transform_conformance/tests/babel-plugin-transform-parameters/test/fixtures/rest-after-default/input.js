@@ -0,0 +1,3 @@
+function f(m, { n } = {}, [k] = [3], ...more) {
+  return more;
+}
@@ -0,0 +1,6 @@
+function f(m) {
+  var { n } = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : {};
+  var [k] = arguments.length > 2 && arguments[2] !== void 0 ? arguments[2] : [3];
+  for (var _len = arguments.length, more = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) more[_key - 3] = arguments[_key];
+  return more;
+}
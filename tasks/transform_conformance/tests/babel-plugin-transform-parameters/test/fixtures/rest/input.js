@@ -0,0 +1,7 @@
+function f(...args) {
+  return args;
+}
+function g(a, b, ...rest) {
+  return rest[0];
+}
+function h(a, ...[b, c]) {}
@@ -0,0 +1,12 @@
+function f() {
+  for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) args[_key] = arguments[_key];
+  return args;
+}
+function g(a, b) {
+  for (var _len2 = arguments.length, rest = new Array(_len2 > 2 ? _len2 - 2 : 0), _key2 = 2; _key2 < _len2; _key2++) rest[_key2 - 2] = arguments[_key2];
+  return rest[0];
+}
+function h(a) {
+  for (var _len3 = arguments.length, _ref = new Array(_len3 > 1 ? _len3 - 1 : 0), _key3 = 1; _key3 < _len3; _key3++) _ref[_key3 - 1] = arguments[_key3];
+  var [b, c] = _ref;
+}
@@ -0,0 +1,8 @@
+function* gen() {
+  yield arguments.length;
+  yield this;
+  const fn = () => this;
+  function nested() {
+    return arguments;
+  }
+}
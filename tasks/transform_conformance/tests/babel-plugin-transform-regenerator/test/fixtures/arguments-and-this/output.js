@@ -0,0 +1,20 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var fn, nested, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        nested = function _nested() {
+          return arguments;
+        };
+        _context.next = 3;
+        return _args.length;
+      case 3:
+        _context.next = 5;
+        return this;
+      case 5: fn = () => this;
+      case 6:
+      case "end": return _context.stop();
+    }
+  }, _marked, this);
+}
@@ -0,0 +1,20 @@
+function* gen(x) {
+  var a = yield x;
+  let b = a + (yield 1);
+  function inner() {
+    return b;
+  }
+  return inner();
+}
+
+const expr = function* () {
+  yield 1;
+};
+
+const named = function* named() {
+  yield named;
+};
+
+export default function* () {
+  yield 2;
+}
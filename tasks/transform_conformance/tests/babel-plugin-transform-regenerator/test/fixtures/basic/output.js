@@ -0,0 +1,58 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(_callee2);
+function gen(x) {
+  var a, b, inner;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        inner = function _inner() {
+          return b;
+        };
+        _context.next = 3;
+        return x;
+      case 3:
+        a = _context.sent;
+        _context.t0 = a;
+        _context.next = 7;
+        return 1;
+      case 7:
+        _context.t1 = _context.sent;
+        b = _context.t0 + _context.t1;
+        return _context.abrupt("return", inner());
+      case 10:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+const expr = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, _callee);
+});
+const named = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function named() {
+  return babelHelpers.regeneratorRuntime().wrap(function named$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return named;
+      case 2:
+      case "end": return _context3.stop();
+    }
+  }, named);
+});
+export default function _callee2() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context4) {
+    while (1) switch (_context4.prev = _context4.next) {
+      case 0:
+        _context4.next = 2;
+        return 2;
+      case 2:
+      case "end": return _context4.stop();
+    }
+  }, _marked2);
+}
@@ -0,0 +1,9 @@
+function* inner() {
+  yield 1;
+  return 2;
+}
+
+function* outer() {
+  const result = yield* inner();
+  yield* [result, 3];
+}
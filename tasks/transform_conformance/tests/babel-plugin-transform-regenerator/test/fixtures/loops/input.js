@@ -0,0 +1,25 @@
+function* loops(n, obj, list) {
+  for (let i = 0; i < n; i++) {
+    if (i % 2) continue;
+    yield i;
+  }
+  while (true) {
+    if (yield) break;
+  }
+  do {
+    n -= yield n;
+  } while (n > 0);
+  outer: for (var key in obj) {
+    for (const item of list) {
+      if (item === key) continue outer;
+      yield item;
+    }
+  }
+  switch (yield "switch") {
+    case 1:
+      yield "one";
+      break;
+    default:
+      yield "default";
+  }
+}
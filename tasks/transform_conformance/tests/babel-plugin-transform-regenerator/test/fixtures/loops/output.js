@@ -0,0 +1,95 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(loops);
+function loops(n, obj, list) {
+  var i, key, item;
+  return babelHelpers.regeneratorRuntime().wrap(function loops$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < n)) {
+          _context.next = 9;
+          break;
+        }
+        if (!(i % 2)) {
+          _context.next = 4;
+          break;
+        }
+        return _context.abrupt("continue", 6);
+      case 4:
+        _context.next = 6;
+        return i;
+      case 6:
+        i++;
+        _context.next = 1;
+        break;
+      case 9:
+        if (!true) {
+          _context.next = 16;
+          break;
+        }
+        _context.next = 12;
+        return;
+      case 12:
+        if (!_context.sent) {
+          _context.next = 14;
+          break;
+        }
+        return _context.abrupt("break", 16);
+      case 14:
+        _context.next = 9;
+        break;
+      case 16:
+        _context.t0 = n;
+        _context.next = 19;
+        return n;
+      case 19: n = _context.t0 - _context.sent;
+      case 20: if (n > 0) {
+        _context.next = 16;
+        break;
+      }
+      case 21: _context.t1 = babelHelpers.regeneratorRuntime().keys(obj);
+      case 22:
+        if ((_context.t2 = _context.t1()).done) {
+          _context.next = 35;
+          break;
+        }
+        key = _context.t2.value;
+        _context.t3 = list[Symbol.iterator]();
+      case 25:
+        if ((_context.t4 = _context.t3.next()).done) {
+          _context.next = 33;
+          break;
+        }
+        item = _context.t4.value;
+        if (!(item === key)) {
+          _context.next = 29;
+          break;
+        }
+        return _context.abrupt("continue", 22);
+      case 29:
+        _context.next = 31;
+        return item;
+      case 31:
+        _context.next = 25;
+        break;
+      case 33:
+        _context.next = 22;
+        break;
+      case 35:
+        _context.next = 37;
+        return "switch";
+      case 37:
+        _context.t5 = _context.sent;
+        _context.next = _context.t5 === 1 ? 40 : 43;
+        break;
+      case 40:
+        _context.next = 42;
+        return "one";
+      case 42: return _context.abrupt("break", 45);
+      case 43:
+        _context.next = 45;
+        return "default";
+      case 45:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,14 @@
+class A {
+  *values() {
+    yield this.a;
+  }
+  static *[Symbol.iterator]() {
+    yield 1;
+  }
+}
+
+const obj = {
+  *entries() {
+    yield [1, 2];
+  },
+};
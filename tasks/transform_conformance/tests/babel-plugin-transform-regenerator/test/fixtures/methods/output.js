@@ -0,0 +1,35 @@
+class A {
+  values() {
+    return babelHelpers.regeneratorRuntime().wrap(function values$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return this.a;
+        case 2:
+        case "end": return _context.stop();
+      }
+    }, null, this);
+  }
+  static [Symbol.iterator]() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context2.stop();
+      }
+    });
+  }
+}
+const obj = { entries() {
+  return babelHelpers.regeneratorRuntime().wrap(function entries$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return [1, 2];
+      case 2:
+      case "end": return _context3.stop();
+    }
+  });
+} };
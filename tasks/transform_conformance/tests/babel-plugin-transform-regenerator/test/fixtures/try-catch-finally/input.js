@@ -0,0 +1,25 @@
+function* tryCatch() {
+  try {
+    yield a();
+  } catch (err) {
+    yield err;
+  }
+}
+
+function* tryFinally() {
+  try {
+    yield a();
+  } finally {
+    yield b();
+  }
+}
+
+function* tryCatchFinally() {
+  try {
+    return yield a();
+  } catch (err) {
+    console.log(err);
+  } finally {
+    cleanup();
+  }
+}
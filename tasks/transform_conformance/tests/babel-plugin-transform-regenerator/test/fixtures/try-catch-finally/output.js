@@ -0,0 +1,69 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(tryCatch), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(tryFinally), _marked3 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(tryCatchFinally);
+function tryCatch() {
+  return babelHelpers.regeneratorRuntime().wrap(function tryCatch$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return a();
+      case 3:
+        _context.next = 9;
+        break;
+      case 5:
+        _context.prev = 5;
+        _context.t0 = _context["catch"](0);
+        _context.next = 9;
+        return _context.t0;
+      case 9:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [[0, 5]]);
+}
+function tryFinally() {
+  return babelHelpers.regeneratorRuntime().wrap(function tryFinally$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.prev = 0;
+        _context2.next = 3;
+        return a();
+      case 3:
+        _context2.prev = 3;
+        _context2.next = 6;
+        return b();
+      case 6: return _context2.finish(3);
+      case 7:
+      case "end": return _context2.stop();
+    }
+  }, _marked2, null, [[
+    0,
+    ,
+    3,
+    7
+  ]]);
+}
+function tryCatchFinally() {
+  return babelHelpers.regeneratorRuntime().wrap(function tryCatchFinally$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.prev = 0;
+        _context3.next = 3;
+        return a();
+      case 3: return _context3.abrupt("return", _context3.sent);
+      case 6:
+        _context3.prev = 6;
+        _context3.t0 = _context3["catch"](0);
+        console.log(_context3.t0);
+      case 9:
+        _context3.prev = 9;
+        cleanup();
+        return _context3.finish(9);
+      case 12:
+      case "end": return _context3.stop();
+    }
+  }, _marked3, null, [[
+    0,
+    6,
+    9,
+    12
+  ]]);
+}
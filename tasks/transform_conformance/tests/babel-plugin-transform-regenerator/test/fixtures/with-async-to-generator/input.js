@@ -0,0 +1,10 @@
+async function foo(x) {
+  const y = await bar(x);
+  return y + 1;
+}
+
+class A {
+  async method() {
+    await this.foo();
+  }
+}
@@ -0,0 +1,2 @@
+var a = [b].concat(babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, c));
+f.apply(void 0, babelHelpers.maybeArrayLike(babelHelpers.toConsumableArray, a));
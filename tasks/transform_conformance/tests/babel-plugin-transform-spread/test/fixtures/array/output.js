@@ -0,0 +1,8 @@
+var a = babelHelpers.toConsumableArray(b);
+var c = [d].concat(babelHelpers.toConsumableArray(e), [f]);
+var g = [].concat(babelHelpers.toConsumableArray(h), babelHelpers.toConsumableArray(i));
+var j = [1, 2].concat(babelHelpers.arrayLikeToArray([
+  3,
+  ,
+  5
+]));
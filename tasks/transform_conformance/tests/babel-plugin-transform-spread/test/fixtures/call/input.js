@@ -0,0 +1,9 @@
+f(...a);
+f(a, ...b, c);
+obj.method(...a);
+obj.a.b.c(...a);
+getObj().method(...a);
+obj[key](...a);
+function g() {
+  return h(...arguments);
+}
@@ -0,0 +1,10 @@
+var _obj, _obj$a$b, _getObj, _obj2;
+f.apply(void 0, babelHelpers.toConsumableArray(a));
+f.apply(void 0, [a].concat(babelHelpers.toConsumableArray(b), [c]));
+(_obj = obj).method.apply(_obj, babelHelpers.toConsumableArray(a));
+(_obj$a$b = obj.a.b).c.apply(_obj$a$b, babelHelpers.toConsumableArray(a));
+(_getObj = getObj()).method.apply(_getObj, babelHelpers.toConsumableArray(a));
+(_obj2 = obj)[key].apply(_obj2, babelHelpers.toConsumableArray(a));
+function g() {
+  return h.apply(void 0, arguments);
+}
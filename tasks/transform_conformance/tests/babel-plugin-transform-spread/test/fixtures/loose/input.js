@@ -0,0 +1,4 @@
+var a = [b, ...c, d];
+f(...a);
+obj.method(x, ...a);
+new Foo(...a);
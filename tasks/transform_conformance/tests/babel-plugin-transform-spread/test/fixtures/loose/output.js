@@ -0,0 +1,5 @@
+var _obj;
+var a = [b].concat(c, [d]);
+f.apply(void 0, a);
+(_obj = obj).method.apply(_obj, [x].concat(a));
+babelHelpers.construct(Foo, a);
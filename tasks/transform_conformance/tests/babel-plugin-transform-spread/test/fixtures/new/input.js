@@ -0,0 +1,3 @@
+new Foo(...a);
+new Foo(a, ...b);
+new obj.Foo(...a, b);
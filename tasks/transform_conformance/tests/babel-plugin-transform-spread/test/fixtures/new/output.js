@@ -0,0 +1,3 @@
+babelHelpers.construct(Foo, babelHelpers.toConsumableArray(a));
+babelHelpers.construct(Foo, [a].concat(babelHelpers.toConsumableArray(b)));
+babelHelpers.construct(obj.Foo, babelHelpers.toConsumableArray(a).concat([b]));
@@ -0,0 +1,6 @@
+var a = `foo${bar}`;
+var b = `${foo}bar${baz}`;
+var c = `${foo}${bar}${baz}`;
+var d = `a${1}b${"c"}d${foo}${""}e`;
+var e = `\u{1F600}${foo}\n`;
+var f = `no substitutions`;
@@ -0,0 +1,6 @@
+var a = "foo".concat(bar);
+var b = "".concat(foo, "bar").concat(baz);
+var c = "".concat(foo).concat(bar).concat(baz);
+var d = "a".concat(1, "b", "c", "d", foo, "e");
+var e = "😀".concat(foo, "\n");
+var f = "no substitutions";
@@ -0,0 +1,4 @@
+var _templateObject;
+var a = "foo" + bar + "baz" + qux;
+var b = "" + foo;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
@@ -0,0 +1,4 @@
+var a = `foo${bar}`;
+var b = `${foo}bar${baz}`;
+var c = `${foo}${bar}`;
+tag`foo${bar}baz`;
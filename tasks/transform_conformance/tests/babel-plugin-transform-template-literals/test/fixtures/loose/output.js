@@ -0,0 +1,5 @@
+var _templateObject;
+var a = "foo" + bar;
+var b = foo + "bar" + baz;
+var c = "" + foo + bar;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "baz"])), bar);
@@ -0,0 +1,2 @@
+var a = `foo${bar}baz${qux}`;
+tag`foo${bar}baz`;
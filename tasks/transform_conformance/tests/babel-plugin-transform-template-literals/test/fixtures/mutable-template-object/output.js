@@ -0,0 +1,3 @@
+var _templateObject;
+var a = "foo".concat(bar, "baz").concat(qux);
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["foo", "baz"])), bar);
@@ -0,0 +1,6 @@
+tag`foo${bar}baz`;
+tag`\unicode and \u{55}`;
+obj.tag`a${b}c${d}e`;
+function f() {
+  return tag`nested ${tag`inner`}`;
+}
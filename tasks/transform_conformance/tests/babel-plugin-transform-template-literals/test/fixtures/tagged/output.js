@@ -0,0 +1,15 @@
+var _templateObject;
+var _templateObject2;
+var _templateObject3;
+var _templateObject4;
+var _templateObject5;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["foo", "baz"])), bar);
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+obj.tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral([
+  "a",
+  "c",
+  "e"
+])), b, d);
+function f() {
+  return tag(_templateObject5 || (_templateObject5 = babelHelpers.taggedTemplateLiteral(["nested ", ""])), tag(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["inner"]))));
+}
@@ -0,0 +1,32 @@
+namespace N {
+  export const a = 1;
+  export let b = 2;
+  export function f() {
+    return c;
+  }
+}
+
+namespace N {
+  export let c = a + b;
+  console.log(a, b, f());
+
+  function g(a: number) {
+    return a;
+  }
+}
+
+namespace N.M {
+  export const x = 1;
+}
+
+namespace N.M {
+  console.log(x);
+}
+
+namespace O {
+  export const a = 1;
+}
+
+namespace P {
+  console.log(a);
+}
@@ -0,0 +1,36 @@
+let N;
+(function(_N) {
+  const a = _N.a = 1;
+  _N.b = 2;
+  function f() {
+    return _N.c;
+  }
+  _N.f = f;
+})(N || (N = {}));
+(function(_N2) {
+  _N2.c = _N2.a + _N2.b;
+  console.log(_N2.a, _N2.b, _N2.f());
+  function g(a) {
+    return a;
+  }
+})(N || (N = {}));
+(function(_N3) {
+  let M;
+  (function(_M) {
+    const x = _M.x = 1;
+  })(M || (M = _N3.M || (_N3.M = {})));
+})(N || (N = {}));
+(function(_N4) {
+  let M;
+  (function(_M2) {
+    console.log(_M2.x);
+  })(M || (M = _N4.M || (_N4.M = {})));
+})(N || (N = {}));
+let O;
+(function(_O) {
+  const a = _O.a = 1;
+})(O || (O = {}));
+let P;
+(function(_P) {
+  console.log(a);
+})(P || (P = {}));
@@ -0,0 +1,23 @@
+namespace N {
+  export let a = 1, b;
+  export var c = a + 1;
+  export let [d, { e, f: g = 2 }, ...h] = [1, { e: 2 }];
+  export let { i, ...j } = { i: 1 };
+
+  a++;
+  b = a;
+  ({ c, d = 1 } = { c: 1 });
+  [e, g] = [g, e];
+  for (h of []) {}
+  console.log({ a, b, i }, j);
+
+  function f() {
+    let a = 0;
+    return a + b;
+  }
+
+  export namespace M {
+    export let a = c;
+    a = b;
+  }
+}
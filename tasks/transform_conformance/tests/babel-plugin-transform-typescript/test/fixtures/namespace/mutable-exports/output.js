@@ -0,0 +1,26 @@
+let N;
+(function(_N) {
+  _N.a = 1;
+  _N.c = _N.a + 1;
+  [_N.d, {e: _N.e, f: _N.g = 2}, ..._N.h] = [1, { e: 2 }];
+  ({i: _N.i, ..._N.j} = { i: 1 });
+  _N.a++;
+  _N.b = _N.a;
+  ({c: _N.c, d: _N.d = 1} = { c: 1 });
+  [_N.e, _N.g] = [_N.g, _N.e];
+  for (_N.h of []) {}
+  console.log({
+    a: _N.a,
+    b: _N.b,
+    i: _N.i
+  }, _N.j);
+  function f() {
+    let a = 0;
+    return a + _N.b;
+  }
+  let M;
+  (function(_M) {
+    _M.a = _N.c;
+    _M.a = _N.b;
+  })(M || (M = _N.M || (_N.M = {})));
+})(N || (N = {}));
@@ -4,3 +4,8 @@ c2 = /((?<a>b)){2}/
 nested1 = /(?<!(?<a>b))/
 nested2 = /((?<a>b))/
 nested3 = /(?:(?<a>b))/
+
+references1 = /(?<year>\d{4})-(?<month>\d{2})-\k<month>/
+references2 = /(?<a>b)\k<a>1/
+duplicates1 = /(?<a>b)|(?<a>c)/
+duplicates2 = /(?<a>b)|(?<a>c)\k<a>/
@@ -1,6 +1,12 @@
-c1 = new RegExp("(?<a>b)", "");
-c2 = new RegExp("((?<a>b)){2}", "");
-
-nested1 = new RegExp("(?<!(?<a>b))", "");
-nested2 = new RegExp("((?<a>b))", "");
-nested3 = new RegExp("(?:(?<a>b))", "");
+c1 = babelHelpers.wrapRegExp(/(b)/, { a: 1 });
+c2 = babelHelpers.wrapRegExp(/((b)){2}/, { a: 2 });
+nested1 = babelHelpers.wrapRegExp(/(?<!(b))/, { a: 1 });
+nested2 = babelHelpers.wrapRegExp(/((b))/, { a: 2 });
+nested3 = babelHelpers.wrapRegExp(/(?:(b))/, { a: 1 });
+references1 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})-\2/, {
+  year: 1,
+  month: 2
+});
+references2 = babelHelpers.wrapRegExp(/(b)(?:\1)1/, { a: 1 });
+duplicates1 = babelHelpers.wrapRegExp(/(b)|(c)/, { a: [1, 2] });
+duplicates2 = new RegExp("(?<a>b)|(?<a>c)\\k<a>", "");
@@ -1,2 +1,7 @@
 d1 = /\p{Emoji}/u
 d2 = /\p{Emoji}{2}/u
+d3 = /\p{ASCII_Hex_Digit}/u
+d4 = /\P{ASCII}/u
+d5 = /[a\p{Script=Greek}\d]/u
+d6 = /[^\p{Lu}_]/iu
+d7 = /\p{Cs}/u
@@ -1 +1,7 @@
 g1 = /[\p{White_Space}&&\p{ASCII}]/v
+g2 = /[\p{ASCII_Hex_Digit}--[a-f]]/v
+g3 = /[[a-z]--[^aeiou]]/v
+g4 = /[\q{abc|d|}x-z]/v
+g5 = /[^\d\s]/v
+g6 = /[\p{ASCII}&&\w]/iv
+g7 = /\p{RGI_Emoji}/v
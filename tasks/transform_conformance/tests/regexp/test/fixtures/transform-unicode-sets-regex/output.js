@@ -1 +1,7 @@
-g1 = new RegExp("[\\p{White_Space}&&\\p{ASCII}]", "v");
+g1 = /[\u0009-\u000D\u0020]/u;
+g2 = /[0-9A-F]/u;
+g3 = /[aeiou]/u;
+g4 = /(?:abc|[dx-z]|)/u;
+g5 = /[^\u0009-\u000D\u00200-9\u00A0\u1680\u2000-\u200A\u2028-\u2029\u202F\u205F\u3000\uFEFF]/u;
+g6 = new RegExp("[\\p{ASCII}&&\\w]", "iv");
+g7 = new RegExp("\\p{RGI_Emoji}", "v");